@@ -0,0 +1,102 @@
+// derive_form/src/choice_enum.rs — #[derive(ChoiceEnum)]: choice options from enum variants
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, quote};
+use syn::{Data, DeriveInput, Fields, LitStr};
+
+/// `#[choice(...)]` options of the enum or of one variant.
+#[derive(Default)]
+struct ChoiceAttrs {
+    name: Option<String>,
+    value: Option<String>,
+    label: Option<String>,
+}
+
+fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<ChoiceAttrs> {
+    let mut out = ChoiceAttrs::default();
+    for attr in attrs.iter().filter(|a| a.path().is_ident("choice")) {
+        attr.parse_nested_meta(|meta| {
+            let slot = if meta.path.is_ident("name") {
+                &mut out.name
+            } else if meta.path.is_ident("value") {
+                &mut out.value
+            } else if meta.path.is_ident("label") {
+                &mut out.label
+            } else {
+                return Err(meta.error("expected `name`, `value` or `label`"));
+            };
+            *slot = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        })?;
+    }
+    Ok(out)
+}
+
+pub fn derive_choice_enum(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[derive(ChoiceEnum)] only applies to enums",
+        ));
+    };
+    let enum_attrs = parse_attrs(&input.attrs)?;
+    if enum_attrs.value.is_some() || enum_attrs.label.is_some() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`value` and `label` apply to a variant, not to the enum",
+        ));
+    }
+
+    let ident = &input.ident;
+    // Same type name as the enums of `model!`
+    let enum_name = enum_attrs
+        .name
+        .unwrap_or_else(|| ident.to_string().to_ascii_lowercase());
+
+    let mut values = Vec::new();
+    let mut labels = Vec::new();
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "#[derive(ChoiceEnum)] variants cannot hold data",
+            ));
+        }
+        let attrs = parse_attrs(&variant.attrs)?;
+        if attrs.name.is_some() {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "`name` applies to the enum, not to a variant",
+            ));
+        }
+        // Stored value: explicit `value`, otherwise the discriminant, otherwise the name
+        let value = match (attrs.value, &variant.discriminant) {
+            (Some(value), _) => value,
+            (None, Some((_, expr))) => expr.to_token_stream().to_string().replace(' ', ""),
+            (None, None) => variant.ident.to_string(),
+        };
+        labels.push(attrs.label.unwrap_or_else(|| variant.ident.to_string()));
+        values.push(value);
+        variants.push(&variant.ident);
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::runique::forms::ChoiceEnum for #ident #ty_generics #where_clause {
+            const ENUM_NAME: &'static str = #enum_name;
+
+            fn choices() -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+                ::std::vec![
+                    #((#values.to_string(), #labels.to_string()),)*
+                ]
+            }
+
+            fn choice_value(&self) -> &'static str {
+                match *self {
+                    #(Self::#variants => #values,)*
+                }
+            }
+        }
+    })
+}
@@ -1,5 +1,6 @@
 use crate::model::ast::{EnumDef, FormFieldAttr, FormFieldDecl, FormFieldKind};
-use crate::model::generateur::generate_enum_defs;
+use crate::model::generateur::{FormHookTokens, form_hook_tokens, generate_enum_defs};
+use crate::model::utils::ident_pascal;
 use crate::registry::{FormWidget, PhantomColumn, PkKind, phantom_columns};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
@@ -9,6 +10,8 @@ use syn::{Ident, LitStr, Token, braced, parse::ParseStream};
 
 pub(crate) struct ExtendDsl {
     pub table: String,
+    /// `form_hooks: true` — the generated form forwards its hooks to `FormHooks`.
+    pub form_hooks: bool,
     pub enums: Vec<EnumDef>,
     pub fields: Vec<FormFieldDecl>,
 }
@@ -23,6 +26,15 @@ impl syn::parse::Parse for ExtendDsl {
         let table: LitStr = input.parse()?;
         input.parse::<Token![,]>()?;
 
+        // form_hooks: true optional
+        let mut form_hooks = false;
+        if input.peek(Ident) && input.fork().parse::<Ident>()? == "form_hooks" {
+            input.parse::<Ident>()?;
+            input.parse::<Token![:]>()?;
+            form_hooks = input.parse::<syn::LitBool>()?.value();
+            input.parse::<Token![,]>()?;
+        }
+
         // enums: { ... } optional — reuses the model parser (EnumDef: Parse).
         let mut enums = Vec::new();
         if input.peek(Ident) {
@@ -55,6 +67,7 @@ impl syn::parse::Parse for ExtendDsl {
 
         Ok(ExtendDsl {
             table: table.value(),
+            form_hooks,
             enums,
             fields,
         })
@@ -577,7 +590,7 @@ fn extend_form_field_registration(ff: &FormFieldDecl, enums: &[EnumDef]) -> Toke
     quote! { form.field(&#field_expr); }
 }
 
-/// Generates a complete SeaORM entity (Model + Relation + ActiveModelBehavior)
+/// Generates a complete SeaORM entity (Model + Relation + signal-emitting ActiveModelBehavior)
 /// plus an AdminForm, from the phantom base columns + user-declared extended columns.
 pub(crate) fn generate_entity(dsl: &ExtendDsl) -> TokenStream2 {
     let table = &dsl.table;
@@ -694,6 +707,23 @@ pub(crate) fn generate_entity(dsl: &ExtendDsl) -> TokenStream2 {
         })
         .collect();
 
+    // Extended `auto_now` / `auto_now_update` columns, stamped at save time.
+    let columns_with = |wanted: fn(&FormFieldAttr) -> bool| -> Vec<Ident> {
+        dsl.fields
+            .iter()
+            .filter(|ff| ff.attrs.iter().any(wanted))
+            .map(|ff| ident_pascal(&ff.name))
+            .collect()
+    };
+    let auto_now = columns_with(|a| matches!(a, FormFieldAttr::AutoNow));
+    let auto_now_update = columns_with(|a| matches!(a, FormFieldAttr::AutoNowUpdate));
+
+    let FormHookTokens {
+        attr: hooks_attr,
+        customize: hooks_customize,
+        methods: hooks_methods,
+    } = form_hook_tokens(dsl.form_hooks);
+
     quote! {
         #enum_defs
 
@@ -717,7 +747,11 @@ pub(crate) fn generate_entity(dsl: &ExtendDsl) -> TokenStream2 {
         #[derive(Copy, Clone, Debug, ::sea_orm::EnumIter, ::sea_orm::DeriveRelation)]
         pub enum Relation {}
 
-        impl ::sea_orm::ActiveModelBehavior for ActiveModel {}
+        ::runique::impl_signals!(
+            ActiveModel,
+            auto_now = [#(Column::#auto_now),*],
+            auto_now_update = [#(Column::#auto_now_update),*],
+        );
 
         pub fn admin_from_form(
             __data: &::std::collections::HashMap<::std::string::String, ::std::string::String>,
@@ -753,11 +787,14 @@ pub(crate) fn generate_entity(dsl: &ExtendDsl) -> TokenStream2 {
             pub form: ::runique::forms::Forms,
         }
 
+        #hooks_attr
         impl ::runique::forms::field::RuniqueForm for #form_name {
             fn register_fields(form: &mut ::runique::forms::Forms) {
                 #(#phantom_registrations)*
                 #(#extended_registrations)*
+                #hooks_customize
             }
+            #hooks_methods
             fn from_form(form: ::runique::forms::Forms) -> Self {
                 Self { form }
             }
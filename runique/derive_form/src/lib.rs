@@ -1,5 +1,6 @@
 use proc_macro::TokenStream;
 
+mod choice_enum;
 mod extend_schema;
 mod model;
 mod registry;
@@ -49,6 +50,36 @@ pub fn form(attr: TokenStream, item: TokenStream) -> TokenStream {
     schema_form::model_schema(attr, item)
 }
 
+/// Implements `runique::forms::ChoiceEnum` for a fieldless enum: its variants
+/// become the options of `ChoiceField::from_enum` and the variants of
+/// `ColumnDef::enum_of`.
+///
+/// Stored value: `#[choice(value = "…")]`, otherwise the explicit discriminant,
+/// otherwise the variant name. Label: `#[choice(label = "…")]`, otherwise the
+/// variant name. `#[choice(name = "…")]` on the enum sets the database type
+/// name (default: the enum name in lowercase, as for `model!` enums).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(ChoiceEnum)]
+/// pub enum Status {
+///     #[choice(label = "Brouillon")]
+///     Draft,
+///     #[choice(value = "pub", label = "Publié")]
+///     Published,
+/// }
+///
+/// form.field(&ChoiceField::from_enum::<Status>("status"));
+/// ```
+#[proc_macro_derive(ChoiceEnum, attributes(choice))]
+pub fn choice_enum(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    choice_enum::derive_choice_enum(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 #[proc_macro]
 pub fn model(input: TokenStream) -> TokenStream {
     model::model_impl(input)
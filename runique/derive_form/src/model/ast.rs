@@ -251,4 +251,6 @@ pub struct MetaDef {
     #[allow(dead_code)]
     pub abstract_model: bool,
     pub indexes: Vec<Vec<syn::Ident>>,
+    /// `form_hooks: true` — the generated form forwards its hooks to `FormHooks`.
+    pub form_hooks: bool,
 }
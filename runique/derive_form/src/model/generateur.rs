@@ -8,7 +8,7 @@ pub fn generate(model: &ModelInput) -> TokenStream2 {
     let schema = generate_schema(model);
     let sea_model = generate_sea_model(model);
     let relation_enum = generate_relation_enum(model);
-    let active_model = generate_active_model();
+    let active_model = generate_active_model(model);
     let from_str_map: TokenStream2 = generate_from_str_map(model);
     let partial_update: TokenStream2 = generate_partial_update(model);
     let admin_form = generate_admin_form(model);
@@ -85,7 +85,35 @@ pub fn generate_enum_defs(enums: &[EnumDef]) -> TokenStream2 {
                 variant_names.iter().map(|v| v.to_string()).collect();
             let first = &variant_names[0];
 
-            match e.backing_type {
+            // ChoiceEnum: the stored value and label of each variant
+            let enum_type_name = e.name.to_string().to_ascii_lowercase();
+            let choice_values: Vec<String> = e
+                .variants
+                .iter()
+                .map(|v| match (&e.backing_type, &v.value) {
+                    (EnumBackingType::Auto, _) => v.db_str(),
+                    (_, Some(syn::Lit::Int(n))) => n.base10_digits().to_string(),
+                    _ => "0".to_string(),
+                })
+                .collect();
+            let choice_labels: Vec<String> = e.variants.iter().map(|v| v.display_str()).collect();
+            let choice_impl = quote! {
+                impl ::runique::forms::ChoiceEnum for #name {
+                    const ENUM_NAME: &'static str = #enum_type_name;
+
+                    fn choices() -> ::std::vec::Vec<(::std::string::String, ::std::string::String)> {
+                        ::std::vec![#((#choice_values.to_string(), #choice_labels.to_string()),)*]
+                    }
+
+                    fn choice_value(&self) -> &'static str {
+                        match *self {
+                            #(#name::#variant_names => #choice_values,)*
+                        }
+                    }
+                }
+            };
+
+            let definition = match e.backing_type {
                 EnumBackingType::I32 => {
                     let db_values: Vec<i32> = e
                         .variants
@@ -340,6 +368,10 @@ pub fn generate_enum_defs(enums: &[EnumDef]) -> TokenStream2 {
                         }
                     }
                 }
+            };
+            quote! {
+                #definition
+                #choice_impl
             }
         })
         .collect()
@@ -1188,6 +1220,13 @@ pub fn generate_admin_form(model: &ModelInput) -> TokenStream2 {
     }).collect()
     }; // end of if form_fields
 
+    let form_hooks = model.meta.as_ref().is_some_and(|m| m.form_hooks);
+    let FormHookTokens {
+        attr: hooks_attr,
+        customize: hooks_customize,
+        methods: hooks_methods,
+    } = form_hook_tokens(form_hooks);
+
     quote! {
         /// Stable alias used by the `runique start` daemon to reference this form.
         /// Always available via `{module}::AdminForm`.
@@ -1200,10 +1239,13 @@ pub fn generate_admin_form(model: &ModelInput) -> TokenStream2 {
             pub form: ::runique::forms::Forms,
         }
 
+        #hooks_attr
         impl ::runique::forms::field::RuniqueForm for #form_name {
             fn register_fields(form: &mut ::runique::forms::Forms) {
                 #(#field_registrations)*
+                #hooks_customize
             }
+            #hooks_methods
 
             fn from_form(form: ::runique::forms::Forms) -> Self {
                 Self { form }
@@ -1220,6 +1262,39 @@ pub fn generate_admin_form(model: &ModelInput) -> TokenStream2 {
     }
 }
 
+/// Pieces of a generated `impl RuniqueForm` that forward to `FormHooks`.
+/// All empty when `form_hooks` is off, so the trait defaults apply.
+pub(crate) struct FormHookTokens {
+    pub attr: TokenStream2,
+    pub customize: TokenStream2,
+    pub methods: TokenStream2,
+}
+
+pub(crate) fn form_hook_tokens(enabled: bool) -> FormHookTokens {
+    if !enabled {
+        return FormHookTokens {
+            attr: quote! {},
+            customize: quote! {},
+            methods: quote! {},
+        };
+    }
+    FormHookTokens {
+        attr: quote! { #[::runique::async_trait::async_trait] },
+        customize: quote! {
+            <Self as ::runique::forms::field::FormHooks>::customize(form);
+        },
+        methods: quote! {
+            fn clean_value(&self, name: &str, value: &str) -> ::std::result::Result<(), ::std::string::String> {
+                <Self as ::runique::forms::field::FormHooks>::clean_value(self, name, value)
+            }
+
+            async fn clean(&mut self) -> ::std::result::Result<(), ::std::collections::HashMap<::std::string::String, ::std::string::String>> {
+                <Self as ::runique::forms::field::FormHooks>::clean(self).await
+            }
+        },
+    }
+}
+
 /// Generates `form.field(&...)` from a `FormFieldDecl` declaration (form_fields: block).
 /// `model` is passed to allow resolution of enum variants for `Choice`/`Radio`.
 fn generate_form_field_decl(ff: &FormFieldDecl, model: &ModelInput) -> TokenStream2 {
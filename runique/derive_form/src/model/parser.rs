@@ -621,6 +621,7 @@ impl Parse for MetaDef {
         let mut verbose_name_plural = None;
         let mut abstract_model = false;
         let mut indexes = Vec::new();
+        let mut form_hooks = false;
 
         while !input.is_empty() {
             let key: Ident = input.parse()?;
@@ -668,6 +669,10 @@ impl Parse for MetaDef {
                     let b: syn::LitBool = input.parse()?;
                     abstract_model = b.value();
                 }
+                "form_hooks" => {
+                    let b: syn::LitBool = input.parse()?;
+                    form_hooks = b.value();
+                }
                 "indexes" => {
                     let content;
                     syn::bracketed!(content in input);
@@ -701,6 +706,7 @@ impl Parse for MetaDef {
             verbose_name_plural,
             abstract_model,
             indexes,
+            form_hooks,
         })
     }
 }
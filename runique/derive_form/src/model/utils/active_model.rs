@@ -1,10 +1,28 @@
+use crate::model::{FieldOption, ModelInput};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 
+use super::relation_enum::ident_pascal;
+
 // ── ActiveModel ───────────────────────────────────────────────
-pub fn generate_active_model() -> TokenStream2 {
+pub fn generate_active_model(model: &ModelInput) -> TokenStream2 {
+    let columns_with = |wanted: fn(&FieldOption) -> bool| -> Vec<proc_macro2::Ident> {
+        model
+            .fields
+            .iter()
+            .filter(|f| f.options.iter().any(wanted))
+            .map(|f| ident_pascal(&f.name))
+            .collect()
+    };
+    let auto_now = columns_with(|o| matches!(o, FieldOption::AutoNow));
+    let auto_now_update = columns_with(|o| matches!(o, FieldOption::AutoNowUpdate));
+
     quote! {
-        impl ::sea_orm::ActiveModelBehavior for ActiveModel {}
+        ::runique::impl_signals!(
+            ActiveModel,
+            auto_now = [#(Column::#auto_now),*],
+            auto_now_update = [#(Column::#auto_now_update),*],
+        );
         ::runique::impl_objects!(Entity);
     }
 }
@@ -111,11 +111,16 @@ fn generate_relations(model: &ModelInput) -> Vec<TokenStream2> {
                     .relation(::runique::migration::RelationDef::has_one(#model_str).as_name(#as_str))
                 }
             }
-            RelationDef::ManyToMany { model, through, via_self: _ } => {
+            RelationDef::ManyToMany { model, through, via_self } => {
                 let model_str = to_snake_case(&model.to_string());
                 let through_str = to_snake_case(&through.to_string());
+                let owner_col = via_self.to_string();
+                let target_col = format!("{}_id", model_str);
                 quote! {
-                    .relation(::runique::migration::RelationDef::many_to_many(#model_str, #through_str))
+                    .relation(
+                        ::runique::migration::RelationDef::many_to_many(#model_str, #through_str)
+                            .through(#owner_col, #target_col)
+                    )
                 }
             }
         }
@@ -255,7 +255,7 @@ fn table_to_module(table_name: &str) -> String {
     }
 }
 
-fn ident_pascal(name: &syn::Ident) -> proc_macro2::Ident {
+pub(crate) fn ident_pascal(name: &syn::Ident) -> proc_macro2::Ident {
     quote::format_ident!("{}", pascal_case(&name.to_string()))
 }
 
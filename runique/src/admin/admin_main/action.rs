@@ -23,6 +23,11 @@ pub(super) enum CollectionAction {
     List,
     Create,
     Bulk,
+    /// CSV download of the list (same search / filters / sort).
+    Export,
+    /// JSON `[{id, label}]` search feeding an autocomplete FK box of the
+    /// create/edit form.
+    Autocomplete,
 }
 
 /// Actions on a member URL: `/admin/{resource}/{id}/{action}`.
@@ -40,11 +45,14 @@ impl CollectionAction {
             "list" => Some(Self::List),
             "create" => Some(Self::Create),
             "bulk" => Some(Self::Bulk),
+            "export" => Some(Self::Export),
+            "autocomplete" => Some(Self::Autocomplete),
             _ => None,
         }
     }
 
-    /// Valid POST actions on a collection (`None` → 404). `list` is GET-only.
+    /// Valid POST actions on a collection (`None` → 404). `list`, `export` and
+    /// `autocomplete` are GET-only.
     pub(super) fn parse_post(action: &str) -> Option<Self> {
         match action {
             "create" => Some(Self::Create),
@@ -55,7 +63,7 @@ impl CollectionAction {
 
     pub(super) fn authorize_get(&self, perms: &ResourcePerms) -> Access {
         match self {
-            Self::List => {
+            Self::List | Self::Export => {
                 if perms.can_read {
                     Access::Granted
                 } else {
@@ -78,6 +86,11 @@ impl CollectionAction {
                     Access::DeniedResource
                 }
             }
+            // Only the create/edit forms use it; reading the related resource is
+            // checked by the handler.
+            Self::Autocomplete => {
+                grant_if(perms.can_create || perms.can_update || perms.can_update_own)
+            }
         }
     }
 
@@ -104,8 +117,8 @@ impl CollectionAction {
                     Access::DeniedResource
                 }
             }
-            // `list` is rejected at parse time; unreachable in practice.
-            Self::List => Access::DeniedResource,
+            // GET-only actions are rejected at parse time; unreachable in practice.
+            Self::List | Self::Export | Self::Autocomplete => Access::DeniedResource,
         }
     }
 }
@@ -187,7 +200,11 @@ mod tests {
         assert!(CollectionAction::parse_get("list").is_some());
         assert!(CollectionAction::parse_post("list").is_none()); // list is GET-only
         assert!(CollectionAction::parse_get("nope").is_none());
+        assert!(CollectionAction::parse_get("export").is_some());
+        assert!(CollectionAction::parse_post("export").is_none()); // export is GET-only
         assert!(CollectionAction::parse_post("create").is_some());
+        assert!(CollectionAction::parse_get("autocomplete").is_some());
+        assert!(CollectionAction::parse_post("autocomplete").is_none()); // GET-only
     }
 
     #[test]
@@ -212,6 +229,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn export_follows_list_rule() {
+        assert_eq!(
+            CollectionAction::Export.authorize_get(&perms(false, true, false, false, false, false)),
+            Access::Granted
+        );
+        assert_eq!(
+            CollectionAction::Export.authorize_get(&NONE),
+            Access::DeniedDashboard
+        );
+    }
+
+    #[test]
+    fn autocomplete_requires_a_form_right() {
+        // create or (own) update → granted
+        assert_eq!(
+            CollectionAction::Autocomplete
+                .authorize_get(&perms(true, false, false, false, false, false)),
+            Access::Granted
+        );
+        assert_eq!(
+            CollectionAction::Autocomplete
+                .authorize_get(&perms(false, false, false, false, true, false)),
+            Access::Granted
+        );
+        // read-only → no form, no search
+        assert_eq!(
+            CollectionAction::Autocomplete
+                .authorize_get(&perms(false, true, false, false, false, false)),
+            Access::DeniedResource
+        );
+    }
+
     #[test]
     fn create_get_is_two_stage() {
         // no read at all → dashboard
@@ -0,0 +1,158 @@
+//! Autocomplete FK boxes: the `GET /admin/{resource}/autocomplete` search and the
+//! swap of the declared `autocomplete_fields` selects for search boxes.
+use super::ResourcePerms;
+use crate::admin::helper::{dyn_form::DynForm, fk_key, resource_entry::ResourceEntry};
+use crate::auth::session::CurrentUser;
+use crate::forms::{RowLookup, base::FormField, fields::AutocompleteField};
+use crate::utils::aliases::{ADb, AppResult, StrMap};
+use crate::utils::trad::t;
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde_json::{Value, json};
+
+/// Rows returned per search.
+const AUTOCOMPLETE_LIMIT: u64 = 20;
+/// Longest search term kept (longer input is truncated).
+const AUTOCOMPLETE_MAX_TERM: usize = 100;
+
+/// Label column of `target`'s rows — its `label_field`, else the id.
+fn label_column(target: &ResourceEntry) -> &str {
+    target.meta.label_field.as_deref().unwrap_or("id")
+}
+
+/// `GET /admin/{resource}/autocomplete?field={column}&q={term}` — JSON
+/// `[{"id": …, "label": …}]` of the rows of the resource `column` points to whose
+/// label contains `term` (case-insensitive). Access to `resource`'s forms is
+/// checked upstream; reading the related resource is checked here (else 403).
+pub(super) async fn handle_autocomplete(
+    db: ADb,
+    state: &super::PrototypeAdminState,
+    entry: &ResourceEntry,
+    current_user: &CurrentUser,
+    params: &StrMap,
+) -> AppResult<Response> {
+    let field = params.get("field").map(String::as_str).unwrap_or("");
+    let Some((_, related)) = entry
+        .meta
+        .autocomplete_fields
+        .iter()
+        .find(|(col, _)| col == field)
+    else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+    let Some(target) = state.registry.get(related) else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+    if !ResourcePerms::resolve(current_user, related).can_read {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
+    let Some(search) = &target.autocomplete_fn else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    let term: String = params
+        .get("q")
+        .map(|q| q.trim().chars().take(AUTOCOMPLETE_MAX_TERM).collect())
+        .unwrap_or_default();
+    let rows = search(
+        db,
+        term,
+        label_column(target).to_string(),
+        AUTOCOMPLETE_LIMIT,
+    )
+    .await
+    .map_err(|e| {
+        Box::new(crate::context::template::AppError::new(
+            crate::errors::error::ErrorContext::database(e),
+        ))
+    })?;
+    let body: Vec<Value> = rows
+        .into_iter()
+        .map(|(id, label)| json!({ "id": id, "label": label }))
+        .collect();
+    Ok(Json(body).into_response())
+}
+
+/// `id → label` lookup over `target`'s rows, through its `get_fn`.
+fn target_lookup(target: &ResourceEntry) -> Option<RowLookup> {
+    let get_fn = target.get_fn.clone()?;
+    let label = label_column(target).to_string();
+    Some(RowLookup::new(move |db, id| {
+        let get_fn = get_fn.clone();
+        let label = label.clone();
+        async move {
+            let row = get_fn(std::sync::Arc::new(db), id.clone()).await?;
+            Ok(row.map(|row| row.get(&label).and_then(fk_key).unwrap_or(id)))
+        }
+    }))
+}
+
+/// Replaces the `autocomplete_fields` of a built form with search boxes querying
+/// `{base}/autocomplete`. Each keeps its key, label, required flag and value; on a
+/// form with a value (edit, re-render) the selected row's label is shown.
+pub(super) async fn apply_autocomplete_fields(
+    form: &mut Box<dyn DynForm>,
+    entry: &ResourceEntry,
+    state: &super::PrototypeAdminState,
+    db: &ADb,
+    base: &str,
+) {
+    for (col, related) in &entry.meta.autocomplete_fields {
+        let fields = &mut form.get_form_mut().fields;
+        let Some(old) = fields.get(col) else {
+            continue;
+        };
+        let mut field = AutocompleteField::new(col, &format!("{base}/autocomplete?field={col}"))
+            .label(old.label());
+        field.set_required(old.required(), None);
+        field.set_value(old.value());
+        let value = field.value().trim().to_string();
+        if !value.is_empty()
+            && let Some(lookup) = state.registry.get(related).and_then(target_lookup)
+            && let Ok(Some(label)) = lookup.label(db, &value).await
+        {
+            field.set_selected_label(&label);
+        }
+        fields.insert(col.clone(), Box::new(field));
+    }
+}
+
+/// Confirms the id submitted in each autocomplete box matches a row of the related
+/// resource (field error otherwise). Run after [`DynForm::is_valid`], which has no
+/// database. Returns `false` on any error.
+pub(super) async fn check_autocomplete_values(
+    form: &mut Box<dyn DynForm>,
+    entry: &ResourceEntry,
+    state: &super::PrototypeAdminState,
+    db: &ADb,
+) -> bool {
+    let mut valid = true;
+    for (col, related) in &entry.meta.autocomplete_fields {
+        let Some(lookup) = state.registry.get(related).and_then(target_lookup) else {
+            continue;
+        };
+        let Some(field) = form.get_form_mut().fields.get_mut(col) else {
+            continue;
+        };
+        let value = field.value().trim().to_string();
+        if value.is_empty() || field.error().is_some() {
+            continue;
+        }
+        match lookup.label(db, &value).await {
+            Ok(Some(label)) => field.set_selected_label(&label),
+            Ok(None) => {
+                field.set_error(t("forms.choice_invalid").to_string());
+                valid = false;
+            }
+            Err(e) => {
+                tracing::error!(field = %col, "autocomplete lookup failed: {}", e);
+                field.set_error(t("forms.choice_invalid").to_string());
+                valid = false;
+            }
+        }
+    }
+    valid
+}
@@ -92,7 +92,7 @@ pub(super) async fn handle_bulk_edit_get(
         .collect();
     let bulk_count = ids.len();
 
-    let tera = req.engine.tera.clone();
+    let tera = req.engine.tera.current();
     let csrf = req
         .csrf_token
         .masked()
@@ -169,12 +169,63 @@ pub(super) async fn handle_bulk_action(
         "update-submit" => {
             handle_bulk_update(req, entry, ids, body, state, current_user, parent).await
         }
-        _ => Err(Box::new(AppError::new(ErrorContext::not_found(
-            "Unknown bulk action",
-        )))),
+        name => match entry.bulk_action(name) {
+            Some(action) => {
+                handle_custom_action(req, entry, action, ids, state, current_user, parent).await
+            }
+            None => Err(Box::new(AppError::new(ErrorContext::not_found(
+                "Unknown bulk action",
+            )))),
+        },
     }
 }
 
+/// Runs a custom [`BulkAction`](crate::admin::helper::BulkAction) and flashes its summary.
+async fn handle_custom_action(
+    req: &mut Request,
+    entry: &ResourceEntry,
+    action: &crate::admin::helper::BulkAction,
+    ids: Vec<String>,
+    state: &super::PrototypeAdminState,
+    current_user: &CurrentUser,
+    parent: Option<&ParentBinding>,
+) -> AppResult<Response> {
+    let cids: Vec<String> = ids.iter().map(|id| closure_id_of(parent, id)).collect();
+    let summary = (action.run)(req.engine.db.clone(), cids.clone())
+        .await
+        .map_err(|e| Box::new(AppError::new(ErrorContext::database(e))))?;
+
+    let batch_id = Some(Uuid::new_v4().to_string());
+    // Same `{field: {"new": …}}` shape as the other summaries, rendered by the diff view.
+    let history_summary = summary_json(
+        &serde_json::json!({ "bulk_action": { "new": action.name } }),
+        entry.meta.key,
+    );
+    for cid in &cids {
+        state
+            .log_action(
+                &req.engine.db,
+                history::AdminActionLog {
+                    user_id: current_user.id,
+                    username: &current_user.username,
+                    resource_key: entry.meta.key,
+                    object_pk: cid,
+                    action: "edit",
+                    summary: history_summary.clone(),
+                    batch_id: batch_id.clone(),
+                },
+            )
+            .await;
+    }
+
+    req.notices.success(summary).await;
+    Ok(Redirect::to(&format!(
+        "{}/list",
+        scope_base(&state.config.prefix, entry, parent)
+    ))
+    .into_response())
+}
+
 async fn handle_bulk_update(
     req: &mut Request,
     entry: &ResourceEntry,
@@ -259,19 +310,20 @@ async fn handle_bulk_update(
             }
             Err(e) => return Err(Box::new(AppError::new(ErrorContext::database(e)))),
         }
-        history::log_admin_action(
-            &req.engine.db,
-            history::AdminActionLog {
-                user_id: current_user.id,
-                username: &current_user.username,
-                resource_key: entry.meta.key,
-                object_pk: &cid,
-                action: "edit",
-                summary,
-                batch_id: batch_id.clone(),
-            },
-        )
-        .await;
+        state
+            .log_action(
+                &req.engine.db,
+                history::AdminActionLog {
+                    user_id: current_user.id,
+                    username: &current_user.username,
+                    resource_key: entry.meta.key,
+                    object_pk: &cid,
+                    action: "edit",
+                    summary,
+                    batch_id: batch_id.clone(),
+                },
+            )
+            .await;
     }
 
     req.notices
@@ -285,6 +337,12 @@ fn is_unique_violation(e: &sea_orm::DbErr) -> bool {
     msg.contains("unique") || msg.contains("UNIQUE") || msg.contains("Duplicate")
 }
 
+/// SQLite `FOREIGN KEY constraint failed`, Postgres `violates foreign key constraint`,
+/// MySQL `a foreign key constraint fails`.
+fn is_foreign_key_violation(e: &sea_orm::DbErr) -> bool {
+    e.to_string().to_lowercase().contains("foreign key")
+}
+
 async fn handle_group_set(
     req: &mut Request,
     entry: &ResourceEntry,
@@ -358,19 +416,20 @@ async fn handle_group_set(
         update_fn(req.engine.db.clone(), cid.clone(), updates.clone())
             .await
             .map_err(|e| Box::new(AppError::new(ErrorContext::database(e))))?;
-        history::log_admin_action(
-            &req.engine.db,
-            history::AdminActionLog {
-                user_id: current_user.id,
-                username: &current_user.username,
-                resource_key: entry.meta.key,
-                object_pk: &cid,
-                action: "edit",
-                summary,
-                batch_id: batch_id.clone(),
-            },
-        )
-        .await;
+        state
+            .log_action(
+                &req.engine.db,
+                history::AdminActionLog {
+                    user_id: current_user.id,
+                    username: &current_user.username,
+                    resource_key: entry.meta.key,
+                    object_pk: &cid,
+                    action: "edit",
+                    summary,
+                    batch_id: batch_id.clone(),
+                },
+            )
+            .await;
     }
 
     req.notices
@@ -393,34 +452,74 @@ async fn handle_bulk_delete(
         )))
     })?;
 
+    // A row still referenced by another table is skipped, not fatal: the others
+    // are deleted and the skipped count is reported.
     let batch_id = Some(Uuid::new_v4().to_string());
-    let count = ids.len();
+    let mut count = 0usize;
+    let mut blocked = 0usize;
     for id in &ids {
         let cid = closure_id_of(parent, id);
-        delete_fn(req.engine.db.clone(), cid.clone())
-            .await
-            .map_err(|e| Box::new(AppError::new(ErrorContext::database(e))))?;
-        history::log_admin_action(
-            &req.engine.db,
-            history::AdminActionLog {
-                user_id: current_user.id,
-                username: &current_user.username,
-                resource_key: entry.meta.key,
-                object_pk: &cid,
-                action: "delete",
-                summary: None,
-                batch_id: batch_id.clone(),
-            },
-        )
-        .await;
+        match delete_fn(req.engine.db.clone(), cid.clone()).await {
+            Ok(()) => count += 1,
+            Err(e) if is_foreign_key_violation(&e) => {
+                blocked += 1;
+                continue;
+            }
+            Err(e) => return Err(Box::new(AppError::new(ErrorContext::database(e)))),
+        }
+        state
+            .log_action(
+                &req.engine.db,
+                history::AdminActionLog {
+                    user_id: current_user.id,
+                    username: &current_user.username,
+                    resource_key: entry.meta.key,
+                    object_pk: &cid,
+                    action: "delete",
+                    summary: None,
+                    batch_id: batch_id.clone(),
+                },
+            )
+            .await;
     }
 
-    req.notices
-        .success(format!("{count} {}", t("admin.bulk.delete_success")))
-        .await;
+    if count > 0 {
+        req.notices
+            .success(format!("{count} {}", t("admin.bulk.delete_success")))
+            .await;
+    }
+    if blocked > 0 {
+        req.notices
+            .warning(format!("{blocked} {}", t("admin.bulk.delete_blocked")))
+            .await;
+    }
     Ok(Redirect::to(&format!(
         "{}/list",
         scope_base(&state.config.prefix, entry, parent)
     ))
     .into_response())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbErr, RuntimeErr};
+
+    fn exec_err(msg: &str) -> DbErr {
+        DbErr::Exec(RuntimeErr::Internal(msg.to_string()))
+    }
+
+    #[test]
+    fn foreign_key_violations_across_backends() {
+        for msg in [
+            "FOREIGN KEY constraint failed",
+            "update or delete on table \"blog\" violates foreign key constraint \"fk_comment_blog\"",
+            "Cannot delete or update a parent row: a foreign key constraint fails",
+        ] {
+            assert!(is_foreign_key_violation(&exec_err(msg)), "{msg}");
+        }
+        assert!(!is_foreign_key_violation(&exec_err(
+            "UNIQUE constraint failed"
+        )));
+    }
+}
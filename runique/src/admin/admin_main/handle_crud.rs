@@ -1,9 +1,13 @@
 use super::format_datetime;
+use super::handle_autocomplete::{apply_autocomplete_fields, check_autocomplete_values};
+use super::handle_inline_formset::{
+    InlineCtx, bind_inline_formsets, build_inline_formsets, log_inline_ops, save_with_inlines,
+    take_inline_data,
+};
 use super::{ParentBinding, closure_id_of, scope_base};
 use crate::admin::helper::resource_entry::ResourceEntry;
 use crate::admin::history;
 use crate::auth::session::CurrentUser;
-use crate::config::static_files::resolve_media_root;
 use crate::context::template::{AppError, Request};
 use crate::errors::error::ErrorContext;
 use crate::utils::{
@@ -85,6 +89,40 @@ fn hide_scope_fields(
     }
 }
 
+/// Pins the `readonly_fields` of submitted data to their stored values, so a posted
+/// value never reaches the save. A column missing from the stored row is dropped.
+pub(super) fn pin_readonly_values(data: &mut StrMap, readonly: &[String], stored: &StrMap) {
+    for name in readonly {
+        match stored.get(name) {
+            Some(value) => data.insert(name.clone(), value.clone()),
+            None => data.remove(name),
+        };
+    }
+}
+
+/// Replaces the `readonly_fields` of a built form with text-only fields showing the
+/// stored value. Same key, so each keeps its place among the editable fields; a
+/// stored column the form does not declare is appended.
+pub(super) fn show_readonly_fields(
+    form: &mut Box<dyn crate::admin::helper::dyn_form::DynForm>,
+    readonly: &[String],
+    stored: &StrMap,
+) {
+    use crate::forms::base::FormField;
+    use crate::forms::fields::DisplayField;
+
+    let fields = &mut form.get_form_mut().fields;
+    for name in readonly {
+        let mut display = match fields.get(name) {
+            Some(field) => DisplayField::new(name).label(field.label()),
+            None if stored.contains_key(name) => DisplayField::new(name),
+            None => continue,
+        };
+        display.set_value(stored.get(name).map_or("", String::as_str));
+        fields.insert(name.clone(), Box::new(display));
+    }
+}
+
 pub(super) async fn handle_detail(
     req: &mut Request,
     entry: &ResourceEntry,
@@ -102,6 +140,7 @@ pub(super) async fn handle_detail(
     };
 
     if let Some(mut v) = object {
+        entry.apply_computed_fields(std::slice::from_mut(&mut v));
         crate::admin::helper::resolve_fk_labels(
             req.engine.db.as_ref(),
             std::slice::from_mut(&mut v),
@@ -141,9 +180,10 @@ pub(super) async fn handle_create_get(
     req: &mut Request,
     entry: &ResourceEntry,
     state: &super::PrototypeAdminState,
+    current_user: &CurrentUser,
     parent: Option<&ParentBinding>,
 ) -> AppResult<Response> {
-    let tera = req.engine.tera.clone();
+    let tera = req.engine.tera.current();
     let csrf = req
         .csrf_token
         .masked()
@@ -165,11 +205,13 @@ pub(super) async fn handle_create_get(
         req.engine.db.clone(),
         resource_keys,
         initial,
-        tera,
-        csrf,
+        tera.clone(),
+        csrf.clone(),
         axum::http::Method::GET,
     )
     .await;
+    let base = scope_base(&state.config.prefix, entry, parent);
+    apply_autocomplete_fields(&mut form, entry, state, &req.engine.db, &base).await;
     if let Some(p) = parent {
         hide_scope_fields(&mut form, p, None);
     }
@@ -178,6 +220,16 @@ pub(super) async fn handle_create_get(
         let m2m_fields = loader(req.engine.db.clone(), None).await;
         req.context.insert("m2m_fields", &m2m_fields);
     }
+    let inline_ctx = InlineCtx {
+        db: req.engine.db.clone(),
+        tera,
+        csrf,
+        state,
+        current_user,
+    };
+    let inline_formsets = build_inline_formsets(&inline_ctx, entry, None).await;
+    req.context
+        .insert(ctx_create::INLINE_FORMSETS, &inline_formsets);
     inject_csp_nonce(&mut form, &req.context);
     req.context.insert(ctx_create::FORM_FIELDS, form.get_form());
     req.context.insert(ctx_create::IS_EDIT, &false);
@@ -210,8 +262,10 @@ pub(super) async fn handle_create_post(
         }
     }
 
+    // Inline rows are bound on their own, never seen by the parent form.
+    let inline_data = take_inline_data(entry, &mut body);
     let mut body_for_create = body.clone();
-    let tera = req.engine.tera.clone();
+    let tera = req.engine.tera.current();
     let csrf = req
         .csrf_token
         .masked()
@@ -227,15 +281,29 @@ pub(super) async fn handle_create_post(
         req.engine.db.clone(),
         resource_keys,
         body,
-        tera,
-        csrf,
+        tera.clone(),
+        csrf.clone(),
         axum::http::Method::POST,
     )
     .await;
+    let base = scope_base(&state.config.prefix, entry, parent);
+    apply_autocomplete_fields(&mut form, entry, state, &req.engine.db, &base).await;
     if let Some(p) = parent {
         hide_scope_fields(&mut form, p, None);
     }
-    let valid = form.is_valid().await;
+    let inline_ctx = InlineCtx {
+        db: req.engine.db.clone(),
+        tera,
+        csrf,
+        state,
+        current_user,
+    };
+    let inline = bind_inline_formsets(&inline_ctx, entry, None, &inline_data).await;
+    req.context
+        .insert(ctx_create::INLINE_FORMSETS, &inline.formsets);
+    let valid = form.is_valid().await
+        && check_autocomplete_values(&mut form, entry, state, &req.engine.db).await
+        && inline.valid;
     if let Some(level) = crate::utils::runique_log::get_log()
         .admin
         .as_ref()
@@ -248,12 +316,26 @@ pub(super) async fn handle_create_post(
         for (name, field) in &form.get_form().fields {
             body_for_create.insert(name.clone(), field.value().to_string());
         }
-        let result = match &entry.create_fn {
-            Some(f) => f(req.engine.db.clone(), body_for_create.clone()).await,
-            None => form.save(&req.engine.db).await,
+        // With inline rows to write, parent and children go through one transaction.
+        let result = match &entry.tx_save_fn {
+            Some(save) if !inline.ops.is_empty() => {
+                save_with_inlines(
+                    &req.engine.db,
+                    save,
+                    None,
+                    body_for_create.clone(),
+                    &inline.ops,
+                )
+                .await
+            }
+            _ => match &entry.create_fn {
+                Some(f) => f(req.engine.db.clone(), body_for_create.clone()).await,
+                None => form.save(&req.engine.db).await,
+            }
+            .map(|()| (String::new(), Vec::new())),
         };
-        match result {
-            Ok(()) => {
+        let (created_pk, inline_ids) = match result {
+            Ok(saved) => {
                 if let Some(level) = crate::utils::runique_log::get_log()
                     .admin
                     .as_ref()
@@ -261,6 +343,7 @@ pub(super) async fn handle_create_post(
                 {
                     crate::runique_log!(level, resource = %entry.meta.key, "create POST — saved ok");
                 }
+                saved
             }
             Err(sea_orm::DbErr::Custom(ref msg)) => {
                 if let Some(level) = crate::utils::runique_log::get_log()
@@ -313,21 +396,23 @@ pub(super) async fn handle_create_post(
                     .unwrap_or_else(|| state.config.templates.create.resolve());
                 return req.render(template);
             }
-        }
+        };
 
-        history::log_admin_action(
-            &req.engine.db,
-            history::AdminActionLog {
-                user_id: current_user.id,
-                username: &current_user.username,
-                resource_key: entry.meta.key,
-                object_pk: "",
-                action: "create",
-                summary: None,
-                batch_id: None,
-            },
-        )
-        .await;
+        state
+            .log_action(
+                &req.engine.db,
+                history::AdminActionLog {
+                    user_id: current_user.id,
+                    username: &current_user.username,
+                    resource_key: entry.meta.key,
+                    object_pk: &created_pk,
+                    action: "create",
+                    summary: history::created_fields(&body_for_create),
+                    batch_id: None,
+                },
+            )
+            .await;
+        log_inline_ops(state, &req.engine.db, current_user, inline.ops, &inline_ids).await;
 
         if entry.meta.inject_password
             && let Some(email) = body_for_create.get("email")
@@ -352,11 +437,7 @@ pub(super) async fn handle_create_post(
         req.notices
             .success(t("admin.create.success").to_string())
             .await;
-        return Ok(Redirect::to(&format!(
-            "{}/list",
-            scope_base(&state.config.prefix, entry, parent)
-        ))
-        .into_response());
+        return Ok(Redirect::to(&format!("{base}/list")).into_response());
     }
 
     if let Some(loader) = &entry.m2m_loader {
@@ -380,10 +461,11 @@ pub(super) async fn handle_edit_get(
     entry: &ResourceEntry,
     id: String,
     state: &super::PrototypeAdminState,
+    current_user: &CurrentUser,
     parent: Option<&ParentBinding>,
 ) -> AppResult<Response> {
     let closure_id = closure_id_of(parent, &id);
-    let tera = req.engine.tera.clone();
+    let tera = req.engine.tera.current();
     let csrf = req
         .csrf_token
         .masked()
@@ -413,14 +495,17 @@ pub(super) async fn handle_edit_get(
         req.engine.db.clone(),
         resource_keys,
         data.clone(),
-        tera,
-        csrf,
+        tera.clone(),
+        csrf.clone(),
         axum::http::Method::GET,
     )
     .await;
+    let base = scope_base(&state.config.prefix, entry, parent);
+    apply_autocomplete_fields(&mut form, entry, state, &req.engine.db, &base).await;
     if let Some(p) = parent {
         hide_scope_fields(&mut form, p, Some(&id));
     }
+    show_readonly_fields(&mut form, &entry.meta.display.readonly_fields, &data);
 
     if let Some(ts) = data.get("updated_at") {
         req.context.insert(ctx_edit::ORIG_UPDATED_AT, ts);
@@ -438,6 +523,16 @@ pub(super) async fn handle_edit_get(
         let m2m_fields = loader(req.engine.db.clone(), Some(closure_id.clone())).await;
         req.context.insert("m2m_fields", &m2m_fields);
     }
+    let inline_ctx = InlineCtx {
+        db: req.engine.db.clone(),
+        tera,
+        csrf,
+        state,
+        current_user,
+    };
+    let inline_formsets = build_inline_formsets(&inline_ctx, entry, Some(&closure_id)).await;
+    req.context
+        .insert(ctx_edit::INLINE_FORMSETS, &inline_formsets);
 
     inject_csp_nonce(&mut form, &req.context);
     req.context.insert(ctx_edit::FORM_FIELDS, form.get_form());
@@ -456,12 +551,31 @@ pub(super) async fn handle_edit_post(
     req: &mut Request,
     entry: &ResourceEntry,
     id: String,
-    body: StrMap,
+    mut body: StrMap,
     state: &super::PrototypeAdminState,
     current_user: &CurrentUser,
     parent: Option<&ParentBinding>,
 ) -> AppResult<Response> {
     let closure_id = closure_id_of(parent, &id);
+    // Read-only columns: the stored row wins over any posted value.
+    let readonly = &entry.meta.display.readonly_fields;
+    let stored_obj: Option<Option<Value>> = match &entry.get_fn {
+        Some(get_fn) if !readonly.is_empty() => Some(
+            get_fn(req.engine.db.clone(), closure_id.clone())
+                .await
+                .map_err(|e| Box::new(AppError::new(ErrorContext::database(e))))?,
+        ),
+        _ => None,
+    };
+    let stored = stored_obj
+        .clone()
+        .flatten()
+        .map(value_to_strmap)
+        .unwrap_or_default();
+    if !readonly.is_empty() {
+        pin_readonly_values(&mut body, readonly, &stored);
+    }
+    let inline_data = take_inline_data(entry, &mut body);
     let mut body_for_update = body.clone();
     let orig_updated_at = body_for_update.remove("__original_updated_at");
     let return_qs = body_for_update
@@ -472,7 +586,7 @@ pub(super) async fn handle_edit_post(
         force_scope_values(&mut body_for_update, p, Some(&id));
     }
 
-    let tera = req.engine.tera.clone();
+    let tera = req.engine.tera.current();
     let csrf = req
         .csrf_token
         .masked()
@@ -492,17 +606,31 @@ pub(super) async fn handle_edit_post(
         req.engine.db.clone(),
         resource_keys,
         body,
-        tera,
-        csrf,
+        tera.clone(),
+        csrf.clone(),
         axum::http::Method::PATCH,
     )
     .await;
+    let base = scope_base(&state.config.prefix, entry, parent);
+    apply_autocomplete_fields(&mut form, entry, state, &req.engine.db, &base).await;
     if let Some(p) = parent {
         hide_scope_fields(&mut form, p, Some(&id));
     }
+    show_readonly_fields(&mut form, readonly, &stored);
+    let inline_ctx = InlineCtx {
+        db: req.engine.db.clone(),
+        tera,
+        csrf,
+        state,
+        current_user,
+    };
+    let inline = bind_inline_formsets(&inline_ctx, entry, Some(&closure_id), &inline_data).await;
+    req.context
+        .insert(ctx_edit::INLINE_FORMSETS, &inline.formsets);
 
     let mut is_locked = false;
-    let is_form_valid = form.is_valid().await;
+    let is_form_valid = form.is_valid().await
+        && check_autocomplete_values(&mut form, entry, state, &req.engine.db).await;
     if let Some(level) = crate::utils::runique_log::get_log()
         .admin
         .as_ref()
@@ -512,7 +640,9 @@ pub(super) async fn handle_edit_post(
     }
 
     let old_obj: Option<Value> = if is_form_valid {
-        if let Some(get_fn) = &entry.get_fn {
+        if let Some(obj) = stored_obj {
+            obj
+        } else if let Some(get_fn) = &entry.get_fn {
             get_fn(req.engine.db.clone(), closure_id.clone())
                 .await
                 .map_err(|e| Box::new(AppError::new(ErrorContext::database(e))))?
@@ -534,15 +664,14 @@ pub(super) async fn handle_edit_post(
         req.notices.error("This content has been modified by someone else during your editing. Refresh the page.").await;
     }
 
-    if !is_locked && !form.get_form().has_errors() {
+    if !is_locked && !form.get_form().has_errors() && inline.valid {
         // Sync finalized field values (e.g. file paths moved by finalize()) into body
         for (name, field) in &form.get_form().fields {
             body_for_update.insert(name.clone(), field.value().to_string());
         }
         // Delete old files replaced by a new upload
         if let Some(ref old) = old_obj {
-            let media_root = resolve_media_root();
-            let media_root = media_root.trim_end_matches('/');
+            let storage = crate::utils::storage::storage();
             for (name, field) in &form.get_form().fields {
                 if field.field_type() != "file" {
                     continue;
@@ -554,67 +683,91 @@ pub(super) async fn handle_edit_post(
                 if let Some(old_val) = old.get(name).and_then(|v| v.as_str())
                     && !old_val.is_empty()
                     && old_val != new_val
+                    && let Err(e) = storage.delete(old_val).await
                 {
-                    let old_abs = format!("{}/{}", media_root, old_val.trim_start_matches('/'));
-                    if let Err(e) = std::fs::remove_file(&old_abs)
-                        && e.kind() != std::io::ErrorKind::NotFound
-                    {
-                        tracing::warn!(path = %old_abs, error = %e, "old upload removal failed (edit)");
-                    }
+                    tracing::warn!(path = %old_val, error = %e, "old upload removal failed (edit)");
                 }
             }
         }
+        // A new password on a user resource logs that user's existing sessions out
+        // (session epochs) — other resources' ids are not user ids
+        let password_changed = state.config.user_resources.contains_key(entry.meta.key)
+            && form
+                .get_form()
+                .fields
+                .values()
+                .any(|f| f.field_type() == "password" && !f.value().is_empty());
         let summary = old_obj
             .as_ref()
             .and_then(|v| history::diff_fields(v, &body_for_update));
-        let result = match &entry.update_fn {
-            Some(f) => f(req.engine.db.clone(), closure_id.clone(), body_for_update).await,
-            None => form.save(&req.engine.db).await,
-        };
-        if let Err(e) = result {
-            if let Some(level) = crate::utils::runique_log::get_log()
-                .admin
-                .as_ref()
-                .and_then(|a| a.crud)
-            {
-                crate::runique_log!(level, resource = %entry.meta.key, id = %id, error = %e, unique = is_unique_violation(&e), "edit POST — DB error");
-            }
-            form.get_form_mut().database_error(&e);
-            if !is_unique_violation(&e) {
-                return Err(Box::new(AppError::new(ErrorContext::database(e))));
+        let result = match &entry.tx_save_fn {
+            Some(save) if !inline.ops.is_empty() => save_with_inlines(
+                &req.engine.db,
+                save,
+                Some(closure_id.clone()),
+                body_for_update,
+                &inline.ops,
+            )
+            .await
+            .map(|(_, ids)| ids),
+            _ => match &entry.update_fn {
+                Some(f) => f(req.engine.db.clone(), closure_id.clone(), body_for_update).await,
+                None => form.save(&req.engine.db).await,
             }
-        } else {
-            if let Some(level) = crate::utils::runique_log::get_log()
-                .admin
-                .as_ref()
-                .and_then(|a| a.crud)
-            {
-                crate::runique_log!(level, resource = %entry.meta.key, id = %id, "edit POST — saved ok");
+            .map(|()| Vec::new()),
+        };
+        match result {
+            Err(e) => {
+                if let Some(level) = crate::utils::runique_log::get_log()
+                    .admin
+                    .as_ref()
+                    .and_then(|a| a.crud)
+                {
+                    crate::runique_log!(level, resource = %entry.meta.key, id = %id, error = %e, unique = is_unique_violation(&e), "edit POST — DB error");
+                }
+                form.get_form_mut().database_error(&e);
+                if !is_unique_violation(&e) {
+                    return Err(Box::new(AppError::new(ErrorContext::database(e))));
+                }
             }
-            if summary.is_some() {
-                history::log_admin_action(
-                    &req.engine.db,
-                    history::AdminActionLog {
-                        user_id: current_user.id,
-                        username: &current_user.username,
-                        resource_key: entry.meta.key,
-                        object_pk: &closure_id,
-                        action: "edit",
-                        summary,
-                        batch_id: None,
-                    },
-                )
-                .await;
+            Ok(inline_ids) => {
+                if let Some(level) = crate::utils::runique_log::get_log()
+                    .admin
+                    .as_ref()
+                    .and_then(|a| a.crud)
+                {
+                    crate::runique_log!(level, resource = %entry.meta.key, id = %id, "edit POST — saved ok");
+                }
+                if password_changed && let Ok(user_id) = closure_id.parse::<crate::utils::pk::Pk>()
+                {
+                    crate::auth::epoch::bump_session_epoch(user_id);
+                }
+                if summary.is_some() {
+                    state
+                        .log_action(
+                            &req.engine.db,
+                            history::AdminActionLog {
+                                user_id: current_user.id,
+                                username: &current_user.username,
+                                resource_key: entry.meta.key,
+                                object_pk: &closure_id,
+                                action: "edit",
+                                summary,
+                                batch_id: None,
+                            },
+                        )
+                        .await;
+                }
+                log_inline_ops(state, &req.engine.db, current_user, inline.ops, &inline_ids).await;
+                req.notices
+                    .success(t("admin.edit.success").to_string())
+                    .await;
+                let list_url = match return_qs {
+                    Some(qs) => format!("{}/list?{}", base, qs),
+                    None => format!("{}/list", base),
+                };
+                return Ok(Redirect::to(&list_url).into_response());
             }
-            req.notices
-                .success(t("admin.edit.success").to_string())
-                .await;
-            let base = scope_base(&state.config.prefix, entry, parent);
-            let list_url = match return_qs {
-                Some(qs) => format!("{}/list?{}", base, qs),
-                None => format!("{}/list", base),
-            };
-            return Ok(Redirect::to(&list_url).into_response());
         }
     }
 
@@ -692,6 +845,15 @@ pub(super) async fn handle_delete_post(
         )))
     })?;
 
+    // Snapshot of the row for the history, read before it is gone.
+    let deleted = match &entry.get_fn {
+        Some(get_fn) if state.config.history => get_fn(req.engine.db.clone(), closure_id.clone())
+            .await
+            .ok()
+            .flatten(),
+        _ => None,
+    };
+
     let delete_result = delete_fn(req.engine.db.clone(), closure_id.clone()).await;
     if let Some(level) = crate::utils::runique_log::get_log()
         .admin
@@ -709,19 +871,20 @@ pub(super) async fn handle_delete_post(
     }
     delete_result.map_err(|e| Box::new(AppError::new(ErrorContext::database(e))))?;
 
-    history::log_admin_action(
-        &req.engine.db,
-        history::AdminActionLog {
-            user_id: current_user.id,
-            username: &current_user.username,
-            resource_key: entry.meta.key,
-            object_pk: &closure_id,
-            action: "delete",
-            summary: None,
-            batch_id: None,
-        },
-    )
-    .await;
+    state
+        .log_action(
+            &req.engine.db,
+            history::AdminActionLog {
+                user_id: current_user.id,
+                username: &current_user.username,
+                resource_key: entry.meta.key,
+                object_pk: &closure_id,
+                action: "delete",
+                summary: deleted.as_ref().and_then(history::deleted_fields),
+                batch_id: None,
+            },
+        )
+        .await;
 
     req.notices
         .success(t("admin.delete.success").to_string())
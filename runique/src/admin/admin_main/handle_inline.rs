@@ -88,6 +88,7 @@ pub(super) async fn build_inlines(
             }
         };
 
+        child.apply_computed_fields(&mut rows);
         crate::admin::helper::resolve_fk_labels(db.as_ref(), &mut rows, &child.meta.fk_display)
             .await;
         if let Some(apply_enum_labels) = child.enum_label_fn {
@@ -0,0 +1,593 @@
+//! Inline formsets of the create/edit form: the rows of each related resource
+//! declared with `AdminResource::inline`, edited on the parent's form and saved in
+//! the same transaction as the parent.
+//!
+//! Row `i` of an inline posts its fields as `{related}-{i}-{field}`, plus
+//! `{related}-{i}-id` (existing row) and `{related}-{i}-DELETE`; the management
+//! value `{related}-TOTAL_FORMS` gives the row count, like [`FormSet`].
+//!
+//! [`FormSet`]: crate::forms::FormSet
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use sea_orm::{DbErr, TransactionTrait};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::PrototypeAdminState;
+use super::ResourcePerms;
+use super::handle_crud::{pin_readonly_values, show_readonly_fields, value_to_strmap};
+use crate::admin::helper::dyn_form::DynForm;
+use crate::admin::helper::resource_entry::{
+    ListParams, ResourceEntry, SortDir, TxDeleteFn, TxSaveFn,
+};
+use crate::admin::history;
+use crate::admin::resource::InlineDef;
+use crate::auth::session::CurrentUser;
+use crate::forms::FORMSET_INDEX_PLACEHOLDER;
+use crate::forms::base::FormField;
+use crate::middleware::errors::error::html_escape;
+use crate::utils::aliases::{ADb, ATera, StrMap};
+use crate::utils::constante::session_key::session::CSRF_TOKEN_KEY;
+use crate::utils::trad::t;
+
+/// Hard cap on the rows read from a submission, whatever `TOTAL_FORMS` claims.
+const MAX_INLINE_ROWS: usize = 500;
+
+/// One inline of the create/edit form, rendered by `admin/inline_formset.html`.
+#[derive(Serialize)]
+pub(super) struct InlineFormset {
+    /// Child resource key — also the field prefix (`{key}-{i}-{field}`).
+    pub key: String,
+    pub title: String,
+    /// Labels of the visible columns, in field order.
+    pub headers: Vec<String>,
+    pub rows: Vec<InlineRow>,
+    /// Blank row indexed `__prefix__`, cloned client-side by "add another".
+    pub empty_row: InlineRow,
+    /// Value of `{key}-TOTAL_FORMS`.
+    pub total_forms: usize,
+    /// "Add another" is offered (the user may create children).
+    pub can_add: bool,
+    /// The DELETE column is shown (inline allows it and the user may delete).
+    pub can_delete: bool,
+    /// Inline-level errors (unknown row, missing right).
+    pub errors: Vec<String>,
+}
+
+/// A row of an [`InlineFormset`], fields already rendered.
+#[derive(Serialize)]
+pub(super) struct InlineRow {
+    /// Row index as posted (`"0"`, `"1"`, … or `__prefix__`).
+    pub index: String,
+    /// Stored ID of an existing row; `None` for a new one.
+    pub id: Option<String>,
+    /// Hidden inputs of the row (its `id`, hidden form fields).
+    pub hidden: String,
+    /// One rendered cell per header.
+    pub cells: Vec<String>,
+    /// DELETE box ticked on the submission being re-displayed.
+    pub delete: bool,
+}
+
+/// Write planned for a child row, run inside the parent's transaction.
+pub(super) enum InlineOp {
+    /// Creates (`id == None`) or updates a child; `fk_column` receives the parent ID.
+    Save {
+        resource_key: &'static str,
+        save: TxSaveFn,
+        fk_column: String,
+        id: Option<String>,
+        data: StrMap,
+        /// History summary of an update (`None` = nothing changed).
+        summary: Option<String>,
+    },
+    Delete {
+        resource_key: &'static str,
+        delete: TxDeleteFn,
+        id: String,
+    },
+}
+
+/// Inline formsets bound to a submission: what to re-display, and what to write.
+pub(super) struct InlineSubmission {
+    pub formsets: Vec<InlineFormset>,
+    pub ops: Vec<InlineOp>,
+    pub valid: bool,
+}
+
+/// Request-level values every row form is built with.
+pub(super) struct InlineCtx<'a> {
+    pub db: ADb,
+    pub tera: ATera,
+    pub csrf: String,
+    pub state: &'a PrototypeAdminState,
+    pub current_user: &'a CurrentUser,
+}
+
+/// An inline resolved against the registry and the user's rights.
+struct ResolvedInline<'a> {
+    def: &'a InlineDef,
+    child: &'a ResourceEntry,
+    fk_column: &'a str,
+    save: TxSaveFn,
+    delete: Option<TxDeleteFn>,
+    perms: ResourcePerms,
+}
+
+/// Keeps the inlines that can be shown: FK column known, child registered with its
+/// transactional closures, parent able to save in a transaction, child readable.
+fn resolve<'a>(ctx: &InlineCtx<'a>, parent: &'a ResourceEntry) -> Vec<ResolvedInline<'a>> {
+    if parent.tx_save_fn.is_none() {
+        return Vec::new();
+    }
+    parent
+        .meta
+        .inlines
+        .iter()
+        .filter_map(|def| {
+            let fk_column = def.fk_column.as_deref()?;
+            let child = ctx.state.registry.get(def.related)?;
+            let save = child.tx_save_fn.clone()?;
+            let perms = ResourcePerms::resolve(ctx.current_user, child.meta.key);
+            perms.can_read.then(|| ResolvedInline {
+                def,
+                child,
+                fk_column,
+                save,
+                delete: child.tx_delete_fn.clone(),
+                perms,
+            })
+        })
+        .collect()
+}
+
+impl ResolvedInline<'_> {
+    fn can_delete(&self) -> bool {
+        self.def.can_delete && self.perms.can_delete && self.delete.is_some()
+    }
+
+    /// Stored children of `parent_id`, by ID (none on create).
+    async fn existing_rows(&self, db: &ADb, parent_id: Option<&str>) -> Vec<(String, Value)> {
+        let (Some(parent_id), Some(list_fn)) = (parent_id, &self.child.list_fn) else {
+            return Vec::new();
+        };
+        let params = ListParams {
+            offset: 0,
+            limit: MAX_INLINE_ROWS as u64,
+            sort_by: None,
+            sort_dir: SortDir::Asc,
+            search: None,
+            column_filters: Vec::new(),
+            scope: Some((self.fk_column.to_string(), parent_id.to_string())),
+        };
+        match list_fn(db.clone(), params).await {
+            Ok(rows) => rows
+                .into_iter()
+                .filter_map(|row| {
+                    let id = match row.get("id")? {
+                        Value::String(s) => s.clone(),
+                        Value::Number(n) => n.to_string(),
+                        _ => return None,
+                    };
+                    Some((id, row))
+                })
+                .collect(),
+            Err(e) => {
+                if let Some(level) = crate::utils::runique_log::get_log()
+                    .admin
+                    .as_ref()
+                    .and_then(|a| a.crud)
+                {
+                    crate::runique_log!(
+                        level,
+                        resource = self.child.meta.key,
+                        error = %e,
+                        "inline list_fn failed — rows omitted"
+                    );
+                }
+                Vec::new()
+            }
+        }
+    }
+
+    /// Child form filled from `data`, without CSRF nor FK field (the parent sets it).
+    async fn build_form(
+        &self,
+        ctx: &InlineCtx<'_>,
+        data: StrMap,
+        existing: bool,
+        method: axum::http::Method,
+    ) -> Box<dyn DynForm> {
+        let builder = match &self.child.edit_form_builder {
+            Some(edit) if existing => edit,
+            _ => &self.child.form_builder,
+        };
+        let resource_keys = ctx
+            .state
+            .registry
+            .all()
+            .map(|e| e.meta.key.to_string())
+            .collect::<Vec<_>>();
+        let mut form = (builder)(
+            ctx.db.clone(),
+            resource_keys,
+            data,
+            ctx.tera.clone(),
+            ctx.csrf.clone(),
+            method,
+        )
+        .await;
+        let fields = &mut form.get_form_mut().fields;
+        fields.shift_remove(CSRF_TOKEN_KEY);
+        fields.shift_remove(self.fk_column);
+        fields.shift_remove("id");
+        form
+    }
+
+    fn headers(&self, form: &dyn DynForm) -> Vec<String> {
+        form.get_form()
+            .fields
+            .values()
+            .filter(|f| f.field_type() != "hidden")
+            .map(|f| f.label().to_string())
+            .collect()
+    }
+
+    /// Renders `form` as row `index`, its fields named `{key}-{index}-{field}`.
+    fn render_row(
+        &self,
+        tera: &ATera,
+        mut form: Box<dyn DynForm>,
+        index: &str,
+        id: Option<String>,
+        delete: bool,
+    ) -> InlineRow {
+        let prefix = format!("{}-{}", self.child.meta.key, index);
+        form.get_form_mut().set_prefix(&prefix);
+        let render = |f: &dyn FormField| {
+            f.render(tera).unwrap_or_else(|e| {
+                format!("<p style='color:red'>Render error: {}</p>", html_escape(&e))
+            })
+        };
+        let mut hidden = String::new();
+        if let Some(id) = &id {
+            hidden.push_str(&format!(
+                r#"<input type="hidden" name="{}-id" value="{}">"#,
+                prefix,
+                html_escape(id)
+            ));
+        }
+        let mut cells = Vec::new();
+        for field in form.get_form().fields.values() {
+            if field.field_type() == "hidden" {
+                hidden.push_str(&render(field.as_ref()));
+            } else {
+                cells.push(render(field.as_ref()));
+            }
+        }
+        InlineRow {
+            index: index.to_string(),
+            id,
+            hidden,
+            cells,
+            delete,
+        }
+    }
+
+    fn formset(
+        &self,
+        headers: Vec<String>,
+        rows: Vec<InlineRow>,
+        empty_row: InlineRow,
+        errors: Vec<String>,
+    ) -> InlineFormset {
+        InlineFormset {
+            key: self.child.meta.key.to_string(),
+            title: self.child.meta.title.to_string(),
+            headers,
+            total_forms: rows.len(),
+            rows,
+            empty_row,
+            can_add: self.perms.can_create,
+            can_delete: self.can_delete(),
+            errors,
+        }
+    }
+
+    /// Blank row template, and the headers taken from it.
+    async fn empty_row(&self, ctx: &InlineCtx<'_>) -> (Vec<String>, InlineRow) {
+        let form = self
+            .build_form(ctx, StrMap::new(), false, axum::http::Method::GET)
+            .await;
+        let headers = self.headers(form.as_ref());
+        let row = self.render_row(&ctx.tera, form, FORMSET_INDEX_PLACEHOLDER, None, false);
+        (headers, row)
+    }
+}
+
+/// Removes the inline values (`{related}-…`) from a parent submission, so they
+/// never reach the parent's form, save or history diff.
+pub(super) fn take_inline_data(parent: &ResourceEntry, body: &mut StrMap) -> StrMap {
+    let prefixes: Vec<String> = parent
+        .meta
+        .inlines
+        .iter()
+        .map(|def| format!("{}-", def.related))
+        .collect();
+    let keys: Vec<String> = body
+        .keys()
+        .filter(|k| prefixes.iter().any(|p| k.starts_with(p.as_str())))
+        .cloned()
+        .collect();
+    keys.into_iter()
+        .filter_map(|k| body.remove(&k).map(|v| (k, v)))
+        .collect()
+}
+
+/// Inline formsets of a first display: the stored children of `parent_id` (edit)
+/// followed by `extra` blank rows when the user may create children.
+pub(super) async fn build_inline_formsets(
+    ctx: &InlineCtx<'_>,
+    parent: &ResourceEntry,
+    parent_id: Option<&str>,
+) -> Vec<InlineFormset> {
+    let mut formsets = Vec::new();
+    for inline in resolve(ctx, parent) {
+        let (headers, empty_row) = inline.empty_row(ctx).await;
+        let mut rows = Vec::new();
+        for (id, stored) in inline.existing_rows(&ctx.db, parent_id).await {
+            let form = inline
+                .build_form(
+                    ctx,
+                    value_to_strmap(stored.clone()),
+                    true,
+                    axum::http::Method::GET,
+                )
+                .await;
+            let mut form = form;
+            show_readonly_fields(
+                &mut form,
+                &inline.child.meta.display.readonly_fields,
+                &value_to_strmap(stored),
+            );
+            let index = rows.len().to_string();
+            rows.push(inline.render_row(&ctx.tera, form, &index, Some(id), false));
+        }
+        if inline.perms.can_create {
+            for _ in 0..inline.def.extra {
+                let form = inline
+                    .build_form(ctx, StrMap::new(), false, axum::http::Method::GET)
+                    .await;
+                let index = rows.len().to_string();
+                rows.push(inline.render_row(&ctx.tera, form, &index, None, false));
+            }
+        }
+        formsets.push(inline.formset(headers, rows, empty_row, Vec::new()));
+    }
+    formsets
+}
+
+/// Binds the posted rows of every inline: validates them against the child forms
+/// and the user's rights, and plans their writes. A row ID that is not a stored
+/// child of `parent_id` is rejected, so a submission can only touch its own rows.
+pub(super) async fn bind_inline_formsets(
+    ctx: &InlineCtx<'_>,
+    parent: &ResourceEntry,
+    parent_id: Option<&str>,
+    data: &StrMap,
+) -> InlineSubmission {
+    let mut submission = InlineSubmission {
+        formsets: Vec::new(),
+        ops: Vec::new(),
+        valid: true,
+    };
+    for inline in resolve(ctx, parent) {
+        let key = inline.child.meta.key;
+        let (headers, empty_row) = inline.empty_row(ctx).await;
+        // Not rendered on the submitted page (no management value): nothing to bind.
+        let Some(total) = data
+            .get(&format!("{}-TOTAL_FORMS", key))
+            .and_then(|v| v.trim().parse::<usize>().ok())
+        else {
+            submission
+                .formsets
+                .push(inline.formset(headers, Vec::new(), empty_row, Vec::new()));
+            continue;
+        };
+        let existing: HashMap<String, Value> = inline
+            .existing_rows(&ctx.db, parent_id)
+            .await
+            .into_iter()
+            .collect();
+        let readonly = &inline.child.meta.display.readonly_fields;
+        let mut rows = Vec::new();
+        let mut errors = Vec::new();
+
+        for i in 0..total.min(MAX_INLINE_ROWS) {
+            let row_prefix = format!("{}-{}-", key, i);
+            let mut row: StrMap = data
+                .iter()
+                .filter_map(|(k, v)| {
+                    k.strip_prefix(&row_prefix)
+                        .map(|field| (field.to_string(), v.clone()))
+                })
+                .collect();
+            let id = row.remove("id").filter(|id| !id.trim().is_empty());
+            let delete = row
+                .remove("DELETE")
+                .is_some_and(|v| matches!(v.as_str(), "on" | "true" | "1"));
+
+            let stored = match &id {
+                Some(id) => match existing.get(id) {
+                    Some(stored) => Some(stored),
+                    None => {
+                        errors.push(t("admin.inline.unknown_row").into_owned());
+                        submission.valid = false;
+                        continue;
+                    }
+                },
+                None => None,
+            };
+
+            if delete {
+                let Some(id) = id else {
+                    continue;
+                };
+                if inline.can_delete()
+                    && let Some(delete) = &inline.delete
+                {
+                    submission.ops.push(InlineOp::Delete {
+                        resource_key: key,
+                        delete: delete.clone(),
+                        id: id.clone(),
+                    });
+                } else {
+                    errors.push(t("admin.inline.forbidden").into_owned());
+                    submission.valid = false;
+                }
+                let form = inline
+                    .build_form(ctx, row, true, axum::http::Method::GET)
+                    .await;
+                let index = rows.len().to_string();
+                rows.push(inline.render_row(&ctx.tera, form, &index, Some(id), true));
+                continue;
+            }
+
+            let has_data = row.values().any(|v| !v.trim().is_empty());
+            // Extra row left blank: no child to create.
+            if id.is_none() && !has_data {
+                continue;
+            }
+            let allowed = match id {
+                Some(_) => inline.perms.can_update,
+                None => inline.perms.can_create,
+            };
+            if !allowed {
+                errors.push(t("admin.inline.forbidden").into_owned());
+                submission.valid = false;
+                continue;
+            }
+
+            let stored_map = stored.cloned().map(value_to_strmap).unwrap_or_default();
+            if stored.is_some() && !readonly.is_empty() {
+                pin_readonly_values(&mut row, readonly, &stored_map);
+            }
+            let method = if stored.is_some() {
+                axum::http::Method::PATCH
+            } else {
+                axum::http::Method::POST
+            };
+            let mut form = inline
+                .build_form(ctx, row.clone(), stored.is_some(), method)
+                .await;
+            if stored.is_some() {
+                show_readonly_fields(&mut form, readonly, &stored_map);
+            }
+            if form.is_valid().await {
+                let mut data = row;
+                // Finalized values (e.g. moved upload paths) win over the raw post.
+                for (name, field) in &form.get_form().fields {
+                    data.insert(name.clone(), field.value().to_string());
+                }
+                let summary = stored.and_then(|old| history::diff_fields(old, &data));
+                if id.is_none() || summary.is_some() {
+                    submission.ops.push(InlineOp::Save {
+                        resource_key: key,
+                        save: inline.save.clone(),
+                        fk_column: inline.fk_column.to_string(),
+                        id: id.clone(),
+                        data,
+                        summary,
+                    });
+                }
+            } else {
+                submission.valid = false;
+            }
+            let index = rows.len().to_string();
+            rows.push(inline.render_row(&ctx.tera, form, &index, id, false));
+        }
+
+        submission
+            .formsets
+            .push(inline.formset(headers, rows, empty_row, errors));
+    }
+    submission
+}
+
+/// Saves the parent through `parent_save` then runs `ops` with the parent ID as
+/// FK, all in one transaction: any failure rolls the whole submission back.
+/// Returns the parent ID and the ID written by each op, in order.
+pub(super) async fn save_with_inlines(
+    db: &ADb,
+    parent_save: &TxSaveFn,
+    parent_id: Option<String>,
+    parent_data: StrMap,
+    ops: &[InlineOp],
+) -> Result<(String, Vec<String>), DbErr> {
+    let txn = Arc::new(db.begin().await?);
+    let saved_id = parent_save(txn.clone(), parent_id, parent_data).await?;
+    let mut ids = Vec::with_capacity(ops.len());
+    for op in ops {
+        match op {
+            InlineOp::Save {
+                save,
+                fk_column,
+                id,
+                data,
+                ..
+            } => {
+                let mut data = data.clone();
+                data.insert(fk_column.clone(), saved_id.clone());
+                ids.push(save(txn.clone(), id.clone(), data).await?);
+            }
+            InlineOp::Delete { delete, id, .. } => {
+                delete(txn.clone(), id.clone()).await?;
+                ids.push(id.clone());
+            }
+        }
+    }
+    let txn = Arc::try_unwrap(txn)
+        .map_err(|_| DbErr::Custom("inline transaction still in use".to_string()))?;
+    txn.commit().await?;
+    Ok((saved_id, ids))
+}
+
+/// Records the committed child writes in the admin history.
+pub(super) async fn log_inline_ops(
+    state: &super::PrototypeAdminState,
+    db: &ADb,
+    current_user: &CurrentUser,
+    ops: Vec<InlineOp>,
+    ids: &[String],
+) {
+    for (op, id) in ops.into_iter().zip(ids) {
+        let (resource_key, action, summary) = match op {
+            InlineOp::Save {
+                resource_key,
+                id: None,
+                ..
+            } => (resource_key, "create", None),
+            InlineOp::Save {
+                resource_key,
+                summary,
+                ..
+            } => (resource_key, "edit", summary),
+            InlineOp::Delete { resource_key, .. } => (resource_key, "delete", None),
+        };
+        state
+            .log_action(
+                db,
+                history::AdminActionLog {
+                    user_id: current_user.id,
+                    username: &current_user.username,
+                    resource_key,
+                    object_pk: id,
+                    action,
+                    summary,
+                    batch_id: None,
+                },
+            )
+            .await;
+    }
+}
@@ -1,14 +1,17 @@
 use super::format_datetime;
 use crate::admin::{
-    helper::resource_entry::{ListParams, ResourceEntry, SortDir},
+    helper::{
+        list_filter::{FilterKind, filter_kind},
+        resource_entry::{ListParams, ResourceEntry, SortDir},
+    },
     resource::ColumnFilter,
 };
 use crate::auth::session::CurrentUser;
 use crate::context::template::{AppError, Request};
 use crate::errors::error::ErrorContext;
 use crate::utils::{
-    aliases::AppResult,
-    constante::admin_context::list as list_ctx,
+    aliases::{AppResult, StrMap},
+    constante::admin_context::list::{self as list_ctx, PAGE, SORT_BY, SORT_DIR},
     trad::{current_lang, t},
 };
 use axum::response::Response;
@@ -25,6 +28,49 @@ pub(super) struct ListQuery {
     pub scope: Option<(String, String)>,
 }
 
+impl ListQuery {
+    /// List state from the query string: `page`, `sort_by`, `sort_dir`, `search`,
+    /// `filter_{col}`, `fp_{col}` — shared by the list view and the CSV export.
+    pub(super) fn from_params(params: &StrMap, parent: Option<&super::ParentBinding>) -> Self {
+        let page = params
+            .get(PAGE)
+            .and_then(|p| p.parse::<u64>().ok())
+            .unwrap_or(1)
+            .max(1);
+        let sort_by = params.get(SORT_BY).filter(|s| !s.is_empty()).cloned();
+        let sort_dir = match params.get(SORT_DIR).map(String::as_str) {
+            Some("desc") => SortDir::Desc,
+            _ => SortDir::Asc,
+        };
+        let search = params.get("search").filter(|s| !s.is_empty()).cloned();
+        let column_filters: Vec<(String, String)> = params
+            .iter()
+            .filter_map(|(k, v)| {
+                k.strip_prefix("filter_")
+                    .filter(|_| !v.is_empty())
+                    .map(|col| (col.to_string(), v.clone()))
+            })
+            .collect();
+        let filter_pages: HashMap<String, u64> = params
+            .iter()
+            .filter_map(|(k, v)| {
+                let col = k.strip_prefix("fp_")?;
+                let page = v.parse::<u64>().ok()?;
+                Some((col.to_string(), page))
+            })
+            .collect();
+        Self {
+            page,
+            sort_by,
+            sort_dir,
+            search,
+            column_filters,
+            filter_pages,
+            scope: parent.map(|p| (p.fk_col.to_string(), p.parent_id.clone())),
+        }
+    }
+}
+
 pub(super) async fn handle_list(
     req: &mut Request,
     entry: &ResourceEntry,
@@ -58,18 +104,24 @@ pub(super) async fn handle_list(
         column_filters: column_filters.clone(),
         scope: scope.clone(),
     };
+    // Active filters → filtered count, so the total and page count follow the list.
+    let filtered_count = entry
+        .filtered_count_fn
+        .as_ref()
+        .filter(|_| !column_filters.is_empty());
 
     let (entries_result, count_result, filter_result) = tokio::join!(
         async {
             match &entry.list_fn {
-                Some(f) => f(req.engine.db.clone(), list_params).await,
+                Some(f) => f(req.engine.db.clone(), list_params.clone()).await,
                 None => Ok(Vec::new()),
             }
         },
         async {
-            match &entry.count_fn {
-                Some(f) => f(req.engine.db.clone(), search.clone(), scope.clone()).await,
-                None => Ok(0u64),
+            match (filtered_count, &entry.count_fn) {
+                (Some(f), _) => f(req.engine.db.clone(), list_params.clone()).await,
+                (None, Some(f)) => f(req.engine.db.clone(), search.clone(), scope.clone()).await,
+                (None, None) => Ok(0u64),
             }
         },
         async {
@@ -88,6 +140,7 @@ pub(super) async fn handle_list(
     );
     let mut entries =
         entries_result.map_err(|e| Box::new(AppError::new(ErrorContext::database(e))))?;
+    entry.apply_computed_fields(&mut entries);
     crate::admin::helper::resolve_fk_labels(
         req.engine.db.as_ref(),
         &mut entries,
@@ -131,6 +184,10 @@ pub(super) async fn handle_list(
         .iter()
         .map(|(k, (vals, _))| (k.clone(), vals.clone()))
         .collect();
+    let filter_kinds: HashMap<String, FilterKind> = filter_values
+        .iter()
+        .map(|(col, vals)| (col.clone(), filter_kind(vals)))
+        .collect();
     let filter_totals: HashMap<String, u64> = filter_result
         .into_iter()
         .map(|(k, (_, total))| (k, total))
@@ -146,6 +203,11 @@ pub(super) async fn handle_list(
     let page = page.min(page_count.max(1));
 
     let (visible_columns, column_labels) = resolve_columns(entry, &entries);
+    let computed_columns: Vec<&str> = entry
+        .computed_fields
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect();
 
     let safe_sort_by = sort_by
         .filter(|s| s == "id" || visible_columns.contains(s))
@@ -276,11 +338,13 @@ pub(super) async fn handle_list(
         "current_page"              => "list",
         list_ctx::VISIBLE_COLUMNS   => visible_columns,
         list_ctx::COLUMN_LABELS     => column_labels,
+        list_ctx::COMPUTED_COLUMNS  => computed_columns,
         list_ctx::SORT_BY           => safe_sort_by,
         list_ctx::SORT_DIR          => sort_dir.as_str(),
         list_ctx::SORT_DIR_TOGGLE   => sort_dir.toggle(),
         list_ctx::SEARCH            => search.unwrap_or_default(),
         list_ctx::FILTER_VALUES     => filter_values,
+        list_ctx::FILTER_KINDS      => filter_kinds,
         list_ctx::ACTIVE_FILTERS    => active_filters,
         list_ctx::FILTER_QS         => filter_qs,
         list_ctx::FILTER_META       => filter_meta,
@@ -303,6 +367,7 @@ pub(super) async fn handle_list(
 
 /// Resolves the visible columns and their labels for a set of rows, honoring the
 /// resource's `ColumnFilter` and falling back to the `permission.col.*` i18n keys.
+/// Computed columns not listed by `ColumnFilter::Include` come last.
 /// Shared by the list view and the parent-detail inline sub-lists so both render
 /// identical columns.
 pub(super) fn resolve_columns(
@@ -344,6 +409,20 @@ pub(super) fn resolve_columns(
             ),
         };
 
+    let mut visible_columns = visible_columns;
+    for computed in &entry.computed_fields {
+        let excluded = matches!(&entry.meta.display.columns, ColumnFilter::Exclude(cols) if cols.contains(&computed.name));
+        let present = entries
+            .first()
+            .is_some_and(|row| row.get(&computed.name).is_some());
+        if present && !excluded && !visible_columns.contains(&computed.name) {
+            visible_columns.push(computed.name.clone());
+        }
+        column_labels
+            .entry(computed.name.clone())
+            .or_insert_with(|| computed.label.clone());
+    }
+
     for col in &visible_columns {
         if !column_labels.contains_key(col) {
             let key = format!("permission.col.{col}");
@@ -58,7 +58,7 @@ pub(super) async fn send_user_created_email(
         t("admin.user_created.email_validity").as_ref(),
     );
 
-    let body_html = match req.engine.tera.render(template_name, &ctx) {
+    let body_html = match req.engine.tera.current().render(template_name, &ctx) {
         Ok(rendered) => rendered,
         Err(_) => format!(
             "<p>Hello {username_str},</p><p>Click on the link to set your password:</p><p><a href=\"{reset_url}\">{reset_url}</a></p>"
@@ -193,7 +193,7 @@ pub(super) async fn handle_reset_password(
         ctx.insert("t_body", t("admin.reset_password.email_body").as_ref());
         ctx.insert("t_btn", t("admin.reset_password.btn").as_ref());
         ctx.insert("t_ignore", t("admin.reset_password.email_ignore").as_ref());
-        let body = match req.engine.tera.render(template_name, &ctx) {
+        let body = match req.engine.tera.current().render(template_name, &ctx) {
             Ok(rendered) => rendered,
             Err(_) => format!(
                 "<p>Hello {username},</p><p>Click on the following link to reset your password (valid for 1 hour):</p><p><a href=\"{reset_url}\">{reset_url}</a></p>"
@@ -5,12 +5,21 @@
 //! - `GET/POST /admin/{resource}/{id}/{action}` → [`admin_get_id`] / [`admin_post_id`]
 
 mod action;
+mod handle_autocomplete;
 mod handle_bulk;
 mod handle_crud;
+mod handle_export;
 mod handle_inline;
+mod handle_inline_formset;
 mod handle_list;
 mod handle_password;
 
+use crate::admin::{
+    AdminRegistry,
+    config::AdminConfig,
+    helper::resource_entry::ResourceEntry,
+    trad::{inject_admin_prefix, insert_admin_messages},
+};
 use crate::auth::session::CurrentUser;
 use crate::context::template::{AppError, Request};
 use crate::errors::error::ErrorContext;
@@ -21,22 +30,13 @@ use crate::utils::{
     session_key::session::CSRF_TOKEN_KEY,
     trad::{current_lang, t},
 };
-use crate::{
-    admin::{
-        AdminRegistry,
-        config::AdminConfig,
-        helper::resource_entry::{ResourceEntry, SortDir},
-        trad::{inject_admin_prefix, insert_admin_messages},
-    },
-    utils::admin_context::list::{PAGE, SORT_BY, SORT_DIR},
-};
 use axum::{
     Extension,
     extract::{Path, Query},
     http::StatusCode,
     response::{IntoResponse, Redirect, Response},
 };
-use std::{collections::HashMap, sync::Arc};
+use std::sync::Arc;
 use subtle::ConstantTimeEq;
 
 use self::action::{Access, CollectionAction, MemberAction};
@@ -84,6 +84,20 @@ pub struct PrototypeAdminState {
     pub config: Arc<AdminConfig>,
 }
 
+impl PrototypeAdminState {
+    /// Records an admin action in the history — skipped when
+    /// [`AdminConfig::history`] is off.
+    pub(super) async fn log_action(
+        &self,
+        db: &crate::utils::aliases::ADb,
+        log: crate::admin::history::AdminActionLog<'_>,
+    ) {
+        if self.config.history {
+            crate::admin::history::log_admin_action(db, log).await;
+        }
+    }
+}
+
 // ─── Route target ────────────────────────────────────────────
 
 /// The routing identity of a request, built by the thin flat/nested wrappers and
@@ -362,51 +376,28 @@ async fn dispatch_collection_get(
             "collection GET access check"
         );
     }
+    // A JSON fetch can't follow the login / list redirect.
+    if matches!(act, CollectionAction::Autocomplete) && !matches!(access, Access::Granted) {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
     let base = scope_base(&state.config.prefix, entry, parent.as_ref());
     if let Some(resp) = enforce(access, &req.notices, &state.config.prefix, &base).await {
         return Ok(resp);
     }
 
     match act {
+        CollectionAction::Autocomplete => {
+            handle_autocomplete::handle_autocomplete(
+                req.engine.db.clone(),
+                &state,
+                entry,
+                &current_user,
+                &params,
+            )
+            .await
+        }
         CollectionAction::List => {
-            let page = params
-                .get(PAGE)
-                .and_then(|p| p.parse::<u64>().ok())
-                .unwrap_or(1)
-                .max(1);
-            let sort_by = params.get(SORT_BY).filter(|s| !s.is_empty()).cloned();
-            let sort_dir = match params.get(SORT_DIR).map(String::as_str) {
-                Some("desc") => SortDir::Desc,
-                _ => SortDir::Asc,
-            };
-            let search = params.get("search").filter(|s| !s.is_empty()).cloned();
-            let column_filters: Vec<(String, String)> = params
-                .iter()
-                .filter_map(|(k, v)| {
-                    k.strip_prefix("filter_")
-                        .filter(|_| !v.is_empty())
-                        .map(|col| (col.to_string(), v.clone()))
-                })
-                .collect();
-            let filter_pages: HashMap<String, u64> = params
-                .iter()
-                .filter_map(|(k, v)| {
-                    let col = k.strip_prefix("fp_")?;
-                    let page = v.parse::<u64>().ok()?;
-                    Some((col.to_string(), page))
-                })
-                .collect();
-            let query = ListQuery {
-                page,
-                sort_by,
-                sort_dir,
-                search,
-                column_filters,
-                filter_pages,
-                scope: parent
-                    .as_ref()
-                    .map(|p| (p.fk_col.to_string(), p.parent_id.clone())),
-            };
+            let query = ListQuery::from_params(&params, parent.as_ref());
             let is_htmx = headers.contains_key("hx-request");
             if let Some(level) = crate::utils::runique_log::get_log()
                 .admin
@@ -434,6 +425,23 @@ async fn dispatch_collection_get(
             )
             .await
         }
+        CollectionAction::Export => {
+            let query = ListQuery::from_params(&params, parent.as_ref());
+            if let Some(level) = crate::utils::runique_log::get_log()
+                .admin
+                .as_ref()
+                .and_then(|a| a.list)
+            {
+                crate::runique_log!(
+                    level,
+                    resource = %resource_key,
+                    search = ?query.search,
+                    filters = query.column_filters.len(),
+                    "csv export"
+                );
+            }
+            handle_export::handle_export(req.engine.db.clone(), entry, &params, query).await
+        }
         CollectionAction::Create => {
             if let Some(level) = crate::utils::runique_log::get_log()
                 .admin
@@ -442,7 +450,7 @@ async fn dispatch_collection_get(
             {
                 crate::runique_log!(level, resource = %resource_key, action = "create GET", "crud");
             }
-            handle_create_get(&mut req, entry, &state, parent.as_ref()).await
+            handle_create_get(&mut req, entry, &state, &current_user, parent.as_ref()).await
         }
         CollectionAction::Bulk => {
             if let Some(level) = crate::utils::runique_log::get_log()
@@ -583,10 +591,10 @@ async fn dispatch_collection_post(
             )
             .await
         }
-        // `list` is rejected by `parse_post`; unreachable.
-        CollectionAction::List => Err(Box::new(AppError::new(ErrorContext::not_found(
-            "Unknown action",
-        )))),
+        // GET-only actions are rejected by `parse_post`; unreachable.
+        CollectionAction::List | CollectionAction::Export | CollectionAction::Autocomplete => Err(
+            Box::new(AppError::new(ErrorContext::not_found("Unknown action"))),
+        ),
     }
 }
 
@@ -709,7 +717,7 @@ async fn dispatch_member_get(
             {
                 crate::runique_log!(level, resource = %resource_key, id = %id, action = "edit GET", "crud");
             }
-            handle_edit_get(&mut req, entry, id, &state, parent.as_ref()).await
+            handle_edit_get(&mut req, entry, id, &state, &current_user, parent.as_ref()).await
         }
         MemberAction::Delete => {
             if let Some(level) = crate::utils::runique_log::get_log()
@@ -905,6 +913,8 @@ pub(super) fn inject_context(
     req.context.insert(ctx_common::RESOURCE, &entry.meta);
     req.context
         .insert(ctx_list::GROUP_ACTIONS, &entry.group_actions);
+    req.context
+        .insert(ctx_list::BULK_ACTIONS, &entry.bulk_actions);
 
     // Single source of truth for action URLs: templates build every link from
     // `resource_base`, which is scope-aware (flat or nested). See Bloc 6.
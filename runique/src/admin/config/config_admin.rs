@@ -1,7 +1,7 @@
 //! Admin panel configuration: prefix, title, hot reload, auth, and templates.
 use std::sync::Arc;
 
-use crate::admin::helper::AdminTemplate;
+use crate::admin::helper::{AdminTemplate, BulkAction, ComputedField};
 use crate::auth::{guard::LoginGuard, session::AdminAuth};
 use crate::middleware::security::RateLimiter;
 use crate::utils::env::is_debug;
@@ -61,6 +61,15 @@ pub struct AdminConfig {
 
     /// Per-account brute-force protection on admin login (optional).
     pub login_guard: Option<Arc<LoginGuard>>,
+
+    /// Custom bulk actions: (resource key, action), added to the registry at boot.
+    pub bulk_actions: Vec<(String, BulkAction)>,
+
+    /// Computed columns: (resource key, field), added to the registry at boot.
+    pub computed_fields: Vec<(String, ComputedField)>,
+
+    /// Records every create / edit / delete in `eihwaz_history` (default: true)
+    pub history: bool,
 }
 
 impl Clone for AdminConfig {
@@ -80,6 +89,9 @@ impl Clone for AdminConfig {
             resource_order: self.resource_order.clone(),
             rate_limiter: self.rate_limiter.clone(),
             login_guard: self.login_guard.clone(),
+            bulk_actions: self.bulk_actions.clone(),
+            computed_fields: self.computed_fields.clone(),
+            history: self.history,
         }
     }
 }
@@ -94,6 +106,7 @@ impl std::fmt::Debug for AdminConfig {
             .field("enabled", &self.enabled)
             .field("auth", &self.auth.as_ref().map(|_| "<AdminAuth>"))
             .field("templates", &self.templates)
+            .field("history", &self.history)
             .finish()
     }
 }
@@ -115,6 +128,9 @@ impl AdminConfig {
             resource_order: Vec::new(),
             rate_limiter: None,
             login_guard: None,
+            bulk_actions: Vec::new(),
+            computed_fields: Vec::new(),
+            history: true,
         }
     }
 
@@ -245,6 +261,32 @@ impl AdminConfig {
         self.resource_order = order.into_iter().map(Into::into).collect();
         self
     }
+
+    /// Adds a custom bulk action to the resource `resource_key` — offered in the
+    /// list view dropdown, next to "delete selected".
+    pub fn bulk_action(mut self, resource_key: &str, action: BulkAction) -> Self {
+        self.bulk_actions.push((resource_key.to_string(), action));
+        self
+    }
+
+    /// Adds a computed column to the resource `resource_key` — shown in the list and
+    /// detail views.
+    pub fn computed_field(mut self, resource_key: &str, field: ComputedField) -> Self {
+        self.computed_fields.push((resource_key.to_string(), field));
+        self
+    }
+
+    /// Turns the audit history on or off. Off, admin writes skip the
+    /// `eihwaz_history` insert (and the reads that build its diffs); the history
+    /// views still list earlier entries.
+    ///
+    /// ```rust,ignore
+    /// AdminConfig::new().history(false)
+    /// ```
+    pub fn history(mut self, enabled: bool) -> Self {
+        self.history = enabled;
+        self
+    }
 }
 
 impl Default for AdminConfig {
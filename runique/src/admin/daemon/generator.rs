@@ -129,7 +129,7 @@ fn write_admin_register(out: &mut String, parsed: &ParsedAdmin) -> Result<(), St
     let _ = writeln!(out);
 
     for r in resources {
-        write_resource_entry(out, r)?;
+        write_resource_entry(out, r, resources)?;
     }
 
     // Applies configure{}: overrides the DisplayConfig and group_actions of any resource
@@ -151,7 +151,8 @@ fn write_admin_register(out: &mut String, parsed: &ParsedAdmin) -> Result<(), St
                 .iter()
                 .map(|(c, l)| (c.clone(), l.clone(), None))
                 .collect();
-            let chain = build_display_chain(&display_3, &cfg.list_exclude, &cfg.list_filter);
+            let chain =
+                build_display_chain(&display_3, &cfg.list_exclude, &cfg.list_filter, &[], &[]);
             let _ = writeln!(out, "    registry.configure(\"{}\", {});", cfg.key, chain);
         }
         if !cfg.group_action.is_empty() {
@@ -225,7 +226,11 @@ fn write_admin_register(out: &mut String, parsed: &ParsedAdmin) -> Result<(), St
     Ok(())
 }
 
-fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String> {
+fn write_resource_entry(
+    out: &mut String,
+    r: &ResourceDef,
+    resources: &[ResourceDef],
+) -> Result<(), String> {
     let key = &r.key;
     let model = full_model_path(&r.model_type);
     let title = &r.title;
@@ -290,11 +295,39 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
         let _ = writeln!(out, "    let meta = meta.extra(\"{}\", \"{}\");", k, v);
     }
 
+    // Inlines — FK column detected from the model schemas when not declared
+    for inline in &r.inlines {
+        let _ = writeln!(
+            out,
+            "    let meta = meta.inline_def({});",
+            build_inline_def(r, inline, resources)?
+        );
+    }
+
+    // Autocomplete FK boxes — the related resource serves the search
+    if let Some(ref col) = r.label_field {
+        let _ = writeln!(out, "    let meta = meta.label_field(\"{}\");", col);
+    }
+    for (col, related) in &r.autocomplete_fields {
+        if !resources.iter().any(|c| &c.key == related) {
+            return Err(format!(
+                "Autocomplete \"{}\" of \"{}\": no resource \"{}\" declared in admin!{{}}",
+                col, r.key, related
+            ));
+        }
+        let _ = writeln!(
+            out,
+            "    let meta = meta.autocomplete(\"{}\", \"{}\");",
+            col, related
+        );
+    }
+
     // FormBuilder closure — injecte les ChoiceFields FK si déclarés dans list_display
+    // (sauf les colonnes en autocomplete : pas de <select> à remplir)
     let fk_cols_for_form: Vec<_> = r
         .list_display
         .iter()
-        .filter(|(_, _, fk)| fk.is_some())
+        .filter(|(col, _, fk)| fk.is_some() && !r.autocomplete_fields.iter().any(|(c, _)| c == col))
         .collect();
     write_form_builder_closure_fk(out, "form_builder", &form_path, &wrapper, &fk_cols_for_form);
 
@@ -360,17 +393,8 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
     let _ = writeln!(out, "            }}");
     let _ = writeln!(
         out,
-        "            for (col, val) in &params.column_filters {{"
-    );
-    let _ = writeln!(
-        out,
-        "                if !FILTER_COLS.contains(&col.as_str()) {{ continue; }}"
-    );
-    let _ = writeln!(
-        out,
-        "                query = query.filter(Expr::col(Alias::new(col.as_str())).cast_as(Alias::new(\"TEXT\")).eq(val.clone()));"
+        "            query = query.filter(column_filter_condition(&params.column_filters, FILTER_COLS));"
     );
-    let _ = writeln!(out, "            }}");
     // Trusted parent scope (framework-injected) — applied unconditionally, bypasses FILTER_COLS.
     let _ = writeln!(
         out,
@@ -385,7 +409,7 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
         out,
         "            if let Some(ref search_str) = params.search {{"
     );
-    write_search_conditions(out, &r.list_display, &module);
+    write_search_conditions(out, &r.search_fields, &r.list_display, &module);
     let _ = writeln!(out, "                query = query.filter(search_cond);");
     let _ = writeln!(out, "            }}");
     let _ = writeln!(
@@ -443,7 +467,7 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
         module
     );
     let _ = writeln!(out, "            if let Some(ref search_str) = _search {{");
-    write_search_conditions(out, &r.list_display, &module);
+    write_search_conditions(out, &r.search_fields, &r.list_display, &module);
     let _ = writeln!(out, "                query = query.filter(search_cond);");
     let _ = writeln!(out, "            }}");
     // Mirror list_fn's trusted parent scope so a scoped list paginates on its scoped total.
@@ -461,6 +485,53 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
     let _ = writeln!(out, "    }});");
     let _ = writeln!(out);
 
+    // FilteredCountFn closure — count_fn + active sidebar filters (same allowlist as list_fn)
+    if !r.list_filter.is_empty() {
+        let _ = writeln!(
+            out,
+            "    let filtered_count_fn: FilteredCountFn = Arc::new(|db: ADb, params: ListParams| {{"
+        );
+        let _ = writeln!(out, "        Box::pin(async move {{");
+        let _ = writeln!(
+            out,
+            "            use sea_orm::{{QueryFilter, sea_query::{{Alias, Expr, ExprTrait}}}};"
+        );
+        let _ = writeln!(
+            out,
+            "            const FILTER_COLS: &[&str] = &[{filter_cols_literal}];",
+            filter_cols_literal = filter_cols_literal
+        );
+        let _ = writeln!(
+            out,
+            "            let mut query = {}::Entity::find();",
+            module
+        );
+        let _ = writeln!(
+            out,
+            "            query = query.filter(column_filter_condition(&params.column_filters, FILTER_COLS));"
+        );
+        let _ = writeln!(
+            out,
+            "            if let Some((col, val)) = &params.scope && col.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {{"
+        );
+        let _ = writeln!(
+            out,
+            "                query = query.filter(Expr::col(Alias::new(col.as_str())).cast_as(Alias::new(\"TEXT\")).eq(val.clone()));"
+        );
+        let _ = writeln!(out, "            }}");
+        let _ = writeln!(
+            out,
+            "            if let Some(ref search_str) = params.search {{"
+        );
+        write_search_conditions(out, &r.search_fields, &r.list_display, &module);
+        let _ = writeln!(out, "                query = query.filter(search_cond);");
+        let _ = writeln!(out, "            }}");
+        let _ = writeln!(out, "            query.count(&*db).await");
+        let _ = writeln!(out, "        }})");
+        let _ = writeln!(out, "    }});");
+        let _ = writeln!(out);
+    }
+
     // GetFn closure
     let _ = writeln!(
         out,
@@ -567,28 +638,7 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
             module
         );
         let _ = writeln!(out, "            let inserted_id = result.id.to_string();");
-        for m2m in &r.m2m {
-            let prefix = format!("m2m_{}__", m2m.field_name);
-            let _ = writeln!(out, "            for key in data.keys() {{");
-            let _ = writeln!(
-                out,
-                "                if let Some(target_id) = key.strip_prefix(\"{prefix}\") && !target_id.is_empty() && target_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {{",
-                prefix = prefix
-            );
-            let _ = writeln!(
-                out,
-                "                    let sql = format!(\"INSERT INTO {junction} ({self_fk}, {target_fk}) VALUES ({{}}, {{}}) ON CONFLICT DO NOTHING\", inserted_id, target_id);",
-                junction = m2m.junction_table,
-                self_fk = m2m.self_fk,
-                target_fk = m2m.target_fk
-            );
-            let _ = writeln!(
-                out,
-                "                    let _ = db.execute_unprepared(&sql).await;"
-            );
-            let _ = writeln!(out, "                }}");
-            let _ = writeln!(out, "            }}");
-        }
+        write_m2m_links(out, r, "inserted_id", false, "            ");
         let _ = writeln!(out, "            Ok(())");
     }
     let _ = writeln!(out, "        }})");
@@ -617,34 +667,7 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
             "            {}::admin_from_form(&data, Some(id)).update(&*db).await?;",
             module
         );
-        for m2m in &r.m2m {
-            let prefix = format!("m2m_{}__", m2m.field_name);
-            let _ = writeln!(
-                out,
-                "            let _ = db.execute_unprepared(&format!(\"DELETE FROM {junction} WHERE {self_fk} = {{}}\", id_str)).await;",
-                junction = m2m.junction_table,
-                self_fk = m2m.self_fk
-            );
-            let _ = writeln!(out, "            for key in data.keys() {{");
-            let _ = writeln!(
-                out,
-                "                if let Some(target_id) = key.strip_prefix(\"{prefix}\") && !target_id.is_empty() && target_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {{",
-                prefix = prefix
-            );
-            let _ = writeln!(
-                out,
-                "                    let sql = format!(\"INSERT INTO {junction} ({self_fk}, {target_fk}) VALUES ({{}}, {{}})\", id_str, target_id);",
-                junction = m2m.junction_table,
-                self_fk = m2m.self_fk,
-                target_fk = m2m.target_fk
-            );
-            let _ = writeln!(
-                out,
-                "                    let _ = db.execute_unprepared(&sql).await;"
-            );
-            let _ = writeln!(out, "                }}");
-            let _ = writeln!(out, "            }}");
-        }
+        write_m2m_links(out, r, "id_str", true, "            ");
         let _ = writeln!(out, "            Ok(())");
     }
     let _ = writeln!(out, "        }})");
@@ -668,6 +691,24 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
     let _ = writeln!(out, "    }});");
     let _ = writeln!(out);
 
+    // TxSaveFn / TxDeleteFn closures — only for resources in an inline relation, whose
+    // parent form and inline rows are saved in one transaction
+    let in_inline = !r.inlines.is_empty()
+        || resources
+            .iter()
+            .any(|p| p.inlines.iter().any(|i| i.related == r.key));
+    if in_inline {
+        write_tx_closures(out, r, &module, id_parse_code);
+    }
+
+    // AutocompleteFn closure — only for resources searched by another's autocomplete box
+    let is_autocomplete_target = resources
+        .iter()
+        .any(|p| p.autocomplete_fields.iter().any(|(_, rel)| rel == &r.key));
+    if is_autocomplete_target {
+        write_autocomplete_fn(out, &module);
+    }
+
     // M2mLoaderFn closure (optional — only when m2m is declared)
     if !r.m2m.is_empty() {
         let _ = writeln!(
@@ -761,9 +802,21 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
         write_form_builder_closure(out, "edit_form_builder", &default_form, &edit_wrapper);
     }
 
-    // DisplayConfig with list_display, list_exclude and/or list_filter if configured
-    if !r.list_display.is_empty() || !r.list_exclude.is_empty() || !r.list_filter.is_empty() {
-        let chain = build_display_chain(&r.list_display, &r.list_exclude, &r.list_filter);
+    // DisplayConfig with list_display, list_exclude, list_filter, search_fields and/or
+    // readonly_fields if configured
+    if !r.list_display.is_empty()
+        || !r.list_exclude.is_empty()
+        || !r.list_filter.is_empty()
+        || !r.search_fields.is_empty()
+        || !r.readonly_fields.is_empty()
+    {
+        let chain = build_display_chain(
+            &r.list_display,
+            &r.list_exclude,
+            &r.list_filter,
+            &r.search_fields,
+            &r.readonly_fields,
+        );
         let _ = writeln!(out, "    let meta = meta.display({});", chain);
     }
 
@@ -884,6 +937,10 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
     );
     if !r.list_filter.is_empty() {
         let _ = writeln!(out, "            .with_filter_fn(filter_fn)");
+        let _ = writeln!(
+            out,
+            "            .with_filtered_count_fn(filtered_count_fn)"
+        );
     }
     if !r.group_action.is_empty() {
         let actions_str = build_group_actions_str(&r.group_action);
@@ -899,6 +956,13 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
     if let Some(ref own_field) = r.own_field {
         let _ = writeln!(out, "            .with_own_field(\"{}\")", own_field);
     }
+    if in_inline {
+        let _ = writeln!(out, "            .with_tx_save_fn(tx_save_fn)");
+        let _ = writeln!(out, "            .with_tx_delete_fn(tx_delete_fn)");
+    }
+    if is_autocomplete_target {
+        let _ = writeln!(out, "            .with_autocomplete_fn(autocomplete_fn)");
+    }
     let _ = writeln!(out, "    );");
     let _ = writeln!(out);
 
@@ -907,6 +971,189 @@ fn write_resource_entry(out: &mut String, r: &ResourceDef) -> Result<(), String>
 
 // ─── Code generation helpers ─────────────────────────────────────────────────
 
+/// Emits the junction-table writes of every m2m field for the row `id_var`.
+/// `replace`: deletes the existing links first (update); otherwise inserts only,
+/// skipping the links already present (create).
+fn write_m2m_links(out: &mut String, r: &ResourceDef, id_var: &str, replace: bool, indent: &str) {
+    for m2m in &r.m2m {
+        let prefix = format!("m2m_{}__", m2m.field_name);
+        if replace {
+            let _ = writeln!(
+                out,
+                "{indent}let _ = db.execute_unprepared(&format!(\"DELETE FROM {junction} WHERE {self_fk} = {{}}\", {id_var})).await;",
+                junction = m2m.junction_table,
+                self_fk = m2m.self_fk
+            );
+        }
+        let _ = writeln!(out, "{indent}for key in data.keys() {{");
+        let _ = writeln!(
+            out,
+            "{indent}    if let Some(target_id) = key.strip_prefix(\"{prefix}\") && !target_id.is_empty() && target_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {{",
+        );
+        let _ = writeln!(
+            out,
+            "{indent}        let sql = format!(\"INSERT INTO {junction} ({self_fk}, {target_fk}) VALUES ({{}}, {{}}){conflict}\", {id_var}, target_id);",
+            junction = m2m.junction_table,
+            self_fk = m2m.self_fk,
+            target_fk = m2m.target_fk,
+            conflict = if replace {
+                ""
+            } else {
+                " ON CONFLICT DO NOTHING"
+            },
+        );
+        let _ = writeln!(
+            out,
+            "{indent}        let _ = db.execute_unprepared(&sql).await;"
+        );
+        let _ = writeln!(out, "{indent}    }}");
+        let _ = writeln!(out, "{indent}}}");
+    }
+}
+
+/// Emits the `tx_save_fn` / `tx_delete_fn` closures: same writes as `create_fn`,
+/// `update_fn` and `delete_fn` (m2m links included), run on the caller's transaction.
+fn write_tx_closures(out: &mut String, r: &ResourceDef, module: &str, id_parse_code: &str) {
+    let _ = writeln!(
+        out,
+        "    let tx_save_fn: TxSaveFn = Arc::new(|db: ATxn, id: Option<String>, data: StrMap| {{"
+    );
+    let _ = writeln!(out, "        Box::pin(async move {{");
+    if !r.m2m.is_empty() {
+        let _ = writeln!(out, "            use sea_orm::ConnectionTrait;");
+    }
+    let _ = writeln!(out, "            match id {{");
+    let _ = writeln!(out, "                Some(id) => {{");
+    let _ = writeln!(out, "                    {};", id_parse_code);
+    let _ = writeln!(out, "                    let id_str = id.to_string();");
+    let _ = writeln!(
+        out,
+        "                    {}::admin_from_form(&data, Some(id)).update(&*db).await?;",
+        module
+    );
+    write_m2m_links(out, r, "id_str", true, "                    ");
+    let _ = writeln!(out, "                    Ok(id_str)");
+    let _ = writeln!(out, "                }}");
+    let _ = writeln!(out, "                None => {{");
+    let _ = writeln!(
+        out,
+        "                    let result = {}::admin_from_form(&data, None).insert(&*db).await?;",
+        module
+    );
+    let _ = writeln!(
+        out,
+        "                    let inserted_id = result.id.to_string();"
+    );
+    write_m2m_links(out, r, "inserted_id", false, "                    ");
+    let _ = writeln!(out, "                    Ok(inserted_id)");
+    let _ = writeln!(out, "                }}");
+    let _ = writeln!(out, "            }}");
+    let _ = writeln!(out, "        }})");
+    let _ = writeln!(out, "    }});");
+    let _ = writeln!(out);
+
+    let _ = writeln!(
+        out,
+        "    let tx_delete_fn: TxDeleteFn = Arc::new(|db: ATxn, id: String| {{"
+    );
+    let _ = writeln!(out, "        Box::pin(async move {{");
+    let _ = writeln!(out, "            {};", id_parse_code);
+    let _ = writeln!(
+        out,
+        "            {}::Entity::delete_by_id(id).exec(&*db).await.map(|_| ())",
+        module
+    );
+    let _ = writeln!(out, "        }})");
+    let _ = writeln!(out, "    }});");
+    let _ = writeln!(out);
+}
+
+/// Emits the `AutocompleteFn` closure: rows whose label column contains the term
+/// (case-insensitive, compared as text), as `(id, label)` pairs.
+fn write_autocomplete_fn(out: &mut String, module: &str) {
+    let _ = writeln!(
+        out,
+        "    let autocomplete_fn: AutocompleteFn = Arc::new(|db: ADb, term: String, label: String, limit: u64| {{"
+    );
+    let _ = writeln!(out, "        Box::pin(async move {{");
+    let _ = writeln!(
+        out,
+        "            use sea_orm::{{QueryFilter, sea_query::{{Alias, Expr, ExprTrait, Func}}}};"
+    );
+    let _ = writeln!(
+        out,
+        "            let mut query = {}::Entity::find();",
+        module
+    );
+    let _ = writeln!(out, "            if !term.is_empty() {{");
+    let _ = writeln!(
+        out,
+        "                let text = Expr::col(Alias::new(label.as_str())).cast_as(Alias::new(\"TEXT\"));"
+    );
+    let _ = writeln!(
+        out,
+        "                query = query.filter(Expr::expr(Func::lower(text)).like(format!(\"%{{}}%\", term.to_lowercase())));"
+    );
+    let _ = writeln!(out, "            }}");
+    let _ = writeln!(
+        out,
+        "            let rows = query.limit(limit).all(&*db).await?;"
+    );
+    let _ = writeln!(out, "            Ok(rows.into_iter().map(|r| {{");
+    let _ = writeln!(
+        out,
+        "                let v = serde_json::to_value(r).unwrap_or_default();"
+    );
+    let _ = writeln!(
+        out,
+        "                let id = runique::admin::fk_key(&v[\"id\"]).unwrap_or_default();"
+    );
+    let _ = writeln!(
+        out,
+        "                let text = runique::admin::fk_key(&v[label.as_str()]).unwrap_or_else(|| id.clone());"
+    );
+    let _ = writeln!(out, "                (id, text)");
+    let _ = writeln!(out, "            }}).collect())");
+    let _ = writeln!(out, "        }})");
+    let _ = writeln!(out, "    }});");
+    let _ = writeln!(out);
+}
+
+/// Builds the `InlineDef` expression of one inline of `parent`. The related resource
+/// must be declared in the same `admin!{}`: its model schema gives the FK column when
+/// the declaration leaves it out.
+fn build_inline_def(
+    parent: &ResourceDef,
+    inline: &crate::admin::daemon::parser::InlineSpec,
+    resources: &[ResourceDef],
+) -> Result<String, String> {
+    let related = resources
+        .iter()
+        .find(|c| c.key == inline.related && c.key != parent.key)
+        .ok_or_else(|| {
+            format!(
+                "Inline \"{}\" of \"{}\": no other resource declared under this key in admin!{{}}",
+                inline.related, parent.key
+            )
+        })?;
+    let mut def = format!("InlineDef::new(\"{}\")", inline.related);
+    match &inline.fk_column {
+        Some(col) => def.push_str(&format!(".fk_column(\"{}\")", col)),
+        None => def.push_str(&format!(
+            ".detect_fk(&{}::schema(), &{}::schema())",
+            model_to_module(&related.model_type),
+            model_to_module(&parent.model_type)
+        )),
+    }
+    if let Some(extra) = inline.extra {
+        def.push_str(&format!(".extra({})", extra));
+    }
+    if let Some(can_delete) = inline.can_delete {
+        def.push_str(&format!(".can_delete({})", can_delete));
+    }
+    Ok(def)
+}
+
 /// Emits a complete `DynForm` impl block for a wrapper struct.
 /// `save_param` is the parameter name ("db" or "_db"), `save_body` is the return expression.
 fn write_dyn_form_wrapper(
@@ -1034,9 +1281,11 @@ fn write_form_builder_closure_fk(
 }
 
 /// Emits a `search_cond!` call for the search block.
-/// FK columns are skipped — searching a raw FK ID makes no sense.
+/// `search_fields` wins when declared; otherwise the `list_display` columns, FK columns
+/// skipped — searching a raw FK ID makes no sense.
 fn write_search_conditions(
     out: &mut String,
+    search_fields: &[String],
     list_display: &[(
         String,
         String,
@@ -1044,11 +1293,15 @@ fn write_search_conditions(
     )],
     module: &str,
 ) {
-    let searchable: Vec<&str> = list_display
-        .iter()
-        .filter(|(_, _, fk)| fk.is_none())
-        .map(|(col, _, _)| col.as_str())
-        .collect();
+    let searchable: Vec<&str> = if search_fields.is_empty() {
+        list_display
+            .iter()
+            .filter(|(_, _, fk)| fk.is_none())
+            .map(|(col, _, _)| col.as_str())
+            .collect()
+    } else {
+        search_fields.iter().map(String::as_str).collect()
+    };
 
     if searchable.is_empty() {
         let _ = writeln!(
@@ -1071,7 +1324,8 @@ fn write_search_conditions(
     }
 }
 
-/// Builds a `DisplayConfig::new().columns_include(...).columns_exclude(...).list_filter(...)` chain.
+/// Builds a `DisplayConfig::new().columns_include(...).columns_exclude(...).list_filter(...)`
+/// `.search_fields(...)` chain.
 fn build_display_chain(
     list_display: &[(
         String,
@@ -1080,6 +1334,8 @@ fn build_display_chain(
     )],
     list_exclude: &[String],
     list_filter: &[(String, String, u64)],
+    search_fields: &[String],
+    readonly_fields: &[String],
 ) -> String {
     let mut chain = "DisplayConfig::new()".to_string();
     if !list_display.is_empty() {
@@ -1106,6 +1362,22 @@ fn build_display_chain(
             .join(", ");
         chain.push_str(&format!(".list_filter(vec![{}])", filters));
     }
+    if !search_fields.is_empty() {
+        let cols = search_fields
+            .iter()
+            .map(|c| format!("\"{}\"", c))
+            .collect::<Vec<_>>()
+            .join(", ");
+        chain.push_str(&format!(".search_fields(vec![{}])", cols));
+    }
+    if !readonly_fields.is_empty() {
+        let cols = readonly_fields
+            .iter()
+            .map(|c| format!("\"{}\"", c))
+            .collect::<Vec<_>>()
+            .join(", ");
+        chain.push_str(&format!(".readonly_fields(vec![{}])", cols));
+    }
     chain
 }
 
@@ -1164,3 +1436,186 @@ fn model_to_module(model_type: &str) -> String {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::admin::daemon::parse_admin_file;
+
+    fn entry_code(source: &str) -> String {
+        let parsed = parse_admin_file(source).unwrap();
+        let mut out = String::new();
+        write_resource_entry(&mut out, &parsed.resources[0], &parsed.resources).unwrap();
+        out
+    }
+
+    #[test]
+    fn search_fields_drive_search_and_display() {
+        let out = entry_code(
+            r#"admin! {
+                blog: blog::Model => BlogForm {
+                    title: "Blog",
+                    list_display: [["title", "Title"], ["status", "Status"]],
+                    search_fields: ["title", "author"],
+                }
+            }"#,
+        );
+        assert!(out.contains(r#"or("title" icontains search_str, "author" icontains search_str)"#));
+        assert!(!out.contains(r#""status" icontains"#));
+        assert!(out.contains(r#".search_fields(vec!["title", "author"])"#));
+    }
+
+    #[test]
+    fn search_defaults_to_list_display() {
+        let out = entry_code(
+            r#"admin! {
+                blog: blog::Model => BlogForm {
+                    title: "Blog",
+                    list_display: [["title", "Title"]],
+                }
+            }"#,
+        );
+        assert!(out.contains(r#"or("title" icontains search_str)"#));
+        assert!(!out.contains(".search_fields("));
+    }
+
+    #[test]
+    fn search_fields_reject_non_identifiers() {
+        let err = parse_admin_file(
+            r#"admin! {
+                blog: blog::Model => BlogForm { title: "Blog", search_fields: ["title; --"] }
+            }"#,
+        )
+        .unwrap_err();
+        assert!(err.contains("search_fields"));
+    }
+
+    #[test]
+    fn readonly_fields_reach_display_config() {
+        let out = entry_code(
+            r#"admin! {
+                blog: blog::Model => BlogForm {
+                    title: "Blog",
+                    readonly_fields: ["created_at", "slug"],
+                }
+            }"#,
+        );
+        assert!(out.contains(
+            r#"meta.display(DisplayConfig::new().readonly_fields(vec!["created_at", "slug"]))"#
+        ));
+    }
+
+    const INLINE_ADMIN: &str = r#"admin! {
+        article: article::Model => ArticleForm {
+            title: "Articles",
+            inlines: [["comment"], ["tag", "post_id", 1, false]],
+        }
+        comment: comment::Model => CommentForm { title: "Comments" }
+        tag: tag::Model => TagForm { title: "Tags" }
+        note: note::Model => NoteForm { title: "Notes" }
+    }"#;
+
+    fn code_of(key: &str) -> String {
+        let parsed = parse_admin_file(INLINE_ADMIN).unwrap();
+        let r = parsed.resources.iter().find(|r| r.key == key).unwrap();
+        let mut out = String::new();
+        write_resource_entry(&mut out, r, &parsed.resources).unwrap();
+        out
+    }
+
+    #[test]
+    fn inlines_reach_meta_with_detected_or_declared_fk() {
+        let out = code_of("article");
+        assert!(out.contains(
+            r#"meta.inline_def(InlineDef::new("comment").detect_fk(&comment::schema(), &article::schema()));"#
+        ));
+        assert!(out.contains(
+            r#"meta.inline_def(InlineDef::new("tag").fk_column("post_id").extra(1).can_delete(false));"#
+        ));
+    }
+
+    #[test]
+    fn tx_closures_only_for_inline_resources() {
+        for key in ["article", "comment", "tag"] {
+            let out = code_of(key);
+            assert!(out.contains("let tx_save_fn: TxSaveFn"), "{key}");
+            assert!(out.contains(".with_tx_delete_fn(tx_delete_fn)"), "{key}");
+        }
+        assert!(!code_of("note").contains("tx_save_fn"));
+    }
+
+    #[test]
+    fn inline_of_undeclared_resource_is_an_error() {
+        let parsed = parse_admin_file(
+            r#"admin! {
+                article: article::Model => ArticleForm { title: "A", inlines: [["comment"]] }
+            }"#,
+        )
+        .unwrap();
+        let err = write_resource_entry(&mut String::new(), &parsed.resources[0], &parsed.resources)
+            .unwrap_err();
+        assert!(err.contains("comment"));
+    }
+
+    const AUTOCOMPLETE_ADMIN: &str = r#"admin! {
+        article: article::Model => ArticleForm {
+            title: "Articles",
+            list_display: [["author_id", "Author", "authors.name"]],
+            autocomplete_fields: [["author_id", "author"]],
+        }
+        author: author::Model => AuthorForm { title: "Authors", label_field: "name" }
+    }"#;
+
+    fn autocomplete_code_of(key: &str) -> String {
+        let parsed = parse_admin_file(AUTOCOMPLETE_ADMIN).unwrap();
+        let r = parsed.resources.iter().find(|r| r.key == key).unwrap();
+        let mut out = String::new();
+        write_resource_entry(&mut out, r, &parsed.resources).unwrap();
+        out
+    }
+
+    #[test]
+    fn autocomplete_fields_replace_the_fk_select() {
+        let out = autocomplete_code_of("article");
+        assert!(out.contains(r#"meta.autocomplete("author_id", "author");"#));
+        assert!(!out.contains(r#"field_choices("author_id""#));
+        // the list still resolves the FK label
+        assert!(out.contains("meta.fk_display("));
+        assert!(!out.contains("autocomplete_fn"));
+    }
+
+    #[test]
+    fn autocomplete_target_gets_search_fn_and_label() {
+        let out = autocomplete_code_of("author");
+        assert!(out.contains(r#"meta.label_field("name");"#));
+        assert!(out.contains("let autocomplete_fn: AutocompleteFn"));
+        assert!(out.contains(".with_autocomplete_fn(autocomplete_fn)"));
+    }
+
+    #[test]
+    fn autocomplete_of_undeclared_resource_is_an_error() {
+        let parsed = parse_admin_file(
+            r#"admin! {
+                article: article::Model => ArticleForm {
+                    title: "A",
+                    autocomplete_fields: [["author_id", "author"]],
+                }
+            }"#,
+        )
+        .unwrap();
+        let err = write_resource_entry(&mut String::new(), &parsed.resources[0], &parsed.resources)
+            .unwrap_err();
+        assert!(err.contains("author"));
+    }
+
+    #[test]
+    fn autocomplete_fields_reject_non_identifiers() {
+        let err = parse_admin_file(
+            r#"admin! {
+                a: a::Model => AForm { title: "A", autocomplete_fields: [["x", "b; --"]] }
+            }"#,
+        )
+        .unwrap_err();
+        assert!(err.contains("autocomplete_fields"));
+    }
+}
@@ -43,6 +43,13 @@ pub(crate) struct ResourceDef {
     /// Columns excluded from the list: `["col1", "col2"]`
     pub list_exclude: Vec<String>,
 
+    /// Columns searched by the list search box: `["title", "author"]`.
+    /// Empty = the `list_display` columns (or every column).
+    pub search_fields: Vec<String>,
+
+    /// Columns shown as plain text in the edit form, never written by it: `["created_at"]`
+    pub readonly_fields: Vec<String>,
+
     /// Fields available for group bulk update: `[("field", "Label")]` or `[("field", "Label", "value")]`
     pub group_action: Vec<(String, String, Option<String>)>,
 
@@ -56,6 +63,29 @@ pub(crate) struct ResourceDef {
 
     /// Many-to-many relations to manage on create/edit.
     pub m2m: Vec<M2mFieldDef>,
+
+    /// Related resources edited as rows of the create/edit form.
+    pub inlines: Vec<InlineSpec>,
+
+    /// Column labelling this resource's rows in autocomplete boxes: `label_field: "name"`
+    pub label_field: Option<String>,
+
+    /// FK columns edited through a search box: `[("author_id", "authors")]`
+    pub autocomplete_fields: Vec<(String, String)>,
+}
+
+/// One inline of a resource.
+/// DSL: `["comments"]`, `["comments", "article_id"]` or `["comments", "article_id", 1, false]`
+#[derive(Debug, Clone)]
+pub(crate) struct InlineSpec {
+    /// Key of the related resource (e.g., "comments")
+    pub related: String,
+    /// FK column on the related resource; `None` (omitted or `""`) = detected from the schemas
+    pub fk_column: Option<String>,
+    /// Blank rows offered (runtime default when omitted)
+    pub extra: Option<u64>,
+    /// Whether rows can be deleted (runtime default when omitted)
+    pub can_delete: Option<bool>,
 }
 
 /// FK resolution for a list_display column: display a related record's label instead of the raw ID.
@@ -227,10 +257,15 @@ fn parse_admin_tokens(tokens: TokenStream) -> Result<ParsedAdmin, String> {
             list_filter: body.list_filter,
             list_display: body.list_display,
             list_exclude: body.list_exclude,
+            search_fields: body.search_fields,
+            readonly_fields: body.readonly_fields,
             group_action: body.group_action,
             bulk_create: body.bulk_create,
             own_field: body.own_field,
             m2m: body.m2m,
+            inlines: body.inlines,
+            label_field: body.label_field,
+            autocomplete_fields: body.autocomplete_fields,
         });
 
         // Optional comma between resources
@@ -314,7 +349,7 @@ fn parse_configure_body(key: String, tokens: TokenStream) -> Result<ConfigureDef
                 list_display = parse_pair_array(&mut iter)?;
             }
             "list_exclude" => {
-                list_exclude = parse_list_exclude(&mut iter)?;
+                list_exclude = parse_string_list(&mut iter, "list_exclude")?;
             }
             "list_filter" => {
                 list_filter = parse_list_filter(&mut iter)?;
@@ -362,10 +397,15 @@ struct ResourceBody {
     list_filter: Vec<(String, String, u64)>,
     list_display: Vec<(String, String, Option<FkDisplay>)>,
     list_exclude: Vec<String>,
+    search_fields: Vec<String>,
+    readonly_fields: Vec<String>,
     group_action: Vec<(String, String, Option<String>)>,
     bulk_create: Option<String>,
     own_field: Option<String>,
     m2m: Vec<M2mFieldDef>,
+    inlines: Vec<InlineSpec>,
+    label_field: Option<String>,
+    autocomplete_fields: Vec<(String, String)>,
 }
 
 fn parse_resource_body(tokens: TokenStream) -> Result<ResourceBody, String> {
@@ -386,10 +426,15 @@ fn parse_resource_body(tokens: TokenStream) -> Result<ResourceBody, String> {
         list_filter: Vec::new(),
         list_display: Vec::new(),
         list_exclude: Vec::new(),
+        search_fields: Vec::new(),
+        readonly_fields: Vec::new(),
         group_action: Vec::new(),
         bulk_create: None,
         own_field: None,
         m2m: Vec::new(),
+        inlines: Vec::new(),
+        label_field: None,
+        autocomplete_fields: Vec::new(),
     };
 
     while iter.peek().is_some() {
@@ -439,7 +484,13 @@ fn parse_resource_body(tokens: TokenStream) -> Result<ResourceBody, String> {
                 body.list_display = parse_list_display(&mut iter)?;
             }
             "list_exclude" => {
-                body.list_exclude = parse_list_exclude(&mut iter)?;
+                body.list_exclude = parse_string_list(&mut iter, "list_exclude")?;
+            }
+            "search_fields" => {
+                body.search_fields = parse_column_names(&mut iter, "search_fields")?;
+            }
+            "readonly_fields" => {
+                body.readonly_fields = parse_column_names(&mut iter, "readonly_fields")?;
             }
             "group_action" => {
                 body.group_action = parse_group_action(&mut iter)?;
@@ -453,6 +504,19 @@ fn parse_resource_body(tokens: TokenStream) -> Result<ResourceBody, String> {
             "m2m" => {
                 body.m2m = parse_m2m(&mut iter)?;
             }
+            "inlines" => {
+                body.inlines = parse_inlines(&mut iter)?;
+            }
+            "label_field" => {
+                let col = parse_string_literal(&mut iter)?;
+                if !is_column_name(&col) {
+                    return Err(format!("Invalid column in label_field: {:?}", col));
+                }
+                body.label_field = Some(col);
+            }
+            "autocomplete_fields" => {
+                body.autocomplete_fields = parse_autocomplete_fields(&mut iter)?;
+            }
             other => {
                 skip_until_punct(&mut iter, ',');
                 eprintln!("  Unknown field in admin!{{}}: '{}'", other);
@@ -574,8 +638,9 @@ fn parse_group_action(
     }
 }
 
-/// Parse list_exclude: ["col1", "col2", ...]
-fn parse_list_exclude(iter: &mut TokenIter) -> Result<Vec<String>, String> {
+/// Parse a list of string literals: ["col1", "col2", ...] (list_exclude, search_fields,
+/// readonly_fields)
+fn parse_string_list(iter: &mut TokenIter, field: &str) -> Result<Vec<String>, String> {
     use proc_macro2::TokenTree;
 
     match iter.next() {
@@ -591,15 +656,15 @@ fn parse_list_exclude(iter: &mut TokenIter) -> Result<Vec<String>, String> {
                             cols.push(s[1..s.len().saturating_sub(1)].to_string());
                         } else {
                             return Err(format!(
-                                "Expected string literal in list_exclude, found: {}",
-                                s
+                                "Expected string literal in {}, found: {}",
+                                field, s
                             ));
                         }
                     }
                     Some(other) => {
                         return Err(format!(
-                            "Expected string literal in list_exclude, found: {}",
-                            other
+                            "Expected string literal in {}, found: {}",
+                            field, other
                         ));
                     }
                     None => break,
@@ -607,9 +672,38 @@ fn parse_list_exclude(iter: &mut TokenIter) -> Result<Vec<String>, String> {
             }
             Ok(cols)
         }
-        Some(other) => Err(format!("Expected [...] for list_exclude, got {:?}", other)),
-        None => Err("Expected [...] for list_exclude, end of file".to_string()),
+        Some(other) => Err(format!("Expected [...] for {}, got {:?}", field, other)),
+        None => Err(format!("Expected [...] for {}, end of file", field)),
+    }
+}
+
+/// Parse search_fields / readonly_fields: ["col1", "col2"] — identifiers only (emitted
+/// into generated code).
+fn parse_column_names(iter: &mut TokenIter, field: &str) -> Result<Vec<String>, String> {
+    let cols = parse_string_list(iter, field)?;
+    if let Some(bad) = cols.iter().find(|c| !is_column_name(c)) {
+        return Err(format!("Invalid column in {}: {:?}", field, bad));
     }
+    Ok(cols)
+}
+
+fn is_column_name(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+/// Parse autocomplete_fields: [["fk_col", "related"], ...] — identifiers only.
+fn parse_autocomplete_fields(iter: &mut TokenIter) -> Result<Vec<(String, String)>, String> {
+    let pairs = parse_str_pair_array(iter, "autocomplete_fields")?;
+    if let Some((col, related)) = pairs
+        .iter()
+        .find(|(c, r)| !is_column_name(c) || !is_column_name(r))
+    {
+        return Err(format!(
+            "Invalid entry in autocomplete_fields: [{:?}, {:?}]",
+            col, related
+        ));
+    }
+    Ok(pairs)
 }
 
 /// Parse list_filter: [["col_sql", "Label"], ...] or [["col_sql", "Label", 10], ...]
@@ -834,6 +928,65 @@ fn parse_m2m(iter: &mut TokenIter) -> Result<Vec<M2mFieldDef>, String> {
     }
 }
 
+/// Parse inlines: [["related"], ["related", "fk_col"], ["related", "fk_col", extra, can_delete]]
+fn parse_inlines(iter: &mut TokenIter) -> Result<Vec<InlineSpec>, String> {
+    use proc_macro2::TokenTree;
+
+    let is_name =
+        |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_');
+    match iter.next() {
+        Some(TokenTree::Group(outer)) => {
+            let mut specs = Vec::new();
+            let mut inner = outer.stream().into_iter().peekable();
+            while inner.peek().is_some() {
+                match inner.next() {
+                    Some(TokenTree::Punct(p)) if p.as_char() == ',' => continue,
+                    Some(TokenTree::Group(entry)) => {
+                        let mut t = entry.stream().into_iter().peekable();
+                        let related = parse_string_literal(&mut t)?;
+                        if !is_name(&related) {
+                            return Err(format!("Invalid resource in inlines: {:?}", related));
+                        }
+                        let mut spec = InlineSpec {
+                            related,
+                            fk_column: None,
+                            extra: None,
+                            can_delete: None,
+                        };
+                        if let Some(TokenTree::Punct(p)) = t.next()
+                            && p.as_char() == ','
+                        {
+                            let fk = parse_string_literal(&mut t)?;
+                            if !fk.is_empty() && !is_name(&fk) {
+                                return Err(format!("Invalid column in inlines: {:?}", fk));
+                            }
+                            spec.fk_column = Some(fk).filter(|c| !c.is_empty());
+                        }
+                        if let Some(TokenTree::Punct(p)) = t.next()
+                            && p.as_char() == ','
+                        {
+                            spec.extra = Some(parse_integer_literal(&mut t)?);
+                        }
+                        if let Some(TokenTree::Punct(p)) = t.next()
+                            && p.as_char() == ','
+                        {
+                            spec.can_delete = Some(parse_bool(&mut t)?);
+                        }
+                        specs.push(spec);
+                    }
+                    Some(other) => {
+                        return Err(format!("Expected [...] entry in inlines, found: {}", other));
+                    }
+                    None => break,
+                }
+            }
+            Ok(specs)
+        }
+        Some(other) => Err(format!("Expected [...] for inlines, found: {}", other)),
+        None => Err("Expected [...] for inlines, end of file".to_string()),
+    }
+}
+
 type TokenIter = std::iter::Peekable<proc_macro2::token_stream::IntoIter>;
 
 /// Parses a type path (e.g., `users::Model`, `crate::models::users::Model`)
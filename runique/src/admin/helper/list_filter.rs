@@ -0,0 +1,95 @@
+//! Sidebar filters of the list view: filter kind detection and `filter_{col}` → SeaORM
+//! condition translation.
+//!
+//! Query string keys:
+//! - `filter_{col}=value` — exact match (`CAST(col AS TEXT) = value`);
+//! - `filter_{col}__gte=YYYY-MM-DD` / `filter_{col}__lte=YYYY-MM-DD` — date range,
+//!   both bounds inclusive (a datetime value is also accepted).
+use chrono::{NaiveDate, NaiveDateTime};
+use sea_orm::{
+    Condition,
+    sea_query::{Alias, Expr, ExprTrait, SimpleExpr},
+};
+
+/// Suffix of the lower bound of a date range filter.
+pub const GTE_SUFFIX: &str = "__gte";
+/// Suffix of the upper bound of a date range filter.
+pub const LTE_SUFFIX: &str = "__lte";
+
+/// Rendering of a sidebar filter group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterKind {
+    /// One link per distinct value (booleans, enums, short codes).
+    Values,
+    /// `from` / `to` date inputs (datetime columns).
+    DateRange,
+}
+
+fn parse_datetime(s: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f"))
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M"))
+        .ok()
+}
+
+fn parse_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
+}
+
+/// Kind of a filter group, from its distinct values: `DateRange` when every value
+/// is a date or a datetime, `Values` otherwise.
+pub fn filter_kind(values: &[String]) -> FilterKind {
+    let is_date = |v: &String| parse_datetime(v).is_some() || parse_date(v).is_some();
+    if !values.is_empty() && values.iter().all(is_date) {
+        FilterKind::DateRange
+    } else {
+        FilterKind::Values
+    }
+}
+
+fn is_identifier(col: &str) -> bool {
+    !col.is_empty() && col.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+fn as_text(col: &str) -> SimpleExpr {
+    Expr::col(Alias::new(col)).cast_as(Alias::new("TEXT"))
+}
+
+/// Condition of a single `(key, value)` filter — `None` if the value is not valid
+/// for the operator.
+fn filter_expr(key: &str, val: &str) -> Option<(String, SimpleExpr)> {
+    if let Some(col) = key.strip_suffix(GTE_SUFFIX) {
+        // ISO text compares chronologically: "2026-01-05" <= "2026-01-05 10:00:00".
+        parse_date(val).or_else(|| parse_datetime(val).map(|dt| dt.date()))?;
+        return Some((col.to_string(), as_text(col).gte(val.to_string())));
+    }
+    if let Some(col) = key.strip_suffix(LTE_SUFFIX) {
+        let expr = match parse_date(val) {
+            // Whole end day included: strictly before the next day.
+            Some(day) => as_text(col).lt(day.succ_opt()?.format("%Y-%m-%d").to_string()),
+            None => {
+                parse_datetime(val)?;
+                as_text(col).lte(val.to_string())
+            }
+        };
+        return Some((col.to_string(), expr));
+    }
+    Some((key.to_string(), as_text(key).eq(val.to_string())))
+}
+
+/// `AND` of every active filter whose column is in `allowed`. Unknown columns,
+/// non-identifier names and invalid date bounds are ignored — the keys come from the
+/// query string.
+///
+/// ```rust,ignore
+/// const FILTER_COLS: &[&str] = &["status", "created_at"];
+/// query = query.filter(column_filter_condition(&params.column_filters, FILTER_COLS));
+/// ```
+pub fn column_filter_condition(filters: &[(String, String)], allowed: &[&str]) -> Condition {
+    filters
+        .iter()
+        .filter_map(|(key, val)| filter_expr(key, val))
+        .filter(|(col, _)| is_identifier(col) && allowed.contains(&col.as_str()))
+        .fold(Condition::all(), |cond, (_, expr)| cond.add(expr))
+}
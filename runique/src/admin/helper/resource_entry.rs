@@ -10,7 +10,7 @@ pub use crate::admin::{
     helper::dyn_form::DynForm,
     resource::{AdminResource, ColumnFilter, CrudOperation, DisplayConfig},
 };
-use crate::utils::aliases::{ADb, ATera, StrMap};
+use crate::utils::aliases::{ADb, ATera, ATxn, StrMap};
 
 /// Sort direction for the admin list view.
 #[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
@@ -45,7 +45,8 @@ pub struct ListParams {
     pub sort_by: Option<String>,
     pub sort_dir: SortDir,
     pub search: Option<String>,
-    /// Exact filters by column: [(`col_sql`, `value`)]
+    /// Sidebar filters: [(`col_sql`, `value`)], or [(`col_sql__gte` / `col_sql__lte`, `date`)]
+    /// for a date range — see [`column_filter_condition`](crate::admin::helper::column_filter_condition).
     pub column_filters: Vec<(String, String)>,
     /// Trusted parent scope `Some((fk_col, parent_id))` when this resource is
     /// listed as a scoped child. Applied unconditionally (framework-injected,
@@ -83,6 +84,26 @@ pub type UpdateFn =
 /// Closure creating a new entry from validated form data.
 pub type CreateFn = Arc<dyn Fn(ADb, StrMap) -> BoxFuture<'static, Result<(), DbErr>> + Send + Sync>;
 
+/// Closure writing an entry inside the caller's transaction: creates it (`None`) or
+/// updates it (`Some(id)`) from validated form data, and returns its ID. Used when a
+/// parent and its inline rows are saved together.
+pub type TxSaveFn = Arc<
+    dyn Fn(ATxn, Option<String>, StrMap) -> BoxFuture<'static, Result<String, DbErr>> + Send + Sync,
+>;
+
+/// Closure deleting an entry by its ID inside the caller's transaction.
+pub type TxDeleteFn =
+    Arc<dyn Fn(ATxn, String) -> BoxFuture<'static, Result<(), DbErr>> + Send + Sync>;
+
+/// Closure returning up to `limit` `(id, label)` pairs whose label column (3rd
+/// argument) contains the term (2nd argument, case-insensitive). Feeds the
+/// autocomplete endpoint of the resources that point to this one.
+pub type AutocompleteFn = Arc<
+    dyn Fn(ADb, String, String, u64) -> BoxFuture<'static, Result<Vec<(String, String)>, DbErr>>
+        + Send
+        + Sync,
+>;
+
 /// Closure returning the total number of entries.
 ///
 /// Receives the optional search term **and** the trusted parent scope
@@ -96,6 +117,12 @@ pub type CountFn = Arc<
         + Sync,
 >;
 
+/// Closure returning the number of entries matching the **whole** list query —
+/// search, parent scope and active sidebar filters. Used instead of [`CountFn`] while
+/// filters are active, so the total and the page count follow the filtered list.
+pub type FilteredCountFn =
+    Arc<dyn Fn(ADb, ListParams) -> BoxFuture<'static, Result<u64, DbErr>> + Send + Sync>;
+
 /// Closure returning distinct values for each column configured in `list_filter`.
 /// Parameter: current page per column (0-based).
 /// Returns: `HashMap`<`col_sql`, (`page_values`, `total_distinct`)>
@@ -159,6 +186,118 @@ impl GroupAction {
     }
 }
 
+/// Closure running a custom bulk action on the selected IDs — returns the summary
+/// flashed to the user (e.g. `"3 articles published"`).
+pub type BulkActionFn =
+    Arc<dyn Fn(ADb, Vec<String>) -> BoxFuture<'static, Result<String, DbErr>> + Send + Sync>;
+
+/// `bulk_action` values handled by the framework — a custom action cannot use them.
+pub const RESERVED_BULK_ACTIONS: &[&str] = &["delete", "group_set", "update-submit", "bulk-edit"];
+
+/// Named bulk action offered in the list view dropdown, next to "delete selected".
+///
+/// ```rust,ignore
+/// BulkAction::new("publish", "Publier", |db, ids| async move {
+///     let n = article::Entity::update_many()
+///         .col_expr(article::Column::Published, Expr::value(true))
+///         .filter(article::Column::Id.is_in(ids.iter().filter_map(|id| id.parse::<i32>().ok())))
+///         .exec(&*db)
+///         .await?
+///         .rows_affected;
+///     Ok(format!("{n} article(s) publié(s)."))
+/// })
+/// ```
+#[derive(Clone, serde::Serialize)]
+pub struct BulkAction {
+    /// Value posted as `bulk_action` — must not be one of [`RESERVED_BULK_ACTIONS`].
+    pub name: String,
+    pub label: String,
+    #[serde(skip)]
+    pub run: BulkActionFn,
+}
+
+impl BulkAction {
+    pub fn new<F, Fut>(name: &str, label: &str, f: F) -> Self
+    where
+        F: Fn(ADb, Vec<String>) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Result<String, DbErr>> + Send + 'static,
+    {
+        Self {
+            name: name.to_string(),
+            label: label.to_string(),
+            run: Arc::new(move |db, ids| Box::pin(f(db, ids))),
+        }
+    }
+}
+
+impl std::fmt::Debug for BulkAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BulkAction")
+            .field("name", &self.name)
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+/// Closure deriving a computed column from a serialized row (raw column values).
+pub type ComputedFn = Arc<dyn Fn(&Value) -> String + Send + Sync>;
+
+/// Column with no backing field, derived from the row and shown in the list and
+/// detail views (read-only, not sortable nor searchable).
+///
+/// ```rust,ignore
+/// ComputedField::new("full_name", "Nom complet", |row| {
+///     format!("{} {}", row["first_name"].as_str().unwrap_or(""), row["last_name"].as_str().unwrap_or(""))
+/// })
+/// ```
+#[derive(Clone, serde::Serialize)]
+pub struct ComputedField {
+    /// Key of the value in the row — must not collide with a real column.
+    pub name: String,
+    pub label: String,
+    #[serde(skip)]
+    pub compute: ComputedFn,
+}
+
+impl ComputedField {
+    pub fn new<F>(name: &str, label: &str, f: F) -> Self
+    where
+        F: Fn(&Value) -> String + Send + Sync + 'static,
+    {
+        Self {
+            name: name.to_string(),
+            label: label.to_string(),
+            compute: Arc::new(f),
+        }
+    }
+}
+
+impl std::fmt::Debug for ComputedField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComputedField")
+            .field("name", &self.name)
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+/// Inserts the `fields` values into each row. Run on raw rows, before FK and enum
+/// labels, so the closures see the stored values.
+pub fn apply_computed_fields(fields: &[ComputedField], rows: &mut [Value]) {
+    if fields.is_empty() {
+        return;
+    }
+    for row in rows {
+        let values: Vec<(String, Value)> = fields
+            .iter()
+            .map(|c| (c.name.clone(), Value::String((c.compute)(row))))
+            .collect();
+        if let Value::Object(map) = row {
+            map.extend(values);
+        }
+    }
+}
+
 /// Admin registry entry: metadata + CRUD closures.
 pub struct ResourceEntry {
     pub meta: AdminResource,
@@ -170,9 +309,17 @@ pub struct ResourceEntry {
     pub update_fn: Option<UpdateFn>,
     pub partial_update_fn: Option<UpdateFn>,
     pub create_fn: Option<CreateFn>,
+    pub tx_save_fn: Option<TxSaveFn>,
+    pub tx_delete_fn: Option<TxDeleteFn>,
+    pub autocomplete_fn: Option<AutocompleteFn>,
     pub count_fn: Option<CountFn>,
+    pub filtered_count_fn: Option<FilteredCountFn>,
     pub filter_fn: Option<FilterFn>,
     pub group_actions: Vec<GroupAction>,
+    /// Custom bulk actions, in declaration order.
+    pub bulk_actions: Vec<BulkAction>,
+    /// Computed columns, in declaration order.
+    pub computed_fields: Vec<ComputedField>,
     pub m2m_loader: Option<M2mLoaderFn>,
     pub unique_fields: &'static [&'static str],
     /// Field name used to verify record ownership when `can_update_own`/`can_delete_own` is set.
@@ -194,9 +341,15 @@ impl ResourceEntry {
             update_fn: None,
             partial_update_fn: None,
             create_fn: None,
+            tx_save_fn: None,
+            tx_delete_fn: None,
+            autocomplete_fn: None,
             count_fn: None,
+            filtered_count_fn: None,
             filter_fn: None,
             group_actions: Vec::new(),
+            bulk_actions: Vec::new(),
+            computed_fields: Vec::new(),
             m2m_loader: None,
             unique_fields: &[],
             own_field: None,
@@ -249,11 +402,31 @@ impl ResourceEntry {
         self
     }
     #[must_use]
+    pub fn with_tx_save_fn(mut self, f: TxSaveFn) -> Self {
+        self.tx_save_fn = Some(f);
+        self
+    }
+    #[must_use]
+    pub fn with_tx_delete_fn(mut self, f: TxDeleteFn) -> Self {
+        self.tx_delete_fn = Some(f);
+        self
+    }
+    #[must_use]
+    pub fn with_autocomplete_fn(mut self, f: AutocompleteFn) -> Self {
+        self.autocomplete_fn = Some(f);
+        self
+    }
+    #[must_use]
     pub fn with_count_fn(mut self, f: CountFn) -> Self {
         self.count_fn = Some(f);
         self
     }
     #[must_use]
+    pub fn with_filtered_count_fn(mut self, f: FilteredCountFn) -> Self {
+        self.filtered_count_fn = Some(f);
+        self
+    }
+    #[must_use]
     pub fn with_filter_fn(mut self, f: FilterFn) -> Self {
         self.filter_fn = Some(f);
         self
@@ -281,4 +454,55 @@ impl ResourceEntry {
         self.group_actions = merged;
         self
     }
+
+    /// Adds a custom bulk action. A reserved or already registered name is ignored
+    /// (with a warning).
+    #[must_use]
+    pub fn with_bulk_action(mut self, action: BulkAction) -> Self {
+        self.add_bulk_action(action);
+        self
+    }
+
+    pub(crate) fn add_bulk_action(&mut self, action: BulkAction) {
+        if RESERVED_BULK_ACTIONS.contains(&action.name.as_str())
+            || self.bulk_actions.iter().any(|a| a.name == action.name)
+        {
+            tracing::warn!(
+                resource = self.meta.key,
+                action = %action.name,
+                "bulk action name reserved or already registered — ignored"
+            );
+            return;
+        }
+        self.bulk_actions.push(action);
+    }
+
+    /// Custom bulk action registered under `name`.
+    pub fn bulk_action(&self, name: &str) -> Option<&BulkAction> {
+        self.bulk_actions.iter().find(|a| a.name == name)
+    }
+
+    /// Adds a computed column. A name already registered is ignored (with a warning).
+    #[must_use]
+    pub fn with_computed_field(mut self, field: ComputedField) -> Self {
+        self.add_computed_field(field);
+        self
+    }
+
+    pub(crate) fn add_computed_field(&mut self, field: ComputedField) {
+        if self.computed_fields.iter().any(|c| c.name == field.name) {
+            tracing::warn!(
+                resource = self.meta.key,
+                field = %field.name,
+                "computed field already registered — ignored"
+            );
+            return;
+        }
+        self.computed_fields.push(field);
+    }
+
+    /// Inserts the computed columns into each row — see [`apply_computed_fields`].
+    pub fn apply_computed_fields(&self, rows: &mut [Value]) {
+        apply_computed_fields(&self.computed_fields, rows);
+    }
 }
@@ -77,7 +77,7 @@ pub fn diff_fields(old: &Value, body: &StrMap) -> Option<String> {
     };
     let mut changes = std::collections::BTreeMap::new();
     for (k, new_val) in body {
-        if k == CSRF_TOKEN_KEY || k == "__original_updated_at" {
+        if !is_logged_field(k) {
             continue;
         }
         let old_str = match map.get(k) {
@@ -92,6 +92,46 @@ pub fn diff_fields(old: &Value, body: &StrMap) -> Option<String> {
             );
         }
     }
+    encode_changes(changes)
+}
+
+/// Values submitted on create, as a diff with no old side:
+/// `{"title":{"new":"a"}}`.
+pub fn created_fields(body: &StrMap) -> Option<String> {
+    let changes = body
+        .iter()
+        .filter(|(k, _)| is_logged_field(k))
+        .map(|(k, v)| (k.clone(), serde_json::json!({ "new": v })))
+        .collect();
+    encode_changes(changes)
+}
+
+/// Values of a deleted object (`get_fn` result), as a diff with no new side:
+/// `{"title":{"old":"a"}}`.
+pub fn deleted_fields(old: &Value) -> Option<String> {
+    let Value::Object(map) = old else {
+        return None;
+    };
+    let changes = map
+        .iter()
+        .filter(|(k, _)| is_logged_field(k))
+        .map(|(k, v)| {
+            let old_str = match v {
+                Value::String(s) => s.clone(),
+                v => v.to_string(),
+            };
+            (k.clone(), serde_json::json!({ "old": old_str }))
+        })
+        .collect();
+    encode_changes(changes)
+}
+
+/// Form-internal and secret keys are never written to the history.
+fn is_logged_field(key: &str) -> bool {
+    !matches!(key, CSRF_TOKEN_KEY | "__original_updated_at" | "password")
+}
+
+fn encode_changes(changes: std::collections::BTreeMap<String, Value>) -> Option<String> {
     if changes.is_empty() {
         None
     } else {
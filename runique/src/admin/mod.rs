@@ -48,7 +48,7 @@ impl AdminRoutes {
 }
 pub use registry::AdminRegistry;
 pub use resource::{
-    AdminIdType, AdminResource, ColumnFilter, CrudOperation, DisplayConfig, ParentScope,
+    AdminIdType, AdminResource, ColumnFilter, CrudOperation, DisplayConfig, InlineDef, ParentScope,
     ResourcePermissions,
 };
 
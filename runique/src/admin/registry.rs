@@ -100,6 +100,30 @@ impl AdminRegistry {
         }
     }
 
+    /// Adds a custom bulk action to an existing resource (built-in or declared).
+    /// No effect if the key does not exist.
+    pub fn add_bulk_action(
+        &mut self,
+        key: &str,
+        action: crate::admin::helper::resource_entry::BulkAction,
+    ) {
+        if let Some(entry) = self.resources.get_mut(key) {
+            entry.add_bulk_action(action);
+        }
+    }
+
+    /// Adds a computed column to an existing resource (built-in or declared).
+    /// No effect if the key does not exist.
+    pub fn add_computed_field(
+        &mut self,
+        key: &str,
+        field: crate::admin::helper::resource_entry::ComputedField,
+    ) {
+        if let Some(entry) = self.resources.get_mut(key) {
+            entry.add_computed_field(field);
+        }
+    }
+
     /// Removes a resource from the registry (e.g., to hide a builtin when extend!{} takes over).
     pub fn remove(&mut self, key: &str) {
         self.resources.shift_remove(key);
@@ -140,6 +140,66 @@ impl ParentScope {
     }
 }
 
+/// Related resource edited as a table of rows on its parent's create/edit form
+/// (Django's `TabularInline`), saved in the same transaction as the parent.
+///
+/// `related` is the registry key of the child resource; `fk_column` the child
+/// column pointing to the parent. The daemon fills it from the schemas when the
+/// `admin!{}` declaration leaves it out ([`ModelSchema::fk_column_to`]).
+///
+/// [`ModelSchema::fk_column_to`]: crate::migration::schema::ModelSchema::fk_column_to
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct InlineDef {
+    /// Registry key of the child resource (e.g. `"comments"`).
+    pub related: &'static str,
+    /// Child column holding the FK to the parent (e.g. `"article_id"`).
+    /// `None` = not detected: the inline is skipped.
+    pub fk_column: Option<String>,
+    /// Blank rows offered for new children (3 by default).
+    pub extra: usize,
+    /// Whether rows can be deleted from the parent form (true by default).
+    pub can_delete: bool,
+}
+
+impl InlineDef {
+    pub fn new(related: &'static str) -> Self {
+        Self {
+            related,
+            fk_column: None,
+            extra: 3,
+            can_delete: true,
+        }
+    }
+
+    pub fn fk_column(mut self, col: &str) -> Self {
+        self.fk_column = Some(col.to_string());
+        self
+    }
+
+    /// Takes the FK column from the schemas when none was given — see
+    /// [`ModelSchema::fk_column_to`](crate::migration::schema::ModelSchema::fk_column_to).
+    pub fn detect_fk(
+        mut self,
+        related: &crate::migration::schema::ModelSchema,
+        parent: &crate::migration::schema::ModelSchema,
+    ) -> Self {
+        if self.fk_column.is_none() {
+            self.fk_column = related.fk_column_to(parent);
+        }
+        self
+    }
+
+    pub fn extra(mut self, extra: usize) -> Self {
+        self.extra = extra;
+        self
+    }
+
+    pub fn can_delete(mut self, can_delete: bool) -> Self {
+        self.can_delete = can_delete;
+        self
+    }
+}
+
 /// Configuration of resource display in the admin interface
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct DisplayConfig {
@@ -154,6 +214,12 @@ pub struct DisplayConfig {
 
     /// Sidebar filters: [(col_sql, displayed_label, limit_per_page)]
     pub list_filter: Vec<(String, String, u64)>,
+
+    /// Columns searched by the list search box (empty = the displayed columns)
+    pub search_fields: Vec<String>,
+
+    /// Columns shown as plain text in the edit form; posted values are ignored
+    pub readonly_fields: Vec<String>,
 }
 
 impl DisplayConfig {
@@ -163,6 +229,8 @@ impl DisplayConfig {
             columns: ColumnFilter::All,
             pagination: 25,
             list_filter: Vec::new(),
+            search_fields: Vec::new(),
+            readonly_fields: Vec::new(),
         }
     }
 
@@ -198,6 +266,19 @@ impl DisplayConfig {
             .collect();
         self
     }
+
+    /// Columns searched by the list search box: `vec!["title", "author"]`
+    pub fn search_fields(mut self, cols: Vec<&str>) -> Self {
+        self.search_fields = cols.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Columns shown as text in the edit form and never written by it:
+    /// `vec!["created_at"]`
+    pub fn readonly_fields(mut self, cols: Vec<&str>) -> Self {
+        self.readonly_fields = cols.iter().map(|s| s.to_string()).collect();
+        self
+    }
 }
 
 impl Default for DisplayConfig {
@@ -257,6 +338,17 @@ pub struct AdminResource {
     /// (`/{parent}/{parent_id}/{child}/...`). See [`ParentScope`]. `None` = a
     /// normal top-level resource.
     pub parent_scope: Option<ParentScope>,
+
+    /// Related resources edited as rows of the create/edit form. See [`InlineDef`].
+    pub inlines: Vec<InlineDef>,
+
+    /// Column labelling a row of this resource in another resource's autocomplete
+    /// box (Django's `__str__`). `None` = the id.
+    pub label_field: Option<String>,
+
+    /// FK columns edited through a search box over a related resource instead of
+    /// a `<select>` — `[(column, related_key)]`.
+    pub autocomplete_fields: Vec<(String, String)>,
 }
 
 impl AdminResource {
@@ -284,6 +376,9 @@ impl AdminResource {
             inject_password: false,
             fk_display: Vec::new(),
             parent_scope: None,
+            inlines: Vec::new(),
+            label_field: None,
+            autocomplete_fields: Vec::new(),
         }
     }
 
@@ -312,6 +407,9 @@ impl AdminResource {
             inject_password: false,
             fk_display: Vec::new(),
             parent_scope: None,
+            inlines: Vec::new(),
+            label_field: None,
+            autocomplete_fields: Vec::new(),
         }
     }
 
@@ -349,6 +447,36 @@ impl AdminResource {
         self
     }
 
+    /// Edits the rows of `related` on this resource's create/edit form, linked by
+    /// `fk_column` (see [`InlineDef`] for the defaults).
+    #[must_use]
+    pub fn inline(self, related: &'static str, fk_column: &str) -> Self {
+        self.inline_def(InlineDef::new(related).fk_column(fk_column))
+    }
+
+    /// Adds a fully configured inline: `InlineDef::new("comments").extra(1)`.
+    #[must_use]
+    pub fn inline_def(mut self, inline: InlineDef) -> Self {
+        self.inlines.push(inline);
+        self
+    }
+
+    /// Column used as the label of this resource's rows in autocomplete boxes.
+    #[must_use]
+    pub fn label_field(mut self, column: &str) -> Self {
+        self.label_field = Some(column.to_string());
+        self
+    }
+
+    /// Edits `column` with a search box over the rows of `related`, fed by
+    /// `/{key}/autocomplete?field={column}&q=…`, instead of a `<select>`.
+    #[must_use]
+    pub fn autocomplete(mut self, column: &str, related: &str) -> Self {
+        self.autocomplete_fields
+            .push((column.to_string(), related.to_string()));
+        self
+    }
+
     /// Configures the display of this resource
     pub fn display(mut self, display: DisplayConfig) -> Self {
         self.display = display;
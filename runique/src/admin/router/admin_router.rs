@@ -14,8 +14,9 @@ use serde::Deserialize;
 
 use crate::app::staging::AdminStaging;
 use crate::auth::{
-    guard::LoginGuard,
-    session::{is_admin_authenticated, load_user_middleware, login, logout},
+    guard::{LoginGuard, LoginOutcome},
+    session::{is_admin_authenticated, load_user_middleware, login_user_full, logout},
+    totp::{complete_registered_totp_login, is_totp_pending},
 };
 use crate::context::template::Request;
 use crate::middleware::security::rate_limit_middleware;
@@ -124,6 +125,8 @@ pub fn build_admin_router(admin_staging: AdminStaging, _db: crate::utils::aliase
         // Replace proto_state config with the one from AdminStaging
         // so that templates configured via .templates() are taken into account.
         let order = config.resource_order.clone();
+        let bulk_actions = config.bulk_actions.clone();
+        let computed_fields = config.computed_fields.clone();
         let config = Arc::new(config);
 
         // Unwrap the Arc<PrototypeAdminState> to access fields in ownership.
@@ -134,11 +137,23 @@ pub fn build_admin_router(admin_staging: AdminStaging, _db: crate::utils::aliase
                     if !order.is_empty() {
                         reg.reorder(&order);
                     }
+                    for (key, action) in bulk_actions {
+                        reg.add_bulk_action(&key, action);
+                    }
+                    for (key, field) in computed_fields {
+                        reg.add_computed_field(&key, field);
+                    }
                     Arc::new(reg)
                 }
-                Err(arc) => arc,
+                Err(arc) => {
+                    warn_additions_dropped(bulk_actions.len(), computed_fields.len());
+                    arc
+                }
             },
-            Err(arc) => arc.registry.clone(),
+            Err(arc) => {
+                warn_additions_dropped(bulk_actions.len(), computed_fields.len());
+                arc.registry.clone()
+            }
         };
 
         let merged = Arc::new(PrototypeAdminState { registry, config });
@@ -148,6 +163,18 @@ pub fn build_admin_router(admin_staging: AdminStaging, _db: crate::utils::aliase
     router
 }
 
+/// The registry is shared (cloned `Arc`) at boot: custom bulk actions and computed
+/// fields cannot be added.
+fn warn_additions_dropped(bulk_actions: usize, computed_fields: usize) {
+    if bulk_actions + computed_fields > 0 {
+        tracing::warn!(
+            bulk_actions,
+            computed_fields,
+            "admin registry shared at boot — custom bulk actions and computed fields ignored"
+        );
+    }
+}
+
 /// Session key for runtime override of the dashboard template.
 ///
 /// A developer can store a Tera template name in this key to temporarily replace
@@ -278,13 +305,31 @@ async fn admin_login_get(
     insert_admin_messages(&mut req.context, "login");
     inject_admin_prefix(&mut req.context, &admin.config.prefix);
 
+    let totp_required = is_totp_pending(&req.session).await;
     req = req
         .insert("site_title", &admin.config.site_title)
         .insert("site_url", &admin.config.site_url)
-        .insert("lang", current_lang().code());
+        .insert("lang", current_lang().code())
+        .insert("totp_required", totp_required);
     req.render(admin.config.templates.login.resolve())
 }
 
+/// Login page after a POST — the code form while a TOTP step is pending.
+async fn admin_login_page(mut req: Request, admin: &AdminState, error: Option<String>) -> Response {
+    let totp_required = is_totp_pending(&req.session).await;
+    insert_admin_messages(&mut req.context, "login");
+    insert_admin_messages(&mut req.context, "base");
+    inject_admin_prefix(&mut req.context, &admin.config.prefix);
+    req = req
+        .insert("lang", current_lang().code())
+        .insert("site_title", &admin.config.site_title)
+        .insert("site_url", &admin.config.site_url)
+        .insert("totp_required", totp_required)
+        .insert("error", &error);
+    req.render(admin.config.templates.login.resolve())
+        .unwrap_or_else(axum::response::IntoResponse::into_response)
+}
+
 async fn admin_login_post(
     Extension(admin): Extension<Arc<AdminState>>,
     mut req: Request,
@@ -331,22 +376,40 @@ async fn admin_login_post(
             .unwrap_or_else(axum::response::IntoResponse::into_response);
     }
 
-    // Login guard verification (brute-force)
-    if let Some(guard) = &admin.login_guard {
-        let key = LoginGuard::effective_key(&data.username, "unknown");
-        if guard.is_locked(&key) {
-            let secs = guard.remaining_lockout_secs(&key).unwrap_or(0);
-            insert_admin_messages(&mut req.context, "login");
-            inject_admin_prefix(&mut req.context, &admin.config.prefix);
-            req = req
-                .insert("lang", current_lang().code())
-                .insert("site_title", &admin.config.site_title)
-                .insert("site_url", &admin.config.site_url)
-                .insert("error", tf("admin.login.error_locked", &[secs]));
-            return req
-                .render(admin.config.templates.login.resolve())
-                .unwrap_or_else(axum::response::IntoResponse::into_response);
-        }
+    let db_store = req
+        .engine
+        .session_db_store
+        .read()
+        .ok()
+        .and_then(|g| g.as_ref().cloned());
+    let exclusive = req.engine.features.exclusive_login;
+
+    // Second step: password accepted, the TOTP code is expected
+    if is_totp_pending(&req.session).await
+        && let Some(code) = req.prisme.data.get("totp_code").cloned()
+    {
+        return match complete_registered_totp_login(
+            &req.session,
+            &req.engine.db,
+            &code,
+            db_store.as_deref(),
+            exclusive,
+        )
+        .await
+        {
+            Ok(true) => Redirect::to(&format!("{}/", admin.config.prefix)).into_response(),
+            Ok(false) => {
+                admin_login_page(req, &admin, Some(t("admin.login.error_totp").to_string())).await
+            }
+            Err(_) => {
+                admin_login_page(
+                    req,
+                    &admin,
+                    Some(t("admin.login.error_session").to_string()),
+                )
+                .await
+            }
+        };
     }
 
     let Some(auth) = &admin.config.auth else {
@@ -357,24 +420,48 @@ async fn admin_login_post(
             .into_response();
     };
 
-    let result = auth
-        .authenticate(&data.username, &data.password, &req.engine.db)
-        .await;
-
-    if let Some(user) = result {
-        if let Some(guard) = &admin.login_guard {
-            let key = LoginGuard::effective_key(&data.username, "unknown");
-            guard.record_success(&key);
+    // Login guard (brute-force): consulted before the password is even checked
+    let ip = req
+        .client_ip
+        .map_or_else(|| "unknown".to_string(), |ip| ip.to_string());
+    let outcome = match &admin.login_guard {
+        Some(guard) => {
+            guard
+                .authenticate(
+                    auth.as_ref(),
+                    &data.username,
+                    &data.password,
+                    &ip,
+                    &req.engine.db,
+                )
+                .await
         }
+        None => auth
+            .authenticate(&data.username, &data.password, &req.engine.db)
+            .await
+            .into(),
+    };
 
-        let db_store = req
-            .engine
-            .session_db_store
-            .read()
-            .ok()
-            .and_then(|g| g.as_ref().cloned());
-        let exclusive = req.engine.features.exclusive_login;
-        if login(
+    if let LoginOutcome::Throttled { retry_after } = outcome {
+        insert_admin_messages(&mut req.context, "login");
+        inject_admin_prefix(&mut req.context, &admin.config.prefix);
+        req = req
+            .insert("lang", current_lang().code())
+            .insert("site_title", &admin.config.site_title)
+            .insert("site_url", &admin.config.site_url)
+            .insert("error", tf("admin.login.error_locked", &[retry_after]));
+        return req
+            .render(admin.config.templates.login.resolve())
+            .unwrap_or_else(axum::response::IntoResponse::into_response);
+    }
+
+    if let LoginOutcome::Authenticated(user) = outcome {
+        let remember = req
+            .prisme
+            .data
+            .get("remember_me")
+            .is_some_and(|v| matches!(v.as_str(), "true" | "on" | "1"));
+        if login_user_full(
             &req.session,
             &req.engine.db,
             user.user_id,
@@ -383,6 +470,7 @@ async fn admin_login_post(
             user.is_superuser,
             db_store.as_deref(),
             exclusive,
+            remember,
         )
         .await
         .is_err()
@@ -400,13 +488,13 @@ async fn admin_login_post(
                 .unwrap_or_else(axum::response::IntoResponse::into_response);
         }
 
-        Redirect::to(&format!("{}/", admin.config.prefix)).into_response()
-    } else {
-        if let Some(guard) = &admin.login_guard {
-            let key = LoginGuard::effective_key(&data.username, "unknown");
-            guard.record_failure(&key);
+        // Account with 2FA: the session waits for the code
+        if is_totp_pending(&req.session).await {
+            return admin_login_page(req, &admin, None).await;
         }
 
+        Redirect::to(&format!("{}/", admin.config.prefix)).into_response()
+    } else {
         insert_admin_messages(&mut req.context, "login");
         insert_admin_messages(&mut req.context, "base");
         inject_admin_prefix(&mut req.context, &admin.config.prefix);
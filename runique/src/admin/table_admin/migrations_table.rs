@@ -432,6 +432,82 @@ impl sea_orm_migration::MigrationTrait for EihwazResetTokensMigration {
     }
 }
 
+/// Generates the `TableCreateStatement` for the `eihwaz_api_tokens` table.
+///
+/// Stores **hashed** bearer tokens (never the raw token). `expires_at` and
+/// `last_used_at` are nullable: a token may never expire, and usage tracking is opt-in.
+pub fn create_eihwaz_api_tokens_table() -> TableCreateStatement {
+    let mut user_id_col = ColumnDef::new(Alias::new("user_id"));
+    #[cfg(feature = "big-pk")]
+    user_id_col.big_integer();
+    #[cfg(not(feature = "big-pk"))]
+    user_id_col.integer();
+    user_id_col.not_null();
+
+    let user_table = user_table_name();
+    let fk_name = format!("fk_eihwaz_api_tokens_{}_id", user_table);
+
+    Table::create()
+        .table(Alias::new("eihwaz_api_tokens"))
+        .if_not_exists()
+        .col(
+            ColumnDef::new(Alias::new("id"))
+                .big_integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(Alias::new("token_hash"))
+                .string()
+                .not_null()
+                .unique_key(),
+        )
+        .col(&mut user_id_col)
+        .col(ColumnDef::new(Alias::new("expires_at")).date_time().null())
+        .col(
+            ColumnDef::new(Alias::new("last_used_at"))
+                .date_time()
+                .null(),
+        )
+        .foreign_key(
+            ForeignKey::create()
+                .name(&fk_name)
+                .from(Alias::new("eihwaz_api_tokens"), Alias::new("user_id"))
+                .to(Alias::new(user_table.as_str()), Alias::new("id"))
+                .on_delete(ForeignKeyAction::Cascade),
+        )
+        .to_owned()
+}
+
+/// "Turnkey" migration to create the `eihwaz_api_tokens` table.
+/// To be placed in the `Migrator` `vec!` after `EihwazUsersMigration`.
+pub struct EihwazApiTokensMigration;
+
+impl sea_orm_migration::MigrationName for EihwazApiTokensMigration {
+    fn name(&self) -> &str {
+        "m000000_000006_runique_api_tokens"
+    }
+}
+
+#[async_trait::async_trait]
+impl sea_orm_migration::MigrationTrait for EihwazApiTokensMigration {
+    async fn up(&self, manager: &sea_orm_migration::SchemaManager) -> Result<(), sea_orm::DbErr> {
+        manager.create_table(create_eihwaz_api_tokens_table()).await
+    }
+
+    async fn down(&self, manager: &sea_orm_migration::SchemaManager) -> Result<(), sea_orm::DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(Alias::new("eihwaz_api_tokens"))
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
 /// Complete "turnkey" migration to initialize Runique's native RBAC architecture.
 /// To be injected directly into the `Migrations::up()` `vec!` after your User table migration.
 pub struct AdminTableMigration;
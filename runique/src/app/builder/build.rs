@@ -6,31 +6,46 @@
 //!   3. Core (Templates → Engine → URLs)
 //!   4. Admin panel (merged before middleware stack)
 //!   5. Password reset routes
-//!   6. Middleware staging (slots sort + apply)
-//!   7. Static files
+//!   6. Debug route list (`/__routes`, debug only)
+//!   7. Middleware staging (slots sort + apply)
+//!   8. Health probes and static files (outside the middleware stack)
 
 use std::sync::Arc;
 
 use axum::{
     Router,
-    http::{HeaderName, HeaderValue},
+    extract::{Path, State},
+    http::{HeaderName, HeaderValue, StatusCode},
+    response::Redirect,
+    routing::get,
 };
 use tower_http::{services::ServeDir, set_header::SetResponseHeaderLayer};
 
 use super::super::error_build::BuildError;
+use super::super::health::health_router;
+use super::super::route_list::route_list_router;
 use super::super::runique_app::RuniqueApp;
+use super::super::template_reload::{LiveTera, spawn_watcher};
 use super::super::templates::TemplateLoader;
 use super::RuniqueAppBuilder;
 use crate::admin::build_admin_router;
 use crate::config::RuniqueConfig;
 use crate::engine::RuniqueEngine;
 use crate::macros::{add_urls, register_name_url};
-use crate::middleware::HostPolicy;
+use crate::middleware::{
+    EtagCache, HostPolicy, StaticRangeLayer, immutable_static_middleware, static_etag_middleware,
+    take_host_overrides,
+};
+use crate::utils::StaticManifest;
 use crate::utils::aliases::new;
 use crate::utils::runique_log::log_init;
+use crate::utils::storage::{LocalStorage, Storage, clean_path};
+use crate::utils::trad::Catalogs;
 
 #[cfg(feature = "orm")]
 use crate::middleware::session::session_db::RuniqueSessionStore;
+#[cfg(feature = "orm")]
+use crate::tasks::TaskQueue;
 
 impl RuniqueAppBuilder {
     /// Validates and builds the application.
@@ -68,12 +83,44 @@ impl RuniqueAppBuilder {
         let statics_enabled = self.statics.enabled;
         let static_cache = self.statics.static_cache;
         let media_cache = self.statics.media_cache;
+        // Installed before anything runs: `FileField::finalize` and the admin read it
+        if let Some(storage) = &self.statics.storage {
+            crate::utils::storage::set_storage(storage.clone());
+        }
+        let storage = self.statics.storage.take();
         let router = self.router;
+        let template_autoreload = self.template_autoreload;
+        let tera_hooks = self.tera_hooks;
+        let health = self.health;
+        let cache = self.cache.ok().flatten().unwrap_or_default();
+        #[cfg(feature = "orm")]
+        let db = new(db);
+        #[cfg(feature = "orm")]
+        let tasks = TaskQueue::start(db.clone(), self.tasks, self.task_backend);
+        #[cfg(feature = "orm")]
+        let scheduler = self.scheduler.start(db.clone());
+        #[cfg(feature = "orm")]
+        crate::signals::set_db(db.clone());
 
         // Step 4: core construction — strict order: Templates → Config → Engine → URLs
 
-        let tera = new(TemplateLoader::init(&config, url_registry.clone())
-            .map_err(|e| BuildError::template(e.to_string()))?);
+        // Static files hashed once: `static_url()` in templates + `immutable` on hashed URLs
+        let manifest =
+            StaticManifest::build(std::path::Path::new(&config.static_files.staticfiles_dirs));
+        let i18n = Arc::new(
+            Catalogs::load(&config.i18n)
+                .map_err(|e| BuildError::validation(format!("translation catalog: {e}")))?,
+        );
+        let tera = LiveTera::new(
+            TemplateLoader::init(
+                &config,
+                url_registry.clone(),
+                manifest.clone(),
+                i18n.clone(),
+                &tera_hooks,
+            )
+            .map_err(|e| BuildError::template(e.to_string()))?,
+        );
 
         let config = new(config);
         crate::utils::password::password_init(config.password.clone());
@@ -82,13 +129,19 @@ impl RuniqueAppBuilder {
             config: (*config).clone(),
             tera: tera.clone(),
             #[cfg(feature = "orm")]
-            db: new(db),
+            db,
+            #[cfg(feature = "orm")]
+            tasks,
+            #[cfg(feature = "orm")]
+            scheduler,
+            cache,
             features: {
                 let mut f = middleware.features.clone();
                 f.exclusive_login = middleware.exclusive_login;
                 f
             },
             url_registry,
+            i18n,
             security_csp: {
                 let mut policy = middleware.security_policy.take().unwrap_or_default();
                 if self.admin.enabled {
@@ -99,9 +152,18 @@ impl RuniqueAppBuilder {
             security_hosts: new(HostPolicy::new(
                 middleware.allowed_hosts.clone(),
                 middleware.features.enable_host_validation,
-            )),
+            )
+            .with_overrides(take_host_overrides())),
             csrf_exempt_paths: Arc::new(middleware.csrf_exempt_paths.clone()),
+            trusted_origins: Arc::new(
+                middleware
+                    .cors_config
+                    .as_ref()
+                    .map(|c| c.trusted_origins())
+                    .unwrap_or_default(),
+            ),
             permissions_policy: Arc::new(middleware.permissions_policy.take().unwrap_or_default()),
+            security_headers: Arc::new(middleware.security_headers.take().unwrap_or_default()),
             trusted_proxies: Arc::new(
                 middleware
                     .trusted_proxies_config
@@ -116,10 +178,23 @@ impl RuniqueAppBuilder {
 
         add_urls(&engine);
 
+        // Debug by default: templates rebuilt on change, release keeps the compiled instance
+        if template_autoreload {
+            spawn_watcher(
+                &engine.tera,
+                (*config).clone(),
+                engine.url_registry.clone(),
+                engine.i18n.clone(),
+                tera_hooks,
+            );
+        }
+
         // Step 4b: admin + password reset — merged BEFORE the middleware stack.
         // `.layer()` in Axum only covers routes present at call time;
         // merging after means admin routes run without Session/CSRF/Extensions.
         let router = router.unwrap_or_default();
+        // Application routes only: probing admin routes would run their auth layers
+        let probed_routes = config.debug.then(|| router.clone());
 
         let router = if let Some(pr) = self.password_reset {
             let forgot_path = pr.config.forgot_route.clone();
@@ -225,10 +300,15 @@ impl RuniqueAppBuilder {
             crate::runique_log!(level, routes = count, "url registry");
         }
 
+        // Served through the middleware stack, like any application route
+        let router = match probed_routes {
+            Some(routes) => router.merge(route_list_router(&routes, &engine).await),
+            None => router,
+        };
+
         // Step 5: middleware staging — automatic slot sort and apply
         let _exclusive_login = middleware.exclusive_login;
-        let (router, session_store) =
-            middleware.apply_to_router(router, config, engine.clone(), tera);
+        let (router, session_store) = middleware.apply_to_router(router, config, engine.clone());
         if let Some(store) = session_store
             && let Ok(mut guard) = engine.session_store.write()
         {
@@ -243,9 +323,22 @@ impl RuniqueAppBuilder {
             }
         }
 
+        // Probes answer even when the middleware stack would refuse the request
+        let router = match health {
+            Some(health) => router.merge(health_router(health, engine.clone())),
+            None => router,
+        };
+
         // Step 6: static files (conditional)
         let router = if statics_enabled {
-            Self::attach_static_files(router, &engine.config, static_cache, media_cache)
+            Self::attach_static_files(
+                router,
+                &engine.config,
+                manifest,
+                static_cache,
+                media_cache,
+                storage,
+            )
         } else {
             router
         };
@@ -264,58 +357,24 @@ impl RuniqueAppBuilder {
         self.middleware.validate()?;
         self.statics.validate()?;
         self.admin.validate()?;
-        self.cross_validate()
-    }
-
-    fn cross_validate(&self) -> Result<(), BuildError> {
-        if self.config.debug {
-            return Ok(());
+        if let Some(e) = &self.profile_error {
+            return Err(BuildError::validation(format!("profile: {e}")));
         }
-
-        use crate::app::error_build::{CheckError, CheckReport};
-
-        let mut report = CheckReport::new();
-        #[cfg(feature = "acme")]
-        let sec = &self.config.security;
-        let srv = &self.config.server;
-
-        if crate::config::server::secret_key_is_weak(&srv.secret_key) {
-            report.add(
-                CheckError::new(
-                    "Security",
-                    "SECRET_KEY is missing, empty, the default value, or shorter than 32 characters",
-                )
-                .with_suggestion(
-                    "Set SECRET_KEY to a random 32+ character string in your .env file (the `runique` CLI can generate one)",
-                ),
-            );
+        if let Err(e) = &self.cache {
+            return Err(BuildError::validation(format!("cache: {e}")));
         }
-
-        #[cfg(feature = "acme")]
-        if sec.acme_enabled {
-            if sec.acme_domain.is_none() {
-                report.add(
-                    CheckError::new("ACME", "ACME_ENABLED=true but ACME_DOMAIN is not set")
-                        .with_suggestion(
-                            "Set ACME_DOMAIN to your production domain in your .env file",
-                        ),
-                );
-            }
-            if sec.acme_email.is_none() {
-                report.add(
-                    CheckError::new("ACME", "ACME_ENABLED=true but ACME_EMAIL is not set")
-                        .with_suggestion(
-                            "Set ACME_EMAIL to your Let's Encrypt contact email in your .env file",
-                        ),
-                );
-            }
+        if let Some(Err(e)) = self.health.as_ref().map(|h| h.validate()) {
+            return Err(BuildError::validation(format!("health: {e}")));
         }
-
-        if report.has_errors() {
-            return Err(BuildError::check(report));
+        #[cfg(feature = "orm")]
+        if let Some(e) = self.scheduler.errors().first() {
+            return Err(BuildError::validation(format!("schedule: {e}")));
         }
+        self.cross_validate()
+    }
 
-        Ok(())
+    fn cross_validate(&self) -> Result<(), BuildError> {
+        self.config.validate().map_err(BuildError::check)
     }
 
     fn all_ready(&self) -> bool {
@@ -330,8 +389,10 @@ impl RuniqueAppBuilder {
     pub(super) fn attach_static_files(
         mut router: Router,
         config: &RuniqueConfig,
+        manifest: StaticManifest,
         static_cache: &'static str,
         media_cache: &'static str,
+        storage: Option<Arc<dyn Storage>>,
     ) -> Router {
         let security_headers = || {
             tower::ServiceBuilder::new()
@@ -353,27 +414,62 @@ impl RuniqueAppBuilder {
                 ))
         };
 
-        let static_headers = security_headers().layer(SetResponseHeaderLayer::if_not_present(
-            HeaderName::from_static("cache-control"),
-            HeaderValue::from_static(static_cache),
-        ));
-
-        let media_headers = security_headers().layer(SetResponseHeaderLayer::if_not_present(
-            HeaderName::from_static("cache-control"),
-            HeaderValue::from_static(media_cache),
-        ));
-
-        router = router
-            .nest_service(
-                &config.static_files.static_url,
-                static_headers
-                    .clone()
-                    .service(ServeDir::new(&config.static_files.staticfiles_dirs)),
-            )
-            .nest_service(
+        // Innermost: strong ETag + `If-None-Match` → 304 (mtime `If-Modified-Since` is ServeDir's),
+        // then `If-Range` / multi-range on top of ServeDir's single ranges
+        let etags = EtagCache::default();
+        let static_headers = security_headers()
+            .layer(SetResponseHeaderLayer::if_not_present(
+                HeaderName::from_static("cache-control"),
+                HeaderValue::from_static(static_cache),
+            ))
+            .layer(StaticRangeLayer)
+            .layer(axum::middleware::from_fn_with_state(
+                etags.clone(),
+                static_etag_middleware,
+            ));
+
+        let media_headers = security_headers()
+            .layer(SetResponseHeaderLayer::if_not_present(
+                HeaderName::from_static("cache-control"),
+                HeaderValue::from_static(media_cache),
+            ))
+            .layer(StaticRangeLayer)
+            .layer(axum::middleware::from_fn_with_state(
+                etags,
+                static_etag_middleware,
+            ));
+
+        router = router.nest_service(
+            &config.static_files.static_url,
+            static_headers
+                .clone()
+                .layer(axum::middleware::from_fn_with_state(
+                    manifest,
+                    immutable_static_middleware,
+                ))
+                .service(ServeDir::new(&config.static_files.staticfiles_dirs)),
+        );
+
+        // Media through the storage: served from its directory when local,
+        // redirected to its URL otherwise (no proxying of remote bytes)
+        let storage = storage.unwrap_or_else(|| {
+            Arc::new(LocalStorage::new(
+                &config.static_files.media_root,
                 &config.static_files.media_url,
-                media_headers.service(ServeDir::new(&config.static_files.media_root)),
-            );
+            ))
+        });
+        router = match storage.local_root() {
+            Some(root) => router.nest_service(
+                &config.static_files.media_url,
+                media_headers.service(ServeDir::new(root)),
+            ),
+            None => router.nest(
+                &config.static_files.media_url,
+                Router::new()
+                    .route("/{*path}", get(media_redirect))
+                    .with_state(storage),
+            ),
+        };
 
         if !config.static_files.static_runique_url.is_empty() {
             router = router.nest_service(
@@ -400,3 +496,14 @@ impl RuniqueAppBuilder {
         router
     }
 }
+
+/// `GET {media_url}/{path}` for a remote storage: temporary redirect to the object URL
+/// (a signed URL expires, so the redirect must not be cached as permanent).
+async fn media_redirect(
+    State(storage): State<Arc<dyn Storage>>,
+    Path(path): Path<String>,
+) -> Result<Redirect, StatusCode> {
+    // `..` would step out of the bucket with path-style URLs
+    let path = clean_path(&path).map_err(|_| StatusCode::NOT_FOUND)?;
+    Ok(Redirect::temporary(&storage.url_for(&path)))
+}
@@ -2,18 +2,29 @@
 mod build;
 
 use axum::Router;
+use std::sync::Arc;
+use tera::Tera;
 use tower_sessions::cookie::time::Duration;
 
-use super::staging::{AdminStaging, CoreStaging, MiddlewareStaging, StaticStaging};
+use super::health::HealthConfig;
+use super::staging::{
+    AdminStaging, CompressionConfig, CoreStaging, MiddlewareStaging, StaticStaging,
+};
+use super::templates::TeraHook;
 use crate::auth::{
     PasswordResetAdapter, PasswordResetConfig, PasswordResetStaging, session::UserEntity,
 };
-use crate::config::RuniqueConfig;
+use crate::cache::{Cache, CacheHandle};
+use crate::config::{I18nConfig, RuniqueConfig};
+use crate::flash::FlashConfig;
+use crate::forms::theme::FormTheme;
 use crate::utils::runique_log::RuniqueLog;
 
 #[cfg(feature = "orm")]
 use crate::db::DatabaseConfig;
 #[cfg(feature = "orm")]
+use crate::tasks::{Scheduler, TaskBackend, TaskConfig};
+#[cfg(feature = "orm")]
 use sea_orm::DatabaseConnection;
 
 /// Intelligent application builder for Runique
@@ -27,6 +38,17 @@ pub struct RuniqueAppBuilder {
     pub(super) router: Option<Router>,
     pub(super) admin: AdminStaging,
     pub(super) password_reset: Option<PasswordResetStaging>,
+    pub(super) template_autoreload: bool,
+    pub(super) tera_hooks: Vec<TeraHook>,
+    pub(super) cache: Result<Option<CacheHandle>, String>,
+    pub(super) health: Option<HealthConfig>,
+    pub(super) profile_error: Option<String>,
+    #[cfg(feature = "orm")]
+    pub(super) tasks: TaskConfig,
+    #[cfg(feature = "orm")]
+    pub(super) task_backend: Option<Arc<dyn TaskBackend>>,
+    #[cfg(feature = "orm")]
+    pub(super) scheduler: Scheduler,
 }
 
 impl RuniqueAppBuilder {
@@ -37,6 +59,7 @@ impl RuniqueAppBuilder {
     /// and the dev can then override it via `.middleware(|m| ...)`.
     pub fn new(config: RuniqueConfig) -> Self {
         let middleware = MiddlewareStaging::from_config(&config);
+        let template_autoreload = config.debug;
         Self {
             config,
             core: CoreStaging::new(),
@@ -45,6 +68,17 @@ impl RuniqueAppBuilder {
             router: None,
             admin: AdminStaging::new(),
             password_reset: None,
+            template_autoreload,
+            tera_hooks: Vec::new(),
+            cache: Ok(None),
+            health: None,
+            profile_error: None,
+            #[cfg(feature = "orm")]
+            tasks: TaskConfig::default(),
+            #[cfg(feature = "orm")]
+            task_backend: None,
+            #[cfg(feature = "orm")]
+            scheduler: Scheduler::default(),
         }
     }
 
@@ -55,6 +89,28 @@ impl RuniqueAppBuilder {
     // Regardless of the call order by a dev.
     // ═══════════════════════════════════════════════════════════
 
+    // ─── Profile ─────────────────────────────────────────────────────────────
+
+    /// Reloads the configuration under `profile` — see [`RuniqueConfig::with_profile`].
+    /// `prod` turns `debug` off and CSP strict unless the profile file or the
+    /// environment say otherwise. Call it first: it resets the middleware staging,
+    /// so only the builder calls after it override the profile.
+    ///
+    /// ```rust,ignore
+    /// RuniqueApp::builder(RuniqueConfig::from_toml("runique.toml")?).profile("prod")
+    /// ```
+    pub fn profile(mut self, profile: &str) -> Self {
+        match self.config.clone().with_profile(profile) {
+            Ok(config) => {
+                self.middleware = MiddlewareStaging::from_config(&config);
+                self.template_autoreload = config.debug;
+                self.config = config;
+            }
+            Err(e) => self.profile_error = Some(e.to_string()),
+        }
+        self
+    }
+
     // ─── Core ────────────────────────────────────────────────────────────────
 
     /// Configures the core via a closure.
@@ -123,11 +179,123 @@ impl RuniqueAppBuilder {
         self
     }
 
+    /// Sets the default CSS theme of `render_form` (overrides `FORM_THEME`).
+    /// A form can still pick its own with [`Forms::set_theme`](crate::forms::Forms::set_theme).
+    ///
+    /// ```rust,ignore
+    /// RuniqueApp::builder(config).with_form_theme(FormTheme::Bootstrap5)
+    /// ```
+    pub fn with_form_theme(mut self, theme: FormTheme) -> Self {
+        self.config.form_theme = theme;
+        self
+    }
+
+    /// Flash messages queue policy: cap (oldest dropped) and de-duplication of
+    /// identical consecutive messages. `success!` / `error!`… are unchanged.
+    ///
+    /// ```rust,ignore
+    /// RuniqueApp::builder(config).with_flash(|f| f.max_messages(5).deduplicate(true))
+    /// ```
+    pub fn with_flash(mut self, f: impl FnOnce(FlashConfig) -> FlashConfig) -> Self {
+        self.config.flash = f(self.config.flash);
+        self
+    }
+
+    /// Configures the translation catalogs (overrides `LOCALE_DIR` / `DEFAULT_LOCALE`).
+    ///
+    /// ```rust,ignore
+    /// RuniqueApp::builder(config)
+    ///     .with_i18n(|i| i.locale_dir("locales").default_locale("fr"))
+    /// ```
+    pub fn with_i18n(mut self, f: impl FnOnce(I18nConfig) -> I18nConfig) -> Self {
+        self.config.i18n = f(self.config.i18n);
+        self
+    }
+
+    /// Rebuilds templates when a file of `TEMPLATES_DIR` or `STATICFILES_DIRS` changes.
+    /// Default: `config.debug` — release builds keep the instance compiled at startup.
+    /// A template that fails to parse is logged and the previous version kept.
+    ///
+    /// ```rust,ignore
+    /// RuniqueApp::builder(config).with_template_autoreload(false)
+    /// ```
+    pub fn with_template_autoreload(mut self, enable: bool) -> Self {
+        self.template_autoreload = enable;
+        self
+    }
+
+    /// Customizes the Tera instance: own filters, functions, testers or templates.
+    ///
+    /// Runs after the framework registrations (`static`, `markdown`, `link()`…), so a
+    /// filter registered under a built-in name replaces it. Called again on every
+    /// template reload — keep it free of side effects. Several calls add up, in order.
+    ///
+    /// ```rust,ignore
+    /// RuniqueApp::builder(config).with_tera(|tera| {
+    ///     tera.register_filter("money", |v: &Value, _: &HashMap<String, Value>| {
+    ///         Ok(Value::String(format!("{:.2} €", v.as_f64().unwrap_or(0.0))))
+    ///     });
+    /// })
+    /// ```
+    pub fn with_tera(mut self, f: impl Fn(&mut Tera) + Send + Sync + 'static) -> Self {
+        self.tera_hooks.push(Arc::new(f));
+        self
+    }
+
+    /// Starts `workers` background task workers at `build()` — tokio tasks sharing
+    /// the queue, `engine.tasks.enqueue(task)` feeds them. `0` (default) disables it.
+    ///
+    /// ```rust,ignore
+    /// RuniqueApp::builder(config).with_worker(2)
+    /// ```
+    #[cfg(feature = "orm")]
+    pub fn with_worker(mut self, workers: usize) -> Self {
+        self.tasks.workers = workers;
+        self
+    }
+
+    /// Retry policy of the background tasks: attempts and exponential backoff.
+    ///
+    /// ```rust,ignore
+    /// RuniqueApp::builder(config)
+    ///     .with_worker(2)
+    ///     .with_tasks(|t| t.max_retries(5).backoff(Duration::from_secs(2)))
+    /// ```
+    #[cfg(feature = "orm")]
+    pub fn with_tasks(mut self, f: impl FnOnce(TaskConfig) -> TaskConfig) -> Self {
+        self.tasks = f(self.tasks);
+        self
+    }
+
+    /// Replaces the in-memory task storage (lost on restart) with `backend`.
+    #[cfg(feature = "orm")]
+    pub fn with_task_backend(mut self, backend: impl TaskBackend) -> Self {
+        self.task_backend = Some(Arc::new(backend));
+        self
+    }
+
+    /// Declares periodic jobs, fired from `build()` until shutdown. A run still in
+    /// progress makes the next occurrence skip (`.allow_overlap()` to run anyway).
+    /// An invalid cron expression fails `build()`. Several calls add up.
+    ///
+    /// ```rust,ignore
+    /// RuniqueApp::builder(config).with_schedule(|s| {
+    ///     s.every(Duration::from_secs(3600)).run(cleanup);
+    ///     s.cron("0 3 * * *").run(nightly);
+    /// })
+    /// ```
+    #[cfg(feature = "orm")]
+    pub fn with_schedule(mut self, f: impl FnOnce(&mut Scheduler)) -> Self {
+        f(&mut self.scheduler);
+        self
+    }
+
     // ─── Routes ──────────────────────────────────────────────────────────────
 
-    /// Defines the application routes.
-    pub fn routes(mut self, router: Router) -> Self {
-        self.router = Some(router);
+    /// Defines the application routes — a `Router` or a
+    /// [`RouteGroup`](crate::macros::RouteGroup).
+    pub fn routes(mut self, router: impl Into<Router>) -> Self {
+        self.router = Some(router.into());
         self
     }
 
@@ -165,6 +333,23 @@ impl RuniqueAppBuilder {
         self
     }
 
+    /// Durée d'une session ouverte avec « se souvenir de moi »
+    /// (`login_user_full(.., remember = true)`). Défaut si non appelé : **30 jours**.
+    pub fn with_remember_me_duration(mut self, duration: Duration) -> Self {
+        self.middleware = self.middleware.with_remember_me_duration(duration);
+        self
+    }
+
+    /// Shortcut: configures response compression (see
+    /// [`MiddlewareStaging::with_compression`]).
+    pub fn with_compression(
+        mut self,
+        f: impl FnOnce(CompressionConfig) -> CompressionConfig,
+    ) -> Self {
+        self.middleware = self.middleware.with_compression(f);
+        self
+    }
+
     /// Shortcut: enables/disables debug error pages.
     pub fn with_error_handler(mut self, enable: bool) -> Self {
         self.middleware = self.middleware.with_debug_errors(enable);
@@ -202,6 +387,71 @@ impl RuniqueAppBuilder {
         self
     }
 
+    /// Installs a mailer backend — replaces the one set by `with_mailer*`.
+    ///
+    /// ```rust,ignore
+    /// let outbox = MemoryMailer::new();
+    /// builder::new(config).with_mailer_backend(outbox.clone())
+    /// ```
+    pub fn with_mailer_backend(self, mailer: impl crate::utils::mailer::Mailer) -> Self {
+        crate::utils::mailer::set_mailer(mailer);
+        self
+    }
+
+    /// Shortcut: stores uploaded media in `storage` — see [`StaticStaging::storage`].
+    ///
+    /// ```rust,ignore
+    /// builder::new(config).with_storage(S3Storage::new(S3Config::from_env()?))
+    /// ```
+    pub fn with_storage(mut self, storage: impl crate::utils::storage::Storage) -> Self {
+        self.statics = self.statics.storage(storage);
+        self
+    }
+
+    /// Shortcut: storage from `STORAGE_BACKEND` and the `S3_*` environment variables.
+    pub fn with_storage_from_env(mut self) -> Self {
+        self.statics = self.statics.storage_from_env();
+        self
+    }
+
+    /// Replaces the in-memory application cache (`engine.cache`) with `cache`.
+    ///
+    /// ```rust,ignore
+    /// builder::new(config).with_cache(RedisCache::new("redis://127.0.0.1/")?)
+    /// ```
+    pub fn with_cache(mut self, cache: impl Cache) -> Self {
+        self.cache = Ok(Some(CacheHandle::new(cache)));
+        self
+    }
+
+    /// Application cache from `CACHE_BACKEND` (`memory` / `redis`) and `REDIS_URL` —
+    /// an invalid value fails `build()`.
+    pub fn with_cache_from_env(mut self) -> Self {
+        self.cache = crate::cache::cache_from_env().map(Some);
+        self
+    }
+
+    /// Registers the `/healthz` (liveness) and `/readyz` (readiness: `SELECT 1` on the
+    /// database) probes, outside CSRF, allowed hosts and auth.
+    ///
+    /// ```rust,ignore
+    /// builder::new(config).with_health()
+    /// ```
+    pub fn with_health(self) -> Self {
+        self.with_health_config(|h| h)
+    }
+
+    /// Like [`with_health`](Self::with_health), with the paths, the timeout or extra
+    /// readiness checks configured via a closure.
+    ///
+    /// ```rust,ignore
+    /// .with_health_config(|h| h.timeout(Duration::from_secs(1)).check_cache().check_mailer())
+    /// ```
+    pub fn with_health_config(mut self, f: impl FnOnce(HealthConfig) -> HealthConfig) -> Self {
+        self.health = Some(f(HealthConfig::default()));
+        self
+    }
+
     /// Shortcut: enables the static files service (enabled by default).
     pub fn statics(mut self) -> Self {
         self.statics = self.statics.enable();
@@ -262,4 +512,26 @@ impl RuniqueAppBuilder {
         });
         self
     }
+
+    /// Logs out every session of a user when their
+    /// [`session_epoch`](crate::auth::user_trait::RuniqueUser::session_epoch) changes —
+    /// by default on password change.
+    /// ```rust,ignore
+    /// .with_session_epoch::<BuiltinUserEntity>()
+    /// ```
+    pub fn with_session_epoch<E: UserEntity + 'static>(self) -> Self {
+        crate::auth::epoch::register_session_epoch::<E>();
+        self
+    }
+
+    /// Stops every login (`login`, `login_user_full`, admin) at the TOTP step for
+    /// users of `E` with a [`totp_secret`](crate::auth::user_trait::RuniqueUser::totp_secret).
+    /// Implied by `DefaultAdminAuth<E>` and the `login_with_totp::<E>` helpers.
+    /// ```rust,ignore
+    /// .with_totp::<users::Entity>()
+    /// ```
+    pub fn with_totp<E: UserEntity + 'static>(self) -> Self {
+        crate::auth::totp::register_totp::<E>();
+        self
+    }
 }
@@ -0,0 +1,205 @@
+//! Liveness / readiness probes — `/healthz` and `/readyz`, enabled with `with_health()`.
+//!
+//! The routes are merged after the middleware stack: no CSRF, allowed hosts,
+//! session or auth check stands between a probe and its answer. Being unauthenticated,
+//! they only say which check failed; the error itself goes to the logs.
+use crate::utils::aliases::AEngine;
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+#[cfg(feature = "orm")]
+use sea_orm::ConnectionTrait;
+use serde_json::{Map, Value, json};
+use std::{future::Future, time::Duration};
+
+/// Probe settings, passed to `.with_health_config(|h| ...)`.
+///
+/// ```rust,ignore
+/// RuniqueApp::builder(config).with_health_config(|h| {
+///     h.timeout(Duration::from_secs(1)).check_cache().check_mailer()
+/// })
+/// ```
+#[derive(Debug, Clone)]
+pub struct HealthConfig {
+    pub(crate) liveness_path: String,
+    pub(crate) readiness_path: String,
+    pub(crate) timeout: Duration,
+    pub(crate) check_cache: bool,
+    pub(crate) check_mailer: bool,
+    pub(crate) expose_pool_stats: bool,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            liveness_path: "/healthz".to_string(),
+            readiness_path: "/readyz".to_string(),
+            timeout: Duration::from_secs(2),
+            check_cache: false,
+            check_mailer: false,
+            expose_pool_stats: false,
+        }
+    }
+}
+
+impl HealthConfig {
+    /// Liveness path — `/healthz` by default.
+    pub fn liveness_path(mut self, path: impl Into<String>) -> Self {
+        self.liveness_path = path.into();
+        self
+    }
+
+    /// Readiness path — `/readyz` by default.
+    pub fn readiness_path(mut self, path: impl Into<String>) -> Self {
+        self.readiness_path = path.into();
+        self
+    }
+
+    /// Time allowed to each readiness check — 2 seconds by default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Readiness also pings `engine.cache`.
+    pub fn check_cache(mut self) -> Self {
+        self.check_cache = true;
+        self
+    }
+
+    /// Readiness also pings the mailer — fails while none is configured.
+    pub fn check_mailer(mut self) -> Self {
+        self.check_mailer = true;
+        self
+    }
+
+    /// Readiness also reports the connection pool (`size`, `idle`, `in_use`) —
+    /// off by default, the probe being unauthenticated.
+    pub fn expose_pool_stats(mut self) -> Self {
+        self.expose_pool_stats = true;
+        self
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        for path in [&self.liveness_path, &self.readiness_path] {
+            if !path.starts_with('/') {
+                return Err(format!("path `{path}` must start with `/`"));
+            }
+        }
+        if self.liveness_path == self.readiness_path {
+            return Err("liveness and readiness paths must differ".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+struct HealthState {
+    engine: AEngine,
+    config: HealthConfig,
+}
+
+/// The two probe routes, to merge outside the middleware layers.
+pub(crate) fn health_router(config: HealthConfig, engine: AEngine) -> Router {
+    Router::new()
+        .route(&config.liveness_path, get(liveness))
+        .route(&config.readiness_path, get(readiness))
+        .with_state(HealthState { engine, config })
+}
+
+/// Probe answers must never be served from a cache.
+fn probe_response(status: StatusCode, body: Value) -> Response {
+    let mut res = (status, Json(body)).into_response();
+    res.headers_mut()
+        .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    res
+}
+
+/// `GET /healthz` — the process is up and serving requests.
+async fn liveness() -> Response {
+    probe_response(StatusCode::OK, json!({ "status": "ok" }))
+}
+
+async fn check<F>(timeout: Duration, check: F) -> Result<(), String>
+where
+    F: Future<Output = Result<(), String>>,
+{
+    tokio::time::timeout(timeout, check)
+        .await
+        .unwrap_or_else(|_| Err(format!("timed out after {timeout:?}")))
+}
+
+/// `GET /readyz` — `200` when every check passes, `503` with the name of the first
+/// failing check as `reason` otherwise. Error details are only logged.
+async fn readiness(State(state): State<HealthState>) -> Response {
+    let HealthState { engine, config } = state;
+    let mut results: Vec<(&'static str, Result<(), String>)> = Vec::new();
+
+    #[cfg(feature = "orm")]
+    results.push((
+        "database",
+        check(config.timeout, async {
+            engine
+                .db
+                .execute_unprepared("SELECT 1")
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        })
+        .await,
+    ));
+    if config.check_cache {
+        results.push((
+            "cache",
+            check(config.timeout, async {
+                engine.cache.ping().await.map_err(|e| e.to_string())
+            })
+            .await,
+        ));
+    }
+    if config.check_mailer {
+        results.push((
+            "mailer",
+            check(config.timeout, async {
+                match crate::utils::mailer::current_mailer() {
+                    Some(mailer) => mailer.ping().await,
+                    None => Err("no mailer configured".to_string()),
+                }
+            })
+            .await,
+        ));
+    }
+
+    let mut checks = Map::new();
+    let mut reason = None;
+    for (name, result) in results {
+        let value = match result {
+            Ok(()) => "ok",
+            Err(e) => {
+                tracing::warn!(check = name, error = %e, "readiness check failed");
+                reason.get_or_insert(name);
+                "unavailable"
+            }
+        };
+        checks.insert(name.to_string(), Value::String(value.to_string()));
+    }
+
+    let (status, mut body) = match reason {
+        None => (StatusCode::OK, json!({ "status": "ok", "checks": checks })),
+        Some(reason) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            json!({ "status": "unavailable", "reason": reason, "checks": checks }),
+        ),
+    };
+    #[cfg(feature = "orm")]
+    if config.expose_pool_stats
+        && let Some(stats) = crate::db::pool_stats(&engine.db)
+    {
+        body["pool"] = json!(stats);
+    }
+    probe_response(status, body)
+}
@@ -1,14 +1,19 @@
-//! App module — `RuniqueAppBuilder` constructor, final `RuniqueApp` application, build errors, and staging.
+//! App module — `RuniqueAppBuilder` constructor, final `RuniqueApp` application, build errors, health probes, debug route list, and staging.
 pub mod builder;
 pub mod error_build;
+pub mod health;
+pub mod route_list;
 pub mod runique_app;
 pub mod staging;
+pub mod template_reload;
 pub mod templates;
 
 pub use builder::RuniqueAppBuilder;
 pub use error_build::{BuildError, BuildErrorKind, CheckError, CheckReport};
+pub use health::HealthConfig;
 pub use runique_app::RuniqueApp;
 pub use staging::{
     AdminStaging, CoreStaging, CorsConfig, CspConfig, HostConfig, MiddlewareStaging,
     PermissionsPolicyConfig, StaticStaging, TrustedProxiesConfig,
 };
+pub use template_reload::LiveTera;
@@ -0,0 +1,133 @@
+//! Debug route list — `/__routes`, every named route with its pattern and methods.
+//!
+//! Mounted by `build()` when `config.debug` is true. Methods are read once at
+//! startup from the `Allow` header of a `405` answer: each pattern is probed on the
+//! application routes with a method no route declares, so no handler runs. Routes
+//! mounted by Runique (admin, password reset) sit behind their own layers and are
+//! listed without methods.
+use crate::context::Request;
+use crate::middleware::errors::error::html_escape;
+use crate::utils::aliases::AEngine;
+use axum::{
+    Json, Router,
+    body::Body,
+    extract::State,
+    http::{Method, Request as HttpRequest, StatusCode, header},
+    response::{Html, IntoResponse, Response},
+    routing::get,
+};
+use futures_util::FutureExt;
+use regex::Regex;
+use serde::Serialize;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, LazyLock};
+use tower::ServiceExt;
+
+/// Path of the route list.
+pub const ROUTE_LIST_PATH: &str = "/__routes";
+
+static PATH_PARAM: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\{\*?[^}/]+\}").unwrap());
+
+#[derive(Debug, Clone, Serialize)]
+struct RouteInfo {
+    name: String,
+    pattern: String,
+    methods: Vec<String>,
+}
+
+/// Methods accepted by `pattern` in `router` — empty when the pattern is not
+/// served by it (name registered for a route mounted elsewhere).
+async fn methods_of(router: &Router, pattern: &str) -> Vec<String> {
+    let uri = PATH_PARAM.replace_all(pattern, "_");
+    let Ok(method) = Method::from_bytes(b"RUNIQUE-PROBE") else {
+        return Vec::new();
+    };
+    let Ok(request) = HttpRequest::builder()
+        .method(method)
+        .uri(uri.as_ref())
+        .body(Body::empty())
+    else {
+        return Vec::new();
+    };
+    // A `.layer()` added by the application does run: a panic there must not abort the build
+    let Ok(Ok(response)) = AssertUnwindSafe(router.clone().oneshot(request))
+        .catch_unwind()
+        .await
+    else {
+        return Vec::new();
+    };
+    if response.status() != StatusCode::METHOD_NOT_ALLOWED {
+        return Vec::new();
+    }
+    response
+        .headers()
+        .get(header::ALLOW)
+        .and_then(|v| v.to_str().ok())
+        .map(|allow| allow.split(',').map(|m| m.trim().to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// The `/__routes` route — every name of the engine registry, methods probed on
+/// `routes`.
+pub(crate) async fn route_list_router(router: &Router, engine: &AEngine) -> Router {
+    let mut entries: Vec<(String, String)> = engine
+        .url_registry
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .map(|(name, pattern)| (name.clone(), pattern.clone()))
+        .collect();
+    entries.sort();
+
+    let mut routes = Vec::with_capacity(entries.len());
+    for (name, pattern) in entries {
+        let methods = methods_of(router, &pattern).await;
+        routes.push(RouteInfo {
+            name,
+            pattern,
+            methods,
+        });
+    }
+    Router::new()
+        .route(ROUTE_LIST_PATH, get(route_list))
+        .with_state(Arc::new(routes))
+}
+
+/// `GET /__routes` — HTML table, JSON when the client asks for it.
+async fn route_list(State(routes): State<Arc<Vec<RouteInfo>>>, req: Request) -> Response {
+    if req.wants_json() {
+        return Json(routes.as_ref()).into_response();
+    }
+    let rows: String = routes
+        .iter()
+        .map(|r| {
+            let methods = if r.methods.is_empty() {
+                "—".to_string()
+            } else {
+                r.methods.join(", ")
+            };
+            format!(
+                "<tr><td><code>{}</code></td><td><code>{}</code></td><td>{}</td></tr>",
+                html_escape(&r.name),
+                html_escape(&r.pattern),
+                html_escape(&methods),
+            )
+        })
+        .collect();
+    let nonce = req
+        .context
+        .get("csp_nonce")
+        .and_then(|n| n.as_str())
+        .unwrap_or_default();
+    Html(format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>Routes</title>\
+         <style nonce=\"{}\">body{{font-family:sans-serif;margin:2rem}}table{{border-collapse:collapse}}\
+         td,th{{border:1px solid #ccc;padding:.3rem .8rem;text-align:left}}</style></head>\
+         <body><h1>Named routes ({})</h1><table><thead><tr><th>Name</th><th>Pattern</th>\
+         <th>Methods</th></tr></thead><tbody>{}</tbody></table></body></html>",
+        html_escape(nonce),
+        routes.len(),
+        rows
+    ))
+    .into_response()
+}
@@ -9,6 +9,10 @@ use crate::utils::trad::{t, tf};
 
 use super::builder::RuniqueAppBuilder;
 
+/// Longest wait for the scheduled jobs, then the background task queue, on shutdown.
+#[cfg(feature = "orm")]
+const TASK_DRAIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
 // ═══════════════════════════════════════════════════════════════
 // RuniqueApp — Built application, ready to be launched
 // ═══════════════════════════════════════════════════════════════
@@ -87,6 +91,20 @@ impl RuniqueApp {
         })
         .await?;
 
+        // Periodic jobs stop first (a run may still enqueue tasks), then background
+        // tasks still queued get a bounded chance to finish
+        #[cfg(feature = "orm")]
+        if !self.engine.scheduler.shutdown(TASK_DRAIN_TIMEOUT).await {
+            tracing::warn!("shutdown: scheduled jobs still running");
+        }
+        #[cfg(feature = "orm")]
+        if !self.engine.tasks.drain_timeout(TASK_DRAIN_TIMEOUT).await {
+            tracing::warn!(
+                pending = self.engine.tasks.pending(),
+                "shutdown: background tasks abandoned"
+            );
+        }
+
         Ok(())
     }
 
@@ -3,7 +3,10 @@
 use std::sync::Arc;
 
 use crate::admin::PrototypeAdminState;
-use crate::admin::{AdminConfig, helper::AdminTemplate};
+use crate::admin::{
+    AdminConfig,
+    helper::{AdminTemplate, BulkAction, ComputedField},
+};
 use crate::app::error_build::{BuildError, CheckError, CheckReport};
 use crate::auth::{guard::LoginGuard, session::AdminAuth};
 use crate::middleware::security::RateLimiter;
@@ -102,6 +105,39 @@ impl AdminStaging {
         self
     }
 
+    /// Adds a custom bulk action to a resource. The closure receives the selected
+    /// IDs and returns the summary flashed to the user.
+    ///
+    /// ```rust,ignore
+    /// .with_admin(|a| a.bulk_action(
+    ///     "blog",
+    ///     BulkAction::new("publish", "Publier", |db, ids| async move {
+    ///         let n = blog::publish_many(&db, &ids).await?;
+    ///         Ok(format!("{n} article(s) publié(s)."))
+    ///     }),
+    /// ))
+    /// ```
+    pub fn bulk_action(mut self, resource_key: &str, action: BulkAction) -> Self {
+        self.config = self.config.bulk_action(resource_key, action);
+        self
+    }
+
+    /// Adds a computed column to a resource, shown in the list and detail views.
+    /// The closure receives the serialized row.
+    ///
+    /// ```rust,ignore
+    /// .with_admin(|a| a.computed_field(
+    ///     "users",
+    ///     ComputedField::new("full_name", "Nom complet", |row| {
+    ///         format!("{} {}", row["first_name"].as_str().unwrap_or(""), row["last_name"].as_str().unwrap_or(""))
+    ///     }),
+    /// ))
+    /// ```
+    pub fn computed_field(mut self, resource_key: &str, field: ComputedField) -> Self {
+        self.config = self.config.computed_field(resource_key, field);
+        self
+    }
+
     pub fn hot_reload(mut self, enabled: bool) -> Self {
         self.config = self.config.hot_reload(enabled);
         self
@@ -129,6 +165,12 @@ impl AdminStaging {
         self
     }
 
+    /// Records admin creates / edits / deletes in `eihwaz_history` (default: true).
+    pub fn history(mut self, enabled: bool) -> Self {
+        self.config = self.config.history(enabled);
+        self
+    }
+
     /// Connects the admin authentication handler.
     ///
     /// ## With built-in User (zero config):
@@ -0,0 +1,98 @@
+use crate::middleware::compression::{
+    ALREADY_COMPRESSED, CompressionPredicate, compressed_etag_middleware,
+};
+use axum::{Router, middleware};
+use tower_http::compression::CompressionLayer;
+
+/// Response compression configuration passed via closure to `.with_compression(|c| { ... })`.
+///
+/// Negotiated through `Accept-Encoding` (brotli, gzip). Enabled by default in release,
+/// disabled in debug (`RUNIQUE_ENABLE_COMPRESSION` overrides both).
+/// Images, video, audio, archives, fonts and SSE streams are never compressed.
+///
+/// # Example
+/// ```rust,ignore
+/// .middleware(|m| {
+///     m.with_compression(|c| {
+///         c.min_size(2048)
+///          .brotli(false)
+///          .exclude("application/pdf")
+///     })
+/// })
+/// ```
+///
+/// # Disable
+/// ```rust,ignore
+/// m.with_compression(|c| c.enabled(false))
+/// ```
+#[derive(Clone)]
+pub struct CompressionConfig {
+    pub(crate) enabled: bool,
+    pub(crate) min_size: u16,
+    pub(crate) gzip: bool,
+    pub(crate) brotli: bool,
+    pub(crate) excluded: Vec<String>,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_size: 1024,
+            gzip: true,
+            brotli: true,
+            excluded: ALREADY_COMPRESSED.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl CompressionConfig {
+    /// Enables or disables compression.
+    pub fn enabled(mut self, enable: bool) -> Self {
+        self.enabled = enable;
+        self
+    }
+
+    /// Responses smaller than `bytes` are sent as is (default: 1024).
+    /// Responses of unknown size (streams) are always compressed.
+    pub fn min_size(mut self, bytes: u16) -> Self {
+        self.min_size = bytes;
+        self
+    }
+
+    /// Offers gzip (default: true).
+    pub fn gzip(mut self, enable: bool) -> Self {
+        self.gzip = enable;
+        self
+    }
+
+    /// Offers brotli (default: true).
+    pub fn brotli(mut self, enable: bool) -> Self {
+        self.brotli = enable;
+        self
+    }
+
+    /// Never compresses content types starting with `prefix` (callable multiple times).
+    pub fn exclude(mut self, prefix: impl Into<String>) -> Self {
+        self.excluded.push(prefix.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Wraps `router` with the `tower_http` compression layer, then with
+    /// [`compressed_etag_middleware`] so a compressed body never keeps a strong `ETag`.
+    /// `Content-Length` is dropped by the layer whenever the body is re-encoded.
+    pub fn apply(&self, router: Router) -> Router {
+        let layer = CompressionLayer::new()
+            .gzip(self.gzip)
+            .br(self.brotli)
+            .no_deflate()
+            .no_zstd()
+            .compress_when(CompressionPredicate::new(
+                self.min_size,
+                self.excluded.iter().cloned(),
+            ));
+        router
+            .layer(layer)
+            .layer(middleware::from_fn(compressed_etag_middleware))
+    }
+}
@@ -119,6 +119,13 @@ impl CoreStaging {
             );
         }
 
+        #[cfg(feature = "orm")]
+        if let Some(Err(e)) = self.db_config.as_ref().map(DatabaseConfig::validate) {
+            report.add(CheckError::new("Database", e).with_suggestion(
+                "Set DB_MIN_CONNECTIONS to at most DB_MAX_CONNECTIONS (and DB_MAX_CONNECTIONS above 0)",
+            ));
+        }
+
         if report.has_errors() {
             return Err(BuildError::check(report));
         }
@@ -1,13 +1,22 @@
+use axum::http::{HeaderName, HeaderValue, Method};
+use std::time::Duration;
+use tower_http::cors::{AllowHeaders, AllowMethods, AllowOrigin, Any, CorsLayer};
+
 /// CORS configuration passed via closure to `.with_cors(|c| { ... })`.
 ///
 /// Disabled by default — explicitly configure origins.
-/// Validation at build time: wildcard origin + `allow_credentials(true)` = BuildError.
+/// Validation at build time: wildcard origin + `allow_credentials(true)` = BuildError,
+/// as well as any origin, method or header that is not a valid HTTP value.
 ///
 /// # Example — frontend séparé
 /// ```rust,ignore
 /// .middleware(|m| {
 ///     m.with_cors(|c| {
 ///         c.origin("https://app.monsite.com")
+///          .method("GET")
+///          .method("POST")
+///          .header("content-type")
+///          .header("x-csrf-token")
 ///          .allow_credentials(true)
 ///     })
 /// })
@@ -20,6 +29,8 @@
 #[derive(Default)]
 pub struct CorsConfig {
     pub(crate) origins: Vec<String>,
+    pub(crate) methods: Vec<String>,
+    pub(crate) headers: Vec<String>,
     pub(crate) allow_credentials: bool,
     pub(crate) max_age_secs: u64,
 }
@@ -37,6 +48,23 @@ impl CorsConfig {
         self
     }
 
+    /// Méthodes autorisées en cross-origin (appelable plusieurs fois).
+    ///
+    /// Sans appel : toutes les méthodes — reflétées depuis le preflight
+    /// quand `allow_credentials(true)`, `*` sinon.
+    pub fn method(mut self, method: impl Into<String>) -> Self {
+        self.methods.push(method.into().to_ascii_uppercase());
+        self
+    }
+
+    /// Headers de requête autorisés en cross-origin (appelable plusieurs fois).
+    ///
+    /// Sans appel : tous les headers, selon la même règle que [`method`](Self::method).
+    pub fn header(mut self, header: impl Into<String>) -> Self {
+        self.headers.push(header.into().to_ascii_lowercase());
+        self
+    }
+
     /// Autorise les cookies et headers d'auth cross-origin.
     /// Interdit si `any_origin()` est configuré — BuildError au démarrage.
     pub fn allow_credentials(mut self, allow: bool) -> Self {
@@ -53,4 +81,79 @@ impl CorsConfig {
     pub(crate) fn is_wildcard(&self) -> bool {
         self.origins.iter().any(|o| o == "*")
     }
+
+    /// Origines explicites — celles qui peuvent aussi ouvrir un WebSocket.
+    pub(crate) fn trusted_origins(&self) -> Vec<String> {
+        self.origins.iter().filter(|o| *o != "*").cloned().collect()
+    }
+
+    /// Première méthode, header ou origine invalide, pour le `BuildError` de validation.
+    pub(crate) fn invalid_entry(&self) -> Option<String> {
+        let origins = self
+            .origins
+            .iter()
+            .filter(|o| *o != "*")
+            .find(|o| HeaderValue::from_str(o).is_err());
+        let methods = self
+            .methods
+            .iter()
+            .find(|m| Method::from_bytes(m.as_bytes()).is_err());
+        let headers = self
+            .headers
+            .iter()
+            .find(|h| HeaderName::from_bytes(h.as_bytes()).is_err());
+        origins.or(methods).or(headers).cloned()
+    }
+
+    /// Builds the `tower_http` layer: preflight `OPTIONS` answered directly, origin
+    /// echoed only when allowlisted.
+    pub fn layer(&self) -> CorsLayer {
+        let max_age = Duration::from_secs(if self.max_age_secs > 0 {
+            self.max_age_secs
+        } else {
+            3600
+        });
+
+        // `*` is forbidden by browsers alongside credentials: mirror the preflight instead
+        let methods = if !self.methods.is_empty() {
+            AllowMethods::list(
+                self.methods
+                    .iter()
+                    .filter_map(|m| Method::from_bytes(m.as_bytes()).ok()),
+            )
+        } else if self.allow_credentials {
+            AllowMethods::mirror_request()
+        } else {
+            AllowMethods::from(Any)
+        };
+        let headers = if !self.headers.is_empty() {
+            AllowHeaders::list(
+                self.headers
+                    .iter()
+                    .filter_map(|h| HeaderName::from_bytes(h.as_bytes()).ok()),
+            )
+        } else if self.allow_credentials {
+            AllowHeaders::mirror_request()
+        } else {
+            AllowHeaders::from(Any)
+        };
+
+        let layer = CorsLayer::new()
+            .allow_methods(methods)
+            .allow_headers(headers)
+            .max_age(max_age);
+
+        if self.is_wildcard() {
+            layer.allow_origin(Any)
+        } else {
+            let origins: Vec<HeaderValue> = self
+                .origins
+                .iter()
+                .filter_map(|o| HeaderValue::from_str(o).ok())
+                .collect();
+            layer
+                .allow_origin(AllowOrigin::list(origins))
+                .allow_credentials(self.allow_credentials)
+        }
+    }
 }
@@ -21,6 +21,7 @@ use crate::middleware::SecurityPolicy;
 // TOGGLES:
 //   .with_header_security(bool) → HSTS, X-Frame-Options, COEP, COOP, CORP...
 //   .with_nonce(bool)           → CSP nonce per request
+//   .nonce_for("style")         → restrict the nonce to script-src / style-src
 //   .with_upgrade_insecure(bool)→ upgrade-insecure-requests
 //
 // PRESET:
@@ -91,6 +92,25 @@ impl CspConfig {
         self
     }
 
+    /// Restricts the nonce to the given directive (`"script-src"` / `"style-src"`,
+    /// or the short `"script"` / `"style"`); call it once per directive.
+    /// Without any call, both directives get the nonce. Enables the nonce.
+    ///
+    /// ```rust,ignore
+    /// c.nonce_for("script").nonce_for("style")
+    /// ```
+    pub fn nonce_for(mut self, directive: &str) -> Self {
+        let directive = match directive {
+            "script" | "style" => format!("{directive}-src"),
+            other => other.to_string(),
+        };
+        if !self.policy.nonce_directives.contains(&directive) {
+            self.policy.nonce_directives.push(directive);
+        }
+        self.policy.use_nonce = true;
+        self
+    }
+
     /// Enables or disables `upgrade-insecure-requests`.
     pub fn with_upgrade_insecure(mut self, enable: bool) -> Self {
         self.policy.upgrade_insecure_requests = enable;
@@ -27,21 +27,22 @@
 //!   the lowest slot is applied LAST (.layer) = the most EXTERNAL
 //!
 //! RESULT on an incoming request:
-//!   → Extensions(0) → TrustedProxies(2) → CORS(8) → ErrorHandler(10) → Custom(20+)
-//!   → OpenRedirect(25) → CSP(30) → Cache(40) → Session(50) → CSRF(60)
-//!   → Host(70) → Handler
+//!   → Extensions(0) → RequestId(1) → TrustedProxies(2) → Compression(5) → CORS(8)
+//!   → ErrorHandler(10) → AppendSlash(12) → Custom(20+) → OpenRedirect(25) → CSP(30)
+//!   → Cache(40) → Session(50) → RateLimit(58) → CSRF(60) → Host(70) → Transaction(75)
+//!   → Handler
 
 use crate::context::RequestExtensions;
 use crate::middleware::session::CleaningMemoryStore;
 use crate::middleware::{
-    allowed_hosts_middleware, anti_bot_middleware, csp_middleware, csrf_middleware,
-    dev_no_cache_middleware, error_handler_middleware, open_redirect_middleware,
-    security_headers_middleware, trusted_proxies_middleware,
+    allowed_hosts_middleware, anti_bot_middleware, append_slash_middleware, csp_middleware,
+    csrf_middleware, dev_no_cache_middleware, error_handler_middleware, open_redirect_middleware,
+    rate_limit_middleware, request_id_middleware, security_headers_middleware,
+    trusted_proxies_middleware,
 };
-use crate::utils::aliases::{AEngine, ARuniqueConfig, ATera};
+use crate::utils::aliases::{AEngine, ARuniqueConfig};
 use axum::{self, Router, middleware};
 use std::sync::Arc;
-use tower_http::compression::CompressionLayer;
 use tower_sessions::cookie::time::Duration;
 use tower_sessions::{Expiry, SessionManagerLayer};
 
@@ -50,10 +51,12 @@ use super::MiddlewareStaging;
 // ─── Built-in slots — Guaranteed execution order on the request ───────────────
 
 const SLOT_EXTENSIONS: u16 = 0; // Engine/Tera/Config injection (outermost)
+const SLOT_REQUEST_ID: u16 = 1; // Span + id around everything that logs, error pages included
 const SLOT_TRUSTED_PROXIES: u16 = 2; // Real client IP extraction — before everything
 const SLOT_COMPRESSION: u16 = 5; // Compression (external, before any other middleware)
 const SLOT_CORS: u16 = 8; // Outside ErrorHandler — OPTIONS preflight never reaches CSRF
 const SLOT_ERROR_HANDLER: u16 = 10; // Catches errors of the WHOLE stack
+const SLOT_APPEND_SLASH: u16 = 12; // Inside ErrorHandler — redirects before a 404 page is rendered
 const SLOT_CUSTOM_BASE: u16 = 20; // Dev's custom middlewares start here
 const SLOT_OPEN_REDIRECT: u16 = 25; // After custom, before CSP — wraps response inspection
 const SLOT_SECURITY_HEADERS: u16 = 30;
@@ -62,9 +65,12 @@ const SLOT_CACHE: u16 = 40;
 const SLOT_SESSION: u16 = 50; // Before CSRF (CSRF depends on it)
 const SLOT_SESSION_UPGRADE: u16 = 55; // After Session (reads/writes in session)
 const SLOT_AUTH: u16 = 57; // After Session — loads CurrentUser from the session
+const SLOT_RATE_LIMIT: u16 = 58; // After Session (session key) — rejects before CSRF
 const SLOT_CSRF: u16 = 60; // After Session (reads/writes in session)
 const SLOT_ANTI_BOT: u16 = 65; // After CSRF — injects honeypot field name extension
 const SLOT_HOST_VALIDATION: u16 = 70; // Last defense before handler
+#[cfg(feature = "orm")]
+const SLOT_TRANSACTION: u16 = 75; // Innermost — settles the handler's transaction from its response
 
 // ─── MiddlewareEntry ──────────────────────────────────────────────────────────
 
@@ -90,7 +96,6 @@ impl MiddlewareStaging {
         router: Router,
         config: ARuniqueConfig,
         engine: AEngine,
-        tera: ATera,
     ) -> (Router, Option<Arc<CleaningMemoryStore>>) {
         let debug = config.debug;
         let mut entries: Vec<MiddlewareEntry> = Vec::new();
@@ -98,7 +103,6 @@ impl MiddlewareStaging {
         // Slot 0: Extensions (Engine, Tera, Config) — outermost
         {
             let eng = engine.clone();
-            let t = tera.clone();
             let c = config.clone();
             entries.push(MiddlewareEntry {
                 slot: SLOT_EXTENSIONS,
@@ -108,7 +112,7 @@ impl MiddlewareStaging {
                         move |mut req: axum::http::Request<axum::body::Body>,
                               next: axum::middleware::Next| {
                             let extensions = RequestExtensions::new()
-                                .with_tera(t.clone())
+                                .with_tera(eng.tera.current())
                                 .with_config(c.clone())
                                 .with_engine(eng.clone());
                             extensions.inject_request(&mut req);
@@ -119,6 +123,24 @@ impl MiddlewareStaging {
             });
         }
 
+        // Slot 1: Request id — its span wraps the error handler and every later log line
+        if let Some(header) = self
+            .request_id_header
+            .as_deref()
+            .and_then(|h| axum::http::HeaderName::try_from(h).ok())
+        {
+            entries.push(MiddlewareEntry {
+                slot: SLOT_REQUEST_ID,
+                name: "RequestId",
+                apply: Box::new(move |r| {
+                    r.layer(middleware::from_fn_with_state(
+                        header,
+                        request_id_middleware,
+                    ))
+                }),
+            });
+        }
+
         // Slot 2: Trusted proxies — extract real client IP from X-Forwarded-For
         {
             let eng = engine.clone();
@@ -134,45 +156,19 @@ impl MiddlewareStaging {
             });
         }
 
-        // Slot 5: Compression — before any other middleware
-        entries.push(MiddlewareEntry {
-            slot: SLOT_COMPRESSION,
-            name: "Compression",
-            apply: Box::new(|r| r.layer(CompressionLayer::new())),
-        });
+        // Slot 5: Compression — outside every middleware that writes the body or its headers
+        if self.features.enable_compression {
+            let compression = self.compression_config.clone();
+            entries.push(MiddlewareEntry {
+                slot: SLOT_COMPRESSION,
+                name: "Compression",
+                apply: Box::new(move |r| compression.apply(r)),
+            });
+        }
 
         // Slot 8: CORS — outside ErrorHandler so OPTIONS preflight never reaches CSRF/Session
         if let Some(cors) = self.cors_config {
-            use axum::http::HeaderValue;
-            use std::time::Duration as StdDuration;
-            use tower_http::cors::{AllowOrigin, CorsLayer};
-
-            let max_age = StdDuration::from_secs(if cors.max_age_secs > 0 {
-                cors.max_age_secs
-            } else {
-                3600
-            });
-
-            let layer = if cors.is_wildcard() {
-                CorsLayer::new()
-                    .allow_origin(tower_http::cors::Any)
-                    .allow_methods(tower_http::cors::Any)
-                    .allow_headers(tower_http::cors::Any)
-                    .max_age(max_age)
-            } else {
-                let origins: Vec<HeaderValue> = cors
-                    .origins
-                    .iter()
-                    .filter_map(|o| HeaderValue::from_str(o).ok())
-                    .collect();
-                CorsLayer::new()
-                    .allow_origin(AllowOrigin::list(origins))
-                    .allow_methods(tower_http::cors::Any)
-                    .allow_headers(tower_http::cors::Any)
-                    .allow_credentials(cors.allow_credentials)
-                    .max_age(max_age)
-            };
-
+            let layer = cors.layer();
             entries.push(MiddlewareEntry {
                 slot: SLOT_CORS,
                 name: "CORS",
@@ -207,6 +203,22 @@ impl MiddlewareStaging {
             });
         }
 
+        // Slot 75: Transaction — idle until a handler extracts `Transaction`
+        #[cfg(feature = "orm")]
+        {
+            let db = engine.db.clone();
+            entries.push(MiddlewareEntry {
+                slot: SLOT_TRANSACTION,
+                name: "Transaction",
+                apply: Box::new(move |r| {
+                    r.layer(middleware::from_fn_with_state(
+                        db,
+                        crate::db::transaction::transaction_middleware,
+                    ))
+                }),
+            });
+        }
+
         // Slot 50: Session — before CSRF (CSRF depends on it)
         let memory_store: Option<Arc<CleaningMemoryStore>> = {
             let applicator = self.session_applicator;
@@ -256,6 +268,22 @@ impl MiddlewareStaging {
             store_arc
         };
 
+        // Slot 58: Rate limit — after Session so the session id can be the key
+        if let Some(limiter) = self.rate_limiter {
+            let limiter = Arc::new(limiter);
+            limiter.spawn_cleanup(limiter.window.max(std::time::Duration::from_secs(1)));
+            entries.push(MiddlewareEntry {
+                slot: SLOT_RATE_LIMIT,
+                name: "RateLimit",
+                apply: Box::new(move |r| {
+                    r.layer(middleware::from_fn_with_state(
+                        limiter,
+                        rate_limit_middleware,
+                    ))
+                }),
+            });
+        }
+
         // Slot 60: CSRF — ALWAYS enabled, after Session
         {
             let eng = engine.clone();
@@ -324,16 +352,20 @@ impl MiddlewareStaging {
 
         // Slot 55: Upgrade session TTL if authenticated
         {
-            let duration = self.session_duration;
+            let ttl = SessionTtl {
+                standard: self.session_duration,
+                remember: self.remember_me_duration,
+            };
             // Source unique : login lit la même valeur (builder) que ce middleware,
             // via le OnceLock posé ici → cookie/DB/refresh ne divergent plus (AM3).
-            crate::auth::session::set_auth_session_ttl_secs(duration.whole_seconds());
+            crate::auth::session::set_auth_session_ttl_secs(ttl.standard.whole_seconds());
+            crate::auth::session::set_remember_me_ttl_secs(ttl.remember.whole_seconds());
             entries.push(MiddlewareEntry {
                 slot: SLOT_SESSION_UPGRADE,
                 name: "SessionTtlUpgrade",
                 apply: Box::new(move |r| {
                     r.layer(axum::middleware::from_fn_with_state(
-                        duration,
+                        ttl,
                         session_ttl_upgrade,
                     ))
                 }),
@@ -356,6 +388,18 @@ impl MiddlewareStaging {
             });
         }
 
+        // Slot 12: Append slash — sees the 404 before the error page replaces it
+        if self.features.append_slash {
+            let eng = engine.clone();
+            entries.push(MiddlewareEntry {
+                slot: SLOT_APPEND_SLASH,
+                name: "AppendSlash",
+                apply: Box::new(move |r| {
+                    r.layer(middleware::from_fn_with_state(eng, append_slash_middleware))
+                }),
+            });
+        }
+
         // Custom middlewares: automatically placed between ErrorHandler and CSP (slots 20+)
         for (i, custom_mw) in self.custom_middlewares.into_iter().enumerate() {
             entries.push(MiddlewareEntry {
@@ -391,15 +435,28 @@ impl MiddlewareStaging {
 
 // ─── Private middleware handlers ──────────────────────────────────────────────
 
-/// Upgrades session TTL to the authenticated duration when the user is logged in.
+/// Authenticated session durations: standard and "remember me".
+#[derive(Clone, Copy)]
+struct SessionTtl {
+    standard: Duration,
+    remember: Duration,
+}
+
+/// Upgrades session TTL to the authenticated duration when the user is logged in
+/// (the "remember me" duration if the session was opened with it).
 async fn session_ttl_upgrade(
-    axum::extract::State(duration): axum::extract::State<Duration>,
+    axum::extract::State(ttl): axum::extract::State<SessionTtl>,
     req: axum::http::Request<axum::body::Body>,
     next: axum::middleware::Next,
 ) -> axum::response::Response {
     if let Some(session) = req.extensions().get::<tower_sessions::Session>()
         && crate::auth::session::is_authenticated(session).await
     {
+        let duration = if crate::auth::session::is_remembered(session).await {
+            ttl.remember
+        } else {
+            ttl.standard
+        };
         session.set_expiry(Some(Expiry::OnInactivity(duration)));
     }
     next.run(req).await
@@ -1,14 +1,18 @@
 //! Middleware staging: configuration API for the middleware stack.
 mod applicator;
 
+use super::compression_config::CompressionConfig;
 use super::cors_config::CorsConfig;
 use super::csp_config::CspConfig;
 use super::host_config::HostConfig;
 use super::permissions_policy_config::PermissionsPolicyConfig;
+use super::security_headers_config::SecurityHeadersConfig;
 use super::trusted_proxies_config::TrustedProxiesConfig;
 use crate::app::error_build::BuildError;
 use crate::config::RuniqueConfig;
-use crate::middleware::{MiddlewareConfig, PermissionsPolicy, SecurityPolicy};
+use crate::middleware::{
+    MiddlewareConfig, PermissionsPolicy, RateLimiter, SecurityHeaders, SecurityPolicy,
+};
 use axum::Router;
 use tower_sessions::cookie::time::Duration;
 use tower_sessions::{Expiry, SessionManagerLayer, SessionStore};
@@ -33,6 +37,9 @@ pub struct MiddlewareStaging {
     /// Inactivity duration before session expiration
     pub(crate) session_duration: Duration,
 
+    /// Inactivity duration of a session opened with "remember me"
+    pub(crate) remember_me_duration: Duration,
+
     /// Anonymous session duration => anonymous session lifetime
     pub(crate) anonymous_session_duration: Duration,
     /// Custom session applicator (None = default MemoryStore)
@@ -57,16 +64,25 @@ pub struct MiddlewareStaging {
     /// Allowed hosts defined via the builder
     pub(crate) allowed_hosts: Vec<String>,
 
+    /// Compression settings (on/off lives in `features.enable_compression`)
+    pub(crate) compression_config: CompressionConfig,
+
     /// CORS configuration (None = CORS désactivé)
     pub(crate) cors_config: Option<CorsConfig>,
     /// Chemins exemptés de la validation CSRF (ex: endpoints webhook)
     pub(crate) csrf_exempt_paths: Vec<String>,
     /// Permissions-Policy header configuration (None = default secure preset)
     pub(crate) permissions_policy: Option<PermissionsPolicy>,
+    /// HSTS / Referrer-Policy / Permissions-Policy toggles (None = strict defaults)
+    pub(crate) security_headers: Option<SecurityHeaders>,
     /// Trusted proxies configuration (None = default: private networks)
     pub(crate) trusted_proxies_config: Option<TrustedProxiesConfig>,
     /// Anti-bot honeypot middleware (false by default)
     pub(crate) anti_bot: bool,
+    /// Application-wide rate limiter (None = disabled)
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    /// Request id header (None = request id middleware disabled)
+    pub(crate) request_id_header: Option<String>,
 }
 
 impl MiddlewareStaging {
@@ -81,6 +97,7 @@ impl MiddlewareStaging {
         Self {
             features,
             session_duration: Duration::seconds(86400),
+            remember_me_duration: Duration::days(30),
             anonymous_session_duration: Duration::seconds(300),
             session_applicator: None,
             session_low_watermark: 128 * 1024 * 1024,
@@ -90,11 +107,15 @@ impl MiddlewareStaging {
             custom_middlewares: Vec::new(),
             security_policy: None,
             allowed_hosts: Vec::new(),
+            compression_config: CompressionConfig::default(),
             cors_config: None,
             csrf_exempt_paths: Vec::new(),
             permissions_policy: None,
+            security_headers: None,
             trusted_proxies_config: None,
             anti_bot: false,
+            rate_limiter: None,
+            request_id_header: None,
         }
     }
 
@@ -133,12 +154,18 @@ impl MiddlewareStaging {
             enable_host_validation: false,
             enable_debug_errors: true, // always mounted — config.debug manages the content
             enable_cache: get_env_or("RUNIQUE_ENABLE_CACHE", defaults.enable_cache),
+            enable_compression: get_env_or(
+                "RUNIQUE_ENABLE_COMPRESSION",
+                defaults.enable_compression,
+            ),
+            append_slash: get_env_or("RUNIQUE_APPEND_SLASH", defaults.append_slash),
             exclusive_login: false, // propagated via `apply_to_router` from `self.exclusive_login`
         };
 
         Self {
             features,
             session_duration: Duration::seconds(86400),
+            remember_me_duration: Duration::days(30),
             anonymous_session_duration: Duration::seconds(300),
             session_applicator: None,
             session_low_watermark: 128 * 1024 * 1024,
@@ -148,11 +175,15 @@ impl MiddlewareStaging {
             custom_middlewares: Vec::new(),
             security_policy: None,
             allowed_hosts: Vec::new(),
+            compression_config: CompressionConfig::default(),
             cors_config: None,
             csrf_exempt_paths: Vec::new(),
             permissions_policy: None,
+            security_headers: None,
             trusted_proxies_config: None,
             anti_bot: false,
+            rate_limiter: None,
+            request_id_header: None,
         }
     }
 
@@ -245,6 +276,39 @@ impl MiddlewareStaging {
         self
     }
 
+    /// Configures response compression via a closure.
+    ///
+    /// Enabled by default in release, disabled in debug. Calling this method enables
+    /// it unless the closure calls `.enabled(false)`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .middleware(|m| m.with_compression(|c| c.min_size(2048).exclude("application/pdf")))
+    /// ```
+    pub fn with_compression(
+        mut self,
+        f: impl FnOnce(CompressionConfig) -> CompressionConfig,
+    ) -> Self {
+        let config = f(CompressionConfig::default());
+        self.features.enable_compression = config.enabled;
+        self.compression_config = config;
+        self
+    }
+
+    /// Redirects a `404` to the same path with the trailing slash added or removed
+    /// when that path is a named route: `/about` → `/about/`. `301` for `GET`/`HEAD`,
+    /// `307` otherwise; the query string is kept. Also enabled by
+    /// `RUNIQUE_APPEND_SLASH=true`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .middleware(|m| m.with_append_slash(true))
+    /// ```
+    pub fn with_append_slash(mut self, enable: bool) -> Self {
+        self.features.append_slash = enable;
+        self
+    }
+
     // ═══════════════════════════════════════════════════
     // Session configuration
     // ═══════════════════════════════════════════════════
@@ -255,6 +319,13 @@ impl MiddlewareStaging {
         self
     }
 
+    /// Configures the inactivity duration of sessions opened with "remember me"
+    /// (`login_user_full(.., remember = true)`) — default: 30 days
+    pub fn with_remember_me_duration(mut self, duration: Duration) -> Self {
+        self.remember_me_duration = duration;
+        self
+    }
+
     /// Configures the inactivity duration before anonymous session expiration
     pub fn with_anonymous_session_duration(mut self, duration: Duration) -> Self {
         self.anonymous_session_duration = duration;
@@ -353,6 +424,51 @@ impl MiddlewareStaging {
         self
     }
 
+    /// Rate-limits every request of the application (429 + `Retry-After`).
+    ///
+    /// Runs after the session, so [`RateLimitKey::Session`](crate::middleware::RateLimitKey)
+    /// can key on the session id. For a single route, prefer `.rate_limit(...)` on the router.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .middleware(|m| {
+    ///     m.with_rate_limit(
+    ///         RateLimiter::new()
+    ///             .max_requests(100)
+    ///             .retry_after(60)
+    ///             .key_by(RateLimitKey::Session),
+    ///     )
+    /// })
+    /// ```
+    pub fn with_rate_limit(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Tags every request with an id: the incoming `X-Request-Id` when present,
+    /// a UUID v4 otherwise. The id is echoed in the response, carried by the
+    /// request's tracing span, and available as `req.request_id` and `{{ request_id }}`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .middleware(|m| m.with_request_id())
+    /// ```
+    pub fn with_request_id(self) -> Self {
+        self.with_request_id_header(crate::middleware::DEFAULT_REQUEST_ID_HEADER)
+    }
+
+    /// Like [`with_request_id`](Self::with_request_id), reading and echoing `header`
+    /// instead of `X-Request-Id`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .middleware(|m| m.with_request_id_header("X-Correlation-Id"))
+    /// ```
+    pub fn with_request_id_header(mut self, header: impl Into<String>) -> Self {
+        self.request_id_header = Some(header.into());
+        self
+    }
+
     // ═══════════════════════════════════════════════════
     // CSRF exemptions
     // ═══════════════════════════════════════════════════
@@ -363,10 +479,14 @@ impl MiddlewareStaging {
     /// (webhooks Stripe, GitHub, etc.) — ces endpoints doivent implémenter
     /// leur propre vérification de signature en remplacement du CSRF.
     ///
+    /// Un chemin est exact ; un motif terminé par `*` exempte tout ce qui
+    /// commence par le préfixe (`/webhook/*`). L'exemption ne porte que sur la
+    /// validation : le jeton reste injecté dans la requête et la réponse.
+    ///
     /// # Example
     /// ```rust,ignore
     /// .middleware(|m| {
-    ///     m.csrf_exempt(vec!["/webhook/stripe", "/webhook/github"])
+    ///     m.csrf_exempt(vec!["/webhook/stripe", "/hooks/*"])
     /// })
     /// ```
     pub fn csrf_exempt(mut self, paths: Vec<impl Into<String>>) -> Self {
@@ -430,6 +550,28 @@ impl MiddlewareStaging {
         self
     }
 
+    /// Configures HSTS, Referrer-Policy and the Permissions-Policy toggle via a closure.
+    ///
+    /// Starts from strict defaults; HSTS is only sent over HTTPS and not in debug.
+    /// Any header can be omitted (`None` / `false`).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// .middleware(|m| {
+    ///     m.with_security_headers(|h| {
+    ///         h.hsts(Some(Hsts::new(63_072_000).include_subdomains(true)))
+    ///          .referrer_policy(Some(ReferrerPolicy::NoReferrer))
+    ///     })
+    /// })
+    /// ```
+    pub fn with_security_headers(
+        mut self,
+        f: impl FnOnce(SecurityHeadersConfig) -> SecurityHeadersConfig,
+    ) -> Self {
+        self.security_headers = Some(f(SecurityHeadersConfig::default()).build());
+        self
+    }
+
     // ═══════════════════════════════════════════════════
     // Trusted Proxies
     // ═══════════════════════════════════════════════════
@@ -476,6 +618,18 @@ impl MiddlewareStaging {
                  Use explicit origins instead: .origin(\"https://app.example.com\")",
             ));
         }
+        if let Some(invalid) = self.cors_config.as_ref().and_then(|c| c.invalid_entry()) {
+            return Err(BuildError::validation(format!(
+                "CORS: invalid origin, method or header: {invalid:?}"
+            )));
+        }
+        if let Some(header) = &self.request_id_header
+            && axum::http::HeaderName::try_from(header.as_str()).is_err()
+        {
+            return Err(BuildError::validation(format!(
+                "request id: invalid header name {header:?}"
+            )));
+        }
         Ok(())
     }
 
@@ -499,6 +653,11 @@ impl MiddlewareStaging {
         self.session_duration
     }
 
+    /// Returns the configured "remember me" session duration
+    pub fn remember_me_duration(&self) -> Duration {
+        self.remember_me_duration
+    }
+
     /// Returns the number of custom middlewares added
     pub fn custom_count(&self) -> usize {
         self.custom_middlewares.len()
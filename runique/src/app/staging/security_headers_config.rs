@@ -0,0 +1,89 @@
+//! HSTS, Referrer-Policy and Permissions-Policy configuration passed via closure to the builder.
+use crate::middleware::security::security_headers::{Hsts, ReferrerPolicy, SecurityHeaders};
+
+// ═══════════════════════════════════════════════════════════════
+// SecurityHeadersConfig
+// ═══════════════════════════════════════════════════════════════
+//
+// Used exclusively in the `with_security_headers` closure:
+//
+//   .middleware(|m| {
+//       m.with_security_headers(|h| {
+//           h.hsts(Some(Hsts::new(63_072_000).include_subdomains(true)))
+//            .referrer_policy(Some(ReferrerPolicy::NoReferrer))
+//            .permissions_policy(false)
+//       })
+//   })
+//
+// Default: HSTS one year + includeSubDomains + preload (HTTPS only, never in
+// debug), Referrer-Policy `strict-origin-when-cross-origin`, Permissions-Policy on.
+//
+// METHODS:
+//   .hsts(Option<Hsts>)                     — `None` omits the header
+//   .hsts_in_debug(bool)                    — also send HSTS when DEBUG=true
+//   .referrer_policy(Option<ReferrerPolicy>)— `None` omits the header
+//   .permissions_policy(bool)               — `false` omits the header
+//
+// ═══════════════════════════════════════════════════════════════
+
+/// HSTS, Referrer-Policy and Permissions-Policy configuration, passed via closure
+/// to `.with_security_headers(|h| { ... })`.
+///
+/// Starts from strict defaults. HSTS is only sent when Runique serves HTTPS
+/// (`ENFORCE_HTTPS` or ACME), and never in debug unless `.hsts_in_debug(true)`.
+///
+/// # Example
+/// ```rust,ignore
+/// .middleware(|m| {
+///     m.with_security_headers(|h| {
+///         h.hsts(Some(Hsts::new(63_072_000).include_subdomains(true)))
+///          .referrer_policy(Some(ReferrerPolicy::SameOrigin))
+///     })
+/// })
+/// ```
+///
+/// # Example — omit a header
+/// ```rust,ignore
+/// m.with_security_headers(|h| h.hsts(None).referrer_policy(None))
+/// ```
+#[derive(Default)]
+pub struct SecurityHeadersConfig {
+    inner: SecurityHeaders,
+}
+
+impl SecurityHeadersConfig {
+    /// `Strict-Transport-Security` value — `None` omits the header.
+    pub fn hsts(mut self, hsts: Option<Hsts>) -> Self {
+        self.inner.hsts = hsts;
+        self
+    }
+
+    /// Also sends HSTS in debug (`DEBUG=true`). Off by default: a browser that saw
+    /// HSTS on `localhost` refuses plain HTTP there until `max-age` runs out.
+    pub fn hsts_in_debug(mut self, enable: bool) -> Self {
+        self.inner.hsts_in_debug = enable;
+        self
+    }
+
+    /// `Referrer-Policy` value — `None` omits the header.
+    pub fn referrer_policy(mut self, policy: Option<ReferrerPolicy>) -> Self {
+        self.inner.referrer_policy = policy;
+        self
+    }
+
+    /// Sends the `Permissions-Policy` header — its directives are set by
+    /// `.with_permissions_policy(|p| ...)`. `false` omits the header.
+    pub fn permissions_policy(mut self, enable: bool) -> Self {
+        self.inner.permissions_policy = enable;
+        self
+    }
+
+    pub(crate) fn build(self) -> SecurityHeaders {
+        self.inner
+    }
+
+    /// Returns the current settings for inspection.
+    pub fn get_headers(&self) -> &SecurityHeaders {
+        &self.inner
+    }
+}
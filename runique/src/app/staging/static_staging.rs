@@ -1,5 +1,10 @@
 //! Static files staging: enables or disables the asset service.
-use crate::{app::error_build::BuildError, config::static_files::resolve_media_root};
+use crate::{
+    app::error_build::BuildError,
+    config::static_files::resolve_media_root,
+    utils::storage::{Storage, storage_from_env},
+};
+use std::sync::Arc;
 //
 // Controls whether static files (CSS, JS, media, Runique
 // internal assets) are served by the application.
@@ -18,6 +23,10 @@ pub struct StaticStaging {
     pub(crate) static_cache: &'static str,
     /// Cache-Control header for user-uploaded media (/media/)
     pub(crate) media_cache: &'static str,
+    /// Storage of uploaded media — local `MEDIA_ROOT` when `None`
+    pub(crate) storage: Option<Arc<dyn Storage>>,
+    /// `STORAGE_BACKEND` / `S3_*` misconfiguration, reported by `validate()`
+    pub(crate) storage_error: Option<String>,
 }
 
 impl StaticStaging {
@@ -27,6 +36,8 @@ impl StaticStaging {
             enabled: true,
             static_cache: DEFAULT_STATIC_CACHE,
             media_cache: DEFAULT_MEDIA_CACHE,
+            storage: None,
+            storage_error: None,
         }
     }
 
@@ -91,6 +102,27 @@ impl StaticStaging {
         self
     }
 
+    /// Stores uploaded media in `storage` instead of `MEDIA_ROOT`.
+    /// A remote storage (no local root) gets `/media/...` redirected to its URLs.
+    ///
+    /// ```rust,ignore
+    /// .static_files(|s| s.storage(S3Storage::new(S3Config::from_env()?)))
+    /// ```
+    pub fn storage(mut self, storage: impl Storage) -> Self {
+        self.storage = Some(Arc::new(storage));
+        self
+    }
+
+    /// Storage from `STORAGE_BACKEND` (`local` / `s3`) and the `S3_*` variables.
+    /// A misconfiguration fails `build()`.
+    pub fn storage_from_env(mut self) -> Self {
+        match storage_from_env() {
+            Ok(storage) => self.storage = storage,
+            Err(e) => self.storage_error = Some(e),
+        }
+        self
+    }
+
     // ═══════════════════════════════════════════════════
     // Validation
     // ═══════════════════════════════════════════════════
@@ -98,6 +130,9 @@ impl StaticStaging {
     /// Validates static files configuration.
     /// Checks that the media root directory is accessible (creates it if needed).
     pub fn validate(&self) -> Result<(), BuildError> {
+        if let Some(e) = &self.storage_error {
+            return Err(BuildError::validation(format!("storage: {e}")));
+        }
         if !self.enabled {
             return Ok(());
         }
@@ -0,0 +1,142 @@
+//! Template hot-reload — `LiveTera` (atomically swapped Tera instance) and the
+//! `notify` watcher rebuilding it when a template or static file changes.
+use crate::app::templates::{TemplateLoader, TeraHook};
+use crate::config::RuniqueConfig;
+use crate::utils::StaticManifest;
+use crate::utils::aliases::{ARlockmap, ATera};
+use crate::utils::trad::Catalogs;
+use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::{
+    path::Path,
+    sync::{Arc, RwLock, Weak, mpsc},
+    time::Duration,
+};
+use tera::Tera;
+
+/// Quiet period after the last event before rebuilding — one reload per save.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Interval at which the watcher checks that the engine is still alive.
+const LIVENESS_TICK: Duration = Duration::from_secs(1);
+
+/// Shared Tera instance. [`current`](Self::current) hands out a snapshot: a render
+/// in progress keeps its instance while a reload swaps in the next one.
+#[derive(Clone, Debug, Default)]
+pub struct LiveTera(Arc<RwLock<ATera>>);
+
+impl LiveTera {
+    pub fn new(tera: Tera) -> Self {
+        Self::from(Arc::new(tera))
+    }
+
+    /// The Tera instance to render with.
+    pub fn current(&self) -> ATera {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replaces the instance — subsequent [`current`](Self::current) calls get `tera`.
+    pub fn swap(&self, tera: Tera) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(tera);
+    }
+}
+
+impl From<ATera> for LiveTera {
+    fn from(tera: ATera) -> Self {
+        Self(Arc::new(RwLock::new(tera)))
+    }
+}
+
+/// Watches the template directories and the static directory (`static_url()` hashes),
+/// rebuilding `live` on change. A template that fails to parse is logged and the
+/// previous instance kept. The thread stops once every `LiveTera` clone is dropped.
+pub(crate) fn spawn_watcher(
+    live: &LiveTera,
+    config: RuniqueConfig,
+    url_registry: ARlockmap,
+    catalogs: Arc<Catalogs>,
+    hooks: Vec<TeraHook>,
+) {
+    let (tx, rx) = mpsc::channel::<notify::Result<Event>>();
+    let mut watcher = match RecommendedWatcher::new(tx, Config::default()) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            tracing::warn!(error = %e, "template autoreload disabled: unable to create watcher");
+            return;
+        }
+    };
+    let dirs = config
+        .static_files
+        .templates_dir
+        .iter()
+        .chain(std::iter::once(&config.static_files.staticfiles_dirs));
+    for dir in dirs.map(Path::new).filter(|dir| dir.is_dir()) {
+        if let Err(e) = watcher.watch(dir, RecursiveMode::Recursive) {
+            tracing::warn!(dir = %dir.display(), error = %e, "template autoreload: unable to watch");
+        }
+    }
+
+    let live = Arc::downgrade(&live.0);
+    std::thread::spawn(move || {
+        // Owned by the thread: dropping it stops the notifications
+        let _watcher = watcher;
+        watch_loop(&rx, &live, &config, &url_registry, &catalogs, &hooks);
+    });
+}
+
+fn watch_loop(
+    rx: &mpsc::Receiver<notify::Result<Event>>,
+    live: &Weak<RwLock<ATera>>,
+    config: &RuniqueConfig,
+    url_registry: &ARlockmap,
+    catalogs: &Arc<Catalogs>,
+    hooks: &[TeraHook],
+) {
+    loop {
+        match rx.recv_timeout(LIVENESS_TICK) {
+            Ok(Ok(event)) if is_change(&event) => {
+                // Debounce: editors write a file in several events
+                while rx.recv_timeout(DEBOUNCE).is_ok() {}
+                let Some(slot) = live.upgrade() else { return };
+                reload(&LiveTera(slot), config, url_registry, catalogs, hooks);
+            }
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => tracing::warn!(error = %e, "template autoreload: watcher error"),
+            Err(mpsc::RecvTimeoutError::Timeout) if live.strong_count() == 0 => return,
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+fn is_change(event: &Event) -> bool {
+    matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    )
+}
+
+/// Rebuilds Tera from disk into `live`; keeps the previous instance on error.
+fn reload(
+    live: &LiveTera,
+    config: &RuniqueConfig,
+    url_registry: &ARlockmap,
+    catalogs: &Arc<Catalogs>,
+    hooks: &[TeraHook],
+) {
+    let manifest = StaticManifest::build(Path::new(&config.static_files.staticfiles_dirs));
+    match TemplateLoader::init(
+        config,
+        url_registry.clone(),
+        manifest,
+        catalogs.clone(),
+        hooks,
+    ) {
+        Ok(tera) => {
+            live.swap(tera);
+            tracing::info!("templates reloaded");
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "template reload failed — previous templates kept");
+        }
+    }
+}
@@ -1,20 +1,33 @@
 //! Loading and initialization of the Tera template engine (internal + user).
 use crate::config::RuniqueConfig;
-use crate::context::tera::static_tera;
+use crate::context::tera::{TransFunction, static_tera};
+use crate::utils::StaticManifest;
 use crate::utils::aliases::ARlockmap;
 use crate::utils::constante::*;
+use crate::utils::trad::Catalogs;
 use regex::Captures;
-use std::{collections::HashMap, path::Path};
+use std::{collections::HashMap, path::Path, sync::Arc};
 use tera::Tera;
 
+/// Customization of the Tera instance registered with
+/// [`RuniqueAppBuilder::with_tera`](crate::app::RuniqueAppBuilder::with_tera) —
+/// runs after the framework registrations, at startup and on every template reload.
+pub type TeraHook = Arc<dyn Fn(&mut Tera) + Send + Sync>;
+
 /// Loads and configures the Tera instance with internal framework templates and project templates.
 pub(crate) struct TemplateLoader;
 
 impl TemplateLoader {
-    /// Initializes Tera and processes all templates (internal + users)
+    /// Initializes Tera and processes all templates (internal + users).
+    /// `manifest` holds the content hashes used by `static_url()`, `catalogs` the
+    /// translations of `{% trans %}`; `hooks` run last, so a user filter or function
+    /// can replace a built-in one.
     pub fn init(
         config: &RuniqueConfig,
         url_registry: ARlockmap,
+        manifest: StaticManifest,
+        catalogs: Arc<Catalogs>,
+        hooks: &[TeraHook],
     ) -> Result<Tera, Box<dyn std::error::Error>> {
         let mut tera = Tera::default();
         tera.autoescape_on(vec!["html", "xml"]);
@@ -27,7 +40,27 @@ impl TemplateLoader {
             config.static_files.static_runique_url.clone(),
             config.static_files.media_runique.clone(),
             url_registry.clone(),
+            config.form_theme,
+        );
+        tera.register_function(
+            "static_url",
+            static_tera::StaticUrlFunction::new(
+                config.static_files.static_url.clone(),
+                manifest.clone(),
+            ),
+        );
+        tera.register_function(
+            "sri_hash",
+            static_tera::SriHashFunction::new(manifest.clone()),
+        );
+        tera.register_function(
+            "script_tag",
+            static_tera::ScriptTagFunction::new(
+                config.static_files.static_url.clone(),
+                manifest.clone(),
+            ),
         );
+        tera.register_function("trans", TransFunction::new(catalogs, config.debug));
 
         let static_dir = Path::new(&config.static_files.staticfiles_dirs);
         let integrity_map = crate::utils::integrity::build_integrity_map(static_dir);
@@ -46,14 +79,15 @@ impl TemplateLoader {
                 for entry in paths.flatten() {
                     let content = std::fs::read_to_string(&entry)?;
 
-                    let processed = Self::process_content(content, &integrity_map);
-
                     // Calculation of the template's logical name (relative path)
                     let name = entry
                         .strip_prefix(template_dir)?
                         .to_string_lossy()
                         .replace("\\", "/");
 
+                    Self::warn_missing_sri(&name, &content, &manifest);
+                    let processed = Self::process_content(content, &integrity_map);
+
                     all_templates.push((name, processed));
                 }
             }
@@ -66,6 +100,11 @@ impl TemplateLoader {
             return Err(Box::new(e));
         }
 
+        // 5. User filters/functions (`with_tera`) — after the built-ins, which they may override
+        for hook in hooks {
+            hook(&mut tera);
+        }
+
         if let Some(level) = crate::utils::runique_log::get_log()
             .builder
             .as_ref()
@@ -88,6 +127,22 @@ impl TemplateLoader {
         Ok(tera)
     }
 
+    /// Warns about `sri_hash("…")` / `script_tag("…")` pointing to a file absent from
+    /// the static directory — rendered without `integrity` rather than broken.
+    fn warn_missing_sri(name: &str, content: &str, manifest: &StaticManifest) {
+        for caps in SRI_FUNCTION_REGEX.captures_iter(content) {
+            let path = &caps["path"];
+            if manifest.integrity(path).is_none() {
+                tracing::warn!(
+                    template = name,
+                    path,
+                    "{}(): file not found in the static directory, rendered without integrity",
+                    &caps["func"]
+                );
+            }
+        }
+    }
+
     /// Applies all Runique transformations on a template content
     fn process_content(mut content: String, integrity_map: &HashMap<String, String>) -> String {
         // Simple replacements (Runique DSL)
@@ -95,6 +150,17 @@ impl TemplateLoader {
         content = content.replace("{% messages %}", r#"{% include "message.html" %}"#);
         content = content.replace("{% csp %}", r#"{% include "csp.html" %}"#);
 
+        // Nonce helpers: {{ script_nonce() }}, {% script %}…{% endscript %}, {% style %}…{% endstyle %}
+        content = SCRIPT_NONCE_REGEX
+            .replace_all(&content, r#"{% include "csp.html" %}"#)
+            .to_string();
+        content = NONCE_BLOCK_OPEN_REGEX
+            .replace_all(&content, r#"<$tag {% include "csp.html" %}>"#)
+            .to_string();
+        content = NONCE_BLOCK_CLOSE_REGEX
+            .replace_all(&content, "</$tag>")
+            .to_string();
+
         // Form processing (Isolated fields)
         content = FORM_FIELD_REGEX
             .replace_all(&content, |caps: &Captures| {
@@ -127,10 +193,11 @@ impl TemplateLoader {
             })
             .to_string();
 
-        // Markdown processing ({{ var | markdown }} → {{ var | markdown | safe }})
+        // Markdown processing ({{ var | markdown }} → {{ var | markdown | safe }},
+        // same for `render_markdown`)
         content = MARKDOWN_REGEX
             .replace_all(&content, |caps: &Captures| {
-                format!("{{{{ {} | markdown | safe }}}}", &caps[1])
+                format!("{{{{ {} | {} | safe }}}}", &caps[1], &caps[2])
             })
             .to_string();
 
@@ -139,7 +206,8 @@ impl TemplateLoader {
         // already-cleaned HTML — output-side sanitization, not trust on storage.
         content = SANITIZE_REGEX
             .replace_all(&content, |caps: &Captures| {
-                format!("{{{{ {} | sanitize | safe }}}}", &caps[1])
+                let args = caps.get(2).map_or("", |m| m.as_str());
+                format!("{{{{ {} | sanitize{} | safe }}}}", &caps[1], args)
             })
             .to_string();
 
@@ -149,6 +217,30 @@ impl TemplateLoader {
             .replace_all(&content, "{{ form_fields.html | safe }}")
             .to_string();
 
+        // Cache-busting URLs: static_url("path") → static_url(path="path")
+        content = STATIC_URL_REGEX
+            .replace_all(&content, r#"static_url(path="$path")"#)
+            .to_string();
+
+        // SRI: sri_hash("path") → sri_hash(path="path"), script_tag also gets the nonce
+        content = SRI_FUNCTION_REGEX
+            .replace_all(&content, |caps: &Captures| match &caps["func"] {
+                "script_tag" => format!(
+                    r#"script_tag(path="{}", nonce=csp_nonce | default(value=""))"#,
+                    &caps["path"]
+                ),
+                func => format!(r#"{}(path="{}")"#, func, &caps["path"]),
+            })
+            .to_string();
+
+        // Translations: {% trans "key" %} → trans() with the request locale
+        content = TRANS_REGEX
+            .replace_all(
+                &content,
+                r#"{{ trans(key="$key", locale=locale | default(value="")) }}"#,
+            )
+            .to_string();
+
         // Static/Media processing — literal strings: {% static "path" %} / {% media "path" %}
         content = BALISE_LINK
             .replace_all(&content, |caps: &Captures| {
@@ -200,3 +292,83 @@ impl TemplateLoader {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(content: &str) -> String {
+        TemplateLoader::process_content(content.to_string(), &HashMap::new())
+    }
+
+    #[test]
+    fn script_nonce_devient_l_attribut_nonce() {
+        assert_eq!(
+            process(r#"<script {{ script_nonce() }} src="/app.js"></script>"#),
+            r#"<script {% include "csp.html" %} src="/app.js"></script>"#
+        );
+    }
+
+    #[test]
+    fn blocs_script_et_style_portent_le_nonce() {
+        assert_eq!(
+            process("{% script %}init();{% endscript %}"),
+            r#"<script {% include "csp.html" %}>init();</script>"#
+        );
+        assert_eq!(
+            process("{%style%}body{}{% endstyle %}"),
+            r#"<style {% include "csp.html" %}>body{}</style>"#
+        );
+    }
+
+    #[test]
+    fn sri_hash_et_script_tag_en_arguments_nommes() {
+        assert_eq!(
+            process(r#"<link {{ sri_hash('css/app.css') }}>"#),
+            r#"<link {{ sri_hash(path="css/app.css") }}>"#
+        );
+        assert_eq!(
+            process(r#"{{ script_tag("js/app.js") }}"#),
+            r#"{{ script_tag(path="js/app.js", nonce=csp_nonce | default(value="")) }}"#
+        );
+    }
+
+    #[test]
+    fn render_markdown_force_safe_comme_markdown() {
+        assert_eq!(
+            process("{{ post.body | render_markdown }}"),
+            "{{ post.body | render_markdown | safe }}"
+        );
+        assert_eq!(
+            process("{{post.body|markdown}}"),
+            "{{ post.body | markdown | safe }}"
+        );
+    }
+
+    #[test]
+    fn sanitize_garde_les_arguments_de_politique() {
+        assert_eq!(
+            process(r#"{{ post.body | sanitize(policy="blog") }}"#),
+            r#"{{ post.body | sanitize(policy="blog") | safe }}"#
+        );
+        assert_eq!(
+            process("{{ post.body | sanitize }}"),
+            "{{ post.body | sanitize | safe }}"
+        );
+    }
+
+    #[test]
+    fn nonce_rendu_identique_a_csp_nonce() {
+        let mut tera = Tera::default();
+        tera.add_raw_template("csp.html", r#"nonce="{{ csp_nonce }}""#)
+            .unwrap();
+        tera.add_raw_template("page.html", &process("{% script %}x(){% endscript %}"))
+            .unwrap();
+        let mut context = tera::Context::new();
+        context.insert("csp_nonce", "abc123");
+        assert_eq!(
+            tera.render("page.html", &context).unwrap(),
+            r#"<script nonce="abc123">x()</script>"#
+        );
+    }
+}
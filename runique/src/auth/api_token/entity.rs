@@ -0,0 +1,44 @@
+//! SeaORM entity for the `eihwaz_api_tokens` table.
+//!
+//! Stores **hashed** API tokens (never the raw token): the client keeps the raw
+//! bearer token, the server hashes the incoming one and looks up the row.
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "eihwaz_api_tokens")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+
+    /// SHA-256 digest of the raw token, base64url (the lookup key).
+    #[sea_orm(unique)]
+    pub token_hash: String,
+
+    /// FK → user table: the user the token authenticates.
+    pub user_id: crate::utils::pk::Pk,
+
+    /// Expiration timestamp (UTC naive) — `None` never expires.
+    pub expires_at: Option<chrono::NaiveDateTime>,
+
+    /// Last successful authentication (UTC naive), when tracking is enabled.
+    pub last_used_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::auth::user::Entity",
+        from = "Column::UserId",
+        to = "crate::auth::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<crate::auth::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,246 @@
+//! Bearer API tokens — `Authorization: Bearer <token>` authentication for clients
+//! without cookies, populating the same [`CurrentUser`] as the session path.
+//!
+//! The raw token is only shown once to the client. The store keeps its **hash**
+//! (table `eihwaz_api_tokens` by default), so a DB read leak cannot be replayed.
+use crate::auth::session::{CurrentUser, UserEntity, cached_groupes};
+use crate::auth::user_trait::RuniqueUser;
+use crate::context::RequestExtensions;
+use crate::utils::aliases::ADb;
+use crate::utils::pk::Pk;
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use sea_orm::{ActiveValue::Set, ColumnTrait, DbErr, EntityTrait, QueryFilter, sea_query::Expr};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::Duration;
+use subtle::ConstantTimeEq;
+
+pub mod entity;
+
+/// Random bytes of a raw token (256 bits).
+const API_TOKEN_BYTES: usize = 32;
+
+/// Generates a raw token (base64url) — to give to the client, never to store.
+pub fn generate_api_token() -> String {
+    let bytes: [u8; API_TOKEN_BYTES] = rand::random();
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Hashes a raw token (the store lookup key).
+pub fn hash_api_token(token: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(token.as_bytes()))
+}
+
+/// Token record returned by a [`TokenStore`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiToken {
+    pub token_hash: String,
+    pub user_id: Pk,
+    /// `None` never expires.
+    pub expires_at: Option<chrono::NaiveDateTime>,
+}
+
+impl ApiToken {
+    fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|exp| exp <= chrono::Utc::now().naive_utc())
+    }
+}
+
+/// Storage backend of API tokens — default: [`DbTokenStore`].
+#[async_trait::async_trait]
+pub trait TokenStore: Send + Sync + 'static {
+    /// Looks up a token by its hash (see [`hash_api_token`]).
+    async fn find(&self, token_hash: &str) -> Option<ApiToken>;
+    /// Records a successful use — only called when
+    /// [`track_last_used`](ApiTokenAuth::track_last_used) is enabled.
+    async fn touch(&self, _token_hash: &str) {}
+}
+
+/// [`TokenStore`] backed by the `eihwaz_api_tokens` table
+/// (migration `EihwazApiTokensMigration`).
+pub struct DbTokenStore {
+    db: ADb,
+}
+
+impl DbTokenStore {
+    pub fn new(db: ADb) -> Self {
+        Self { db }
+    }
+
+    /// Creates a token for `user_id` — `ttl: None` never expires.
+    /// Persists the hash; returns the **raw** token, to show once.
+    pub async fn create(&self, user_id: Pk, ttl: Option<Duration>) -> Result<String, DbErr> {
+        let token = generate_api_token();
+        let now = chrono::Utc::now().naive_utc();
+        let expires_at = ttl.map(|ttl| {
+            chrono::Duration::from_std(ttl)
+                .ok()
+                .and_then(|d| now.checked_add_signed(d))
+                .unwrap_or(now)
+        });
+        let model = entity::ActiveModel {
+            token_hash: Set(hash_api_token(&token)),
+            user_id: Set(user_id),
+            expires_at: Set(expires_at),
+            last_used_at: Set(None),
+            ..Default::default()
+        };
+        entity::Entity::insert(model).exec(&*self.db).await?;
+        Ok(token)
+    }
+
+    /// Deletes a token from its raw value. Returns `true` if it existed.
+    pub async fn revoke(&self, token: &str) -> Result<bool, DbErr> {
+        let res = entity::Entity::delete_many()
+            .filter(entity::Column::TokenHash.eq(hash_api_token(token)))
+            .exec(&*self.db)
+            .await?;
+        Ok(res.rows_affected > 0)
+    }
+
+    /// Deletes every token of `user_id` (e.g. on password change).
+    pub async fn revoke_all(&self, user_id: Pk) -> Result<u64, DbErr> {
+        let res = entity::Entity::delete_many()
+            .filter(entity::Column::UserId.eq(user_id))
+            .exec(&*self.db)
+            .await?;
+        Ok(res.rows_affected)
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenStore for DbTokenStore {
+    async fn find(&self, token_hash: &str) -> Option<ApiToken> {
+        let row = entity::Entity::find()
+            .filter(entity::Column::TokenHash.eq(token_hash))
+            .one(&*self.db)
+            .await
+            .ok()??;
+        Some(ApiToken {
+            token_hash: row.token_hash,
+            user_id: row.user_id,
+            expires_at: row.expires_at,
+        })
+    }
+
+    async fn touch(&self, token_hash: &str) {
+        if let Err(e) = entity::Entity::update_many()
+            .col_expr(
+                entity::Column::LastUsedAt,
+                Expr::value(chrono::Utc::now().naive_utc()),
+            )
+            .filter(entity::Column::TokenHash.eq(token_hash))
+            .exec(&*self.db)
+            .await
+        {
+            tracing::warn!(error = %e, "api token last_used_at update failed");
+        }
+    }
+}
+
+/// State of [`api_token_middleware`].
+///
+/// ```rust,ignore
+/// let auth = Arc::new(ApiTokenAuth::new(db.clone()).track_last_used(true));
+/// let api = Router::new()
+///     .route("/api/me", get(me))
+///     .layer(axum::middleware::from_fn_with_state(auth, api_token_middleware::<users::Entity>));
+/// ```
+pub struct ApiTokenAuth {
+    db: ADb,
+    store: Arc<dyn TokenStore>,
+    track_last_used: bool,
+}
+
+impl ApiTokenAuth {
+    /// Tokens from the `eihwaz_api_tokens` table, users loaded from `db`.
+    pub fn new(db: ADb) -> Self {
+        Self {
+            store: Arc::new(DbTokenStore::new(db.clone())),
+            db,
+            track_last_used: false,
+        }
+    }
+
+    /// Replaces the token storage backend.
+    pub fn store(mut self, store: impl TokenStore) -> Self {
+        self.store = Arc::new(store);
+        self
+    }
+
+    /// Updates the token's last-used timestamp on each authenticated request.
+    pub fn track_last_used(mut self, enabled: bool) -> Self {
+        self.track_last_used = enabled;
+        self
+    }
+}
+
+/// Token of an `Authorization: Bearer <token>` header.
+pub(crate) fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = value.split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then_some(token)
+}
+
+fn unauthorized() -> Response {
+    (
+        StatusCode::UNAUTHORIZED,
+        [(header::WWW_AUTHENTICATE, "Bearer")],
+    )
+        .into_response()
+}
+
+/// Middleware: authenticates `Authorization: Bearer <token>` requests and injects
+/// the [`CurrentUser`] — `login_required` / `permission_required` then apply unchanged.
+///
+/// Requests without the header pass through (session auth); an unknown, expired
+/// or inactive-user token gets `401`.
+pub async fn api_token_middleware<E: UserEntity>(
+    State(auth): State<Arc<ApiTokenAuth>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(token) = bearer_token(request.headers()) else {
+        return next.run(request).await;
+    };
+    let token_hash = hash_api_token(token);
+    let Some(record) = auth
+        .store
+        .find(&token_hash)
+        .await
+        .filter(|r| bool::from(r.token_hash.as_bytes().ct_eq(token_hash.as_bytes())))
+        .filter(|r| !r.is_expired())
+    else {
+        return unauthorized();
+    };
+    let Some(user) = E::find_by_id(&auth.db, record.user_id)
+        .await
+        .filter(|u| u.is_active())
+    else {
+        return unauthorized();
+    };
+    if auth.track_last_used {
+        auth.store.touch(&token_hash).await;
+    }
+
+    let current_user = CurrentUser {
+        id: user.user_id(),
+        username: user.username().to_string(),
+        is_staff: user.is_staff(),
+        is_superuser: user.is_superuser(),
+        groupes: cached_groupes(&auth.db, user.user_id(), user.username()).await,
+    };
+    RequestExtensions::new()
+        .with_current_user(current_user)
+        .inject_request(&mut request);
+
+    next.run(request).await
+}
@@ -0,0 +1,137 @@
+//! Session epochs — a per-user value stamped into the session at login. Once it
+//! changes (password change, forced reset), every session opened before is logged
+//! out by [`load_user_middleware`](crate::auth::session::load_user_middleware).
+//!
+//! Disabled until an entity is registered via
+//! [`register_session_epoch`] (builder: `.with_session_epoch::<E>()`).
+//!
+//! Epochs are cached per process for [`EPOCH_CACHE_TTL`]: a change made by
+//! another worker, the CLI or directly in the DB takes effect within that delay,
+//! a change made in this process ([`bump_session_epoch`]) immediately.
+use crate::auth::session::UserEntity;
+use crate::auth::user_trait::RuniqueUser;
+use crate::utils::constante::session_key::session::SESSION_EPOCH_KEY;
+use crate::utils::pk::Pk;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use futures_util::future::BoxFuture;
+use sea_orm::DatabaseConnection;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{LazyLock, OnceLock, RwLock};
+use std::time::{Duration, Instant};
+use tower_sessions::Session;
+
+/// Loads the current epoch of a user — see [`register_session_epoch`].
+type EpochLoader = for<'a> fn(&'a DatabaseConnection, Pk) -> BoxFuture<'a, Option<String>>;
+
+static EPOCH_LOADER: OnceLock<EpochLoader> = OnceLock::new();
+
+/// How long a loaded epoch is trusted before being read again from the DB.
+pub const EPOCH_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Current epoch per user and when it was loaded — avoids a user query on every
+/// request. Per process: [`bump_session_epoch`] only evicts the local entry.
+static EPOCH_CACHE: LazyLock<RwLock<HashMap<Pk, (String, Instant)>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Default epoch of [`RuniqueUser::session_epoch`]: a short digest of the password
+/// hash — changes with the password, without exposing the hash in the session.
+pub fn password_epoch(password_hash: &str) -> String {
+    URL_SAFE_NO_PAD.encode(&Sha256::digest(password_hash.as_bytes())[..12])
+}
+
+fn load_epoch<E: UserEntity>(
+    db: &DatabaseConnection,
+    user_id: Pk,
+) -> BoxFuture<'_, Option<String>> {
+    Box::pin(async move {
+        E::find_by_id(db, user_id)
+            .await
+            .map(|user| user.session_epoch())
+    })
+}
+
+/// Enables session epochs, resolved through `E`. Called once at build; later calls
+/// are ignored.
+pub fn register_session_epoch<E: UserEntity>() {
+    if EPOCH_LOADER.set(load_epoch::<E>).is_err() {
+        tracing::warn!("session epoch entity already registered — ignored");
+    }
+}
+
+fn cached_epoch(user_id: Pk) -> Option<String> {
+    let cache = EPOCH_CACHE.read().unwrap_or_else(|p| {
+        tracing::warn!("session epoch cache lock poisoned (recovered, read)");
+        p.into_inner()
+    });
+    cache
+        .get(&user_id)
+        .filter(|(_, loaded_at)| loaded_at.elapsed() < EPOCH_CACHE_TTL)
+        .map(|(epoch, _)| epoch.clone())
+}
+
+/// Forgets the cached epoch of `user_id`: the next request reloads it, so every
+/// session stamped with the previous value is logged out.
+///
+/// Call it after changing a password (done by the built-in reset flow and the
+/// admin edit form) or after changing a custom [`RuniqueUser::session_epoch`]
+/// value (e.g. a counter column). Other processes pick the change up once their
+/// entry expires ([`EPOCH_CACHE_TTL`]).
+pub fn bump_session_epoch(user_id: Pk) {
+    let mut cache = EPOCH_CACHE.write().unwrap_or_else(|p| {
+        tracing::warn!("session epoch cache lock poisoned (recovered, evict)");
+        p.into_inner()
+    });
+    cache.remove(&user_id);
+}
+
+/// Current epoch of `user_id` — `None` if epochs are disabled or the user is unknown.
+pub(crate) async fn current_session_epoch(db: &DatabaseConnection, user_id: Pk) -> Option<String> {
+    let loader = EPOCH_LOADER.get()?;
+    if let Some(epoch) = cached_epoch(user_id) {
+        return Some(epoch);
+    }
+    let epoch = loader(db, user_id).await?;
+    let mut cache = EPOCH_CACHE.write().unwrap_or_else(|p| {
+        tracing::warn!("session epoch cache lock poisoned (recovered, insert)");
+        p.into_inner()
+    });
+    cache.insert(user_id, (epoch.clone(), Instant::now()));
+    Some(epoch)
+}
+
+/// Stamps the current epoch of `user_id` into the session (login). Reloads it from
+/// the DB, so a login never inherits a stale cached value.
+pub(crate) async fn stamp_session_epoch(
+    session: &Session,
+    db: &DatabaseConnection,
+    user_id: Pk,
+) -> Result<(), tower_sessions::session::Error> {
+    bump_session_epoch(user_id);
+    match current_session_epoch(db, user_id).await {
+        Some(epoch) => session.insert(SESSION_EPOCH_KEY, epoch).await,
+        None => session
+            .remove::<String>(SESSION_EPOCH_KEY)
+            .await
+            .map(|_| ()),
+    }
+}
+
+/// `false` if the session was stamped with another epoch than the current one —
+/// including a session opened before epochs were enabled.
+/// `true` when epochs are disabled or the user cannot be resolved.
+pub(crate) async fn session_epoch_matches(
+    session: &Session,
+    db: &DatabaseConnection,
+    user_id: Pk,
+) -> bool {
+    let Some(current) = current_session_epoch(db, user_id).await else {
+        return true;
+    };
+    session
+        .get::<String>(SESSION_EPOCH_KEY)
+        .await
+        .ok()
+        .flatten()
+        .is_some_and(|stamped| stamped == current)
+}
@@ -1,5 +1,9 @@
-//! `LoginAdmin` — admin login form with username/password fields.
-use crate::forms::{Forms, field::RuniqueForm, fields::text::TextField};
+//! `LoginAdmin` — admin login form with username/password/remember-me fields.
+use crate::forms::{
+    Forms,
+    field::RuniqueForm,
+    fields::{boolean::BooleanField, text::TextField},
+};
 use crate::impl_form_access;
 
 /// Admin login form provided by Runique.
@@ -25,6 +29,11 @@ impl RuniqueForm for LoginAdmin {
                 .label(crate::utils::trad::t("admin.password").as_ref())
                 .required(),
         );
+
+        form.field(
+            &BooleanField::new("remember_me")
+                .label(crate::utils::trad::t("admin.login.label_remember").as_ref()),
+        );
     }
 
     impl_form_access!();
@@ -1,6 +1,8 @@
 //! Brute-force protection, `login_required` middleware, and permission cache.
 use crate::admin::permissions::Groupe;
+use crate::auth::session::{AdminAuth, AdminLoginResult};
 use crate::utils::pk::Pk;
+use sea_orm::DatabaseConnection;
 use std::{
     collections::HashMap,
     sync::{Arc, LazyLock, Mutex, RwLock},
@@ -64,13 +66,88 @@ pub fn clear_cache() {
 // LoginGuard
 // ═══════════════════════════════════════════════════════════════
 
-/// Entry by username: (number of failures, start of lockout)
-type Store = Arc<Mutex<HashMap<String, (u32, Instant)>>>;
+/// Failure counters behind a [`LoginGuard`] — in memory by default.
+///
+/// Implement it to share lockouts between instances (Redis…).
+pub trait LoginAttemptStore: Send + Sync {
+    /// Records a failure for `key`; returns the new failure count.
+    fn record_failure(&self, key: &str) -> u32;
+
+    /// Clears the counter of `key` (successful login).
+    fn reset(&self, key: &str);
+
+    /// Failure count and time elapsed since the last failure, `None` if unknown.
+    fn get(&self, key: &str) -> Option<(u32, Duration)>;
+
+    /// Drops entries older than `lockout` — called periodically by [`LoginGuard::spawn_cleanup`].
+    fn purge(&self, _lockout: Duration) {}
+}
+
+/// Default in-memory store: (number of failures, last failure) per key.
+#[derive(Default)]
+pub struct MemoryLoginAttemptStore {
+    entries: Mutex<HashMap<String, (u32, Instant)>>,
+}
+
+impl MemoryLoginAttemptStore {
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<String, (u32, Instant)>> {
+        match self.entries.lock() {
+            Ok(s) => s,
+            Err(p) => p.into_inner(),
+        }
+    }
+}
+
+impl LoginAttemptStore for MemoryLoginAttemptStore {
+    fn record_failure(&self, key: &str) -> u32 {
+        let mut store = self.entries();
+        let entry = store.entry(key.to_string()).or_insert((0, Instant::now()));
+        entry.0 = entry.0.saturating_add(1);
+        entry.1 = Instant::now();
+        entry.0
+    }
+
+    fn reset(&self, key: &str) {
+        self.entries().remove(key);
+    }
+
+    fn get(&self, key: &str) -> Option<(u32, Duration)> {
+        self.entries()
+            .get(key)
+            .map(|(n, last)| (*n, last.elapsed()))
+    }
+
+    fn purge(&self, lockout: Duration) {
+        self.entries()
+            .retain(|_, (_, last)| last.elapsed() < lockout);
+    }
+}
+
+/// Outcome of [`LoginGuard::authenticate`].
+#[derive(Debug, Clone)]
+pub enum LoginOutcome {
+    /// Credentials accepted — the counter has been reset
+    Authenticated(AdminLoginResult),
+    /// Credentials rejected — the failure has been recorded
+    Invalid,
+    /// Too many failures: attempt refused without checking the password
+    Throttled {
+        /// Seconds before a new attempt is accepted
+        retry_after: u64,
+    },
+}
+
+impl From<Option<AdminLoginResult>> for LoginOutcome {
+    fn from(result: Option<AdminLoginResult>) -> Self {
+        result.map_or(Self::Invalid, Self::Authenticated)
+    }
+}
 
 /// Brute-force protection by username.
 ///
 /// Tracks failed login attempts per account, independent of IP.
 /// No false positives related to NAT or shared proxies.
+/// With [`per_ip`](Self::per_ip), counts per username + IP instead.
 ///
 /// # Example
 /// ```rust,ignore
@@ -80,35 +157,33 @@ type Store = Arc<Mutex<HashMap<String, (u32, Instant)>>>;
 /// let guard = Arc::new(LoginGuard::new().max_attempts(5).lockout_secs(300));
 ///
 /// // In the login handler:
-/// if guard.is_locked(&username) {
-///     // return a blocking error
-/// }
-/// match authenticate(&username, &password, &db).await {
-///     Some(user) => {
-///         guard.record_success(&username);
-///         login(&session, user.id, &user.username).await?;
-///     }
-///     None => {
-///         guard.record_failure(&username);
+/// match guard.authenticate(&auth, &username, &password, &ip, &db).await {
+///     LoginOutcome::Authenticated(user) => {
+///         login(&session, &db, user.user_id, &user.username, …).await?;
 ///     }
+///     LoginOutcome::Invalid => { /* wrong credentials */ }
+///     LoginOutcome::Throttled { retry_after } => { /* locked for retry_after s */ }
 /// }
 /// ```
 #[derive(Clone)]
 pub struct LoginGuard {
-    store: Store,
+    store: Arc<dyn LoginAttemptStore>,
     /// Number of failures before lockout
     pub max_attempts: u32,
     /// Lockout duration in seconds
     pub lockout_secs: u64,
+    /// Count failures per username + IP rather than per username
+    pub per_ip: bool,
 }
 
 impl LoginGuard {
     /// Creates a `LoginGuard` with default values (5 attempts / 300 s).
     pub fn new() -> Self {
         Self {
-            store: Arc::new(Mutex::new(HashMap::new())),
+            store: Arc::new(MemoryLoginAttemptStore::default()),
             max_attempts: 5,
             lockout_secs: 300,
+            per_ip: false,
         }
     }
 
@@ -126,67 +201,54 @@ impl LoginGuard {
         self
     }
 
+    /// Counts failures per username + IP: an attacker cannot lock a legitimate
+    /// user out from another address, at the cost of allowing distributed attempts.
+    #[must_use]
+    pub fn per_ip(mut self, enabled: bool) -> Self {
+        self.per_ip = enabled;
+        self
+    }
+
+    /// Replaces the in-memory store (shared lockouts across instances, Redis…).
+    #[must_use]
+    pub fn store(mut self, store: impl LoginAttemptStore + 'static) -> Self {
+        self.store = Arc::new(store);
+        self
+    }
+
     /// Spawns a Tokio task that periodically purges expired entries.
     pub fn spawn_cleanup(&self, period: tokio::time::Duration) {
         let store = self.store.clone();
-        let lockout_secs = self.lockout_secs;
+        let lockout = Duration::from_secs(self.lockout_secs);
         tokio::spawn(async move {
             let mut ticker = interval(period);
             loop {
                 ticker.tick().await;
-                let mut guard = match store.lock() {
-                    Ok(g) => g,
-                    Err(p) => p.into_inner(),
-                };
-                let lockout = Duration::from_secs(lockout_secs);
-                guard.retain(|_, (_, last)| last.elapsed() < lockout);
+                store.purge(lockout);
             }
         });
     }
 
     /// Records a connection failure for this username
     pub fn record_failure(&self, username: &str) {
-        let mut store = match self.store.lock() {
-            Ok(s) => s,
-            Err(p) => p.into_inner(),
-        };
-        let entry = store
-            .entry(username.to_string())
-            .or_insert((0, Instant::now()));
-        entry.0 = entry.0.saturating_add(1);
-        entry.1 = Instant::now();
+        self.store.record_failure(username);
     }
 
     /// Resets the counter after a successful connection
     pub fn record_success(&self, username: &str) {
-        let mut store = match self.store.lock() {
-            Ok(s) => s,
-            Err(p) => p.into_inner(),
-        };
-        store.remove(username);
+        self.store.reset(username);
     }
 
     /// Returns `true` if the account is temporarily locked
     #[must_use]
     pub fn is_locked(&self, username: &str) -> bool {
-        let store = match self.store.lock() {
-            Ok(s) => s,
-            Err(p) => p.into_inner(),
-        };
-        let (attempts, last) = store
-            .get(username)
-            .map_or((0, Instant::now()), |(a, t)| (*a, *t));
-        attempts >= self.max_attempts && last.elapsed() < Duration::from_secs(self.lockout_secs)
+        self.remaining_lockout_secs(username).is_some()
     }
 
     /// Current number of failures for this username
     #[must_use]
     pub fn attempts(&self, username: &str) -> u32 {
-        let store = match self.store.lock() {
-            Ok(s) => s,
-            Err(p) => p.into_inner(),
-        };
-        store.get(username).map_or(0, |(n, _)| *n)
+        self.store.get(username).map_or(0, |(n, _)| n)
     }
 
     /// Returns the effective key to use with `LoginGuard`.
@@ -202,22 +264,54 @@ impl LoginGuard {
         }
     }
 
+    /// Key used by this guard: [`effective_key`](Self::effective_key), suffixed
+    /// with `@{ip}` when [`per_ip`](Self::per_ip) is enabled.
+    #[must_use]
+    pub fn key_for(&self, username: &str, ip: &str) -> String {
+        let key = Self::effective_key(username, ip);
+        if self.per_ip && !username.trim().is_empty() {
+            format!("{key}@{ip}")
+        } else {
+            key.into_owned()
+        }
+    }
+
     /// Remaining seconds before unlocking, or `None` if not locked
     #[must_use]
     pub fn remaining_lockout_secs(&self, username: &str) -> Option<u64> {
-        let store = match self.store.lock() {
-            Ok(s) => s,
-            Err(p) => p.into_inner(),
-        };
-        if let Some((attempts, last)) = store.get(username)
-            && *attempts >= self.max_attempts
-        {
-            let elapsed = last.elapsed().as_secs();
-            if elapsed < self.lockout_secs {
-                return Some(self.lockout_secs.saturating_sub(elapsed));
+        let (attempts, elapsed) = self.store.get(username)?;
+        let elapsed = elapsed.as_secs();
+        if attempts >= self.max_attempts && elapsed < self.lockout_secs {
+            Some(self.lockout_secs.saturating_sub(elapsed))
+        } else {
+            None
+        }
+    }
+
+    /// Authenticates through `auth` while enforcing the lockout: refuses without
+    /// checking the password when locked, records failures, resets on success.
+    pub async fn authenticate(
+        &self,
+        auth: &dyn AdminAuth,
+        username: &str,
+        password: &str,
+        ip: &str,
+        db: &DatabaseConnection,
+    ) -> LoginOutcome {
+        let key = self.key_for(username, ip);
+        if let Some(retry_after) = self.remaining_lockout_secs(&key) {
+            return LoginOutcome::Throttled { retry_after };
+        }
+        match auth.authenticate(username, password, db).await {
+            Some(user) => {
+                self.record_success(&key);
+                LoginOutcome::Authenticated(user)
+            }
+            None => {
+                self.record_failure(&key);
+                LoginOutcome::Invalid
             }
         }
-        None
     }
 }
 
@@ -240,16 +334,112 @@ use axum::{
 };
 use tower_sessions::Session;
 
-/// Middleware that redirects to `redirect_url` if the user is not authenticated.
+/// Id of the authenticated user: a `CurrentUser` injected upstream (bearer token —
+/// see [`api_token_middleware`](crate::auth::api_token::api_token_middleware)), else the session.
+///
+/// Takes the injected id rather than the request: `&Request<Body>` is not `Send`.
+async fn authenticated_user_id(session: &Session, injected: Option<Pk>) -> Option<Pk> {
+    match injected {
+        Some(id) => Some(id),
+        None => crate::auth::session::get_user_id(session).await,
+    }
+}
+
+fn injected_user_id(req: &Request<Body>) -> Option<Pk> {
+    req.extensions()
+        .get::<crate::auth::session::CurrentUser>()
+        .map(|user| user.id)
+}
+
+/// WebSocket handshake (`Upgrade: websocket`) — a client cannot follow a redirect.
+fn is_websocket_upgrade(req: &Request<Body>) -> bool {
+    req.headers()
+        .get(axum::http::header::UPGRADE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.eq_ignore_ascii_case("websocket"))
+}
+
+/// Rejection of an anonymous request: redirect to `url`, `401` for a WebSocket handshake.
+fn unauthenticated(url: &str, websocket: bool) -> Response {
+    if websocket {
+        axum::http::StatusCode::UNAUTHORIZED.into_response()
+    } else {
+        Redirect::to(url).into_response()
+    }
+}
+
+/// Middleware that redirects to `redirect_url` if the user is not authenticated
+/// (`401` for a WebSocket handshake).
 pub(crate) async fn login_required_middleware(
     State(redirect_url): State<Arc<String>>,
     session: Session,
     req: Request<Body>,
     next: Next,
 ) -> Response {
-    if crate::auth::session::is_authenticated(&session).await {
+    let websocket = is_websocket_upgrade(&req);
+    if authenticated_user_id(&session, injected_user_id(&req))
+        .await
+        .is_some()
+    {
         next.run(req).await
     } else {
-        Redirect::to(redirect_url.as_str()).into_response()
+        unauthenticated(&redirect_url, websocket)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// permission_required middleware
+// ═══════════════════════════════════════════════════════════════
+
+/// State of [`permission_required_middleware`].
+pub(crate) struct PermissionRequired {
+    pub(crate) permission: String,
+    pub(crate) login_url: String,
+}
+
+/// Middleware that redirects to `login_url` if the user is not authenticated,
+/// and renders `403.html` if they lack `permission`
+/// (see [`CurrentUser::has_permission`](crate::auth::session::CurrentUser::has_permission)).
+///
+/// Uses the injected [`CurrentUser`](crate::auth::session::CurrentUser) when an
+/// earlier middleware loaded it, otherwise builds it from the session and the
+/// permission cache — no user lookup per request.
+pub(crate) async fn permission_required_middleware(
+    State(guard): State<Arc<PermissionRequired>>,
+    session: Session,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let websocket = is_websocket_upgrade(&req);
+    let Some(engine) = req
+        .extensions()
+        .get::<crate::utils::aliases::AEngine>()
+        .cloned()
+    else {
+        return axum::http::StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+    let user = match req.extensions().get::<crate::auth::session::CurrentUser>() {
+        Some(user) => Some(user.clone()),
+        None => crate::auth::session::session_current_user(&session, &engine.db).await,
+    };
+    match user {
+        Some(user) if user.has_permission(&guard.permission) => next.run(req).await,
+        Some(_) => {
+            let csrf_token = req
+                .extensions()
+                .get::<crate::utils::csrf::CsrfToken>()
+                .map(|t| t.0.clone());
+            let request_id = req
+                .extensions()
+                .get::<crate::middleware::RequestId>()
+                .map(|r| r.0.clone());
+            crate::middleware::errors::render_403(
+                &engine.tera.current(),
+                &engine.config,
+                csrf_token,
+                request_id,
+            )
+        }
+        None => unauthenticated(&guard.login_url, websocket),
     }
 }
@@ -1,20 +1,37 @@
-//! Authentication — session, guards, permissions, password reset.
+//! Authentication — session, API tokens, guards, permissions, password reset.
+pub mod api_token;
+pub mod epoch;
 pub mod form;
 pub mod guard;
 pub mod password;
+pub mod permission;
 pub mod session;
+pub mod signed_reset;
+pub mod totp;
 pub mod user;
 pub mod user_trait;
 
+pub use api_token::{ApiTokenAuth, DbTokenStore, TokenStore, api_token_middleware};
+pub use epoch::{bump_session_epoch, password_epoch, register_session_epoch};
 pub use form::LoginAdmin;
-pub use guard::LoginGuard;
+pub use guard::{LoginAttemptStore, LoginGuard, LoginOutcome, MemoryLoginAttemptStore};
 pub use password::{
     ForgotPasswordForm, PasswordResetAdapter, PasswordResetConfig, PasswordResetForm,
-    PasswordResetHandler, PasswordResetStaging, handle_forgot_password, handle_password_reset,
+    PasswordResetHandler, PasswordResetStaging, SetPasswordForm, handle_forgot_password,
+    handle_password_reset,
 };
+pub use permission::{granted_permissions, permission_matches};
 pub use session::{
     AdminAuth, AdminLoginResult, CurrentUser, DefaultAdminAuth, UserEntity, auth_login,
-    get_user_id, get_username, is_admin_authenticated, is_authenticated, load_user_middleware,
-    login, logout, protect_session, unprotect_session,
+    get_user_id, get_username, is_admin_authenticated, is_authenticated, is_remembered,
+    load_user_middleware, login, login_user_full, logout, protect_session, unprotect_session,
+};
+pub use signed_reset::{
+    DEFAULT_RESET_TOKEN_TTL, make_reset_token, reset_token_user_id, verify_reset_token,
+    verify_reset_token_keys,
+};
+pub use totp::{
+    LoginStep, complete_totp_login, generate_totp_secret, is_totp_pending, is_totp_verified,
+    login_with_totp, register_totp, totp_provisioning_uri, verify_totp,
 };
 pub use user::{BuiltinUserEntity, RuniqueAdminAuth, authenticate_user};
@@ -17,6 +17,7 @@ use crate::forms::{
     Forms,
     field::RuniqueForm,
     fields::{hidden::HiddenField, text::TextField},
+    options::PasswordRules,
 };
 use crate::utils::{
     aliases::{AppResult, StrMap},
@@ -24,6 +25,26 @@ use crate::utils::{
 };
 use crate::{context_update, impl_form_access};
 
+// ─── New password field ───────────────────────────────────────────────────────
+
+/// Minimum length of a new password, in characters.
+const NEW_PASSWORD_MIN_LENGTH: u32 = 10;
+
+/// New password field of the reset forms — validated like any password
+/// [`TextField`]: length in characters, strength rules from the environment
+/// ([`PasswordRules::from_env`], same as the CLI).
+fn new_password_field() -> TextField {
+    let mut field = TextField::password("password")
+        .label(&t("reset.new_password_label"))
+        .required()
+        .min_length(
+            NEW_PASSWORD_MIN_LENGTH,
+            &tf("reset.password_min_length", &[&NEW_PASSWORD_MIN_LENGTH]),
+        );
+    field.config.password_rules = PasswordRules::from_env();
+    field
+}
+
 // ─── ForgotPasswordForm ───────────────────────────────────────────────────────
 
 #[derive(Serialize, Debug, Clone)]
@@ -62,11 +83,7 @@ impl RuniqueForm for PasswordResetForm {
                 .label(&t("reset.email_label"))
                 .required(),
         );
-        form.field(
-            &TextField::password("password")
-                .label(&t("reset.new_password_label"))
-                .required(),
-        );
+        form.field(&new_password_field());
         form.field(
             &TextField::password("confirm")
                 .label(&t("reset.confirm_label"))
@@ -92,13 +109,50 @@ impl RuniqueForm for PasswordResetForm {
             }
         }
 
-        if password.len() < 10 {
+        if password != confirm {
             errors.insert(
-                "password".to_string(),
-                tf("reset.password_min_length", &["10"]).clone(),
+                "confirm".to_string(),
+                t("reset.password_mismatch").to_string(),
             );
         }
 
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    impl_form_access!();
+}
+
+// ─── SetPasswordForm ──────────────────────────────────────────────────────────
+
+/// New password + confirmation, for flows where the user is already identified
+/// (signed token from [`verify_reset_token`](crate::auth::verify_reset_token),
+/// password change from a profile page…).
+#[derive(Serialize, Debug, Clone)]
+#[serde(transparent)]
+pub struct SetPasswordForm {
+    pub form: Forms,
+}
+
+#[async_trait]
+impl RuniqueForm for SetPasswordForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&new_password_field());
+        form.field(
+            &TextField::password("confirm")
+                .label(&t("reset.confirm_label"))
+                .required(),
+        );
+    }
+
+    async fn clean(&mut self) -> Result<(), StrMap> {
+        let password = self.cleaned_string("password").unwrap_or_default();
+        let confirm = self.cleaned_string("confirm").unwrap_or_default();
+        let mut errors = StrMap::new();
+
         if password != confirm {
             errors.insert(
                 "confirm".to_string(),
@@ -272,7 +326,7 @@ pub async fn handle_forgot_password<E: UserEntity + 'static>(
                     let mut ctx = TeraCtx::new();
                     ctx.insert("username", &username);
                     ctx.insert("reset_url", &reset_url);
-                    if let Ok(msg) = mail.template(&request.engine.tera, tpl, ctx) {
+                    if let Ok(msg) = mail.template(&request.engine.tera.current(), tpl, ctx) {
                         let log_level = crate::utils::runique_log::get_log()
                             .auth
                             .as_ref()
@@ -425,6 +479,7 @@ pub async fn handle_password_reset<E: UserEntity + 'static>(
 
         match E::update_password_by_id(&db, user_id, &new_hash).await {
             Ok(()) => {
+                crate::auth::epoch::bump_session_epoch(user_id);
                 if let Some(level) = crate::utils::runique_log::get_log()
                     .auth
                     .as_ref()
@@ -0,0 +1,45 @@
+//! Permission strings derived from group rights, and wildcard matching
+//! (`blog.update`, `blog.*`, `*`).
+use crate::admin::permissions::Permission;
+
+/// `true` if the granted permission covers `required`.
+///
+/// `*` covers everything, `blog.*` covers `blog.edit` and `blog.post.delete`
+/// (but not `blog` itself nor `blogs.edit`), anything else must match exactly.
+pub fn permission_matches(granted: &str, required: &str) -> bool {
+    if granted == "*" || granted == required {
+        return true;
+    }
+    granted
+        .strip_suffix(".*")
+        .and_then(|prefix| required.strip_prefix(prefix))
+        .is_some_and(|rest| rest.starts_with('.') && rest.len() > 1)
+}
+
+/// Permission strings granted by one aggregated right: `"{resource}.{action}"` for
+/// each enabled flag (`create`, `read`, `update`, `delete`, `update_own`,
+/// `delete_own`), plus `"{resource}.*"` when create, read, update and delete are
+/// all enabled.
+pub fn granted_permissions(permission: &Permission) -> Vec<String> {
+    let key = &permission.resource_key;
+    let mut granted: Vec<String> = [
+        ("create", permission.can_create),
+        ("read", permission.can_read),
+        ("update", permission.can_update),
+        ("delete", permission.can_delete),
+        ("update_own", permission.can_update_own),
+        ("delete_own", permission.can_delete_own),
+    ]
+    .into_iter()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(action, _)| format!("{key}.{action}"))
+    .collect();
+    if permission.can_create
+        && permission.can_read
+        && permission.can_update
+        && permission.can_delete
+    {
+        granted.push(format!("{key}.*"));
+    }
+    granted
+}
@@ -8,8 +8,9 @@ use crate::utils::config::TraceResult;
 use crate::utils::constante::{
     admin_key::admin_context::permission::GROUPES,
     session_key::session::{
-        SESSION_ACTIVE_KEY, SESSION_USER_ID_KEY, SESSION_USER_IS_STAFF_KEY,
-        SESSION_USER_IS_SUPERUSER_KEY, SESSION_USER_USERNAME_KEY,
+        SESSION_ACTIVE_KEY, SESSION_EPOCH_KEY, SESSION_REMEMBER_ME_KEY, SESSION_TOTP_VERIFIED_KEY,
+        SESSION_USER_ID_KEY, SESSION_USER_IS_STAFF_KEY, SESSION_USER_IS_SUPERUSER_KEY,
+        SESSION_USER_USERNAME_KEY,
     },
 };
 use crate::utils::pk::Pk;
@@ -32,23 +33,57 @@ static AUTH_SESSION_TTL_SECS: std::sync::OnceLock<i64> = std::sync::OnceLock::ne
 /// Appelé une fois au build. Idempotent. Un second appel avec une valeur
 /// **différente** (deux apps dans le même process) est **loggé**, jamais avalé.
 pub fn set_auth_session_ttl_secs(secs: i64) {
-    match AUTH_SESSION_TTL_SECS.get() {
+    set_ttl_once(&AUTH_SESSION_TTL_SECS, secs, "auth session TTL");
+}
+
+/// Défaut « se souvenir de moi » si le builder n'a rien fixé (30 jours).
+const DEFAULT_REMEMBER_ME_TTL_SECS: i64 = 30 * 86_400;
+
+/// Durée de vie d'une session ouverte avec `remember = true`, en secondes.
+/// Posée une fois au build depuis `MiddlewareStaging.remember_me_duration`
+/// (builder `.with_remember_me_duration(...)`), comme [`AUTH_SESSION_TTL_SECS`].
+static REMEMBER_ME_TTL_SECS: std::sync::OnceLock<i64> = std::sync::OnceLock::new();
+
+/// Appelé une fois au build — même contrat que [`set_auth_session_ttl_secs`].
+pub fn set_remember_me_ttl_secs(secs: i64) {
+    set_ttl_once(&REMEMBER_ME_TTL_SECS, secs, "remember-me TTL");
+}
+
+fn set_ttl_once(cell: &std::sync::OnceLock<i64>, secs: i64, what: &str) {
+    match cell.get() {
         None => {
-            if AUTH_SESSION_TTL_SECS.set(secs).is_err() {
-                tracing::warn!("auth session TTL set raced at build — keeping the first value");
+            if cell.set(secs).is_err() {
+                tracing::warn!("{what} set raced at build — keeping the first value");
             }
         }
         Some(&existing) if existing != secs => {
             tracing::warn!(
                 existing,
                 attempted = secs,
-                "auth session TTL already set to a different value (multi-app in one process?) — keeping the first"
+                "{what} already set to a different value (multi-app in one process?) — keeping the first"
             );
         }
         Some(_) => {}
     }
 }
 
+/// TTL effectif d'une session « se souvenir de moi » (builder, sinon défaut).
+fn remember_me_ttl_secs() -> i64 {
+    REMEMBER_ME_TTL_SECS
+        .get()
+        .copied()
+        .unwrap_or(DEFAULT_REMEMBER_ME_TTL_SECS)
+}
+
+/// TTL d'une session authentifiée selon le choix « se souvenir de moi ».
+pub(crate) fn session_ttl_secs(remember: bool) -> i64 {
+    if remember {
+        remember_me_ttl_secs()
+    } else {
+        auth_session_ttl_secs()
+    }
+}
+
 /// Résolution pure du TTL (testable sans toucher au global).
 fn resolve_ttl_secs(configured: Option<i64>) -> i64 {
     configured.unwrap_or(DEFAULT_AUTH_SESSION_TTL_SECS)
@@ -170,6 +205,17 @@ pub trait UserEntity: Send + Sync + 'static {
             .ok_or_else(|| sea_orm::DbErr::RecordNotFound("User not found".into()))?;
         Self::update_password(db, user.email(), new_hash).await
     }
+
+    /// Persists the last accepted TOTP step of a user, read back through
+    /// [`RuniqueUser::totp_last_step`] — shares the replay protection between
+    /// processes and restarts. Default: not persisted.
+    async fn set_totp_last_step(
+        _db: &DatabaseConnection,
+        _id: crate::utils::pk::Pk,
+        _step: u64,
+    ) -> Result<(), sea_orm::DbErr> {
+        Ok(())
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -200,6 +246,9 @@ impl<E: UserEntity> AdminAuth for DefaultAdminAuth<E> {
         password: &str,
         db: &DatabaseConnection,
     ) -> Option<AdminLoginResult> {
+        // The admin login then goes through the TOTP step of `E`
+        crate::auth::totp::register_totp::<E>();
+
         // 1. Retrieve the user from the DB
         let user_opt = E::find_by_username(db, username).await;
 
@@ -283,6 +332,26 @@ impl CurrentUser {
     pub fn can_access_admin(&self) -> bool {
         self.is_staff || self.is_superuser
     }
+
+    /// Permission strings granted by the user's groups — see
+    /// [`granted_permissions`](crate::auth::permission::granted_permissions).
+    pub fn permissions(&self) -> Vec<String> {
+        self.permissions_effectives()
+            .iter()
+            .flat_map(crate::auth::permission::granted_permissions)
+            .collect()
+    }
+
+    /// Checks a permission such as `"blog.update"` against the group rights
+    /// (`blog.*` is granted by full CRUD). A superuser has every permission.
+    #[must_use]
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.is_superuser
+            || self
+                .permissions()
+                .iter()
+                .any(|granted| crate::auth::permission::permission_matches(granted, permission))
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -298,14 +367,14 @@ async fn session_bool(session: &Session, key: &str) -> bool {
         .unwrap_or(false)
 }
 
-/// Checks if the user is authenticated.
+/// Checks if the user is authenticated — a login waiting for its TOTP code is not.
 pub async fn is_authenticated(session: &Session) -> bool {
-    session
-        .get::<Pk>(SESSION_USER_ID_KEY)
-        .await
-        .ok()
-        .flatten()
-        .is_some()
+    get_user_id(session).await.is_some()
+}
+
+/// Checks if the session was opened with "remember me" (see [`login_user_full`]).
+pub async fn is_remembered(session: &Session) -> bool {
+    session_bool(session, SESSION_REMEMBER_ME_KEY).await
 }
 
 /// Checks if the user is authenticated and has admin access.
@@ -315,9 +384,20 @@ pub async fn is_admin_authenticated(session: &Session) -> bool {
             || session_bool(session, SESSION_USER_IS_SUPERUSER_KEY).await)
 }
 
-/// Retrieves the ID of the logged-in user.
+/// Retrieves the ID of the logged-in user — `None` unless the login went through
+/// the TOTP step (see [`crate::auth::totp`]).
 pub async fn get_user_id(session: &Session) -> Option<Pk> {
-    session.get::<Pk>(SESSION_USER_ID_KEY).await.ok().flatten()
+    let user_id = session
+        .get::<Pk>(SESSION_USER_ID_KEY)
+        .await
+        .ok()
+        .flatten()?;
+    session
+        .get::<bool>(SESSION_TOTP_VERIFIED_KEY)
+        .await
+        .ok()
+        .flatten()
+        .map(|_| user_id)
 }
 
 /// Retrieves the username of the logged-in user.
@@ -337,6 +417,7 @@ pub async fn get_username(session: &Session) -> Option<String> {
 ///
 /// If `db_store` is provided, persists the session in DB (multi-device).
 /// If `exclusive` is `true`, invalidates other sessions for the user.
+/// Same as [`login_user_full`] with `remember = false`.
 ///
 /// ```rust,ignore
 /// login(&session, &db, user.id, &user.username, user.is_staff, user.is_superuser, None, false).await?;
@@ -351,6 +432,81 @@ pub async fn login(
     is_superuser: bool,
     db_store: Option<&RuniqueSessionStore>,
     exclusive: bool,
+) -> Result<(), tower_sessions::session::Error> {
+    login_user_full(
+        session,
+        db,
+        user_id,
+        username,
+        is_staff,
+        is_superuser,
+        db_store,
+        exclusive,
+        false,
+    )
+    .await
+}
+
+/// [`login`] with a "remember me" choice.
+///
+/// `remember = true` keeps the session (cookie and DB row) for the remember-me duration
+/// (`with_remember_me_duration`, 30 days by default) instead of the standard
+/// authenticated duration (`with_session_duration`). The choice is stored in the session,
+/// so the per-request refresh keeps applying it.
+///
+/// A user with a [`totp_secret`](RuniqueUser::totp_secret) (entity registered, see
+/// [`crate::auth::totp`]) is not authenticated yet: the session waits for the code
+/// ([`is_totp_pending`](crate::auth::totp::is_totp_pending)), to check with
+/// [`complete_totp_login`](crate::auth::totp::complete_totp_login).
+///
+/// ```rust,ignore
+/// let remember = form.get_bool("remember_me");
+/// login_user_full(&session, &db, user.id, &user.username, user.is_staff, user.is_superuser, None, false, remember).await?;
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub async fn login_user_full(
+    session: &Session,
+    db: &DatabaseConnection,
+    user_id: Pk,
+    username: &str,
+    is_staff: bool,
+    is_superuser: bool,
+    db_store: Option<&RuniqueSessionStore>,
+    exclusive: bool,
+    remember: bool,
+) -> Result<(), tower_sessions::session::Error> {
+    if crate::auth::totp::requires_totp(db, user_id).await {
+        return crate::auth::totp::start_pending(session, user_id, remember).await;
+    }
+    open_session(
+        session,
+        db,
+        user_id,
+        username,
+        is_staff,
+        is_superuser,
+        db_store,
+        exclusive,
+        remember,
+        false,
+    )
+    .await
+}
+
+/// Authenticates the session, once the TOTP step is settled (`totp_verified`:
+/// a code was entered).
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn open_session(
+    session: &Session,
+    db: &DatabaseConnection,
+    user_id: Pk,
+    username: &str,
+    is_staff: bool,
+    is_superuser: bool,
+    db_store: Option<&RuniqueSessionStore>,
+    exclusive: bool,
+    remember: bool,
+    totp_verified: bool,
 ) -> Result<(), tower_sessions::session::Error> {
     // If another session is already active, perform a clean logout before login
     let existing_id: Option<_> = session.get::<Pk>(SESSION_USER_ID_KEY).await.ok().flatten();
@@ -409,11 +565,22 @@ pub async fn login(
         .insert(SESSION_USER_IS_SUPERUSER_KEY, is_superuser)
         .await?;
 
+    crate::auth::epoch::stamp_session_epoch(session, db, user_id).await?;
+
+    if remember {
+        session.insert(SESSION_REMEMBER_ME_KEY, true).await?;
+    } else {
+        session.remove::<bool>(SESSION_REMEMBER_ME_KEY).await?;
+    }
+    session
+        .insert(SESSION_TOTP_VERIFIED_KEY, totp_verified)
+        .await?;
+
     // Promote the session TTL to the authenticated duration on the login request
     // itself, so the first persisted row already carries the long expiry instead of
     // the 5-min anonymous window. The ttl-upgrade middleware only kicks in from the
     // next request; without this, a restart in that first window logs the user out.
-    let ttl_secs = auth_session_ttl_secs();
+    let ttl_secs = session_ttl_secs(remember);
     session.set_expiry(Some(tower_sessions::Expiry::OnInactivity(
         tower_sessions::cookie::time::Duration::seconds(ttl_secs),
     )));
@@ -534,6 +701,9 @@ pub async fn logout(
         .await?;
     session.remove::<Vec<Groupe>>(GROUPES).await?;
     session.remove::<i64>(SESSION_ACTIVE_KEY).await?;
+    session.remove::<bool>(SESSION_REMEMBER_ME_KEY).await?;
+    session.remove::<String>(SESSION_EPOCH_KEY).await?;
+    session.remove::<bool>(SESSION_TOTP_VERIFIED_KEY).await?;
     session.delete().await
 }
 
@@ -556,7 +726,66 @@ pub async fn unprotect_session(session: &Session) -> Result<(), tower_sessions::
 // Axum Middlewares
 // ═══════════════════════════════════════════════════════════════
 
+/// Groups of `user_id` from cache — DB reload if cache is empty (after clear_cache).
+///
+/// The reload is the moment a rights change becomes visible in this user's
+/// context; trace it so that effect is observable (`auth.permissions`).
+pub(crate) async fn cached_groupes(
+    db: &DatabaseConnection,
+    user_id: Pk,
+    username: &str,
+) -> Vec<Groupe> {
+    if let Some(cached) = get_permissions(user_id) {
+        return cached.groupes.clone();
+    }
+    let groupes = pull_groupes_db(db, user_id).await;
+    if let Some(level) = crate::utils::runique_log::get_log()
+        .auth
+        .as_ref()
+        .and_then(|a| a.permissions)
+    {
+        crate::runique_log!(
+            level,
+            user = %username,
+            groupes = groupes.len(),
+            "permission cache miss — reloaded from DB (context refreshed)"
+        );
+    }
+    cache_permissions(user_id, groupes.clone());
+    groupes
+}
+
+/// [`CurrentUser`] of an authenticated session: admin flags from the session,
+/// groups from the permission cache.
+pub(crate) async fn session_current_user(
+    session: &Session,
+    db: &DatabaseConnection,
+) -> Option<CurrentUser> {
+    let user_id = get_user_id(session).await?;
+    let username = get_username(session).await?;
+    Some(session_user(session, db, user_id, username).await)
+}
+
+async fn session_user(
+    session: &Session,
+    db: &DatabaseConnection,
+    user_id: Pk,
+    username: String,
+) -> CurrentUser {
+    let groupes = cached_groupes(db, user_id, &username).await;
+    CurrentUser {
+        id: user_id,
+        username,
+        is_staff: session_bool(session, SESSION_USER_IS_STAFF_KEY).await,
+        is_superuser: session_bool(session, SESSION_USER_IS_SUPERUSER_KEY).await,
+        groupes,
+    }
+}
+
 /// Middleware: loads user info into the request extensions.
+///
+/// A session stamped with an outdated epoch (password changed since login, see
+/// [`crate::auth::epoch`]) is logged out and the request continues anonymously.
 pub async fn load_user_middleware(
     axum::extract::State(db): axum::extract::State<crate::utils::aliases::ADb>,
     session: Session,
@@ -566,44 +795,29 @@ pub async fn load_user_middleware(
     if let (Some(user_id), Some(username)) =
         (get_user_id(&session).await, get_username(&session).await)
     {
-        let is_staff = session_bool(&session, SESSION_USER_IS_STAFF_KEY).await;
-        let is_superuser = session_bool(&session, SESSION_USER_IS_SUPERUSER_KEY).await;
-
-        // Groups from cache — DB reload if cache is empty (after clear_cache).
-        // The reload is the moment a rights change becomes visible in this user's
-        // context; trace it so that effect is observable (`auth.permissions`).
-        let groupes = match get_permissions(user_id) {
-            Some(cached) => cached.groupes.clone(),
-            None => {
-                let groupes = pull_groupes_db(&*db, user_id).await;
-                if let Some(level) = crate::utils::runique_log::get_log()
-                    .auth
-                    .as_ref()
-                    .and_then(|a| a.permissions)
-                {
-                    crate::runique_log!(
-                        level,
-                        user = %username,
-                        groupes = groupes.len(),
-                        "permission cache miss — reloaded from DB (context refreshed)"
-                    );
-                }
-                cache_permissions(user_id, groupes.clone());
-                groupes
+        if !crate::auth::epoch::session_epoch_matches(&session, &db, user_id).await {
+            if let Some(level) = crate::utils::runique_log::get_log()
+                .auth
+                .as_ref()
+                .and_then(|a| a.login)
+            {
+                crate::runique_log!(level, user_id = %user_id, "session epoch changed — logout");
             }
-        };
+            logout(&session, None).await.trace(
+                crate::utils::runique_log::get_log()
+                    .session
+                    .as_ref()
+                    .and_then(|s| s.store),
+                "logout on session epoch change",
+            );
+            return next.run(request).await;
+        }
 
-        let current_user = CurrentUser {
-            id: user_id,
-            username,
-            is_staff,
-            is_superuser,
-            groupes,
-        };
+        let current_user = session_user(&session, &db, user_id, username).await;
 
         let extensions = RequestExtensions::new().with_current_user(current_user);
         extensions.inject_request(&mut request);
-    } else if session.id().is_some() {
+    } else if session.id().is_some() && !crate::auth::totp::is_totp_pending(&session).await {
         session.delete().await.trace(
             crate::utils::runique_log::get_log()
                 .session
@@ -0,0 +1,138 @@
+//! Stateless password reset tokens — HMAC-SHA256 over `user_id`, expiry and the
+//! current password hash.
+//!
+//! No table involved: the token carries `{user_id}.{expires}.{signature}`. Mixing the
+//! password hash into the signature makes it single-use — once the password changes,
+//! every token issued before no longer verifies. For the DB-backed flow with email
+//! links, see [`PasswordResetConfig`](crate::auth::PasswordResetConfig).
+//!
+//! Key rotation: tokens are signed with `SECRET_KEY` only, and verified against
+//! [`ServerConfig::secret_keys`](crate::config::ServerConfig::secret_keys) — the
+//! primary key, then `SECRET_KEY_FALLBACKS` — with the `_keys` variants.
+use crate::auth::session::UserEntity;
+use crate::auth::user_trait::RuniqueUser;
+use crate::utils::pk::Pk;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use hmac::{Hmac, KeyInit, Mac};
+use sea_orm::DatabaseConnection;
+use sha2::Sha256;
+use std::time::Duration;
+use subtle::ConstantTimeEq;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Default lifetime of a reset token: 1 hour.
+pub const DEFAULT_RESET_TOKEN_TTL: Duration = Duration::from_secs(3600);
+
+fn sign(secret_key: &str, user_id: Pk, expires: i64, password_hash: &str) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret_key.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(format!("reset:{user_id}:{expires}:{password_hash}").as_bytes());
+    URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes())
+}
+
+fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+/// Token issued at `now` (unix seconds) — see [`make_reset_token`].
+pub fn make_reset_token_at(
+    secret_key: &str,
+    user_id: Pk,
+    password_hash: &str,
+    ttl: Duration,
+    now: i64,
+) -> String {
+    let ttl = i64::try_from(ttl.as_secs()).unwrap_or(i64::MAX);
+    let expires = now.saturating_add(ttl);
+    let signature = sign(secret_key, user_id, expires, password_hash);
+    format!("{user_id}.{expires}.{signature}")
+}
+
+/// Signed reset token for `user_id`, valid for `ttl` and until the password changes.
+///
+/// ```rust,ignore
+/// let token = make_reset_token(
+///     &engine.config.server.secret_key,
+///     user.user_id(),
+///     user.password_hash(),
+///     DEFAULT_RESET_TOKEN_TTL,
+/// );
+/// ```
+pub fn make_reset_token(
+    secret_key: &str,
+    user_id: Pk,
+    password_hash: &str,
+    ttl: Duration,
+) -> String {
+    make_reset_token_at(secret_key, user_id, password_hash, ttl, now())
+}
+
+/// User id carried by the token, **not verified** — only to load the user whose
+/// hash [`verify_reset_token_with`] needs.
+pub fn reset_token_user_id(token: &str) -> Option<Pk> {
+    token.split('.').next()?.parse().ok()
+}
+
+/// Verifies `token` against the user's current `password_hash` at `now`.
+/// Returns the user id when the signature matches and the token has not expired.
+pub fn verify_reset_token_with(
+    secret_key: &str,
+    token: &str,
+    password_hash: &str,
+    now: i64,
+) -> Option<Pk> {
+    verify_reset_token_with_keys(&[secret_key], token, password_hash, now)
+}
+
+/// Same as [`verify_reset_token_with`], accepting a signature from any of `keys`.
+/// Keys are tried in order, each with a constant-time comparison.
+pub fn verify_reset_token_with_keys(
+    keys: &[&str],
+    token: &str,
+    password_hash: &str,
+    now: i64,
+) -> Option<Pk> {
+    let mut parts = token.split('.');
+    let user_id: Pk = parts.next()?.parse().ok()?;
+    let expires: i64 = parts.next()?.parse().ok()?;
+    let signature = parts.next()?;
+    if parts.next().is_some() || now > expires {
+        return None;
+    }
+    keys.iter()
+        .any(|key| {
+            let expected = sign(key, user_id, expires, password_hash);
+            bool::from(expected.as_bytes().ct_eq(signature.as_bytes()))
+        })
+        .then_some(user_id)
+}
+
+/// Loads the user named by the token and verifies it: returns the user id if the
+/// token is authentic, unexpired, and the password has not changed since.
+pub async fn verify_reset_token<E: UserEntity>(
+    db: &DatabaseConnection,
+    secret_key: &str,
+    token: &str,
+) -> Option<Pk> {
+    verify_reset_token_keys::<E>(db, &[secret_key], token).await
+}
+
+/// Same as [`verify_reset_token`] during a key rotation — a token signed by any
+/// of `keys` is accepted.
+///
+/// ```rust,ignore
+/// let keys = request.engine.config.server.secret_keys();
+/// let user_id = verify_reset_token_keys::<users::Entity>(&db, &keys, &token).await;
+/// ```
+pub async fn verify_reset_token_keys<E: UserEntity>(
+    db: &DatabaseConnection,
+    keys: &[&str],
+    token: &str,
+) -> Option<Pk> {
+    let user = E::find_by_id(db, reset_token_user_id(token)?).await?;
+    if !user.is_active() {
+        return None;
+    }
+    verify_reset_token_with_keys(keys, token, user.password_hash(), now())
+}
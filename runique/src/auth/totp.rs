@@ -0,0 +1,463 @@
+//! TOTP two-factor authentication (RFC 6238): secret, provisioning URI, verification, login step.
+//!
+//! Every login goes through the TOTP step once the user entity is known — registered
+//! via [`register_totp`] (builder: `.with_totp::<E>()`), or implicitly by
+//! [`login_with_totp`], [`complete_totp_login`] and `DefaultAdminAuth<E>`. Several
+//! entities can be registered (admin accounts and site users).
+use crate::auth::session::{UserEntity, open_session};
+use crate::auth::user_trait::RuniqueUser;
+use crate::middleware::session::session_db::RuniqueSessionStore;
+use crate::utils::constante::session_key::session::{
+    SESSION_TOTP_ATTEMPTS_KEY, SESSION_TOTP_PENDING_KEY, SESSION_TOTP_REMEMBER_KEY,
+    SESSION_TOTP_VERIFIED_KEY,
+};
+use crate::utils::pk::Pk;
+use data_encoding::BASE32_NOPAD;
+use futures_util::future::BoxFuture;
+use hmac::{Hmac, KeyInit, Mac};
+use sea_orm::DatabaseConnection;
+use sha1::Sha1;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+use subtle::ConstantTimeEq;
+use tower_sessions::Session;
+
+/// Duration of a TOTP step in seconds.
+pub const TOTP_PERIOD_SECS: u64 = 30;
+/// Number of digits of a code.
+pub const TOTP_DIGITS: u32 = 6;
+/// Failed codes allowed before the pending login is dropped.
+pub const TOTP_MAX_ATTEMPTS: u32 = 5;
+
+/// Accepted steps before / after the current one (clock drift).
+const TOTP_SKEW: i64 = 1;
+/// 160-bit secrets, as recommended by RFC 4226.
+const TOTP_SECRET_BYTES: usize = 20;
+
+/// Generates a random base32 secret (no padding), to store on the user.
+pub fn generate_totp_secret() -> String {
+    let bytes: [u8; TOTP_SECRET_BYTES] = rand::random();
+    BASE32_NOPAD.encode(&bytes)
+}
+
+/// `otpauth://` URI to encode as a QR code for authenticator apps.
+///
+/// ```rust,ignore
+/// let uri = totp_provisioning_uri(&secret, "alice@example.com", "Mon site");
+/// ```
+pub fn totp_provisioning_uri(secret: &str, account: &str, issuer: &str) -> String {
+    let issuer = urlencoding::encode(issuer);
+    format!(
+        "otpauth://totp/{issuer}:{account}?secret={secret}&issuer={issuer}\
+         &algorithm=SHA1&digits={TOTP_DIGITS}&period={TOTP_PERIOD_SECS}",
+        account = urlencoding::encode(account),
+    )
+}
+
+/// Decodes a base32 secret — case, spaces and `=` padding are tolerated.
+fn decode_secret(secret: &str) -> Option<Vec<u8>> {
+    let normalized: String = secret
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '=')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    BASE32_NOPAD
+        .decode(normalized.as_bytes())
+        .ok()
+        .filter(|k| !k.is_empty())
+}
+
+fn hotp(key: &[u8], counter: u64) -> String {
+    let mut mac =
+        <Hmac<Sha1> as KeyInit>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(&counter.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+    let offset = usize::from(digest[digest.len() - 1] & 0x0f);
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    format!(
+        "{:0width$}",
+        binary % 10u32.pow(TOTP_DIGITS),
+        width = TOTP_DIGITS as usize
+    )
+}
+
+/// Code expected at `unix_secs`, `None` if the secret is not valid base32.
+pub fn totp_code_at(secret: &str, unix_secs: u64) -> Option<String> {
+    let key = decode_secret(secret)?;
+    Some(hotp(&key, unix_secs / TOTP_PERIOD_SECS))
+}
+
+/// Verifies `code` against `secret` at `unix_secs`, with ±1 step tolerance.
+///
+/// Returns the matched time step, to record as the user's last accepted step.
+/// Steps at or below `last_step` are refused, so a code is accepted only once.
+pub fn verify_totp_at(
+    secret: &str,
+    code: &str,
+    unix_secs: u64,
+    last_step: Option<u64>,
+) -> Option<u64> {
+    let code = code.trim();
+    if code.len() != TOTP_DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let key = decode_secret(secret)?;
+    let counter = (unix_secs / TOTP_PERIOD_SECS) as i64;
+    (-TOTP_SKEW..=TOTP_SKEW)
+        .filter_map(|delta| u64::try_from(counter + delta).ok())
+        .filter(|step| last_step.is_none_or(|last| *step > last))
+        .fold(None, |found, step| {
+            let hit = bool::from(hotp(&key, step).as_bytes().ct_eq(code.as_bytes()));
+            found.or(hit.then_some(step))
+        })
+}
+
+/// Verifies `code` against `secret` at the current time (30 s window, ±1 step) —
+/// see [`verify_totp_at`].
+pub fn verify_totp(secret: &str, code: &str, last_step: Option<u64>) -> Option<u64> {
+    let now = chrono::Utc::now().timestamp().max(0) as u64;
+    verify_totp_at(secret, code, now, last_step)
+}
+
+// ═══════════════════════════════════════════════════════════════
+// User entity
+// ═══════════════════════════════════════════════════════════════
+
+/// What the TOTP step needs from a user, whatever its entity.
+pub(crate) struct TotpAccount {
+    pub(crate) user_id: Pk,
+    pub(crate) username: String,
+    pub(crate) is_active: bool,
+    pub(crate) is_staff: bool,
+    pub(crate) is_superuser: bool,
+    pub(crate) secret: Option<String>,
+    pub(crate) last_step: Option<u64>,
+}
+
+type FindAccount = for<'a> fn(&'a DatabaseConnection, Pk) -> BoxFuture<'a, Option<TotpAccount>>;
+type RecordStep =
+    for<'a> fn(&'a DatabaseConnection, Pk, u64) -> BoxFuture<'a, Result<(), sea_orm::DbErr>>;
+
+/// Entity-erased access to the users, so `login_user_full` and the admin can
+/// enforce the TOTP step without knowing `E`.
+#[derive(Clone, Copy)]
+pub(crate) struct TotpBackend {
+    find: FindAccount,
+    record_step: RecordStep,
+}
+
+static TOTP_BACKENDS: LazyLock<RwLock<Vec<(TypeId, TotpBackend)>>> =
+    LazyLock::new(|| RwLock::new(Vec::new()));
+
+/// Last accepted step per user in this process — the floor when the entity does
+/// not persist it ([`UserEntity::set_totp_last_step`]).
+static LAST_STEPS: LazyLock<RwLock<HashMap<Pk, u64>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+fn find_account<E: UserEntity>(
+    db: &DatabaseConnection,
+    user_id: Pk,
+) -> BoxFuture<'_, Option<TotpAccount>> {
+    Box::pin(async move {
+        E::find_by_id(db, user_id).await.map(|user| TotpAccount {
+            user_id: user.user_id(),
+            username: user.username().to_string(),
+            is_active: user.is_active(),
+            is_staff: user.is_staff(),
+            is_superuser: user.is_superuser(),
+            secret: user.totp_secret().map(str::to_string),
+            last_step: user.totp_last_step(),
+        })
+    })
+}
+
+fn record_step<E: UserEntity>(
+    db: &DatabaseConnection,
+    user_id: Pk,
+    step: u64,
+) -> BoxFuture<'_, Result<(), sea_orm::DbErr>> {
+    Box::pin(E::set_totp_last_step(db, user_id, step))
+}
+
+impl TotpBackend {
+    fn of<E: UserEntity>() -> Self {
+        Self {
+            find: find_account::<E>,
+            record_step: record_step::<E>,
+        }
+    }
+}
+
+/// Makes `login_user_full` (and so the admin login) stop at the TOTP step for
+/// users of `E` with a [`totp_secret`](RuniqueUser::totp_secret). Idempotent.
+pub fn register_totp<E: UserEntity>() {
+    let mut backends = TOTP_BACKENDS.write().unwrap_or_else(|p| {
+        tracing::warn!("TOTP entities lock poisoned (recovered, register)");
+        p.into_inner()
+    });
+    if backends.iter().all(|(id, _)| *id != TypeId::of::<E>()) {
+        backends.push((TypeId::of::<E>(), TotpBackend::of::<E>()));
+    }
+}
+
+fn registered_backends() -> Vec<TotpBackend> {
+    let backends = TOTP_BACKENDS.read().unwrap_or_else(|p| {
+        tracing::warn!("TOTP entities lock poisoned (recovered, read)");
+        p.into_inner()
+    });
+    backends.iter().map(|(_, backend)| *backend).collect()
+}
+
+/// Registered entity holding a TOTP secret for `user_id`.
+async fn totp_backend_of(db: &DatabaseConnection, user_id: Pk) -> Option<TotpBackend> {
+    for backend in registered_backends() {
+        if (backend.find)(db, user_id)
+            .await
+            .is_some_and(|account| account.secret.is_some())
+        {
+            return Some(backend);
+        }
+    }
+    None
+}
+
+/// `true` if `user_id` has to pass the TOTP step before being authenticated.
+pub(crate) async fn requires_totp(db: &DatabaseConnection, user_id: Pk) -> bool {
+    totp_backend_of(db, user_id).await.is_some()
+}
+
+fn local_last_step(user_id: Pk) -> Option<u64> {
+    let steps = LAST_STEPS.read().unwrap_or_else(|p| {
+        tracing::warn!("TOTP step lock poisoned (recovered, read)");
+        p.into_inner()
+    });
+    steps.get(&user_id).copied()
+}
+
+fn store_local_last_step(user_id: Pk, step: u64) {
+    let mut steps = LAST_STEPS.write().unwrap_or_else(|p| {
+        tracing::warn!("TOTP step lock poisoned (recovered, insert)");
+        p.into_inner()
+    });
+    let last = steps.entry(user_id).or_insert(step);
+    *last = (*last).max(step);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Two-step login
+// ═══════════════════════════════════════════════════════════════
+
+/// Result of [`login_with_totp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginStep {
+    /// Session authenticated (account without TOTP secret)
+    Authenticated,
+    /// Password accepted, the TOTP code is still required — see [`complete_totp_login`]
+    TotpRequired,
+    /// Unknown or inactive account: nothing written in the session
+    Refused,
+}
+
+/// Logs in a user from their id, stopping at the TOTP step when the account
+/// has a [`totp_secret`](RuniqueUser::totp_secret).
+///
+/// Call it once the password is verified. `db_store`, `exclusive` and `remember`
+/// are those of [`login_user_full`](crate::auth::session::login_user_full); the
+/// session is only authenticated after [`complete_totp_login`] succeeds.
+///
+/// ```rust,ignore
+/// let store = engine.session_db_store.read().ok().and_then(|g| g.as_ref().cloned());
+/// let exclusive = engine.features.exclusive_login;
+/// match login_with_totp::<users::Entity>(&session, &db, user.id, store.as_deref(), exclusive, remember).await? {
+///     LoginStep::Authenticated => Redirect::to("/dashboard"),
+///     LoginStep::TotpRequired => Redirect::to("/login/totp"),
+///     LoginStep::Refused => Redirect::to("/login"),
+/// }
+/// ```
+pub async fn login_with_totp<E: UserEntity>(
+    session: &Session,
+    db: &DatabaseConnection,
+    user_id: Pk,
+    db_store: Option<&RuniqueSessionStore>,
+    exclusive: bool,
+    remember: bool,
+) -> Result<LoginStep, tower_sessions::session::Error> {
+    register_totp::<E>();
+    let Some(account) = find_account::<E>(db, user_id).await else {
+        return Ok(LoginStep::Refused);
+    };
+    if !account.is_active {
+        return Ok(LoginStep::Refused);
+    }
+    if account.secret.is_some() {
+        start_pending(session, user_id, remember).await?;
+        return Ok(LoginStep::TotpRequired);
+    }
+    open_account_session(session, db, &account, db_store, exclusive, remember, false).await?;
+    Ok(LoginStep::Authenticated)
+}
+
+/// Second step: checks `code` for the pending user and authenticates the session,
+/// with the `remember` choice made at the password step.
+///
+/// Returns `false` on a wrong or already used code. After [`TOTP_MAX_ATTEMPTS`]
+/// failures the pending login is dropped and the password must be entered again.
+pub async fn complete_totp_login<E: UserEntity>(
+    session: &Session,
+    db: &DatabaseConnection,
+    code: &str,
+    db_store: Option<&RuniqueSessionStore>,
+    exclusive: bool,
+) -> Result<bool, tower_sessions::session::Error> {
+    register_totp::<E>();
+    complete_with(
+        TotpBackend::of::<E>(),
+        session,
+        db,
+        code,
+        db_store,
+        exclusive,
+    )
+    .await
+}
+
+/// [`complete_totp_login`] through the registered entity — admin login.
+pub(crate) async fn complete_registered_totp_login(
+    session: &Session,
+    db: &DatabaseConnection,
+    code: &str,
+    db_store: Option<&RuniqueSessionStore>,
+    exclusive: bool,
+) -> Result<bool, tower_sessions::session::Error> {
+    let Some(user_id) = session.get::<Pk>(SESSION_TOTP_PENDING_KEY).await? else {
+        return Ok(false);
+    };
+    match totp_backend_of(db, user_id).await {
+        Some(backend) => complete_with(backend, session, db, code, db_store, exclusive).await,
+        None => {
+            clear_pending(session).await?;
+            Ok(false)
+        }
+    }
+}
+
+async fn complete_with(
+    backend: TotpBackend,
+    session: &Session,
+    db: &DatabaseConnection,
+    code: &str,
+    db_store: Option<&RuniqueSessionStore>,
+    exclusive: bool,
+) -> Result<bool, tower_sessions::session::Error> {
+    let Some(user_id) = session.get::<Pk>(SESSION_TOTP_PENDING_KEY).await? else {
+        return Ok(false);
+    };
+    let account = (backend.find)(db, user_id).await.filter(|a| a.is_active);
+    let step = account.as_ref().and_then(|account| {
+        let last_step = account.last_step.max(local_last_step(user_id));
+        verify_totp(account.secret.as_deref()?, code, last_step)
+    });
+
+    match (account, step) {
+        (Some(account), Some(step)) => {
+            store_local_last_step(user_id, step);
+            if let Err(e) = (backend.record_step)(db, user_id, step).await {
+                tracing::warn!(user_id = %user_id, error = %e, "persist TOTP step");
+            }
+            let remember = session
+                .get::<bool>(SESSION_TOTP_REMEMBER_KEY)
+                .await?
+                .unwrap_or(false);
+            clear_pending(session).await?;
+            open_account_session(session, db, &account, db_store, exclusive, remember, true)
+                .await?;
+            Ok(true)
+        }
+        (Some(_), None) => {
+            let attempts = session
+                .get::<u32>(SESSION_TOTP_ATTEMPTS_KEY)
+                .await?
+                .unwrap_or(0)
+                .saturating_add(1);
+            if attempts >= TOTP_MAX_ATTEMPTS {
+                clear_pending(session).await?;
+            } else {
+                session.insert(SESSION_TOTP_ATTEMPTS_KEY, attempts).await?;
+            }
+            Ok(false)
+        }
+        (None, _) => {
+            clear_pending(session).await?;
+            Ok(false)
+        }
+    }
+}
+
+/// `true` while a login waits for its TOTP code.
+pub async fn is_totp_pending(session: &Session) -> bool {
+    session
+        .get::<Pk>(SESSION_TOTP_PENDING_KEY)
+        .await
+        .ok()
+        .flatten()
+        .is_some()
+}
+
+/// `true` once a TOTP code has been entered for this session — `false` for an
+/// account without a secret.
+pub async fn is_totp_verified(session: &Session) -> bool {
+    session
+        .get::<bool>(SESSION_TOTP_VERIFIED_KEY)
+        .await
+        .ok()
+        .flatten()
+        .unwrap_or(false)
+}
+
+/// Password accepted for `user_id`: waits for the code without authenticating.
+pub(crate) async fn start_pending(
+    session: &Session,
+    user_id: Pk,
+    remember: bool,
+) -> Result<(), tower_sessions::session::Error> {
+    session.insert(SESSION_TOTP_PENDING_KEY, user_id).await?;
+    session.insert(SESSION_TOTP_ATTEMPTS_KEY, 0u32).await?;
+    session.insert(SESSION_TOTP_REMEMBER_KEY, remember).await
+}
+
+async fn clear_pending(session: &Session) -> Result<(), tower_sessions::session::Error> {
+    session.remove::<Pk>(SESSION_TOTP_PENDING_KEY).await?;
+    session.remove::<u32>(SESSION_TOTP_ATTEMPTS_KEY).await?;
+    session.remove::<bool>(SESSION_TOTP_REMEMBER_KEY).await?;
+    Ok(())
+}
+
+async fn open_account_session(
+    session: &Session,
+    db: &DatabaseConnection,
+    account: &TotpAccount,
+    db_store: Option<&RuniqueSessionStore>,
+    exclusive: bool,
+    remember: bool,
+    totp_verified: bool,
+) -> Result<(), tower_sessions::session::Error> {
+    open_session(
+        session,
+        db,
+        account.user_id,
+        &account.username,
+        account.is_staff,
+        account.is_superuser,
+        db_store,
+        exclusive,
+        remember,
+        totp_verified,
+    )
+    .await
+}
@@ -2,7 +2,7 @@
 pub use crate::auth::{session::UserEntity, user_trait::RuniqueUser};
 use crate::utils::config::TraceResult;
 use crate::utils::pk::Pk;
-use crate::{impl_objects, search};
+use crate::{impl_objects, impl_signals, search};
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, DatabaseConnection, EntityTrait, entity::prelude::*,
 };
@@ -46,7 +46,11 @@ impl Related<crate::middleware::session::session_db::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+impl_signals!(
+    ActiveModel,
+    auto_now = [Column::CreatedAt],
+    auto_now_update = [Column::UpdatedAt],
+);
 
 // ─── RuniqueUser ─────────────────────────────────────────────────────────────
 impl RuniqueUser for Model {
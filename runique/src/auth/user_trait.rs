@@ -17,6 +17,26 @@ pub trait RuniqueUser: Send + Sync {
     /// Full admin access — bypasses all permission checks.
     fn is_superuser(&self) -> bool;
 
+    /// Base32 TOTP secret — `Some` enables the second login step (2FA).
+    /// `None` by default.
+    fn totp_secret(&self) -> Option<&str> {
+        None
+    }
+
+    /// Last TOTP time step accepted for this user — codes at or below it are
+    /// refused. Persisted by [`UserEntity::set_totp_last_step`](crate::auth::session::UserEntity::set_totp_last_step);
+    /// `None` by default (replay protection then holds per process only).
+    fn totp_last_step(&self) -> Option<u64> {
+        None
+    }
+
+    /// Value stamped into the session at login — when it changes, every older session
+    /// is logged out (see [`crate::auth::epoch`]). Default: a digest of the password hash,
+    /// so a password change invalidates all sessions.
+    fn session_epoch(&self) -> String {
+        crate::auth::epoch::password_epoch(self.password_hash())
+    }
+
     /// Custom roles. Returns an empty Vec by default.
     fn roles(&self) -> Vec<String> {
         vec![]
@@ -1,8 +1,8 @@
-//! CLI binary `runique` — `new`, `start`, `makemigration`, `migrate` commands via clap.
+//! CLI binary `runique` — `new`, `startapp`, `start`, `makemigration`, `migrate` commands via clap.
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use runique::utils::{
-    cli::{create_superuser, makemigration, migrate, runique_start},
+    cli::{create_app, create_superuser, makemigration, migrate, runique_start},
     create_new_project, init_logging,
     trad::{Lang, set_lang},
 };
@@ -22,6 +22,12 @@ enum Commands {
     /// Generates the complete structure with routes, views, models and templates.
     New { name: String },
 
+    /// Create an app module in the current project
+    ///
+    /// Generates src/<name>/ (models, forms, views, url, admin stub) and
+    /// templates/<name>/index.html, without overwriting existing files.
+    Startapp { name: String },
+
     /// Start Runique services (from the project root)
     ///
     /// Automatically detects the project configuration:
@@ -37,7 +43,14 @@ enum Commands {
         admin: String,
     },
     /// Create an admin superuser
-    CreateSuperuser,
+    ///
+    /// With `--noinput`, reads RUNIQUE_SUPERUSER_USERNAME, RUNIQUE_SUPERUSER_EMAIL
+    /// and RUNIQUE_SUPERUSER_PASSWORD instead of prompting.
+    CreateSuperuser {
+        /// Create the account from environment variables, without prompts
+        #[arg(long)]
+        noinput: bool,
+    },
     Migration {
         #[command(subcommand)]
         action: MigrateAction,
@@ -57,6 +70,14 @@ enum MigrateAction {
     Up {
         #[arg(long, default_value = "migration/src")]
         migrations: String,
+        /// Print the SQL of pending migrations without executing it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Print the SQL of pending migrations without executing it (same as `up --dry-run`)
+    Plan {
+        #[arg(long, default_value = "migration/src")]
+        migrations: String,
     },
     Down {
         #[arg(long, default_value = "migration/src")]
@@ -70,6 +91,20 @@ enum MigrateAction {
         #[arg(long, default_value = "migration/src")]
         migrations: String,
     },
+    /// Roll back the N most recent batches, newest first, or with `--to`
+    /// until the given migration is the latest applied one
+    Rollback {
+        #[arg(long, default_value = "migration/src")]
+        migrations: String,
+        #[arg(long, default_value = "1")]
+        steps: usize,
+        /// Migration name (e.g. m20260221_163108_create_blog_table) to roll back to
+        #[arg(long, conflicts_with = "steps")]
+        to: Option<String>,
+        /// Skip the confirmation prompt of `--to`
+        #[arg(long, requires = "to")]
+        yes: bool,
+    },
 }
 
 #[tokio::main]
@@ -90,10 +125,18 @@ async fn main() -> Result<()> {
 
     match cli.command {
         Commands::New { name } => create_new_project(&name)?,
+        Commands::Startapp { name } => create_app(std::path::Path::new("."), &name)?,
         Commands::Start { main, admin } => runique_start(&main, &admin)?,
-        Commands::CreateSuperuser => create_superuser().await?,
+        Commands::CreateSuperuser { noinput } => create_superuser(noinput).await?,
         Commands::Migration { action } => match action {
-            MigrateAction::Up { migrations } => {
+            MigrateAction::Up {
+                migrations,
+                dry_run: true,
+            }
+            | MigrateAction::Plan { migrations } => {
+                migrate::plan(&migrations).await?;
+            }
+            MigrateAction::Up { migrations, .. } => {
                 migrate::up(&migrations).await?;
             }
             MigrateAction::Down {
@@ -104,8 +147,25 @@ async fn main() -> Result<()> {
                 migrate::down(&migrations, files, batch).await?;
             }
             MigrateAction::Status { migrations } => {
+                // Rollback files are listed even when the database cannot be read
+                if let Err(e) = migrate::show(&migrations).await {
+                    eprintln!("{:#}\n", e);
+                }
                 migrate::status(&migrations)?;
             }
+            MigrateAction::Rollback {
+                migrations,
+                to: Some(target),
+                yes,
+                ..
+            } => {
+                migrate::rollback_to(&migrations, &target, yes).await?;
+            }
+            MigrateAction::Rollback {
+                migrations, steps, ..
+            } => {
+                migrate::rollback(&migrations, steps).await?;
+            }
         },
         Commands::Makemigrations {
             entities,
@@ -0,0 +1,103 @@
+//! `MemoryCache` — in-process map with per-entry expiry.
+use crate::cache::{Cache, CacheError};
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Expired entries are swept every this many writes (reads skip them anyway).
+const SWEEP_EVERY: usize = 1024;
+
+/// Process-local cache — the default. Lost on restart, not shared between instances
+/// (use [`RedisCache`](super::RedisCache) for that). Clones share the same entries.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCache {
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    entries: HashMap<String, (Vec<u8>, Instant)>,
+    writes: usize,
+}
+
+impl MemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Live entries.
+    pub fn len(&self) -> usize {
+        let now = Instant::now();
+        self.lock()
+            .entries
+            .values()
+            .filter(|(_, expires)| *expires > now)
+            .count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait]
+impl Cache for MemoryCache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, CacheError> {
+        let mut inner = self.lock();
+        match inner.entries.get(key) {
+            Some((value, expires)) if *expires > Instant::now() => Ok(Some(value.clone())),
+            Some(_) => {
+                inner.entries.remove(key);
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn set_with_ttl(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Duration,
+    ) -> Result<(), CacheError> {
+        let now = Instant::now();
+        let mut inner = self.lock();
+        inner.writes += 1;
+        if inner.writes.is_multiple_of(SWEEP_EVERY) {
+            inner.entries.retain(|_, (_, expires)| *expires > now);
+        }
+        // `Duration::MAX` and the like: kept until deleted
+        let expires = now
+            .checked_add(ttl)
+            .unwrap_or_else(|| now + Duration::from_secs(u32::MAX.into()));
+        inner.entries.insert(key.to_string(), (value, expires));
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), CacheError> {
+        self.lock().entries.remove(key);
+        Ok(())
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<usize, CacheError> {
+        let mut inner = self.lock();
+        let before = inner.entries.len();
+        inner.entries.retain(|key, _| !key.starts_with(prefix));
+        Ok(before - inner.entries.len())
+    }
+
+    fn backend(&self) -> &'static str {
+        "memory"
+    }
+}
@@ -0,0 +1,182 @@
+//! Cache — `Cache` trait, in-memory TTL default, Redis with the `redis` feature,
+//! and whole-page caching (`cache_page`).
+pub mod memory;
+pub mod page;
+#[cfg(feature = "redis")]
+pub mod redis;
+
+pub use memory::MemoryCache;
+pub use page::{cache_page_middleware, invalidate_page, page_key};
+#[cfg(feature = "redis")]
+pub use redis::RedisCache;
+
+use async_trait::async_trait;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{fmt, ops::Deref, sync::Arc, time::Duration};
+
+// ─── Trait ────────────────────────────────────────────────────────────────────
+
+/// Key → bytes store with expiry. Typed values go through
+/// [`get_or_set`](#method.get_or_set) and friends on `dyn Cache`.
+#[async_trait]
+pub trait Cache: Send + Sync + 'static {
+    /// Value of `key` — `None` if missing or expired.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, CacheError>;
+
+    /// Stores `value` under `key` for `ttl`, replacing any previous value.
+    async fn set_with_ttl(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Duration,
+    ) -> Result<(), CacheError>;
+
+    /// Removes `key` — a missing key is not an error.
+    async fn delete(&self, key: &str) -> Result<(), CacheError>;
+
+    /// Removes every key starting with `prefix` — returns how many.
+    async fn delete_prefix(&self, prefix: &str) -> Result<usize, CacheError>;
+
+    /// Checks that the backend answers — used by the `/readyz` probe. A read of a
+    /// missing key by default.
+    async fn ping(&self) -> Result<(), CacheError> {
+        self.get("runique:ping").await.map(|_| ())
+    }
+
+    /// Name in the logs.
+    fn backend(&self) -> &'static str {
+        "custom"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheError {
+    /// Cache service failure (connection, protocol).
+    Backend(String),
+    /// Typed value that could not be encoded or decoded (JSON).
+    Serialization(String),
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Backend(e) => write!(f, "cache: {e}"),
+            Self::Serialization(e) => write!(f, "cache value: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for CacheError {}
+
+impl dyn Cache {
+    /// Value of `key` decoded from JSON.
+    pub async fn get_value<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, CacheError> {
+        match self.get(key).await? {
+            Some(bytes) => serde_json::from_slice(&bytes)
+                .map(Some)
+                .map_err(|e| CacheError::Serialization(format!("{key}: {e}"))),
+            None => Ok(None),
+        }
+    }
+
+    /// Stores `value` as JSON under `key` for `ttl`.
+    pub async fn set_value<T: Serialize + ?Sized>(
+        &self,
+        key: &str,
+        value: &T,
+        ttl: Duration,
+    ) -> Result<(), CacheError> {
+        let bytes = serde_json::to_vec(value)
+            .map_err(|e| CacheError::Serialization(format!("{key}: {e}")))?;
+        self.set_with_ttl(key, bytes, ttl).await
+    }
+
+    /// Cached value of `key`, or `compute()` stored for `ttl`. Errors of `compute`
+    /// are returned and not cached; a failing cache only logs — the value is computed.
+    ///
+    /// ```rust,ignore
+    /// let posts = req.cache().get_or_set("home:posts", Duration::from_secs(60), || async {
+    ///     post::Entity::find().order_by_desc(post::Column::CreatedAt).limit(10).all(req.db()).await
+    /// }).await?;
+    /// ```
+    pub async fn get_or_set<T, E, F, Fut>(
+        &self,
+        key: &str,
+        ttl: Duration,
+        compute: F,
+    ) -> Result<T, E>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        match self.get_value(key).await {
+            Ok(Some(value)) => return Ok(value),
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(key, backend = self.backend(), error = %e, "cache read failed")
+            }
+        }
+        let value = compute().await?;
+        if let Err(e) = self.set_value(key, &value, ttl).await {
+            tracing::warn!(key, backend = self.backend(), error = %e, "cache write failed");
+        }
+        Ok(value)
+    }
+}
+
+// ─── Handle ───────────────────────────────────────────────────────────────────
+
+/// The application cache — `engine.cache` / `req.cache()`. [`MemoryCache`] unless
+/// the builder installed another backend (`with_cache`).
+#[derive(Clone)]
+pub struct CacheHandle(Arc<dyn Cache>);
+
+impl CacheHandle {
+    pub fn new(cache: impl Cache) -> Self {
+        Self(Arc::new(cache))
+    }
+}
+
+impl Default for CacheHandle {
+    fn default() -> Self {
+        Self::new(MemoryCache::new())
+    }
+}
+
+impl fmt::Debug for CacheHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CacheHandle")
+            .field(&self.0.backend())
+            .finish()
+    }
+}
+
+impl Deref for CacheHandle {
+    type Target = dyn Cache;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+/// Cache described by `CACHE_BACKEND` (`memory` by default, `redis` with the `redis`
+/// feature and `REDIS_URL`).
+pub fn cache_from_env() -> Result<CacheHandle, String> {
+    match std::env::var("CACHE_BACKEND")
+        .as_deref()
+        .unwrap_or("memory")
+    {
+        "memory" => Ok(CacheHandle::default()),
+        #[cfg(feature = "redis")]
+        "redis" => {
+            let url = std::env::var("REDIS_URL").map_err(|_| "REDIS_URL is not set".to_string())?;
+            RedisCache::new(&url)
+                .map(CacheHandle::new)
+                .map_err(|e| e.to_string())
+        }
+        #[cfg(not(feature = "redis"))]
+        "redis" => Err("CACHE_BACKEND=redis requires the `redis` feature of runique".to_string()),
+        other => Err(format!("unknown CACHE_BACKEND: {other}")),
+    }
+}
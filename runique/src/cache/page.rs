@@ -0,0 +1,191 @@
+//! Whole-response caching — `RouterExt::cache_page` and its middleware.
+use crate::auth::session::CurrentUser;
+use crate::cache::{Cache, CacheError};
+use crate::context::template::negotiates_json;
+use crate::utils::{aliases::AEngine, csp_nonce::CspNonce};
+use axum::{
+    body::{Body, to_bytes},
+    extract::{Request, State},
+    http::{HeaderName, HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Cache key of a page: `page:{path}?{query}`.
+pub fn page_key(path: &str, query: Option<&str>) -> String {
+    format!("page:{path}?{}", query.unwrap_or_default())
+}
+
+/// Key of the response to `req`: [`page_key`], plus `#json` when the request
+/// negotiates the JSON answer of [`Request::respond`](crate::context::Request::respond)
+/// — the same URL serves HTML and JSON.
+fn request_key(req: &Request) -> String {
+    let query = req.uri().query();
+    let format = query.and_then(|q| {
+        form_urlencoded::parse(q.as_bytes())
+            .find(|(name, _)| name == "format")
+            .map(|(_, value)| value.into_owned())
+    });
+    let accept = req
+        .headers()
+        .get(header::ACCEPT)
+        .and_then(|v| v.to_str().ok());
+    let key = page_key(req.uri().path(), query);
+    if negotiates_json(format.as_deref(), accept) {
+        format!("{key}#json")
+    } else {
+        key
+    }
+}
+
+/// Drops the cached versions of `path`, whatever their query string — returns how many.
+///
+/// ```rust,ignore
+/// invalidate_page(&req.engine.cache, "/").await?; // after publishing a post
+/// ```
+pub async fn invalidate_page(cache: &dyn Cache, path: &str) -> Result<usize, CacheError> {
+    cache.delete_prefix(&page_key(path, None)).await
+}
+
+/// Marks a response replayed from the page cache with the CSP of the request that
+/// rendered it: [`security_headers_middleware`](crate::middleware::security_headers_middleware)
+/// keeps that header, whose nonce is the one written in the cached HTML.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CachedCsp;
+
+/// Status and headers of a cached response — stored before the body.
+#[derive(Serialize, Deserialize)]
+struct PageHead {
+    status: u16,
+    headers: Vec<(String, String)>,
+}
+
+/// `[head length: u32 BE][head JSON][body]`
+fn encode(head: &PageHead, body: &[u8]) -> Option<Vec<u8>> {
+    let head = serde_json::to_vec(head).ok()?;
+    let mut out = Vec::with_capacity(4 + head.len() + body.len());
+    out.extend_from_slice(&u32::try_from(head.len()).ok()?.to_be_bytes());
+    out.extend_from_slice(&head);
+    out.extend_from_slice(body);
+    Some(out)
+}
+
+fn decode(bytes: Vec<u8>) -> Option<Response> {
+    let len = u32::from_be_bytes(bytes.get(..4)?.try_into().ok()?) as usize;
+    let head: PageHead = serde_json::from_slice(bytes.get(4..4 + len)?).ok()?;
+    let mut response = Response::new(Body::from(bytes[4 + len..].to_vec()));
+    *response.status_mut() = StatusCode::from_u16(head.status).ok()?;
+    for (name, value) in head.headers {
+        response.headers_mut().append(
+            HeaderName::try_from(name).ok()?,
+            HeaderValue::try_from(value).ok()?,
+        );
+    }
+    Some(response)
+}
+
+/// Only plain shared `200` pages: no cookie, not private, not a stream.
+fn cacheable(response: &Response) -> bool {
+    let headers = response.headers();
+    let header_has = |name: HeaderName, needle: &str| {
+        headers
+            .get_all(name)
+            .iter()
+            .any(|v| v.to_str().is_ok_and(|v| v.contains(needle)))
+    };
+    response.status() == StatusCode::OK
+        && !headers.contains_key(header::SET_COOKIE)
+        && !header_has(header::CACHE_CONTROL, "private")
+        && !header_has(header::CACHE_CONTROL, "no-store")
+        && !header_has(header::CONTENT_TYPE, "text/event-stream")
+}
+
+/// Serves `GET` / `HEAD` from the application cache (`engine.cache`), keyed by
+/// path + query ([`page_key`]) and negotiated format; a miss runs the handler and stores its response
+/// for `ttl`. Logged-in users always get a fresh page — it may show their data.
+///
+/// The same response is served to every visitor: keep it for pages without
+/// per-visitor content (CSRF token of a form, flash messages). The CSP nonce is
+/// stored with the page, so its `{% script %}` / `{% style %}` blocks keep running.
+///
+/// ```rust,ignore
+/// Router::new()
+///     .route("/", get(home))
+///     .route_layer(axum::middleware::from_fn_with_state(
+///         Duration::from_secs(60),
+///         cache_page_middleware,
+///     ))
+/// ```
+pub async fn cache_page_middleware(
+    State(ttl): State<Duration>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if !matches!(*req.method(), Method::GET | Method::HEAD)
+        || req.extensions().get::<CurrentUser>().is_some()
+    {
+        return next.run(req).await;
+    }
+    let Some(engine) = req.extensions().get::<AEngine>().cloned() else {
+        return next.run(req).await;
+    };
+    let cache = &engine.cache;
+    let key = request_key(&req);
+
+    match cache.get(&key).await {
+        Ok(Some(bytes)) => match decode(bytes) {
+            Some(mut response) => {
+                if response
+                    .headers()
+                    .contains_key(header::CONTENT_SECURITY_POLICY)
+                {
+                    response.extensions_mut().insert(CachedCsp);
+                }
+                return response;
+            }
+            None => tracing::warn!(key, "cached page unreadable — rendering it again"),
+        },
+        Ok(None) => {}
+        Err(e) => tracing::warn!(key, backend = cache.backend(), error = %e, "cache read failed"),
+    }
+
+    let is_get = req.method() == Method::GET;
+    let nonce = req.extensions().get::<CspNonce>().cloned();
+    let response = next.run(req).await;
+    if !is_get || !cacheable(&response) {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::error!(key, error = %e, "response body unreadable");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let mut head = PageHead {
+        status: parts.status.as_u16(),
+        headers: parts
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect(),
+    };
+    // The CSP is added further out, after this layer: store the one matching the
+    // nonce rendered into the body, replayed as is on a hit
+    if let Some(nonce) = nonce {
+        head.headers.push((
+            header::CONTENT_SECURITY_POLICY.to_string(),
+            engine.security_csp.to_header_value(Some(nonce.as_str())),
+        ));
+    }
+    if let Some(bytes) = encode(&head, &body)
+        && let Err(e) = cache.set_with_ttl(&key, bytes, ttl).await
+    {
+        tracing::warn!(key, backend = cache.backend(), error = %e, "cache write failed");
+    }
+    Response::from_parts(parts, Body::from(body))
+}
@@ -0,0 +1,142 @@
+//! `RedisCache` — entries in Redis (`SET … PX`), shared by every instance of the app.
+use crate::cache::{Cache, CacheError};
+use async_trait::async_trait;
+use redis::{AsyncCommands, aio::ConnectionManager};
+use std::{fmt, time::Duration};
+use tokio::sync::OnceCell;
+
+/// Redis-backed cache. Connects on first use and reconnects by itself; keys are
+/// prefixed (`runique:` by default) so several apps can share a database.
+///
+/// ```rust,ignore
+/// RuniqueApp::builder(config).with_cache(RedisCache::new("redis://127.0.0.1/")?.prefix("blog:"))
+/// ```
+pub struct RedisCache {
+    client: redis::Client,
+    conn: OnceCell<ConnectionManager>,
+    prefix: String,
+}
+
+impl fmt::Debug for RedisCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisCache")
+            .field("prefix", &self.prefix)
+            .finish()
+    }
+}
+
+fn backend_error(e: redis::RedisError) -> CacheError {
+    CacheError::Backend(format!("redis: {e}"))
+}
+
+/// Escapes the glob characters of a `SCAN MATCH` pattern.
+fn escape_glob(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+impl RedisCache {
+    /// `url`: `redis://[:password@]host[:port][/db]` — only parsed here.
+    pub fn new(url: &str) -> Result<Self, CacheError> {
+        Ok(Self {
+            client: redis::Client::open(url).map_err(backend_error)?,
+            conn: OnceCell::new(),
+            prefix: "runique:".to_string(),
+        })
+    }
+
+    /// Prefix of every key — `runique:` by default.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    async fn conn(&self) -> Result<ConnectionManager, CacheError> {
+        self.conn
+            .get_or_try_init(|| ConnectionManager::new(self.client.clone()))
+            .await
+            .cloned()
+            .map_err(backend_error)
+    }
+
+    fn key(&self, key: &str) -> String {
+        format!("{}{key}", self.prefix)
+    }
+}
+
+#[async_trait]
+impl Cache for RedisCache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, CacheError> {
+        self.conn()
+            .await?
+            .get(self.key(key))
+            .await
+            .map_err(backend_error)
+    }
+
+    async fn set_with_ttl(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Duration,
+    ) -> Result<(), CacheError> {
+        let millis = ttl.as_millis().min(u64::MAX as u128) as u64;
+        if millis == 0 {
+            // `PX 0` is rejected by Redis — an already expired value is just absent
+            return self.delete(key).await;
+        }
+        self.conn()
+            .await?
+            .pset_ex(self.key(key), value, millis)
+            .await
+            .map_err(backend_error)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), CacheError> {
+        self.conn()
+            .await?
+            .del(self.key(key))
+            .await
+            .map_err(backend_error)
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<usize, CacheError> {
+        let mut conn = self.conn().await?;
+        let pattern = format!("{}*", escape_glob(&self.key(prefix)));
+        let keys: Vec<String> = {
+            let mut iter = conn
+                .scan_match::<_, String>(pattern)
+                .await
+                .map_err(backend_error)?;
+            let mut keys = Vec::new();
+            while let Some(key) = iter.next_item().await {
+                keys.push(key);
+            }
+            keys
+        };
+        let mut deleted = 0;
+        for chunk in keys.chunks(500) {
+            deleted += conn.del::<_, usize>(chunk).await.map_err(backend_error)?;
+        }
+        Ok(deleted)
+    }
+
+    async fn ping(&self) -> Result<(), CacheError> {
+        let mut conn = self.conn().await?;
+        redis::cmd("PING")
+            .query_async::<String>(&mut conn)
+            .await
+            .map(|_| ())
+            .map_err(backend_error)
+    }
+
+    fn backend(&self) -> &'static str {
+        "redis"
+    }
+}
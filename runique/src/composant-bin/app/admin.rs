@@ -0,0 +1,17 @@
+//! AdminPanel declaration of the `{{APP_NAME}}` app.
+//!
+//! `runique start` reads a single `src/admin.rs`: copy the import and the resource
+//! below into it (inside its `admin! { … }` block) to manage `{{MODEL_NAME}}` rows.
+//!
+//! ```rust,ignore
+//! use crate::{{APP_NAME}}::{forms::{{MODEL_NAME}}Form, models as {{APP_NAME}}};
+//!
+//! admin! {
+//!     {{APP_NAME}}: {{APP_NAME}}::Model => {{MODEL_NAME}}Form {
+//!         title: "{{MODEL_NAME}}",
+//!         list_display: [
+//!             ["title", "Title"],
+//!         ]
+//!     }
+//! }
+//! ```
@@ -0,0 +1,21 @@
+use super::models;
+use runique::prelude::*;
+use runique::sea_orm::DatabaseTransaction;
+
+#[form(schema = models, fields = [title])]
+pub struct {{MODEL_NAME}}Form;
+
+#[async_trait]
+impl RuniqueForm for {{MODEL_NAME}}Form {
+    impl_form_access!(model);
+
+    async fn on_save(&mut self, txn: &DatabaseTransaction) -> Result<(), DbErr> {
+        models::ActiveModel {
+            title: Set(self.cleaned_string("title").unwrap_or_default()),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod admin;
+pub mod forms;
+pub mod models;
+pub mod url;
+pub mod views;
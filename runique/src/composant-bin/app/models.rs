@@ -0,0 +1,10 @@
+use runique::prelude::*;
+
+model! {
+    {{MODEL_NAME}},
+    table: "{{APP_NAME}}",
+    pk: id => Pk,
+    {
+        title: text [required],
+    }
+}
@@ -0,0 +1,8 @@
+use super::views::*;
+use runique::prelude::*;
+
+pub fn routes() -> Router {
+    urlpatterns! {
+        "/{{APP_NAME}}" => view!{ index }, name = "{{APP_NAME}}_index",
+    }
+}
@@ -0,0 +1,28 @@
+use super::{forms::{{MODEL_NAME}}Form, models};
+use runique::prelude::*;
+
+/// Liste + formulaire de création
+pub async fn index(mut request: Request) -> AppResult<Response> {
+    let mut form: {{MODEL_NAME}}Form = request.form();
+
+    if request.is_post() && form.is_valid().await {
+        match form.save(&request.engine.db).await {
+            Ok(()) => {
+                success!(request.notices => "{{MODEL_NAME}} created.");
+                return Ok(Redirect::to("/{{APP_NAME}}").into_response());
+            }
+            Err(err) => form.get_form_mut().database_error(&err),
+        }
+    }
+
+    let items = models::Entity::objects
+        .all()
+        .all(&request.engine.db)
+        .await?;
+    context_update!(request => {
+        "title" => "{{MODEL_NAME}}",
+        "items" => &items,
+        "form" => &form,
+    });
+    request.render("{{APP_NAME}}/index.html")
+}
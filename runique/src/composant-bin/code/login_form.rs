@@ -18,6 +18,7 @@ impl RuniqueForm for LoginForm {
     fn register_fields(form: &mut Forms) {
         form.field(&TextField::text("username").label("Username").required());
         form.field(&TextField::password("password").label("Password").required());
+        form.field(&BooleanField::new("remember_me").label("Remember me"));
     }
 
     impl_form_access!();
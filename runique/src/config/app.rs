@@ -1,12 +1,22 @@
 //! Main Runique application configuration.
-use crate::config::{security::SecurityConfig, server::ServerConfig, static_files::StaticConfig};
+use crate::config::{
+    file::{ConfigFile, ConfigFileError, VarSource, env_profile, env_var, profile_default},
+    i18n::I18nConfig,
+    security::SecurityConfig,
+    server::ServerConfig,
+    static_files::StaticConfig,
+};
+use crate::flash::FlashConfig;
+use crate::forms::theme::FormTheme;
 use crate::middleware::MiddlewareConfig;
 use crate::utils::password::PasswordConfig;
 use crate::utils::runique_log::RuniqueLog;
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
-/// Aggregated global configuration: server, middleware, security, passwords, static files.
-/// Built via [`RuniqueConfig::from_env`] which reads environment variables.
+/// Aggregated global configuration: server, middleware, security, passwords, static files, i18n.
+/// Built via [`RuniqueConfig::from_env`] which reads environment variables, or
+/// [`RuniqueConfig::from_toml`] which reads a file the environment overrides.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RuniqueConfig {
     pub server: ServerConfig,
@@ -14,6 +24,8 @@ pub struct RuniqueConfig {
     pub security: SecurityConfig,
     pub password: PasswordConfig,
     pub static_files: StaticConfig,
+    /// Translation catalogs and default locale — adjustable via `.with_i18n()`.
+    pub i18n: I18nConfig,
     /// Log configuration by category — initialized via `.with_log()`.
     #[serde(skip)]
     pub log: RuniqueLog,
@@ -22,23 +34,92 @@ pub struct RuniqueConfig {
     /// IANA timezone name — read from `TZ` env var. Default: `"UTC"`.
     /// Parse with `chrono-tz`: `config.timezone.parse::<chrono_tz::Tz>()`.
     pub timezone: String,
+    /// Default CSS theme of `render_form` — read from `FORM_THEME`. Default: `Plain`.
+    pub form_theme: FormTheme,
+    /// Flash messages queue policy — set via `.with_flash()`.
+    pub flash: FlashConfig,
+    /// Active profile (`dev`, `staging`, `prod`…) — read from `RUNIQUE_ENV` or set via `.profile()`.
+    pub profile: Option<String>,
+    /// TOML file loaded by `from_toml`, re-read by [`with_profile`](Self::with_profile).
+    #[serde(skip)]
+    pub config_file: Option<PathBuf>,
 }
 
 impl RuniqueConfig {
     /// Loads configuration from environment variables (reads `.env` via `dotenvy`).
+    /// `RUNIQUE_ENV=prod` applies the `prod` defaults to unset variables.
     pub fn from_env() -> Self {
         dotenvy::dotenv().ok();
+        Self::from_env_profile(env_profile())
+    }
+
+    /// Loads the `[server]`, `[security]` and `[static]` sections (plus top-level
+    /// `debug`, `base_dir`, `timezone`) of a TOML file — see [`ConfigFile`]. A set
+    /// environment variable overrides its key; the rest stays as in `from_env`.
+    /// `RUNIQUE_ENV` selects the profile file overlaid on it.
+    ///
+    /// ```rust,ignore
+    /// let config = RuniqueConfig::from_toml("runique.toml")?;
+    /// RuniqueApp::builder(config).with_database_config(DatabaseConfig::from_toml("runique.toml")?.build())
+    /// ```
+    pub fn from_toml(path: impl AsRef<Path>) -> Result<Self, ConfigFileError> {
+        let file = ConfigFile::load(&path)?;
+        Ok(Self::from_file(file, path.as_ref()))
+    }
+
+    /// Same as [`from_toml`](Self::from_toml) with an explicit profile instead of `RUNIQUE_ENV`.
+    pub fn from_toml_profile(
+        path: impl AsRef<Path>,
+        profile: &str,
+    ) -> Result<Self, ConfigFileError> {
+        let file = ConfigFile::load_profile(&path, Some(profile))?;
+        Ok(Self::from_file(file, path.as_ref()))
+    }
+
+    /// Reloads this configuration under `profile`: from its TOML file when it came
+    /// from `from_toml`, from the environment otherwise. `log` and `flash`, only
+    /// set in code, are kept.
+    pub fn with_profile(self, profile: &str) -> Result<Self, ConfigFileError> {
+        let mut config = match &self.config_file {
+            Some(path) => Self::from_toml_profile(path, profile)?,
+            None => Self::from_env_profile(Some(profile.to_string())),
+        };
+        config.log = self.log;
+        config.flash = self.flash;
+        Ok(config)
+    }
+
+    fn from_env_profile(profile: Option<String>) -> Self {
+        let mut config = Self::from_source(&|name| {
+            env_var(name).or_else(|| profile_default(profile.as_deref()?, name))
+        });
+        config.profile = profile;
+        config
+    }
+
+    fn from_file(file: ConfigFile, path: &Path) -> Self {
+        let mut config = Self::from_source(&|name| file.var(name));
+        config.profile = file.profile().map(str::to_string);
+        config.config_file = Some(path.to_path_buf());
+        config
+    }
 
+    fn from_source(var: VarSource) -> Self {
         Self {
-            server: ServerConfig::from_env(),
+            server: ServerConfig::from_source(var),
             middleware: MiddlewareConfig::from_env(),
-            security: SecurityConfig::from_env(),
+            security: SecurityConfig::from_source(var),
             password: PasswordConfig::auto(),
-            static_files: StaticConfig::from_env(),
-            base_dir: std::env::var("BASE_DIR").unwrap_or_else(|_| ".".to_string()),
-            debug: matches!(std::env::var("DEBUG").as_deref(), Ok("true" | "1")),
-            timezone: std::env::var("TZ").unwrap_or_else(|_| "UTC".to_string()),
+            static_files: StaticConfig::from_source(var),
+            i18n: I18nConfig::from_env(),
+            base_dir: var("BASE_DIR").unwrap_or_else(|| ".".to_string()),
+            debug: matches!(var("DEBUG").as_deref(), Some("true" | "1")),
+            timezone: var("TZ").unwrap_or_else(|| "UTC".to_string()),
             log: RuniqueLog::default(),
+            form_theme: FormTheme::from_env(),
+            flash: FlashConfig::default(),
+            profile: None,
+            config_file: None,
         }
     }
 }
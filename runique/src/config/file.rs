@@ -0,0 +1,320 @@
+//! TOML configuration file — `RuniqueConfig::from_toml`, `DatabaseConfig::from_toml`.
+//!
+//! Each key mirrors an environment variable (`[server] secret_key` ↔ `SECRET_KEY`):
+//! the variable, when set, overrides the file value. String values may reference
+//! variables with `${NAME}`.
+//!
+//! Profiles: `RUNIQUE_ENV=prod` overlays `runique.prod.toml` on `runique.toml`, key
+//! by key. Precedence, lowest first: base file < profile defaults < profile file
+//! < environment < explicit builder calls.
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+use toml::{Table, Value};
+
+/// Reads one configuration variable — the environment, or a file over it.
+pub(crate) type VarSource<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// [`VarSource`] of `from_env()`.
+pub(crate) fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+/// Environment variable selecting the profile (`dev`, `staging`, `prod`…).
+pub const PROFILE_VAR: &str = "RUNIQUE_ENV";
+
+/// Safe defaults of the `prod` profile — overridden by the profile file and the environment.
+const PROD_DEFAULTS: &[(&str, &str)] = &[
+    ("DEBUG", "false"),
+    ("STRICT_CSP", "true"),
+    ("DB_LOGGING", "false"),
+    ("DB_SLOW_QUERY_MS", "0"),
+];
+
+/// Profile named by `RUNIQUE_ENV`, if set and not empty.
+pub(crate) fn env_profile() -> Option<String> {
+    env_var(PROFILE_VAR).filter(|p| !p.trim().is_empty())
+}
+
+fn is_prod(profile: &str) -> bool {
+    matches!(profile, "prod" | "production")
+}
+
+/// Default of `var` under `profile` — only `prod` (or `production`) has some.
+pub(crate) fn profile_default(profile: &str, var: &str) -> Option<String> {
+    if !is_prod(profile) {
+        return None;
+    }
+    PROD_DEFAULTS
+        .iter()
+        .find(|(name, _)| *name == var)
+        .map(|(_, value)| value.to_string())
+}
+
+/// `runique.toml` + `prod` → `runique.prod.toml`, next to the base file.
+pub fn profile_path(path: impl AsRef<Path>, profile: &str) -> PathBuf {
+    let path = path.as_ref();
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let file = match path.extension() {
+        Some(ext) => format!("{stem}.{profile}.{}", ext.to_string_lossy()),
+        None => format!("{stem}.{profile}"),
+    };
+    path.with_file_name(file)
+}
+
+/// `(section, key, environment variable)` — `""` is the top level of the file.
+const KEYS: &[(&str, &str, &str)] = &[
+    ("", "debug", "DEBUG"),
+    ("", "base_dir", "BASE_DIR"),
+    ("", "timezone", "TZ"),
+    ("server", "ip_server", "IP_SERVER"),
+    ("server", "port", "PORT"),
+    ("server", "secret_key", "SECRET_KEY"),
+    ("server", "secret_key_fallbacks", "SECRET_KEY_FALLBACKS"),
+    ("security", "strict_csp", "STRICT_CSP"),
+    ("security", "rate_limiting", "RATE_LIMITING"),
+    ("security", "enforce_https", "ENFORCE_HTTPS"),
+    ("security", "allowed_hosts", "ALLOWED_HOSTS"),
+    ("security", "acme_enabled", "ACME_ENABLED"),
+    ("security", "acme_domain", "ACME_DOMAIN"),
+    ("security", "acme_email", "ACME_EMAIL"),
+    ("security", "acme_certs_dir", "ACME_CERTS_DIR"),
+    ("static", "templates_dir", "TEMPLATES_DIR"),
+    ("static", "staticfiles_dirs", "STATICFILES_DIRS"),
+    ("static", "static_url", "STATIC_URL"),
+    ("static", "media_root", "MEDIA_ROOT"),
+    ("static", "media_url", "MEDIA_URL"),
+    ("static", "staticfiles", "STATICFILES"),
+    ("static", "og_image", "OG_IMAGE"),
+    ("static", "max_upload_mb", "RUNIQUE_MAX_UPLOAD_MB"),
+    ("static", "max_text_field_kb", "RUNIQUE_MAX_TEXT_FIELD_KB"),
+    ("database", "url", "DATABASE_URL"),
+    ("database", "engine", "DB_ENGINE"),
+    ("database", "user", "DB_USER"),
+    ("database", "password", "DB_PASSWORD"),
+    ("database", "host", "DB_HOST"),
+    ("database", "port", "DB_PORT"),
+    ("database", "name", "DB_NAME"),
+    ("database", "max_connections", "DB_MAX_CONNECTIONS"),
+    ("database", "min_connections", "DB_MIN_CONNECTIONS"),
+    ("database", "connect_timeout", "DB_CONNECT_TIMEOUT"),
+    ("database", "acquire_timeout", "DB_ACQUIRE_TIMEOUT"),
+    ("database", "idle_timeout", "DB_IDLE_TIMEOUT"),
+    ("database", "max_lifetime", "DB_MAX_LIFETIME"),
+    ("database", "logging", "DB_LOGGING"),
+    ("database", "slow_query_ms", "DB_SLOW_QUERY_MS"),
+    ("database", "replica_url", "DB_REPLICA_URL"),
+    ("database", "replica_fallback", "DB_REPLICA_FALLBACK"),
+];
+
+/// Error while loading a TOML configuration file.
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigFileError {
+    #[error("cannot read {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("invalid TOML in {path}: {source}")]
+    Parse {
+        path: String,
+        source: toml::de::Error,
+    },
+    #[error("{key}: environment variable `{var}` is not set")]
+    MissingVariable { key: String, var: String },
+    #[error("{key}: expected a string, number, boolean or array of those")]
+    InvalidValue { key: String },
+    #[error("unknown configuration keys: {}", .0.join(", "))]
+    UnknownKeys(Vec<String>),
+}
+
+/// Values of a TOML configuration file, keyed by environment variable name.
+///
+/// ```toml
+/// debug = false
+///
+/// [server]
+/// port = 8000
+/// secret_key = "${SECRET_KEY}"
+///
+/// [security]
+/// allowed_hosts = ["example.com", ".example.com"]
+///
+/// [static]
+/// static_url = "/assets"
+///
+/// [database]
+/// url = "${DATABASE_URL}"
+/// max_connections = 50
+/// ```
+///
+/// Unknown keys are an error when the resolved `debug` is `true`, a warning otherwise.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigFile {
+    values: HashMap<&'static str, String>,
+    profile: Option<String>,
+}
+
+impl ConfigFile {
+    /// Reads and parses `path`, overlaid with the profile named by `RUNIQUE_ENV` —
+    /// also loads `.env`, whose variables override the file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigFileError> {
+        dotenvy::dotenv().ok();
+        Self::load_profile(path, env_profile().as_deref())
+    }
+
+    /// Reads `path`, then overlays `profile`'s defaults and its file
+    /// ([`profile_path`]) when it exists.
+    pub fn load_profile(
+        path: impl AsRef<Path>,
+        profile: Option<&str>,
+    ) -> Result<Self, ConfigFileError> {
+        dotenvy::dotenv().ok();
+        let path = path.as_ref();
+        let (mut file, mut unknown) = Self::read(path)?;
+
+        if let Some(profile) = profile {
+            if is_prod(profile) {
+                for (var, value) in PROD_DEFAULTS {
+                    file.values.insert(var, value.to_string());
+                }
+            }
+            let overlay_path = profile_path(path, profile);
+            if overlay_path.exists() {
+                let (overlay, overlay_unknown) = Self::read(&overlay_path)?;
+                file.values.extend(overlay.values);
+                unknown.extend(overlay_unknown);
+            }
+            file.profile = Some(profile.to_string());
+        }
+
+        file.check_unknown(unknown, &path.display().to_string())?;
+        Ok(file)
+    }
+
+    /// Parses TOML `content` — `origin` only names the file in errors.
+    pub fn parse(content: &str, origin: &str) -> Result<Self, ConfigFileError> {
+        let (file, unknown) = Self::parse_values(content, origin)?;
+        file.check_unknown(unknown, origin)?;
+        Ok(file)
+    }
+
+    /// Profile overlaid by [`load`](Self::load) / [`load_profile`](Self::load_profile).
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    fn read(path: &Path) -> Result<(Self, Vec<String>), ConfigFileError> {
+        let content = std::fs::read_to_string(path).map_err(|source| ConfigFileError::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+        Self::parse_values(&content, &path.display().to_string())
+    }
+
+    fn parse_values(content: &str, origin: &str) -> Result<(Self, Vec<String>), ConfigFileError> {
+        let table: Table = content.parse().map_err(|source| ConfigFileError::Parse {
+            path: origin.to_string(),
+            source,
+        })?;
+
+        let mut file = Self::default();
+        let mut unknown = Vec::new();
+        for (key, value) in &table {
+            match value {
+                Value::Table(section) if KEYS.iter().any(|(s, _, _)| s == key) => {
+                    for (name, value) in section {
+                        file.insert(key, name, value, &mut unknown)?;
+                    }
+                }
+                _ => file.insert("", key, value, &mut unknown)?,
+            }
+        }
+        Ok((file, unknown))
+    }
+
+    fn check_unknown(&self, unknown: Vec<String>, origin: &str) -> Result<(), ConfigFileError> {
+        if unknown.is_empty() {
+            return Ok(());
+        }
+        let debug = matches!(self.var("DEBUG").as_deref(), Some("true" | "1"));
+        if debug {
+            return Err(ConfigFileError::UnknownKeys(unknown));
+        }
+        eprintln!(
+            "[runique] WARNING: {origin}: unknown configuration keys ignored: {}",
+            unknown.join(", ")
+        );
+        Ok(())
+    }
+
+    /// The environment variable `name` when set, else the file value.
+    pub fn var(&self, name: &str) -> Option<String> {
+        env_var(name).or_else(|| self.values.get(name).cloned())
+    }
+
+    fn insert(
+        &mut self,
+        section: &str,
+        key: &str,
+        value: &Value,
+        unknown: &mut Vec<String>,
+    ) -> Result<(), ConfigFileError> {
+        let full_key = match section {
+            "" => key.to_string(),
+            s => format!("{s}.{key}"),
+        };
+        let Some((_, _, var)) = KEYS.iter().find(|(s, k, _)| *s == section && *k == key) else {
+            unknown.push(full_key);
+            return Ok(());
+        };
+        let value = match value {
+            Value::Array(items) => items
+                .iter()
+                .map(|item| scalar(item, &full_key))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(","),
+            item => scalar(item, &full_key)?,
+        };
+        self.values.insert(var, value);
+        Ok(())
+    }
+}
+
+/// A TOML scalar as the string its environment variable would hold.
+fn scalar(value: &Value, key: &str) -> Result<String, ConfigFileError> {
+    match value {
+        Value::String(s) => interpolate(s, key),
+        Value::Integer(i) => Ok(i.to_string()),
+        Value::Float(f) => Ok(f.to_string()),
+        Value::Boolean(b) => Ok(b.to_string()),
+        _ => Err(ConfigFileError::InvalidValue {
+            key: key.to_string(),
+        }),
+    }
+}
+
+/// Replaces every `${NAME}` with the environment variable `NAME`.
+fn interpolate(raw: &str, key: &str) -> Result<String, ConfigFileError> {
+    let mut out = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(start) = rest.find("${") {
+        let Some(len) = rest[start + 2..].find('}') else {
+            break;
+        };
+        let name = &rest[start + 2..start + 2 + len];
+        let value = env_var(name).ok_or_else(|| ConfigFileError::MissingVariable {
+            key: key.to_string(),
+            var: name.to_string(),
+        })?;
+        out.push_str(&rest[..start]);
+        out.push_str(&value);
+        rest = &rest[start + 3 + len..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
@@ -0,0 +1,50 @@
+//! Localization configuration — translation catalogs directory and default locale.
+use serde::{Deserialize, Serialize};
+
+/// Where the project's translation catalogs live and which locale to fall back to.
+/// Loaded from the environment, adjustable with `.with_i18n(|i| ...)`.
+///
+/// ```rust,ignore
+/// RuniqueApp::builder(config)
+///     .with_i18n(|i| i.locale_dir("locales").default_locale("fr"))
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct I18nConfig {
+    /// Directory of the `<locale>.json` / `<locale>.po` catalogs (env: `LOCALE_DIR`, default: `locales`).
+    pub locale_dir: String,
+    /// Locale used when no other matches, and for keys missing from the active catalog
+    /// (env: `DEFAULT_LOCALE`, default: `en`).
+    pub default_locale: String,
+}
+
+impl Default for I18nConfig {
+    fn default() -> Self {
+        Self {
+            locale_dir: "locales".to_string(),
+            default_locale: "en".to_string(),
+        }
+    }
+}
+
+impl I18nConfig {
+    /// Loads configuration from environment variables.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            locale_dir: std::env::var("LOCALE_DIR").unwrap_or(default.locale_dir),
+            default_locale: std::env::var("DEFAULT_LOCALE").unwrap_or(default.default_locale),
+        }
+    }
+
+    /// Directory of the translation catalogs.
+    pub fn locale_dir(mut self, dir: impl Into<String>) -> Self {
+        self.locale_dir = dir.into();
+        self
+    }
+
+    /// Fallback locale.
+    pub fn default_locale(mut self, locale: impl Into<String>) -> Self {
+        self.default_locale = locale.into();
+        self
+    }
+}
@@ -1,11 +1,16 @@
-//! Application configuration — server, security, static files, router.
+//! Application configuration — server, security, static files, router, i18n, TOML file, startup validation.
 pub mod app;
+pub mod file;
+pub mod i18n;
 pub mod router;
 pub mod security;
 pub mod server;
 pub mod static_files;
+pub mod validate;
 
 pub use app::*;
+pub use file::{ConfigFile, ConfigFileError};
+pub use i18n::*;
 pub use router::*;
 pub use security::*;
 pub use server::*;
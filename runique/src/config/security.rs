@@ -1,4 +1,5 @@
 //! Global security settings (CSP, rate limiting, HTTPS, allowed hosts).
+use crate::config::file::{VarSource, env_var};
 use serde::{Deserialize, Serialize};
 
 /// Security settings read from the environment.
@@ -25,25 +26,29 @@ pub struct SecurityConfig {
 impl SecurityConfig {
     /// Loads configuration from environment variables.
     pub fn from_env() -> Self {
-        let strict_csp = std::env::var("STRICT_CSP")
+        Self::from_source(&env_var)
+    }
+
+    /// Same as [`from_env`](Self::from_env), reading each variable through `var`.
+    pub(crate) fn from_source(var: VarSource) -> Self {
+        let strict_csp = var("STRICT_CSP")
             .map(|v| v.parse().unwrap_or(true))
             .unwrap_or(true);
-        let rate_limiting = std::env::var("RATE_LIMITING")
+        let rate_limiting = var("RATE_LIMITING")
             .map(|v| v.parse().unwrap_or(true))
             .unwrap_or(true);
-        let enforce_https = std::env::var("ENFORCE_HTTPS")
+        let enforce_https = var("ENFORCE_HTTPS")
             .map(|v| v.parse().unwrap_or(false))
             .unwrap_or(false);
-        let allowed_hosts: Vec<String> = std::env::var("ALLOWED_HOSTS")
+        let allowed_hosts: Vec<String> = var("ALLOWED_HOSTS")
             .map(|v| v.split(',').map(|s| s.trim().to_string()).collect())
-            .unwrap_or_else(|_| vec!["localhost".to_string(), "127.0.0.1".to_string()]);
-        let acme_enabled = std::env::var("ACME_ENABLED")
+            .unwrap_or_else(|| vec!["localhost".to_string(), "127.0.0.1".to_string()]);
+        let acme_enabled = var("ACME_ENABLED")
             .map(|v| v.parse().unwrap_or(false))
             .unwrap_or(false);
-        let acme_domain = std::env::var("ACME_DOMAIN").ok().filter(|s| !s.is_empty());
-        let acme_email = std::env::var("ACME_EMAIL").ok().filter(|s| !s.is_empty());
-        let acme_certs_dir = std::env::var("ACME_CERTS_DIR")
-            .ok()
+        let acme_domain = var("ACME_DOMAIN").filter(|s| !s.is_empty());
+        let acme_email = var("ACME_EMAIL").filter(|s| !s.is_empty());
+        let acme_certs_dir = var("ACME_CERTS_DIR")
             .filter(|s| !s.is_empty())
             .unwrap_or_else(|| "./certs".to_string());
 
@@ -1,6 +1,6 @@
 //! HTTP server configuration (address, port, secret key).
+use crate::config::file::{VarSource, env_var};
 use serde::{Deserialize, Serialize};
-use std::env;
 
 const DEFAULT_SECRET_KEY: &str = "default_secret_key";
 
@@ -27,23 +27,37 @@ pub struct ServerConfig {
     pub port: u16,
     /// Secret key for HMAC/CSRF (env: `SECRET_KEY`). A warning is issued if missing.
     pub secret_key: String,
+    /// Previous keys, still accepted when verifying a signature but never used to
+    /// sign (env: `SECRET_KEY_FALLBACKS`, comma-separated). Drop them once the
+    /// tokens they signed have expired.
+    pub secret_key_fallbacks: Vec<String>,
 }
 
 impl ServerConfig {
     /// Loads configuration from environment variables.
     pub fn from_env() -> Self {
-        let ip = env::var("IP_SERVER").unwrap_or_else(|_| "127.0.0.1".to_string());
-        let port: u16 = env::var("PORT")
-            .ok()
-            .and_then(|p| p.parse().ok())
-            .unwrap_or(3000);
+        Self::from_source(&env_var)
+    }
+
+    /// Same as [`from_env`](Self::from_env), reading each variable through `var`.
+    pub(crate) fn from_source(var: VarSource) -> Self {
+        let ip = var("IP_SERVER").unwrap_or_else(|| "127.0.0.1".to_string());
+        let port: u16 = match var("PORT") {
+            Some(raw) => raw.trim().parse().unwrap_or_else(|_| {
+                eprintln!(
+                    "[runique] WARNING: PORT={raw} is not a valid port (0-65535) — using 3000."
+                );
+                3000
+            }),
+            None => 3000,
+        };
 
         ServerConfig {
             ip_server: ip.clone(),
             domain_server: format!("{}:{}", ip, port),
             port,
             secret_key: {
-                let key = env::var("SECRET_KEY").unwrap_or_else(|_| DEFAULT_SECRET_KEY.to_string());
+                let key = var("SECRET_KEY").unwrap_or_else(|| DEFAULT_SECRET_KEY.to_string());
                 if key == DEFAULT_SECRET_KEY {
                     eprintln!(
                         "[runique] WARNING: SECRET_KEY is not defined — using default key. \
@@ -52,8 +66,28 @@ impl ServerConfig {
                 }
                 key
             },
+            secret_key_fallbacks: var("SECRET_KEY_FALLBACKS")
+                .map(|v| {
+                    v.split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
         }
     }
+
+    /// Keys to verify a signature with, in order: `secret_key`, then the fallbacks.
+    ///
+    /// ```rust,ignore
+    /// let keys = engine.config.server.secret_keys();
+    /// verify_reset_token_keys::<users::Entity>(&db, &keys, &token).await
+    /// ```
+    pub fn secret_keys(&self) -> Vec<&str> {
+        std::iter::once(self.secret_key.as_str())
+            .chain(self.secret_key_fallbacks.iter().map(String::as_str))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 //! Static files, media, and templates configuration.
+use crate::config::file::{VarSource, env_var};
 use serde::{Deserialize, Serialize};
 
 /// Paths and URLs for framework and user project assets.
@@ -44,10 +45,14 @@ fn current_dir_str() -> String {
 pub(crate) static MEDIA_ENV_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
 
 pub fn resolve_media_root() -> String {
-    if let Ok(root) = std::env::var("MEDIA_ROOT") {
+    resolve_media_root_from(&env_var)
+}
+
+fn resolve_media_root_from(var: VarSource) -> String {
+    if let Some(root) = var("MEDIA_ROOT") {
         return root;
     }
-    if let Ok(base) = std::env::var("BASE_DIR") {
+    if let Some(base) = var("BASE_DIR") {
         return format!("{}/media", base);
     }
     format!("{}/media", current_dir_str())
@@ -56,49 +61,49 @@ pub fn resolve_media_root() -> String {
 impl StaticConfig {
     /// Loads paths from environment variables with sensible defaults.
     pub fn from_env() -> Self {
-        let base_dir = std::env::var("BASE_DIR").unwrap_or_else(|_| ".".to_string());
+        Self::from_source(&env_var)
+    }
+
+    /// Same as [`from_env`](Self::from_env), reading each variable through `var`.
+    pub(crate) fn from_source(var: VarSource) -> Self {
+        let base_dir = var("BASE_DIR").unwrap_or_else(|| ".".to_string());
 
-        let static_runique_path = std::env::var("STATIC_RUNIQUE_PATH")
-            .unwrap_or_else(|_| format!("{}/static", env!("CARGO_MANIFEST_DIR")));
+        let static_runique_path = var("STATIC_RUNIQUE_PATH")
+            .unwrap_or_else(|| format!("{}/static", env!("CARGO_MANIFEST_DIR")));
 
         let static_runique_url =
-            std::env::var("STATIC_RUNIQUE_URL").unwrap_or_else(|_| "/runique/static".to_string());
+            var("STATIC_RUNIQUE_URL").unwrap_or_else(|| "/runique/static".to_string());
 
-        let media_runique_path = std::env::var("MEDIA_RUNIQUE_PATH")
-            .unwrap_or_else(|_| format!("{}/media", env!("CARGO_MANIFEST_DIR")));
+        let media_runique_path = var("MEDIA_RUNIQUE_PATH")
+            .unwrap_or_else(|| format!("{}/media", env!("CARGO_MANIFEST_DIR")));
 
         let media_runique =
-            std::env::var("MEDIA_RUNIQUE_URL").unwrap_or_else(|_| "/runique/media".to_string());
+            var("MEDIA_RUNIQUE_URL").unwrap_or_else(|| "/runique/media".to_string());
 
-        let og_image =
-            std::env::var("OG_IMAGE").unwrap_or("/runique/static/favicon_runique.ico".to_string());
+        let og_image = var("OG_IMAGE").unwrap_or("/runique/static/favicon_runique.ico".to_string());
 
-        let templates_runique = std::env::var("TEMPLATES_RUNIQUE")
-            .unwrap_or_else(|_| format!("{}/templates", env!("CARGO_MANIFEST_DIR")));
+        let templates_runique = var("TEMPLATES_RUNIQUE")
+            .unwrap_or_else(|| format!("{}/templates", env!("CARGO_MANIFEST_DIR")));
 
-        let templates_dir = std::env::var("TEMPLATES_DIR")
+        let templates_dir = var("TEMPLATES_DIR")
             .map(|v| v.split(',').map(|s| s.trim().to_string()).collect())
-            .unwrap_or_else(|_| vec!["templates".to_string()]);
+            .unwrap_or_else(|| vec!["templates".to_string()]);
 
-        let staticfiles_dirs =
-            std::env::var("STATICFILES_DIRS").unwrap_or_else(|_| "static".to_string());
+        let staticfiles_dirs = var("STATICFILES_DIRS").unwrap_or_else(|| "static".to_string());
 
-        let media_root = resolve_media_root();
+        let media_root = resolve_media_root_from(var);
 
-        let static_url = std::env::var("STATIC_URL").unwrap_or_else(|_| "/static".to_string());
+        let static_url = var("STATIC_URL").unwrap_or_else(|| "/static".to_string());
 
-        let media_url = std::env::var("MEDIA_URL").unwrap_or_else(|_| "/media".to_string());
+        let media_url = var("MEDIA_URL").unwrap_or_else(|| "/media".to_string());
 
-        let staticfiles =
-            std::env::var("STATICFILES").unwrap_or_else(|_| "default_storage".to_string());
+        let staticfiles = var("STATICFILES").unwrap_or_else(|| "default_storage".to_string());
 
-        let max_upload_mb = std::env::var("RUNIQUE_MAX_UPLOAD_MB")
-            .ok()
+        let max_upload_mb = var("RUNIQUE_MAX_UPLOAD_MB")
             .and_then(|v| v.parse::<u64>().ok())
             .unwrap_or(100);
 
-        let max_text_field_kb = std::env::var("RUNIQUE_MAX_TEXT_FIELD_KB")
-            .ok()
+        let max_text_field_kb = var("RUNIQUE_MAX_TEXT_FIELD_KB")
             .and_then(|v| v.parse::<usize>().ok())
             .unwrap_or(1024);
 
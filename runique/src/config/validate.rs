@@ -0,0 +1,167 @@
+//! Startup validation of [`RuniqueConfig`] — `RuniqueConfig::validate`, run by `build()`.
+use crate::app::error_build::{CheckError, CheckReport};
+use crate::config::RuniqueConfig;
+use crate::config::server::secret_key_is_weak;
+use std::path::Path;
+
+impl RuniqueConfig {
+    /// Checks the whole configuration and reports every problem at once.
+    ///
+    /// Errors (blocking):
+    /// - `SECRET_KEY` or one of `SECRET_KEY_FALLBACKS` weak or default while `debug = false`
+    /// - a `TEMPLATES_DIR` entry that is missing or unreadable
+    /// - a malformed `STATIC_URL` / `MEDIA_URL` / `STATIC_RUNIQUE_URL`
+    /// - an `ALLOWED_HOSTS` entry carrying a scheme or a path
+    /// - ACME enabled without domain or email (`acme` feature, `debug = false`)
+    ///
+    /// Warnings (logged only): `debug = true`, port `0`, `ALLOWED_HOSTS=*` in production.
+    pub fn validate(&self) -> Result<(), CheckReport> {
+        let mut report = CheckReport::new();
+
+        if !self.debug && secret_key_is_weak(&self.server.secret_key) {
+            report.add(
+                CheckError::new(
+                    "Security",
+                    "SECRET_KEY is missing, empty, the default value, or shorter than 32 characters",
+                )
+                .with_suggestion(
+                    "Set SECRET_KEY to a random 32+ character string in your .env file (the `runique` CLI can generate one)",
+                ),
+            );
+        }
+
+        if !self.debug
+            && self
+                .server
+                .secret_key_fallbacks
+                .iter()
+                .any(|key| secret_key_is_weak(key))
+        {
+            report.add(
+                CheckError::new(
+                    "Security",
+                    "SECRET_KEY_FALLBACKS contains a key shorter than 32 characters or the default value",
+                )
+                .with_suggestion(
+                    "Fallback keys still verify signatures: remove the weak key from SECRET_KEY_FALLBACKS",
+                ),
+            );
+        }
+
+        for dir in &self.static_files.templates_dir {
+            if let Some(problem) = dir_problem(Path::new(dir)) {
+                report.add(
+                    CheckError::new("Templates", format!("TEMPLATES_DIR `{dir}` {problem}"))
+                        .with_suggestion(
+                            "Create the directory or fix TEMPLATES_DIR (comma-separated, relative to the working directory)",
+                        ),
+                );
+            }
+        }
+
+        let statics = &self.static_files;
+        for (var, url) in [
+            ("STATIC_URL", &statics.static_url),
+            ("MEDIA_URL", &statics.media_url),
+            ("STATIC_RUNIQUE_URL", &statics.static_runique_url),
+        ] {
+            if let Some(problem) = url_prefix_problem(url) {
+                report.add(
+                    CheckError::new("Static", format!("{var} `{url}` {problem}")).with_suggestion(
+                        format!("Use a path prefix such as `/{}`", default_segment(var)),
+                    ),
+                );
+            }
+        }
+
+        for host in &self.security.allowed_hosts {
+            if host.contains("://") || host.contains('/') {
+                report.add(
+                    CheckError::new(
+                        "AllowedHosts",
+                        format!("ALLOWED_HOSTS entry `{host}` is not a bare host name"),
+                    )
+                    .with_suggestion(
+                        "List host names only (`example.com`, `.example.com` for subdomains) — no scheme or path",
+                    ),
+                );
+            }
+        }
+
+        #[cfg(feature = "acme")]
+        if !self.debug && self.security.acme_enabled {
+            if self.security.acme_domain.is_none() {
+                report.add(
+                    CheckError::new("ACME", "ACME_ENABLED=true but ACME_DOMAIN is not set")
+                        .with_suggestion(
+                            "Set ACME_DOMAIN to your production domain in your .env file",
+                        ),
+                );
+            }
+            if self.security.acme_email.is_none() {
+                report.add(
+                    CheckError::new("ACME", "ACME_ENABLED=true but ACME_EMAIL is not set")
+                        .with_suggestion(
+                            "Set ACME_EMAIL to your Let's Encrypt contact email in your .env file",
+                        ),
+                );
+            }
+        }
+
+        if self.debug {
+            tracing::warn!(
+                "DEBUG=true: detailed error pages and template hot reload are enabled — never in production"
+            );
+        }
+        if self.server.port == 0 {
+            tracing::warn!("PORT=0: the system will pick a free port at startup");
+        }
+        if !self.debug && self.security.allowed_hosts.iter().any(|h| h.trim() == "*") {
+            tracing::warn!("ALLOWED_HOSTS contains `*`: every Host header is accepted");
+        }
+
+        if report.has_errors() {
+            return Err(report);
+        }
+        Ok(())
+    }
+}
+
+/// Why `dir` cannot serve templates, if it cannot.
+fn dir_problem(dir: &Path) -> Option<&'static str> {
+    if !dir.exists() {
+        Some("does not exist")
+    } else if !dir.is_dir() {
+        Some("is not a directory")
+    } else if std::fs::read_dir(dir).is_err() {
+        Some("is not readable")
+    } else {
+        None
+    }
+}
+
+/// Why `url` cannot be nested as a route prefix, if it cannot. Empty means "not served".
+fn url_prefix_problem(url: &str) -> Option<&'static str> {
+    if url.is_empty() {
+        None
+    } else if !url.starts_with('/') {
+        Some("must start with `/`")
+    } else if url.trim_end_matches('/').is_empty() {
+        Some("cannot be the site root")
+    } else if url
+        .chars()
+        .any(|c| c.is_whitespace() || matches!(c, '?' | '#' | '{' | '}'))
+    {
+        Some("must not contain spaces, `?`, `#` or braces")
+    } else {
+        None
+    }
+}
+
+fn default_segment(var: &str) -> &'static str {
+    match var {
+        "MEDIA_URL" => "media",
+        "STATIC_RUNIQUE_URL" => "runique/static",
+        _ => "static",
+    }
+}
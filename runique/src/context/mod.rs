@@ -1,12 +1,16 @@
-//! Request context — extractors, Request template, extensions, and Tera filters.
+//! Request context — extractors, Request template, extensions, Tera filters, SSE responses and WebSockets.
 // pub mod error;
 pub mod request;
 pub mod request_extensions;
+pub mod sse;
 pub mod template;
 pub mod tera;
+pub mod ws;
 
 // pub use error::*;
 pub use request::*;
 pub use request_extensions::*;
+pub use sse::{sse_stream, sse_stream_with};
 pub use template::*;
 pub use tera::*;
+pub use ws::{WsUpgrade, ws};
@@ -3,6 +3,7 @@ use crate::context::Request;
 use crate::flash::Message;
 use crate::utils::aliases::AEngine;
 use crate::utils::csrf::CsrfToken;
+use crate::utils::trad::resolve_locale;
 use axum::{extract::FromRequestParts, http::StatusCode, http::request::Parts};
 use tower_sessions::Session;
 
@@ -11,10 +12,12 @@ use tower_sessions::Session;
 // — The main engine (`RuniqueEngine`)
 // — The template engine (`TemplateEngine`)
 // — The flash messages manager (`Message`)
+// — The active locale (URL prefix → session → `Accept-Language` → default)
 pub struct RuniqueContext {
     pub engine: AEngine,
     pub tpl: Request,
     pub flash: Message,
+    pub locale: String,
 }
 
 impl<S> FromRequestParts<S> for RuniqueContext
@@ -45,18 +48,22 @@ where
             .cloned()
             .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
 
-        // 4. Building the complete context
+        // 4. Active locale, shared with the template context
+        let locale = resolve_locale(&engine.i18n, parts.uri.path(), &parts.headers, session).await;
+        let mut tpl = Request::new(
+            engine.clone(),
+            session.clone(),
+            csrf_token,
+            parts.method.clone(),
+        );
+        tpl.set_locale(locale.clone());
+
+        // 5. Building the complete context
         Ok(Self {
             engine: engine.clone(),
-            tpl: Request::new(
-                engine.clone(),
-                session.clone(),
-                csrf_token,
-                parts.method.clone(),
-            ),
-            flash: Message {
-                session: session.clone(),
-            },
+            tpl,
+            flash: Message::new(session.clone(), engine.config.flash),
+            locale,
         })
     }
 }
@@ -0,0 +1,65 @@
+//! Server-Sent Events — `sse_stream()` turns a stream of [`Event`]s into a
+//! `text/event-stream` response with heartbeat comments.
+use axum::{
+    http::{HeaderName, HeaderValue},
+    response::{
+        IntoResponse, Response,
+        sse::{KeepAlive, Sse},
+    },
+};
+use futures_util::{Stream, StreamExt};
+use std::{convert::Infallible, time::Duration};
+
+pub use axum::response::sse::Event;
+
+/// Interval of the `: heartbeat` comment sent while no event is emitted — keeps
+/// proxies and load balancers from closing an idle connection.
+pub const SSE_HEARTBEAT: Duration = Duration::from_secs(15);
+
+/// `text/event-stream` response streaming `events`, with a heartbeat every
+/// [`SSE_HEARTBEAT`]. When the client disconnects the response body is dropped,
+/// and `events` with it (a `broadcast::Receiver` inside unsubscribes).
+///
+/// ```rust,ignore
+/// use runique::context::sse::{Event, sse_stream};
+/// use tokio::sync::broadcast;
+///
+/// // Shared sender, e.g. registered with `.with_custom_db(tx)`
+/// async fn live_counts(req: Request) -> Response {
+///     let rx = req.engine.extension::<broadcast::Sender<u64>>().unwrap().subscribe();
+///     let events = futures_util::stream::unfold(rx, |mut rx| async move {
+///         loop {
+///             match rx.recv().await {
+///                 Ok(count) => return Some((Event::default().event("count").data(count.to_string()), rx)),
+///                 Err(broadcast::error::RecvError::Lagged(_)) => continue,
+///                 Err(broadcast::error::RecvError::Closed) => return None,
+///             }
+///         }
+///     });
+///     sse_stream(events)
+/// }
+/// ```
+pub fn sse_stream<S>(events: S) -> Response
+where
+    S: Stream<Item = Event> + Send + 'static,
+{
+    sse_stream_with(events, SSE_HEARTBEAT)
+}
+
+/// Like [`sse_stream`], with a custom heartbeat interval.
+pub fn sse_stream_with<S>(events: S, heartbeat: Duration) -> Response
+where
+    S: Stream<Item = Event> + Send + 'static,
+{
+    let sse = Sse::new(events.map(Ok::<_, Infallible>))
+        .keep_alive(KeepAlive::new().interval(heartbeat).text("heartbeat"));
+    // nginx buffers proxied responses by default — events would arrive in bursts
+    (
+        [(
+            HeaderName::from_static("x-accel-buffering"),
+            HeaderValue::from_static("no"),
+        )],
+        sse,
+    )
+        .into_response()
+}
@@ -1,26 +1,35 @@
 //! Main request context: `AppError`, `RuniqueContext`, and Tera context construction.
-use crate::app::templates::TemplateLoader;
 use crate::auth::session::CurrentUser;
+use crate::cache::{CacheError, CacheHandle};
 use crate::errors::error::ErrorContext;
 use crate::flash::Message;
 use crate::forms::{
     extractor::{Prisme, csrf_required, prisme_pipeline},
     field::RuniqueForm,
+    formset::FormSet,
 };
 use crate::impl_from_error;
+use crate::macros::routeur::group::GroupContext;
+use crate::middleware::conditional::{if_none_match, not_modified, strong_etag};
+use crate::middleware::request_id::RequestId;
 use crate::middleware::security::anti_bot::HoneypotFieldName;
+use crate::middleware::security::trusted_proxies::ClientIp;
+use crate::tasks::EnqueueError;
 use crate::utils::aliases::{AEngine, AppResult};
+use crate::utils::trad::resolve_locale;
 use crate::utils::url_params::UrlParams;
 use crate::utils::{csp_nonce::CspNonce, csrf::CsrfToken};
 use axum::{
+    Json,
     body::Body,
     extract::{FromRequest, FromRequestParts, Path},
-    http::{Request as HttpRequest, StatusCode, method::Method},
+    http::{HeaderValue, Request as HttpRequest, StatusCode, header, method::Method},
     response::{Html, IntoResponse, Response},
 };
 use sea_orm::DbErr;
 use serde::de::DeserializeOwned;
 use std::collections::HashMap;
+use std::net::IpAddr;
 use std::str::FromStr;
 use std::sync::Arc;
 use tera::Context;
@@ -58,6 +67,28 @@ impl AppError {
 
 impl_from_error!(anyhow::Error => from_anyhow, DbErr => database);
 
+impl From<EnqueueError> for AppError {
+    fn from(err: EnqueueError) -> Self {
+        Self::from(anyhow::Error::new(err))
+    }
+}
+impl From<EnqueueError> for Box<AppError> {
+    fn from(err: EnqueueError) -> Self {
+        Box::new(AppError::from(err))
+    }
+}
+
+impl From<CacheError> for AppError {
+    fn from(err: CacheError) -> Self {
+        Self::from(anyhow::Error::new(err))
+    }
+}
+impl From<CacheError> for Box<AppError> {
+    fn from(err: CacheError) -> Self {
+        Box::new(AppError::from(err))
+    }
+}
+
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let status = StatusCode::from_u16(self.context.status_code)
@@ -86,6 +117,49 @@ impl IntoResponse for Box<AppError> {
 
 // --- TEMPLATE CONTEXT ---
 
+/// Context keys injected by the framework, left out of [`Request::respond`]'s JSON.
+const INTERNAL_CONTEXT_KEYS: &[&str] = &[
+    "debug",
+    "csrf_token",
+    "csp_nonce",
+    "static_runique",
+    "messages",
+    "current_user",
+    "icon_image",
+    "og_image",
+    "current_path",
+    "locale",
+    "request_id",
+];
+
+/// Negotiation of [`Request::respond`] from the `format` query parameter and the
+/// `Accept` header — also used by the page cache to key both answers apart.
+pub(crate) fn negotiates_json(format: Option<&str>, accept: Option<&str>) -> bool {
+    match format {
+        Some(format) if format.eq_ignore_ascii_case("json") => return true,
+        Some(format) if format.eq_ignore_ascii_case("html") => return false,
+        _ => {}
+    }
+    let Some(accept) = accept else {
+        return false;
+    };
+    let (mut json_q, mut html_q) = (0.0_f32, 0.0_f32);
+    for range in accept.split(',') {
+        let mut parts = range.split(';').map(str::trim);
+        let media = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let q = parts
+            .find_map(|p| p.strip_prefix("q="))
+            .and_then(|q| q.parse::<f32>().ok())
+            .unwrap_or(1.0);
+        match media.as_str() {
+            "application/json" => json_q = json_q.max(q),
+            "text/html" | "application/xhtml+xml" | "text/*" | "*/*" => html_q = html_q.max(q),
+            _ => {}
+        }
+    }
+    json_q > html_q
+}
+
 /// Request context automatically extracted in handlers via `FromRequest`.
 /// Contains the engine, session, flash messages, CSRF token, Tera context,
 /// and the Prisme-extracted form data (query params on GET, body on POST).
@@ -118,6 +192,12 @@ pub struct Request {
     pub prisme: Prisme,
     /// Honeypot field name injected by anti_bot middleware (None if middleware not active).
     pub honeypot_field_name: Option<String>,
+    /// Real client IP resolved by the trusted proxies middleware (None outside the stack).
+    pub client_ip: Option<IpAddr>,
+    /// Active locale (URL prefix → session → `Accept-Language` → default), `locale` in templates.
+    pub locale: String,
+    /// Id set by the request id middleware (None if not active), `request_id` in templates.
+    pub request_id: Option<String>,
 }
 
 impl<S> FromRequest<S> for Request
@@ -147,13 +227,16 @@ where
         let nonce = ex.get::<CspNonce>().map(|n| n.as_str()).unwrap_or_default();
         let user = ex.get::<CurrentUser>().cloned();
         let honeypot_field_name = ex.get::<HoneypotFieldName>().map(|h| h.0.clone());
+        let client_ip = ex.get::<ClientIp>().map(|c| c.0);
+        let request_id = ex.get::<RequestId>().map(|r| r.0.clone());
 
-        let notices = Message {
-            session: session.clone(),
-        };
+        let notices = Message::new(session.clone(), engine.config.flash);
         let messages = notices.get_all().await;
 
-        let mut context = Context::new();
+        let mut context = ex
+            .get::<GroupContext>()
+            .map(|g| g.0.as_ref().clone())
+            .unwrap_or_default();
         context.insert("debug", &engine.config.debug);
         context.insert(
             "csrf_token",
@@ -168,6 +251,9 @@ where
         if let Some(ref u) = user {
             context.insert("current_user", u);
         }
+        if let Some(ref id) = request_id {
+            context.insert("request_id", id);
+        }
 
         let path_params = Path::<HashMap<String, String>>::from_request_parts(&mut parts, state)
             .await
@@ -188,6 +274,9 @@ where
 
         context.insert("current_path", parts.uri.path());
 
+        let locale = resolve_locale(&engine.i18n, parts.uri.path(), &parts.headers, &session).await;
+        context.insert("locale", &locale);
+
         let raw_query = parts.uri.query().unwrap_or_default().to_string();
         let query_params =
             serde_urlencoded::from_str::<HashMap<String, String>>(&raw_query).unwrap_or_default();
@@ -213,6 +302,9 @@ where
             user,
             prisme,
             honeypot_field_name,
+            client_ip,
+            locale,
+            request_id,
         })
     }
 }
@@ -231,11 +323,14 @@ impl Request {
                 .unwrap_or_else(|_| csrf_token.clone())
                 .as_str(),
         );
+        let locale = engine.i18n.default_locale().to_string();
+        context.insert("locale", &locale);
+        let notices = Message::new(session.clone(), engine.config.flash);
 
         Self {
             engine,
-            session: session.clone(),
-            notices: Message { session },
+            session,
+            notices,
             csrf_token,
             context,
             method,
@@ -249,9 +344,27 @@ impl Request {
                 csrf_valid: true,
             },
             honeypot_field_name: None,
+            client_ip: None,
+            locale,
+            request_id: None,
         }
     }
 
+    /// Translation of `key` in the request locale — same lookup as `{% trans %}`.
+    ///
+    /// ```rust,ignore
+    /// success!(req.notices => req.trans("flash.saved"));
+    /// ```
+    pub fn trans(&self, key: &str) -> String {
+        self.engine.i18n.translate(&self.locale, key).to_string()
+    }
+
+    /// Sets the active locale of this request (and `locale` in the template context).
+    pub fn set_locale(&mut self, locale: impl Into<String>) {
+        self.locale = locale.into();
+        self.context.insert("locale", &self.locale);
+    }
+
     /// Returns `true` if the request method is GET.
     pub fn is_get(&self) -> bool {
         self.method == Method::GET
@@ -273,54 +386,86 @@ impl Request {
     }
     /// Unique generic rendering to avoid duplication
     pub fn render(&mut self, template: &str) -> AppResult<Response> {
-        let html_result = if self.engine.config.debug {
-            // In debug mode, Tera is fully reinitialized with the Loader
-            // This applies Regex on {% messages %}, {% form.xxx %}, etc.
-            match TemplateLoader::init(&self.engine.config, self.engine.url_registry.clone()) {
-                Ok(dev_tera) => {
-                    let res = dev_tera.render(template, &self.context);
-                    if let Err(ref e) = res {
-                        // Detailed log of the Tera error with all sources
-                        error!(
-                            template = template,
-                            error_kind = ?e.kind,
-                            error_message = %e,
-                            "Tera rendering failed in debug mode"
-                        );
-
-                        // Log the full error chain (source)
-                        // Uses the source() method from the std::error::Error trait
-                        use std::error::Error as StdError;
-                        if let Some(source) = e.source() {
-                            error!(
-                                source_error = %source,
-                                "Tera error source"
-                            );
-                        }
-                    }
-                    res
-                }
-                Err(e) => {
-                    error!(
-                        template = template,
-                        error = %e,
-                        "Failed to initialize TemplateLoader in debug mode"
-                    );
-                    return Err(AppError::map_tera(
-                        tera::Error::msg(e.to_string()),
-                        template,
-                        &self.engine.tera,
-                    ));
-                }
-            }
-        } else {
-            // Production mode: uses the already transformed instance
-            self.engine.tera.render(template, &self.context)
+        self.render_html(template)
+            .map(|html| Html(html).into_response())
+    }
+
+    /// Like [`render`](Self::render), with a strong ETag of the page: `304 Not Modified`
+    /// when the browser's `If-None-Match` matches. The CSP nonce is left out of the
+    /// hash (a 304 carries no CSP, so the cached page keeps its own). Pages showing
+    /// flash messages are sent without ETag, so a message is never lost to a 304.
+    ///
+    /// ```rust,ignore
+    /// context_update!(req => { "article" => &article });
+    /// req.render_cached("blog/detail.html")
+    /// ```
+    pub fn render_cached(&mut self, template: &str) -> AppResult<Response> {
+        let html = self.render_html(template)?;
+        let has_messages = self
+            .context
+            .get("messages")
+            .and_then(|m| m.as_array())
+            .is_some_and(|m| !m.is_empty());
+        if has_messages {
+            return Ok(Html(html).into_response());
+        }
+
+        let nonce = self
+            .context
+            .get("csp_nonce")
+            .and_then(|n| n.as_str())
+            .filter(|n| !n.is_empty());
+        // The nonce appears raw or HTML-escaped (`/` → `&#x2F;`) depending on the template
+        let etag = match nonce {
+            Some(nonce) => strong_etag(
+                html.replace(&tera::escape_html(nonce), "")
+                    .replace(nonce, "")
+                    .as_bytes(),
+            ),
+            None => strong_etag(html.as_bytes()),
         };
+        let mut response = Html(html).into_response();
+        if let Ok(value) = HeaderValue::from_str(&etag) {
+            response.headers_mut().insert(header::ETAG, value);
+        }
+        response.headers_mut().insert(
+            header::CACHE_CONTROL,
+            HeaderValue::from_static("private, no-cache"),
+        );
+        if if_none_match(&self.headers, &etag) {
+            let (parts, _) = response.into_parts();
+            return Ok(not_modified(&parts));
+        }
+        Ok(response)
+    }
 
-        html_result
-            .map(|html| Html(html).into_response())
-            .map_err(|e| AppError::map_tera(e, template, &self.engine.tera))
+    fn render_html(&mut self, template: &str) -> AppResult<String> {
+        // Snapshot: a template autoreload swapping the instance does not affect this render
+        let tera = self.engine.tera.current();
+        let html_result = tera.render(template, &self.context);
+        if self.engine.config.debug
+            && let Err(ref e) = html_result
+        {
+            // Detailed log of the Tera error with all sources
+            error!(
+                template = template,
+                error_kind = ?e.kind,
+                error_message = %e,
+                "Tera rendering failed in debug mode"
+            );
+
+            // Log the full error chain (source)
+            // Uses the source() method from the std::error::Error trait
+            use std::error::Error as StdError;
+            if let Some(source) = e.source() {
+                error!(
+                    source_error = %source,
+                    "Tera error source"
+                );
+            }
+        }
+
+        html_result.map_err(|e| AppError::map_tera(e, template, &tera))
     }
 
     /// Fluent insertion with builder pattern
@@ -341,11 +486,56 @@ impl Request {
         self.render(template)
     }
 
+    /// Renders `template` for browsers, or answers the Tera context as JSON for
+    /// API clients ([`wants_json`](Self::wants_json)), so one view serves both.
+    /// The JSON body leaves out the keys the framework injects (`csrf_token`,
+    /// `csp_nonce`, `messages`, …). Both answers carry `Vary: Accept`.
+    ///
+    /// ```rust,ignore
+    /// context_update!(req => { "articles" => &articles });
+    /// req.respond("blog/list.html")
+    /// ```
+    pub fn respond(&mut self, template: &str) -> AppResult<Response> {
+        let mut response = if self.wants_json() {
+            let mut data = self.context.clone().into_json();
+            if let Some(map) = data.as_object_mut() {
+                for key in INTERNAL_CONTEXT_KEYS {
+                    map.remove(*key);
+                }
+            }
+            Json(data).into_response()
+        } else {
+            self.render(template)?
+        };
+        response.headers_mut().append(
+            axum::http::header::VARY,
+            axum::http::HeaderValue::from_static("Accept"),
+        );
+        Ok(response)
+    }
+
+    /// `true` when the client asked for JSON: `?format=json` (or `?format=html`)
+    /// wins over the `Accept` header, where `application/json` must rank strictly
+    /// above HTML. No header or `*/*` alone → HTML.
+    pub fn wants_json(&self) -> bool {
+        negotiates_json(
+            self.get_query("format"),
+            self.headers
+                .get(axum::http::header::ACCEPT)
+                .and_then(|v| v.to_str().ok()),
+        )
+    }
+
     /// Returns a reference to the database connection.
     pub fn db(&self) -> &sea_orm::DatabaseConnection {
         &self.engine.db
     }
 
+    /// Returns the application cache (`engine.cache`).
+    pub fn cache(&self) -> &CacheHandle {
+        &self.engine.cache
+    }
+
     /// Returns a path segment as a string slice (`/users/{id}` → `get_path("id")`).
     pub fn get_path(&self, key: &str) -> Option<&str> {
         self.path_params.get(key).map(|s| s.as_str())
@@ -356,6 +546,32 @@ impl Request {
         self.path_params.get(key)?.parse().ok()
     }
 
+    /// Deserializes the path segments into a typed struct — `AppError` 404 when a
+    /// segment does not parse, so `/articles/abc` is a missing page, not a server
+    /// error. An `Option` field is `None` when its optional segment (`{month?}`) is
+    /// absent.
+    ///
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct Archive { year: u32, month: Option<u32> }
+    ///
+    /// let archive: Archive = req.path()?;
+    /// ```
+    pub fn path<T: DeserializeOwned>(&self) -> AppResult<T> {
+        serde_urlencoded::to_string(&self.path_params)
+            .ok()
+            .and_then(|encoded| serde_urlencoded::from_str(&encoded).ok())
+            .ok_or_else(|| {
+                let mut segments: Vec<String> = self
+                    .path_params
+                    .iter()
+                    .map(|(k, v)| format!("{k}={v}"))
+                    .collect();
+                segments.sort();
+                Box::new(AppError::new(ErrorContext::not_found(&segments.join(", "))))
+            })
+    }
+
     /// Returns a query parameter as a string slice (`?page=2` → `get_query("page")`).
     pub fn get_query(&self, key: &str) -> Option<&str> {
         self.query_params.get(key).map(|s| s.as_str())
@@ -389,14 +605,14 @@ impl Request {
             .csrf_token
             .masked()
             .unwrap_or_else(|_| self.csrf_token.clone());
-        let mut form = T::build(self.engine.tera.clone(), masked.as_str());
+        let mut form = T::build(self.engine.tera.current(), masked.as_str());
         form.get_form_mut()
             .set_url_params(&self.path_params, &self.query_params);
 
         if let Some(ref hp_name) = self.honeypot_field_name {
             form.get_form_mut().set_honeypot(hp_name);
-            if self.is_post() && self.prisme.data.get(hp_name).is_some_and(|v| !v.is_empty()) {
-                form.get_form_mut().force_invalid = true;
+            if self.honeypot_tripped::<T>(hp_name) {
+                form.get_form_mut().mark_spam();
             }
         }
 
@@ -411,4 +627,54 @@ impl Request {
             .fill(&self.prisme.data, self.method.clone());
         form
     }
+
+    /// Builds a [`FormSet`] of `F` whose rows post as `{prefix}-{index}-{field}`,
+    /// filled from the submitted data. Same CSRF and honeypot guards as
+    /// [`form`](Self::form); chain `.min()` / `.max()` / `.extra()` on the result.
+    ///
+    /// ```rust,ignore
+    /// let mut lines = req.formset::<LineForm>("items").min(1).max(20);
+    /// if req.is_post() && lines.is_valid().await {
+    ///     for line in lines.cleaned() { /* … */ }
+    /// }
+    /// ```
+    pub fn formset<F: RuniqueForm>(&self, prefix: &str) -> FormSet<F> {
+        let masked = self
+            .csrf_token
+            .masked()
+            .unwrap_or_else(|_| self.csrf_token.clone());
+        let mut formset = FormSet::new(prefix, self.engine.tera.current(), masked.as_str());
+        if let Some(ref hp_name) = self.honeypot_field_name {
+            formset.honeypot_field_name = Some(hp_name.clone());
+            if self.honeypot_tripped::<FormSet<F>>(hp_name) {
+                formset.force_invalid = true;
+            }
+        }
+        if csrf_required(&self.method) && !self.prisme.csrf_valid {
+            formset.force_invalid = true;
+        }
+        formset.fill(&self.prisme.data, self.method.clone());
+        formset
+    }
+
+    /// A rendered form always posts the trap, empty: a filled or missing
+    /// honeypot is a bot, even with a valid CSRF token.
+    fn honeypot_tripped<T>(&self, hp_name: &str) -> bool {
+        if !self.is_post() {
+            return false;
+        }
+        match self.prisme.data.get(hp_name) {
+            Some(value) => !value.is_empty(),
+            None => {
+                // Also what a hand-written template without the trap
+                // produces: say so, the user only sees a silent failure
+                tracing::warn!(
+                    form = std::any::type_name::<T>(),
+                    "anti-bot: honeypot field absent from the submission, form rejected \
+                     (render the form with `form` / `render_form`, or include `form.honeypot_html`)"
+                );
+                true
+            }
+        }
+    }
 }
@@ -1,10 +1,17 @@
-//! Tera `form` filter — HTML rendering of a form field by name from context.
+//! Tera `form` filter — HTML rendering of a form field by name from context —
+//! and `render_form()` function — whole form wrapped in a stable markup.
 // Dans src/tera_function/form_filter.rs
+use crate::context::tera::themed::themed_field;
+use crate::forms::theme::FormTheme;
+use crate::middleware::errors::error::html_escape;
 use crate::utils::aliases::JsonMap;
 use crate::utils::aliases::TResult;
-use tera::Value;
+use crate::utils::constante::session_key::session::CSRF_TOKEN_KEY;
+use crate::utils::trad::tf;
+use tera::{Function, Value};
 
 pub fn form_filter(value: &Value, args: &JsonMap) -> TResult {
+    ensure_prepared(value)?;
     if let Some(field_name) = args.get("field").and_then(|v| v.as_str()) {
         // Reserved accessor `{% form.x.js %}` — the form's auto-collected <script>
         // block (real CSP nonce + resolved static URLs), pre-rendered once by
@@ -39,6 +46,22 @@ pub fn form_filter(value: &Value, args: &JsonMap) -> TResult {
     render_form_html(value)
 }
 
+/// Refuses a form whose `from_query` options were never loaded: rendering it
+/// would silently show empty lists.
+fn ensure_prepared(value: &Value) -> Result<(), tera::Error> {
+    let pending: Vec<&str> = form_key(value, "unprepared_choices")
+        .and_then(|v| v.as_array())
+        .map(|names| names.iter().filter_map(|n| n.as_str()).collect())
+        .unwrap_or_default();
+    if pending.is_empty() {
+        return Ok(());
+    }
+    Err(tera::Error::msg(tf(
+        "forms.choices_not_prepared",
+        &[&pending.join(", ")],
+    )))
+}
+
 /// Checks if the field is the first by index (excluding csrf_token)
 fn is_first_field_by_index(value: &Value, field_name: &str) -> bool {
     let fields = value
@@ -195,3 +218,161 @@ fn find_html(value: &Value) -> Option<String> {
 
     None
 }
+
+/// Tera function `render_form(form=form, layout="stacked", theme=…, csrf_token=csrf_token)`.
+///
+/// Emits the whole form: form-level errors, CSRF field, then every field in
+/// declaration order, honeypot and scripts. With the default `plain` theme each
+/// field keeps its own template (label, input, help text, inline error):
+///
+/// ```html
+/// <div class="runique-form runique-form--stacked">
+///   <ul class="runique-form__errors"><li>…</li></ul>
+///   <div class="runique-field runique-field--email runique-field--error" data-field="email">…</div>
+/// </div>
+/// ```
+///
+/// `bootstrap5` / `tailwind` build the label/input/error markup with the
+/// framework's classes (see [`FormTheme`]). The theme comes from the `theme`
+/// argument, else from the form ([`Forms::set_theme`](crate::forms::Forms::set_theme)),
+/// else `default_theme` — the application's `RuniqueConfig::form_theme`, set
+/// when the function is registered on its Tera instance.
+///
+/// `layout`: `"stacked"` (default) or `"inline"`. `csrf_token` overrides the
+/// token carried by the form.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderFormFunction {
+    pub default_theme: FormTheme,
+}
+
+impl Function for RenderFormFunction {
+    fn is_safe(&self) -> bool {
+        true
+    }
+
+    fn call(&self, args: &JsonMap) -> TResult {
+        let form = args
+            .get("form")
+            .ok_or_else(|| tera::Error::msg("render_form: missing `form` argument"))?;
+        ensure_prepared(form)?;
+        let inline = match args.get("layout").and_then(|v| v.as_str()) {
+            None | Some("stacked") => false,
+            Some("inline") => true,
+            Some(other) => {
+                return Err(tera::Error::msg(format!(
+                    "render_form: unknown layout '{}' (expected \"stacked\" or \"inline\")",
+                    other
+                )));
+            }
+        };
+        let theme = match args
+            .get("theme")
+            .or_else(|| form_key(form, "theme"))
+            .and_then(|v| v.as_str())
+        {
+            Some(name) => name
+                .parse::<FormTheme>()
+                .map_err(|e| tera::Error::msg(format!("render_form: {}", e)))?,
+            None => self.default_theme,
+        };
+        let classes = theme.classes();
+        let fields = form_key(form, "fields")
+            .and_then(|f| f.as_object())
+            .ok_or_else(|| tera::Error::msg("render_form: `form` is not a serialized Forms"))?;
+        let rendered = find_rendered_fields(form).and_then(|r| r.as_object());
+
+        let mut ordered: Vec<&Value> = fields.values().collect();
+        ordered.sort_by_key(|f| f.get("index").and_then(|i| i.as_u64()).unwrap_or(u64::MAX));
+
+        let mut html = format!(
+            r#"<div class="{}">"#,
+            if inline {
+                classes.form_inline
+            } else {
+                classes.form
+            }
+        );
+
+        if let Some(errors) = form_key(form, "form_errors")
+            .and_then(|e| e.as_array())
+            .filter(|e| !e.is_empty())
+        {
+            html.push_str(&format!(r#"<ul class="{}">"#, classes.form_errors));
+            for error in errors.iter().filter_map(|e| e.as_str()) {
+                html.push_str(&format!("<li>{}</li>", html_escape(error)));
+            }
+            html.push_str("</ul>");
+        }
+
+        let csrf = match args.get(CSRF_TOKEN_KEY).and_then(|v| v.as_str()) {
+            Some(token) => format!(
+                r#"<input type="hidden" name="{}" value="{}">"#,
+                CSRF_TOKEN_KEY,
+                html_escape(token)
+            ),
+            None => render_csrf(form).unwrap_or_default(),
+        };
+        html.push_str(&csrf);
+
+        for field in ordered {
+            let Some(name) = field.get("name").and_then(|n| n.as_str()) else {
+                continue;
+            };
+            if name == CSRF_TOKEN_KEY {
+                continue;
+            }
+            if !theme.uses_field_templates()
+                && let Some(markup) = themed_field(field, classes)
+            {
+                html.push_str(&markup);
+                continue;
+            }
+            let Some(markup) = rendered.and_then(|r| r.get(name)).and_then(|h| h.as_str()) else {
+                continue;
+            };
+            if !theme.uses_field_templates() {
+                html.push_str(&format!(
+                    r#"<div class="{}" data-field="{}">{}</div>"#,
+                    classes.wrapper,
+                    html_escape(name),
+                    markup
+                ));
+                continue;
+            }
+            let kind = field
+                .get("field_type")
+                .and_then(|t| t.as_str())
+                .unwrap_or("text");
+            let error_class = if field.get("error").is_some() {
+                " runique-field--error"
+            } else {
+                ""
+            };
+            html.push_str(&format!(
+                r#"<div class="{} {}--{}{}" data-field="{}">{}</div>"#,
+                classes.wrapper,
+                classes.wrapper,
+                html_escape(kind),
+                error_class,
+                html_escape(name),
+                markup
+            ));
+        }
+
+        if let Some(hp) = get_honeypot_html(form) {
+            html.push_str(&hp);
+        }
+        if let Some(js) = render_scripts(form) {
+            html.push_str(&js);
+        }
+        html.push_str("</div>");
+        Ok(Value::String(html))
+    }
+}
+
+/// `value.key`, or `value.form.key` when a whole `RuniqueForm` struct was passed.
+fn form_key<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    value
+        .get(key)
+        .or_else(|| value.get("form").and_then(|f| f.get(key)))
+}
@@ -1,8 +1,11 @@
-//! Tera filters and functions — `form_filter`, `| static`, `{% link %}`, `| markdown`, CSRF token.
+//! Tera filters and functions — `form_filter`, `render_form()`, `| static`, `static_url()`, `{% link %}`, `| markdown`, `{% trans %}`, CSRF token.
 pub mod form;
 pub mod static_tera;
+mod themed;
+pub mod trans;
 pub mod url;
 
 pub use form::*;
 pub use static_tera::*;
+pub use trans::*;
 pub use url::*;
@@ -1,12 +1,14 @@
-//! Global registration of Tera filters/functions — `register_asset_filters` and `| markdown` filter.
-use crate::context::tera::form::form_filter;
+//! Global registration of Tera filters/functions — `register_asset_filters` and `| markdown` / `| render_markdown` filters.
+use crate::context::tera::form::{RenderFormFunction, form_filter};
 use crate::context::tera::url::LinkFunction;
+use crate::forms::theme::FormTheme;
 use crate::middleware::CsrfTokenFunction;
+use crate::utils::StaticManifest;
 use crate::utils::aliases::{ARlockmap, JsonMap, TResult};
 use crate::utils::trad::tf;
 use chrono::NaiveDateTime;
-use pulldown_cmark::{Options, Parser, html};
-use tera::{Tera, Value};
+use std::{collections::HashSet, sync::Mutex};
+use tera::{Function, Tera, Value};
 
 // Filter to mask a sensitive value with bullets (real number of characters)
 fn mask_filter(value: &Value, _: &JsonMap) -> TResult {
@@ -38,30 +40,29 @@ fn format_date_filter(value: &Value, _: &JsonMap) -> TResult {
     Ok(Value::String(formatted))
 }
 
-// Markdown filter → HTML (tables, strikethrough, heading ids)
+// Markdown filter → HTML (tables, strikethrough, heading ids), also registered
+// as `render_markdown`. The template preprocessor forces `| safe` on both, so the
+// output is emitted unescaped — `render_markdown` sanitizes it to neutralize XSS
+// in user-authored Markdown (raw <script>, javascript: links, etc.).
 fn markdown_filter(value: &Value, _: &JsonMap) -> TResult {
     let md = value.as_str().unwrap_or("");
-    let mut opts = Options::empty();
-    opts.insert(Options::ENABLE_TABLES);
-    opts.insert(Options::ENABLE_STRIKETHROUGH);
-    opts.insert(Options::ENABLE_HEADING_ATTRIBUTES);
-    let parser = Parser::new_ext(md, opts);
-    let mut output = String::new();
-    html::push_html(&mut output, parser);
-    // The template preprocessor forces `| safe` on every `| markdown`, so the
-    // output is emitted unescaped. Sanitize here to neutralize XSS in
-    // user-authored Markdown (raw <script>, javascript: links, etc.).
-    let output = crate::utils::sanitizer::sanitize_markdown(&output);
-    Ok(Value::String(output))
+    Ok(Value::String(crate::utils::sanitizer::render_markdown(md)))
 }
 
 // Re-sanitizes stored rich HTML at render time. The preprocessor forces `| safe`
 // on every `| sanitize`, so the output is emitted unescaped — but it is ammonia's
 // own (XSS-free by construction) output, re-cleaned here regardless of how the
-// value reached storage.
-fn sanitize_filter(value: &Value, _: &JsonMap) -> TResult {
+// value reached storage. `policy="name"` uses a registered `SanitizePolicy`.
+fn sanitize_filter(value: &Value, args: &JsonMap) -> TResult {
     let raw = value.as_str().unwrap_or("");
-    Ok(Value::String(crate::utils::sanitizer::sanitize_rich(raw)))
+    match args.get("policy").and_then(Value::as_str) {
+        Some(name) => {
+            let policy = crate::utils::sanitizer::get_sanitize_policy(name)
+                .ok_or_else(|| tera::Error::msg(format!("sanitize: unknown policy '{name}'")))?;
+            Ok(Value::String(policy.clean(raw)))
+        }
+        None => Ok(Value::String(crate::utils::sanitizer::sanitize_rich(raw))),
+    }
 }
 
 // Plain-text projection of a (possibly rich) value: strips every tag and decodes
@@ -122,6 +123,139 @@ fn register_filter(base_url: String, version: String) -> impl Fn(&Value, &JsonMa
     }
 }
 
+/// `static_url(path="css/app.css")` → `/static/css/app.css?v=<content hash>` from the
+/// startup [`StaticManifest`]. A file absent from it keeps the plain URL (warned once).
+pub struct StaticUrlFunction {
+    base_url: String,
+    manifest: StaticManifest,
+    warned: Mutex<HashSet<String>>,
+}
+
+impl StaticUrlFunction {
+    pub fn new(base_url: String, manifest: StaticManifest) -> Self {
+        Self {
+            base_url,
+            manifest,
+            warned: Mutex::new(HashSet::new()),
+        }
+    }
+}
+
+impl Function for StaticUrlFunction {
+    fn call(&self, args: &JsonMap) -> TResult {
+        let file = args
+            .get("path")
+            .and_then(Value::as_str)
+            .ok_or_else(|| tera::Error::msg("static_url() requires a 'path' argument"))?
+            .trim_start_matches('/');
+        let url = format!("{}/{}", self.base_url.trim_end_matches('/'), file);
+
+        match self.manifest.hash(file) {
+            Some(hash) => Ok(Value::String(format!("{url}?v={hash}"))),
+            None => {
+                let first = self
+                    .warned
+                    .lock()
+                    .map(|mut warned| warned.insert(file.to_string()))
+                    .unwrap_or(false);
+                if first {
+                    tracing::warn!(
+                        path = file,
+                        "static_url(): file not found in the static directory, served without hash"
+                    );
+                }
+                Ok(Value::String(url))
+            }
+        }
+    }
+}
+
+/// `sri_hash(path="js/app.js")` → `integrity="sha384-…"` from the startup
+/// [`StaticManifest`]. A file absent from it gives an empty string — the loader
+/// already warned about it when the template was compiled.
+pub struct SriHashFunction {
+    manifest: StaticManifest,
+}
+
+impl SriHashFunction {
+    pub fn new(manifest: StaticManifest) -> Self {
+        Self { manifest }
+    }
+}
+
+impl Function for SriHashFunction {
+    fn call(&self, args: &JsonMap) -> TResult {
+        let file = args
+            .get("path")
+            .and_then(Value::as_str)
+            .ok_or_else(|| tera::Error::msg("sri_hash() requires a 'path' argument"))?;
+        Ok(Value::String(
+            self.manifest
+                .integrity(file)
+                .map(|integrity| format!(r#"integrity="{integrity}""#))
+                .unwrap_or_default(),
+        ))
+    }
+
+    fn is_safe(&self) -> bool {
+        true
+    }
+}
+
+/// `script_tag(path="js/app.js", nonce=csp_nonce)` → a `<script>` carrying the
+/// hashed `src`, `integrity`, `crossorigin="anonymous"` and the CSP nonce. A file
+/// absent from the [`StaticManifest`] keeps the plain `src`, without `integrity`.
+pub struct ScriptTagFunction {
+    base_url: String,
+    manifest: StaticManifest,
+}
+
+impl ScriptTagFunction {
+    pub fn new(base_url: String, manifest: StaticManifest) -> Self {
+        Self { base_url, manifest }
+    }
+}
+
+impl Function for ScriptTagFunction {
+    fn call(&self, args: &JsonMap) -> TResult {
+        let file = args
+            .get("path")
+            .and_then(Value::as_str)
+            .ok_or_else(|| tera::Error::msg("script_tag() requires a 'path' argument"))?
+            .trim_start_matches('/');
+        let mut src = format!("{}/{}", self.base_url.trim_end_matches('/'), file);
+        if let Some(hash) = self.manifest.hash(file) {
+            src = format!("{src}?v={hash}");
+        }
+
+        let mut tag = format!(
+            r#"<script src="{}""#,
+            html_escape::encode_double_quoted_attribute(&src)
+        );
+        if let Some(integrity) = self.manifest.integrity(file) {
+            tag.push_str(&format!(
+                r#" integrity="{integrity}" crossorigin="anonymous""#
+            ));
+        }
+        if let Some(nonce) = args
+            .get("nonce")
+            .and_then(Value::as_str)
+            .filter(|n| !n.is_empty())
+        {
+            tag.push_str(&format!(
+                r#" nonce="{}""#,
+                html_escape::encode_double_quoted_attribute(nonce)
+            ));
+        }
+        tag.push_str("></script>");
+        Ok(Value::String(tag))
+    }
+
+    fn is_safe(&self) -> bool {
+        true
+    }
+}
+
 pub fn register_asset_filters(
     tera: &mut Tera,
     static_url: String,
@@ -129,6 +263,7 @@ pub fn register_asset_filters(
     runique_static_url: String,
     runique_media_url: String,
     url_registry: ARlockmap,
+    form_theme: FormTheme,
 ) {
     let version = crate::utils::env::css_token();
     tera.register_filter("mask", mask_filter);
@@ -145,10 +280,17 @@ pub fn register_asset_filters(
     tera.register_filter("form", form_filter);
     tera.register_filter("csrf_field", csrf_filter);
     tera.register_filter("markdown", markdown_filter);
+    tera.register_filter("render_markdown", markdown_filter);
     tera.register_filter("sanitize", sanitize_filter);
     tera.register_filter("plaintext", plaintext_filter);
     tera.register_filter("format_date", format_date_filter);
     tera.register_filter("humanize", humanize_filter);
     tera.register_function("csrf_token", CsrfTokenFunction);
     tera.register_function("link", LinkFunction { url_registry });
+    tera.register_function(
+        "render_form",
+        RenderFormFunction {
+            default_theme: form_theme,
+        },
+    );
 }
@@ -0,0 +1,361 @@
+//! Framework markup of `render_form` for the `Bootstrap5` / `Tailwind` themes,
+//! built from the serialized field data instead of the field template.
+use crate::forms::theme::ThemeClasses;
+use crate::middleware::errors::error::html_escape;
+use tera::Value;
+
+/// Full markup of one field (wrapper included), or `None` when the field keeps its
+/// own template: dates, files, rich text and special widgets carry constraints
+/// (`min`, `accept`, editor scripts) that only their template knows about.
+pub(crate) fn themed_field(field: &Value, c: &ThemeClasses) -> Option<String> {
+    let f = FieldData::new(field)?;
+    let template = str_of(field, "template_name");
+    match (template, f.kind) {
+        ("base_hidden.html", _) => Some(format!(
+            r#"<input type="hidden" name="{}" value="{}">"#,
+            f.name,
+            html_escape(f.value)
+        )),
+        ("base_boolean.html", _) => Some(boolean(&f, c)),
+        ("base_radio.html", _) => Some(choice_group(&f, c, "radio")),
+        ("base_checkbox.html", _) => Some(choice_group(&f, c, "checkbox")),
+        ("base_select.html", _) => Some(select(&f, c)),
+        ("base_string.html", "richtext") => None,
+        ("base_string.html", "textarea") => Some(textarea(&f, c)),
+        ("base_string.html" | "base_number.html" | "base_color.html", _) => Some(input(&f, c)),
+        _ => None,
+    }
+}
+
+/// Escaped view of a serialized field.
+struct FieldData<'a> {
+    raw: &'a Value,
+    name: String,
+    kind: &'a str,
+    label: String,
+    value: &'a str,
+    error: Option<String>,
+    required: bool,
+    /// `readonly` / `disabled` flags + `html_attributes`, leading space included.
+    attrs: String,
+}
+
+impl<'a> FieldData<'a> {
+    fn new(raw: &'a Value) -> Option<Self> {
+        let name = raw.get("name").and_then(|n| n.as_str())?;
+        let mut attrs = String::new();
+        for flag in ["readonly", "disabled"] {
+            if raw.get(flag).is_some_and(flag_set) {
+                attrs.push(' ');
+                attrs.push_str(flag);
+            }
+        }
+        if let Some(extra) = raw.get("html_attributes").and_then(|a| a.as_object()) {
+            for (key, value) in extra {
+                attrs.push_str(&format!(
+                    r#" {}="{}""#,
+                    html_escape(key),
+                    html_escape(value.as_str().unwrap_or_default())
+                ));
+            }
+        }
+        Some(Self {
+            raw,
+            name: html_escape(name),
+            kind: str_of(raw, "field_type"),
+            label: html_escape(str_of(raw, "label")),
+            value: str_of(raw, "value"),
+            error: raw
+                .get("error")
+                .and_then(|e| e.as_str())
+                .filter(|e| !e.is_empty())
+                .map(html_escape),
+            required: raw.get("is_required").is_some_and(flag_set),
+            attrs,
+        })
+    }
+
+    /// Checkboxes never get `required`: for a boolean it means NOT NULL, not "must be ticked".
+    fn required_attr(&self) -> &'static str {
+        if self.required { " required" } else { "" }
+    }
+
+    fn id(&self) -> String {
+        format!("id_{}", self.name)
+    }
+
+    fn placeholder(&self) -> String {
+        match str_of(self.raw, "placeholder") {
+            "" => String::new(),
+            p => format!(r#" placeholder="{}""#, html_escape(p)),
+        }
+    }
+
+    /// Help text, password requirements, then the error message.
+    fn footer(&self, c: &ThemeClasses) -> String {
+        let mut html = String::new();
+        if let Some(reqs) = self
+            .raw
+            .pointer("/meta/requirements")
+            .and_then(|r| r.as_array())
+        {
+            html.push_str(&format!("<ul{}>", class_attr(&[c.help])));
+            for req in reqs.iter().filter_map(|r| r.as_str()) {
+                html.push_str(&format!("<li>{}</li>", html_escape(req)));
+            }
+            html.push_str("</ul>");
+        }
+        if let Some(help) = self.raw.get("help_text").and_then(|h| h.as_str()) {
+            html.push_str(&format!(
+                "<div{}>{}</div>",
+                class_attr(&[c.help]),
+                html_escape(help)
+            ));
+        }
+        if let Some(error) = &self.error {
+            html.push_str(&format!("<div{}>{}</div>", class_attr(&[c.error]), error));
+        }
+        html
+    }
+
+    fn invalid<'c>(&self, c: &'c ThemeClasses) -> &'c str {
+        if self.error.is_some() { c.invalid } else { "" }
+    }
+
+    fn open(&self, c: &ThemeClasses, tag: &str) -> String {
+        format!(
+            r#"<{}{} data-field="{}">"#,
+            tag,
+            class_attr(&[c.wrapper]),
+            self.name
+        )
+    }
+
+    fn label(&self, c: &ThemeClasses) -> String {
+        if self.label.is_empty() {
+            return String::new();
+        }
+        format!(
+            r#"<label for="{}"{}>{}</label>"#,
+            self.id(),
+            class_attr(&[c.label]),
+            self.label
+        )
+    }
+}
+
+fn input(f: &FieldData, c: &ThemeClasses) -> String {
+    // Never echo a password back into the page
+    let value = if f.kind == "password" {
+        String::new()
+    } else {
+        format!(r#" value="{}""#, html_escape(f.value))
+    };
+    format!(
+        r#"{}{}<input type="{}" id="{}" name="{}"{}{}{}{}{}>{}</div>"#,
+        f.open(c, "div"),
+        f.label(c),
+        html_escape(if f.kind.is_empty() { "text" } else { f.kind }),
+        f.id(),
+        f.name,
+        value,
+        f.placeholder(),
+        class_attr(&[c.input, f.invalid(c)]),
+        f.required_attr(),
+        f.attrs,
+        f.footer(c)
+    )
+}
+
+fn textarea(f: &FieldData, c: &ThemeClasses) -> String {
+    format!(
+        r#"{}{}<textarea id="{}" name="{}"{}{}{}{}>{}</textarea>{}</div>"#,
+        f.open(c, "div"),
+        f.label(c),
+        f.id(),
+        f.name,
+        f.placeholder(),
+        class_attr(&[c.input, f.invalid(c)]),
+        f.required_attr(),
+        f.attrs,
+        html_escape(f.value),
+        f.footer(c)
+    )
+}
+
+fn select(f: &FieldData, c: &ThemeClasses) -> String {
+    let meta = f.raw.get("meta");
+    let multiple = meta
+        .and_then(|m| m.get("multiple"))
+        .and_then(|m| m.as_bool())
+        .unwrap_or(false);
+    let selected: Vec<&str> = f.value.split(',').map(str::trim).collect();
+
+    let mut options = String::new();
+    let placeholder = str_of(f.raw, "placeholder");
+    if !placeholder.is_empty() {
+        options.push_str(&format!(
+            r#"<option value=""{}>{}</option>"#,
+            if f.value.is_empty() { " selected" } else { "" },
+            html_escape(placeholder)
+        ));
+    }
+    // Each <optgroup> goes before the option at its `position` (past the end: after
+    // the last one) — see `ChoiceGroup::position`
+    let list = |key: &str| {
+        meta.and_then(|m| m.get(key))
+            .and_then(|v| v.as_array())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    };
+    let (choices, groups) = (list("choices"), list("groups"));
+    let last = choices.len();
+    let position = |g: &Value| {
+        g.get("position")
+            .and_then(Value::as_u64)
+            .map_or(last, |p| (p as usize).min(last))
+    };
+    for index in 0..=last {
+        for group in groups.iter().filter(|g| position(g) == index) {
+            options.push_str(&format!(
+                r#"<optgroup label="{}">{}</optgroup>"#,
+                html_escape(str_of(group, "label")),
+                list_options(group.get("choices"), &selected)
+            ));
+        }
+        if let Some(choice) = choices.get(index) {
+            options.push_str(&option_tag(choice, &selected));
+        }
+    }
+
+    format!(
+        r#"{}{}<select id="{}" name="{}"{}{}{}{}>{}</select>{}</div>"#,
+        f.open(c, "div"),
+        f.label(c),
+        f.id(),
+        f.name,
+        if multiple { " multiple" } else { "" },
+        class_attr(&[c.select, f.invalid(c)]),
+        f.required_attr(),
+        f.attrs,
+        options,
+        f.footer(c)
+    )
+}
+
+fn list_options(choices: Option<&Value>, selected: &[&str]) -> String {
+    choices
+        .and_then(|ch| ch.as_array())
+        .map(|list| list.iter().map(|ch| option_tag(ch, selected)).collect())
+        .unwrap_or_default()
+}
+
+fn option_tag(choice: &Value, selected: &[&str]) -> String {
+    format!(
+        r#"<option value="{}"{}>{}</option>"#,
+        html_escape(str_of(choice, "value")),
+        if is_checked(choice, selected) {
+            " selected"
+        } else {
+            ""
+        },
+        html_escape(str_of(choice, "label"))
+    )
+}
+
+/// Single checkbox / radio (`BooleanField`): Bootstrap `form-check` layout.
+fn boolean(f: &FieldData, c: &ThemeClasses) -> String {
+    let label = if f.label.is_empty() {
+        String::new()
+    } else {
+        format!(
+            r#"<label for="{}"{}>{}</label>"#,
+            f.id(),
+            class_attr(&[c.check_label]),
+            f.label
+        )
+    };
+    format!(
+        r#"{}<div{}><input type="{}" id="{}" name="{}" value="true"{}{}{}>{}</div>{}</div>"#,
+        f.open(c, "div"),
+        class_attr(&[c.check]),
+        html_escape(f.kind),
+        f.id(),
+        f.name,
+        if f.value == "true" { " checked" } else { "" },
+        class_attr(&[c.check_input, f.invalid(c)]),
+        f.attrs,
+        label,
+        f.footer(c)
+    )
+}
+
+/// `RadioField` / `CheckboxField`: `<fieldset>` with one `form-check` per option.
+fn choice_group(f: &FieldData, c: &ThemeClasses, input_type: &str) -> String {
+    let selected: Vec<&str> = f.value.split(',').map(str::trim).collect();
+    let mut html = f.open(c, "fieldset");
+    if !f.label.is_empty() {
+        html.push_str(&format!(
+            "<legend{}>{}</legend>",
+            class_attr(&[c.label]),
+            f.label
+        ));
+    }
+    let choices = f
+        .raw
+        .pointer("/meta/choices")
+        .and_then(|ch| ch.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for (i, choice) in choices.iter().enumerate() {
+        let id = format!("{}_{}", f.id(), i);
+        html.push_str(&format!(
+            r#"<div{}><input type="{}" id="{}" name="{}" value="{}"{}{}{}{}><label for="{}"{}>{}</label></div>"#,
+            class_attr(&[c.check]),
+            input_type,
+            id,
+            f.name,
+            html_escape(str_of(choice, "value")),
+            if is_checked(choice, &selected) { " checked" } else { "" },
+            class_attr(&[c.check_input, f.invalid(c)]),
+            if input_type == "radio" { f.required_attr() } else { "" },
+            f.attrs,
+            id,
+            class_attr(&[c.check_label]),
+            html_escape(str_of(choice, "label"))
+        ));
+    }
+    html.push_str(&f.footer(c));
+    html.push_str("</fieldset>");
+    html
+}
+
+fn is_checked(choice: &Value, selected: &[&str]) -> bool {
+    choice
+        .get("selected")
+        .and_then(|s| s.as_bool())
+        .unwrap_or(false)
+        || selected.contains(&str_of(choice, "value"))
+}
+
+/// ` class="a b"`, or nothing when every part is empty.
+fn class_attr(parts: &[&str]) -> String {
+    let classes: Vec<&str> = parts.iter().copied().filter(|p| !p.is_empty()).collect();
+    if classes.is_empty() {
+        String::new()
+    } else {
+        format!(r#" class="{}""#, classes.join(" "))
+    }
+}
+
+/// `required` / `readonly` / `disabled` serialize either as a bool or as `{choice, message}`.
+fn flag_set(value: &Value) -> bool {
+    value
+        .as_bool()
+        .or_else(|| value.get("choice").and_then(|c| c.as_bool()))
+        .unwrap_or(false)
+}
+
+fn str_of<'a>(value: &'a Value, key: &str) -> &'a str {
+    value.get(key).and_then(|v| v.as_str()).unwrap_or_default()
+}
@@ -0,0 +1,40 @@
+//! Tera `trans` function — `{% trans "nav.home" %}` looks the key up in the request locale.
+use crate::utils::aliases::{JsonMap, TResult};
+use crate::utils::trad::Catalogs;
+use std::sync::Arc;
+use tera::{Function, Value};
+
+/// `trans(key="…", locale="…")` — the `{% trans %}` tag passes the `locale` of the
+/// request context. Missing keys fall back to the default locale, then the key itself.
+pub struct TransFunction {
+    catalogs: Arc<Catalogs>,
+    /// Logs keys missing from the active locale (debug builds)
+    debug: bool,
+}
+
+impl TransFunction {
+    pub fn new(catalogs: Arc<Catalogs>, debug: bool) -> Self {
+        Self { catalogs, debug }
+    }
+}
+
+impl Function for TransFunction {
+    fn call(&self, args: &JsonMap) -> TResult {
+        let key = args
+            .get("key")
+            .and_then(Value::as_str)
+            .ok_or_else(|| tera::Error::msg("trans() requires a 'key' argument"))?;
+        let locale = args
+            .get("locale")
+            .and_then(Value::as_str)
+            .filter(|l| !l.is_empty())
+            .unwrap_or(self.catalogs.default_locale());
+
+        if self.debug && self.catalogs.lookup(locale, key).is_none() {
+            tracing::debug!(key, locale, "translation missing");
+        }
+        Ok(Value::String(
+            self.catalogs.translate(locale, key).to_string(),
+        ))
+    }
+}
@@ -1,4 +1,5 @@
 //! Tera `link` function — named URL resolution from the global registry, with optional parameters.
+use crate::macros::routeur::register_url::strip_optional;
 use crate::utils::aliases::{ARlockmap, JsonMap, TResult};
 use tera::{Function, Value};
 
@@ -27,8 +28,8 @@ fn link_function(args: &JsonMap, url_registry: &ARlockmap) -> TResult {
     })?;
     drop(map);
 
-    // Substitute route parameters {id}, {slug}, etc.
-    let mut result = args
+    // Substitute route parameters {id}, {slug}, etc. — unfilled optional segments dropped
+    let result = args
         .iter()
         .filter(|(k, _)| *k != "link" && *k != "query")
         .fold(pattern, |acc, (k, v)| {
@@ -38,7 +39,9 @@ fn link_function(args: &JsonMap, url_registry: &ARlockmap) -> TResult {
                 _ => v.to_string(),
             };
             acc.replace(&format!("{{{}}}", k), &value)
+                .replace(&format!("{{{}?}}", k), &value)
         });
+    let mut result = strip_optional(&result);
 
     // Handle query string parameters
     if let Some(query_val) = args.get("query") {
@@ -80,6 +83,10 @@ fn link_function(args: &JsonMap, url_registry: &ARlockmap) -> TResult {
 //   {% link "article_detail" id=article.id %}
 //   → /articles/42
 //
+// Optional segment ("/archives/{year}/{month?}"):
+//   {% link "archives" year=2024 %}
+//   → /archives/2024
+//
 // Without query:
 //   {% link "article_list" %}
 //   → /articles
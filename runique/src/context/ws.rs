@@ -0,0 +1,140 @@
+//! WebSockets — [`WsUpgrade`] wraps axum's `WebSocketUpgrade` with the Runique
+//! request state (engine, session, [`CurrentUser`]), and [`ws()`] registers the route.
+use crate::auth::session::CurrentUser;
+use crate::engine::RuniqueEngine;
+use crate::utils::{aliases::AEngine, trad::t};
+use axum::{
+    extract::{FromRequestParts, ws::WebSocketUpgrade},
+    handler::Handler,
+    http::{StatusCode, header, request::Parts},
+    response::{IntoResponse, Response},
+    routing::{MethodRouter, get},
+};
+use std::future::Future;
+use tower_sessions::Session;
+
+pub use axum::extract::ws::{CloseFrame, Message, Utf8Bytes, WebSocket};
+
+/// WebSocket handshake extractor. The session and user are read **before** the
+/// upgrade — once upgraded, the connection no longer goes through the middlewares.
+///
+/// Browsers send the session cookie with any cross-site handshake, so the `Origin`
+/// must be the app itself, an allowed host or a CORS origin — `403` otherwise.
+///
+/// ```rust,ignore
+/// use runique::context::ws::{Message, WebSocket, WsUpgrade};
+///
+/// async fn chat(ws: WsUpgrade) -> Response {
+///     let user = ws.user.clone();
+///     ws.on_upgrade(move |socket| room(socket, user))
+/// }
+/// ```
+pub struct WsUpgrade {
+    upgrade: WebSocketUpgrade,
+    pub engine: AEngine,
+    pub session: Session,
+    /// Authenticated user — `None` for an anonymous connection.
+    pub user: Option<CurrentUser>,
+}
+
+impl WsUpgrade {
+    /// Completes the handshake (`101 Switching Protocols`) and runs `callback` on the
+    /// connection. The task ends when `callback` returns: the socket is closed and
+    /// everything it owns is dropped — this is where a room unsubscribes.
+    pub fn on_upgrade<F, Fut>(self, callback: F) -> Response
+    where
+        F: FnOnce(WebSocket) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.upgrade.on_upgrade(callback)
+    }
+
+    /// The axum upgrade, to tune limits (`max_message_size`, `protocols`, …).
+    pub fn into_inner(self) -> WebSocketUpgrade {
+        self.upgrade
+    }
+}
+
+impl<S> FromRequestParts<S> for WsUpgrade
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let err = |msg: &str| (StatusCode::INTERNAL_SERVER_ERROR, msg.to_string()).into_response();
+
+        let engine = parts
+            .extensions
+            .get::<AEngine>()
+            .cloned()
+            .ok_or_else(|| err("engine missing"))?;
+        if !origin_allowed(parts, &engine) {
+            return Err((StatusCode::FORBIDDEN, t("error.forbidden").into_owned()).into_response());
+        }
+        let session = parts
+            .extensions
+            .get::<Session>()
+            .cloned()
+            .ok_or_else(|| err("session missing"))?;
+        let user = parts.extensions.get::<CurrentUser>().cloned();
+        let upgrade = WebSocketUpgrade::from_request_parts(parts, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        Ok(Self {
+            upgrade,
+            engine,
+            session,
+            user,
+        })
+    }
+}
+
+/// `Origin` of the handshake: same host as the request, allowed host (when host
+/// validation is on) or CORS origin. A missing or opaque (`null`) origin is refused.
+fn origin_allowed(parts: &Parts, engine: &RuniqueEngine) -> bool {
+    let Some(origin) = parts
+        .headers
+        .get(header::ORIGIN)
+        .and_then(|v| v.to_str().ok())
+    else {
+        return false;
+    };
+    if engine
+        .trusted_origins
+        .iter()
+        .any(|trusted| trusted.trim_end_matches('/').eq_ignore_ascii_case(origin))
+    {
+        return true;
+    }
+    let Some((_, authority)) = origin.split_once("://") else {
+        return false;
+    };
+    let same_host = parts
+        .headers
+        .get(header::HOST)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|host| host.eq_ignore_ascii_case(authority));
+    same_host
+        || (engine.security_hosts.enabled
+            && engine
+                .security_hosts
+                .is_host_allowed_for(authority, parts.uri.path()))
+}
+
+/// WebSocket route — the handshake is a `GET`. Used like `view!`, and composes with
+/// [`RouterExt::login_required`](crate::macros::RouterExt::login_required):
+///
+/// ```rust,ignore
+/// urlpatterns! {
+///     "/chat" => ws(chat), name = "chat",
+/// }
+/// .login_required("/private-chat", "private_chat", ws(chat), "/login")
+/// ```
+pub fn ws<H, T>(handler: H) -> MethodRouter
+where
+    H: Handler<T, ()>,
+    T: 'static,
+{
+    get(handler)
+}
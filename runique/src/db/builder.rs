@@ -98,7 +98,61 @@ impl DatabaseConfigBuilder {
         self
     }
 
-    /// Enables or disables SQL query logging.
+    /// Sets the time allowed to acquire a connection from the pool.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use runique::prelude::DatabaseConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = DatabaseConfig::from_url("postgres://localhost/db")?
+    ///     .acquire_timeout(Duration::from_secs(1))
+    ///     .build();
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn acquire_timeout(mut self, timeout: Duration) -> Self {
+        self.config.acquire_timeout = timeout;
+        self
+    }
+
+    /// Sets how long an unused connection stays open before the pool closes it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use runique::prelude::DatabaseConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = DatabaseConfig::from_url("postgres://localhost/db")?
+    ///     .idle_timeout(Duration::from_secs(600))
+    ///     .build();
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.config.idle_timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum lifetime of a connection, busy or not.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use runique::prelude::DatabaseConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = DatabaseConfig::from_url("postgres://localhost/db")?
+    ///     .max_lifetime(Duration::from_secs(1800))
+    ///     .build();
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn max_lifetime(mut self, lifetime: Duration) -> Self {
+        self.config.max_lifetime = lifetime;
+        self
+    }
+
+    /// Logs every SQL statement and its duration at `debug` (target `sqlx::query`).
     ///
     /// # Examples
     ///
@@ -115,6 +169,52 @@ impl DatabaseConfigBuilder {
         self
     }
 
+    /// Logs statements slower than `threshold` at `warn`, with their duration.
+    ///
+    /// Independent of [`logging`](Self::logging): slow statements are reported
+    /// even when the others are not.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use runique::prelude::DatabaseConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = DatabaseConfig::from_url("postgres://localhost/db")?
+    ///     .slow_query_threshold(Duration::from_millis(200))
+    ///     .build();
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn slow_query_threshold(mut self, threshold: Duration) -> Self {
+        self.config.slow_query_threshold = Some(threshold);
+        self
+    }
+
+    /// Read replica for [`connect_routed`](DatabaseConfig::connect_routed):
+    /// `SELECT`s go there, everything else to the primary.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use runique::prelude::DatabaseConfig;
+    ///
+    /// let config = DatabaseConfig::from_url("postgres://primary/db")?
+    ///     .replica("postgres://replica/db")
+    ///     .build();
+    /// # Ok::<(), String>(())
+    /// ```
+    pub fn replica(mut self, url: impl Into<String>) -> Self {
+        self.config.replica_url = Some(url.into());
+        self
+    }
+
+    /// Whether reads fall back to the primary when the replica is unreachable
+    /// (default `true`). Off, the replica error is returned.
+    pub fn replica_fallback(mut self, enabled: bool) -> Self {
+        self.config.replica_fallback = enabled;
+        self
+    }
+
     /// Builds the final `DatabaseConfig`.
     ///
     /// # Examples
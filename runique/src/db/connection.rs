@@ -0,0 +1,66 @@
+//! `AsConnection` — what the `objects` managers and query builders run on.
+use super::{RoutedDb, Transaction};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use std::sync::Arc;
+
+/// A connection, a transaction, or a [`RoutedDb`] — anything a manager accepts
+/// as `db`, the app's `Arc<DatabaseConnection>` (`engine.db`) included.
+///
+/// ```rust,ignore
+/// Post::objects.all().all(&request.engine.db).await?; // Arc<DatabaseConnection>
+/// Post::objects.all().all(&*txn).await?;              // DatabaseTransaction
+/// Post::objects.all().all(&routed).await?;            // RoutedDb: read on the replica
+/// ```
+pub trait AsConnection: Sync {
+    type Connection: ConnectionTrait + TransactionTrait<Transaction = DatabaseTransaction>;
+
+    fn as_connection(&self) -> &Self::Connection;
+}
+
+impl AsConnection for DatabaseConnection {
+    type Connection = Self;
+
+    fn as_connection(&self) -> &Self {
+        self
+    }
+}
+
+impl AsConnection for DatabaseTransaction {
+    type Connection = Self;
+
+    fn as_connection(&self) -> &Self {
+        self
+    }
+}
+
+impl AsConnection for RoutedDb {
+    type Connection = Self;
+
+    fn as_connection(&self) -> &Self {
+        self
+    }
+}
+
+impl AsConnection for Transaction {
+    type Connection = DatabaseTransaction;
+
+    fn as_connection(&self) -> &DatabaseTransaction {
+        self
+    }
+}
+
+impl<T: AsConnection + Send> AsConnection for Arc<T> {
+    type Connection = T::Connection;
+
+    fn as_connection(&self) -> &T::Connection {
+        (**self).as_connection()
+    }
+}
+
+impl<T: AsConnection + ?Sized> AsConnection for &T {
+    type Connection = T::Connection;
+
+    fn as_connection(&self) -> &T::Connection {
+        (**self).as_connection()
+    }
+}
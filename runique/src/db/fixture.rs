@@ -0,0 +1,97 @@
+//! Fixture loading — inserts a JSON array of records into a SeaORM entity (Django `loaddata`).
+use std::path::{Path, PathBuf};
+
+use sea_orm::{
+    ActiveModelBehavior, ActiveModelTrait, DatabaseConnection, DbErr, EntityTrait, IntoActiveModel,
+    TransactionTrait, TryIntoModel,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+/// Failure of [`load_fixture`]. Database errors keep their original [`DbErr`]
+/// (reachable through `source()`), with the index of the failing record.
+#[derive(Debug, Error)]
+pub enum FixtureError {
+    #[error("fixture {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    /// Invalid JSON, not an array, or a record that does not match the entity.
+    #[error("fixture {}: {message}", path.display())]
+    Json { path: PathBuf, message: String },
+    /// Insertion of record `index` (0-based) failed.
+    #[error("fixture {}: row {index}: {source}", path.display())]
+    Row {
+        path: PathBuf,
+        index: usize,
+        #[source]
+        source: DbErr,
+    },
+    /// Opening or committing the transaction failed.
+    #[error(transparent)]
+    Db(#[from] DbErr),
+}
+
+/// Loads a JSON fixture (array of objects) into the table of entity `E`.
+///
+/// Each object becomes an `ActiveModel` through [`ActiveModelTrait::from_json`]:
+/// keys present in the object are `Set`, omitted keys stay `NotSet` so database
+/// defaults and auto-increment primary keys apply. All rows are inserted in a
+/// single transaction — any failing row rolls back the whole fixture.
+///
+/// Returns the number of inserted rows. A failing insert is reported as
+/// [`FixtureError::Row`], carrying the record index and the original `DbErr`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use runique::db::load_fixture;
+///
+/// let inserted = load_fixture::<blog::Entity>(&db, Path::new("fixtures/blog.json")).await?;
+/// ```
+pub async fn load_fixture<E>(db: &DatabaseConnection, path: &Path) -> Result<u64, FixtureError>
+where
+    E: EntityTrait,
+    E::Model: IntoActiveModel<E::ActiveModel> + for<'de> Deserialize<'de> + Serialize,
+    E::ActiveModel: ActiveModelBehavior + TryIntoModel<E::Model> + Send,
+{
+    let json_err = |message: String| FixtureError::Json {
+        path: path.to_path_buf(),
+        message,
+    };
+
+    let content = tokio::fs::read_to_string(path)
+        .await
+        .map_err(|source| FixtureError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+    let rows = match serde_json::from_str::<Value>(&content).map_err(|e| json_err(e.to_string()))? {
+        Value::Array(rows) => rows,
+        _ => return Err(json_err("expected a JSON array of objects".to_string())),
+    };
+
+    let txn = db.begin().await?;
+    let mut inserted = 0u64;
+
+    for (index, row) in rows.into_iter().enumerate() {
+        let active = E::ActiveModel::from_json(row)
+            .map_err(|e| json_err(format!("row {}: {}", index, e)))?;
+        active
+            .insert(&txn)
+            .await
+            .map_err(|source| FixtureError::Row {
+                path: path.to_path_buf(),
+                index,
+                source,
+            })?;
+        inserted += 1;
+    }
+
+    txn.commit().await?;
+    Ok(inserted)
+}
@@ -1,8 +1,28 @@
 //! Database configuration and connection — `DatabaseConfig` and SeaORM connection helper.
 pub mod builder;
 pub mod config;
+pub mod connection;
 pub mod engine;
+pub mod fixture;
+pub mod paginator;
+pub mod pool;
+pub mod raw;
+pub mod related;
+pub mod replica;
+pub mod timestamps;
+pub mod transaction;
 
 pub use builder::DatabaseConfigBuilder;
 pub use config::DatabaseConfig;
+pub use connection::AsConnection;
 pub use engine::DatabaseEngine;
+pub use fixture::{FixtureError, load_fixture};
+pub use paginator::{Page, Paginator};
+pub use pool::{PoolStats, pool_stats};
+pub use raw::{
+    raw_execute, raw_execute_named, raw_query, raw_query_named, raw_statement, raw_statement_named,
+};
+pub use related::{prefetch_related, select_related};
+pub use replica::{RoutedDb, on_primary};
+pub use timestamps::touch_timestamps;
+pub use transaction::{Transaction, with_transaction};
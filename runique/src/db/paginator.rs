@@ -0,0 +1,159 @@
+//! `Paginator` — page-by-page access to a SeaORM `Select<E>` with template-ready metadata.
+use sea_orm::{
+    ConnectionTrait, DbErr, EntityTrait, ModelTrait, PaginatorTrait, QuerySelect, Related, Select,
+};
+use serde::Serialize;
+
+/// One page of results plus the metadata a list template needs.
+///
+/// Serializable as a whole, so it can go straight into a Tera context:
+/// `context! { "page" => page }` then `{% for item in page.items %}` /
+/// `{% if page.has_next %}`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Page<M> {
+    pub items: Vec<M>,
+    /// 1-based page number that was requested.
+    pub current: u64,
+    pub per_page: u64,
+    pub total_pages: u64,
+    pub total_items: u64,
+    pub has_next: bool,
+    pub has_prev: bool,
+}
+
+impl<M> Page<M> {
+    pub fn next_page(&self) -> Option<u64> {
+        self.has_next.then(|| self.current + 1)
+    }
+
+    /// Page before `current`; past the end this is the last page, not `current - 1`.
+    pub fn prev_page(&self) -> Option<u64> {
+        self.has_prev
+            .then(|| (self.current - 1).min(self.total_pages))
+    }
+}
+
+impl<M: ModelTrait + Sync> Page<M> {
+    /// Same page, each item with its to-one related row — one batched query for
+    /// the page, see [`select_related`](crate::db::select_related).
+    pub async fn select_related<R>(
+        mut self,
+        db: &impl ConnectionTrait,
+        related: impl Into<Select<R>>,
+    ) -> Result<Page<(M, Option<R::Model>)>, DbErr>
+    where
+        M::Entity: Related<R>,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+    {
+        let items = std::mem::take(&mut self.items);
+        let items = crate::db::select_related(db, items, related).await?;
+        Ok(self.with_items(items))
+    }
+
+    /// Same page, each item with its to-many related rows — see
+    /// [`prefetch_related`](crate::db::prefetch_related).
+    pub async fn prefetch_related<R>(
+        mut self,
+        db: &impl ConnectionTrait,
+        related: impl Into<Select<R>>,
+    ) -> Result<Page<(M, Vec<R::Model>)>, DbErr>
+    where
+        M::Entity: Related<R>,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+    {
+        let items = std::mem::take(&mut self.items);
+        let items = crate::db::prefetch_related(db, items, related).await?;
+        Ok(self.with_items(items))
+    }
+
+    fn with_items<N>(self, items: Vec<N>) -> Page<N> {
+        Page {
+            items,
+            current: self.current,
+            per_page: self.per_page,
+            total_pages: self.total_pages,
+            total_items: self.total_items,
+            has_next: self.has_next,
+            has_prev: self.has_prev,
+        }
+    }
+}
+
+/// Splits a query into pages of `per_page` rows (1-based page numbers).
+///
+/// Each [`page`](Self::page) call issues a `COUNT` plus a `LIMIT/OFFSET` query.
+/// An empty result set or an out-of-range page number yields an empty
+/// `items` list with the real totals — never an error.
+///
+/// ```rust,ignore
+/// use runique::db::Paginator;
+///
+/// let page = Paginator::new(blog::Entity::find().order_by_desc(blog::Column::Id), 10)
+///     .page(&db, 2)
+///     .await?;
+/// ```
+pub struct Paginator<E: EntityTrait> {
+    select: Select<E>,
+    per_page: u64,
+    primary: bool,
+}
+
+impl<E: EntityTrait> Paginator<E> {
+    /// `per_page` is clamped to at least 1.
+    pub fn new(select: Select<E>, per_page: u64) -> Self {
+        Self {
+            select,
+            per_page: per_page.max(1),
+            primary: false,
+        }
+    }
+
+    /// Reads every page from the primary of a [`RoutedDb`](crate::db::RoutedDb).
+    pub fn force_primary(mut self) -> Self {
+        self.primary = true;
+        self
+    }
+
+    pub fn per_page(&self) -> u64 {
+        self.per_page
+    }
+
+    /// Accepts any connection, including a transaction.
+    pub async fn page(
+        &self,
+        db: &impl ConnectionTrait,
+        number: u64,
+    ) -> Result<Page<E::Model>, DbErr>
+    where
+        E::Model: Sync,
+    {
+        crate::db::replica::route(self.primary, async {
+            let total_items = self.select.clone().count(db).await?;
+            let total_pages = total_items.div_ceil(self.per_page);
+
+            let items = if number >= 1 && number <= total_pages {
+                self.select
+                    .clone()
+                    .limit(self.per_page)
+                    .offset((number - 1) * self.per_page)
+                    .all(db)
+                    .await?
+            } else {
+                Vec::new()
+            };
+
+            Ok(Page {
+                items,
+                current: number,
+                per_page: self.per_page,
+                total_pages,
+                total_items,
+                has_next: number < total_pages,
+                has_prev: number > 1 && total_pages > 0,
+            })
+        })
+        .await
+    }
+}
@@ -0,0 +1,48 @@
+//! Live connection pool statistics — `pool_stats`, reported by `/readyz`.
+use sea_orm::{DatabaseConnection, DatabaseConnectionType};
+use serde::Serialize;
+
+/// Snapshot of the connection pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PoolStats {
+    /// Open connections, idle or not.
+    pub size: u32,
+    pub idle: u32,
+    /// Connections currently checked out by a query or a transaction.
+    pub in_use: u32,
+}
+
+/// Current pool statistics — `None` for a connection without an sqlx pool
+/// (mock, proxy, or a backend whose feature is disabled).
+///
+/// ```rust,ignore
+/// if let Some(stats) = runique::db::pool_stats(&engine.db) {
+///     tracing::info!(size = stats.size, in_use = stats.in_use, "db pool");
+/// }
+/// ```
+pub fn pool_stats(db: &DatabaseConnection) -> Option<PoolStats> {
+    let (size, idle) = match &db.inner {
+        #[cfg(feature = "postgres")]
+        DatabaseConnectionType::SqlxPostgresPoolConnection(_) => {
+            let pool = db.get_postgres_connection_pool();
+            (pool.size(), pool.num_idle())
+        }
+        #[cfg(feature = "mysql")]
+        DatabaseConnectionType::SqlxMySqlPoolConnection(_) => {
+            let pool = db.get_mysql_connection_pool();
+            (pool.size(), pool.num_idle())
+        }
+        #[cfg(feature = "sqlite")]
+        DatabaseConnectionType::SqlxSqlitePoolConnection(_) => {
+            let pool = db.get_sqlite_connection_pool();
+            (pool.size(), pool.num_idle())
+        }
+        _ => return None,
+    };
+    let idle = u32::try_from(idle).unwrap_or(u32::MAX).min(size);
+    Some(PoolStats {
+        size,
+        idle,
+        in_use: size - idle,
+    })
+}
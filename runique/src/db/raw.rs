@@ -0,0 +1,241 @@
+//! Raw SQL with bound parameters — [`raw_query`], [`raw_execute`] and their
+//! `_named` variants, for the queries the ORM can't express.
+//!
+//! The SQL text is a `&'static str`: a literal or a `const`, never the `String`
+//! of a `format!`. Values only reach the database as bound parameters, so they
+//! can't be read as SQL. Placeholders are `?` on every backend (rewritten to
+//! `$1, $2…` for PostgreSQL), or `:name` with the `_named` variants. A `?` or
+//! `:name` inside a quoted string, a quoted identifier or a comment is left as is.
+//!
+//! ```compile_fail
+//! # async fn f(db: &sea_orm::DatabaseConnection, name: &str) {
+//! // Rejected at compile time: the SQL must be a literal
+//! let sql = format!("DELETE FROM users WHERE name = '{name}'");
+//! runique::db::raw_execute(db, &sql, []).await;
+//! # }
+//! ```
+use super::AsConnection;
+use sea_orm::{ConnectionTrait, DbBackend, DbErr, FromQueryResult, Statement, Value};
+
+/// Runs a `SELECT` and maps each row into `T`.
+///
+/// ```rust,ignore
+/// use runique::db::raw_query;
+/// use sea_orm::FromQueryResult;
+///
+/// #[derive(FromQueryResult)]
+/// struct AuthorStats { author_id: i32, posts: i64 }
+///
+/// let stats: Vec<AuthorStats> = raw_query(
+///     &db,
+///     "SELECT author_id, COUNT(*) AS posts FROM post WHERE published_at > ? GROUP BY author_id",
+///     [since.into()],
+/// )
+/// .await?;
+/// ```
+pub async fn raw_query<T: FromQueryResult>(
+    db: &impl AsConnection,
+    sql: &'static str,
+    params: impl IntoIterator<Item = Value>,
+) -> Result<Vec<T>, DbErr> {
+    let db = db.as_connection();
+    let stmt = raw_statement(db.get_database_backend(), sql, params)?;
+    T::find_by_statement(stmt).all(db).await
+}
+
+/// Runs an `INSERT` / `UPDATE` / `DELETE` (or DDL) and returns the number of
+/// rows affected.
+///
+/// ```rust,ignore
+/// let archived = raw_execute(&db, "UPDATE post SET archived = ? WHERE updated_at < ?", [
+///     true.into(),
+///     cutoff.into(),
+/// ])
+/// .await?;
+/// ```
+pub async fn raw_execute(
+    db: &impl AsConnection,
+    sql: &'static str,
+    params: impl IntoIterator<Item = Value>,
+) -> Result<u64, DbErr> {
+    let db = db.as_connection();
+    let stmt = raw_statement(db.get_database_backend(), sql, params)?;
+    Ok(db.execute_raw(stmt).await?.rows_affected())
+}
+
+/// [`raw_query`] with `:name` placeholders; a name may appear several times.
+///
+/// ```rust,ignore
+/// let rows: Vec<Hit> = raw_query_named(
+///     &db,
+///     "SELECT id, title FROM post WHERE title LIKE :q OR body LIKE :q",
+///     [("q", format!("%{term}%").into())],
+/// )
+/// .await?;
+/// ```
+pub async fn raw_query_named<T: FromQueryResult>(
+    db: &impl AsConnection,
+    sql: &'static str,
+    params: impl IntoIterator<Item = (&'static str, Value)>,
+) -> Result<Vec<T>, DbErr> {
+    let db = db.as_connection();
+    let stmt = raw_statement_named(db.get_database_backend(), sql, params)?;
+    T::find_by_statement(stmt).all(db).await
+}
+
+/// [`raw_execute`] with `:name` placeholders.
+pub async fn raw_execute_named(
+    db: &impl AsConnection,
+    sql: &'static str,
+    params: impl IntoIterator<Item = (&'static str, Value)>,
+) -> Result<u64, DbErr> {
+    let db = db.as_connection();
+    let stmt = raw_statement_named(db.get_database_backend(), sql, params)?;
+    Ok(db.execute_raw(stmt).await?.rows_affected())
+}
+
+/// Builds the bound statement run by [`raw_query`] / [`raw_execute`] — for
+/// SeaORM APIs taking a `Statement`. Errors when the number of `?` differs
+/// from the number of values.
+pub fn raw_statement(
+    backend: DbBackend,
+    sql: &'static str,
+    params: impl IntoIterator<Item = Value>,
+) -> Result<Statement, DbErr> {
+    let values: Vec<Value> = params.into_iter().collect();
+    let mut count = 0;
+    let sql = rewrite(backend, sql, |placeholder| match placeholder {
+        Placeholder::Positional => {
+            count += 1;
+            Ok(())
+        }
+        Placeholder::Named(name) => Err(format!(
+            "`:{name}` in a positional query, use the `_named` variant"
+        )),
+    })?;
+    if count != values.len() {
+        return Err(DbErr::Custom(format!(
+            "raw SQL has {count} placeholder(s) but {} value(s) were given",
+            values.len()
+        )));
+    }
+    Ok(Statement::from_sql_and_values(backend, sql, values))
+}
+
+/// [`raw_statement`] for `:name` placeholders. Errors on a name without a
+/// value, a value whose name is not in the SQL, or a `?`.
+pub fn raw_statement_named(
+    backend: DbBackend,
+    sql: &'static str,
+    params: impl IntoIterator<Item = (&'static str, Value)>,
+) -> Result<Statement, DbErr> {
+    let params: Vec<(&str, Value)> = params.into_iter().collect();
+    let mut used = vec![false; params.len()];
+    let mut values = Vec::new();
+    let sql = rewrite(backend, sql, |placeholder| match placeholder {
+        Placeholder::Named(name) => {
+            let i = params
+                .iter()
+                .position(|(n, _)| *n == name)
+                .ok_or_else(|| format!("no value given for `:{name}`"))?;
+            used[i] = true;
+            values.push(params[i].1.clone());
+            Ok(())
+        }
+        Placeholder::Positional => Err("`?` in a named query, use `:name`".to_string()),
+    })?;
+    if let Some(i) = used.iter().position(|u| !u) {
+        return Err(DbErr::Custom(format!(
+            "`:{}` is not used by the raw SQL",
+            params[i].0
+        )));
+    }
+    Ok(Statement::from_sql_and_values(backend, sql, values))
+}
+
+enum Placeholder<'a> {
+    Positional,
+    Named(&'a str),
+}
+
+/// Copies `sql`, replacing each placeholder outside quotes and comments by the
+/// backend's bind marker and reporting it to `on_placeholder`.
+fn rewrite<'a>(
+    backend: DbBackend,
+    sql: &'a str,
+    mut on_placeholder: impl FnMut(Placeholder<'a>) -> Result<(), String>,
+) -> Result<String, DbErr> {
+    let bytes = sql.as_bytes();
+    let mut out = String::with_capacity(sql.len() + 8);
+    let mut bound = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        match bytes[i] {
+            // Quoted text runs to the closing quote; a doubled quote escapes it
+            quote @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                while i < bytes.len() {
+                    i += 1;
+                    if bytes[i - 1] == quote {
+                        if bytes.get(i) == Some(&quote) {
+                            i += 1;
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = sql[i..].find('\n').map_or(bytes.len(), |n| i + n);
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = sql[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |n| i + 2 + n + 2);
+            }
+            b'?' => {
+                on_placeholder(Placeholder::Positional).map_err(DbErr::Custom)?;
+                bound += 1;
+                push_marker(&mut out, backend, bound);
+                i += 1;
+                continue;
+            }
+            // `:name`, but not a `::type` cast
+            b':' if i == 0 || bytes[i - 1] != b':' => {
+                let len = bytes[i + 1..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_alphanumeric() || **b == b'_')
+                    .count();
+                let starts_ident = bytes
+                    .get(i + 1)
+                    .is_some_and(|b| b.is_ascii_alphabetic() || *b == b'_');
+                if starts_ident {
+                    on_placeholder(Placeholder::Named(&sql[i + 1..i + 1 + len]))
+                        .map_err(DbErr::Custom)?;
+                    bound += 1;
+                    push_marker(&mut out, backend, bound);
+                    i += 1 + len;
+                    continue;
+                }
+                i += 1;
+            }
+            _ => {
+                // Whole UTF-8 characters
+                i += sql[i..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        out.push_str(&sql[start..i]);
+    }
+    Ok(out)
+}
+
+fn push_marker(out: &mut String, backend: DbBackend, n: usize) {
+    match backend {
+        DbBackend::Postgres => {
+            out.push('$');
+            out.push_str(&n.to_string());
+        }
+        _ => out.push('?'),
+    }
+}
@@ -0,0 +1,66 @@
+//! Eager loading — `select_related` / `prefetch_related` avoid N+1 queries on lists.
+//!
+//! Both run one batched `WHERE key IN (…)` query for the whole list of parents
+//! (SeaORM's loader) and zip the results back in parent order. The related side
+//! is any query on the related entity: pass `Author::objects.all()` to keep its
+//! soft-delete filter, or `author::Entity::find()` for every row.
+use sea_orm::{ConnectionTrait, DbErr, EntityTrait, LoaderTrait, ModelTrait, Related, Select};
+
+/// To-one relation (`belongs_to`, `has_one`): each parent with its related row,
+/// `None` when the key is `NULL` or the row is filtered out.
+///
+/// ```rust,ignore
+/// use runique::db::select_related;
+///
+/// let articles = article::Entity::objects.all().desc(article::Column::Id).all(&db).await?;
+/// let rows = select_related(&db, articles, author::Entity::objects.all()).await?;
+/// for (article, author) in rows { /* … */ }
+/// ```
+pub async fn select_related<M, R>(
+    db: &impl ConnectionTrait,
+    parents: Vec<M>,
+    related: impl Into<Select<R>>,
+) -> Result<Vec<(M, Option<R::Model>)>, DbErr>
+where
+    M: ModelTrait + Sync,
+    M::Entity: Related<R>,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+{
+    if parents.is_empty() {
+        return Ok(Vec::new());
+    }
+    let loaded = parents.load_one(related.into(), db).await?;
+    Ok(parents.into_iter().zip(loaded).collect())
+}
+
+/// To-many relation (`has_many`, many-to-many through a `via` entity): each
+/// parent with its related rows, in the order of the `related` query.
+///
+/// ```rust,ignore
+/// use runique::db::prefetch_related;
+///
+/// let rows = prefetch_related(
+///     &db,
+///     articles,
+///     comment::Entity::objects.all().asc(comment::Column::CreatedAt),
+/// )
+/// .await?;
+/// ```
+pub async fn prefetch_related<M, R>(
+    db: &impl ConnectionTrait,
+    parents: Vec<M>,
+    related: impl Into<Select<R>>,
+) -> Result<Vec<(M, Vec<R::Model>)>, DbErr>
+where
+    M: ModelTrait + Sync,
+    M::Entity: Related<R>,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+{
+    if parents.is_empty() {
+        return Ok(Vec::new());
+    }
+    let loaded = parents.load_many(related.into(), db).await?;
+    Ok(parents.into_iter().zip(loaded).collect())
+}
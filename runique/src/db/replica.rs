@@ -0,0 +1,203 @@
+//! Read replica routing — [`RoutedDb`] sends `SELECT`s to a replica and every
+//! other statement to the primary.
+//!
+//! Built by [`DatabaseConfig::connect_routed`](super::DatabaseConfig::connect_routed)
+//! from `DB_REPLICA_URL` / `.replica(url)`. `RoutedDb` implements `ConnectionTrait`
+//! and `TransactionTrait`: managers, paginators and SeaORM queries take it in place
+//! of a `DatabaseConnection`. A transaction always begins on the primary, so every
+//! statement run through it — reads included — sees its own writes.
+use sea_orm::{
+    AccessMode, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr,
+    ExecResult, IsolationLevel, QueryResult, Statement, TransactionError, TransactionOptions,
+    TransactionTrait,
+};
+use std::{future::Future, pin::Pin};
+
+tokio::task_local! {
+    static FORCE_PRIMARY: ();
+}
+
+/// Primary connection plus an optional read replica.
+///
+/// ```rust,ignore
+/// let db = DatabaseConfig::from_env()?.build().connect_routed().await?;
+///
+/// let posts = Post::objects.all().all(&db).await?;           // replica
+/// post.into_active_model().update(&db).await?;               // primary
+/// let fresh = Post::objects.force_primary().all(&db).await?; // primary
+/// ```
+#[derive(Debug, Clone)]
+pub struct RoutedDb {
+    primary: DatabaseConnection,
+    replica: Option<DatabaseConnection>,
+    fallback: bool,
+}
+
+impl RoutedDb {
+    /// Routes reads to `replica` when there is one, falling back to the primary
+    /// if the replica can't be reached.
+    pub fn new(primary: DatabaseConnection, replica: Option<DatabaseConnection>) -> Self {
+        Self {
+            primary,
+            replica,
+            fallback: true,
+        }
+    }
+
+    /// `false`: a read failing to reach the replica returns its error instead of
+    /// running on the primary.
+    pub fn fallback(mut self, enabled: bool) -> Self {
+        self.fallback = enabled;
+        self
+    }
+
+    pub fn primary(&self) -> &DatabaseConnection {
+        &self.primary
+    }
+
+    pub fn replica(&self) -> Option<&DatabaseConnection> {
+        self.replica.as_ref()
+    }
+
+    /// Connection a read of `sql` goes to, `None` for the primary.
+    fn reader(&self, sql: &str) -> Option<&DatabaseConnection> {
+        if FORCE_PRIMARY.try_with(|_| ()).is_ok() || !is_read(sql) {
+            return None;
+        }
+        self.replica.as_ref()
+    }
+
+    /// Whether a replica error is retried on the primary.
+    fn falls_back(&self, err: &DbErr) -> bool {
+        let unreachable = matches!(err, DbErr::Conn(_) | DbErr::ConnectionAcquire(_));
+        if unreachable && self.fallback {
+            tracing::warn!(error = %err, "read replica unreachable, reading from the primary");
+        }
+        unreachable && self.fallback
+    }
+}
+
+/// Runs `fut` with every [`RoutedDb`] read sent to the primary — for reads that
+/// must see a write made just before, outside a transaction.
+///
+/// ```rust,ignore
+/// use runique::db::on_primary;
+///
+/// let order = on_primary(order::Entity::find_by_id(id).one(&db)).await?;
+/// ```
+pub async fn on_primary<F: Future>(fut: F) -> F::Output {
+    FORCE_PRIMARY.scope((), fut).await
+}
+
+/// `on_primary` when `primary` is set, `fut` as is otherwise.
+pub(crate) async fn route<F: Future>(primary: bool, fut: F) -> F::Output {
+    if primary {
+        on_primary(fut).await
+    } else {
+        fut.await
+    }
+}
+
+/// A plain `SELECT` — `INSERT … RETURNING` and friends also come through the
+/// query methods and must stay on the primary.
+fn is_read(sql: &str) -> bool {
+    sql.trim_start()
+        .get(..6)
+        .is_some_and(|keyword| keyword.eq_ignore_ascii_case("select"))
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for RoutedDb {
+    fn get_database_backend(&self) -> DbBackend {
+        self.primary.get_database_backend()
+    }
+
+    async fn execute_raw(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.primary.execute_raw(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.primary.execute_unprepared(sql).await
+    }
+
+    async fn query_one_raw(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        if let Some(replica) = self.reader(&stmt.sql) {
+            match replica.query_one_raw(stmt.clone()).await {
+                Err(e) if self.falls_back(&e) => {}
+                result => return result,
+            }
+        }
+        self.primary.query_one_raw(stmt).await
+    }
+
+    async fn query_all_raw(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        if let Some(replica) = self.reader(&stmt.sql) {
+            match replica.query_all_raw(stmt.clone()).await {
+                Err(e) if self.falls_back(&e) => {}
+                result => return result,
+            }
+        }
+        self.primary.query_all_raw(stmt).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.primary.support_returning()
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for RoutedDb {
+    type Transaction = DatabaseTransaction;
+
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.primary.begin().await
+    }
+
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.primary
+            .begin_with_config(isolation_level, access_mode)
+            .await
+    }
+
+    async fn begin_with_options(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.primary.begin_with_options(options).await
+    }
+
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        self.primary.transaction(callback).await
+    }
+
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        self.primary
+            .transaction_with_config(callback, isolation_level, access_mode)
+            .await
+    }
+}
@@ -0,0 +1,56 @@
+//! `auto_now` / `auto_now_update` timestamps — filled at save time by the
+//! `ActiveModelBehavior` of [`impl_signals!`](crate::impl_signals).
+use chrono::Utc;
+use sea_orm::sea_query::ColumnType;
+use sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, DbErr, EntityTrait, IdenStatic, Value};
+
+type Column<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::Column;
+
+/// Stamps the timestamp columns of `active` before it is written:
+///
+/// - `auto_now` columns on insert only,
+/// - `auto_now_update` columns on every save (insert included).
+///
+/// A column the caller explicitly `Set` is left untouched, so an import can
+/// keep its historical `created_at`, or an update can opt out of bumping
+/// `updated_at` by setting it to its current value.
+///
+/// The value follows the column type: `TimestampWithTimeZone` gets a UTC
+/// `DateTime<FixedOffset>` (or `DateTime<Utc>`), `DateTime` / `Timestamp` a
+/// naive UTC `NaiveDateTime`, `Date` today's UTC date.
+pub fn touch_timestamps<A: ActiveModelTrait>(
+    active: &mut A,
+    insert: bool,
+    auto_now: &[Column<A>],
+    auto_now_update: &[Column<A>],
+) -> Result<(), DbErr> {
+    let now = Utc::now();
+    let created: &[Column<A>] = if insert { auto_now } else { &[] };
+
+    for &col in created.iter().chain(auto_now_update) {
+        if matches!(active.get(col), ActiveValue::Set(_)) {
+            continue;
+        }
+        let candidates: Vec<Value> = match col.def().get_column_type() {
+            ColumnType::TimestampWithTimeZone => vec![now.fixed_offset().into(), now.into()],
+            ColumnType::DateTime | ColumnType::Timestamp => vec![now.naive_utc().into()],
+            ColumnType::Date => vec![now.date_naive().into()],
+            other => {
+                return Err(DbErr::Type(format!(
+                    "auto_now column `{}` is not a date/time column ({other:?})",
+                    col.as_str()
+                )));
+            }
+        };
+        // The Rust field type decides which candidate fits; keep the last error.
+        let mut result = Ok(());
+        for value in candidates {
+            result = active.try_set(col, value);
+            if result.is_ok() {
+                break;
+            }
+        }
+        result?;
+    }
+    Ok(())
+}
@@ -0,0 +1,203 @@
+//! Request-scoped transactions — the [`Transaction`] extractor and [`with_transaction`].
+//!
+//! The extractor begins a transaction on the app's connection the first time a
+//! handler asks for one. Once the handler has returned, the transaction middleware
+//! (slot 75, right before the handler) commits it on a `< 400` response and rolls
+//! it back otherwise — unless the handler forced the outcome.
+use super::AsConnection;
+use crate::utils::aliases::ADb;
+use axum::{
+    body::Body,
+    extract::{FromRequestParts, State},
+    http::{Request, StatusCode, request::Parts},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sea_orm::{DatabaseTransaction, DbErr, TransactionTrait};
+use std::{
+    future::Future,
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
+
+/// Outcome forced by [`Transaction::force_commit`] / [`Transaction::force_rollback`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    /// Decided by the result: `Ok` / `< 400` commits.
+    Auto,
+    Commit,
+    Rollback,
+}
+
+/// Database transaction shared by a handler (or a [`with_transaction`] closure).
+///
+/// Dereferences to SeaORM's `DatabaseTransaction`: pass `&*txn` wherever a
+/// connection is expected. Cloning is cheap — every clone is the same transaction.
+///
+/// ```rust,ignore
+/// use runique::db::Transaction;
+///
+/// async fn transfer(txn: Transaction, Form(f): Form<TransferForm>) -> AppResult<Response> {
+///     account::debit(&*txn, f.from, f.amount).await?;
+///     account::credit(&*txn, f.to, f.amount).await?;
+///     if f.dry_run {
+///         txn.force_rollback(); // 200, nothing written
+///     }
+///     Ok(StatusCode::OK.into_response())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Transaction {
+    txn: Arc<DatabaseTransaction>,
+    outcome: Arc<Mutex<Outcome>>,
+}
+
+impl Transaction {
+    /// Begins a transaction on `db` — on the primary for a [`RoutedDb`](super::RoutedDb).
+    pub async fn begin(db: &impl AsConnection) -> Result<Self, DbErr> {
+        Ok(Self {
+            txn: Arc::new(db.as_connection().begin().await?),
+            outcome: Arc::new(Mutex::new(Outcome::Auto)),
+        })
+    }
+
+    /// Rolls back whatever the handler returns — a `200` included.
+    pub fn force_rollback(&self) {
+        self.set_outcome(Outcome::Rollback);
+    }
+
+    /// Commits whatever the handler returns — an error response included.
+    pub fn force_commit(&self) {
+        self.set_outcome(Outcome::Commit);
+    }
+
+    fn set_outcome(&self, outcome: Outcome) {
+        *self.outcome.lock().unwrap_or_else(|e| e.into_inner()) = outcome;
+    }
+
+    /// Commits when `success` (or a forced commit), rolls back otherwise.
+    ///
+    /// A clone still alive elsewhere (a spawned task…) keeps the transaction
+    /// open: it is rolled back when that last clone drops, and this is an error.
+    pub async fn finish(self, success: bool) -> Result<(), DbErr> {
+        let commit = match *self.outcome.lock().unwrap_or_else(|e| e.into_inner()) {
+            Outcome::Auto => success,
+            Outcome::Commit => true,
+            Outcome::Rollback => false,
+        };
+        let txn = Arc::try_unwrap(self.txn).map_err(|_| {
+            DbErr::Custom("transaction still in use: rolled back when its last clone drops".into())
+        })?;
+        if commit {
+            txn.commit().await
+        } else {
+            txn.rollback().await
+        }
+    }
+}
+
+impl Deref for Transaction {
+    type Target = DatabaseTransaction;
+
+    fn deref(&self) -> &DatabaseTransaction {
+        &self.txn
+    }
+}
+
+/// Runs `f` in a transaction: committed when it returns `Ok`, rolled back on `Err`.
+///
+/// For code outside a handler (tasks, commands, signals). A commit failure is
+/// returned as the error; on `Err`, a rollback failure is only logged.
+///
+/// ```rust,ignore
+/// let order = with_transaction(&db, |txn| async move {
+///     let order = order::ActiveModel { .. }.insert(&*txn).await?;
+///     stock::reserve(&*txn, order.id).await?;
+///     Ok::<_, DbErr>(order)
+/// })
+/// .await?;
+/// ```
+pub async fn with_transaction<F, Fut, T, E>(db: &impl AsConnection, f: F) -> Result<T, E>
+where
+    F: FnOnce(Transaction) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: From<DbErr>,
+{
+    let txn = Transaction::begin(db).await?;
+    let result = f(txn.clone()).await;
+    match (txn.finish(result.is_ok()).await, result) {
+        (Ok(()), result) => result,
+        (Err(e), Ok(_)) => Err(e.into()),
+        (Err(e), Err(err)) => {
+            tracing::warn!(error = %e, "transaction rollback failed");
+            Err(err)
+        }
+    }
+}
+
+/// Per-request slot filled by the first [`Transaction`] extraction.
+#[derive(Clone)]
+struct TransactionSlot {
+    db: ADb,
+    txn: Arc<tokio::sync::Mutex<Option<Transaction>>>,
+}
+
+/// Slot 75 — settles the request's transaction once the handler has returned.
+///
+/// A failed commit turns the response into a `500`: the client must not see a
+/// success for data that was not written.
+pub(crate) async fn transaction_middleware(
+    State(db): State<ADb>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
+    let slot = TransactionSlot {
+        db,
+        txn: Arc::new(tokio::sync::Mutex::new(None)),
+    };
+    req.extensions_mut().insert(slot.clone());
+    let response = next.run(req).await;
+
+    let Some(txn) = slot.txn.lock().await.take() else {
+        return response;
+    };
+    let status = response.status();
+    let success = !(status.is_client_error() || status.is_server_error());
+    match txn.finish(success).await {
+        Ok(()) => response,
+        Err(e) => {
+            tracing::error!(error = %e, %status, "request transaction not settled");
+            if success {
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            } else {
+                response
+            }
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for Transaction
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let err = |msg: String| (StatusCode::INTERNAL_SERVER_ERROR, msg).into_response();
+
+        let slot = parts
+            .extensions
+            .get::<TransactionSlot>()
+            .cloned()
+            .ok_or_else(|| err("transaction middleware missing".to_string()))?;
+        let mut current = slot.txn.lock().await;
+        if let Some(txn) = current.as_ref() {
+            return Ok(txn.clone());
+        }
+        let txn = Transaction::begin(&slot.db)
+            .await
+            .map_err(|e| err(format!("cannot begin transaction: {e}")))?;
+        *current = Some(txn.clone());
+        Ok(txn)
+    }
+}
@@ -1,8 +1,11 @@
 //! `RuniqueEngine` implementation — construction, middleware attachment, store access.
+use crate::app::LiveTera;
+use crate::cache::CacheHandle;
 use crate::middleware::session::{CleaningMemoryStore, session_db::RuniqueSessionStore};
-use crate::utils::aliases::{
-    ADb, ARlockmap, ASecurityCsp, ASecurityHosts, ATera, new, new_registry,
-};
+#[cfg(feature = "orm")]
+use crate::tasks::{SchedulerHandle, TaskQueue};
+use crate::utils::aliases::{ADb, ARlockmap, ASecurityCsp, ASecurityHosts, new, new_registry};
+use crate::utils::trad::Catalogs;
 use axum::{Router, middleware};
 use std::any::TypeId;
 use std::collections::HashMap;
@@ -12,9 +15,9 @@ use tera::Tera;
 use crate::config::RuniqueConfig;
 // Import our newly renamed structures
 use crate::middleware::{
-    HostPolicy, MiddlewareConfig, PermissionsPolicy, SecurityPolicy, TrustedProxies,
-    allowed_hosts_middleware, csrf_middleware, dev_no_cache_middleware, error_handler_middleware,
-    https_redirect_middleware, security_headers_middleware,
+    HostPolicy, MiddlewareConfig, PermissionsPolicy, SecurityHeaders, SecurityPolicy,
+    TrustedProxies, allowed_hosts_middleware, csrf_middleware, dev_no_cache_middleware,
+    error_handler_middleware, https_redirect_middleware, security_headers_middleware,
 };
 
 #[cfg(feature = "orm")]
@@ -25,13 +28,23 @@ use sea_orm::DatabaseConnection;
 pub struct RuniqueEngine {
     /// General application configuration.
     pub config: RuniqueConfig,
-    /// Shared Tera instance for read/write.
-    pub tera: ATera,
+    /// Shared Tera instance — [`LiveTera::current`] to render; swapped on template autoreload.
+    pub tera: LiveTera,
     #[cfg(feature = "orm")]
     /// Database connection (feature `orm`).
     pub db: ADb,
+    #[cfg(feature = "orm")]
+    /// Background task queue — disabled unless the builder started workers (`with_worker`).
+    pub tasks: TaskQueue,
+    #[cfg(feature = "orm")]
+    /// Periodic jobs declared with `with_schedule` — stopped on shutdown.
+    pub scheduler: SchedulerHandle,
+    /// Application cache — in memory unless the builder set another backend (`with_cache`).
+    pub cache: CacheHandle,
     /// Global registry of named routes (reverse URL).
     pub url_registry: ARlockmap,
+    /// Project translation catalogs (`LOCALE_DIR`) — `{% trans %}` and views.
+    pub i18n: Arc<Catalogs>,
     /// Middleware toggles (cache, CSP, CSRF, etc.).
     pub features: MiddlewareConfig,
     /// Active Content Security Policy.
@@ -40,8 +53,12 @@ pub struct RuniqueEngine {
     pub security_hosts: ASecurityHosts,
     /// Paths exempt from CSRF validation (ex: webhook endpoints).
     pub csrf_exempt_paths: Arc<Vec<String>>,
+    /// Cross-site origins allowed to open a WebSocket — the CORS origins, `*` excluded.
+    pub trusted_origins: Arc<Vec<String>>,
     /// Active Permissions-Policy header configuration.
     pub permissions_policy: Arc<PermissionsPolicy>,
+    /// HSTS, Referrer-Policy and Permissions-Policy toggles of the security headers.
+    pub security_headers: Arc<SecurityHeaders>,
     /// Trusted proxy IPs/CIDRs for real client IP extraction.
     pub trusted_proxies: Arc<TrustedProxies>,
     /// Memory store — anonymous sessions + CSRF.
@@ -61,17 +78,24 @@ impl RuniqueEngine {
         let features = MiddlewareConfig::from_env();
         let security_csp = SecurityPolicy::default();
         let security_hosts = HostPolicy::default();
+        let i18n = Arc::new(Catalogs::new(config.i18n.default_locale.clone()));
 
         Self {
             config,
-            tera: new(tera),
+            tera: LiveTera::new(tera),
             db: new(db),
+            tasks: TaskQueue::default(),
+            scheduler: SchedulerHandle::default(),
+            cache: CacheHandle::default(),
             url_registry: new_registry(),
+            i18n,
             features,
             security_csp: new(security_csp),
             security_hosts: new(security_hosts),
             csrf_exempt_paths: Arc::new(vec![]),
+            trusted_origins: Arc::new(vec![]),
             permissions_policy: Arc::new(PermissionsPolicy::default()),
+            security_headers: Arc::new(SecurityHeaders::default()),
             trusted_proxies: Arc::new(TrustedProxies::default()),
             session_store: LazyLock::new(|| RwLock::new(None)),
             session_db_store: LazyLock::new(|| RwLock::new(None)),
@@ -1,7 +1,10 @@
 //! `Message` — Axum extractor to read/write flash messages in session.
-use crate::flash::flash_struct::FlashMessage;
+use crate::flash::flash_struct::{FlashConfig, FlashMessage};
 use crate::utils::config::TraceResult;
-use crate::utils::{aliases::Messages, constante::session_key::session::FLASH_KEY};
+use crate::utils::{
+    aliases::{AEngine, Messages},
+    constante::session_key::session::FLASH_KEY,
+};
 use axum::extract::FromRequestParts;
 use axum::http::{StatusCode, request::Parts};
 use tower_sessions::Session;
@@ -9,6 +12,15 @@ use tower_sessions::Session;
 #[derive(Clone, Debug)]
 pub struct Message {
     pub session: Session,
+    /// Queue policy (cap, de-duplication) — `config.flash` of the engine.
+    pub config: FlashConfig,
+}
+
+impl Message {
+    /// Flash manager of `session` with the engine's queue policy.
+    pub fn new(session: Session, config: FlashConfig) -> Self {
+        Self { session, config }
+    }
 }
 
 impl<S> FromRequestParts<S> for Message
@@ -23,8 +35,13 @@ where
             .get::<Session>()
             .cloned()
             .ok_or(StatusCode::INTERNAL_SERVER_ERROR)?;
+        let config = parts
+            .extensions
+            .get::<AEngine>()
+            .map(|engine| engine.config.flash)
+            .unwrap_or_default();
 
-        Ok(Self { session })
+        Ok(Self { session, config })
     }
 }
 
@@ -38,7 +55,7 @@ impl Message {
             .flatten()
             .unwrap_or_default();
 
-        messages.push(msg);
+        self.config.enqueue(&mut messages, msg);
         self.session.insert(FLASH_KEY, messages).await.trace(
             crate::utils::runique_log::get_log()
                 .session
@@ -1,15 +1,33 @@
-//! Flash message structs — `FlashMessage` and `MessageLevel` with CSS mapping.
+//! Flash message structs — `FlashMessage`, `MessageLevel` with CSS mapping, and `FlashConfig`.
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Severity of a flash message — serialized lowercase (`"success"`), so a template
+/// can build its class directly: `class="alert alert-{{ message.level }}"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum MessageLevel {
+    // Aliases: messages queued in a session before the lowercase format
+    #[serde(alias = "Success")]
     Success,
+    #[serde(alias = "Error")]
     Error,
+    #[serde(alias = "Info")]
     Info,
+    #[serde(alias = "Warning")]
     Warning,
 }
 
 impl MessageLevel {
+    /// Serialized name of the level: `success`, `error`, `info`, `warning`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageLevel::Success => "success",
+            MessageLevel::Error => "error",
+            MessageLevel::Info => "info",
+            MessageLevel::Warning => "warning",
+        }
+    }
+
     /// Returns the static CSS class associated with the message level.
     /// Used in HTML rendering.
     pub fn as_css_class(&self) -> &'static str {
@@ -21,7 +39,7 @@ impl MessageLevel {
         }
     }
 }
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlashMessage {
     pub content: String,
     pub level: MessageLevel,
@@ -60,3 +78,44 @@ impl FlashMessage {
         }
     }
 }
+
+/// Queue policy of [`Message`](crate::flash::Message), set with `.with_flash(|f| ...)`.
+/// Default: unlimited queue, duplicates kept.
+///
+/// ```rust,ignore
+/// RuniqueApp::builder(config).with_flash(|f| f.max_messages(5).deduplicate(true))
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlashConfig {
+    /// Maximum of queued messages — the oldest are dropped beyond it.
+    pub max_messages: Option<usize>,
+    /// Skips a message identical (level and content) to the last queued one.
+    pub deduplicate: bool,
+}
+
+impl FlashConfig {
+    /// Caps the queue at `max` messages, dropping the oldest.
+    pub fn max_messages(mut self, max: usize) -> Self {
+        self.max_messages = Some(max);
+        self
+    }
+
+    /// Ignores a message identical to the one queued just before.
+    pub fn deduplicate(mut self, enable: bool) -> Self {
+        self.deduplicate = enable;
+        self
+    }
+
+    /// Adds `msg` to `queue` according to the policy.
+    pub fn enqueue(&self, queue: &mut Vec<FlashMessage>, msg: FlashMessage) {
+        if self.deduplicate && queue.last() == Some(&msg) {
+            return;
+        }
+        queue.push(msg);
+        if let Some(max) = self.max_messages
+            && queue.len() > max
+        {
+            queue.drain(..queue.len() - max);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-//! Flash messages — session storage, levels (success/error/info/warning), Axum extractor.
+//! Flash messages — session storage, levels (success/error/info/warning), queue policy, Axum extractor.
 pub mod flash_manager;
 pub mod flash_struct;
 
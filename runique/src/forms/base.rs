@@ -19,6 +19,18 @@ pub struct FieldConfig {
     pub html_attributes: StrMap,
     pub template_name: String,
     pub extra_context: JsonMap,
+    /// Database uniqueness lookup, run by the async validation pass only.
+    #[serde(skip)]
+    pub unique_check: Option<UniqueCheck>,
+    /// Deferred options, loaded by `Forms::prepare`.
+    #[serde(skip)]
+    pub choice_source: Option<ChoiceSource>,
+    /// Id → row lookup, run by the async validation pass only.
+    #[serde(skip)]
+    pub row_lookup: Option<RowLookup>,
+    /// Field an empty slug is derived from (`SlugField::from`).
+    #[serde(skip)]
+    pub slug_source: Option<SlugSource>,
 }
 
 impl FieldConfig {
@@ -34,6 +46,10 @@ impl FieldConfig {
             html_attributes: HashMap::new(),
             template_name: template_name.to_string(),
             extra_context: HashMap::new(),
+            unique_check: None,
+            choice_source: None,
+            row_lookup: None,
+            slug_source: None,
         }
     }
 }
@@ -42,6 +58,8 @@ impl FieldConfig {
 pub struct TextConfig {
     pub max_length: Option<LengthConstraint>,
     pub min_length: Option<LengthConstraint>,
+    #[serde(default)]
+    pub password_rules: PasswordRules,
 }
 
 #[derive(Clone, Serialize, Debug)]
@@ -192,6 +210,69 @@ pub trait FormField: CommonFieldConfig + DynClone + std::fmt::Debug + Send + Syn
         );
     }
 
+    /// Attaches a database uniqueness lookup (see [`UniqueCheck`]).
+    fn set_unique_check(&mut self, check: UniqueCheck) {
+        self.get_field_config_mut().unique_check = Some(check);
+    }
+
+    fn unique_check(&self) -> Option<&UniqueCheck> {
+        self.get_field_config().unique_check.as_ref()
+    }
+
+    /// Attaches a deferred option loader (see [`ChoiceSource`]).
+    fn set_choice_source(&mut self, source: ChoiceSource) {
+        self.get_field_config_mut().choice_source = Some(source);
+    }
+
+    fn choice_source(&self) -> Option<&ChoiceSource> {
+        self.get_field_config().choice_source.as_ref()
+    }
+
+    /// Attaches an id → row lookup (see [`RowLookup`]).
+    fn set_row_lookup(&mut self, lookup: RowLookup) {
+        self.get_field_config_mut().row_lookup = Some(lookup);
+    }
+
+    fn row_lookup(&self) -> Option<&RowLookup> {
+        self.get_field_config().row_lookup.as_ref()
+    }
+
+    /// Derives the value from another field when left empty (see [`SlugSource`]).
+    fn set_slug_source(&mut self, source: SlugSource) {
+        self.get_field_config_mut().slug_source = Some(source);
+    }
+
+    fn slug_source(&self) -> Option<&SlugSource> {
+        self.get_field_config().slug_source.as_ref()
+    }
+
+    /// Replaces the options of a choice field. No-op for other field types.
+    fn set_choices(&mut self, _choices: ChoicePairs) {}
+
+    /// Value read from the submitted data by `Forms::fill` — `data[name]`.
+    /// Composite fields override it to combine their inputs.
+    fn submitted_value(&self, data: &StrMap) -> Option<String> {
+        data.get(self.name()).cloned()
+    }
+
+    /// Shows `label` for the selected row of an autocomplete field. No-op for
+    /// other field types.
+    fn set_selected_label(&mut self, _label: &str) {}
+
+    /// Help text rendered under the input (`field.extra_context.help_text`).
+    fn set_help_text(&mut self, text: &str) {
+        self.get_field_config_mut()
+            .extra_context
+            .insert("help_text".to_string(), json!(text));
+    }
+
+    fn help_text(&self) -> Option<&str> {
+        self.get_field_config()
+            .extra_context
+            .get("help_text")
+            .and_then(|v| v.as_str())
+    }
+
     /// Model-defined ceiling for max_size (file fields only). None for other field types.
     fn model_max_size(&self) -> Option<u64> {
         None
@@ -205,6 +286,12 @@ pub trait FormField: CommonFieldConfig + DynClone + std::fmt::Debug + Send + Syn
         Err("ce champ ne supporte pas max_size".to_string())
     }
 
+    /// `true` for anti-bot trap fields ([`HoneypotField`](crate::forms::fields::HoneypotField)),
+    /// which must stay empty and are never part of the cleaned data.
+    fn is_honeypot(&self) -> bool {
+        false
+    }
+
     /// Field-type specific validation
     fn validate(&mut self) -> bool;
 
@@ -216,6 +303,12 @@ pub trait FormField: CommonFieldConfig + DynClone + std::fmt::Debug + Send + Syn
         Ok(())
     }
 
+    /// File operations left by `finalize` for a remote storage — drained by
+    /// `Forms::store_files`. Only file fields have some.
+    fn take_storage_ops(&mut self) -> Vec<crate::utils::storage::StorageOp> {
+        Vec::new()
+    }
+
     fn to_json_value(&self) -> Value {
         json!(self.get_field_config().value)
     }
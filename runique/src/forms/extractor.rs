@@ -1,5 +1,6 @@
 //! Prisme: non-generic CSRF + body extractor integrated into the Request pipeline.
 use crate::forms::prisme::{aegis, sentinel};
+use crate::middleware::security::csrf::CsrfExempt;
 use crate::utils::aliases::{ARuniqueConfig, StrMap, StrVecMap};
 use crate::utils::trad::t;
 use crate::utils::{
@@ -82,6 +83,7 @@ where
         })?;
 
     let method = req.method().clone();
+    let exempt = req.extensions().get::<CsrfExempt>().is_some();
 
     let content_type = req
         .headers()
@@ -92,7 +94,7 @@ where
 
     let parsed = aegis(req, state, config, &content_type).await?;
 
-    let csrf_valid = check_csrf(&parsed, csrf_session.as_str(), &method);
+    let csrf_valid = exempt || check_csrf(&parsed, csrf_session.as_str(), &method);
     let data = convert_for_form(parsed);
 
     Ok(Prisme { data, csrf_valid })
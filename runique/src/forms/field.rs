@@ -4,7 +4,7 @@ pub use crate::forms::{
 };
 use crate::utils::{
     aliases::{ATera, StrMap},
-    trad::t,
+    trad::{t, tf},
 };
 
 use async_trait::async_trait;
@@ -21,8 +21,9 @@ pub enum SaveContext {
 dyn_clone::clone_trait_object!(FormField);
 
 /// Common logic for all `cleaned_*` — whitelist + priority: POST > path > query.
+/// Confirmation fields (`must_match`) are never exposed.
 fn cleaned_value(form: &Forms, name: &str) -> Option<String> {
-    if !form.fields.contains_key(name) {
+    if !form.fields.contains_key(name) || form.is_match_confirm(name) {
         return None;
     }
     if let Some(val) = form.fields.get(name).map(|f| f.value())
@@ -151,11 +152,21 @@ pub trait RuniqueForm: Sized + Send + Sync {
 
     /// `NaiveDate` — `None` if unknown, empty, or not parseable.
     fn cleaned_naive_date(&self, name: &str) -> Option<chrono::NaiveDate> {
+        let raw = cleaned_value(self.get_form(), name)?;
+        log_coerce(name, &raw, crate::forms::fields::datetime::parse_date(&raw))
+    }
+
+    /// `(start, end)` of a [`DateRangeField`](crate::forms::fields::DateRangeField) —
+    /// `end` is `None` for an open-ended range. `None` if unknown, empty, or not parseable.
+    fn cleaned_date_range(
+        &self,
+        name: &str,
+    ) -> Option<(chrono::NaiveDate, Option<chrono::NaiveDate>)> {
         let raw = cleaned_value(self.get_form(), name)?;
         log_coerce(
             name,
             &raw,
-            chrono::NaiveDate::parse_from_str(&raw, "%Y-%m-%d"),
+            crate::forms::fields::datetime::parse_date_range(&raw),
         )
     }
 
@@ -171,7 +182,7 @@ pub trait RuniqueForm: Sized + Send + Sync {
         log_coerce(
             name,
             &raw,
-            chrono::NaiveDateTime::parse_from_str(&raw, "%Y-%m-%dT%H:%M"),
+            crate::forms::fields::datetime::parse_datetime(&raw),
         )
     }
 
@@ -243,17 +254,66 @@ pub trait RuniqueForm: Sized + Send + Sync {
         self.get_form_mut().clear_values();
     }
 
-    // Business validation hook for individual fields
+    /// Per-field business rule, Django's `clean_<field>`: `Err(msg)` becomes
+    /// the field error. Only called for fields that passed their own validation.
+    ///
+    /// ```rust,ignore
+    /// fn clean_value(&self, name: &str, value: &str) -> Result<(), String> {
+    ///     match name {
+    ///         "username" if value == "admin" => Err("Reserved name".into()),
+    ///         _ => Ok(()),
+    ///     }
+    /// }
+    /// ```
+    fn clean_value(&self, _name: &str, _value: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    // Business validation hook for individual fields.
+    // The default implementation runs `clean_value` on the submitted value.
     async fn clean_field(&mut self, name: &str) -> bool {
-        self.get_form().fields.contains_key(name)
+        let Some(field) = self.get_form().fields.get(name) else {
+            return false;
+        };
+        if field.error().is_some() {
+            return true;
+        }
+        let value = field.value().to_string();
+        match self.clean_value(name, &value) {
+            Ok(()) => true,
+            Err(msg) => {
+                if let Some(field) = self.get_form_mut().fields.get_mut(name) {
+                    field.set_error(msg);
+                }
+                false
+            }
+        }
     }
 
-    // Business validation hook for the entire form
+    // Business validation hook for the entire form (cross-field rules),
+    // called once every field is valid. Errors keyed by a field name land on
+    // that field, the others in the form's global errors.
     async fn clean(&mut self) -> Result<(), StrMap> {
         Ok(())
     }
 
+    /// Validation without database access. A form with `unique_in` fields
+    /// (non-empty) or `from_query` options not loaded by
+    /// [`Forms::prepare`] is rejected with a global error: use
+    /// [`is_valid_with_db`](Self::is_valid_with_db) for those.
     async fn is_valid(&mut self) -> bool {
+        self.run_validation(None).await
+    }
+
+    /// Like [`is_valid`](Self::is_valid), plus the database-backed rules:
+    /// deferred choices are loaded first ([`Forms::prepare`]), `unique_in`
+    /// and row lookups are checked between field validation and `clean()`.
+    async fn is_valid_with_db(&mut self, db: &DatabaseConnection) -> bool {
+        self.run_validation(Some(db)).await
+    }
+
+    #[doc(hidden)]
+    async fn run_validation(&mut self, db: Option<&DatabaseConnection>) -> bool {
         // If the form has no submitted data (e.g. first GET with no params), return false
         // without setting any field errors. This prevents showing validation errors on the
         // initial page load, and lets GET search forms fall through to their else branch
@@ -262,6 +322,29 @@ pub trait RuniqueForm: Sized + Send + Sync {
             return false;
         }
 
+        if let Some(db) = db
+            && let Err(e) = self.get_form_mut().prepare(db).await
+        {
+            tracing::error!("choice loading failed: {}", e);
+            self.get_form_mut()
+                .errors
+                .push(tf("forms.db_error", &[&e.to_string()]));
+            return false;
+        }
+
+        // `from_query` options are unknown until `prepare()`: every submitted
+        // value would be rejected as an invalid choice
+        if db.is_none() {
+            let unloaded = self.get_form().unprepared_choices();
+            if !unloaded.is_empty() {
+                tracing::warn!(fields = ?unloaded, "choices not loaded: call is_valid_with_db");
+                self.get_form_mut()
+                    .errors
+                    .push(tf("forms.db_checks_pending", &[&unloaded.join(", ")]));
+                return false;
+            }
+        }
+
         let mut fields_valid = match self.get_form_mut().is_valid() {
             Ok(valid) => valid,
             Err(ValidationError::StackOverflow) => {
@@ -280,6 +363,29 @@ pub trait RuniqueForm: Sized + Send + Sync {
             }
         }
 
+        match db {
+            Some(db) => {
+                if !self.get_form_mut().check_unique(db).await {
+                    fields_valid = false;
+                }
+                if !self.get_form_mut().check_lookups(db).await {
+                    fields_valid = false;
+                }
+            }
+            // `unique_in` / row lookups cannot be evaluated without a connection: fail rather
+            // than accept a value that may already exist
+            None => {
+                let pending = self.get_form().pending_db_checks();
+                if !pending.is_empty() {
+                    tracing::warn!(fields = ?pending, "database checks skipped: call is_valid_with_db");
+                    self.get_form_mut()
+                        .errors
+                        .push(tf("forms.db_checks_pending", &[&pending.join(", ")]));
+                    fields_valid = false;
+                }
+            }
+        }
+
         if !fields_valid {
             return false;
         }
@@ -290,6 +396,10 @@ pub trait RuniqueForm: Sized + Send + Sync {
                     self.get_form_mut().errors.push(e);
                     return false;
                 }
+                if let Err(e) = self.get_form_mut().store_files().await {
+                    self.get_form_mut().errors.push(e);
+                    return false;
+                }
                 true
             }
             Err(business_errors) => {
@@ -411,3 +521,37 @@ pub trait RuniqueForm: Sized + Send + Sync {
         Self::from_form(form)
     }
 }
+
+/// User hooks of a form generated by `model!` / `extend!`.
+///
+/// The macros emit the whole `impl RuniqueForm`, so these hooks cannot be
+/// overridden there. With `form_hooks: true` (in `meta: { … }` for `model!`,
+/// after `table:` for `extend!`), the generated impl forwards `customize`,
+/// `clean_value` and `clean` to this trait; implement the ones you need.
+///
+/// ```rust,ignore
+/// #[async_trait]
+/// impl FormHooks for UserForm {
+///     fn clean_value(&self, name: &str, value: &str) -> Result<(), String> {
+///         match name {
+///             "username" if value == "admin" => Err("Reserved name".into()),
+///             _ => Ok(()),
+///         }
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait FormHooks: RuniqueForm {
+    /// Forwarded from [`RuniqueForm::customize`].
+    fn customize(_form: &mut Forms) {}
+
+    /// Forwarded from [`RuniqueForm::clean_value`].
+    fn clean_value(&self, _name: &str, _value: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Forwarded from [`RuniqueForm::clean`].
+    async fn clean(&mut self) -> Result<(), StrMap> {
+        Ok(())
+    }
+}
@@ -1,8 +1,12 @@
-//! Selection field: `ChoiceField` with groups, multi-select, and dynamic options.
+//! Selection fields: `ChoiceField` with groups, multi-select, and dynamic options,
+//! radio/checkbox groups, and the `AutocompleteField` search box.
 use crate::forms::base::{CommonFieldConfig, FieldConfig, FormField};
+use crate::forms::options::{ChoiceEnum, ChoicePairs, ChoiceSource, RowLookup};
 use crate::utils::trad::{t, tf};
+use sea_orm::{DatabaseConnection, DbErr};
 use serde::Serialize;
 use serde_json::{Value, json};
+use std::future::Future;
 use std::sync::Arc;
 use tera::{Context, Tera};
 
@@ -31,11 +35,25 @@ impl ChoiceOption {
     }
 }
 
+/// Labelled block of options, rendered as `<optgroup>`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ChoiceGroup {
+    pub label: String,
+    pub choices: Vec<ChoiceOption>,
+    /// Index in [`ChoiceField::choices`] the group renders before; `choices.len()`
+    /// (or more) renders it after every flat option.
+    pub position: usize,
+}
+
 /// Dropdown `<select>` field. Use [`ChoiceField::multiple`] for multi-select.
+///
+/// Options ([`add_choice`](ChoiceField::add_choice)) and `<optgroup>`s
+/// ([`add_group`](ChoiceField::add_group)) render in insertion order.
 #[derive(Clone, Serialize, Debug)]
 pub struct ChoiceField {
     pub base: FieldConfig,
     pub choices: Vec<ChoiceOption>,
+    pub groups: Vec<ChoiceGroup>,
     pub multiple: bool,
 }
 
@@ -45,10 +63,42 @@ impl ChoiceField {
         Self {
             base: FieldConfig::new(name, "select", "base_select.html"),
             choices: Vec::new(),
+            groups: Vec::new(),
             multiple: false,
         }
     }
 
+    /// Dropdown whose options come from a query, run by
+    /// [`Forms::prepare`](crate::forms::Forms::prepare) (or `is_valid_with_db`).
+    /// Until then the list is empty and any submitted value is rejected.
+    ///
+    /// ```rust,ignore
+    /// ChoiceField::from_query("author", |db| async move {
+    ///     let users = users_booster::Entity::find().all(&db).await?;
+    ///     Ok(users.into_iter().map(|u| (u.id.to_string(), u.username)).collect())
+    /// })
+    /// ```
+    pub fn from_query<F, Fut>(name: &str, loader: F) -> Self
+    where
+        F: Fn(DatabaseConnection) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<ChoicePairs, DbErr>> + Send + 'static,
+    {
+        let mut field = Self::new(name);
+        field.set_choice_source(ChoiceSource::new(loader));
+        field
+    }
+
+    /// Dropdown listing the variants of `E` (see [`ChoiceEnum`]), in declaration order.
+    ///
+    /// ```rust,ignore
+    /// form.field(&ChoiceField::from_enum::<Status>("status").required());
+    /// ```
+    pub fn from_enum<E: ChoiceEnum>(name: &str) -> Self {
+        let mut field = Self::new(name);
+        field.set_choices(E::choices());
+        field
+    }
+
     /// Enables `<select multiple>`. Submitted value is a comma-separated string.
     pub fn multiple(mut self) -> Self {
         self.multiple = true;
@@ -56,7 +106,7 @@ impl ChoiceField {
         self
     }
 
-    /// Replaces all options at once.
+    /// Replaces all options at once (groups are kept).
     pub fn choices(mut self, choices: Vec<ChoiceOption>) -> Self {
         self.choices = choices;
         self
@@ -68,6 +118,32 @@ impl ChoiceField {
         self
     }
 
+    /// Appends an `<optgroup>` holding `(value, label)` options, after the
+    /// options and groups added so far.
+    pub fn add_group(mut self, label: &str, choices: Vec<(&str, &str)>) -> Self {
+        self.groups.push(ChoiceGroup {
+            label: label.to_string(),
+            choices: choices
+                .into_iter()
+                .map(|(value, label)| ChoiceOption::new(value, label))
+                .collect(),
+            position: self.choices.len(),
+        });
+        self
+    }
+
+    /// Every option, grouped ones included, in display order.
+    pub fn all_choices(&self) -> impl Iterator<Item = &ChoiceOption> {
+        let count = self.choices.len();
+        (0..=count).flat_map(move |index| {
+            self.groups
+                .iter()
+                .filter(move |g| g.position.min(count) == index)
+                .flat_map(|g| g.choices.iter())
+                .chain(self.choices.get(index))
+        })
+    }
+
     /// Overrides the auto-generated label.
     pub fn label(mut self, label: &str) -> Self {
         self.base.label = label.to_string();
@@ -92,6 +168,18 @@ impl CommonFieldConfig for ChoiceField {
 }
 
 impl FormField for ChoiceField {
+    fn set_value(&mut self, value: &str) {
+        self.base.value = value.to_string();
+        if self.multiple {
+            // Expected format: "value1,value2,value3"
+            let selected: Vec<&str> = value.split(',').map(|s| s.trim()).collect();
+            let grouped = self.groups.iter_mut().flat_map(|g| g.choices.iter_mut());
+            for option in self.choices.iter_mut().chain(grouped) {
+                option.selected = selected.contains(&option.value.as_str());
+            }
+        }
+    }
+
     fn validate(&mut self) -> bool {
         let val = self.base.value.trim();
 
@@ -106,9 +194,18 @@ impl FormField for ChoiceField {
             return false;
         }
 
-        if !val.is_empty() {
-            // Check that the value exists in the choices
-            let valid = self.choices.iter().any(|c| c.value == val);
+        if !val.is_empty() && self.multiple {
+            // Every selected value must exist in the choices
+            let selected: Vec<&str> = val.split(',').map(|s| s.trim()).collect();
+            for sel_val in selected {
+                if !self.all_choices().any(|c| c.value == sel_val) {
+                    self.set_error(tf("forms.choice_invalid_value", &[sel_val]));
+                    return false;
+                }
+            }
+        } else if !val.is_empty() {
+            // Check that the value exists in the choices (flat or grouped)
+            let valid = self.all_choices().any(|c| c.value == val);
             if !valid {
                 self.set_error(t("forms.choice_invalid").to_string());
                 return false;
@@ -119,10 +216,29 @@ impl FormField for ChoiceField {
         true
     }
 
+    fn set_choices(&mut self, choices: ChoicePairs) {
+        self.choices = choices
+            .into_iter()
+            .map(|(value, label)| ChoiceOption::new(&value, &label))
+            .collect();
+        // Re-mark the options already submitted
+        let value = self.base.value.clone();
+        self.set_value(&value);
+    }
+
+    fn to_json_meta(&self) -> Value {
+        json!({
+            "choices": self.choices,
+            "groups": self.groups,
+            "multiple": self.multiple,
+        })
+    }
+
     fn render(&self, tera: &Arc<Tera>) -> Result<String, String> {
         let mut context = Context::new();
         context.insert("field", &self.base);
         context.insert("choices", &self.choices);
+        context.insert("groups", &self.groups);
         context.insert("multiple", &self.multiple);
         context.insert("readonly", &self.to_json_readonly());
         context.insert("disabled", &self.to_json_disabled());
@@ -154,6 +270,18 @@ impl RadioField {
         }
     }
 
+    /// Radio group whose options come from a query, like
+    /// [`ChoiceField::from_query`].
+    pub fn from_query<F, Fut>(name: &str, loader: F) -> Self
+    where
+        F: Fn(DatabaseConnection) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<ChoicePairs, DbErr>> + Send + 'static,
+    {
+        let mut field = Self::new(name);
+        field.set_choice_source(ChoiceSource::new(loader));
+        field
+    }
+
     /// Replaces all options at once.
     pub fn choices(mut self, choices: Vec<ChoiceOption>) -> Self {
         self.choices = choices;
@@ -216,6 +344,17 @@ impl FormField for RadioField {
         true
     }
 
+    fn set_choices(&mut self, choices: ChoicePairs) {
+        self.choices = choices
+            .into_iter()
+            .map(|(value, label)| ChoiceOption::new(&value, &label))
+            .collect();
+    }
+
+    fn to_json_meta(&self) -> Value {
+        json!({ "choices": self.choices })
+    }
+
     fn render(&self, tera: &Arc<Tera>) -> Result<String, String> {
         let mut context = Context::new();
         context.insert("field", &self.base);
@@ -260,6 +399,18 @@ impl CheckboxField {
         }
     }
 
+    /// Checkbox group whose options come from a query, like
+    /// [`ChoiceField::from_query`].
+    pub fn from_query<F, Fut>(name: &str, loader: F) -> Self
+    where
+        F: Fn(DatabaseConnection) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<ChoicePairs, DbErr>> + Send + 'static,
+    {
+        let mut field = Self::new(name);
+        field.set_choice_source(ChoiceSource::new(loader));
+        field
+    }
+
     /// Replaces all options at once.
     pub fn choices(mut self, choices: Vec<ChoiceOption>) -> Self {
         self.choices = choices;
@@ -334,6 +485,20 @@ impl FormField for CheckboxField {
         true
     }
 
+    fn set_choices(&mut self, choices: ChoicePairs) {
+        self.choices = choices
+            .into_iter()
+            .map(|(value, label)| ChoiceOption::new(&value, &label))
+            .collect();
+        // Re-mark the options already submitted
+        let value = self.base.value.clone();
+        self.set_value(&value);
+    }
+
+    fn to_json_meta(&self) -> Value {
+        json!({ "choices": self.choices })
+    }
+
     fn render(&self, tera: &Arc<Tera>) -> Result<String, String> {
         let mut context = Context::new();
         context.insert("field", &self.base);
@@ -350,3 +515,128 @@ impl FormField for CheckboxField {
             })
     }
 }
+
+/// Search box standing in for a `<select>` with too many options — typically a
+/// foreign key to a large table. Typed text is sent (debounced) to `url` as
+/// `?q=<term>`, which answers `[{"id": …, "label": …}]`; the chosen id is the
+/// submitted value.
+///
+/// With [`lookup_in`](AutocompleteField::lookup_in), `is_valid_with_db` rejects
+/// an id matching no row and the box shows the selected row's label.
+///
+/// ```rust,ignore
+/// AutocompleteField::new("author_id", "/authors/search")
+///     .label("Author")
+///     .lookup_in::<author::Entity>(author::Column::Username)
+/// ```
+#[derive(Clone, Serialize, Debug)]
+pub struct AutocompleteField {
+    pub base: FieldConfig,
+    /// JSON endpoint queried with `q=<term>`.
+    pub url: String,
+    /// Label of the selected row shown in the search box (the raw id until a
+    /// lookup resolves it).
+    pub selected_label: String,
+}
+
+impl AutocompleteField {
+    /// Creates a search box querying `url`.
+    pub fn new(name: &str, url: &str) -> Self {
+        Self {
+            base: FieldConfig::new(name, "autocomplete", "base_autocomplete.html"),
+            url: url.to_string(),
+            selected_label: String::new(),
+        }
+    }
+
+    /// Confirms the submitted id against the rows of `E`, labelled by `label`
+    /// (see [`RowLookup::column`]).
+    pub fn lookup_in<E>(self, label: E::Column) -> Self
+    where
+        E: sea_orm::EntityTrait,
+        E::Model: Sync,
+    {
+        self.lookup(RowLookup::column::<E>(label))
+    }
+
+    /// Custom id → label lookup (see [`RowLookup::new`]).
+    pub fn lookup(mut self, lookup: RowLookup) -> Self {
+        self.set_row_lookup(lookup);
+        self
+    }
+
+    /// Overrides the auto-generated label.
+    pub fn label(mut self, label: &str) -> Self {
+        self.base.label = label.to_string();
+        self
+    }
+
+    /// Marks the field as required (no row chosen fails validation).
+    pub fn required(mut self) -> Self {
+        self.set_required(true, None);
+        self
+    }
+}
+
+impl CommonFieldConfig for AutocompleteField {
+    fn get_field_config(&self) -> &FieldConfig {
+        &self.base
+    }
+
+    fn get_field_config_mut(&mut self) -> &mut FieldConfig {
+        &mut self.base
+    }
+}
+
+impl FormField for AutocompleteField {
+    fn validate(&mut self) -> bool {
+        if self.base.is_required.choice && self.base.value.trim().is_empty() {
+            let msg = self
+                .base
+                .is_required
+                .message
+                .clone()
+                .unwrap_or_else(|| t("forms.select_required").to_string());
+            self.set_error(msg);
+            return false;
+        }
+        // Whether the id exists is the lookup's job, in the async pass
+        self.clear_error();
+        true
+    }
+
+    fn set_selected_label(&mut self, label: &str) {
+        self.selected_label = label.to_string();
+    }
+
+    fn to_json_meta(&self) -> Value {
+        json!({
+            "url": self.url,
+            "selected_label": self.selected_label,
+        })
+    }
+
+    fn render(&self, tera: &Arc<Tera>) -> Result<String, String> {
+        let mut context = Context::new();
+        context.insert("field", &self.base);
+        context.insert("url", &self.url);
+        let shown = if self.selected_label.is_empty() {
+            &self.base.value
+        } else {
+            &self.selected_label
+        };
+        context.insert("selected_label", shown);
+        context.insert("search_placeholder", &t("forms.autocomplete_search"));
+        context.insert("readonly", &self.to_json_readonly());
+        context.insert("disabled", &self.to_json_disabled());
+
+        tera.render(&self.base.template_name, &context)
+            .map_err(|e| {
+                tf(
+                    "forms.finalize_error",
+                    &[&self.base.template_name, &e.to_string()],
+                )
+                .to_string()
+            })
+    }
+}
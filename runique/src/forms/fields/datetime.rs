@@ -1,18 +1,41 @@
-//! Date/time fields: `DateField`, `TimeField`, `DateTimeField` with min/max validation.
+//! Date/time fields: `DateField`, `TimeField`, `DateTimeField`, `DateRangeField` with min/max validation.
 use crate::forms::base::{CommonFieldConfig, FieldConfig, FormField};
-use crate::utils::trad::{t, tf};
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::utils::{
+    aliases::StrMap,
+    trad::{t, tf},
+};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, ParseError, Timelike};
 use serde::Serialize;
-use serde_json::json;
+use serde_json::{Value, json};
 use std::sync::Arc;
 use tera::{Context, Tera};
 
-/// Date input (`<input type="date">`). Validates `YYYY-MM-DD` format with optional min/max bounds.
+/// Parses a submitted date: `YYYY-MM-DD` (HTML), or the date part of an ISO 8601
+/// datetime (see [`parse_datetime`]).
+pub fn parse_date(raw: &str) -> Result<NaiveDate, ParseError> {
+    NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .or_else(|e| parse_datetime(raw).map(|dt| dt.date()).map_err(|_| e))
+}
+
+/// Parses a submitted datetime: `YYYY-MM-DDTHH:MM` (HTML), ISO 8601 with seconds
+/// and fractions, or RFC 3339 with an offset — converted to local time.
+pub fn parse_datetime(raw: &str) -> Result<NaiveDateTime, ParseError> {
+    NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M")
+        .or_else(|_| NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S%.f"))
+        .or_else(|_| {
+            DateTime::parse_from_rfc3339(raw).map(|dt| dt.with_timezone(&Local).naive_local())
+        })
+}
+
+/// Date input (`<input type="date">`). Validates `YYYY-MM-DD` (or ISO 8601) with
+/// optional min/max bounds.
 #[derive(Clone, Serialize, Debug)]
 pub struct DateField {
     pub base: FieldConfig,
     pub min_date: Option<NaiveDate>,
     pub max_date: Option<NaiveDate>,
+    /// Rejects dates before the current day, evaluated at validation time.
+    pub min_today: bool,
 }
 
 impl DateField {
@@ -22,6 +45,7 @@ impl DateField {
             base: FieldConfig::new(name, "date", "base_datetime.html"),
             min_date: None,
             max_date: None,
+            min_today: false,
         }
     }
 
@@ -53,6 +77,30 @@ impl DateField {
         self
     }
 
+    /// Rejects dates in the past — today is accepted. Combined with [`min`](Self::min),
+    /// the later bound applies. `msg` overrides the default error (pass `""` for default).
+    pub fn min_today(mut self, msg: &str) -> Self {
+        self.min_today = true;
+        if !msg.is_empty() {
+            self.base
+                .extra_context
+                .insert("past_message".to_string(), json!(msg));
+        }
+        self
+    }
+
+    /// Pre-fills the field with today's date.
+    pub fn default_today(mut self) -> Self {
+        self.base.value = Local::now().date_naive().format("%Y-%m-%d").to_string();
+        self
+    }
+
+    /// Effective lower bound: `min`, or today when [`min_today`](Self::min_today) is later.
+    fn lower_bound(&self) -> Option<NaiveDate> {
+        let today = self.min_today.then(|| Local::now().date_naive());
+        self.min_date.max(today)
+    }
+
     /// Overrides the auto-generated label.
     pub fn label(mut self, label: &str) -> Self {
         self.base.label = label.to_string();
@@ -96,7 +144,7 @@ impl FormField for DateField {
         }
 
         // Parse the date
-        let date = match NaiveDate::parse_from_str(val, "%Y-%m-%d") {
+        let date = match parse_date(val) {
             Ok(d) => d,
             Err(_) => {
                 self.set_error(t("forms.date_invalid").to_string());
@@ -104,16 +152,23 @@ impl FormField for DateField {
             }
         };
 
-        // Check min
-        if let Some(min) = self.min_date
+        // Check min (today included when `min_today`)
+        if let Some(min) = self.lower_bound()
             && date < min
         {
-            let msg = self
-                .base
-                .extra_context
-                .get("min_message")
-                .cloned()
-                .unwrap_or_else(|| json!(tf("forms.date_too_old", &[&min])));
+            let msg = if self.min_date.is_some_and(|m| m >= min) {
+                self.base
+                    .extra_context
+                    .get("min_message")
+                    .cloned()
+                    .unwrap_or_else(|| json!(tf("forms.date_too_old", &[&min])))
+            } else {
+                self.base
+                    .extra_context
+                    .get("past_message")
+                    .cloned()
+                    .unwrap_or_else(|| json!(t("forms.date_in_past")))
+            };
             self.set_error(msg.as_str().unwrap_or_default().to_string());
             return false;
         }
@@ -140,7 +195,7 @@ impl FormField for DateField {
         let mut context = Context::new();
         context.insert("field", &self.base);
 
-        if let Some(min) = &self.min_date {
+        if let Some(min) = self.lower_bound() {
             context.insert("min_date", &min.format("%Y-%m-%d").to_string());
         }
         if let Some(max) = &self.max_date {
@@ -310,6 +365,8 @@ pub struct DateTimeField {
     pub base: FieldConfig,
     pub min_datetime: Option<NaiveDateTime>,
     pub max_datetime: Option<NaiveDateTime>,
+    /// Rejects datetimes before the current minute, evaluated at validation time.
+    pub min_now: bool,
 }
 
 impl DateTimeField {
@@ -319,6 +376,7 @@ impl DateTimeField {
             base: FieldConfig::new(name, "datetime-local", "base_datetime.html"),
             min_datetime: None,
             max_datetime: None,
+            min_now: false,
         }
     }
 
@@ -344,6 +402,39 @@ impl DateTimeField {
         self
     }
 
+    /// Rejects datetimes in the past — the current minute is accepted. Combined
+    /// with [`min`](Self::min), the later bound applies. `msg` overrides the
+    /// default error (pass `""` for default).
+    pub fn min_now(mut self, msg: &str) -> Self {
+        self.min_now = true;
+        if !msg.is_empty() {
+            self.base
+                .extra_context
+                .insert("past_message".to_string(), json!(msg));
+        }
+        self
+    }
+
+    /// Pre-fills the field with the current local date and time.
+    pub fn default_now(mut self) -> Self {
+        self.base.value = Local::now()
+            .naive_local()
+            .format("%Y-%m-%dT%H:%M")
+            .to_string();
+        self
+    }
+
+    /// Effective lower bound: `min`, or the current minute when
+    /// [`min_now`](Self::min_now) is later.
+    fn lower_bound(&self) -> Option<NaiveDateTime> {
+        let now = self.min_now.then(|| {
+            let now = Local::now().naive_local();
+            now.date()
+                .and_time(NaiveTime::from_hms_opt(now.hour(), now.minute(), 0).unwrap_or_default())
+        });
+        self.min_datetime.max(now)
+    }
+
     /// Overrides the auto-generated label.
     pub fn label(mut self, label: &str) -> Self {
         self.base.label = label.to_string();
@@ -385,10 +476,8 @@ impl FormField for DateTimeField {
             return true;
         }
 
-        // Parse the datetime — accept HTML (YYYY-MM-DDTHH:MM) and ISO with seconds only
-        let datetime = match NaiveDateTime::parse_from_str(val, "%Y-%m-%dT%H:%M")
-            .or_else(|_| NaiveDateTime::parse_from_str(val, "%Y-%m-%dT%H:%M:%S"))
-        {
+        // Parse the datetime — HTML (YYYY-MM-DDTHH:MM) or ISO 8601
+        let datetime = match parse_datetime(val) {
             Ok(dt) => dt,
             Err(_) => {
                 self.set_error(t("forms.date_invalid").to_string());
@@ -396,16 +485,23 @@ impl FormField for DateTimeField {
             }
         };
 
-        // Check min
-        if let Some(min) = self.min_datetime
+        // Check min (now included when `min_now`)
+        if let Some(min) = self.lower_bound()
             && datetime < min
         {
-            let msg = self
-                .base
-                .extra_context
-                .get("min_message")
-                .cloned()
-                .unwrap_or_else(|| json!(tf("forms.datetime_too_old", &[&min])));
+            let msg = if self.min_datetime.is_some_and(|m| m >= min) {
+                self.base
+                    .extra_context
+                    .get("min_message")
+                    .cloned()
+                    .unwrap_or_else(|| json!(tf("forms.datetime_too_old", &[&min])))
+            } else {
+                self.base
+                    .extra_context
+                    .get("past_message")
+                    .cloned()
+                    .unwrap_or_else(|| json!(t("forms.datetime_in_past")))
+            };
             self.set_error(msg.as_str().unwrap_or_default().to_string());
             return false;
         }
@@ -432,7 +528,7 @@ impl FormField for DateTimeField {
         let mut context = Context::new();
         context.insert("field", &self.base);
 
-        if let Some(min) = &self.min_datetime {
+        if let Some(min) = self.lower_bound() {
             context.insert("min_datetime", &min.format("%Y-%m-%dT%H:%M").to_string());
         }
         if let Some(max) = &self.max_datetime {
@@ -596,3 +692,264 @@ impl FormField for DurationField {
             })
     }
 }
+
+/// Date range: two `<input type="date">` named `{name}_start` and `{name}_end`,
+/// combined into the value `start/end` (ISO 8601 interval). Validates
+/// `end >= start` and optional bounds, each error attached to the input at fault.
+#[derive(Clone, Serialize, Debug)]
+pub struct DateRangeField {
+    pub base: FieldConfig,
+    pub min_date: Option<NaiveDate>,
+    pub max_date: Option<NaiveDate>,
+    /// Accepts a range without end date.
+    pub open_ended: bool,
+    /// Error of the start input, set by `validate`.
+    pub start_error: Option<String>,
+    /// Error of the end input, set by `validate`.
+    pub end_error: Option<String>,
+}
+
+impl DateRangeField {
+    /// Creates a date range field.
+    pub fn new(name: &str) -> Self {
+        Self {
+            base: FieldConfig::new(name, "daterange", "base_daterange.html"),
+            min_date: None,
+            max_date: None,
+            open_ended: false,
+            start_error: None,
+            end_error: None,
+        }
+    }
+
+    /// Earliest accepted start. `msg` overrides the default error (pass `""` for default).
+    pub fn min(mut self, date: NaiveDate, msg: &str) -> Self {
+        self.min_date = Some(date);
+        if !msg.is_empty() {
+            self.base
+                .extra_context
+                .insert("min_message".to_string(), json!(msg));
+        }
+        self
+    }
+
+    /// Latest accepted end. `msg` overrides the default error (pass `""` for default).
+    pub fn max(mut self, date: NaiveDate, msg: &str) -> Self {
+        self.max_date = Some(date);
+        if !msg.is_empty() {
+            self.base
+                .extra_context
+                .insert("max_message".to_string(), json!(msg));
+        }
+        self
+    }
+
+    /// Allows an empty end date (range open to the future).
+    pub fn open_ended(mut self) -> Self {
+        self.open_ended = true;
+        self
+    }
+
+    /// Overrides the auto-generated label.
+    pub fn label(mut self, label: &str) -> Self {
+        self.base.label = label.to_string();
+        self
+    }
+
+    /// Marks the field as required (empty start fails validation).
+    pub fn required(mut self) -> Self {
+        self.set_required(true, None);
+        self
+    }
+
+    /// Raw `(start, end)` inputs of the value.
+    pub fn parts(&self) -> (&str, &str) {
+        split_range(&self.base.value)
+    }
+
+    /// Parsed `(start, end)` — `None` if the start is empty or invalid.
+    pub fn range(&self) -> Option<(NaiveDate, Option<NaiveDate>)> {
+        parse_date_range(&self.base.value).ok()
+    }
+
+    fn bound_message(&self, key: &str, default: String) -> String {
+        self.base
+            .extra_context
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .unwrap_or(default)
+    }
+
+    /// Error of one input: invalid format or out of the bounds.
+    fn check_date(&self, raw: &str) -> Result<NaiveDate, String> {
+        let date = parse_date(raw).map_err(|_| t("forms.date_invalid").to_string())?;
+        if let Some(min) = self.min_date
+            && date < min
+        {
+            return Err(self.bound_message("min_message", tf("forms.date_too_old", &[&min])));
+        }
+        if let Some(max) = self.max_date
+            && date > max
+        {
+            return Err(self.bound_message("max_message", tf("forms.date_too_far", &[&max])));
+        }
+        Ok(date)
+    }
+}
+
+/// Splits `start/end`; a value without `/` is a start only.
+fn split_range(raw: &str) -> (&str, &str) {
+    let (start, end) = raw.split_once('/').unwrap_or((raw, ""));
+    (start.trim(), end.trim())
+}
+
+/// Parses a `start/end` range (see [`DateRangeField`]); the end is `None` when empty.
+pub fn parse_date_range(raw: &str) -> Result<(NaiveDate, Option<NaiveDate>), ParseError> {
+    let (start, end) = split_range(raw);
+    let start = parse_date(start)?;
+    let end = if end.is_empty() {
+        None
+    } else {
+        Some(parse_date(end)?)
+    };
+    Ok((start, end))
+}
+
+impl CommonFieldConfig for DateRangeField {
+    fn get_field_config(&self) -> &FieldConfig {
+        &self.base
+    }
+
+    fn get_field_config_mut(&mut self) -> &mut FieldConfig {
+        &mut self.base
+    }
+}
+
+impl FormField for DateRangeField {
+    /// `data[name]` when submitted as a whole (`start/end`), otherwise the
+    /// `{name}_start` / `{name}_end` inputs.
+    fn submitted_value(&self, data: &StrMap) -> Option<String> {
+        if let Some(value) = data.get(self.name()) {
+            return Some(value.clone());
+        }
+        let start = data.get(&format!("{}_start", self.name()));
+        let end = data.get(&format!("{}_end", self.name()));
+        if start.is_none() && end.is_none() {
+            return None;
+        }
+        let (start, end) = (start.map_or("", |s| s.trim()), end.map_or("", |s| s.trim()));
+        Some(if start.is_empty() && end.is_empty() {
+            String::new()
+        } else {
+            format!("{start}/{end}")
+        })
+    }
+
+    fn validate(&mut self) -> bool {
+        self.start_error = None;
+        self.end_error = None;
+        let (start_raw, end_raw) = split_range(&self.base.value);
+        let (start_raw, end_raw) = (start_raw.to_string(), end_raw.to_string());
+
+        if start_raw.is_empty() && end_raw.is_empty() {
+            if self.base.is_required.choice {
+                let msg = self
+                    .base
+                    .is_required
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| t("forms.required").to_string());
+                self.start_error = Some(msg.clone());
+                self.set_error(msg);
+                return false;
+            }
+            self.clear_error();
+            return true;
+        }
+
+        let start = if start_raw.is_empty() {
+            self.start_error = Some(t("forms.required").to_string());
+            None
+        } else {
+            match self.check_date(&start_raw) {
+                Ok(date) => Some(date),
+                Err(e) => {
+                    self.start_error = Some(e);
+                    None
+                }
+            }
+        };
+        let end = if end_raw.is_empty() {
+            if !self.open_ended {
+                self.end_error = Some(t("forms.required").to_string());
+            }
+            None
+        } else {
+            match self.check_date(&end_raw) {
+                Ok(date) => Some(date),
+                Err(e) => {
+                    self.end_error = Some(e);
+                    None
+                }
+            }
+        };
+        if let (Some(start), Some(end)) = (start, end)
+            && end < start
+        {
+            self.end_error = Some(t("forms.date_range_order").to_string());
+        }
+
+        match self.start_error.clone().or_else(|| self.end_error.clone()) {
+            Some(msg) => {
+                self.set_error(msg);
+                false
+            }
+            None => {
+                self.clear_error();
+                true
+            }
+        }
+    }
+
+    /// `start` / `end` inputs: name, value and error of each.
+    fn to_json_meta(&self) -> Value {
+        let (start, end) = self.parts();
+        json!({
+            "start": {
+                "name": format!("{}_start", self.base.name),
+                "value": start,
+                "error": self.start_error,
+            },
+            "end": {
+                "name": format!("{}_end", self.base.name),
+                "value": end,
+                "error": self.end_error,
+            },
+            "open_ended": self.open_ended,
+        })
+    }
+
+    fn render(&self, tera: &Arc<Tera>) -> Result<String, String> {
+        let mut context = Context::new();
+        context.insert("field", &self.base);
+        context.insert("meta", &self.to_json_meta());
+        context.insert("readonly", &self.to_json_readonly());
+        context.insert("disabled", &self.to_json_disabled());
+        if let Some(min) = &self.min_date {
+            context.insert("min_date", &min.format("%Y-%m-%d").to_string());
+        }
+        if let Some(max) = &self.max_date {
+            context.insert("max_date", &max.format("%Y-%m-%d").to_string());
+        }
+
+        tera.render(&self.base.template_name, &context)
+            .map_err(|e| {
+                tf(
+                    "forms.finalize_error",
+                    &[&self.base.template_name, &e.to_string()],
+                )
+                .to_string()
+            })
+    }
+}
@@ -1,5 +1,6 @@
 //! File Field: `FileField` with type, size, and upload path validation.
 use crate::config::static_files::resolve_media_root;
+use crate::utils::storage::{StorageOp, storage};
 use crate::utils::trad::{t, tf};
 use crate::{
     config::StaticConfig,
@@ -48,46 +49,84 @@ fn cleanup_files(files: &[String]) {
     }
 }
 
-/// Checks if the file is a valid image using magic bytes.
-/// Covers JPEG, PNG, GIF, WebP, and ISO BMFF containers (AVIF, HEIC, HEIF).
-fn is_valid_path(path: &str) -> bool {
+/// Detects the MIME type of a file from its magic bytes, ignoring its extension.
+/// Returns `None` when the file is missing, unreadable, or of an unknown format.
+pub fn sniff_content_type(path: &str) -> Option<&'static str> {
     use std::io::Read;
 
-    let p = Path::new(path);
-    if !p.exists() {
-        return false;
-    }
-    if path.to_lowercase().ends_with(".svg") {
-        return false;
-    }
     let mut buf = [0u8; 12];
-    let n = std::fs::File::open(p)
+    let n = std::fs::File::open(path)
         .and_then(|mut f| f.read(&mut buf))
         .unwrap_or(0);
     if n < 4 {
+        return None;
+    }
+    let mime = match &buf[..4] {
+        [0xFF, 0xD8, 0xFF, _] => "image/jpeg",
+        [0x89, 0x50, 0x4E, 0x47] => "image/png",
+        [0x47, 0x49, 0x46, 0x38] => "image/gif",
+        b"%PDF" => "application/pdf",
+        [0x50, 0x4B, 0x03, 0x04] => "application/zip",
+        [0x7F, 0x45, 0x4C, 0x46] => "application/x-executable",
+        [0x4D, 0x5A, _, _] => "application/x-msdownload",
+        // WebP (RIFF....WEBP)
+        b"RIFF" if n >= 12 && &buf[8..12] == b"WEBP" => "image/webp",
+        // ISO Base Media File Format: ftyp box at offset 4, major brand at offset 8
+        _ if n >= 12 && &buf[4..8] == b"ftyp" => match &buf[8..12] {
+            b"avif" | b"avis" => "image/avif",
+            b"heic" | b"heix" | b"hevc" | b"hevx" => "image/heic",
+            b"mif1" | b"msf1" => "image/heif",
+            b"isom" | b"iso2" | b"iso5" | b"mp41" | b"mp42" | b"avc1" | b"dash" => "video/mp4",
+            b"M4V " => "video/x-m4v",
+            b"M4A " => "audio/mp4",
+            b"qt  " => "video/quicktime",
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(mime)
+}
+
+/// Text formats have no magic bytes: their type comes from the extension,
+/// and the content must be valid UTF-8.
+const TEXT_CONTENT_TYPES: &[(&str, &str)] = &[
+    ("txt", "text/plain"),
+    ("csv", "text/csv"),
+    ("json", "application/json"),
+    ("svg", "image/svg+xml"),
+];
+
+/// Detects the MIME type of an uploaded file: magic bytes first
+/// ([`sniff_content_type`]), then the extension for text formats whose
+/// content is valid UTF-8. `None` when neither applies.
+pub fn detect_content_type(path: &str) -> Option<&'static str> {
+    if let Some(mime) = sniff_content_type(path) {
+        return Some(mime);
+    }
+    let ext = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())?
+        .to_lowercase();
+    let (_, mime) = TEXT_CONTENT_TYPES.iter().find(|(e, _)| *e == ext)?;
+    let bytes = std::fs::read(path).ok()?;
+    std::str::from_utf8(&bytes).ok().map(|_| *mime)
+}
+
+/// Checks if the file is a valid image using magic bytes.
+/// Covers JPEG, PNG, GIF, WebP, and ISO BMFF images (AVIF, HEIC, HEIF).
+fn is_valid_path(path: &str) -> bool {
+    if path.to_lowercase().ends_with(".svg") {
         return false;
     }
-    // JPEG
-    if buf[..3] == [0xFF, 0xD8, 0xFF] {
-        return true;
-    }
-    // PNG
-    if buf[..4] == [0x89, 0x50, 0x4E, 0x47] {
-        return true;
-    }
-    // GIF
-    if buf[..4] == [0x47, 0x49, 0x46, 0x38] {
-        return true;
-    }
-    // WebP (RIFF....WEBP)
-    if n >= 12 && &buf[..4] == b"RIFF" && &buf[8..12] == b"WEBP" {
-        return true;
-    }
-    // AVIF / HEIC / HEIF — ISO Base Media File Format: ftyp box at offset 4
-    if n >= 8 && &buf[4..8] == b"ftyp" {
-        return true;
-    }
-    false
+    sniff_content_type(path).is_some_and(|mime| mime.starts_with("image/"))
+}
+
+/// `true` if `mime` matches one of `allowed` (exact, or `"image/*"`-style wildcard).
+fn content_type_allowed(allowed: &[String], mime: &str) -> bool {
+    allowed.iter().any(|a| match a.strip_suffix("/*") {
+        Some(family) => mime.split('/').next() == Some(family),
+        None => a.eq_ignore_ascii_case(mime),
+    })
 }
 
 /// Parses the field value into a list of file paths
@@ -249,7 +288,11 @@ pub struct FileField {
     pub max_files: Option<usize>,
     pub max_width: Option<u32>,
     pub max_height: Option<u32>,
+    /// MIME whitelist checked against the sniffed content (empty = no check).
+    pub allowed_content_types: Vec<String>,
     pub prev_value: Option<String>,
+    /// Uploads and removals left by `finalize` for a remote storage.
+    pub storage_ops: Vec<StorageOp>,
 }
 
 impl CommonFieldConfig for FileField {
@@ -281,7 +324,9 @@ impl FileField {
             max_files: None,
             max_width: None,
             max_height: None,
+            allowed_content_types: Vec::new(),
             prev_value: None,
+            storage_ops: Vec::new(),
         }
     }
 
@@ -370,6 +415,15 @@ impl FileField {
         self
     }
 
+    /// Restricts uploads to the given MIME types, detected from the file content
+    /// (a `.exe` renamed to `.png` is still rejected). Accepts `"image/*"` wildcards.
+    /// Text types (`text/plain`, `text/csv`, `application/json`, `image/svg+xml`)
+    /// are recognised by extension and must contain valid UTF-8.
+    pub fn allowed_content_types(mut self, types: &[&str]) -> Self {
+        self.allowed_content_types = types.iter().map(|t| t.to_lowercase()).collect();
+        self
+    }
+
     /// Overrides the extension whitelist. Pass lowercase extensions without dots: `vec!["jpg", "png"]`.
     pub fn allowed_extensions(mut self, exts: Vec<&str>) -> Self {
         self.allowed_extensions = AllowedExtensions::new(exts);
@@ -461,7 +515,25 @@ impl FormField for FileField {
             }
         }
 
-        // 4. Image validation: real format + dimensions
+        // 4. Content type sniffed from magic bytes
+        if !self.allowed_content_types.is_empty() {
+            for filename in &files {
+                let mime = detect_content_type(filename);
+                if !mime.is_some_and(|m| content_type_allowed(&self.allowed_content_types, m)) {
+                    let allowed = self.allowed_content_types.join(", ");
+                    let detected = mime.unwrap_or("?");
+                    cleanup_files(&files);
+                    self.base.value.clear();
+                    self.set_error(tf(
+                        "forms.file_content_type_blocked",
+                        &[filename.as_str(), detected, allowed.as_str()],
+                    ));
+                    return false;
+                }
+            }
+        }
+
+        // 5. Image validation: real format + dimensions
         if let FileFieldType::Image = self.field_type {
             for filename in &files {
                 if !is_valid_path(filename) {
@@ -471,11 +543,18 @@ impl FormField for FileField {
                     return false;
                 }
 
-                if (self.max_width.is_some() || self.max_height.is_some())
-                    && let Ok(reader) = ImageReader::open(filename)
-                    && let Ok(dims) = reader.into_dimensions()
-                {
-                    let (w, h) = dims;
+                if self.max_width.is_some() || self.max_height.is_some() {
+                    // Header must decode: magic bytes alone don't prove a valid image
+                    let Some((w, h)) = ImageReader::open(filename)
+                        .ok()
+                        .and_then(|r| r.with_guessed_format().ok())
+                        .and_then(|r| r.into_dimensions().ok())
+                    else {
+                        cleanup_files(&files);
+                        self.base.value.clear();
+                        self.set_error(tf("forms.file_invalid_image", &[filename.as_str()]));
+                        return false;
+                    };
                     if let Some(max_w) = self.max_width
                         && w > max_w
                     {
@@ -524,16 +603,6 @@ impl FormField for FileField {
             None => String::new(),
         };
 
-        // Physical destination: {MEDIA_ROOT}/{upload_rel}
-        let media_root = resolve_media_root();
-        let media_root_clean = media_root.trim_end_matches('/');
-        let dest_dir_abs = if upload_rel_clean.is_empty() {
-            media_root_clean.to_string()
-        } else {
-            format!("{}/{}", media_root_clean, upload_rel_clean)
-        };
-        let dest_dir_abs_path = Path::new(&dest_dir_abs);
-
         // Construit le chemin relatif stocké ("filename" en racine, sinon "rel/filename").
         let to_rel = |filename: &str| -> String {
             if upload_rel_clean.is_empty() {
@@ -549,6 +618,49 @@ impl FormField for FileField {
             .filter(|s| !s.is_empty())
             .collect();
 
+        // Stockage distant : on ne fait que décider des chemins, l'envoi est async
+        // (`Forms::store_files`, juste après `finalize` dans `is_valid`).
+        let Some(media_root) = storage().local_root() else {
+            let mut new_paths = Vec::new();
+            for file_path in &files {
+                let src = Path::new(file_path.as_str());
+                if !src.is_file() {
+                    new_paths.push(file_path.clone());
+                    continue;
+                }
+                let filename = src
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or(file_path.as_str());
+                let path = to_rel(filename);
+                self.storage_ops.push(StorageOp::Save {
+                    staged: src.to_path_buf(),
+                    path: path.clone(),
+                });
+                new_paths.push(path);
+            }
+            self.base.value = new_paths.join(",");
+            if let Some(prev) = &self.prev_value {
+                for old_rel in prev.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()) {
+                    if !new_paths.iter().any(|p| p == old_rel) {
+                        self.storage_ops
+                            .push(StorageOp::Delete(old_rel.to_string()));
+                    }
+                }
+            }
+            return Ok(());
+        };
+
+        // Physical destination: {storage root}/{upload_rel}
+        let media_root = media_root.to_string_lossy();
+        let media_root_clean = media_root.trim_end_matches('/');
+        let dest_dir_abs = if upload_rel_clean.is_empty() {
+            media_root_clean.to_string()
+        } else {
+            format!("{}/{}", media_root_clean, upload_rel_clean)
+        };
+        let dest_dir_abs_path = Path::new(&dest_dir_abs);
+
         let mut new_paths = Vec::new();
 
         for file_path in &files {
@@ -605,6 +717,10 @@ impl FormField for FileField {
         Ok(())
     }
 
+    fn take_storage_ops(&mut self) -> Vec<StorageOp> {
+        std::mem::take(&mut self.storage_ops)
+    }
+
     fn render(&self, tera: &Arc<Tera>) -> Result<String, String> {
         let mut context = Context::new();
 
@@ -1,4 +1,5 @@
-//! Hidden field `HiddenField` for non-displayed data (tokens, internal IDs).
+//! Non-input fields: `HiddenField` for non-displayed data (tokens, internal IDs),
+//! `HoneypotField` (anti-bot trap) and `DisplayField` (read-only value shown as text).
 use crate::forms::base::{CommonFieldConfig, FieldConfig, FormField};
 use crate::utils::trad::{t, tf};
 use serde::Serialize;
@@ -54,7 +55,9 @@ impl CommonFieldConfig for HiddenField {
     }
 }
 
-/// HoneypotField - invisible anti-bot trap field
+/// HoneypotField - invisible anti-bot trap field.
+/// Hidden via CSS; a non-empty value marks the submission as spam
+/// ([`Forms::is_spam`](crate::forms::Forms::is_spam)) and fails validation without an error message.
 #[derive(Clone, Serialize, Debug)]
 pub struct HoneypotField {
     pub base: FieldConfig,
@@ -79,10 +82,15 @@ impl CommonFieldConfig for HoneypotField {
 }
 
 impl FormField for HoneypotField {
-    fn validate(&mut self) -> bool {
+    fn is_honeypot(&self) -> bool {
         true
     }
 
+    fn validate(&mut self) -> bool {
+        // No error message: the bot must not learn why the form was rejected
+        self.base.value.trim().is_empty()
+    }
+
     fn render(&self, tera: &Arc<Tera>) -> Result<String, String> {
         let mut context = Context::new();
         context.insert("field", &self.base);
@@ -137,3 +145,54 @@ impl FormField for HiddenField {
             })
     }
 }
+
+/// DisplayField - value shown as plain text, with no input: nothing is submitted
+/// for it. Used by the admin for `readonly_fields`.
+#[derive(Clone, Serialize, Debug)]
+pub struct DisplayField {
+    pub base: FieldConfig,
+}
+
+impl DisplayField {
+    pub fn new(name: &str) -> Self {
+        Self {
+            base: FieldConfig::new(name, "display", "base_display.html"),
+        }
+    }
+
+    /// Overrides the auto-generated label.
+    pub fn label(mut self, label: &str) -> Self {
+        self.base.label = label.to_string();
+        self
+    }
+}
+
+impl CommonFieldConfig for DisplayField {
+    fn get_field_config(&self) -> &FieldConfig {
+        &self.base
+    }
+
+    fn get_field_config_mut(&mut self) -> &mut FieldConfig {
+        &mut self.base
+    }
+}
+
+impl FormField for DisplayField {
+    fn validate(&mut self) -> bool {
+        self.clear_error();
+        true
+    }
+
+    fn render(&self, tera: &Arc<Tera>) -> Result<String, String> {
+        let mut context = Context::new();
+        context.insert("field", &self.base);
+        tera.render(&self.base.template_name, &context)
+            .map_err(|e| {
+                tf(
+                    "forms.finalize_error",
+                    &[&self.base.template_name, &e.to_string()],
+                )
+                .to_string()
+            })
+    }
+}
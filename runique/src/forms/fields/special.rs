@@ -1,5 +1,6 @@
 //! Special fields: `ColorField`, `UUIDField`, `IPAddressField`, `JSONField`, `SlugField`.
 use crate::forms::base::{CommonFieldConfig, FieldConfig, FormField};
+use crate::forms::options::{SlugSource, UniqueCheck};
 use crate::utils::trad::{t, tf};
 use serde::Serialize;
 use serde_json::json;
@@ -107,6 +108,7 @@ impl FormField for ColorField {
 pub struct SlugField {
     pub base: FieldConfig,
     pub allow_unicode: bool,
+    pub max_length: Option<usize>,
 }
 
 impl CommonFieldConfig for SlugField {
@@ -124,9 +126,52 @@ impl SlugField {
         Self {
             base: FieldConfig::new(name, "text", "base_special.html"),
             allow_unicode: false,
+            max_length: None,
         }
     }
 
+    /// When left empty, the slug is derived from `source` during validation
+    /// ([`slugify`](crate::utils::slugify)). With [`unique_in`](SlugField::unique_in),
+    /// collisions get a `-2`, `-3`, … suffix.
+    ///
+    /// ```rust,ignore
+    /// form.field(&SlugField::new("slug").from("title").max_length(60));
+    /// ```
+    pub fn from(mut self, source: &str) -> Self {
+        self.set_slug_source(SlugSource::new(source, self.max_length));
+        self
+    }
+
+    /// Maximum length, enforced on typed slugs and respected by derived ones.
+    pub fn max_length(mut self, max: usize) -> Self {
+        self.max_length = Some(max);
+        if let Some(source) = self.base.slug_source.as_mut() {
+            source.max_length = Some(max);
+        }
+        self.base
+            .html_attributes
+            .insert("maxlength".to_string(), max.to_string());
+        self
+    }
+
+    /// Same as [`TextField::unique_in`](crate::forms::fields::TextField::unique_in).
+    pub fn unique_in<E>(mut self, column: E::Column, msg: &str) -> Self
+    where
+        E: sea_orm::EntityTrait,
+        E::Model: Sync,
+    {
+        self.set_unique_check(UniqueCheck::new::<E>(
+            column,
+            (!msg.is_empty()).then(|| msg.to_string()),
+        ));
+        self
+    }
+
+    pub fn required(mut self) -> Self {
+        self.set_required(true, None);
+        self
+    }
+
     pub fn allow_unicode(mut self) -> Self {
         self.allow_unicode = true;
         self
@@ -184,6 +229,13 @@ impl FormField for SlugField {
                 self.set_error(t("forms.slug_no_dash").to_string());
                 return false;
             }
+
+            if let Some(max) = self.max_length
+                && val.chars().count() > max
+            {
+                self.set_error(tf("forms.too_long", &[&max]));
+                return false;
+            }
         }
 
         self.clear_error();
@@ -1,11 +1,12 @@
-//! Champs texte : `TextField`, `EmailField`, `UrlField`, `TextAreaField`, `PasswordField`.
+//! Champs texte : `TextField`, `EmailField`, `UrlField`, `TextAreaField`, `PasswordField`, `MarkdownField`.
 pub use crate::forms::generic::GenericField;
 use crate::forms::{
     base::{CommonFieldConfig, FieldConfig, FormField, TextConfig},
-    options::LengthConstraint,
+    options::{LengthConstraint, UniqueCheck},
 };
 use crate::utils::{
     password::{PasswordConfig, PasswordService},
+    sanitizer::{SanitizePolicy, sanitize_rich, sanitize_strict},
     trad::{t, tf},
 };
 use serde::Serialize;
@@ -15,13 +16,17 @@ use validator::{ValidateEmail, ValidateUrl};
 
 /// Single-line text input. Construct with [`TextField::text`], [`::email`](TextField::email),
 /// [`::password`](TextField::password), [`::url`](TextField::url), [`::phone`](TextField::phone),
-/// [`::textarea`](TextField::textarea), or [`::richtext`](TextField::richtext).
+/// [`::textarea`](TextField::textarea), [`::richtext`](TextField::richtext), or
+/// [`::markdown`](TextField::markdown).
 #[derive(Clone, Serialize, Debug)]
 pub struct TextField {
     pub base: FieldConfig,
     pub config: TextConfig,
     pub format: SpecialFormat,
     pub hash_password: bool, // true by default
+    /// Allowlist replacing the built-in sanitization — see [`TextField::sanitize_policy`].
+    #[serde(skip)]
+    pub sanitizer: Option<Arc<SanitizePolicy>>,
 }
 
 /// Validation format applied to a [`TextField`].
@@ -34,6 +39,7 @@ pub enum SpecialFormat {
     RichText,
     Csrf,
     Phone,
+    Markdown,
 }
 impl CommonFieldConfig for TextField {
     fn get_field_config(&self) -> &FieldConfig {
@@ -54,6 +60,7 @@ impl TextField {
             config: TextConfig::default(),
             format,
             hash_password: true,
+            sanitizer: None,
         }
     }
     /// Disables automatic password hashing (helper)
@@ -79,6 +86,41 @@ impl TextField {
         self
     }
 
+    /// Password strength: at least one uppercase letter.
+    pub fn require_uppercase(mut self) -> Self {
+        self.config.password_rules.require_uppercase = true;
+        self
+    }
+
+    /// Password strength: at least one ASCII digit.
+    pub fn require_digit(mut self) -> Self {
+        self.config.password_rules.require_digit = true;
+        self
+    }
+
+    /// Password strength: at least one non-alphanumeric character.
+    pub fn require_symbol(mut self) -> Self {
+        self.config.password_rules.require_symbol = true;
+        self
+    }
+
+    /// Password strength: rejects values from the embedded common-password list
+    /// (case-insensitive, surrounding whitespace ignored).
+    pub fn reject_common(mut self) -> Self {
+        self.config.password_rules.reject_common = true;
+        self
+    }
+
+    /// Requirement lines for the configured strength rules (min length included),
+    /// empty when none is set. Also exposed to templates as `requirements`.
+    pub fn password_requirements(&self) -> Vec<String> {
+        let rules = &self.config.password_rules;
+        if rules.is_empty() {
+            return Vec::new();
+        }
+        rules.requirements(self.config.min_length.as_ref().map(|l| l.value))
+    }
+
     /// Plain text `<input type="text">`.
     pub fn text(name: &str) -> Self {
         Self::create(name, "text", SpecialFormat::None)
@@ -91,6 +133,11 @@ impl TextField {
     pub fn richtext(name: &str) -> Self {
         Self::create(name, "richtext", SpecialFormat::RichText)
     }
+    /// Markdown `<textarea>`. The value is kept as written — render it with the
+    /// sanitizing `| render_markdown` filter ([`render_markdown`](crate::utils::sanitizer::render_markdown)).
+    pub fn markdown(name: &str) -> Self {
+        Self::create(name, "markdown", SpecialFormat::Markdown)
+    }
     /// Password input. Value is auto-hashed on `finalize()` when password mode is `Auto`.
     pub fn password(name: &str) -> Self {
         Self::create(name, "password", SpecialFormat::Password)
@@ -140,6 +187,39 @@ impl TextField {
         self
     }
 
+    /// Rejects values already stored in `column` of entity `E`. Checked by the
+    /// async validation pass (`is_valid_with_db`); skipped when the field is empty.
+    /// `msg` overrides the default error message (pass `""` for default).
+    pub fn unique_in<E>(mut self, column: E::Column, msg: &str) -> Self
+    where
+        E: sea_orm::EntityTrait,
+        E::Model: Sync,
+    {
+        self.set_unique_check(UniqueCheck::new::<E>(
+            column,
+            (!msg.is_empty()).then(|| msg.to_string()),
+        ));
+        self
+    }
+
+    /// Cleans the submitted value with `policy` instead of the built-in sanitization
+    /// (rich allowlist for `richtext`, every tag stripped otherwise). Ignored by
+    /// password fields.
+    pub fn sanitize_policy(mut self, policy: SanitizePolicy) -> Self {
+        self.sanitizer = Some(Arc::new(policy));
+        self
+    }
+
+    /// Live-preview hook of a `markdown` field: the textarea gets
+    /// `data-markdown-preview="id_{name}_preview"`, the id of an empty
+    /// `.markdown-preview` container rendered right after it for your script.
+    pub fn with_preview(mut self) -> Self {
+        self.base
+            .extra_context
+            .insert("markdown_preview".to_string(), serde_json::json!(true));
+        self
+    }
+
     /// Number of visible rows for `textarea` fields.
     pub fn rows(mut self, rows: usize) -> Self {
         self.base
@@ -151,13 +231,18 @@ impl TextField {
 
 impl FormField for TextField {
     fn set_value(&mut self, value: &str) {
-        let cleaned = match self.format {
-            SpecialFormat::Password | SpecialFormat::Csrf => value.to_string(),
-            SpecialFormat::RichText => crate::utils::sanitizer::sanitize_rich(value),
+        let cleaned = match (&self.format, &self.sanitizer) {
+            // Markdown is stored raw: stripping tags would eat `<` and inline code,
+            // and safety is applied on output by `render_markdown`.
+            (SpecialFormat::Password | SpecialFormat::Csrf | SpecialFormat::Markdown, _) => {
+                value.to_string()
+            }
+            (_, Some(policy)) => policy.clean(value),
+            (SpecialFormat::RichText, None) => sanitize_rich(value),
             // `sanitize_strict` already strips every tag (scripts included) via ammonia
             // and decodes entities, so legitimate `>`, `&`, `<` survive. The XSS guard
             // stays the output-side auto-escaping; no lossy input mutation needed.
-            _ => crate::utils::sanitizer::sanitize_strict(value),
+            _ => sanitize_strict(value),
         };
         self.base.value = cleaned;
     }
@@ -207,6 +292,12 @@ impl FormField for TextField {
             }
         }
 
+        // Password strength rules
+        if let Some(msg) = self.config.password_rules.check(&val) {
+            self.set_error(msg);
+            return false;
+        }
+
         // Special format validation
         match &self.format {
             SpecialFormat::Email if !val.validate_email() => {
@@ -260,6 +351,14 @@ impl FormField for TextField {
         }
         Ok(())
     }
+    fn to_json_meta(&self) -> serde_json::Value {
+        let requirements = self.password_requirements();
+        if requirements.is_empty() {
+            return serde_json::json!({});
+        }
+        serde_json::json!({ "requirements": requirements })
+    }
+
     fn render(&self, tera: &Arc<Tera>) -> Result<String, String> {
         let mut context = Context::new();
 
@@ -287,6 +386,11 @@ impl FormField for TextField {
             context.insert("rows", &rows);
         }
 
+        let requirements = self.password_requirements();
+        if !requirements.is_empty() {
+            context.insert("requirements", &requirements);
+        }
+
         tera.render(&self.base.template_name, &context)
             .map_err(|e| {
                 tf(
@@ -1,9 +1,10 @@
 //! `Forms` — main form container: fields, validation, rendering, CSRF management.
 use crate::forms::{
     base::FormField,
-    fields::{ChoiceField, HiddenField, HoneypotField},
+    fields::{ChoiceField, HiddenField, HoneypotField, TextField},
     generic::GenericField,
     renderer::FormRenderer,
+    theme::FormTheme,
     validator::{FormValidator, ValidationError},
 };
 
@@ -12,9 +13,14 @@ use crate::utils::config::TraceResult;
 use crate::utils::{
     aliases::{FieldsMap, StrMap},
     constante::session_key::session::CSRF_TOKEN_KEY,
+    storage::StorageOp,
     trad::{t, tf},
 };
-use axum::http::Method;
+use axum::{
+    Json,
+    http::{Method, StatusCode},
+    response::{IntoResponse, Response},
+};
 use indexmap::IndexMap;
 use serde::{
     Serialize,
@@ -40,6 +46,26 @@ pub struct Forms {
     pub(crate) force_invalid: bool,
     /// Honeypot field name injected by anti-bot middleware (for rendering).
     pub(crate) honeypot_field_name: Option<String>,
+    /// Set when the honeypot was filled or missing from the submission.
+    spam: bool,
+    /// Confirmation pairs registered with [`must_match`](Forms::must_match).
+    match_rules: Vec<MatchRule>,
+    /// Set once [`prepare`](Forms::prepare) has loaded the deferred choices.
+    prepared: bool,
+    /// Slugs derived during the last validation: `(field, source text)`.
+    auto_slugs: Vec<(String, String)>,
+    /// CSS theme for `render_form`; `None` → application default.
+    theme: Option<FormTheme>,
+    /// Primary key of the edited row, ignored by the `unique_in` checks.
+    excluded_pk: Option<sea_orm::Value>,
+}
+
+/// `confirm` must repeat the value of `field`.
+#[derive(Debug, Clone)]
+struct MatchRule {
+    field: String,
+    confirm: String,
+    message: Option<String>,
 }
 
 impl std::fmt::Debug for Forms {
@@ -57,7 +83,7 @@ impl Serialize for Forms {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("Forms", 9)?;
+        let mut state = serializer.serialize_struct("Forms", 10)?;
 
         state.serialize_field("errors", &self.errors())?;
         state.serialize_field("form_errors", &self.errors)?;
@@ -122,7 +148,7 @@ impl Serialize for Forms {
             .enumerate()
             .map(|(index, (name, field))| {
                 let mut field_map = serde_json::Map::new();
-                field_map.insert("name".to_string(), json!(name));
+                field_map.insert("name".to_string(), json!(field.name()));
                 field_map.insert("label".to_string(), json!(field.label()));
                 field_map.insert("field_type".to_string(), json!(field.field_type()));
                 field_map.insert("template_name".to_string(), json!(field.template_name()));
@@ -134,6 +160,9 @@ impl Serialize for Forms {
                 field_map.insert("disabled".to_string(), field.to_json_disabled());
                 field_map.insert("html_attributes".to_string(), field.to_json_attributes());
                 field_map.insert("meta".to_string(), field.to_json_meta());
+                if let Some(help) = field.help_text() {
+                    field_map.insert("help_text".to_string(), json!(help));
+                }
                 if let Some(err) = field.error() {
                     field_map.insert("error".to_string(), json!(err));
                 }
@@ -157,6 +186,8 @@ impl Serialize for Forms {
             _ => String::new(),
         };
         state.serialize_field("honeypot_html", &honeypot_html)?;
+        state.serialize_field("theme", &self.theme)?;
+        state.serialize_field("unprepared_choices", &self.unprepared_choices())?;
 
         state.end()
     }
@@ -167,6 +198,8 @@ impl Serialize for Forms {
 // ============================================================================
 use std::cell::Cell;
 const MAX_VALIDATION_DEPTH: usize = 10;
+/// Highest `-N` tried for a derived slug before reporting it as taken.
+const MAX_SLUG_SUFFIX: u32 = 1000;
 
 thread_local! {
     static VALIDATION_DEPTH: Cell<usize> = const { Cell::new(0) };
@@ -212,6 +245,12 @@ impl Forms {
             query_params: HashMap::new(),
             force_invalid: false,
             honeypot_field_name: None,
+            spam: false,
+            match_rules: Vec::new(),
+            prepared: false,
+            auto_slugs: Vec::new(),
+            theme: None,
+            excluded_pk: None,
         }
     }
 
@@ -220,6 +259,48 @@ impl Forms {
         self.honeypot_field_name = Some(name.to_string());
     }
 
+    /// Overrides the application-wide [`FormTheme`] for this form's `render_form` output.
+    /// Renders and reads every field as `{prefix}-{name}` (CSRF aside), so several
+    /// instances of one form can share a request: [`FormSet`](crate::forms::FormSet)
+    /// rows use `items-0`, `items-1`, …. Fields keep their key: `cleaned_string("title")`
+    /// is unchanged. Call it after registering the fields.
+    pub fn set_prefix(&mut self, prefix: &str) -> &mut Self {
+        for (name, field) in self.fields.iter_mut() {
+            if name != CSRF_TOKEN_KEY {
+                field.set_name(&format!("{}-{}", prefix, name));
+            }
+        }
+        self
+    }
+
+    pub fn set_theme(&mut self, theme: FormTheme) -> &mut Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Theme set on this form; `None` → `render_form` uses the application's
+    /// theme (`RuniqueConfig::form_theme`).
+    pub fn theme(&self) -> Option<FormTheme> {
+        self.theme
+    }
+
+    /// Flags the submission as spam: the form becomes invalid without any error message.
+    pub(crate) fn mark_spam(&mut self) {
+        self.spam = true;
+        self.force_invalid = true;
+    }
+
+    /// `true` if the submission looked like a bot: middleware honeypot filled or
+    /// missing, or a registered [`HoneypotField`] carrying a value. Meant for logging —
+    /// the form itself is already invalid.
+    pub fn is_spam(&self) -> bool {
+        self.spam
+            || self
+                .fields
+                .values()
+                .any(|f| f.is_honeypot() && !f.value().trim().is_empty())
+    }
+
     /// Injects path and query parameters so `cleaned_*` methods can read them (GET search forms).
     pub fn set_url_params(
         &mut self,
@@ -304,6 +385,46 @@ impl Forms {
         );
     }
 
+    /// Cross-field rule: `confirm` must hold the same value as `field`, otherwise
+    /// `confirm` gets `message` (pass `""` for the default). Two empty values
+    /// match — leave emptiness to `required`.
+    ///
+    /// If `confirm` is not registered yet, it is appended as a password input
+    /// with the same `required` flag. Either way its value is never hashed and
+    /// is hidden from the `cleaned_*` accessors, so it never reaches the save.
+    ///
+    /// ```rust,ignore
+    /// form.field(&TextField::password("password").required());
+    /// form.must_match("password", "password_confirm", "");
+    /// ```
+    pub fn must_match(&mut self, field: &str, confirm: &str, message: &str) -> &mut Self {
+        if !self.fields.contains_key(confirm) {
+            let (label, required) = self
+                .fields
+                .get(field)
+                .map(|f| (f.label().to_lowercase(), f.required()))
+                .unwrap_or_else(|| (field.replace('_', " "), false));
+            let mut input = TextField::password(confirm)
+                .no_hash()
+                .label(&tf("forms.confirm_label", &[&label]));
+            if required {
+                input = input.required();
+            }
+            self.field(&input);
+        }
+        self.match_rules.push(MatchRule {
+            field: field.to_string(),
+            confirm: confirm.to_string(),
+            message: (!message.is_empty()).then(|| message.to_string()),
+        });
+        self
+    }
+
+    /// `true` for the confirmation side of a [`must_match`](Forms::must_match) pair.
+    pub(crate) fn is_match_confirm(&self, name: &str) -> bool {
+        self.match_rules.iter().any(|r| r.confirm == name)
+    }
+
     /// Fills the form fields from a data map.
     /// If allow_password is false, password fields are ignored (GET security).
     /// In PATCH mode (admin edit), password fields have their required constraint
@@ -319,7 +440,7 @@ impl Forms {
             if field.field_type() == "password" && is_edit {
                 field.set_required(false, None);
             }
-            if let Some(value) = data.get(field.name()) {
+            if let Some(value) = field.submitted_value(data) {
                 if !value.trim().is_empty() {
                     has_data = true;
                 }
@@ -334,7 +455,7 @@ impl Forms {
                         crate::runique_log!(level, field = %field.name(), value = %value, "set_value");
                     }
                 }
-                field.set_value(value);
+                field.set_value(&value);
             }
         }
         // Normalizes checkboxes/radios absent from POST → "false".
@@ -454,6 +575,9 @@ impl Forms {
             .as_ref()
             .and_then(|f| f.finalize);
         for (name, field) in self.fields.iter_mut() {
+            if self.match_rules.iter().any(|r| r.confirm == *name) {
+                continue;
+            }
             match field.finalize() {
                 Ok(()) => {
                     if let Some(level) = log_finalize {
@@ -470,6 +594,36 @@ impl Forms {
         }
         Ok(())
     }
+
+    /// Runs the file operations `finalize` left for a remote storage: uploads the
+    /// staged files, removes the replaced ones. Stops at the first failed upload.
+    pub async fn store_files(&mut self) -> Result<(), String> {
+        let ops: Vec<(String, StorageOp)> = self
+            .fields
+            .iter_mut()
+            .flat_map(|(name, field)| {
+                let name = name.clone();
+                field
+                    .take_storage_ops()
+                    .into_iter()
+                    .map(move |op| (name.clone(), op))
+            })
+            .collect();
+        if ops.is_empty() {
+            return Ok(());
+        }
+        let storage = crate::utils::storage::storage();
+        for (name, op) in ops {
+            let is_delete = matches!(op, StorageOp::Delete(_));
+            if let Err(e) = op.run(storage.as_ref()).await {
+                if !is_delete {
+                    return Err(tf("forms.finalize_error", &[&name, &e.to_string()]));
+                }
+                tracing::warn!(field = %name, error = %e, "old upload removal failed");
+            }
+        }
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -481,12 +635,241 @@ impl Forms {
     /// Returns `Ok(false)` if the form was force-invalidated (honeypot).
     /// Used internally by [`RuniqueForm::is_valid`].
     pub fn is_valid(&mut self) -> Result<bool, ValidationError> {
-        if self.force_invalid {
+        if self.force_invalid || self.is_spam() {
             return Ok(false);
         }
         self.validated = true;
-        Self::validate(&mut self.fields, &self.errors)
+        self.fill_slugs();
+        let result = Self::validate(&mut self.fields, &self.errors);
+        if matches!(result, Err(ValidationError::StackOverflow)) || self.check_matches() {
+            return result;
+        }
+        match result {
+            Err(ValidationError::GlobalErrors(errors)) => {
+                Err(ValidationError::GlobalErrors(errors))
+            }
+            _ => Err(ValidationError::FieldValidation(
+                self.fields
+                    .iter()
+                    .filter_map(|(name, field)| field.error().map(|e| (name.clone(), e.clone())))
+                    .collect(),
+            )),
+        }
+    }
+
+    /// Fills every empty field carrying a [`SlugSource`](crate::forms::options::SlugSource)
+    /// from its source field (`SlugField::from`).
+    fn fill_slugs(&mut self) {
+        self.auto_slugs.clear();
+        let derived: Vec<(String, String, String)> = self
+            .fields
+            .iter()
+            .filter(|(_, field)| field.value().trim().is_empty())
+            .filter_map(|(name, field)| {
+                let source = field.slug_source()?;
+                let text = self.fields.get(&source.field)?.value().to_string();
+                let slug = source.build(&text, None);
+                (!slug.is_empty()).then(|| (name.clone(), slug, text))
+            })
+            .collect();
+        for (name, slug, text) in derived {
+            if let Some(field) = self.fields.get_mut(&name) {
+                field.set_value(&slug);
+            }
+            self.auto_slugs.push((name, text));
+        }
+    }
+
+    /// Applies the [`must_match`](Forms::must_match) rules. A pair is skipped
+    /// when either side already carries an error.
+    fn check_matches(&mut self) -> bool {
+        let mut valid = true;
+        for rule in &self.match_rules {
+            let (Some(a), Some(b)) = (self.fields.get(&rule.field), self.fields.get(&rule.confirm))
+            else {
+                continue;
+            };
+            if a.error().is_some() || b.error().is_some() || a.value() == b.value() {
+                continue;
+            }
+            let msg = rule
+                .message
+                .clone()
+                .unwrap_or_else(|| tf("forms.fields_mismatch", &[a.label()]));
+            if let Some(b) = self.fields.get_mut(&rule.confirm) {
+                b.set_error(msg);
+            }
+            valid = false;
+        }
+        valid
     }
+    /// Edit forms: the row whose primary key is `pk` is ignored by the
+    /// `unique_in` checks, so saving a record unchanged does not collide with itself.
+    ///
+    /// ```rust,ignore
+    /// form.get_form_mut().exclude_pk(article.id);
+    /// if form.is_valid_with_db(&db).await { … }
+    /// ```
+    pub fn exclude_pk(&mut self, pk: impl Into<sea_orm::Value>) {
+        self.excluded_pk = Some(pk.into());
+    }
+
+    /// Fields whose validation needs the database: a `unique_in` check or a
+    /// row lookup on a non-empty value. Plain `is_valid()` cannot evaluate them.
+    pub fn pending_db_checks(&self) -> Vec<String> {
+        self.fields
+            .values()
+            .filter(|f| {
+                (f.unique_check().is_some() || f.row_lookup().is_some())
+                    && !f.value().trim().is_empty()
+            })
+            .map(|f| f.name().to_string())
+            .collect()
+    }
+
+    /// Fields built with `from_query` whose options [`prepare`](Self::prepare)
+    /// has not loaded yet. Rendering refuses such a form, and so does
+    /// `is_valid()` without a database.
+    pub fn unprepared_choices(&self) -> Vec<String> {
+        if self.prepared {
+            return Vec::new();
+        }
+        let mut names: Vec<String> = self
+            .fields
+            .values()
+            .filter(|f| f.choice_source().is_some())
+            .map(|f| f.name().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Async pass for database-backed rules ([`UniqueCheck`](crate::forms::UniqueCheck)):
+    /// each field carrying a check, with a non-empty value and no error yet,
+    /// gets an error when the value already exists (the row set with
+    /// [`exclude_pk`](Self::exclude_pk) aside). Returns `false` on any error.
+    /// Slugs derived from another field get the first free `-2`, `-3`, …
+    /// suffix instead, found with a single query.
+    pub async fn check_unique(&mut self, db: &sea_orm::DatabaseConnection) -> bool {
+        let mut valid = true;
+        for (name, field) in self.fields.iter_mut() {
+            let Some(check) = field.unique_check().cloned() else {
+                continue;
+            };
+            let value = field.value().trim().to_string();
+            if value.is_empty() || field.error().is_some() {
+                continue;
+            }
+            let derived = self
+                .auto_slugs
+                .iter()
+                .find(|(slug, _)| slug == name)
+                .and_then(|(_, text)| Some((field.slug_source()?.clone(), text.clone())));
+            let taken = match &derived {
+                // Derived slug: one query for every `base…` value, then the first
+                // free candidate; all `MAX_SLUG_SUFFIX` taken → field error
+                Some((source, text)) => {
+                    let (prefix, candidates) = source.candidates(text, MAX_SLUG_SUFFIX);
+                    check
+                        .values_with_prefix(db, &prefix, self.excluded_pk.clone())
+                        .await
+                        .map(|existing| {
+                            match candidates.into_iter().find(|c| !existing.contains(c)) {
+                                Some(free) => {
+                                    if free != value {
+                                        field.set_value(&free);
+                                    }
+                                    false
+                                }
+                                None => true,
+                            }
+                        })
+                }
+                None => {
+                    check
+                        .exists_except(db, &value, self.excluded_pk.clone())
+                        .await
+                }
+            };
+            match taken {
+                Ok(false) => {}
+                Ok(true) => {
+                    let msg = check.message.clone().unwrap_or_else(|| {
+                        tf("forms.unique_field_taken", &[&name.replace('_', " ")])
+                    });
+                    field.set_error(msg);
+                    valid = false;
+                }
+                Err(e) => {
+                    tracing::error!(field = %field.name(), "unique check failed: {}", e);
+                    self.errors.push(tf("forms.db_error", &[&e.to_string()]));
+                    valid = false;
+                }
+            }
+        }
+        valid
+    }
+
+    /// Async pass for id fields carrying a [`RowLookup`](crate::forms::RowLookup)
+    /// (`AutocompleteField::lookup_in`): a non-empty value matching no row gets
+    /// an error, a matching one shows the row's label. Returns `false` on any error.
+    pub async fn check_lookups(&mut self, db: &sea_orm::DatabaseConnection) -> bool {
+        let mut valid = true;
+        for field in self.fields.values_mut() {
+            let Some(lookup) = field.row_lookup().cloned() else {
+                continue;
+            };
+            let value = field.value().trim().to_string();
+            if value.is_empty() || field.error().is_some() {
+                continue;
+            }
+            match lookup.label(db, &value).await {
+                Ok(Some(label)) => field.set_selected_label(&label),
+                Ok(None) => {
+                    field.set_error(t("forms.choice_invalid").to_string());
+                    valid = false;
+                }
+                Err(e) => {
+                    tracing::error!(field = %field.name(), "row lookup failed: {}", e);
+                    self.errors.push(tf("forms.db_error", &[&e.to_string()]));
+                    valid = false;
+                }
+            }
+        }
+        valid
+    }
+
+    /// Loads the deferred options of every field built with a
+    /// [`ChoiceSource`](crate::forms::options::ChoiceSource)
+    /// (`ChoiceField::from_query`), and the label of the row selected in an
+    /// `AutocompleteField::lookup_in`. Runs the queries once; later calls reuse
+    /// the cached lists. Call it before rendering — rendering a form whose
+    /// options were never loaded fails with an explicit error;
+    /// `is_valid_with_db` calls it before validating.
+    pub async fn prepare(
+        &mut self,
+        db: &sea_orm::DatabaseConnection,
+    ) -> Result<(), sea_orm::DbErr> {
+        if self.prepared {
+            return Ok(());
+        }
+        for field in self.fields.values_mut() {
+            if let Some(source) = field.choice_source().cloned() {
+                field.set_choices(source.load(db).await?);
+            }
+            // Autocomplete boxes show the label of the row already selected
+            let value = field.value().trim().to_string();
+            if let Some(lookup) = field.row_lookup().cloned()
+                && !value.is_empty()
+                && let Some(label) = lookup.label(db, &value).await?
+            {
+                field.set_selected_label(&label);
+            }
+        }
+        self.prepared = true;
+        Ok(())
+    }
+
     /// Returns `true` if any field or form-level error is present.
     pub fn has_errors(&self) -> bool {
         FormValidator::has_errors(&self.fields, &self.errors)
@@ -509,6 +892,46 @@ impl Forms {
     pub fn errors(&self) -> StrMap {
         FormValidator::collect_errors(&self.fields, &self.errors)
     }
+
+    /// Errors as a machine-readable payload for API clients:
+    /// `{ "field": ["msg"], "__all__": ["form-level msg"] }`. Form-level errors
+    /// (`clean`, database) go under `__all__`, present only when non-empty.
+    pub fn errors_as_json(&self) -> Value {
+        let mut map = serde_json::Map::new();
+        for (name, field) in &self.fields {
+            if let Some(err) = field.error() {
+                map.insert(name.clone(), json!([err]));
+            }
+        }
+        if !self.errors.is_empty() {
+            map.insert(NON_FIELD_ERRORS.to_string(), json!(self.errors));
+        }
+        Value::Object(map)
+    }
+
+    /// `422 Unprocessable Entity` carrying [`errors_as_json`](Self::errors_as_json):
+    ///
+    /// ```rust,ignore
+    /// if !form.is_valid().await {
+    ///     return Ok(form.get_form().error_response().into_response());
+    /// }
+    /// ```
+    pub fn error_response(&self) -> FormErrorsResponse {
+        FormErrorsResponse(self.errors_as_json())
+    }
+}
+
+/// Key of the form-level errors in [`Forms::errors_as_json`].
+pub const NON_FIELD_ERRORS: &str = "__all__";
+
+/// Validation errors answered as JSON with status 422.
+#[derive(Debug, Clone)]
+pub struct FormErrorsResponse(pub Value);
+
+impl IntoResponse for FormErrorsResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::UNPROCESSABLE_ENTITY, Json(self.0)).into_response()
+    }
 }
 
 // ============================================================================
@@ -517,6 +940,10 @@ impl Forms {
 
 impl Forms {
     pub fn render(&self) -> Result<String, String> {
+        let unprepared = self.unprepared_choices();
+        if !unprepared.is_empty() {
+            return Err(tf("forms.choices_not_prepared", &[&unprepared.join(", ")]));
+        }
         self.renderer
             .as_ref()
             .ok_or_else(|| t("forms.tera_not_configured").into_owned())?
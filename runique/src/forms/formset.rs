@@ -0,0 +1,362 @@
+//! `FormSet` — a variable number of instances of one form (order lines, addresses…),
+//! posted as `{prefix}-{index}-{field}` plus a management block.
+use crate::forms::{
+    base::FormField, field::RuniqueForm, fields::HiddenField, fields::HoneypotField, form::Forms,
+};
+use crate::middleware::errors::error::html_escape;
+use crate::utils::{
+    aliases::{ATera, StrMap},
+    constante::session_key::session::CSRF_TOKEN_KEY,
+    trad::{t, tf},
+};
+use axum::http::Method;
+use sea_orm::DatabaseConnection;
+use serde::{
+    Serialize,
+    ser::{SerializeStruct, Serializer},
+};
+
+/// Hard cap on the rows built from a submission, whatever `TOTAL_FORMS` claims.
+const ABSOLUTE_MAX_FORMS: usize = 1000;
+
+/// Index placeholder of the blank row cloned client-side by "add another".
+pub const FORMSET_INDEX_PLACEHOLDER: &str = "__prefix__";
+
+/// Repeatable instances of the form `F`.
+///
+/// Row `i` reads and renders its fields as `{prefix}-{i}-{field}`; the management
+/// block posts `{prefix}-TOTAL_FORMS` so the server knows how many rows to parse.
+/// Two formsets with different prefixes can share one page and one submission.
+///
+/// ```rust,ignore
+/// let mut lines = req.formset::<LineForm>("items").min(1).max(20).extra(2);
+/// if req.is_post() && form.is_valid().await && lines.is_valid().await {
+///     for line in lines.cleaned() {
+///         let product = line.cleaned_string("product");
+///     }
+/// }
+/// ```
+///
+/// Rows left completely empty are ignored: they are neither validated, counted
+/// against `min` / `max`, nor returned by [`cleaned`](Self::cleaned).
+pub struct FormSet<F: RuniqueForm> {
+    prefix: String,
+    tera: ATera,
+    csrf_token: String,
+    rows: Vec<Row<F>>,
+    /// Blank row indexed `__prefix__`, cloned client-side by "add another".
+    empty_form: F,
+    min: usize,
+    max: usize,
+    extra: usize,
+    /// Formset-level errors: row count, missing management data.
+    pub errors: Vec<String>,
+    submitted: bool,
+    management_valid: bool,
+    pub(crate) force_invalid: bool,
+    pub(crate) honeypot_field_name: Option<String>,
+}
+
+struct Row<F> {
+    form: F,
+    /// At least one non-empty value was posted for this row.
+    has_data: bool,
+}
+
+impl<F: RuniqueForm> std::fmt::Debug for FormSet<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FormSet")
+            .field("prefix", &self.prefix)
+            .field("rows", &self.rows.len())
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
+impl<F: RuniqueForm> FormSet<F> {
+    /// Empty formset: `extra` (1 by default) blank rows, no bounds.
+    pub fn new(prefix: &str, tera: ATera, csrf_token: &str) -> Self {
+        let empty_form = Self::new_row(prefix, &tera, csrf_token, FORMSET_INDEX_PLACEHOLDER);
+        let mut formset = Self {
+            prefix: prefix.to_string(),
+            tera,
+            csrf_token: csrf_token.to_string(),
+            rows: Vec::new(),
+            empty_form,
+            min: 0,
+            max: ABSOLUTE_MAX_FORMS,
+            extra: 1,
+            errors: Vec::new(),
+            submitted: false,
+            management_valid: true,
+            force_invalid: false,
+            honeypot_field_name: None,
+        };
+        formset.sync_blank_rows();
+        formset
+    }
+
+    /// At least `min` filled rows; an unsubmitted formset shows `min + extra` blank rows.
+    pub fn min(mut self, min: usize) -> Self {
+        self.min = min;
+        self.sync_blank_rows();
+        self
+    }
+
+    /// At most `max` filled rows (capped at 1000).
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max.min(ABSOLUTE_MAX_FORMS);
+        self.sync_blank_rows();
+        self
+    }
+
+    /// Blank rows shown on top of `min` before the first submission.
+    pub fn extra(mut self, extra: usize) -> Self {
+        self.extra = extra;
+        self.sync_blank_rows();
+        self
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// `{prefix}-{name}`: key of a management value (`TOTAL_FORMS`, …).
+    pub fn management_key(&self, name: &str) -> String {
+        format!("{}-{}", self.prefix, name)
+    }
+
+    /// New instance of `F` reading `{prefix}-{index}-{field}`, without its own CSRF
+    /// field: the formset carries a single token for every row.
+    fn new_row(prefix: &str, tera: &ATera, csrf_token: &str, index: &str) -> F {
+        let mut form = F::build(tera.clone(), csrf_token);
+        let inner = form.get_form_mut();
+        inner.fields.shift_remove(CSRF_TOKEN_KEY);
+        inner.set_prefix(&format!("{}-{}", prefix, index));
+        form
+    }
+
+    fn build_row(&self, index: &str) -> F {
+        Self::new_row(&self.prefix, &self.tera, &self.csrf_token, index)
+    }
+
+    /// Before any submission, keeps `min + extra` blank rows (at most `max`).
+    fn sync_blank_rows(&mut self) {
+        if self.submitted {
+            return;
+        }
+        let count = (self.min + self.extra).min(self.max);
+        self.rows.truncate(count);
+        while self.rows.len() < count {
+            let form = self.build_row(&self.rows.len().to_string());
+            self.rows.push(Row {
+                form,
+                has_data: false,
+            });
+        }
+    }
+
+    /// Rebuilds the rows from submitted data: `{prefix}-TOTAL_FORMS` rows, each
+    /// filled from its `{prefix}-{i}-{field}` values. A GET without that key
+    /// (first display) leaves the blank rows in place.
+    pub fn fill(&mut self, data: &StrMap, method: Method) {
+        let is_post = matches!(method, Method::POST | Method::PUT | Method::PATCH);
+        let total = data
+            .get(&self.management_key("TOTAL_FORMS"))
+            .map(|v| v.trim().parse::<usize>());
+        let total = match total {
+            Some(Ok(total)) => total,
+            None if !is_post => return,
+            // Submitted without usable management data: nothing can be trusted
+            _ => {
+                self.submitted = true;
+                self.management_valid = false;
+                self.rows.clear();
+                return;
+            }
+        };
+        self.submitted = true;
+        self.rows = (0..total.min(ABSOLUTE_MAX_FORMS))
+            .map(|i| {
+                let row_prefix = format!("{}-{}-", self.prefix, i);
+                let has_data = data
+                    .iter()
+                    .any(|(key, value)| key.starts_with(&row_prefix) && !value.trim().is_empty());
+                let mut form = self.build_row(&i.to_string());
+                form.get_form_mut().fill(data, method.clone());
+                Row { form, has_data }
+            })
+            .collect();
+    }
+
+    /// Every row, empty ones included, in index order.
+    pub fn forms(&self) -> impl Iterator<Item = &F> {
+        self.rows.iter().map(|row| &row.form)
+    }
+
+    pub fn forms_mut(&mut self) -> impl Iterator<Item = &mut F> {
+        self.rows.iter_mut().map(|row| &mut row.form)
+    }
+
+    /// Number of submitted rows holding at least one value.
+    pub fn filled_count(&self) -> usize {
+        self.rows.iter().filter(|row| row.has_data).count()
+    }
+
+    /// Loads the deferred choices (`from_query`) of every row and of the blank
+    /// row template; call it after `min` / `extra` and before rendering.
+    pub async fn prepare(&mut self, db: &DatabaseConnection) -> Result<(), sea_orm::DbErr> {
+        for row in &mut self.rows {
+            row.form.get_form_mut().prepare(db).await?;
+        }
+        self.empty_form.get_form_mut().prepare(db).await
+    }
+
+    /// Validates every filled row ([`RuniqueForm::is_valid`]) and the row count.
+    pub async fn is_valid(&mut self) -> bool {
+        self.run_validation(None).await
+    }
+
+    /// Like [`is_valid`](Self::is_valid), with the database-backed rules of each row.
+    pub async fn is_valid_with_db(&mut self, db: &DatabaseConnection) -> bool {
+        self.run_validation(Some(db)).await
+    }
+
+    async fn run_validation(&mut self, db: Option<&DatabaseConnection>) -> bool {
+        if !self.submitted || self.force_invalid {
+            return false;
+        }
+        self.errors.clear();
+        if !self.management_valid {
+            self.errors
+                .push(t("forms.formset_management_missing").into_owned());
+            return false;
+        }
+
+        let mut valid = true;
+        for row in self.rows.iter_mut().filter(|row| row.has_data) {
+            let row_valid = match db {
+                Some(db) => row.form.is_valid_with_db(db).await,
+                None => row.form.is_valid().await,
+            };
+            valid &= row_valid;
+        }
+
+        let filled = self.filled_count();
+        if filled < self.min {
+            self.errors
+                .push(tf("forms.formset_too_few", &[&self.min.to_string()]));
+            valid = false;
+        }
+        if filled > self.max {
+            self.errors
+                .push(tf("forms.formset_too_many", &[&self.max.to_string()]));
+            valid = false;
+        }
+        valid
+    }
+
+    /// Errors of each row, in index order (empty map for a valid or empty row).
+    pub fn row_errors(&self) -> Vec<StrMap> {
+        self.rows
+            .iter()
+            .map(|row| row.form.get_form().errors())
+            .collect()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty() || self.rows.iter().any(|row| row.form.get_form().has_errors())
+    }
+
+    /// The filled rows, in index order. Call it after a successful
+    /// [`is_valid`](Self::is_valid).
+    pub fn cleaned(self) -> Vec<F> {
+        self.rows
+            .into_iter()
+            .filter(|row| row.has_data)
+            .map(|row| row.form)
+            .collect()
+    }
+
+    /// Hidden inputs posting the row count and bounds, the CSRF token and, with
+    /// the anti-bot middleware, the honeypot trap.
+    pub fn management_html(&self) -> Result<String, String> {
+        let mut html = Vec::new();
+        let mut csrf = HiddenField::new_csrf();
+        csrf.set_value(&self.csrf_token);
+        html.push(csrf.render(&self.tera)?);
+        for (name, value) in [
+            ("TOTAL_FORMS", self.rows.len()),
+            ("INITIAL_FORMS", 0),
+            ("MIN_NUM_FORMS", self.min),
+            ("MAX_NUM_FORMS", self.max),
+        ] {
+            let mut field = HiddenField::new(&self.management_key(name));
+            field.set_value(&value.to_string());
+            html.push(field.render(&self.tera)?);
+        }
+        if let Some(name) = &self.honeypot_field_name {
+            html.push(HoneypotField::new(name).render(&self.tera)?);
+        }
+        Ok(html.join("\n"))
+    }
+
+    /// Blank row indexed `__prefix__`, cloned by the "add another" button.
+    pub fn empty_form_html(&self) -> Result<String, String> {
+        self.empty_form.get_form().render()
+    }
+
+    /// Full markup (`base_formset.html`): management block, formset errors,
+    /// one `<div data-formset-row>` per row, the blank row template and the
+    /// "add another" button.
+    pub fn render(&self) -> Result<String, String> {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| row.form.get_form().render())
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut context = tera::Context::new();
+        context.insert("prefix", &self.prefix);
+        context.insert("max", &self.max);
+        context.insert("management_html", &self.management_html()?);
+        context.insert("errors", &self.errors);
+        context.insert("rows", &rows);
+        context.insert("empty_form", &self.empty_form_html()?);
+        context.insert("add_label", &t("forms.formset_add"));
+        self.tera
+            .render("base_formset.html", &context)
+            .map_err(|e| {
+                tf(
+                    "forms.finalize_error",
+                    &["base_formset.html", &e.to_string()],
+                )
+            })
+    }
+}
+
+impl<F: RuniqueForm> Serialize for FormSet<F> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let render_or_error = |result: Result<String, String>| {
+            result.unwrap_or_else(|e| {
+                format!("<p style='color:red'>Render error: {}</p>", html_escape(&e))
+            })
+        };
+        let mut state = serializer.serialize_struct("FormSet", 9)?;
+        state.serialize_field("prefix", &self.prefix)?;
+        state.serialize_field("min", &self.min)?;
+        state.serialize_field("max", &self.max)?;
+        state.serialize_field("errors", &self.errors)?;
+        state.serialize_field("row_errors", &self.row_errors())?;
+        let forms: Vec<&Forms> = self.forms().map(|form| form.get_form()).collect();
+        state.serialize_field("forms", &forms)?;
+        state.serialize_field("management_html", &render_or_error(self.management_html()))?;
+        state.serialize_field("empty_form", &render_or_error(self.empty_form_html()))?;
+        state.serialize_field("html", &render_or_error(self.render()))?;
+        state.end()
+    }
+}
@@ -17,9 +17,11 @@ define_enum_kind!(
     Choice => ChoiceField,
     Radio => RadioField,
     Checkbox => CheckboxField,
+    Autocomplete => AutocompleteField,
     Date => DateField,
     Time => TimeField,
     DateTime => DateTimeField,
+    DateRange => DateRangeField,
     Duration => DurationField,
     Color => ColorField,
     Slug => SlugField,
@@ -27,6 +29,7 @@ define_enum_kind!(
     JSON => JSONField,
     IPAddress => IPAddressField,
     Hidden => HiddenField,
+    Honeypot => HoneypotField,
 );
 
 #[derive(Clone, Serialize, Debug)]
@@ -44,6 +47,21 @@ impl CommonFieldConfig for GenericField {
     }
 }
 
+impl GenericField {
+    /// Same as [`TextField::unique_in`], for any field kind.
+    pub fn unique_in<E>(mut self, column: E::Column, msg: &str) -> Self
+    where
+        E: sea_orm::EntityTrait,
+        E::Model: Sync,
+    {
+        self.set_unique_check(crate::forms::options::UniqueCheck::new::<E>(
+            column,
+            (!msg.is_empty()).then(|| msg.to_string()),
+        ));
+        self
+    }
+}
+
 impl FormField for GenericField {
     // --- Getters ---
 
@@ -147,4 +165,19 @@ impl FormField for GenericField {
     fn finalize(&mut self) -> Result<(), String> {
         delegate_to_kind!(mut self, finalize)
     }
+    fn take_storage_ops(&mut self) -> Vec<crate::utils::storage::StorageOp> {
+        delegate_to_kind!(mut self, take_storage_ops)
+    }
+    fn is_honeypot(&self) -> bool {
+        delegate_to_kind!(self, is_honeypot)
+    }
+    fn set_choices(&mut self, choices: crate::forms::options::ChoicePairs) {
+        delegate_to_kind!(mut self, set_choices, choices)
+    }
+    fn set_selected_label(&mut self, label: &str) {
+        delegate_to_kind!(mut self, set_selected_label, label)
+    }
+    fn submitted_value(&self, data: &crate::utils::aliases::StrMap) -> Option<String> {
+        delegate_to_kind!(self, submitted_value, data)
+    }
 }
@@ -4,11 +4,13 @@ pub mod extractor;
 pub mod field;
 pub mod fields;
 pub mod form;
+pub mod formset;
 pub mod generic;
 pub mod model_form;
 pub mod options;
 pub mod prisme;
 pub mod renderer;
+pub mod theme;
 pub mod validator;
 
 pub use base::*;
@@ -16,11 +18,13 @@ pub use extractor::*;
 pub use field::*;
 pub use fields::*;
 pub use form::*;
+pub use formset::*;
 pub use generic::*;
 pub use model_form::*;
 pub use options::*;
 pub use prisme::*;
 pub use renderer::*;
+pub use theme::*;
 pub use validator::*;
 
 /// Associates a form with a SeaORM entity.
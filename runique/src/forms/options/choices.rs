@@ -0,0 +1,56 @@
+//! `ChoiceSource` — deferred `(value, label)` options loaded from the database (`ChoiceField::from_query`),
+//! and `ChoiceEnum` — options taken from the variants of a Rust enum (`ChoiceField::from_enum`).
+use futures_util::future::BoxFuture;
+use sea_orm::{DatabaseConnection, DbErr};
+use std::future::Future;
+use std::sync::Arc;
+
+/// `(value, label)` pairs produced by a [`ChoiceSource`].
+pub type ChoicePairs = Vec<(String, String)>;
+
+/// Enum whose variants are the options of a choice field, implemented by
+/// `#[derive(ChoiceEnum)]` and by the enums of `model!`. The same variant set
+/// feeds [`ChoiceField::from_enum`](crate::forms::fields::ChoiceField::from_enum)
+/// and [`ColumnDef::enum_of`](crate::migration::column::ColumnDef::enum_of).
+pub trait ChoiceEnum {
+    /// Database type name of the enum (PostgreSQL `CREATE TYPE`).
+    const ENUM_NAME: &'static str;
+
+    /// `(stored value, label)` of every variant, in declaration order.
+    fn choices() -> ChoicePairs;
+
+    /// Stored value of this variant, as submitted by the form.
+    fn choice_value(&self) -> &'static str;
+}
+
+type Loader =
+    dyn Fn(DatabaseConnection) -> BoxFuture<'static, Result<ChoicePairs, DbErr>> + Send + Sync;
+
+/// Type-erased query run by [`Forms::prepare`](crate::forms::Forms::prepare)
+/// to fill a choice field with the current rows.
+#[derive(Clone)]
+pub struct ChoiceSource {
+    loader: Arc<Loader>,
+}
+
+impl ChoiceSource {
+    pub fn new<F, Fut>(loader: F) -> Self
+    where
+        F: Fn(DatabaseConnection) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<ChoicePairs, DbErr>> + Send + 'static,
+    {
+        Self {
+            loader: Arc::new(move |db| Box::pin(loader(db))),
+        }
+    }
+
+    pub async fn load(&self, db: &DatabaseConnection) -> Result<ChoicePairs, DbErr> {
+        (self.loader)(db.clone()).await
+    }
+}
+
+impl std::fmt::Debug for ChoiceSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChoiceSource").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,69 @@
+//! `RowLookup` — label of the row a submitted id points to (`AutocompleteField::lookup_in`).
+use futures_util::future::BoxFuture;
+use sea_orm::{
+    DatabaseConnection, DbErr, EntityTrait, Iterable, PrimaryKeyToColumn, QueryFilter, QuerySelect,
+    sea_query::{Alias, Expr, ExprTrait},
+};
+use std::future::Future;
+use std::sync::Arc;
+
+type Lookup = dyn Fn(DatabaseConnection, String) -> BoxFuture<'static, Result<Option<String>, DbErr>>
+    + Send
+    + Sync;
+
+/// Type-erased "which row is this id?" query, run by the async validation pass
+/// ([`Forms::check_lookups`](crate::forms::Forms::check_lookups)): `None` means
+/// the id matches no row, `Some(label)` gives the text to display for it.
+#[derive(Clone)]
+pub struct RowLookup {
+    lookup: Arc<Lookup>,
+}
+
+impl RowLookup {
+    pub fn new<F, Fut>(lookup: F) -> Self
+    where
+        F: Fn(DatabaseConnection, String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Option<String>, DbErr>> + Send + 'static,
+    {
+        Self {
+            lookup: Arc::new(move |db, id| Box::pin(lookup(db, id))),
+        }
+    }
+
+    /// Looks for the row of `E` whose primary key equals the id, labelled by its
+    /// `label` column (the id itself when that column is NULL).
+    pub fn column<E>(label: E::Column) -> Self
+    where
+        E: EntityTrait,
+        E::Model: Sync,
+    {
+        Self::new(move |db, id| async move {
+            let Some(pk) = E::PrimaryKey::iter().next() else {
+                return Ok(None);
+            };
+            let label: Option<Option<String>> = E::find()
+                .select_only()
+                .expr(Expr::col(label).cast_as(Alias::new("TEXT")))
+                .filter(
+                    Expr::col(pk.into_column())
+                        .cast_as(Alias::new("TEXT"))
+                        .eq(id.clone()),
+                )
+                .into_tuple()
+                .one(&db)
+                .await?;
+            Ok(label.map(|l| l.unwrap_or(id)))
+        })
+    }
+
+    /// Label of the row `id` points to, `None` when there is none.
+    pub async fn label(&self, db: &DatabaseConnection, id: &str) -> Result<Option<String>, DbErr> {
+        (self.lookup)(db.clone(), id.to_string()).await
+    }
+}
+
+impl std::fmt::Debug for RowLookup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RowLookup").finish_non_exhaustive()
+    }
+}
@@ -1,6 +1,17 @@
-//! Field validation options — `LengthConstraint` and `BoolChoice`.
+//! Field validation options — `LengthConstraint`, `BoolChoice`, `PasswordRules`, `UniqueCheck`,
+//! `ChoiceSource`, `RowLookup` and `SlugSource`.
 pub mod bool_choice;
+pub mod choices;
 pub mod length;
+pub mod lookup;
+pub mod password;
+pub mod slug;
+pub mod unique;
 
 pub use bool_choice::*;
+pub use choices::*;
 pub use length::*;
+pub use lookup::*;
+pub use password::*;
+pub use slug::*;
+pub use unique::*;
@@ -0,0 +1,156 @@
+//! `PasswordRules` — strength rules for password fields (uppercase, digit, symbol, common list).
+use crate::utils::trad::{t, tf};
+use serde::{Deserialize, Serialize};
+
+/// Most common leaked passwords, lowercase. Checked by [`PasswordRules::reject_common`].
+const COMMON_PASSWORDS: &[&str] = &[
+    "123456",
+    "123456789",
+    "12345678",
+    "1234567890",
+    "12345",
+    "1234567",
+    "111111",
+    "000000",
+    "123123",
+    "654321",
+    "666666",
+    "121212",
+    "112233",
+    "987654321",
+    "password",
+    "password1",
+    "password123",
+    "passw0rd",
+    "p@ssw0rd",
+    "qwerty",
+    "qwerty123",
+    "qwertyuiop",
+    "azerty",
+    "azerty123",
+    "azertyuiop",
+    "abc123",
+    "abcdef",
+    "abcd1234",
+    "a1b2c3d4",
+    "1q2w3e4r",
+    "1qaz2wsx",
+    "zaq12wsx",
+    "iloveyou",
+    "admin",
+    "admin123",
+    "administrator",
+    "root",
+    "toor",
+    "welcome",
+    "welcome1",
+    "letmein",
+    "monkey",
+    "dragon",
+    "master",
+    "sunshine",
+    "princess",
+    "football",
+    "baseball",
+    "superman",
+    "batman",
+    "shadow",
+    "michael",
+    "trustno1",
+    "starwars",
+    "whatever",
+    "freedom",
+    "hello",
+    "hello123",
+    "charlie",
+    "login",
+    "secret",
+    "changeme",
+    "default",
+    "motdepasse",
+    "soleil",
+    "doudou",
+    "chocolat",
+    "bonjour",
+    "marseille",
+    "loulou",
+];
+
+/// Strength rules of a password field. Length bounds stay in
+/// [`TextConfig`](crate::forms::base::TextConfig); these add character classes
+/// and the common-password check.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PasswordRules {
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    pub reject_common: bool,
+}
+
+impl PasswordRules {
+    /// Rules read from the environment, for code running outside a form (CLI):
+    /// `PASSWORD_REQUIRE_UPPERCASE`, `PASSWORD_REQUIRE_DIGIT`,
+    /// `PASSWORD_REQUIRE_SYMBOL`, `PASSWORD_REJECT_COMMON` (`true` / `false`,
+    /// default: `false`).
+    pub fn from_env() -> Self {
+        let flag = |key: &str| {
+            std::env::var(key)
+                .map(|v| v.parse().unwrap_or(false))
+                .unwrap_or(false)
+        };
+        Self {
+            require_uppercase: flag("PASSWORD_REQUIRE_UPPERCASE"),
+            require_digit: flag("PASSWORD_REQUIRE_DIGIT"),
+            require_symbol: flag("PASSWORD_REQUIRE_SYMBOL"),
+            reject_common: flag("PASSWORD_REJECT_COMMON"),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// First broken rule, as a translated error message.
+    pub fn check(&self, value: &str) -> Option<String> {
+        if self.require_uppercase && !value.chars().any(char::is_uppercase) {
+            return Some(t("forms.password_no_uppercase").into_owned());
+        }
+        if self.require_digit && !value.chars().any(|c| c.is_ascii_digit()) {
+            return Some(t("forms.password_no_digit").into_owned());
+        }
+        if self.require_symbol && value.chars().all(char::is_alphanumeric) {
+            return Some(t("forms.password_no_symbol").into_owned());
+        }
+        if self.reject_common && is_common(value) {
+            return Some(t("forms.password_too_common").into_owned());
+        }
+        None
+    }
+
+    /// Human-readable list of the rules, for rendering next to the field.
+    pub fn requirements(&self, min_length: Option<u32>) -> Vec<String> {
+        let mut out = Vec::new();
+        if let Some(min) = min_length {
+            out.push(tf("forms.password_req_min_length", &[&min]));
+        }
+        if self.require_uppercase {
+            out.push(t("forms.password_req_uppercase").into_owned());
+        }
+        if self.require_digit {
+            out.push(t("forms.password_req_digit").into_owned());
+        }
+        if self.require_symbol {
+            out.push(t("forms.password_req_symbol").into_owned());
+        }
+        if self.reject_common {
+            out.push(t("forms.password_req_not_common").into_owned());
+        }
+        out
+    }
+}
+
+/// Case-insensitive lookup of the trimmed value in [`COMMON_PASSWORDS`].
+fn is_common(value: &str) -> bool {
+    let normalized = value.trim().to_lowercase();
+    COMMON_PASSWORDS.contains(&normalized.as_str())
+}
@@ -0,0 +1,43 @@
+//! `SlugSource` — slug derived from another field when left empty (`SlugField::from`).
+use crate::utils::slug::{slugify, truncate_slug};
+
+/// Field whose value feeds an empty slug, filled by [`Forms::is_valid`](crate::forms::Forms::is_valid).
+#[derive(Clone, Debug)]
+pub struct SlugSource {
+    pub field: String,
+    pub max_length: Option<usize>,
+}
+
+impl SlugSource {
+    pub fn new(field: &str, max_length: Option<usize>) -> Self {
+        Self {
+            field: field.to_string(),
+            max_length,
+        }
+    }
+
+    /// Candidate slugs of `text` in preference order — bare, then `-2` up to
+    /// `-max_suffix` — and the prefix they all share.
+    pub fn candidates(&self, text: &str, max_suffix: u32) -> (String, Vec<String>) {
+        let tail = format!("-{max_suffix}");
+        let longest = self.build(text, Some(max_suffix));
+        let prefix = longest.strip_suffix(&tail).unwrap_or(&longest).to_string();
+        let mut all = vec![self.build(text, None)];
+        all.extend((2..=max_suffix).map(|n| self.build(text, Some(n))));
+        (prefix, all)
+    }
+
+    /// Slug of `text`; `suffix` (collision counter) is appended as `-N`
+    /// within `max_length`.
+    pub fn build(&self, text: &str, suffix: Option<u32>) -> String {
+        let base = slugify(text);
+        let tail = suffix.map(|n| format!("-{n}")).unwrap_or_default();
+        match self.max_length {
+            Some(max) => {
+                let base = truncate_slug(&base, max.saturating_sub(tail.len()));
+                format!("{base}{tail}")
+            }
+            None => format!("{base}{tail}"),
+        }
+    }
+}
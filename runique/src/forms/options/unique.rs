@@ -0,0 +1,94 @@
+//! `UniqueCheck` — database uniqueness lookup attached to a field (`unique_in::<Entity>(column)`).
+use futures_util::future::BoxFuture;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, Iterable, PaginatorTrait,
+    PrimaryKeyToColumn, QueryFilter, QuerySelect, Value,
+};
+use std::sync::Arc;
+
+type Lookup = dyn Fn(DatabaseConnection, String, Option<Value>) -> BoxFuture<'static, Result<bool, DbErr>>
+    + Send
+    + Sync;
+
+type PrefixLookup = dyn Fn(DatabaseConnection, String, Option<Value>) -> BoxFuture<'static, Result<Vec<String>, DbErr>>
+    + Send
+    + Sync;
+
+/// Type-erased "does this value already exist?" query, run by the async
+/// validation pass ([`Forms::check_unique`](crate::forms::Forms::check_unique)).
+#[derive(Clone)]
+pub struct UniqueCheck {
+    lookup: Arc<Lookup>,
+    prefix_lookup: Arc<PrefixLookup>,
+    pub message: Option<String>,
+}
+
+impl UniqueCheck {
+    /// Looks for a row of `E` whose `column` equals the submitted value.
+    pub fn new<E>(column: E::Column, message: Option<String>) -> Self
+    where
+        E: EntityTrait,
+        E::Model: Sync,
+    {
+        Self {
+            lookup: Arc::new(move |db, value, excluded| {
+                Box::pin(async move {
+                    let mut query = E::find().filter(column.eq(value));
+                    if let (Some(pk), Some(excluded)) = (E::PrimaryKey::iter().next(), excluded) {
+                        query = query.filter(pk.into_column().ne(excluded));
+                    }
+                    let n = query.count(&db).await?;
+                    Ok(n > 0)
+                })
+            }),
+            prefix_lookup: Arc::new(move |db, prefix, excluded| {
+                Box::pin(async move {
+                    let mut query = E::find()
+                        .select_only()
+                        .column(column)
+                        .filter(column.starts_with(prefix));
+                    if let (Some(pk), Some(excluded)) = (E::PrimaryKey::iter().next(), excluded) {
+                        query = query.filter(pk.into_column().ne(excluded));
+                    }
+                    query.into_tuple::<String>().all(&db).await
+                })
+            }),
+            message,
+        }
+    }
+
+    /// `true` when a row already holds `value`.
+    pub async fn exists(&self, db: &DatabaseConnection, value: &str) -> Result<bool, DbErr> {
+        (self.lookup)(db.clone(), value.to_string(), None).await
+    }
+
+    /// Same as [`exists`](Self::exists), ignoring the row whose primary key is
+    /// `pk` — the row being edited.
+    pub async fn exists_except(
+        &self,
+        db: &DatabaseConnection,
+        value: &str,
+        pk: Option<Value>,
+    ) -> Result<bool, DbErr> {
+        (self.lookup)(db.clone(), value.to_string(), pk).await
+    }
+
+    /// Every value starting with `prefix` (the row `pk` aside), in one query —
+    /// used to pick a free `-N` suffix for a derived slug.
+    pub async fn values_with_prefix(
+        &self,
+        db: &DatabaseConnection,
+        prefix: &str,
+        pk: Option<Value>,
+    ) -> Result<Vec<String>, DbErr> {
+        (self.prefix_lookup)(db.clone(), prefix.to_string(), pk).await
+    }
+}
+
+impl std::fmt::Debug for UniqueCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UniqueCheck")
+            .field("message", &self.message)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,137 @@
+//! `FormTheme` — CSS framework used by the `render_form` Tera function.
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Class set emitted by `render_form` for the whole form and each field.
+///
+/// `Plain` keeps the neutral `runique-*` hooks and renders fields through their
+/// own templates; `Bootstrap5` and `Tailwind` generate the label/input/error
+/// markup themselves with the framework's idiomatic classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FormTheme {
+    #[default]
+    Plain,
+    Bootstrap5,
+    Tailwind,
+}
+
+/// Class strings of a theme. Empty string = no `class` attribute.
+#[derive(Debug, Clone, Copy)]
+pub struct ThemeClasses {
+    /// Form container, `layout="stacked"`.
+    pub form: &'static str,
+    /// Form container, `layout="inline"`.
+    pub form_inline: &'static str,
+    /// Form-level errors list (`<ul>`).
+    pub form_errors: &'static str,
+    /// Field wrapper (`<div data-field>`).
+    pub wrapper: &'static str,
+    pub label: &'static str,
+    pub input: &'static str,
+    pub select: &'static str,
+    /// Added to the input when the field carries an error.
+    pub invalid: &'static str,
+    pub error: &'static str,
+    pub help: &'static str,
+    /// Checkbox / radio: wrapper, input and label (Bootstrap `form-check`).
+    pub check: &'static str,
+    pub check_input: &'static str,
+    pub check_label: &'static str,
+}
+
+const PLAIN: ThemeClasses = ThemeClasses {
+    form: "runique-form runique-form--stacked",
+    form_inline: "runique-form runique-form--inline",
+    form_errors: "runique-form__errors",
+    wrapper: "runique-field",
+    label: "",
+    input: "",
+    select: "",
+    invalid: "",
+    error: "",
+    help: "",
+    check: "",
+    check_input: "",
+    check_label: "",
+};
+
+const BOOTSTRAP5: ThemeClasses = ThemeClasses {
+    form: "runique-form",
+    form_inline: "runique-form row row-cols-lg-auto g-3 align-items-end",
+    form_errors: "alert alert-danger list-unstyled",
+    wrapper: "mb-3",
+    label: "form-label",
+    input: "form-control",
+    select: "form-select",
+    invalid: "is-invalid",
+    error: "invalid-feedback d-block",
+    help: "form-text",
+    check: "form-check",
+    check_input: "form-check-input",
+    check_label: "form-check-label",
+};
+
+const TAILWIND: ThemeClasses = ThemeClasses {
+    form: "runique-form space-y-4",
+    form_inline: "runique-form flex flex-wrap items-end gap-4",
+    form_errors: "rounded-md border border-red-300 bg-red-50 p-3 text-sm text-red-700",
+    wrapper: "flex flex-col gap-1",
+    label: "block text-sm font-medium text-gray-700",
+    input: "block w-full rounded-md border border-gray-300 px-3 py-2 text-sm shadow-sm focus:border-indigo-500 focus:outline-none focus:ring-1 focus:ring-indigo-500",
+    select: "block w-full rounded-md border border-gray-300 bg-white px-3 py-2 text-sm shadow-sm focus:border-indigo-500 focus:outline-none focus:ring-1 focus:ring-indigo-500",
+    invalid: "border-red-500 focus:border-red-500 focus:ring-red-500",
+    error: "text-sm text-red-600",
+    help: "text-sm text-gray-500",
+    check: "flex items-center gap-2",
+    check_input: "h-4 w-4 rounded border-gray-300 text-indigo-600 focus:ring-indigo-500",
+    check_label: "text-sm text-gray-700",
+};
+
+impl FormTheme {
+    /// Lowercase name, as accepted by `render_form(theme=…)` and `FORM_THEME`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Plain => "plain",
+            Self::Bootstrap5 => "bootstrap5",
+            Self::Tailwind => "tailwind",
+        }
+    }
+
+    pub fn classes(&self) -> &'static ThemeClasses {
+        match self {
+            Self::Plain => &PLAIN,
+            Self::Bootstrap5 => &BOOTSTRAP5,
+            Self::Tailwind => &TAILWIND,
+        }
+    }
+
+    /// `true` when fields keep their own template markup (only the wrapper is themed).
+    pub fn uses_field_templates(&self) -> bool {
+        matches!(self, Self::Plain)
+    }
+
+    /// Reads `FORM_THEME` (`plain`, `bootstrap5`, `tailwind`). Unknown or missing → `Plain`.
+    pub fn from_env() -> Self {
+        std::env::var("FORM_THEME")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for FormTheme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "plain" => Ok(Self::Plain),
+            "bootstrap5" | "bootstrap" => Ok(Self::Bootstrap5),
+            "tailwind" => Ok(Self::Tailwind),
+            other => Err(format!(
+                "unknown form theme '{}' (expected \"plain\", \"bootstrap5\" or \"tailwind\")",
+                other
+            )),
+        }
+    }
+}
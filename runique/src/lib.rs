@@ -5,6 +5,7 @@
 // ---------------------------------------------------------------------------*
 pub mod app;
 pub mod auth;
+pub mod cache;
 pub mod config;
 pub mod context;
 #[cfg(feature = "orm")]
@@ -14,11 +15,16 @@ pub mod flash;
 pub mod forms;
 pub mod macros;
 pub mod migration;
+#[cfg(feature = "orm")]
+pub mod signals;
+#[cfg(feature = "orm")]
+pub mod tasks;
 
 pub mod admin;
 pub mod errors;
 pub mod middleware;
 
+pub mod testing;
 pub mod utils;
 
 pub use forms::Prisme;
@@ -45,7 +51,7 @@ pub use tower_http;
 pub use tower_sessions;
 pub use uuid;
 
-pub use derive_form::{extend, model};
+pub use derive_form::{ChoiceEnum, extend, model};
 pub use dotenvy;
 pub use pulldown_cmark;
 
@@ -71,6 +77,8 @@ pub mod prelude {
     // ========================================================================
     pub use crate::app::{RuniqueApp, RuniqueAppBuilder};
     pub use crate::config::app::RuniqueConfig;
+    pub use crate::context::sse::{self, sse_stream};
+    pub use crate::context::ws::{self, WsUpgrade, ws};
     pub use crate::context::{AppError, Request};
     pub use crate::engine::RuniqueEngine;
     pub use crate::flash::{FlashMessage, Message, MessageLevel};
@@ -78,7 +86,7 @@ pub mod prelude {
         Forms, Prisme,
         field::{FormField, RuniqueForm, SaveContext},
         fields::{
-            CheckboxField, DateField, DurationField, RadioField, TimeField,
+            CheckboxField, DateField, DateRangeField, DurationField, RadioField, TimeField,
             boolean::BooleanField,
             choice::{ChoiceField, ChoiceOption},
             datetime::DateTimeField,
@@ -90,11 +98,13 @@ pub mod prelude {
         },
         generic::{FieldKind, GenericField},
         model_form::ModelForm,
+        options::ChoiceEnum,
+        theme::FormTheme,
     };
     pub use crate::migration::schema::ModelSchema;
     pub use crate::utils::aliases::*;
     pub use crate::utils::config::Pk;
-    pub use derive_form::{extend, form, model};
+    pub use derive_form::{ChoiceEnum, extend, form, model};
 
     // ========================================================================
     // CONFIGURATION
@@ -108,13 +118,15 @@ pub mod prelude {
     // ========================================================================
     // UTILS
     // ========================================================================
+    pub use crate::cache::{Cache, CacheHandle};
     pub use crate::macros::{
-        RouterExt, register_name_url, register_pending, reverse, reverse_with_parameters,
+        RouteGroup, RouterExt, group, register_name_url, register_pending, reverse,
+        reverse_with_parameters, reverse_with_query,
     };
     pub use crate::utils::csp_nonce::*;
     pub use crate::{
-        context_update, error, flash_now, impl_form_access, impl_objects, info, search,
-        search_cond, success, urlpatterns, view, warning,
+        context_update, error, flash_now, impl_form_access, impl_objects, impl_signals, info,
+        search, search_cond, success, urlpatterns, view, warning,
     };
 
     // ========================================================================
@@ -135,8 +147,16 @@ pub mod prelude {
         handle_forgot_password, handle_password_reset,
     };
     pub use crate::middleware::{
-        allowed_hosts::*, cache::*, config::*, csp::*, csrf::*, errors::*, permissions_policy::*,
-        rate_limit::RateLimiter, trusted_proxies::*,
+        allowed_hosts::*,
+        cache::*,
+        config::*,
+        csp::*,
+        csrf::*,
+        errors::*,
+        permissions_policy::*,
+        rate_limit::{RateLimitKey, RateLimiter},
+        security_headers::*,
+        trusted_proxies::*,
     };
 
     // ========================================================================
@@ -158,6 +178,8 @@ pub mod prelude {
     #[cfg(feature = "orm")]
     pub use crate::db::{DatabaseConfig, DatabaseConfigBuilder, DatabaseEngine};
     #[cfg(feature = "orm")]
+    pub use crate::tasks::{Task, TaskError};
+    #[cfg(feature = "orm")]
     pub use sea_orm::{
         self, ActiveModelBehavior, ActiveModelTrait, ColumnTrait, ConnectOptions, Database,
         DatabaseConnection, DbErr, EntityTrait, ModelTrait, NotSet, QueryFilter, QueryOrder,
@@ -197,6 +219,7 @@ pub mod prelude {
         AutoConfig, Manual, PasswordConfig, hash, password_init, verify,
     };
     pub use crate::utils::reset_token;
+    pub use crate::utils::sanitizer::{SanitizePolicy, register_sanitize_policy};
     pub use hmac::{Hmac, Mac};
     pub use sha2::Sha256;
 
@@ -224,14 +247,17 @@ pub mod prelude {
         config::config_admin::AdminConfig,
         helper::{
             dyn_form::DynForm,
+            list_filter::column_filter_condition,
             resource_entry::{
-                CountFn, CreateFn, DeleteFn, FilterFn, FormBuilder, GetFn, GroupAction, ListFn,
-                ListParams, M2mFieldOptions, M2mLoaderFn, ResourceEntry, SortDir, UpdateFn,
+                AutocompleteFn, BulkAction, ComputedField, CountFn, CreateFn, DeleteFn, FilterFn,
+                FilteredCountFn, FormBuilder, GetFn, GroupAction, ListFn, ListParams,
+                M2mFieldOptions, M2mLoaderFn, ResourceEntry, SortDir, TxDeleteFn, TxSaveFn,
+                UpdateFn,
             },
         },
         registry::AdminRegistry,
         resource::{
-            AdminIdType, AdminResource, ColumnFilter, CrudOperation, DisplayConfig,
+            AdminIdType, AdminResource, ColumnFilter, CrudOperation, DisplayConfig, InlineDef,
             ResourcePermissions,
         },
         router::admin_router::AdminState,
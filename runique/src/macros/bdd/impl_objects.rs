@@ -5,6 +5,10 @@
 /// Generates a constant field `objects` allowing the syntax
 /// `Entity::objects.filter(...).all(&db).await`
 ///
+/// With `soft_delete = Column::DeletedAt`, the manager is a
+/// [`SoftObjects`](crate::macros::bdd::soft_delete::SoftObjects): deleted rows
+/// are hidden by default and `delete()` stamps the column instead of removing.
+///
 #[doc = include_str!("../../../doc-tests/macro_db/impl_objects.md")]
 #[macro_export]
 macro_rules! impl_objects {
@@ -15,4 +19,17 @@ macro_rules! impl_objects {
                 $crate::macros::bdd::objects::Objects::new();
         }
     };
+    ($entity:ty, soft_delete = $column:expr) => {
+        impl $crate::macros::bdd::soft_delete::SoftDelete for $entity {
+            fn deleted_at_column() -> <Self as $crate::sea_orm::EntityTrait>::Column {
+                $column
+            }
+        }
+
+        impl $entity {
+            #[allow(non_upper_case_globals)]
+            pub const objects: $crate::macros::bdd::soft_delete::SoftObjects<Self> =
+                $crate::macros::bdd::soft_delete::SoftObjects::new();
+        }
+    };
 }
@@ -0,0 +1,106 @@
+//! Macro `impl_signals!` — an `ActiveModelBehavior` emitting the lifecycle signals.
+
+/// Implements `ActiveModelBehavior` for an `ActiveModel` so that `insert`, `update`,
+/// `save` and `delete` emit [`Signal`](crate::signals::Signal)s to the handlers
+/// connected with [`signals::connect`](crate::signals::connect).
+///
+/// Used in place of `impl ActiveModelBehavior for ActiveModel {}` — `model!` and the
+/// migration-generated entities already do it. Bulk statements (`update_many`,
+/// `delete_many`, soft delete) bypass the `ActiveModel` and emit nothing.
+///
+/// `auto_now` / `auto_now_update` list the timestamp columns stamped before the
+/// write (see [`touch_timestamps`](crate::db::touch_timestamps)): on insert for the
+/// former, on every save for the latter, unless the column was explicitly `Set`.
+///
+/// ```rust,ignore
+/// #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// pub enum Relation {}
+///
+/// runique::impl_signals!(ActiveModel);
+/// // or, with timestamps
+/// runique::impl_signals!(
+///     ActiveModel,
+///     auto_now = [Column::CreatedAt],
+///     auto_now_update = [Column::UpdatedAt],
+/// );
+/// ```
+#[macro_export]
+macro_rules! impl_signals {
+    ($active_model:ty $(,)?) => {
+        $crate::impl_signals!($active_model, auto_now = [], auto_now_update = []);
+    };
+    ($active_model:ty, auto_now = [$($now:expr),* $(,)?] $(,)?) => {
+        $crate::impl_signals!($active_model, auto_now = [$($now),*], auto_now_update = []);
+    };
+    ($active_model:ty, auto_now_update = [$($update:expr),* $(,)?] $(,)?) => {
+        $crate::impl_signals!($active_model, auto_now = [], auto_now_update = [$($update),*]);
+    };
+    (
+        $active_model:ty,
+        auto_now = [$($now:expr),* $(,)?],
+        auto_now_update = [$($update:expr),* $(,)?] $(,)?
+    ) => {
+        #[$crate::async_trait::async_trait]
+        impl $crate::sea_orm::ActiveModelBehavior for $active_model {
+            async fn before_save<C>(
+                self,
+                _db: &C,
+                insert: bool,
+            ) -> ::std::result::Result<Self, $crate::sea_orm::DbErr>
+            where
+                C: $crate::sea_orm::ConnectionTrait,
+            {
+                let mut this = self;
+                $crate::db::touch_timestamps(
+                    &mut this,
+                    insert,
+                    &[$($now),*],
+                    &[$($update),*],
+                )?;
+                $crate::signals::emit_active($crate::signals::Signal::PreSave, &this, insert)
+                    .await?;
+                Ok(this)
+            }
+
+            async fn after_save<C>(
+                model: <<Self as $crate::sea_orm::ActiveModelTrait>::Entity as $crate::sea_orm::EntityTrait>::Model,
+                _db: &C,
+                insert: bool,
+            ) -> ::std::result::Result<
+                <<Self as $crate::sea_orm::ActiveModelTrait>::Entity as $crate::sea_orm::EntityTrait>::Model,
+                $crate::sea_orm::DbErr,
+            >
+            where
+                C: $crate::sea_orm::ConnectionTrait,
+            {
+                $crate::signals::emit_model($crate::signals::Signal::PostSave, &model, insert)
+                    .await?;
+                Ok(model)
+            }
+
+            async fn before_delete<C>(
+                self,
+                _db: &C,
+            ) -> ::std::result::Result<Self, $crate::sea_orm::DbErr>
+            where
+                C: $crate::sea_orm::ConnectionTrait,
+            {
+                $crate::signals::emit_active($crate::signals::Signal::PreDelete, &self, false)
+                    .await?;
+                Ok(self)
+            }
+
+            async fn after_delete<C>(
+                self,
+                _db: &C,
+            ) -> ::std::result::Result<Self, $crate::sea_orm::DbErr>
+            where
+                C: $crate::sea_orm::ConnectionTrait,
+            {
+                $crate::signals::emit_active($crate::signals::Signal::PostDelete, &self, false)
+                    .await?;
+                Ok(self)
+            }
+        }
+    };
+}
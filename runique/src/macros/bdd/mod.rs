@@ -1,5 +1,8 @@
-//! DB macros and helpers — `impl_objects!`, SeaORM entity manager Django-style.
+//! DB macros and helpers — `impl_objects!`, SeaORM entity manager Django-style,
+//! and `impl_signals!`.
 pub mod filter;
 pub mod impl_objects;
+pub mod impl_signals;
 pub mod objects;
 pub mod query;
+pub mod soft_delete;
@@ -59,11 +59,86 @@
 /// ```
 use super::query::RuniqueQueryBuilder;
 use crate::context::template::Request;
+use crate::db::AsConnection;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::response::Response;
-use sea_orm::{ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbBackend,
+    DbErr, EntityTrait, IntoActiveModel, Iterable, QueryFilter, QuerySelect, Related, Select,
+    SqlErr, TransactionTrait,
+};
 use std::marker::PhantomData;
+use thiserror::Error;
+
+/// Errors returned by the write helpers of [`Objects`] (`get_or_create`, `bulk_create`).
+#[derive(Debug, Error)]
+pub enum ObjectsError {
+    /// The insert hit a unique constraint (e.g. a concurrent insert of the same row).
+    #[error("Unique constraint violated: {0}")]
+    UniqueViolation(String),
+    /// The lookup matched more than one row.
+    #[error("Lookup returned more than one row")]
+    MultipleObjectsReturned,
+    #[error(transparent)]
+    Db(DbErr),
+}
+
+impl From<DbErr> for ObjectsError {
+    fn from(err: DbErr) -> Self {
+        match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(msg)) => ObjectsError::UniqueViolation(msg),
+            _ => ObjectsError::Db(err),
+        }
+    }
+}
+
+/// Conflict policy of [`Objects::bulk_create_with`].
+///
+/// `target` lists the columns of the unique constraint (or primary key) the
+/// conflict is detected on; MySQL ignores it and uses every unique key.
+#[derive(Debug, Clone)]
+pub enum BulkConflict<E: EntityTrait> {
+    /// A conflicting row aborts the whole insert (plain `INSERT`).
+    Error,
+    /// Conflicting rows are skipped (`ON CONFLICT DO NOTHING`).
+    Ignore { target: Vec<E::Column> },
+    /// Conflicting rows get `columns` overwritten with the new values
+    /// (`ON CONFLICT DO UPDATE`).
+    Update {
+        target: Vec<E::Column>,
+        columns: Vec<E::Column>,
+    },
+}
+
+impl<E: EntityTrait> BulkConflict<E> {
+    fn to_on_conflict(&self) -> Option<OnConflict> {
+        match self {
+            BulkConflict::Error => None,
+            BulkConflict::Ignore { target } => {
+                let mut on_conflict = OnConflict::columns(target.iter().copied());
+                // MySQL has no DO NOTHING: sea-query writes `pk = pk` instead.
+                on_conflict.do_nothing_on(E::PrimaryKey::iter());
+                Some(on_conflict)
+            }
+            BulkConflict::Update { target, columns } => Some(
+                OnConflict::columns(target.iter().copied())
+                    .update_columns(columns.iter().copied())
+                    .to_owned(),
+            ),
+        }
+    }
+}
+
+/// Bind parameter ceiling of one statement: `SQLITE_MAX_VARIABLE_NUMBER`
+/// (3.32+) for SQLite, the 16-bit placeholder counter for Postgres / MySQL.
+fn max_params(backend: DbBackend) -> usize {
+    match backend {
+        DbBackend::Sqlite => 32_766,
+        _ => 65_535,
+    }
+}
 
 /// Django-style ORM manager for entities
 ///
@@ -89,6 +164,12 @@ impl<E: EntityTrait> Objects<E> {
         RuniqueQueryBuilder::new(E::find())
     }
 
+    /// Every row, read from the primary of a [`RoutedDb`](crate::db::RoutedDb) —
+    /// see [`RuniqueQueryBuilder::force_primary`].
+    pub fn force_primary(&self) -> RuniqueQueryBuilder<E> {
+        RuniqueQueryBuilder::new(E::find()).force_primary()
+    }
+
     // In impl<E: EntityTrait> Objects<E>
 
     // === EXISTING (keeping current filter/exclude) ===
@@ -127,63 +208,239 @@ impl<E: EntityTrait> Objects<E> {
 
     pub async fn get(
         &self,
-        db: &DatabaseConnection,
+        db: &impl AsConnection,
         id: impl Into<<E::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>,
     ) -> Result<E::Model, DbErr> {
         E::find_by_id(id)
-            .one(db)
+            .one(db.as_connection())
             .await?
             .ok_or(DbErr::RecordNotFound("Record not found".to_string()))
     }
 
     pub async fn get_optional(
         &self,
-        db: &DatabaseConnection,
+        db: &impl AsConnection,
         id: impl Into<<E::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>,
     ) -> Result<Option<E::Model>, DbErr> {
-        E::find_by_id(id).one(db).await
+        E::find_by_id(id).one(db.as_connection()).await
     }
 
-    pub async fn count(&self, db: &DatabaseConnection) -> Result<u64, DbErr>
+    pub async fn count(&self, db: &impl AsConnection) -> Result<u64, DbErr>
     where
         E::Model: Sync,
     {
         use sea_orm::PaginatorTrait;
-        E::find().count(db).await
+        E::find().count(db.as_connection()).await
+    }
+
+    /// Every row with its to-one related row, in two queries — see
+    /// [`select_related`](crate::db::select_related).
+    pub async fn select_related<R>(
+        &self,
+        db: &impl AsConnection,
+        related: impl Into<Select<R>>,
+    ) -> Result<Vec<(E::Model, Option<R::Model>)>, DbErr>
+    where
+        E: Related<R>,
+        E::Model: Sync,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+    {
+        self.all().select_related(db, related).await
     }
+
+    /// Every row with its to-many related rows, in two queries — see
+    /// [`prefetch_related`](crate::db::prefetch_related).
+    pub async fn prefetch_related<R>(
+        &self,
+        db: &impl AsConnection,
+        related: impl Into<Select<R>>,
+    ) -> Result<Vec<(E::Model, Vec<R::Model>)>, DbErr>
+    where
+        E: Related<R>,
+        E::Model: Sync,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+    {
+        self.all().prefetch_related(db, related).await
+    }
+
+    /// Returns the row matching `lookup`, or inserts one built from `lookup` + `defaults`.
+    /// The boolean is `true` when the row was created.
+    ///
+    /// Lookup and insert share one transaction. `defaults` only feed the insert
+    /// and override a lookup value set on the same column.
+    ///
+    /// ```rust,ignore
+    /// let (user, created) = users::Entity::objects
+    ///     .get_or_create(
+    ///         &db,
+    ///         [(users::Column::Username, "bob".into())],
+    ///         [(users::Column::Age, 30.into())],
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn get_or_create<L, D>(
+        &self,
+        db: &impl AsConnection,
+        lookup: L,
+        defaults: D,
+    ) -> Result<(E::Model, bool), ObjectsError>
+    where
+        L: IntoIterator<Item = (E::Column, sea_orm::Value)>,
+        D: IntoIterator<Item = (E::Column, sea_orm::Value)>,
+        E::Model: IntoActiveModel<E::ActiveModel>,
+        E::ActiveModel: ActiveModelBehavior + Send,
+    {
+        get_or_create_in(db, E::find(), lookup, defaults).await
+    }
+
+    /// Inserts `models` with multi-row `INSERT`s of at most `batch_size` rows,
+    /// all inside one transaction. Returns the number of rows inserted.
+    ///
+    /// The batch is shrunk when needed so a statement stays under the
+    /// backend's bind parameter limit; `batch_size == 0` means "as large as
+    /// the limit allows". An empty `models` is a no-op returning `0`.
+    ///
+    /// Signals are not emitted (see [`impl_signals!`](crate::impl_signals)).
+    pub async fn bulk_create(
+        &self,
+        db: &impl AsConnection,
+        models: Vec<E::ActiveModel>,
+        batch_size: usize,
+    ) -> Result<u64, ObjectsError> {
+        bulk_create_in::<E>(db, models, batch_size, BulkConflict::Error).await
+    }
+
+    /// Same as [`bulk_create`](Self::bulk_create) with a conflict policy:
+    /// skip conflicting rows or turn them into updates (upsert).
+    /// Skipped rows are not counted.
+    pub async fn bulk_create_with(
+        &self,
+        db: &impl AsConnection,
+        models: Vec<E::ActiveModel>,
+        batch_size: usize,
+        on_conflict: BulkConflict<E>,
+    ) -> Result<u64, ObjectsError> {
+        bulk_create_in::<E>(db, models, batch_size, on_conflict).await
+    }
+
     pub async fn get_or_404(
         &self,
-        db: &DatabaseConnection,
+        db: &impl AsConnection,
         id: impl Into<<E::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>,
         ctx: &Request,
         error_msg: &str,
     ) -> Result<E::Model, Response> {
         match self.get_optional(db, id).await {
             Ok(Some(entity)) => Ok(entity),
-            Ok(None) => {
-                let mut context = ctx.context.clone();
-                context.insert("title", "Page not found");
-                context.insert("error_message", error_msg);
-                match ctx.engine.tera.render("404.html", &context) {
-                    Ok(html) => Err(axum::response::Html(html).into_response()),
-                    Err(e) => {
-                        tracing::error!("Tera render 404 error: {}", e);
-                        Err((StatusCode::INTERNAL_SERVER_ERROR, "Internal error").into_response())
-                    }
-                }
-            }
-            Err(_) => {
-                let mut context = ctx.context.clone();
-                context.insert("title", "Server error");
-                context.insert("error_message", "Database error");
-                match ctx.engine.tera.render("500.html", &context) {
-                    Ok(html) => Err(axum::response::Html(html).into_response()),
-                    Err(e) => {
-                        tracing::error!("Tera render 500 error: {}", e);
-                        Err((StatusCode::INTERNAL_SERVER_ERROR, "Internal error").into_response())
-                    }
-                }
-            }
+            Ok(None) => Err(lookup_error_page(ctx, error_msg, false)),
+            Err(_) => Err(lookup_error_page(ctx, error_msg, true)),
+        }
+    }
+}
+
+/// Shared body of `get_or_create`: looks `lookup` up inside `base`, inserts
+/// `lookup` + `defaults` when nothing matches.
+pub(super) async fn get_or_create_in<E, L, D>(
+    db: &impl AsConnection,
+    base: Select<E>,
+    lookup: L,
+    defaults: D,
+) -> Result<(E::Model, bool), ObjectsError>
+where
+    E: EntityTrait,
+    L: IntoIterator<Item = (E::Column, sea_orm::Value)>,
+    D: IntoIterator<Item = (E::Column, sea_orm::Value)>,
+    E::Model: IntoActiveModel<E::ActiveModel>,
+    E::ActiveModel: ActiveModelBehavior + Send,
+{
+    let lookup: Vec<(E::Column, sea_orm::Value)> = lookup.into_iter().collect();
+
+    let condition = lookup.iter().fold(Condition::all(), |cond, (col, val)| {
+        cond.add(col.eq(val.clone()))
+    });
+
+    let txn = db.as_connection().begin().await?;
+
+    let mut found = base.filter(condition).limit(2).all(&txn).await?;
+    match found.len() {
+        0 => {}
+        1 => {
+            txn.commit().await?;
+            return Ok((found.remove(0), false));
+        }
+        _ => return Err(ObjectsError::MultipleObjectsReturned),
+    }
+
+    let mut active = E::ActiveModel::default();
+    for (col, val) in lookup.into_iter().chain(defaults) {
+        active.try_set(col, val)?;
+    }
+    let model = active.insert(&txn).await?;
+    txn.commit().await?;
+
+    Ok((model, true))
+}
+
+/// Shared body of `bulk_create`: chunks `models` under the parameter limit
+/// and inserts each chunk inside a single transaction.
+pub(super) async fn bulk_create_in<E: EntityTrait>(
+    db: &impl AsConnection,
+    models: Vec<E::ActiveModel>,
+    batch_size: usize,
+    on_conflict: BulkConflict<E>,
+) -> Result<u64, ObjectsError> {
+    if models.is_empty() {
+        return Ok(0);
+    }
+
+    let conn = db.as_connection();
+    let columns = E::Column::iter().count().max(1);
+    let limit = (max_params(conn.get_database_backend()) / columns).max(1);
+    let batch = match batch_size {
+        0 => limit,
+        n => n.min(limit),
+    };
+    let on_conflict = on_conflict.to_on_conflict();
+
+    let txn = conn.begin().await?;
+    let mut inserted = 0;
+    let mut models = models.into_iter().peekable();
+    while models.peek().is_some() {
+        let chunk: Vec<E::ActiveModel> = models.by_ref().take(batch).collect();
+        let mut insert = E::insert_many(chunk);
+        if let Some(on_conflict) = &on_conflict {
+            insert = insert.on_conflict(on_conflict.clone());
+        }
+        inserted += match insert.exec_without_returning(&txn).await {
+            Ok(n) => n,
+            // Every row of the chunk was skipped by ON CONFLICT DO NOTHING.
+            Err(DbErr::RecordNotInserted) => 0,
+            Err(err) => return Err(err.into()),
+        };
+    }
+    txn.commit().await?;
+
+    Ok(inserted)
+}
+
+/// Error page of `get_or_404`: `404.html` when the lookup found nothing
+/// (`db_error == false`), `500.html` on a database error.
+pub(super) fn lookup_error_page(ctx: &Request, error_msg: &str, db_error: bool) -> Response {
+    let (template, title, message) = if db_error {
+        ("500.html", "Server error", "Database error")
+    } else {
+        ("404.html", "Page not found", error_msg)
+    };
+    let mut context = ctx.context.clone();
+    context.insert("title", title);
+    context.insert("error_message", message);
+    match ctx.engine.tera.current().render(template, &context) {
+        Ok(html) => axum::response::Html(html).into_response(),
+        Err(e) => {
+            tracing::error!("Tera render {} error: {}", template, e);
+            (StatusCode::INTERNAL_SERVER_ERROR, "Internal error").into_response()
         }
     }
 }
@@ -369,6 +626,80 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_objects_get_or_create_cree_puis_retrouve() -> Result<(), ObjectsError> {
+        let db = setup_db().await?;
+
+        let (created, was_created) = Entity::objects
+            .get_or_create(
+                &db,
+                [(Column::Username, "frank".into())],
+                [(Column::Age, 40.into())],
+            )
+            .await?;
+        assert!(was_created);
+        assert_eq!(created.age, 40);
+
+        let (found, was_created) = Entity::objects
+            .get_or_create(
+                &db,
+                [(Column::Username, "frank".into())],
+                [(Column::Age, 99.into())],
+            )
+            .await?;
+        assert!(!was_created);
+        assert_eq!(found.id, created.id);
+        assert_eq!(found.age, 40);
+        assert_eq!(Entity::objects.count(&db).await?, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_objects_get_or_create_plusieurs_resultats() -> Result<(), DbErr> {
+        let db = setup_db().await?;
+        for name in ["g1", "g2"] {
+            ActiveModel {
+                username: Set(name.to_string()),
+                age: Set(33),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await?;
+        }
+        let result = Entity::objects
+            .get_or_create(&db, [(Column::Age, 33.into())], [])
+            .await;
+        assert!(matches!(result, Err(ObjectsError::MultipleObjectsReturned)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_objects_get_or_create_violation_unique() -> Result<(), DbErr> {
+        let db = setup_db().await?;
+        let existing = ActiveModel {
+            username: Set("henry".to_string()),
+            age: Set(50),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+
+        // Lookup miss on username, but the default reuses an existing primary key
+        let result = Entity::objects
+            .get_or_create(
+                &db,
+                [(Column::Username, "other".into())],
+                [(Column::Id, existing.id.into()), (Column::Age, 1.into())],
+            )
+            .await;
+        assert!(
+            matches!(result, Err(ObjectsError::UniqueViolation(_))),
+            "{:?}",
+            result
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_objects_filter_many() -> Result<(), DbErr> {
         let db = setup_db().await?;
@@ -1,5 +1,7 @@
 //! `RuniqueQueryBuilder<E>` — Django-style query builder: filter, exclude, order_by, limit, all, get, count…
+use crate::db::AsConnection;
 use crate::db::DatabaseConfig;
+use crate::db::replica::route;
 /// Django-inspired query builder for SeaORM
 ///
 /// This struct wraps SeaORM's `Select<E>` and provides convenient,
@@ -58,18 +60,30 @@ use crate::db::DatabaseConfig;
 /// ```
 use axum::response::IntoResponse;
 use sea_orm::{
-    ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, ExprTrait, JoinType,
-    QueryFilter, QueryOrder, QuerySelect, Select,
+    ColumnTrait, Condition, DbErr, EntityTrait, ExprTrait, JoinType, QueryFilter, QueryOrder,
+    QuerySelect, Related, Select,
 };
 use std::sync::Arc;
 
 pub struct RuniqueQueryBuilder<E: EntityTrait> {
     query: Select<E>,
+    primary: bool,
 }
 
 impl<E: EntityTrait> RuniqueQueryBuilder<E> {
     pub fn new(query: Select<E>) -> Self {
-        Self { query }
+        Self {
+            query,
+            primary: false,
+        }
+    }
+
+    /// Runs the query on the primary of a [`RoutedDb`](crate::db::RoutedDb),
+    /// never on the replica — for a read that must see a write made just before.
+    /// No effect on a plain connection.
+    pub fn force_primary(mut self) -> Self {
+        self.primary = true;
+        self
     }
 
     // Allows extracting the connection directly from the Engine
@@ -80,8 +94,8 @@ impl<E: EntityTrait> RuniqueQueryBuilder<E> {
         let db = engine.connect().await?;
         self.query.all(&db).await
     }
-    pub async fn all(self, db: &DatabaseConnection) -> Result<Vec<E::Model>, DbErr> {
-        self.query.all(db).await
+    pub async fn all(self, db: &impl AsConnection) -> Result<Vec<E::Model>, DbErr> {
+        route(self.primary, self.query.all(db.as_connection())).await
     }
 
     // In impl<E: EntityTrait> RuniqueQueryBuilder<E>
@@ -167,6 +181,16 @@ impl<E: EntityTrait> RuniqueQueryBuilder<E> {
         self.query
     }
 
+    /// Hands the query to a [`Paginator`](crate::db::Paginator) of `per_page` rows.
+    pub fn paginate(self, per_page: u64) -> crate::db::Paginator<E> {
+        let paginator = crate::db::Paginator::new(self.query, per_page);
+        if self.primary {
+            paginator.force_primary()
+        } else {
+            paginator
+        }
+    }
+
     pub fn limit(mut self, limit: u64) -> Self {
         self.query = self.query.limit(limit);
         self
@@ -177,24 +201,28 @@ impl<E: EntityTrait> RuniqueQueryBuilder<E> {
         self
     }
 
-    pub async fn count(self, db: &DatabaseConnection) -> Result<u64, DbErr>
+    pub async fn count(self, db: &impl AsConnection) -> Result<u64, DbErr>
     where
         E::Model: Sync,
     {
         use sea_orm::PaginatorTrait;
-        self.query.count(db).await
+        route(self.primary, self.query.count(db.as_connection())).await
     }
 
-    pub async fn first(self, db: &DatabaseConnection) -> Result<Option<E::Model>, DbErr> {
-        self.query.one(db).await
+    pub async fn first(self, db: &impl AsConnection) -> Result<Option<E::Model>, DbErr> {
+        route(self.primary, self.query.one(db.as_connection())).await
     }
 
-    pub async fn one(self, db: &DatabaseConnection) -> Result<Option<E::Model>, DbErr>
+    pub async fn one(self, db: &impl AsConnection) -> Result<Option<E::Model>, DbErr>
     where
         E::Model: Sync,
     {
         use sea_orm::PaginatorTrait;
-        let mut results = self.query.paginate(db, 2).fetch_page(0).await?;
+        let mut results = route(
+            self.primary,
+            self.query.paginate(db.as_connection(), 2).fetch_page(0),
+        )
+        .await?;
         match results.len() {
             0 => Ok(None),
             1 => Ok(Some(results.remove(0))),
@@ -214,6 +242,55 @@ impl<E: EntityTrait> RuniqueQueryBuilder<E> {
         self
     }
 
+    /// Runs the query, then loads a to-one relation in one batched query —
+    /// see [`select_related`](crate::db::select_related).
+    ///
+    /// ```rust,ignore
+    /// let rows = article::Entity::objects
+    ///     .filter(article::Column::Published.eq(true))
+    ///     .select_related(&db, author::Entity::objects.all())
+    ///     .await?; // Vec<(article::Model, Option<author::Model>)>
+    /// ```
+    pub async fn select_related<R>(
+        self,
+        db: &impl AsConnection,
+        related: impl Into<Select<R>>,
+    ) -> Result<Vec<(E::Model, Option<R::Model>)>, DbErr>
+    where
+        E: Related<R>,
+        E::Model: Sync,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+    {
+        route(self.primary, async {
+            let db = db.as_connection();
+            let parents = self.query.all(db).await?;
+            crate::db::select_related(db, parents, related).await
+        })
+        .await
+    }
+
+    /// Runs the query, then loads a to-many relation in one batched query —
+    /// see [`prefetch_related`](crate::db::prefetch_related).
+    pub async fn prefetch_related<R>(
+        self,
+        db: &impl AsConnection,
+        related: impl Into<Select<R>>,
+    ) -> Result<Vec<(E::Model, Vec<R::Model>)>, DbErr>
+    where
+        E: Related<R>,
+        E::Model: Sync,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+    {
+        route(self.primary, async {
+            let db = db.as_connection();
+            let parents = self.query.all(db).await?;
+            crate::db::prefetch_related(db, parents, related).await
+        })
+        .await
+    }
+
     /// Loads the related entity at the same time — returns `Vec<(E::Model, Option<R::Model>)>`.
     ///
     /// ```rust,ignore
@@ -231,7 +308,7 @@ impl<E: EntityTrait> RuniqueQueryBuilder<E> {
 
     pub async fn get_or_404(
         self,
-        db: &DatabaseConnection,
+        db: &impl AsConnection,
         ctx: &crate::context::template::Request,
         error_msg: &str,
     ) -> Result<E::Model, axum::response::Response> {
@@ -242,7 +319,7 @@ impl<E: EntityTrait> RuniqueQueryBuilder<E> {
                 context.insert("title", "Page not found");
                 context.insert("error_message", error_msg);
 
-                match ctx.engine.tera.render("404.html", &context) {
+                match ctx.engine.tera.current().render("404.html", &context) {
                     Ok(html) => Err(axum::response::Html(html).into_response()),
                     Err(e) => {
                         tracing::error!("Tera render 404 error: {}", e);
@@ -259,7 +336,7 @@ impl<E: EntityTrait> RuniqueQueryBuilder<E> {
                 context.insert("title", "Server error");
                 context.insert("error_message", "Database error");
 
-                match ctx.engine.tera.render("500.html", &context) {
+                match ctx.engine.tera.current().render("500.html", &context) {
                     Ok(html) => Err(axum::response::Html(html).into_response()),
                     Err(e) => {
                         tracing::error!("Tera render 500 error: {}", e);
@@ -275,6 +352,12 @@ impl<E: EntityTrait> RuniqueQueryBuilder<E> {
     }
 }
 
+impl<E: EntityTrait> From<RuniqueQueryBuilder<E>> for Select<E> {
+    fn from(builder: RuniqueQueryBuilder<E>) -> Self {
+        builder.query
+    }
+}
+
 pub trait Queryable {
     fn objects() -> RuniqueQueryBuilder<Self>
     where
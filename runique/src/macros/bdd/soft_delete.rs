@@ -0,0 +1,405 @@
+//! `SoftObjects<E>` — `objects` manager for soft-deletable entities, exposed via
+//! `impl_objects!(Entity, soft_delete = Column::DeletedAt)`.
+use super::objects::{
+    BulkConflict, ObjectsError, bulk_create_in, get_or_create_in, lookup_error_page,
+};
+use super::query::RuniqueQueryBuilder;
+use crate::context::template::Request;
+use crate::db::AsConnection;
+use axum::response::Response;
+use sea_orm::{
+    ActiveModelBehavior, ColumnTrait, Condition, DbErr, EntityTrait, IntoActiveModel, QueryFilter,
+    Related, Select, prelude::DateTime, sea_query::Expr,
+};
+use std::marker::PhantomData;
+
+/// Entity whose rows are soft-deleted: a delete stamps `deleted_at` instead of
+/// removing the row. Implemented by `impl_objects!(Entity, soft_delete = ...)`.
+pub trait SoftDelete: EntityTrait {
+    /// Nullable timestamp column, `NULL` while the row is live.
+    fn deleted_at_column() -> Self::Column;
+}
+
+/// Django-style manager that hides soft-deleted rows by default.
+///
+/// Same read API as [`Objects`](super::objects::Objects), scoped to live rows
+/// (`deleted_at IS NULL`). [`with_deleted`](Self::with_deleted) and
+/// [`only_deleted`](Self::only_deleted) widen or invert the scope;
+/// [`hard_delete`](Self::hard_delete) really removes rows.
+///
+/// ```rust,ignore
+/// impl_objects!(Entity, soft_delete = Column::DeletedAt);
+///
+/// Entity::objects.delete(&db, Column::Id.eq(3)).await?;       // UPDATE … SET deleted_at
+/// Entity::objects.all().all(&db).await?;                      // live rows only
+/// Entity::objects.with_deleted().all(&db).await?;             // everything
+/// Entity::objects.hard_delete(&db, Column::Id.eq(3)).await?;  // DELETE
+/// ```
+pub struct SoftObjects<E: SoftDelete> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: SoftDelete> Default for SoftObjects<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: SoftDelete> SoftObjects<E> {
+    pub const fn new() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+
+    fn live() -> Select<E> {
+        E::find().filter(E::deleted_at_column().is_null())
+    }
+
+    pub fn all(&self) -> RuniqueQueryBuilder<E> {
+        RuniqueQueryBuilder::new(Self::live())
+    }
+
+    /// Live rows, read from the primary of a [`RoutedDb`](crate::db::RoutedDb) —
+    /// see [`RuniqueQueryBuilder::force_primary`].
+    pub fn force_primary(&self) -> RuniqueQueryBuilder<E> {
+        self.all().force_primary()
+    }
+
+    /// Live and soft-deleted rows.
+    pub fn with_deleted(&self) -> RuniqueQueryBuilder<E> {
+        RuniqueQueryBuilder::new(E::find())
+    }
+
+    /// Soft-deleted rows only (trash view).
+    pub fn only_deleted(&self) -> RuniqueQueryBuilder<E> {
+        RuniqueQueryBuilder::new(E::find().filter(E::deleted_at_column().is_not_null()))
+    }
+
+    pub fn filter<C>(&self, condition: C) -> RuniqueQueryBuilder<E>
+    where
+        C: Into<Condition>,
+    {
+        self.all().filter(condition.into())
+    }
+
+    pub fn exclude<C>(&self, condition: C) -> RuniqueQueryBuilder<E>
+    where
+        C: Into<Condition>,
+    {
+        self.all().exclude(condition.into())
+    }
+
+    pub fn filter_many<C, V, I>(&self, filters: I) -> RuniqueQueryBuilder<E>
+    where
+        C: ColumnTrait,
+        V: Into<sea_orm::Value>,
+        I: IntoIterator<Item = (C, V)>,
+    {
+        self.all().filter_many(filters)
+    }
+
+    pub fn exclude_many<C, V, I>(&self, filters: I) -> RuniqueQueryBuilder<E>
+    where
+        C: ColumnTrait,
+        V: Into<sea_orm::Value>,
+        I: IntoIterator<Item = (C, V)>,
+    {
+        self.all().exclude_many(filters)
+    }
+
+    pub async fn get(
+        &self,
+        db: &impl AsConnection,
+        id: impl Into<<E::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>,
+    ) -> Result<E::Model, DbErr> {
+        self.get_optional(db, id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Record not found".to_string()))
+    }
+
+    pub async fn get_optional(
+        &self,
+        db: &impl AsConnection,
+        id: impl Into<<E::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>,
+    ) -> Result<Option<E::Model>, DbErr> {
+        E::find_by_id(id)
+            .filter(E::deleted_at_column().is_null())
+            .one(db.as_connection())
+            .await
+    }
+
+    pub async fn count(&self, db: &impl AsConnection) -> Result<u64, DbErr>
+    where
+        E::Model: Sync,
+    {
+        use sea_orm::PaginatorTrait;
+        Self::live().count(db.as_connection()).await
+    }
+
+    /// Every live row with its to-one related row, in two queries — see
+    /// [`select_related`](crate::db::select_related).
+    pub async fn select_related<R>(
+        &self,
+        db: &impl AsConnection,
+        related: impl Into<Select<R>>,
+    ) -> Result<Vec<(E::Model, Option<R::Model>)>, DbErr>
+    where
+        E: Related<R>,
+        E::Model: Sync,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+    {
+        self.all().select_related(db, related).await
+    }
+
+    /// Every live row with its to-many related rows, in two queries — see
+    /// [`prefetch_related`](crate::db::prefetch_related).
+    pub async fn prefetch_related<R>(
+        &self,
+        db: &impl AsConnection,
+        related: impl Into<Select<R>>,
+    ) -> Result<Vec<(E::Model, Vec<R::Model>)>, DbErr>
+    where
+        E: Related<R>,
+        E::Model: Sync,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+    {
+        self.all().prefetch_related(db, related).await
+    }
+
+    /// Same as [`Objects::get_or_create`](super::objects::Objects::get_or_create),
+    /// looking up live rows only: a soft-deleted match is not returned, a new
+    /// row is inserted instead (or the insert hits the unique constraint).
+    pub async fn get_or_create<L, D>(
+        &self,
+        db: &impl AsConnection,
+        lookup: L,
+        defaults: D,
+    ) -> Result<(E::Model, bool), ObjectsError>
+    where
+        L: IntoIterator<Item = (E::Column, sea_orm::Value)>,
+        D: IntoIterator<Item = (E::Column, sea_orm::Value)>,
+        E::Model: IntoActiveModel<E::ActiveModel>,
+        E::ActiveModel: ActiveModelBehavior + Send,
+    {
+        get_or_create_in(db, Self::live(), lookup, defaults).await
+    }
+
+    /// See [`Objects::bulk_create`](super::objects::Objects::bulk_create).
+    pub async fn bulk_create(
+        &self,
+        db: &impl AsConnection,
+        models: Vec<E::ActiveModel>,
+        batch_size: usize,
+    ) -> Result<u64, ObjectsError> {
+        bulk_create_in::<E>(db, models, batch_size, BulkConflict::Error).await
+    }
+
+    /// See [`Objects::bulk_create_with`](super::objects::Objects::bulk_create_with).
+    pub async fn bulk_create_with(
+        &self,
+        db: &impl AsConnection,
+        models: Vec<E::ActiveModel>,
+        batch_size: usize,
+        on_conflict: BulkConflict<E>,
+    ) -> Result<u64, ObjectsError> {
+        bulk_create_in::<E>(db, models, batch_size, on_conflict).await
+    }
+
+    /// 404 page for a missing or soft-deleted row.
+    pub async fn get_or_404(
+        &self,
+        db: &impl AsConnection,
+        id: impl Into<<E::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType>,
+        ctx: &Request,
+        error_msg: &str,
+    ) -> Result<E::Model, Response> {
+        match self.get_optional(db, id).await {
+            Ok(Some(entity)) => Ok(entity),
+            Ok(None) => Err(lookup_error_page(ctx, error_msg, false)),
+            Err(_) => Err(lookup_error_page(ctx, error_msg, true)),
+        }
+    }
+
+    /// Soft delete: stamps `deleted_at = CURRENT_TIMESTAMP` on the live rows
+    /// matching `condition`. Returns the number of rows affected.
+    pub async fn delete<C>(&self, db: &impl AsConnection, condition: C) -> Result<u64, DbErr>
+    where
+        C: Into<Condition>,
+    {
+        let col = E::deleted_at_column();
+        let res = E::update_many()
+            .col_expr(col, Expr::current_timestamp())
+            .filter(condition.into())
+            .filter(col.is_null())
+            .exec(db.as_connection())
+            .await?;
+        Ok(res.rows_affected)
+    }
+
+    /// Clears `deleted_at` on the soft-deleted rows matching `condition`.
+    pub async fn restore<C>(&self, db: &impl AsConnection, condition: C) -> Result<u64, DbErr>
+    where
+        C: Into<Condition>,
+    {
+        let col = E::deleted_at_column();
+        let res = E::update_many()
+            .col_expr(col, Expr::value(Option::<DateTime>::None))
+            .filter(condition.into())
+            .filter(col.is_not_null())
+            .exec(db.as_connection())
+            .await?;
+        Ok(res.rows_affected)
+    }
+
+    /// Real `DELETE`, live or soft-deleted rows alike.
+    pub async fn hard_delete<C>(&self, db: &impl AsConnection, condition: C) -> Result<u64, DbErr>
+    where
+        C: Into<Condition>,
+    {
+        let res = E::delete_many()
+            .filter(condition.into())
+            .exec(db.as_connection())
+            .await?;
+        Ok(res.rows_affected)
+    }
+}
+
+impl<E: SoftDelete> Copy for SoftObjects<E> {}
+impl<E: SoftDelete> Clone for SoftObjects<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "sqlite")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::ActiveModelTrait;
+    use sea_orm::Set;
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+    #[sea_orm(table_name = "posts")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub title: String,
+        pub deleted_at: Option<DateTime>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    crate::impl_objects!(Entity, soft_delete = Column::DeletedAt);
+
+    async fn setup_db() -> Result<DatabaseConnection, DbErr> {
+        let db = sea_orm::Database::connect("sqlite::memory:").await?;
+
+        use sea_orm::Schema;
+        let schema = Schema::new(sea_orm::DatabaseBackend::Sqlite);
+        let stmt = schema.create_table_from_entity(Entity);
+        db.execute(&stmt).await?;
+
+        for title in ["a", "b", "c"] {
+            ActiveModel {
+                title: Set(title.to_string()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await?;
+        }
+        Ok(db)
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_masque_la_ligne() -> Result<(), DbErr> {
+        let db = setup_db().await?;
+
+        let n = Entity::objects.delete(&db, Column::Title.eq("a")).await?;
+        assert_eq!(n, 1);
+
+        assert_eq!(Entity::objects.count(&db).await?, 2);
+        assert_eq!(Entity::objects.all().all(&db).await?.len(), 2);
+        assert!(Entity::objects.get_optional(&db, 1).await?.is_none());
+        assert_eq!(Entity::objects.with_deleted().all(&db).await?.len(), 3);
+
+        let trash = Entity::objects.only_deleted().all(&db).await?;
+        assert_eq!(trash.len(), 1);
+        assert!(trash[0].deleted_at.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_filter_reste_sur_les_lignes_vivantes() -> Result<(), DbErr> {
+        let db = setup_db().await?;
+        Entity::objects.delete(&db, Column::Title.eq("b")).await?;
+
+        let rows = Entity::objects
+            .filter(Column::Title.is_in(["a", "b"]))
+            .all(&db)
+            .await?;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].title, "a");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_restore() -> Result<(), DbErr> {
+        let db = setup_db().await?;
+        Entity::objects.delete(&db, Column::Id.eq(2)).await?;
+
+        let n = Entity::objects.restore(&db, Column::Id.eq(2)).await?;
+        assert_eq!(n, 1);
+        assert_eq!(Entity::objects.count(&db).await?, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_soft_get_or_create_ignore_les_lignes_supprimees() -> Result<(), ObjectsError> {
+        let db = setup_db().await?;
+
+        let (found, created) = Entity::objects
+            .get_or_create(&db, [(Column::Title, "a".into())], [])
+            .await?;
+        assert!(!created);
+        assert_eq!(found.id, 1);
+
+        Entity::objects.delete(&db, Column::Id.eq(1)).await?;
+        let (fresh, created) = Entity::objects
+            .get_or_create(&db, [(Column::Title, "a".into())], [])
+            .await?;
+        assert!(created);
+        assert_ne!(fresh.id, 1);
+        assert!(fresh.deleted_at.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_soft_restore_remet_deleted_at_a_null() -> Result<(), DbErr> {
+        let db = setup_db().await?;
+        Entity::objects.delete(&db, Column::Id.eq(3)).await?;
+        Entity::objects.restore(&db, Column::Id.eq(3)).await?;
+        let row = Entity::objects.get(&db, 3).await?;
+        assert!(row.deleted_at.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_hard_delete_supprime_reellement() -> Result<(), DbErr> {
+        let db = setup_db().await?;
+        Entity::objects.delete(&db, Column::Id.eq(1)).await?;
+
+        let n = Entity::objects
+            .hard_delete(&db, Column::Id.is_in([1, 2]))
+            .await?;
+        assert_eq!(n, 2);
+        assert_eq!(Entity::objects.with_deleted().all(&db).await?.len(), 1);
+        Ok(())
+    }
+}
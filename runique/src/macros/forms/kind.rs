@@ -11,9 +11,11 @@ macro_rules! delegate_to_kind {
             $crate::forms::generic::FieldKind::Choice(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Radio(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Checkbox(field) => field.$method($($arg),*),
+            $crate::forms::generic::FieldKind::Autocomplete(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Date(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Time(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::DateTime(field) => field.$method($($arg),*),
+            $crate::forms::generic::FieldKind::DateRange(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Duration(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Color(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Slug(field) => field.$method($($arg),*),
@@ -21,6 +23,7 @@ macro_rules! delegate_to_kind {
             $crate::forms::generic::FieldKind::JSON(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::IPAddress(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Hidden(field) => field.$method($($arg),*),
+            $crate::forms::generic::FieldKind::Honeypot(field) => field.$method($($arg),*),
         }
     };
 
@@ -33,9 +36,11 @@ macro_rules! delegate_to_kind {
             $crate::forms::generic::FieldKind::Choice(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Radio(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Checkbox(field) => field.$method($($arg),*),
+            $crate::forms::generic::FieldKind::Autocomplete(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Date(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Time(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::DateTime(field) => field.$method($($arg),*),
+            $crate::forms::generic::FieldKind::DateRange(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Duration(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Color(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Slug(field) => field.$method($($arg),*),
@@ -43,6 +48,7 @@ macro_rules! delegate_to_kind {
             $crate::forms::generic::FieldKind::JSON(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::IPAddress(field) => field.$method($($arg),*),
             $crate::forms::generic::FieldKind::Hidden(field) => field.$method($($arg),*),
+            $crate::forms::generic::FieldKind::Honeypot(field) => field.$method($($arg),*),
         }
     };
 }
@@ -9,7 +9,8 @@ pub mod helper {
 pub mod routeur;
 pub mod template;
 
-pub use routeur::RouterExt;
 pub use routeur::register_url::{
     add_urls, register_name_url, register_pending, reverse, reverse_with_parameters,
+    reverse_with_query,
 };
+pub use routeur::{RouteGroup, RouterExt, group};
@@ -0,0 +1,248 @@
+//! Route groups — routes nested under a shared prefix, behind a shared middleware stack.
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    body::Body,
+    extract::State,
+    http::Request,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    routing::Route,
+};
+use serde::Serialize;
+use tera::Context;
+use tower::{Layer, Service};
+
+use crate::auth::guard::{
+    PermissionRequired, login_required_middleware, permission_required_middleware,
+};
+use crate::macros::routeur::register_url::{capture_pending, register_pending};
+use crate::middleware::allowed_hosts::{
+    HostOverride, capture_host_overrides, register_host_override,
+};
+
+type GroupLayer = Box<dyn FnOnce(Router) -> Router + Send>;
+
+/// Template variables added by the enclosing groups — merged into
+/// [`Request::context`](crate::context::Request) before the framework's own keys.
+#[derive(Clone)]
+pub(crate) struct GroupContext(pub(crate) Arc<Context>);
+
+/// Starts a route group mounted under `prefix` — see [`RouteGroup`].
+pub fn group(prefix: impl Into<String>) -> RouteGroup {
+    RouteGroup::new(prefix)
+}
+
+/// Routes nested under a prefix, behind middlewares declared once for all of them.
+///
+/// Group middlewares wrap the matched routes only (`route_layer`): they run inside
+/// the global stack of the builder (session, CSRF… are already there), and an
+/// unknown path under the prefix stays a plain `404`. The first declared runs first.
+///
+/// Names registered inside the group get the prefix: `"/{id}"` named `"detail"` in
+/// `group("/app")` reverses to `/app/{id}`. Nested groups add up prefixes and
+/// middlewares, the outer ones running first.
+///
+/// `.allowed_hosts(...)` replaces the global allowed hosts under the prefix — the
+/// innermost group declaring it wins.
+///
+/// ```rust,ignore
+/// urlpatterns! {
+///     "/" => view!{ views::index }, name = "index",
+/// }
+/// .merge(
+///     group("/app")
+///         .login_required("/login")
+///         .context("section", "app")
+///         .routes(|| urlpatterns! {
+///             "/" => view!{ app::dashboard }, name = "dashboard",
+///         })
+///         .group(group("/settings").routes(|| urlpatterns! {
+///             "/" => view!{ app::settings }, name = "settings",
+///         })),
+/// )
+/// ```
+pub struct RouteGroup {
+    prefix: String,
+    router: Router,
+    names: Vec<(String, String)>,
+    host_overrides: Vec<HostOverride>,
+    layers: Vec<GroupLayer>,
+    context: Option<Context>,
+}
+
+impl RouteGroup {
+    /// An empty group mounted under `prefix` — `""` or `"/"` mounts at the root.
+    pub fn new(prefix: impl Into<String>) -> Self {
+        let prefix = prefix.into();
+        let prefix = prefix.trim_end_matches('/');
+        let prefix = match prefix {
+            "" => String::new(),
+            p if p.starts_with('/') => p.to_string(),
+            p => format!("/{p}"),
+        };
+        Self {
+            prefix,
+            router: Router::new(),
+            names: Vec::new(),
+            host_overrides: Vec::new(),
+            layers: Vec::new(),
+            context: None,
+        }
+    }
+
+    /// Adds routes built by `f` — typically a `urlpatterns!` call. The names it
+    /// registers are prefixed with the group prefix. Several calls add up.
+    pub fn routes(mut self, f: impl FnOnce() -> Router) -> Self {
+        let ((router, names), host_overrides) = capture_host_overrides(|| capture_pending(f));
+        self.router = self.router.merge(router);
+        self.names.extend(names);
+        self.host_overrides.extend(host_overrides);
+        self
+    }
+
+    /// Nests `inner` under this group: its prefix and middlewares come after ours.
+    pub fn group(mut self, inner: RouteGroup) -> Self {
+        let (router, names, host_overrides) = inner.into_parts();
+        self.router = self.router.merge(router);
+        self.names.extend(names);
+        self.host_overrides.extend(host_overrides);
+        self
+    }
+
+    /// Hosts accepted under the group prefix, in place of the global allowed hosts
+    /// (same syntax: exact, `.domain.fr` wildcard, `*`). Only applies when host
+    /// validation is enabled.
+    pub fn allowed_hosts<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.host_overrides.push(HostOverride {
+            prefix: String::new(),
+            allowed_hosts: hosts.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Wraps every route of the group with `layer` —
+    /// `axum::middleware::from_fn(my_middleware)` for a plain function.
+    pub fn middleware<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<Route> + Clone + Send + Sync + 'static,
+        L::Service: Service<Request<Body>> + Clone + Send + Sync + 'static,
+        <L::Service as Service<Request<Body>>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request<Body>>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request<Body>>>::Future: Send + 'static,
+    {
+        self.layers
+            .push(Box::new(move |router: Router| router.route_layer(layer)));
+        self
+    }
+
+    /// Redirects anonymous users to `redirect_url` — same guard as
+    /// [`RouterExt::login_required`](crate::macros::RouterExt::login_required).
+    pub fn login_required(self, redirect_url: impl Into<String>) -> Self {
+        self.middleware(axum::middleware::from_fn_with_state(
+            Arc::new(redirect_url.into()),
+            login_required_middleware,
+        ))
+    }
+
+    /// Requires `permission` — same guard as
+    /// [`RouterExt::permission_required`](crate::macros::RouterExt::permission_required).
+    pub fn permission_required(
+        self,
+        permission: impl Into<String>,
+        login_url: impl Into<String>,
+    ) -> Self {
+        self.middleware(axum::middleware::from_fn_with_state(
+            Arc::new(PermissionRequired {
+                permission: permission.into(),
+                login_url: login_url.into(),
+            }),
+            permission_required_middleware,
+        ))
+    }
+
+    /// Adds a template variable to every request of the group — a nested group
+    /// overrides the same key.
+    pub fn context<T: Serialize + ?Sized>(mut self, key: impl Into<String>, value: &T) -> Self {
+        self.context
+            .get_or_insert_with(Context::new)
+            .insert(key, value);
+        self
+    }
+
+    /// The layered router nested under the prefix, the prefixed names and host
+    /// overrides.
+    fn into_parts(self) -> (Router, Vec<(String, String)>, Vec<HostOverride>) {
+        let mut router = self.router;
+        if router.has_routes() {
+            if let Some(context) = self.context {
+                router = router.route_layer(axum::middleware::from_fn_with_state(
+                    Arc::new(context),
+                    group_context_middleware,
+                ));
+            }
+            for layer in self.layers.into_iter().rev() {
+                router = layer(router);
+            }
+        }
+        let prefix = self.prefix;
+        let names = self
+            .names
+            .into_iter()
+            .map(|(name, path)| match path.as_str() {
+                "/" if !prefix.is_empty() => (name, prefix.clone()),
+                _ => (name, format!("{prefix}{path}")),
+            })
+            .collect();
+        let host_overrides = self
+            .host_overrides
+            .into_iter()
+            .map(|o| HostOverride {
+                prefix: format!("{prefix}{}", o.prefix),
+                ..o
+            })
+            .collect();
+        let router = if prefix.is_empty() {
+            router
+        } else {
+            Router::new().nest(&prefix, router)
+        };
+        (router, names, host_overrides)
+    }
+}
+
+impl From<RouteGroup> for Router {
+    fn from(group: RouteGroup) -> Router {
+        let (router, names, host_overrides) = group.into_parts();
+        for (name, path) in names {
+            register_pending(name, path);
+        }
+        for host_override in host_overrides {
+            register_host_override(host_override);
+        }
+        router
+    }
+}
+
+/// Merges the group variables over those of the enclosing groups.
+async fn group_context_middleware(
+    State(context): State<Arc<Context>>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
+    let merged = match req.extensions_mut().remove::<GroupContext>() {
+        Some(GroupContext(mut outer)) => {
+            Arc::make_mut(&mut outer).extend(context.as_ref().clone());
+            outer
+        }
+        None => context,
+    };
+    req.extensions_mut().insert(GroupContext(merged));
+    next.run(req).await
+}
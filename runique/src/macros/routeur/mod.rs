@@ -1,7 +1,10 @@
-//! Macros et helpers routeur — `get_post!`, `urlpatterns!`, registre des noms d'URL, `RouterExt`.
+//! Macros et helpers routeur — `get_post!`, `urlpatterns!`, registre des noms d'URL, `RouterExt`,
+//! groupes de routes.
 pub mod get_post;
+pub mod group;
 pub mod register_url;
 pub mod router;
 pub mod router_ext;
 
+pub use group::{RouteGroup, group};
 pub use router_ext::RouterExt;
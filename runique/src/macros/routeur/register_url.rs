@@ -1,5 +1,8 @@
-//! Global registry of URL names — `register_pending`, `reverse()`, `reverse_with_parameters()`.
+//! Global registry of URL names — `register_pending`, `reverse()`, `reverse_with_parameters()`,
+//! `reverse_with_query()`.
 use crate::engine::RuniqueEngine;
+use std::cell::RefCell;
+use std::collections::HashSet;
 use std::sync::LazyLock;
 use std::sync::{Arc, Mutex};
 
@@ -7,10 +10,102 @@ use std::sync::{Arc, Mutex};
 pub static PENDING_URLS: LazyLock<Mutex<Vec<(String, String)>>> =
     LazyLock::new(|| Mutex::new(Vec::new()));
 
+thread_local! {
+    /// Names registered while a route group builds its routes — prefixed by the
+    /// group before they reach `PENDING_URLS`.
+    static CAPTURED_URLS: RefCell<Option<Vec<(String, String)>>> = const { RefCell::new(None) };
+}
+
 /// Used by the urlpatterns! macro
 pub fn register_pending(name: impl Into<String>, path: impl Into<String>) {
-    let mut pending = PENDING_URLS.lock().unwrap_or_else(|e| e.into_inner());
-    pending.push((name.into(), path.into()));
+    let entry = (name.into(), path.into());
+    let entry = CAPTURED_URLS.with_borrow_mut(|captured| match captured {
+        Some(captured) => {
+            captured.push(entry);
+            None
+        }
+        None => Some(entry),
+    });
+    if let Some(entry) = entry {
+        let mut pending = PENDING_URLS.lock().unwrap_or_else(|e| e.into_inner());
+        pending.push(entry);
+    }
+}
+
+/// Restores the enclosing capture, even when the routes closure panics.
+struct CaptureGuard(Option<Vec<(String, String)>>);
+
+impl Drop for CaptureGuard {
+    fn drop(&mut self) {
+        let outer = self.0.take();
+        CAPTURED_URLS.with_borrow_mut(|captured| *captured = outer);
+    }
+}
+
+/// Runs `f` and returns the names it registers instead of queuing them.
+pub(crate) fn capture_pending<R>(f: impl FnOnce() -> R) -> (R, Vec<(String, String)>) {
+    let guard = CaptureGuard(CAPTURED_URLS.with_borrow_mut(|c| c.replace(Vec::new())));
+    let result = f();
+    let names = CAPTURED_URLS
+        .with_borrow_mut(|c| c.take())
+        .unwrap_or_default();
+    drop(guard);
+    (result, names)
+}
+
+/// Used by the urlpatterns! macro — panics when `name` is already declared in
+/// the same call.
+#[doc(hidden)]
+pub fn register_unique(names: &mut HashSet<String>, name: &str) {
+    if !names.insert(name.to_string()) {
+        panic!("urlpatterns!: route name `{name}` is declared twice in the same namespace");
+    }
+}
+
+/// `true` for an optional segment: `{month?}`.
+fn is_optional(segment: &str) -> bool {
+    segment.starts_with('{') && segment.ends_with("?}")
+}
+
+/// Used by the urlpatterns! macro — the routes to mount for `path`: one per
+/// number of trailing optional segments kept.
+///
+/// `/articles/{year}/{month?}` → `/articles/{year}`, `/articles/{year}/{month}`.
+/// Panics when an optional segment is followed by a required one.
+#[doc(hidden)]
+pub fn route_variants(path: &str) -> Vec<String> {
+    let segments: Vec<&str> = path.split('/').collect();
+    let Some(first) = segments.iter().position(|s| is_optional(s)) else {
+        return vec![path.to_string()];
+    };
+    if let Some(s) = segments[first..].iter().find(|s| !is_optional(s)) {
+        panic!("urlpatterns!: `{s}` follows an optional segment in `{path}`");
+    }
+    (first..=segments.len())
+        .map(|end| {
+            let kept: Vec<String> = segments[..end]
+                .iter()
+                .map(|s| s.replace("?}", "}"))
+                .collect();
+            match kept.join("/") {
+                p if p.is_empty() => "/".to_string(),
+                p => p,
+            }
+        })
+        .collect()
+}
+
+/// Drops every optional segment left unfilled, from the first one on: a later
+/// optional segment cannot be given without the earlier ones.
+pub(crate) fn strip_optional(path: &str) -> String {
+    let segments: Vec<&str> = path.split('/').collect();
+    match segments.iter().position(|s| is_optional(s)) {
+        Some(first) => match segments[..first].join("/") {
+            p if p.is_empty() => "/".to_string(),
+            p => p,
+        },
+        None => path.to_string(),
+    }
 }
 
 // --- 2. Functions directly using RuniqueEngine (Runtime) ---
@@ -37,16 +132,43 @@ pub fn reverse(engine: &Arc<RuniqueEngine>, name: &str) -> Option<String> {
     map.get(name).cloned()
 }
 
-/// Retrieves a URL with parameter substitution
+/// Retrieves a URL with parameter substitution — `{id}` is replaced by the
+/// percent-encoded value of `id`. An optional segment (`{month?}`) left out of
+/// `parameters` is dropped.
 pub fn reverse_with_parameters(
     engine: &Arc<RuniqueEngine>,
     name: &str,
     parameters: &[(&str, &str)],
 ) -> Option<String> {
     let path = reverse(engine, name)?;
-    let result = parameters
-        .iter()
-        .fold(path, |acc, (k, v)| acc.replace(&format!("{{{}}}", k), v));
+    let result = parameters.iter().fold(path, |acc, (k, v)| {
+        let value = urlencoding::encode(v);
+        acc.replace(&format!("{{{}}}", k), &value)
+            .replace(&format!("{{{}?}}", k), &value)
+    });
+    Some(strip_optional(&result))
+}
+
+/// Same as `reverse_with_parameters`, then appends `query` as a percent-encoded
+/// query string, pairs kept in the given order.
+///
+/// ```rust,ignore
+/// reverse_with_query(&engine, "article_list", &[], &[("page", "2"), ("sort", "name")])
+/// // → Some("/articles?page=2&sort=name")
+/// ```
+pub fn reverse_with_query(
+    engine: &Arc<RuniqueEngine>,
+    name: &str,
+    parameters: &[(&str, &str)],
+    query: &[(&str, &str)],
+) -> Option<String> {
+    let mut result = reverse_with_parameters(engine, name, parameters)?;
+    for (i, (k, v)) in query.iter().enumerate() {
+        result.push(if i == 0 { '?' } else { '&' });
+        result.push_str(&urlencoding::encode(k));
+        result.push('=');
+        result.push_str(&urlencoding::encode(v));
+    }
     Some(result)
 }
 
@@ -2,19 +2,52 @@
 
 pub use crate::macros::routeur::register_url::{
     add_urls, register_name_url, register_pending, reverse, reverse_with_parameters,
+    reverse_with_query,
 };
 
+/// Names registered by one `urlpatterns!` call share a namespace: the same name
+/// twice panics. With a leading `namespace = "blog",`, names register as
+/// `blog:<name>` — `reverse(&engine, "blog:detail")`, `{% link "blog:detail" %}`.
+///
+/// A trailing optional segment (`/articles/{year}/{month?}`) mounts the handler
+/// with and without it; the name keeps the full pattern.
 #[macro_export]
 macro_rules! urlpatterns {
     (
+        namespace = $namespace:expr,
         $($path:expr => $handler:expr, name = $name:expr) ,* $(,)?
     ) => {{
         let mut router = $crate::axum::Router::new();
+        let mut names = ::std::collections::HashSet::new();
 
         $(
-            $crate::macros::routeur::register_url::register_pending($name, $path);
+            let name = format!("{}:{}", $namespace, $name);
+            $crate::macros::routeur::register_url::register_unique(&mut names, &name);
+            $crate::macros::routeur::register_url::register_pending(name, $path);
 
-            router = router.route($path, $handler);
+            let handler = $handler;
+            for path in $crate::macros::routeur::register_url::route_variants($path) {
+                router = router.route(&path, handler.clone());
+            }
+        )*
+        router
+    }};
+
+    (
+        $($path:expr => $handler:expr, name = $name:expr) ,* $(,)?
+    ) => {{
+        let mut router = $crate::axum::Router::new();
+        let mut names = ::std::collections::HashSet::new();
+
+        $(
+            let name = $name.to_string();
+            $crate::macros::routeur::register_url::register_unique(&mut names, &name);
+            $crate::macros::routeur::register_url::register_pending(name, $path);
+
+            let handler = $handler;
+            for path in $crate::macros::routeur::register_url::route_variants($path) {
+                router = router.route(&path, handler.clone());
+            }
         )*
         router
     }};
@@ -25,7 +58,10 @@ macro_rules! urlpatterns {
         let mut router = $crate::axum::Router::new();
 
         $(
-            router = router.route($path, $handler);
+            let handler = $handler;
+            for path in $crate::macros::routeur::register_url::route_variants($path) {
+                router = router.route(&path, handler.clone());
+            }
         )*
         router
     }};
@@ -1,9 +1,14 @@
-//! `RouterExt` — Axum `Router` extension to attach a rate limiter or a login guard to a route.
+//! `RouterExt` — Axum `Router` extension to attach a rate limiter, a login guard, a
+//! permission guard or a page cache to a route.
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::{Router, http::Method, routing::MethodRouter};
 
-use crate::auth::guard::login_required_middleware;
+use crate::auth::guard::{
+    PermissionRequired, login_required_middleware, permission_required_middleware,
+};
+use crate::cache::cache_page_middleware;
 use crate::macros::routeur::register_url::register_pending;
 use crate::middleware::rate_limit::{RateLimiter, rate_limit_middleware};
 
@@ -24,7 +29,8 @@ use crate::middleware::rate_limit::{RateLimiter, rate_limit_middleware};
 ///     ])
 /// ```
 pub trait RouterExt {
-    /// Adds a route protected by `login_required` — redirects to `redirect_url` if not authenticated.
+    /// Adds a route protected by `login_required` — redirects to `redirect_url` if not authenticated
+    /// (`401` for a WebSocket handshake, see [`ws`](crate::context::ws::ws)).
     ///
     /// # Example
     /// ```rust,ignore
@@ -39,6 +45,40 @@ pub trait RouterExt {
         redirect_url: impl Into<String>,
     ) -> Self;
 
+    /// Adds a route protected by `permission_required` — redirects to `login_url` if not
+    /// authenticated, renders `403.html` if the user's groups don't grant `permission`
+    /// (`blog.update`, `blog.*`…).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// urlpatterns! { ... }
+    ///     .permission_required("/blog/edit", "blog_edit", view!(edit), "blog.update", "/login")
+    /// ```
+    fn permission_required(
+        self,
+        path: impl Into<String>,
+        name: impl Into<String>,
+        handler: MethodRouter,
+        permission: impl Into<String>,
+        login_url: impl Into<String>,
+    ) -> Self;
+
+    /// Adds a route whose `GET` responses are cached for `ttl`, keyed by path + query —
+    /// see [`cache_page_middleware`] for what is (not) cached.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// urlpatterns! { ... }
+    ///     .cache_page("/", "index", view!(index), Duration::from_secs(60))
+    /// ```
+    fn cache_page(
+        self,
+        path: impl Into<String>,
+        name: impl Into<String>,
+        handler: MethodRouter,
+        ttl: Duration,
+    ) -> Self;
+
     /// Adds a route protected by a rate limiter.
     /// `methods`: HTTP methods to count — empty vec counts all methods.
     fn rate_limit(
@@ -84,6 +124,51 @@ impl RouterExt for Router {
         self.merge(protected)
     }
 
+    fn permission_required(
+        self,
+        path: impl Into<String>,
+        name: impl Into<String>,
+        handler: MethodRouter,
+        permission: impl Into<String>,
+        login_url: impl Into<String>,
+    ) -> Self {
+        let path = path.into();
+        let name = name.into();
+        let guard = Arc::new(PermissionRequired {
+            permission: permission.into(),
+            login_url: login_url.into(),
+        });
+        register_pending(&name, &path);
+        let protected =
+            Router::new()
+                .route(&path, handler)
+                .route_layer(axum::middleware::from_fn_with_state(
+                    guard,
+                    permission_required_middleware,
+                ));
+        self.merge(protected)
+    }
+
+    fn cache_page(
+        self,
+        path: impl Into<String>,
+        name: impl Into<String>,
+        handler: MethodRouter,
+        ttl: Duration,
+    ) -> Self {
+        let path = path.into();
+        let name = name.into();
+        register_pending(&name, &path);
+        let cached =
+            Router::new()
+                .route(&path, handler)
+                .route_layer(axum::middleware::from_fn_with_state(
+                    ttl,
+                    cache_page_middleware,
+                ));
+        self.merge(cached)
+    }
+
     fn rate_limit(
         self,
         path: impl Into<String>,
@@ -0,0 +1,94 @@
+//! Append slash — redirects a `404` to the same path with (or without) a trailing
+//! slash when that path is a registered route.
+use crate::utils::aliases::AEngine;
+use axum::{
+    body::Body,
+    extract::State,
+    http::{HeaderValue, Method, Request, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+/// `true` when `path` matches `pattern` segment by segment: `{param}` matches one
+/// non-empty segment, `{*rest}` the non-empty remainder, trailing `{param?}` may
+/// be absent.
+fn matches(pattern: &str, path: &str) -> bool {
+    let mut pattern = pattern.split('/');
+    let mut path = path.split('/');
+    loop {
+        match (pattern.next(), path.next()) {
+            (None, None) => return true,
+            (Some(p), None) if p.starts_with('{') && p.ends_with("?}") => {
+                return pattern.all(|p| p.starts_with('{') && p.ends_with("?}"));
+            }
+            (Some(p), Some(s)) if p.starts_with("{*") && p.ends_with('}') => {
+                return !s.is_empty() || path.next().is_some();
+            }
+            (Some(p), Some(s)) if p.starts_with('{') && p.ends_with('}') => {
+                if s.is_empty() {
+                    return false;
+                }
+            }
+            (Some(p), Some(s)) if p == s => {}
+            _ => return false,
+        }
+    }
+}
+
+/// The other spelling of `path` when it is a registered route and `path` is not —
+/// a `404` from a matched route (missing object) is never redirected.
+fn alternate(engine: &AEngine, path: &str) -> Option<String> {
+    if path == "/" {
+        return None;
+    }
+    let other = match path.strip_suffix('/') {
+        Some(stripped) => stripped.to_string(),
+        None => format!("{path}/"),
+    };
+    let registry = engine
+        .url_registry
+        .read()
+        .unwrap_or_else(|e| e.into_inner());
+    if registry.values().any(|p| matches(p, path)) {
+        return None;
+    }
+    registry
+        .values()
+        .any(|p| matches(p, &other))
+        .then_some(other)
+}
+
+/// On a `404`, redirects to the path with the trailing slash added or removed when
+/// that path matches a route of the registry. `GET`/`HEAD` get a `301`, other
+/// methods a `307` so the browser replays the method and body. The query string
+/// is kept.
+pub async fn append_slash_middleware(
+    State(engine): State<AEngine>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let path = req.uri().path().to_string();
+    let query = req.uri().query().map(str::to_string);
+    let safe = matches!(*req.method(), Method::GET | Method::HEAD);
+
+    let response = next.run(req).await;
+    if response.status() != StatusCode::NOT_FOUND {
+        return response;
+    }
+    let Some(target) = alternate(&engine, &path) else {
+        return response;
+    };
+    let location = match query {
+        Some(q) => format!("{target}?{q}"),
+        None => target,
+    };
+    let Ok(location) = HeaderValue::from_str(&location) else {
+        return response;
+    };
+    let status = if safe {
+        StatusCode::MOVED_PERMANENTLY
+    } else {
+        StatusCode::TEMPORARY_REDIRECT
+    };
+    (status, [(header::LOCATION, location)]).into_response()
+}
@@ -0,0 +1,37 @@
+//! Cache-busting — static URLs carrying the current `?v=<hash>` of the
+//! [`StaticManifest`] are cached for a year (`immutable`).
+use crate::utils::StaticManifest;
+use axum::{
+    extract::{Request, State},
+    http::{HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::Response,
+};
+
+/// `Cache-Control` of a hashed static URL — its content never changes.
+pub const IMMUTABLE_CACHE: &str = "public, max-age=31536000, immutable";
+
+/// Static files: `Cache-Control: immutable` when `?v=` equals the manifest hash of
+/// the file. A stale or unknown hash keeps the regular static cache policy.
+pub async fn immutable_static_middleware(
+    State(manifest): State<StaticManifest>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let hashed = req
+        .uri()
+        .query()
+        .and_then(|query| query.split('&').find_map(|pair| pair.strip_prefix("v=")))
+        .zip(urlencoding::decode(req.uri().path()).ok())
+        .is_some_and(|(version, path)| manifest.hash(&path) == Some(version));
+
+    let mut response = next.run(req).await;
+    let status = response.status();
+    if hashed && (status.is_success() || status == StatusCode::NOT_MODIFIED) {
+        response.headers_mut().insert(
+            header::CACHE_CONTROL,
+            HeaderValue::from_static(IMMUTABLE_CACHE),
+        );
+    }
+    response
+}
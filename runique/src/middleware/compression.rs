@@ -0,0 +1,94 @@
+//! Response compression — `tower_http` predicate (size threshold, already-compressed
+//! content types skipped) and ETag adjustment of compressed responses.
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::Response,
+};
+use std::sync::Arc;
+use tower_http::compression::predicate::{Predicate, SizeAbove};
+
+/// Content types already compressed (or streamed) — compressing them again only costs CPU.
+pub const ALREADY_COMPRESSED: &[&str] = &[
+    "image/",
+    "video/",
+    "audio/",
+    "font/woff",
+    "application/zip",
+    "application/gzip",
+    "application/x-gzip",
+    "application/x-7z-compressed",
+    "application/x-rar-compressed",
+    "application/grpc",
+    "text/event-stream",
+];
+
+/// Compresses responses of at least `min_size` bytes (or of unknown size) whose
+/// content type does not start with one of the `excluded` prefixes. SVG stays
+/// compressible despite the `image/` prefix.
+#[derive(Clone)]
+pub struct CompressionPredicate {
+    min_size: SizeAbove,
+    excluded: Arc<[String]>,
+}
+
+impl CompressionPredicate {
+    pub fn new(min_size: u16, excluded: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            min_size: SizeAbove::new(min_size),
+            excluded: excluded.into_iter().collect(),
+        }
+    }
+
+    fn is_excluded(&self, headers: &HeaderMap) -> bool {
+        let content_type = headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        if content_type.starts_with("image/svg+xml") {
+            return false;
+        }
+        self.excluded
+            .iter()
+            .any(|prefix| content_type.starts_with(prefix.as_str()))
+    }
+}
+
+impl Predicate for CompressionPredicate {
+    fn should_compress<B>(&self, response: &axum::http::Response<B>) -> bool
+    where
+        B: axum::body::HttpBody,
+    {
+        // `206 multipart/byteranges` carries no top-level `Content-Range` but is a range too
+        response.status() != StatusCode::PARTIAL_CONTENT
+            && !self.is_excluded(response.headers())
+            && self.min_size.should_compress(response)
+    }
+}
+
+/// Placed outside the compression layer: a strong `ETag` identifies the exact
+/// bytes of the uncompressed body, so once `Content-Encoding` is set it is turned
+/// into a weak validator (`W/"…"`) — still valid for `If-None-Match`.
+pub async fn compressed_etag_middleware(req: Request<Body>, next: Next) -> Response {
+    let mut response = next.run(req).await;
+    weaken_etag(response.headers_mut());
+    response
+}
+
+fn weaken_etag(headers: &mut HeaderMap) {
+    if !headers.contains_key(header::CONTENT_ENCODING) {
+        return;
+    }
+    let Some(etag) = headers.get(header::ETAG).and_then(|v| v.to_str().ok()) else {
+        return;
+    };
+    if etag.starts_with("W/") {
+        return;
+    }
+    if let Ok(weak) = HeaderValue::from_str(&format!("W/{etag}")) {
+        headers.insert(header::ETAG, weak);
+    }
+}
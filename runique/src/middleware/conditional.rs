@@ -0,0 +1,151 @@
+//! Conditional GET — strong ETags (SHA-256 of the body), `If-None-Match` → `304 Not Modified`.
+//! Used by the static files service and [`Request::render_cached`](crate::context::Request::render_cached).
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{HeaderMap, HeaderValue, Method, StatusCode, header, response::Parts},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+/// Bodies above this size are not hashed (`If-Modified-Since` still applies to files).
+pub const MAX_ETAG_BODY: usize = 8 * 1024 * 1024;
+
+/// Bound of [`EtagCache`] — cleared when reached.
+const MAX_CACHED_ETAGS: usize = 4096;
+
+/// Headers a `304` keeps (RFC 9110 §15.4.5) — everything else, body-related or
+/// request-specific (CSP nonce, cookies…), is dropped.
+const NOT_MODIFIED_HEADERS: &[header::HeaderName] = &[
+    header::ETAG,
+    header::CACHE_CONTROL,
+    header::CONTENT_LOCATION,
+    header::DATE,
+    header::EXPIRES,
+    header::LAST_MODIFIED,
+    header::VARY,
+];
+
+/// Strong ETag of `bytes`: quoted hex of the first 16 bytes of their SHA-256.
+pub fn strong_etag(bytes: &[u8]) -> String {
+    format!("\"{}\"", hex::encode(&Sha256::digest(bytes)[..16]))
+}
+
+/// `If-None-Match` of `headers` matches `etag` — weak comparison, `*` matches any
+/// (a gzip'd response carries `W/"…"`, still a hit).
+pub fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let target = opaque(etag);
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|tag| tag.trim() == "*" || opaque(tag) == target)
+}
+
+/// `304 Not Modified` keeping only the validator and caching headers of `parts`.
+pub fn not_modified(parts: &Parts) -> Response {
+    let mut response = StatusCode::NOT_MODIFIED.into_response();
+    for name in NOT_MODIFIED_HEADERS {
+        for value in parts.headers.get_all(name) {
+            response.headers_mut().append(name, value.clone());
+        }
+    }
+    response
+}
+
+/// (path, `Last-Modified`, `Content-Length`) — a file version.
+type FileKey = (String, String, String);
+
+/// ETags of the static files already hashed, shared by the static services.
+#[derive(Clone, Default)]
+pub struct EtagCache(Arc<RwLock<HashMap<FileKey, String>>>);
+
+impl EtagCache {
+    fn get(&self, key: &FileKey) -> Option<String> {
+        self.0.read().ok()?.get(key).cloned()
+    }
+
+    fn insert(&self, key: FileKey, etag: String) {
+        if let Ok(mut map) = self.0.write() {
+            if map.len() >= MAX_CACHED_ETAGS {
+                map.clear();
+            }
+            map.insert(key, etag);
+        }
+    }
+}
+
+/// Static files: adds a strong ETag to `200` GET responses (HEAD once the file was
+/// hashed, for `If-Range`) and answers `304` when
+/// `If-None-Match` matches. With `If-None-Match` present, `If-Modified-Since` is
+/// ignored (RFC 9110 §13.1.3); alone, it is handled by `ServeDir` from the file mtime.
+pub async fn static_etag_middleware(
+    State(cache): State<EtagCache>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
+    let head = req.method() == Method::HEAD;
+    if req.method() != Method::GET && !head {
+        return next.run(req).await;
+    }
+    let conditional = req.headers().clone();
+    if conditional.contains_key(header::IF_NONE_MATCH) {
+        req.headers_mut().remove(header::IF_MODIFIED_SINCE);
+    }
+    let path = req.uri().path().to_string();
+
+    let response = next.run(req).await;
+    if response.status() != StatusCode::OK || response.headers().contains_key(header::ETAG) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let header_str = |name| {
+        parts
+            .headers
+            .get(name)
+            .and_then(|v: &HeaderValue| v.to_str().ok())
+            .map(str::to_string)
+    };
+    let length = header_str(header::CONTENT_LENGTH);
+    let key = header_str(header::LAST_MODIFIED)
+        .zip(length.clone())
+        .map(|(modified, length)| (path, modified, length));
+
+    let (body, etag) = match key.as_ref().and_then(|k| cache.get(k)) {
+        Some(etag) => (body, etag),
+        // HEAD has no body to hash: only an already known ETag is added
+        None if head => return Response::from_parts(parts, body),
+        None => {
+            let small = length
+                .and_then(|l| l.parse::<usize>().ok())
+                .is_some_and(|l| l <= MAX_ETAG_BODY);
+            if !small {
+                return Response::from_parts(parts, body);
+            }
+            let Ok(bytes) = axum::body::to_bytes(body, MAX_ETAG_BODY).await else {
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            };
+            let etag = strong_etag(&bytes);
+            if let Some(key) = key {
+                cache.insert(key, etag.clone());
+            }
+            (Body::from(bytes), etag)
+        }
+    };
+
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        parts.headers.insert(header::ETAG, value);
+    }
+    if if_none_match(&conditional, &etag) {
+        return not_modified(&parts);
+    }
+    Response::from_parts(parts, body)
+}
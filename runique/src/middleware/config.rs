@@ -1,4 +1,5 @@
-//! Centralized configuration of Runique middlewares — session, CSP, CSRF, debug errors, rate limit.
+//! Centralized configuration of Runique middlewares — session, CSP, CSRF, debug errors, rate limit, compression,
+//! append slash.
 
 /// Centralized configuration of all Runique middlewares
 ///
@@ -26,6 +27,11 @@ pub struct MiddlewareConfig {
     /// Disable only if you handle errors manually in each handler.
     pub enable_debug_errors: bool,
     pub enable_cache: bool,
+    /// Compresses responses (gzip/brotli) negotiated through `Accept-Encoding`.
+    pub enable_compression: bool,
+    /// Redirects a `404` to the same path with or without its trailing slash when that
+    /// path is a registered route. Off by default: some APIs are slash-sensitive.
+    pub append_slash: bool,
     pub exclusive_login: bool,
 }
 
@@ -37,6 +43,8 @@ impl Default for MiddlewareConfig {
             enable_host_validation: true,
             enable_debug_errors: true,
             enable_cache: true,
+            enable_compression: true,
+            append_slash: false,
             exclusive_login: false,
         }
     }
@@ -57,6 +65,8 @@ impl MiddlewareConfig {
             enable_host_validation: false,
             enable_debug_errors: true, // always mounted — config.debug handles content
             enable_cache: get_bool("RUNIQUE_ENABLE_CACHE", true),
+            enable_compression: get_bool("RUNIQUE_ENABLE_COMPRESSION", true),
+            append_slash: get_bool("RUNIQUE_APPEND_SLASH", false),
             exclusive_login: false,
         }
     }
@@ -69,6 +79,8 @@ impl MiddlewareConfig {
             enable_host_validation: true,
             enable_debug_errors: true,
             enable_cache: true,
+            enable_compression: true,
+            append_slash: false,
             exclusive_login: false,
         }
     }
@@ -81,6 +93,8 @@ impl MiddlewareConfig {
             enable_host_validation: false,
             enable_debug_errors: true,
             enable_cache: false,
+            enable_compression: false,
+            append_slash: false,
             exclusive_login: false,
         }
     }
@@ -93,6 +107,8 @@ impl MiddlewareConfig {
             enable_host_validation: true,
             enable_debug_errors: true,
             enable_cache: true,
+            enable_compression: true,
+            append_slash: false,
             exclusive_login: false,
         }
     }
@@ -125,6 +141,20 @@ impl MiddlewareConfig {
         self
     }
 
+    /// Enables or disables response compression.
+    #[must_use]
+    pub fn with_compression(mut self, enable: bool) -> Self {
+        self.enable_compression = enable;
+        self
+    }
+
+    /// Enables or disables the trailing-slash redirect on `404`.
+    #[must_use]
+    pub fn with_append_slash(mut self, enable: bool) -> Self {
+        self.append_slash = enable;
+        self
+    }
+
     /// Enables or disables `Host` header validation against the allowed hosts list.
     #[must_use]
     pub fn with_host_validation(mut self, enable: bool) -> Self {
@@ -6,6 +6,7 @@ use crate::utils::{
 use crate::{
     config::RuniqueConfig,
     errors::error::{ErrorContext, ErrorType, RuniqueError},
+    middleware::request_id::RequestId,
     utils::csrf::CsrfToken,
     utils::trad::t,
 };
@@ -66,6 +67,7 @@ pub async fn error_handler_middleware(
 ) -> Response {
     // --- Collect request info ---
     let csrf_token: Option<String> = request.extensions().get::<CsrfToken>().map(|t| t.0.clone());
+    let request_id: Option<String> = request.extensions().get::<RequestId>().map(|r| r.0.clone());
     let request_helper = RequestInfoHelper {
         method: request.method().to_string(),
         path: request.uri().path().to_string(),
@@ -94,23 +96,25 @@ pub async fn error_handler_middleware(
     {
         // 429: direct rendering, no debug page
         if status == StatusCode::TOO_MANY_REQUESTS {
-            return render_429(&tera, &config, csrf_token);
+            return render_429(&tera, &config, csrf_token, request_id);
         }
 
         // 503: capacity/overload — direct rendering with Retry-After, no debug page
         if status == StatusCode::SERVICE_UNAVAILABLE {
-            return render_503(&tera, &config, csrf_token);
+            return render_503(&tera, &config, csrf_token, request_id);
         }
 
         let error_ctx = build_error_context(&response, &request_helper, &tera);
 
         // --- Render according to debug or production mode ---
         if config.debug {
-            return render_debug_error_from_context(&tera, &config, &error_ctx, csrf_token);
+            return render_debug_error_from_context(
+                &tera, &config, &error_ctx, csrf_token, request_id,
+            );
         } else {
             return match error_ctx.error_type {
-                ErrorType::NotFound => render_404(&tera, &config, csrf_token),
-                _ => render_500(&tera, &config, csrf_token),
+                ErrorType::NotFound => render_404(&tera, &config, csrf_token, request_id),
+                _ => render_500(&tera, &config, csrf_token, request_id),
             };
         }
     }
@@ -226,9 +230,42 @@ fn inject_security_headers(headers: &mut axum::http::HeaderMap) {
 
 // --- Render Helpers ---
 
-fn render_404(tera: &Tera, config: &RuniqueConfig, csrf_token: Option<String>) -> Response {
+/// Renders `403.html` (fallback page if the template fails) — used by the
+/// `permission_required` guard.
+pub(crate) fn render_403(
+    tera: &Tera,
+    config: &RuniqueConfig,
+    csrf_token: Option<String>,
+    request_id: Option<String>,
+) -> Response {
+    let mut context = Context::new();
+    inject_global_vars(&mut context, config, csrf_token, request_id);
+    context.insert("error_title", &t("html.403_title"));
+    context.insert("error_text", &t("html.403_text"));
+    context.insert("back_home", &t("html.back_home"));
+
+    let rendered = tera
+        .render("403.html", &context)
+        .or_else(|_| tera.render("403", &context));
+    let mut response = match rendered {
+        Ok(html) => (StatusCode::FORBIDDEN, Html(html)).into_response(),
+        Err(e) => {
+            crate::runique_log!(errors_render_level(), error = %e, template = "403.html", "failed to render error template");
+            fallback_403_html()
+        }
+    };
+    inject_security_headers(response.headers_mut());
+    response
+}
+
+fn render_404(
+    tera: &Tera,
+    config: &RuniqueConfig,
+    csrf_token: Option<String>,
+    request_id: Option<String>,
+) -> Response {
     let mut context = Context::new();
-    inject_global_vars(&mut context, config, csrf_token);
+    inject_global_vars(&mut context, config, csrf_token, request_id);
     context.insert("error_title", &t("html.404_title"));
     context.insert("error_text", &t("html.404_text"));
     context.insert("back_home", &t("html.back_home"));
@@ -248,9 +285,14 @@ fn render_404(tera: &Tera, config: &RuniqueConfig, csrf_token: Option<String>) -
     response
 }
 
-fn render_429(tera: &Tera, config: &RuniqueConfig, csrf_token: Option<String>) -> Response {
+fn render_429(
+    tera: &Tera,
+    config: &RuniqueConfig,
+    csrf_token: Option<String>,
+    request_id: Option<String>,
+) -> Response {
     let mut context = Context::new();
-    inject_global_vars(&mut context, config, csrf_token);
+    inject_global_vars(&mut context, config, csrf_token, request_id);
     context.insert("error_title", &t("html.429_title"));
     context.insert("error_text", &t("html.429_text"));
     context.insert("back_home", &t("html.back_home"));
@@ -272,9 +314,14 @@ fn render_429(tera: &Tera, config: &RuniqueConfig, csrf_token: Option<String>) -
 /// Retry delay (seconds) advertised on a 503 so clients back off before retrying.
 const SERVICE_UNAVAILABLE_RETRY_AFTER_SECS: u32 = 30;
 
-fn render_503(tera: &Tera, config: &RuniqueConfig, csrf_token: Option<String>) -> Response {
+fn render_503(
+    tera: &Tera,
+    config: &RuniqueConfig,
+    csrf_token: Option<String>,
+    request_id: Option<String>,
+) -> Response {
     let mut context = Context::new();
-    inject_global_vars(&mut context, config, csrf_token);
+    inject_global_vars(&mut context, config, csrf_token, request_id);
     context.insert("error_title", &t("html.503_title"));
     context.insert("error_text", &t("html.503_text"));
     context.insert("back_home", &t("html.back_home"));
@@ -300,9 +347,14 @@ fn render_503(tera: &Tera, config: &RuniqueConfig, csrf_token: Option<String>) -
     response
 }
 
-fn render_500(tera: &Tera, config: &RuniqueConfig, csrf_token: Option<String>) -> Response {
+fn render_500(
+    tera: &Tera,
+    config: &RuniqueConfig,
+    csrf_token: Option<String>,
+    request_id: Option<String>,
+) -> Response {
     let mut context = Context::new();
-    inject_global_vars(&mut context, config, csrf_token);
+    inject_global_vars(&mut context, config, csrf_token, request_id);
     context.insert("error_title", &t("html.500_title"));
     context.insert("error_text", &t("html.500_text"));
     context.insert("back_home", &t("html.back_home"));
@@ -333,6 +385,7 @@ fn render_debug_error_from_context(
     config: &RuniqueConfig,
     error_ctx: &ErrorContext,
     csrf_token: Option<String>,
+    request_id: Option<String>,
 ) -> Response {
     let mut context = match Context::from_serialize(error_ctx) {
         Ok(ctx) => ctx,
@@ -341,7 +394,7 @@ fn render_debug_error_from_context(
             return critical_error_html(&format!("Serialization Error: {e}"));
         }
     };
-    inject_global_vars(&mut context, config, csrf_token);
+    inject_global_vars(&mut context, config, csrf_token, request_id);
     insert_debug_messages(&mut context);
 
     let mut response = match tera.render("debug.html", &context) {
@@ -360,18 +413,51 @@ fn render_debug_error_from_context(
     response
 }
 
-fn inject_global_vars(context: &mut Context, config: &RuniqueConfig, csrf_token: Option<String>) {
+fn inject_global_vars(
+    context: &mut Context,
+    config: &RuniqueConfig,
+    csrf_token: Option<String>,
+    request_id: Option<String>,
+) {
     context.insert("static_runique", &config.static_files.static_runique_url);
     context.insert("timestamp", &Utc::now().to_rfc3339());
     if let Some(token) = csrf_token {
         context.insert("csrf_token", &token);
     }
+    if let Some(id) = request_id {
+        context.insert("request_id", &id);
+        context.insert("request_id_label", &t("html.request_id"));
+    }
     context.insert("debug", &config.debug);
     context.insert("lang", &crate::utils::trad::current_lang().code());
 }
 
 // --- FALLBACKS ---
 
+fn fallback_403_html() -> Response {
+    let lang = crate::utils::trad::current_lang().code();
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html lang="{lang}">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>{title}</title>
+</head>
+<body>
+    <h1>{title}</h1>
+    <p>{text}</p>
+    <a href="/">{back}</a>
+</body>
+</html>"#,
+        lang = lang,
+        title = t("html.403_title"),
+        text = t("html.403_text"),
+        back = t("html.back_home"),
+    );
+    (StatusCode::FORBIDDEN, Html(html)).into_response()
+}
+
 fn fallback_404_html() -> Response {
     let lang = crate::utils::trad::current_lang().code();
     let html = format!(
@@ -717,6 +803,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fallback_403_returns_forbidden() {
+        let resp = super::fallback_403_html();
+        assert_eq!(resp.status(), axum::http::StatusCode::FORBIDDEN);
+    }
+
     #[test]
     fn test_fallback_404_returns_not_found() {
         let resp = super::fallback_404_html();
@@ -1,13 +1,25 @@
-//! Runique middlewares — security (CSP, CSRF, hosts), session, rate limit, error handling.
+//! Runique middlewares — append slash, security (CSP, CSRF, hosts), session, rate limit, compression, conditional GET, range requests, request id, cache-busting, error handling.
+pub mod append_slash;
+pub mod cache_busting;
+pub mod compression;
+pub mod conditional;
 pub mod dev;
 pub mod errors;
+pub mod range;
+pub mod request_id;
 pub mod security;
 pub mod session;
 
 pub mod config;
 
+pub use append_slash::*;
+pub use cache_busting::*;
+pub use compression::*;
+pub use conditional::*;
 pub use config::*;
 pub use dev::*;
 pub use errors::*;
+pub use range::*;
+pub use request_id::*;
 pub use security::*;
 pub use session::*;
@@ -0,0 +1,297 @@
+//! Range requests on static files — `If-Range` and multi-range (`multipart/byteranges`)
+//! on top of `ServeDir`, which serves single ranges itself (`206`, `416`).
+use axum::{
+    body::{Body, Bytes, HttpBody},
+    http::{HeaderMap, HeaderValue, Method, Request, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use futures_util::{StreamExt, future::BoxFuture, stream};
+use std::{
+    convert::Infallible,
+    task::{Context, Poll},
+};
+use tower::{BoxError, Layer, Service, ServiceExt};
+
+/// More ranges than this in one request are answered with the whole file.
+pub const MAX_RANGES: usize = 16;
+
+/// Wraps the static files service with [`StaticRange`].
+#[derive(Clone, Copy, Default)]
+pub struct StaticRangeLayer;
+
+impl<S> Layer<S> for StaticRangeLayer {
+    type Service = StaticRange<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        StaticRange { inner }
+    }
+}
+
+/// Static files service adding to `ServeDir`:
+/// - `If-Range` — the `Range` is dropped (full `200`) unless the validator still matches;
+/// - several ranges — one `206 multipart/byteranges` response, each part streamed
+///   through a single-range sub-request (the file is never read whole).
+#[derive(Clone)]
+pub struct StaticRange<S> {
+    inner: S,
+}
+
+impl<S, B> Service<Request<Body>> for StaticRange<S>
+where
+    S: Service<Request<Body>, Response = Response<B>, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send,
+    B: HttpBody<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxError>,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Response, Infallible>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let clone = self.inner.clone();
+        let inner = std::mem::replace(&mut self.inner, clone);
+        Box::pin(async move { Ok(serve(inner, req).await) })
+    }
+}
+
+async fn serve<S, B>(inner: S, mut req: Request<Body>) -> Response
+where
+    S: Service<Request<Body>, Response = Response<B>, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send,
+    B: HttpBody<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxError>,
+{
+    let Some(range) = req
+        .headers()
+        .get(header::RANGE)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+    else {
+        return call(inner, req).await;
+    };
+    if req.method() != Method::GET {
+        return call(inner, req).await;
+    }
+
+    let multi = range.contains(',');
+    let if_range = req.headers().get(header::IF_RANGE).cloned();
+    if !multi && if_range.is_none() {
+        return call(inner, req).await;
+    }
+
+    let probe = call(inner.clone(), head_of(&req)).await;
+    if let Some(validator) = if_range
+        && !if_range_matches(&validator, probe.headers())
+    {
+        req.headers_mut().remove(header::RANGE);
+        return call(inner, req).await;
+    }
+    if !multi || probe.status() != StatusCode::OK {
+        return call(inner, req).await;
+    }
+    let Some(size) = probe
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
+    else {
+        return call(inner, req).await;
+    };
+
+    match parse_ranges(&range, size) {
+        None => {
+            req.headers_mut().remove(header::RANGE);
+            call(inner, req).await
+        }
+        Some(ranges) if ranges.is_empty() => unsatisfiable(size),
+        Some(ranges) if ranges.len() == 1 => {
+            let (start, end) = ranges[0];
+            set_range(req.headers_mut(), start, end);
+            call(inner, req).await
+        }
+        Some(ranges) => multipart(inner, req, probe.headers(), ranges, size),
+    }
+}
+
+async fn call<S, B>(inner: S, req: Request<Body>) -> Response
+where
+    S: Service<Request<Body>, Response = Response<B>, Error = Infallible>,
+    B: HttpBody<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxError>,
+{
+    match inner.oneshot(req).await {
+        Ok(resp) => resp.map(Body::new),
+        Err(never) => match never {},
+    }
+}
+
+/// `HEAD` of the same file, without conditional headers — size, type and validators.
+fn head_of(req: &Request<Body>) -> Request<Body> {
+    let mut head = Request::builder()
+        .method(Method::HEAD)
+        .uri(req.uri().clone())
+        .body(Body::empty())
+        .expect("valid probe request");
+    *head.headers_mut() = req.headers().clone();
+    for name in [
+        header::RANGE,
+        header::IF_RANGE,
+        header::IF_NONE_MATCH,
+        header::IF_MODIFIED_SINCE,
+    ] {
+        head.headers_mut().remove(name);
+    }
+    head
+}
+
+/// `If-Range`: an entity tag must match the current `ETag` strongly, a date must
+/// equal `Last-Modified` exactly (RFC 9110 §13.1.5).
+fn if_range_matches(validator: &HeaderValue, current: &HeaderMap) -> bool {
+    let Ok(validator) = validator.to_str() else {
+        return false;
+    };
+    let current = |name| {
+        current
+            .get(name)
+            .and_then(|v: &HeaderValue| v.to_str().ok())
+    };
+    if validator.starts_with('"') {
+        current(header::ETAG) == Some(validator)
+    } else if validator.starts_with("W/") {
+        false
+    } else {
+        current(header::LAST_MODIFIED) == Some(validator)
+    }
+}
+
+/// `bytes=0-99, 200-, -500` resolved against `size`, sorted and coalesced.
+/// `None` — not a byte range set (or too many ranges): the whole file is sent.
+/// `Some(empty)` — no satisfiable range (`416`).
+pub fn parse_ranges(header: &str, size: u64) -> Option<Vec<(u64, u64)>> {
+    let specs = header.trim().strip_prefix("bytes=")?;
+    let specs: Vec<&str> = specs.split(',').map(str::trim).collect();
+    if specs.len() > MAX_RANGES {
+        return None;
+    }
+    let mut ranges = Vec::new();
+    for spec in specs {
+        let (start, end) = spec.split_once('-')?;
+        let range = match (start.trim(), end.trim()) {
+            ("", suffix) => {
+                let suffix: u64 = suffix.parse().ok()?;
+                (suffix > 0 && size > 0).then(|| (size.saturating_sub(suffix), size - 1))
+            }
+            (start, "") => {
+                let start: u64 = start.parse().ok()?;
+                (start < size).then(|| (start, size - 1))
+            }
+            (start, end) => {
+                let (start, end): (u64, u64) = (start.parse().ok()?, end.parse().ok()?);
+                if start > end {
+                    return None;
+                }
+                (start < size).then(|| (start, end.min(size - 1)))
+            }
+        };
+        ranges.extend(range);
+    }
+    ranges.sort_unstable();
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    Some(merged)
+}
+
+fn set_range(headers: &mut HeaderMap, start: u64, end: u64) {
+    if let Ok(value) = HeaderValue::from_str(&format!("bytes={start}-{end}")) {
+        headers.insert(header::RANGE, value);
+    }
+}
+
+fn unsatisfiable(size: u64) -> Response {
+    let mut response = StatusCode::RANGE_NOT_SATISFIABLE.into_response();
+    if let Ok(value) = HeaderValue::from_str(&format!("bytes */{size}")) {
+        response.headers_mut().insert(header::CONTENT_RANGE, value);
+    }
+    response
+}
+
+/// `206 multipart/byteranges`: every part is fetched lazily from `inner` as a
+/// single range, so only the requested slices are read.
+fn multipart<S, B>(
+    inner: S,
+    req: Request<Body>,
+    probe: &HeaderMap,
+    ranges: Vec<(u64, u64)>,
+    size: u64,
+) -> Response
+where
+    S: Service<Request<Body>, Response = Response<B>, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send,
+    B: HttpBody<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxError>,
+{
+    let boundary = uuid::Uuid::new_v4().simple().to_string();
+    let content_type = probe
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream")
+        .to_string();
+    let part_head = |start: u64, end: u64| {
+        format!(
+            "\r\n--{boundary}\r\n{}: {content_type}\r\n{}: bytes {start}-{end}/{size}\r\n\r\n",
+            header::CONTENT_TYPE,
+            header::CONTENT_RANGE,
+        )
+    };
+    let closing = format!("\r\n--{boundary}--\r\n");
+    let length: u64 = ranges
+        .iter()
+        .map(|&(start, end)| part_head(start, end).len() as u64 + end - start + 1)
+        .sum::<u64>()
+        + closing.len() as u64;
+
+    let (parts, _) = req.into_parts();
+    let heads: Vec<String> = ranges.iter().map(|&(s, e)| part_head(s, e)).collect();
+    let body = stream::iter(ranges.into_iter().zip(heads))
+        .then(move |((start, end), head)| {
+            let mut sub = Request::from_parts(parts.clone(), Body::empty());
+            set_range(sub.headers_mut(), start, end);
+            let inner = inner.clone();
+            async move {
+                let resp = call(inner, sub).await;
+                let head = stream::once(async move { Ok(Bytes::from(head)) });
+                if resp.status() != StatusCode::PARTIAL_CONTENT {
+                    let error = axum::Error::new("static file changed during a range request");
+                    return head.chain(stream::once(async move { Err(error) })).boxed();
+                }
+                head.chain(resp.into_body().into_data_stream()).boxed()
+            }
+        })
+        .flatten()
+        .chain(stream::once(async move { Ok(Bytes::from(closing)) }));
+
+    let mut response = Response::new(Body::from_stream(body));
+    *response.status_mut() = StatusCode::PARTIAL_CONTENT;
+    let headers = response.headers_mut();
+    if let Ok(value) = HeaderValue::from_str(&format!("multipart/byteranges; boundary={boundary}"))
+    {
+        headers.insert(header::CONTENT_TYPE, value);
+    }
+    headers.insert(header::CONTENT_LENGTH, HeaderValue::from(length));
+    headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    for name in [header::ETAG, header::LAST_MODIFIED] {
+        if let Some(value) = probe.get(&name) {
+            headers.insert(name, value.clone());
+        }
+    }
+    response
+}
@@ -0,0 +1,64 @@
+//! Request id — correlates the log lines, the response and the error page of one request.
+use axum::{
+    body::Body,
+    extract::State,
+    http::{HeaderName, HeaderValue, Request},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Instrument;
+
+/// Header read and echoed when none is configured.
+pub const DEFAULT_REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Id of the current request — the incoming header value when usable, a UUID v4
+/// otherwise.
+///
+/// Injected into extensions by [`request_id_middleware`]; `req.request_id` in
+/// handlers, `request_id` in templates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// An incoming id ends up in every log line: printable ASCII only, bounded length.
+fn usable(value: &HeaderValue) -> Option<&str> {
+    value
+        .to_str()
+        .ok()
+        .filter(|v| !v.is_empty() && v.len() <= 128 && v.bytes().all(|b| b.is_ascii_graphic()))
+}
+
+/// Reads the id from the `header` request header or generates one, stores it as a
+/// [`RequestId`] extension, runs the rest of the stack inside a `request` span
+/// carrying it, and echoes it in the same response header.
+pub async fn request_id_middleware(
+    State(header): State<HeaderName>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
+    let id = req
+        .headers()
+        .get(&header)
+        .and_then(usable)
+        .map(str::to_string)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    req.extensions_mut().insert(RequestId(id.clone()));
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %id,
+        method = %req.method(),
+        path = %req.uri().path(),
+    );
+    let mut response = next.run(req).instrument(span).await;
+
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(header, value);
+    }
+    response
+}
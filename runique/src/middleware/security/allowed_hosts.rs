@@ -8,6 +8,113 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::sync::{LazyLock, Mutex};
+
+/// Overrides declared by route groups, moved into the [`HostPolicy`] by `build()`.
+static PENDING_HOST_OVERRIDES: LazyLock<Mutex<Vec<HostOverride>>> =
+    LazyLock::new(|| Mutex::new(Vec::new()));
+
+thread_local! {
+    /// Overrides declared while a route group builds its routes — prefixed by the
+    /// group before they reach `PENDING_HOST_OVERRIDES`.
+    static CAPTURED_HOST_OVERRIDES: RefCell<Option<Vec<HostOverride>>> = const { RefCell::new(None) };
+}
+
+/// Allowed hosts for every path under `prefix`, replacing the global list there.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HostOverride {
+    /// Path prefix — `""` covers every path.
+    pub prefix: String,
+    /// Allowed hosts under the prefix (exact or wildcard prefixed by `.`).
+    pub allowed_hosts: Vec<String>,
+}
+
+impl HostOverride {
+    /// `true` when `path` is `prefix` or below it.
+    fn covers(&self, path: &str) -> bool {
+        path.strip_prefix(self.prefix.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
+}
+
+/// Used by route groups — queues an override for the next `build()`.
+pub(crate) fn register_host_override(override_: HostOverride) {
+    let override_ = CAPTURED_HOST_OVERRIDES.with_borrow_mut(|captured| match captured {
+        Some(captured) => {
+            captured.push(override_);
+            None
+        }
+        None => Some(override_),
+    });
+    if let Some(override_) = override_ {
+        let mut pending = PENDING_HOST_OVERRIDES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        pending.push(override_);
+    }
+}
+
+/// Restores the enclosing capture, even when the routes closure panics.
+struct CaptureGuard(Option<Vec<HostOverride>>);
+
+impl Drop for CaptureGuard {
+    fn drop(&mut self) {
+        let outer = self.0.take();
+        CAPTURED_HOST_OVERRIDES.with_borrow_mut(|captured| *captured = outer);
+    }
+}
+
+/// Runs `f` and returns the overrides it registers instead of queuing them.
+pub(crate) fn capture_host_overrides<R>(f: impl FnOnce() -> R) -> (R, Vec<HostOverride>) {
+    let guard = CaptureGuard(CAPTURED_HOST_OVERRIDES.with_borrow_mut(|c| c.replace(Vec::new())));
+    let result = f();
+    let overrides = CAPTURED_HOST_OVERRIDES
+        .with_borrow_mut(|c| c.take())
+        .unwrap_or_default();
+    drop(guard);
+    (result, overrides)
+}
+
+/// Drains the overrides queued by route groups.
+pub(crate) fn take_host_overrides() -> Vec<HostOverride> {
+    let mut pending = PENDING_HOST_OVERRIDES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    std::mem::take(&mut *pending)
+}
+
+/// `true` when `host` matches an entry of `allowed_hosts`.
+fn host_matches(allowed_hosts: &[String], host: &str) -> bool {
+    fn normalize_host(host: &str) -> &str {
+        if host.starts_with('[') {
+            host.split(']').next().map_or(host, |h| &host[..=h.len()])
+        } else {
+            host.split(':').next().unwrap_or(host)
+        }
+    }
+
+    let host = normalize_host(host);
+
+    allowed_hosts.iter().any(|allowed_raw| {
+        let allowed = allowed_raw.trim();
+
+        if allowed == "*" {
+            return true;
+        }
+
+        // normalization on allowed side too
+        let allowed_host = normalize_host(allowed);
+
+        if let Some(suffix) = allowed_host.strip_prefix('.') {
+            host == suffix
+                || (host.ends_with(allowed_host)
+                    && host.as_bytes()[host.len().saturating_sub(allowed_host.len())] == b'.')
+        } else {
+            allowed_host == host
+        }
+    })
+}
 
 /// Host validation policy: allowlist with wildcard support (`.domain.fr`).
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -16,6 +123,9 @@ pub struct HostPolicy {
     pub allowed_hosts: Vec<String>,
     /// Enables or disables validation. If `false`, all requests pass.
     pub enabled: bool,
+    /// Per-prefix lists replacing `allowed_hosts` — the longest matching prefix wins.
+    #[serde(default)]
+    pub overrides: Vec<HostOverride>,
 }
 
 impl HostPolicy {
@@ -23,38 +133,33 @@ impl HostPolicy {
         Self {
             allowed_hosts,
             enabled,
+            overrides: Vec::new(),
         }
     }
-    #[must_use]
-    pub fn is_host_allowed(&self, host: &str) -> bool {
-        fn normalize_host(host: &str) -> &str {
-            if host.starts_with('[') {
-                host.split(']').next().map_or(host, |h| &host[..=h.len()])
-            } else {
-                host.split(':').next().unwrap_or(host)
-            }
-        }
-
-        let host = normalize_host(host);
-
-        self.allowed_hosts.iter().any(|allowed_raw| {
-            let allowed = allowed_raw.trim();
 
-            if allowed == "*" {
-                return true;
-            }
+    /// Adds per-prefix overrides — on the same prefix, the last one wins.
+    #[must_use]
+    pub fn with_overrides(mut self, overrides: Vec<HostOverride>) -> Self {
+        self.overrides.extend(overrides);
+        self
+    }
 
-            // normalization on allowed side too
-            let allowed_host = normalize_host(allowed);
+    #[must_use]
+    pub fn is_host_allowed(&self, host: &str) -> bool {
+        host_matches(&self.allowed_hosts, host)
+    }
 
-            if let Some(suffix) = allowed_host.strip_prefix('.') {
-                host == suffix
-                    || (host.ends_with(allowed_host)
-                        && host.as_bytes()[host.len().saturating_sub(allowed_host.len())] == b'.')
-            } else {
-                allowed_host == host
-            }
-        })
+    /// Like [`is_host_allowed`](Self::is_host_allowed), with the list of the most
+    /// specific override covering `path` when there is one.
+    #[must_use]
+    pub fn is_host_allowed_for(&self, host: &str, path: &str) -> bool {
+        let allowed = self
+            .overrides
+            .iter()
+            .filter(|o| o.covers(path))
+            .max_by_key(|o| o.prefix.len())
+            .map_or(&self.allowed_hosts, |o| &o.allowed_hosts);
+        host_matches(allowed, host)
     }
 
     pub fn validate(&self, headers: &HeaderMap) -> Result<(), (StatusCode, String)> {
@@ -111,7 +216,10 @@ pub(crate) async fn allowed_hosts_middleware(
         }
     };
 
-    if !engine.security_hosts.is_host_allowed(host) {
+    if !engine
+        .security_hosts
+        .is_host_allowed_for(host, request.uri().path())
+    {
         if let Some(level) = get_log()
             .middleware
             .as_ref()
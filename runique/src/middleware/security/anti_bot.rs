@@ -1,6 +1,6 @@
 //! Anti-bot middleware: injects a session-bound random honeypot field name into every request.
 //! The field name is generated on first visit and stored in session — no recognizable prefix.
-//! On POST, `Request::form()` checks if the field was filled and sets `force_invalid`.
+//! On POST, `Request::form()` flags the form as spam if the field is filled or missing.
 use crate::utils::aliases::AEngine;
 use crate::utils::constante::session_key::session::HP_FIELD_KEY;
 use axum::{body::Body, extract::State, http::Request, middleware::Next, response::Response};
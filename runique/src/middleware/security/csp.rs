@@ -1,4 +1,5 @@
 //! Content Security Policy Middleware: generates CSP headers with nonce per request.
+use crate::cache::page::CachedCsp;
 use crate::context::RequestExtensions;
 use crate::utils::{aliases::AEngine, csp_nonce::CspNonce};
 
@@ -16,7 +17,7 @@ pub const HTMX_STYLE_HASHES: &[&str] = &["'sha256-bsV5JivYxvGywDAZ22EZJKBFip65Ng
 use axum::{
     body::Body,
     extract::State,
-    http::{HeaderValue, Request},
+    http::{HeaderValue, Request, StatusCode},
     middleware::Next,
     response::{IntoResponse, Redirect, Response},
 };
@@ -41,6 +42,10 @@ pub struct SecurityPolicy {
     pub form_action: Vec<String>,
     pub upgrade_insecure_requests: bool,
     pub use_nonce: bool,
+    /// Directives receiving the per-request nonce (`script-src`, `style-src`);
+    /// empty → both.
+    #[serde(default)]
+    pub nonce_directives: Vec<String>,
 }
 
 impl Default for SecurityPolicy {
@@ -69,6 +74,7 @@ impl Default for SecurityPolicy {
             form_action: vec!["'self'".into()],
             upgrade_insecure_requests: false,
             use_nonce: true,
+            nonce_directives: Vec::new(),
         }
     }
 }
@@ -90,6 +96,7 @@ impl SecurityPolicy {
             form_action: vec!["'self'".into()],
             upgrade_insecure_requests: true,
             use_nonce: true,
+            nonce_directives: Vec::new(),
         }
     }
     pub fn permissive() -> Self {
@@ -112,6 +119,7 @@ impl SecurityPolicy {
             form_action: vec!["'self'".into()],
             upgrade_insecure_requests: false,
             use_nonce: false,
+            nonce_directives: Vec::new(),
         }
     }
 
@@ -128,6 +136,12 @@ impl SecurityPolicy {
             }
         }
     }
+    /// Whether `directive` gets the nonce: every nonce-capable directive when
+    /// [`nonce_directives`](Self::nonce_directives) is empty.
+    pub fn nonce_applies(&self, directive: &str) -> bool {
+        self.nonce_directives.is_empty() || self.nonce_directives.iter().any(|d| d == directive)
+    }
+
     #[must_use]
     pub fn to_header_value(&self, nonce: Option<&str>) -> String {
         let mut directives = Vec::new();
@@ -138,7 +152,7 @@ impl SecurityPolicy {
 
         if !self.script_src.is_empty() {
             let mut script_sources = self.script_src.clone();
-            if let Some(n) = nonce.filter(|n| !n.is_empty()) {
+            if let Some(n) = nonce.filter(|n| !n.is_empty() && self.nonce_applies("script-src")) {
                 script_sources.push(format!("'nonce-{n}'"));
                 script_sources.retain(|s| s != "'unsafe-inline'");
             }
@@ -147,7 +161,7 @@ impl SecurityPolicy {
 
         if !self.style_src.is_empty() {
             let mut style_sources = self.style_src.clone();
-            if let Some(n) = nonce.filter(|n| !n.is_empty()) {
+            if let Some(n) = nonce.filter(|n| !n.is_empty() && self.nonce_applies("style-src")) {
                 style_sources.push(format!("'nonce-{n}'"));
                 style_sources.retain(|s| s != "'unsafe-inline'" && s != "'unsafe-hashes'");
                 // ↑ Also remove 'unsafe-hashes' when nonce is present
@@ -202,8 +216,11 @@ pub async fn csp_middleware(
 ) -> Response {
     let mut response: axum::http::Response<Body> = next.run(req).await;
 
+    // A 304 reuses the cached page: its stored CSP must keep the nonce that page was rendered with
     let csp_value = engine.security_csp.to_header_value(None);
-    if let Ok(header) = HeaderValue::from_str(&csp_value) {
+    if response.status() != StatusCode::NOT_MODIFIED
+        && let Ok(header) = HeaderValue::from_str(&csp_value)
+    {
         response
             .headers_mut()
             .insert(axum::http::header::CONTENT_SECURITY_POLICY, header);
@@ -227,11 +244,14 @@ pub async fn security_headers_middleware(
     extensions.inject_request(&mut req);
 
     let mut response = next.run(req).await;
+    // A 304, or a page replayed by `cache_page`, keeps the CSP of the nonce it was rendered with
+    let keeps_csp = response.status() == StatusCode::NOT_MODIFIED
+        || response.extensions().get::<CachedCsp>().is_some();
     let headers = response.headers_mut();
 
-    // Use the nonce to build the CSP
+    // Use the nonce to build the CSP — not when the page keeps its own
     let csp_value = engine.security_csp.to_header_value(Some(nonce.as_str()));
-    if let Ok(header) = HeaderValue::from_str(&csp_value) {
+    if !keeps_csp && let Ok(header) = HeaderValue::from_str(&csp_value) {
         headers.insert(axum::http::header::CONTENT_SECURITY_POLICY, header);
     }
 
@@ -251,14 +271,18 @@ pub async fn security_headers_middleware(
         HeaderValue::from_static("1; mode=block"),
     );
 
-    headers.insert(
-        axum::http::header::REFERRER_POLICY,
-        HeaderValue::from_static("strict-origin-when-cross-origin"),
-    );
+    if let Some(policy) = engine.security_headers.referrer_policy {
+        headers.insert(
+            axum::http::header::REFERRER_POLICY,
+            HeaderValue::from_static(policy.as_str()),
+        );
+    }
 
-    let pp = engine.permissions_policy.to_header_value();
-    if let Ok(v) = HeaderValue::from_str(&pp) {
-        headers.insert("permissions-policy", v);
+    if engine.security_headers.permissions_policy {
+        let pp = engine.permissions_policy.to_header_value();
+        if let Ok(v) = HeaderValue::from_str(&pp) {
+            headers.insert("permissions-policy", v);
+        }
     }
 
     headers.insert(
@@ -278,11 +302,12 @@ pub async fn security_headers_middleware(
 
     // HSTS uniquement si Runique sert réellement du HTTPS (ACME / enforce_https) :
     // l'émettre en HTTP simple est inutile (ignoré) et risqué (lock-in HTTPS d'un an).
-    if engine.config.security.should_emit_hsts() {
-        headers.insert(
-            "strict-transport-security",
-            HeaderValue::from_static("max-age=31536000; includeSubDomains; preload"),
-        );
+    if let Some(hsts) = engine.security_headers.hsts_for(
+        engine.config.debug,
+        engine.config.security.should_emit_hsts(),
+    ) && let Ok(v) = HeaderValue::from_str(&hsts)
+    {
+        headers.insert(axum::http::header::STRICT_TRANSPORT_SECURITY, v);
     }
 
     response
@@ -1,4 +1,5 @@
 //! CSRF Middleware: generates and stores the token in session, validates mutating requests.
+use crate::auth::api_token::bearer_token;
 use crate::auth::session::is_authenticated;
 use crate::context::RequestExtensions;
 use crate::utils::{
@@ -37,6 +38,24 @@ impl Function for CsrfTokenFunction {
     }
 }
 
+/// Request extension set by [`csrf_middleware`] on an exempt path or a bearer-token
+/// request: the Prisme pipeline then treats the submission as CSRF-valid.
+#[derive(Debug, Clone, Copy)]
+pub struct CsrfExempt;
+
+/// `true` when `path` matches one of the [`csrf_exempt`] patterns: an exact path,
+/// or a prefix ending with `*` (`/webhook/*` covers `/webhook/stripe`).
+///
+/// [`csrf_exempt`]: crate::app::staging::MiddlewareStaging::csrf_exempt
+pub fn is_csrf_exempt(patterns: &[String], path: &str) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => path.starts_with(prefix),
+            None => pattern == path,
+        })
+}
+
 pub async fn csrf_middleware(
     State(engine): State<AEngine>,
     session: Session,
@@ -70,15 +89,14 @@ pub async fn csrf_middleware(
         }
     }
 
-    // Skip CSRF for exempt paths (webhooks with their own signature verification)
-    if engine
-        .csrf_exempt_paths
-        .iter()
-        .any(|p| p == req.uri().path())
-    {
-        return next.run(req).await;
-    }
+    // Exempt paths (webhooks with their own signature verification) skip the
+    // validation only: they still get a token for later same-origin requests.
+    // So do bearer-token requests: a browser never attaches that header on its own.
+    let exempt = is_csrf_exempt(&engine.csrf_exempt_paths, req.uri().path())
+        || bearer_token(req.headers()).is_some();
 
+    // New tokens are signed with the primary key only. They are then compared to the
+    // session copy, never re-verified by HMAC: rotating SECRET_KEY keeps them valid.
     let secret = &engine.config.server.secret_key;
 
     // Retrieve or generate the session token
@@ -117,10 +135,11 @@ pub async fn csrf_middleware(
     };
 
     // CSRF verification **ONLY for AJAX requests with header**
-    let requires_csrf = matches!(
-        req.method(),
-        &Method::POST | &Method::PUT | &Method::DELETE | &Method::PATCH
-    );
+    let requires_csrf = !exempt
+        && matches!(
+            req.method(),
+            &Method::POST | &Method::PUT | &Method::DELETE | &Method::PATCH
+        );
 
     if requires_csrf {
         let has_header = req.headers().contains_key("X-CSRF-Token");
@@ -172,6 +191,9 @@ pub async fn csrf_middleware(
         .unwrap_or_else(|_| session_token.clone());
     let extensions = RequestExtensions::new().with_csrf_token(session_token.clone());
     extensions.inject_request(&mut req);
+    if exempt {
+        req.extensions_mut().insert(CsrfExempt);
+    }
 
     let mut res = next.run(req).await;
 
@@ -1,4 +1,5 @@
-//! Security middlewares — allowed hosts, CSP, CSRF, open redirect, permissions policy, rate limiting.
+//! Security middlewares — allowed hosts, CSP, CSRF, open redirect, permissions policy, rate limiting,
+//! security headers.
 pub mod allowed_hosts;
 pub mod anti_bot;
 pub mod csp;
@@ -6,6 +7,7 @@ pub mod csrf;
 pub mod open_redirect;
 pub mod permissions_policy;
 pub mod rate_limit;
+pub mod security_headers;
 pub mod trusted_proxies;
 
 pub use allowed_hosts::*;
@@ -15,4 +17,5 @@ pub use csrf::*;
 pub use open_redirect::*;
 pub use permissions_policy::*;
 pub use rate_limit::*;
+pub use security_headers::*;
 pub use trusted_proxies::*;
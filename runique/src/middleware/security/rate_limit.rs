@@ -1,4 +1,5 @@
-//! Rate limiter by key (IP or other) with sliding window and 429 response.
+//! Rate limiter by key (IP or session) with sliding window, pluggable store and 429 response.
+use crate::utils::constante::session_key::session::SESSION_USER_ID_KEY;
 use crate::utils::trad::t;
 use axum::{
     body::Body,
@@ -13,20 +14,96 @@ use std::{
     time::{Duration, Instant},
 };
 use tokio::time::interval;
+use tower_sessions::Session;
 
-/// Entry per key: (request count in window, window start)
-type Store = Arc<Mutex<HashMap<String, (u32, Instant)>>>;
+/// Counter storage behind a [`RateLimiter`] — in memory by default.
+///
+/// Implement it to share counters between instances (Redis…). Calls happen on the
+/// request path: keep them fast.
+pub trait RateLimitStore: Send + Sync {
+    /// Counts a hit for `key`; returns `true` while the key stays within `max` hits per `window`.
+    fn hit(&self, key: &str, max: u32, window: Duration) -> bool;
+
+    /// Seconds before the window of `key` resets (`0` if unknown or already expired).
+    fn retry_after_secs(&self, key: &str, window: Duration) -> u64;
+
+    /// Drops expired entries — called periodically by [`RateLimiter::spawn_cleanup`].
+    fn purge(&self, _window: Duration) {}
+}
+
+/// Default in-memory store: request count and window start per key.
+#[derive(Default)]
+pub struct MemoryRateLimitStore {
+    entries: Mutex<HashMap<String, (u32, Instant)>>,
+}
+
+impl MemoryRateLimitStore {
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<String, (u32, Instant)>> {
+        match self.entries.lock() {
+            Ok(s) => s,
+            Err(p) => p.into_inner(),
+        }
+    }
+}
+
+impl RateLimitStore for MemoryRateLimitStore {
+    fn hit(&self, key: &str, max: u32, window: Duration) -> bool {
+        let mut store = self.entries();
+        let now = Instant::now();
+        let entry = store.entry(key.to_string()).or_insert((0, now));
+
+        if now.duration_since(entry.1) >= window {
+            // New window
+            *entry = (1, now);
+            true
+        } else if entry.0 < max {
+            entry.0 = entry.0.saturating_add(1);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn retry_after_secs(&self, key: &str, window: Duration) -> u64 {
+        match self.entries().get(key) {
+            Some((_, start)) => {
+                let interval = Instant::now().duration_since(*start);
+                window.saturating_sub(interval).as_secs()
+            }
+            None => 0,
+        }
+    }
+
+    fn purge(&self, window: Duration) {
+        let now = Instant::now();
+        self.entries()
+            .retain(|_, (_, start)| now.duration_since(*start) < window);
+    }
+}
 
-/// Configurable rate limiter — sliding window per key (IP or other)
+/// What identifies a client for rate limiting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitKey {
+    /// Client IP (`ClientIp` from trusted proxies, then `X-Forwarded-For` / `X-Real-IP`)
+    #[default]
+    Ip,
+    /// Session id — falls back to the IP unless the session exists in the store
+    /// (no cookie yet, or a cookie the store does not know)
+    Session,
+}
+
+/// Configurable rate limiter — sliding window per key (IP or session)
 #[derive(Clone)]
 pub struct RateLimiter {
-    store: Store,
+    store: Arc<dyn RateLimitStore>,
     /// Maximum number of requests allowed in the window
     pub max_requests: u32,
     /// Window duration
     pub window: Duration,
     /// If set, only these HTTP methods are counted (others pass through freely)
     pub methods: Option<Vec<Method>>,
+    /// Client identification (IP by default)
+    pub key: RateLimitKey,
 }
 
 impl RateLimiter {
@@ -40,13 +117,28 @@ impl RateLimiter {
     /// ```
     pub fn new() -> Self {
         Self {
-            store: Arc::new(Mutex::new(HashMap::new())),
+            store: Arc::new(MemoryRateLimitStore::default()),
             max_requests: 60,
             window: Duration::from_secs(60),
             methods: None,
+            key: RateLimitKey::Ip,
         }
     }
 
+    /// Replaces the in-memory store (shared counters across instances, Redis…).
+    #[must_use]
+    pub fn store(mut self, store: impl RateLimitStore + 'static) -> Self {
+        self.store = Arc::new(store);
+        self
+    }
+
+    /// Chooses what identifies a client: IP (default) or session id.
+    #[must_use]
+    pub fn key_by(mut self, key: RateLimitKey) -> Self {
+        self.key = key;
+        self
+    }
+
     /// Restricts rate limiting to the given HTTP methods.
     /// GET requests are never counted if POST is the only method listed.
     #[must_use]
@@ -78,12 +170,7 @@ impl RateLimiter {
             let mut ticker = interval(period);
             loop {
                 ticker.tick().await;
-                let mut guard = match store.lock() {
-                    Ok(g) => g,
-                    Err(p) => p.into_inner(),
-                };
-                let now = Instant::now();
-                guard.retain(|_, (_, start)| now.duration_since(*start) < window);
+                store.purge(window);
             }
         });
     }
@@ -92,39 +179,13 @@ impl RateLimiter {
     /// Returns `0` if the key is unknown or if the window is already expired.
     #[must_use]
     pub fn retry_after_secs(&self, key: &str) -> u64 {
-        let store = match self.store.lock() {
-            Ok(s) => s,
-            Err(p) => p.into_inner(),
-        };
-        match store.get(key) {
-            Some((_, start)) => {
-                let interval = Instant::now().duration_since(*start);
-                self.window.saturating_sub(interval).as_secs()
-            }
-            None => 0,
-        }
+        self.store.retry_after_secs(key, self.window)
     }
 
     /// Returns `true` if the key is under the limit, `false` if exceeded
     #[must_use]
     pub fn is_allowed(&self, key: &str) -> bool {
-        let mut store = match self.store.lock() {
-            Ok(s) => s,
-            Err(p) => p.into_inner(),
-        };
-        let now = Instant::now();
-        let entry = store.entry(key.to_string()).or_insert((0, now));
-
-        if now.duration_since(entry.1) >= self.window {
-            // New window
-            *entry = (1, now);
-            true
-        } else if entry.0 < self.max_requests {
-            entry.0 = entry.0.saturating_add(1);
-            true
-        } else {
-            false
-        }
+        self.store.hit(key, self.max_requests, self.window)
     }
 }
 
@@ -140,6 +201,7 @@ impl std::fmt::Debug for RateLimiter {
             .field("max_requests", &self.max_requests)
             .field("window", &self.window)
             .field("methods", &self.methods)
+            .field("key", &self.key)
             .finish()
     }
 }
@@ -168,7 +230,17 @@ fn extract_ip(req: &Request<Body>) -> String {
         .unwrap_or_else(|| "unknown".to_string())
 }
 
+/// Key of a session that exists in the store. The id before loading is only the
+/// cookie sent by the client: loading the record clears it when the store does not
+/// know it, so a made-up cookie falls back to the IP.
+async fn stored_session_key(session: &Session) -> Option<String> {
+    session.id()?;
+    session.get_value(SESSION_USER_ID_KEY).await.ok()?;
+    session.id().map(|id| format!("session:{id}"))
+}
+
 /// Rate limiting middleware — to be applied on sensitive routes (login, etc.)
+/// or to the whole application via `MiddlewareStaging::with_rate_limit`.
 ///
 /// # Example
 /// ```rust,ignore
@@ -194,11 +266,16 @@ pub async fn rate_limit_middleware(
     {
         return next.run(req).await;
     }
-    let ip = req
-        .extensions()
-        .get::<crate::middleware::security::trusted_proxies::ClientIp>()
-        .map(|c| c.0.to_string())
-        .unwrap_or_else(|| extract_ip(&req));
+    let session_id = match (limiter.key, req.extensions().get::<Session>()) {
+        (RateLimitKey::Session, Some(session)) => stored_session_key(session).await,
+        _ => None,
+    };
+    let ip = session_id.unwrap_or_else(|| {
+        req.extensions()
+            .get::<crate::middleware::security::trusted_proxies::ClientIp>()
+            .map(|c| c.0.to_string())
+            .unwrap_or_else(|| extract_ip(&req))
+    });
     if limiter.is_allowed(&ip) {
         next.run(req).await
     } else {
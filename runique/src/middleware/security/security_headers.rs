@@ -0,0 +1,122 @@
+//! HSTS, Referrer-Policy and Permissions-Policy settings of `security_headers_middleware`.
+use serde::{Deserialize, Serialize};
+
+/// `Strict-Transport-Security` value.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hsts {
+    /// `max-age`, in seconds.
+    pub max_age: u64,
+    /// Adds `includeSubDomains`.
+    pub include_subdomains: bool,
+    /// Adds `preload` — only meaningful once the domain is submitted to hstspreload.org.
+    pub preload: bool,
+}
+
+impl Default for Hsts {
+    /// One year, subdomains included, preload.
+    fn default() -> Self {
+        Self {
+            max_age: 31_536_000,
+            include_subdomains: true,
+            preload: true,
+        }
+    }
+}
+
+impl Hsts {
+    /// `max-age` alone — add `includeSubDomains` / `preload` with the methods below.
+    pub fn new(max_age: u64) -> Self {
+        Self {
+            max_age,
+            include_subdomains: false,
+            preload: false,
+        }
+    }
+
+    #[must_use]
+    pub fn include_subdomains(mut self, enable: bool) -> Self {
+        self.include_subdomains = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn preload(mut self, enable: bool) -> Self {
+        self.preload = enable;
+        self
+    }
+
+    /// `max-age=31536000; includeSubDomains; preload`
+    pub fn to_header_value(&self) -> String {
+        let mut value = format!("max-age={}", self.max_age);
+        if self.include_subdomains {
+            value.push_str("; includeSubDomains");
+        }
+        if self.preload {
+            value.push_str("; preload");
+        }
+        value
+    }
+}
+
+/// `Referrer-Policy` value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReferrerPolicy {
+    NoReferrer,
+    NoReferrerWhenDowngrade,
+    Origin,
+    OriginWhenCrossOrigin,
+    SameOrigin,
+    StrictOrigin,
+    #[default]
+    StrictOriginWhenCrossOrigin,
+    UnsafeUrl,
+}
+
+impl ReferrerPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoReferrer => "no-referrer",
+            Self::NoReferrerWhenDowngrade => "no-referrer-when-downgrade",
+            Self::Origin => "origin",
+            Self::OriginWhenCrossOrigin => "origin-when-cross-origin",
+            Self::SameOrigin => "same-origin",
+            Self::StrictOrigin => "strict-origin",
+            Self::StrictOriginWhenCrossOrigin => "strict-origin-when-cross-origin",
+            Self::UnsafeUrl => "unsafe-url",
+        }
+    }
+}
+
+/// Headers of `security_headers_middleware` that can be tuned or omitted (`None`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SecurityHeaders {
+    /// Sent only when Runique serves HTTPS (`ENFORCE_HTTPS` or ACME) — see
+    /// [`SecurityConfig::should_emit_hsts`](crate::config::security::SecurityConfig::should_emit_hsts).
+    pub hsts: Option<Hsts>,
+    /// Also sends HSTS in debug — off by default so `http://localhost` is never pinned.
+    pub hsts_in_debug: bool,
+    pub referrer_policy: Option<ReferrerPolicy>,
+    /// Sends the `Permissions-Policy` configured by `with_permissions_policy`.
+    pub permissions_policy: bool,
+}
+
+impl Default for SecurityHeaders {
+    fn default() -> Self {
+        Self {
+            hsts: Some(Hsts::default()),
+            hsts_in_debug: false,
+            referrer_policy: Some(ReferrerPolicy::default()),
+            permissions_policy: true,
+        }
+    }
+}
+
+impl SecurityHeaders {
+    /// The HSTS value to send, `None` when omitted, in debug, or over plain HTTP.
+    pub fn hsts_for(&self, debug: bool, https: bool) -> Option<String> {
+        if !https || (debug && !self.hsts_in_debug) {
+            return None;
+        }
+        self.hsts.as_ref().map(Hsts::to_header_value)
+    }
+}
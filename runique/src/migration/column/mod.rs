@@ -35,6 +35,13 @@ pub struct ColumnDef {
     pub min_value: Option<i64>,
     pub max_float: Option<f64>,
     pub min_float: Option<f64>,
+    /// Raw SQL `CHECK` expression. When absent, one is derived from
+    /// `min_value`/`max_value` (see [`ColumnDef::check_expr`]).
+    pub check: Option<String>,
+    /// Column documentation: `COMMENT` in the DDL, help text on the generated form field.
+    pub comment: Option<String>,
+    /// Soft-delete marker (`deleted_at`): never exposed as a form field.
+    pub soft_delete: bool,
     /// File upload metadata — pure form concern, ignored by `to_sea_column`.
     pub is_file: bool,
     pub file_kind: Option<FileKind>,
@@ -61,6 +68,9 @@ impl ColumnDef {
             min_value: None,
             max_float: None,
             min_float: None,
+            check: None,
+            comment: None,
+            soft_delete: false,
             is_file: false,
             file_kind: None,
             max_size: None,
@@ -176,6 +186,14 @@ impl ColumnDef {
         self
     }
 
+    /// Nullable `deleted_at` timestamp used by soft deletes
+    /// (pairs with `impl_objects!(Entity, soft_delete = Column::DeletedAt)`).
+    pub fn soft_delete_marker() -> Self {
+        let mut col = Self::new("deleted_at").datetime().nullable();
+        col.soft_delete = true;
+        col
+    }
+
     pub fn timestamp(mut self) -> Self {
         self.col_type = ColumnType::Timestamp;
         self
@@ -221,6 +239,13 @@ impl ColumnDef {
         self
     }
 
+    /// [`enum_type`](Self::enum_type) with the type name and stored values of `E`,
+    /// so the column and `ChoiceField::from_enum::<E>` list the same variants.
+    pub fn enum_of<E: crate::forms::ChoiceEnum>(self) -> Self {
+        let variants = E::choices().into_iter().map(|(value, _)| value).collect();
+        self.enum_type(E::ENUM_NAME, variants)
+    }
+
     pub fn enum_type(mut self, name: impl Into<String>, variants: Vec<String>) -> Self {
         use sea_query::DynIden;
         let name_str = name.into();
@@ -267,6 +292,18 @@ impl ColumnDef {
         self
     }
 
+    /// Raw SQL check constraint, e.g. `ColumnDef::new("rating").integer().check("rating BETWEEN 1 AND 5")`.
+    pub fn check(mut self, expr: impl Into<String>) -> Self {
+        self.check = Some(expr.into());
+        self
+    }
+
+    /// Documentation attached to the column, e.g. `.comment("ISO 4217 currency code")`.
+    pub fn comment(mut self, text: impl Into<String>) -> Self {
+        self.comment = Some(text.into());
+        self
+    }
+
     pub fn required(mut self) -> Self {
         self.nullable = false;
         self
@@ -345,6 +382,16 @@ impl ColumnDef {
             col.unique_key();
         }
 
+        if let Some(check) = self.check_expr() {
+            col.check(check);
+        }
+
+        // Inline `COMMENT '...'` on MySQL; ignored by the Postgres/SQLite builders,
+        // see `ModelSchema::to_migration_sql` for Postgres.
+        if let Some(ref comment) = self.comment {
+            col.comment(comment);
+        }
+
         if let Some(ref val) = self.default {
             col.default(val.clone());
         } else if self.auto_now {
@@ -358,6 +405,108 @@ impl ColumnDef {
         col
     }
 
+    /// Check constraint emitted as `CHECK (...)`: the explicit [`check`](Self::check)
+    /// expression, otherwise one derived from the `min_i64`/`max_i64` bounds.
+    pub fn check_expr(&self) -> Option<sea_query::Expr> {
+        use sea_query::{Alias, Expr, ExprTrait};
+
+        if let Some(ref raw) = self.check {
+            return Some(Expr::cust(raw.clone()));
+        }
+
+        let col = || Expr::col(Alias::new(&self.name));
+        match (self.min_value, self.max_value) {
+            (Some(min), Some(max)) => Some(col().gte(min).and(col().lte(max))),
+            (Some(min), None) => Some(col().gte(min)),
+            (None, Some(max)) => Some(col().lte(max)),
+            (None, None) => None,
+        }
+    }
+
+    /// Lists the schema-relevant attributes that differ between `self` (before)
+    /// and `other` (after). Compared field by field so the diff can report
+    /// exactly what changed; an empty list means the column is unchanged.
+    pub fn changed_attributes(&self, other: &ColumnDef) -> Vec<&'static str> {
+        let mut changes = Vec::new();
+        if self.col_type != other.col_type {
+            changes.push("col_type");
+        }
+        if self.nullable != other.nullable {
+            changes.push("nullable");
+        }
+        if self.unique != other.unique {
+            changes.push("unique");
+        }
+        if self.default != other.default {
+            changes.push("default");
+        }
+        if self.max_length != other.max_length {
+            changes.push("max_length");
+        }
+        if self.min_length != other.min_length {
+            changes.push("min_length");
+        }
+        if self.max_value != other.max_value {
+            changes.push("max_value");
+        }
+        if self.min_value != other.min_value {
+            changes.push("min_value");
+        }
+        if self.max_float != other.max_float {
+            changes.push("max_float");
+        }
+        if self.min_float != other.min_float {
+            changes.push("min_float");
+        }
+        if self.check != other.check {
+            changes.push("check");
+        }
+        if self.comment != other.comment {
+            changes.push("comment");
+        }
+        changes
+    }
+
+    /// Variants added to an `Enum` column between `self` (before) and `other` (after),
+    /// when the change is a pure extension: same enum type, every previous variant
+    /// kept in the same order. `None` when a variant was removed or reordered, the
+    /// type renamed, the column is not an enum, or nothing was added.
+    pub fn enum_variants_added(&self, other: &ColumnDef) -> Option<Vec<String>> {
+        let (ColumnType::Enum { name: before, .. }, ColumnType::Enum { name: after, .. }) =
+            (&self.col_type, &other.col_type)
+        else {
+            return None;
+        };
+        if before.to_string() != after.to_string() {
+            return None;
+        }
+
+        // The previous variants must appear in `other` as a subsequence
+        let mut remaining = other.enum_variants.iter();
+        if !self
+            .enum_variants
+            .iter()
+            .all(|v| remaining.any(|candidate| candidate == v))
+        {
+            return None;
+        }
+
+        let added: Vec<String> = other
+            .enum_variants
+            .iter()
+            .filter(|v| !self.enum_variants.contains(v))
+            .cloned()
+            .collect();
+        (!added.is_empty()).then_some(added)
+    }
+
+    /// True when the only differences with `other` are documentation (comment):
+    /// no structural ALTER is needed, only the comment has to be refreshed.
+    pub fn is_metadata_only_change(&self, other: &ColumnDef) -> bool {
+        let changes = self.changed_attributes(other);
+        !changes.is_empty() && changes.iter().all(|c| *c == "comment")
+    }
+
     //__ variant of postgres
     pub fn inet(mut self) -> Self {
         self.col_type = ColumnType::Inet;
@@ -396,7 +545,7 @@ impl ColumnDef {
         };
         use crate::forms::generic::GenericField;
 
-        if self.ignored {
+        if self.ignored || self.soft_delete {
             return None;
         }
 
@@ -548,21 +697,28 @@ impl ColumnDef {
         if required && !self.auto_now && !self.auto_now_update {
             field.set_required(true, None);
         }
+        if let Some(ref comment) = self.comment {
+            field.set_help_text(comment);
+        }
 
         Some(field)
     }
 
     fn format_label(&self) -> String {
-        self.name
-            .split('_')
-            .map(|word| {
-                let mut chars = word.chars();
-                match chars.next() {
-                    None => String::new(),
-                    Some(f) => f.to_uppercase().collect::<String>() + chars.as_str(),
-                }
-            })
-            .collect::<Vec<_>>()
-            .join(" ")
+        label_from_name(&self.name)
     }
 }
+
+/// Form label derived from a field name: `published_at` → `Published At`.
+pub(crate) fn label_from_name(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(f) => f.to_uppercase().collect::<String>() + chars.as_str(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
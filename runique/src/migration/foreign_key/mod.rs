@@ -3,7 +3,9 @@
 //! [`ForeignKeyDef`] follows the builder pattern:
 //! `ForeignKeyDef::new("user_id").references("users").on_delete(ForeignKeyAction::Cascade)`.
 //! The [`ForeignKeyDef::to_sea_foreign_key`] method produces the [`sea_query::ForeignKeyCreateStatement`].
-use sea_query::ForeignKeyAction;
+//! A `from_column` the schema does not declare is created from [`ForeignKeyDef::to_column_def`].
+use crate::migration::{column::ColumnDef, schema::ModelSchema};
+use sea_query::{ColumnType, ForeignKeyAction};
 
 /// Foreign key definition.
 #[derive(Debug, Clone)]
@@ -13,6 +15,10 @@ pub struct ForeignKeyDef {
     pub to_column: String,
     pub on_delete: ForeignKeyAction,
     pub on_update: ForeignKeyAction,
+    /// Type of the referenced key — `None`: unknown, integer assumed.
+    pub key_type: Option<ColumnType>,
+    /// The FK may be `NULL` (auto-created column is nullable).
+    pub optional: bool,
 }
 
 impl ForeignKeyDef {
@@ -23,6 +29,8 @@ impl ForeignKeyDef {
             to_column: "id".to_string(),
             on_delete: ForeignKeyAction::NoAction,
             on_update: ForeignKeyAction::NoAction,
+            key_type: None,
+            optional: false,
         }
     }
 
@@ -46,6 +54,55 @@ impl ForeignKeyDef {
         self
     }
 
+    /// References the primary key of `model`: its table, key column and type.
+    pub fn references_model(mut self, model: &ModelSchema) -> Self {
+        self.to_table = model.table_name.clone();
+        if let Some(pk) = &model.primary_key {
+            self.to_column = pk.name.clone();
+            self.key_type = Some(pk.col_type.clone());
+        }
+        self
+    }
+
+    /// Type of the referenced key, checked against the declared column.
+    pub fn key_type(mut self, col_type: ColumnType) -> Self {
+        self.key_type = Some(col_type);
+        self
+    }
+
+    pub fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Column created for `from_column` when the schema does not declare it:
+    /// typed like the referenced key (integer by default), nullable when the FK
+    /// is [`optional`](Self::optional) or an action sets it to `NULL`.
+    pub fn to_column_def(&self) -> ColumnDef {
+        let mut col = ColumnDef::new(&self.from_column);
+        col.col_type = self.key_type.clone().unwrap_or(ColumnType::Integer);
+        col.nullable = self.optional
+            || matches!(self.on_delete, ForeignKeyAction::SetNull)
+            || matches!(self.on_update, ForeignKeyAction::SetNull);
+        col
+    }
+
+    /// Checks a declared `from_column` of type `col_type` can hold the referenced
+    /// key. Integer widths are interchangeable; without a known
+    /// [`key_type`](Self::key_type) any type is accepted.
+    pub fn check_column_type(&self, col_type: &ColumnType) -> Result<(), String> {
+        let Some(key_type) = &self.key_type else {
+            return Ok(());
+        };
+        if key_family(col_type) == key_family(key_type) {
+            return Ok(());
+        }
+        Err(format!(
+            "foreign key '{}' references {}.{} ({:?}) but the column is declared as {:?}",
+            self.from_column, self.to_table, self.to_column, key_type, col_type
+        ))
+    }
+
     /// Generates the corresponding SeaQuery ForeignKey
     pub fn to_sea_foreign_key(&self, from_table: &str) -> sea_query::ForeignKeyCreateStatement {
         sea_query::ForeignKey::create()
@@ -62,3 +119,14 @@ impl ForeignKeyDef {
             .to_owned()
     }
 }
+
+/// Key types that can reference each other: every integer width is one family.
+fn key_family(col_type: &ColumnType) -> ColumnType {
+    use ColumnType::*;
+    match col_type {
+        TinyInteger | SmallInteger | Integer | BigInteger | TinyUnsigned | SmallUnsigned
+        | Unsigned | BigUnsigned => Integer,
+        String(_) | Char(_) | Text => Text,
+        other => other.clone(),
+    }
+}
@@ -1,7 +1,7 @@
 //! SeaORM Hooks — before/after save/delete, ordered by execution slot.
 //!
 //! [`HooksDef`] is optional in [`crate::migration::ModelSchema`]:
-//! absent = empty `impl ActiveModelBehavior for ActiveModel {}`.
+//! absent = `impl_signals!(ActiveModel)`, which only emits the runtime signals.
 
 /// SeaORM hook type.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,7 +20,7 @@ pub struct Hook {
     pub handler_path: String, // path to the handler function
 }
 
-/// Definition of hooks — optional, None = empty ActiveModelBehavior
+/// Definition of hooks — optional, None = signal-only ActiveModelBehavior
 #[derive(Debug, Clone, Default)]
 pub struct HooksDef {
     pub hooks: Vec<Hook>,
@@ -1,4 +1,26 @@
-//! Table index definition — columns, uniqueness, optional name, SeaQuery generation.
+//! Table index definition — columns, uniqueness, optional name, partial `WHERE`, access method, SeaQuery generation.
+
+#[cfg(feature = "orm")]
+use crate::db::DatabaseEngine;
+
+/// Index access method (`USING ...`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexMethod {
+    BTree,
+    Hash,
+    /// PostgreSQL only (jsonb, arrays, full text).
+    Gin,
+}
+
+impl IndexMethod {
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            IndexMethod::BTree => "BTREE",
+            IndexMethod::Hash => "HASH",
+            IndexMethod::Gin => "GIN",
+        }
+    }
+}
 
 /// Index definition.
 #[derive(Debug, Clone)]
@@ -6,6 +28,9 @@ pub struct IndexDef {
     pub columns: Vec<String>,
     pub unique: bool,
     pub name: Option<String>,
+    /// Raw SQL predicate of a partial index, e.g. `deleted_at IS NULL`.
+    pub where_clause: Option<String>,
+    pub method: Option<IndexMethod>,
 }
 
 impl IndexDef {
@@ -14,6 +39,8 @@ impl IndexDef {
             columns: columns.into_iter().map(|c| c.into()).collect(),
             unique: false,
             name: None,
+            where_clause: None,
+            method: None,
         }
     }
 
@@ -27,8 +54,22 @@ impl IndexDef {
         self
     }
 
+    /// Partial index: only rows matching the predicate are indexed,
+    /// e.g. `IndexDef::new(vec!["email"]).unique().where_clause("deleted_at IS NULL")`.
+    pub fn where_clause(mut self, predicate: impl Into<String>) -> Self {
+        self.where_clause = Some(predicate.into());
+        self
+    }
+
+    pub fn using(mut self, method: IndexMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
     /// Generates the corresponding SeaQuery Index
     pub fn to_sea_index(&self, table: &str) -> sea_query::IndexCreateStatement {
+        use sea_query::ConditionalStatement;
+
         let index_name = self
             .name
             .clone()
@@ -45,6 +86,60 @@ impl IndexDef {
             idx.unique();
         }
 
+        if let Some(method) = self.method {
+            idx.index_type(match method {
+                IndexMethod::BTree => sea_query::IndexType::BTree,
+                IndexMethod::Hash => sea_query::IndexType::Hash,
+                IndexMethod::Gin => {
+                    sea_query::IndexType::Custom(sea_query::Alias::new("GIN").into())
+                }
+            });
+        }
+
+        if let Some(ref predicate) = self.where_clause {
+            idx.cond_where(sea_query::Expr::cust(predicate.clone()));
+        }
+
         idx.to_owned()
     }
+
+    /// Checks that the backend can honour the partial predicate and access method.
+    /// The SQL builders would otherwise drop them silently (SQLite `USING`) or emit
+    /// a statement the server rejects (MySQL `WHERE`).
+    #[cfg(feature = "orm")]
+    pub fn validate_for(&self, engine: &DatabaseEngine) -> Result<(), String> {
+        let label = self.name.clone().unwrap_or_else(|| self.columns.join(", "));
+        let is_mysql = matches!(engine, DatabaseEngine::MySQL | DatabaseEngine::MariaDB);
+
+        if self.where_clause.is_some() && is_mysql {
+            return Err(format!(
+                "index `{}`: partial indexes (WHERE) are not supported by {}",
+                label,
+                engine.name()
+            ));
+        }
+
+        match (self.method, engine) {
+            (None | Some(IndexMethod::BTree), _) => Ok(()),
+            (Some(_), DatabaseEngine::PostgreSQL) => Ok(()),
+            (Some(IndexMethod::Hash), DatabaseEngine::MySQL | DatabaseEngine::MariaDB) => Ok(()),
+            (Some(method), _) => Err(format!(
+                "index `{}`: USING {} is not supported by {}",
+                label,
+                method.as_sql(),
+                engine.name()
+            )),
+        }
+    }
+
+    /// [`to_sea_index`](Self::to_sea_index) guarded by [`validate_for`](Self::validate_for).
+    #[cfg(feature = "orm")]
+    pub fn to_sea_index_for(
+        &self,
+        table: &str,
+        engine: &DatabaseEngine,
+    ) -> Result<sea_query::IndexCreateStatement, String> {
+        self.validate_for(engine)?;
+        Ok(self.to_sea_index(table))
+    }
 }
@@ -0,0 +1,683 @@
+//! Database introspection (`inspectdb`) — rebuilds a [`ModelSchema`] from an existing table.
+//!
+//! Entry point: [`ModelSchema::from_table`]. Columns, nullability, literal defaults, the
+//! primary key (including serial/identity detection) and foreign keys are read from
+//! `information_schema` (PostgreSQL, MySQL/MariaDB) or the `pragma_*` functions (SQLite).
+//! The result can be printed as a SeaORM entity with [`ModelSchema::to_model`].
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, DbErr, QueryResult, Statement};
+use sea_query::{ColumnType, ForeignKeyAction, StringLen};
+
+use crate::migration::{
+    column::ColumnDef, foreign_key::ForeignKeyDef, primary_key::PrimaryKeyDef, schema::ModelSchema,
+    utils::to_pascal_case,
+};
+
+/// A column as reported by the database, before mapping.
+#[derive(Debug)]
+struct RawColumn {
+    name: String,
+    col_type: ColumnType,
+    nullable: bool,
+    default: Option<String>,
+    auto_increment: bool,
+    primary_key: bool,
+    /// 1-based position inside the primary key constraint.
+    pk_position: u32,
+    unique: bool,
+}
+
+impl Default for RawColumn {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            col_type: ColumnType::String(StringLen::None),
+            nullable: false,
+            default: None,
+            auto_increment: false,
+            primary_key: false,
+            pk_position: 0,
+            unique: false,
+        }
+    }
+}
+
+impl ModelSchema {
+    /// Reads `table_name` from the connected database and reconstructs its schema.
+    ///
+    /// Returns `DbErr::Custom` when the table does not exist or the backend is unsupported.
+    pub async fn from_table(
+        db: &DatabaseConnection,
+        table_name: &str,
+    ) -> Result<ModelSchema, DbErr> {
+        let (columns, foreign_keys) = match db.get_database_backend() {
+            DbBackend::Postgres => (
+                postgres_columns(db, table_name).await?,
+                postgres_foreign_keys(db, table_name).await?,
+            ),
+            DbBackend::MySql => (
+                mysql_columns(db, table_name).await?,
+                mysql_foreign_keys(db, table_name).await?,
+            ),
+            DbBackend::Sqlite => (
+                sqlite_columns(db, table_name).await?,
+                sqlite_foreign_keys(db, table_name).await?,
+            ),
+            other => {
+                return Err(DbErr::Custom(format!(
+                    "inspectdb: unsupported backend {:?}",
+                    other
+                )));
+            }
+        };
+
+        if columns.is_empty() {
+            return Err(DbErr::Custom(format!(
+                "inspectdb: table '{}' not found",
+                table_name
+            )));
+        }
+
+        let mut schema = ModelSchema::new(to_pascal_case(table_name)).table_name(table_name);
+
+        // Key order, not column order: `PRIMARY KEY (b, a)` stays `(b, a)`
+        let mut pk_members: Vec<&RawColumn> = columns.iter().filter(|c| c.primary_key).collect();
+        pk_members.sort_by_key(|c| c.pk_position);
+        schema.primary_key = match pk_members.as_slice() {
+            [] => None,
+            [pk] => {
+                let mut def = PrimaryKeyDef::new(&pk.name);
+                def.col_type = pk.col_type.clone();
+                def.auto_increment = pk.auto_increment;
+                Some(def)
+            }
+            members => Some(PrimaryKeyDef::composite(
+                members
+                    .iter()
+                    .map(|c| (c.name.clone(), c.col_type.clone()))
+                    .collect(),
+            )),
+        };
+
+        for raw in columns.iter().filter(|c| !c.primary_key) {
+            let mut col = ColumnDef::new(&raw.name);
+            col.col_type = raw.col_type.clone();
+            col.nullable = raw.nullable;
+            col.unique = raw.unique;
+            if let Some(ref default) = raw.default {
+                match parse_default(default) {
+                    ParsedDefault::Now => col.auto_now = true,
+                    ParsedDefault::Value(value) => col.default = Some(value),
+                    ParsedDefault::Unknown => {}
+                }
+            }
+            schema.columns.push(col);
+        }
+
+        schema.foreign_keys = foreign_keys;
+        Ok(schema)
+    }
+}
+
+// ── Type mapping ─────────────────────────────────────────────────────────────
+
+/// Maps a backend type name (`varchar(50)`, `int4`, `timestamptz`, `tinyint(1)`,
+/// `numeric(10,2)`, `bigint unsigned`…) back onto a [`ColumnType`].
+/// `char_len` overrides the length parsed from the type name when provided.
+/// Unknown types fall back to `String`.
+pub fn sql_type_to_column_type(sql_type: &str, char_len: Option<u32>) -> ColumnType {
+    let lower = sql_type.trim().to_lowercase();
+    let unsigned = lower.contains("unsigned");
+    let (base, params) = match lower.find('(') {
+        Some(i) => {
+            let end = lower[i..].find(')').map(|e| i + e).unwrap_or(lower.len());
+            (
+                lower[..i].trim().to_string(),
+                Some(lower[i + 1..end].to_string()),
+            )
+        }
+        None => (lower.trim_end_matches(" unsigned").trim().to_string(), None),
+    };
+    let nums: Vec<u32> = params
+        .as_deref()
+        .unwrap_or("")
+        .split(',')
+        .filter_map(|p| p.trim().parse().ok())
+        .collect();
+    let len = char_len.or_else(|| nums.first().copied());
+
+    match base.as_str() {
+        "character varying" | "varchar" | "nvarchar" => match len {
+            Some(n) => ColumnType::String(StringLen::N(n)),
+            None => ColumnType::String(StringLen::None),
+        },
+        "character" | "char" | "bpchar" | "nchar" => ColumnType::Char(len),
+        "text" | "tinytext" | "mediumtext" | "longtext" | "clob" => ColumnType::Text,
+        // Only the explicit `tinyint(1)` is a boolean: MySQL 8.0.19+ drops display
+        // widths, so a bare `tinyint` is a genuine small integer
+        "tinyint" if nums.first() == Some(&1) && !unsigned => ColumnType::Boolean,
+        "tinyint" if unsigned => ColumnType::TinyUnsigned,
+        "tinyint" => ColumnType::TinyInteger,
+        "smallint" | "int2" | "smallserial" if unsigned => ColumnType::SmallUnsigned,
+        "smallint" | "int2" | "smallserial" => ColumnType::SmallInteger,
+        "integer" | "int" | "int4" | "mediumint" | "serial" if unsigned => ColumnType::Unsigned,
+        "integer" | "int" | "int4" | "mediumint" | "serial" => ColumnType::Integer,
+        "bigint" | "int8" | "bigserial" if unsigned => ColumnType::BigUnsigned,
+        "bigint" | "int8" | "bigserial" => ColumnType::BigInteger,
+        "real" | "float4" | "float" => ColumnType::Float,
+        "double precision" | "double" | "float8" => ColumnType::Double,
+        "numeric" | "decimal" => match nums.as_slice() {
+            [p, s, ..] => ColumnType::Decimal(Some((*p, *s))),
+            _ => ColumnType::Decimal(None),
+        },
+        "boolean" | "bool" => ColumnType::Boolean,
+        "date" => ColumnType::Date,
+        "time" | "time without time zone" => ColumnType::Time,
+        "timestamp" | "timestamp without time zone" | "datetime" => ColumnType::DateTime,
+        "timestamptz" | "timestamp with time zone" => ColumnType::TimestampWithTimeZone,
+        "uuid" => ColumnType::Uuid,
+        "json" => ColumnType::Json,
+        "jsonb" => ColumnType::JsonBinary,
+        "bytea" | "blob" | "tinyblob" | "mediumblob" | "longblob" => ColumnType::Blob,
+        "inet" => ColumnType::Inet,
+        "cidr" => ColumnType::Cidr,
+        "macaddr" => ColumnType::MacAddr,
+        "interval" => ColumnType::Interval(None, None),
+        _ => ColumnType::String(StringLen::None),
+    }
+}
+
+enum ParsedDefault {
+    Now,
+    Value(sea_query::Value),
+    Unknown,
+}
+
+/// Interprets a raw `column_default`: literals become values, `now()` /
+/// `CURRENT_TIMESTAMP` become `auto_now`, anything else (sequences, functions) is skipped.
+fn parse_default(raw: &str) -> ParsedDefault {
+    // PostgreSQL appends a cast: `'draft'::character varying`, `0::integer`
+    let (expr, cast) = match raw.find("::") {
+        Some(i) => (&raw[..i], Some(&raw[i + 2..])),
+        None => (raw, None),
+    };
+    let expr = expr.trim().trim_start_matches('(').trim_end_matches(')');
+    // ... and quotes negative numbers: `'-1'::integer`, `'-1.5'::numeric`
+    let expr = if cast.is_some_and(is_numeric_cast) {
+        expr.trim_matches('\'')
+    } else {
+        expr
+    };
+    let lower = expr.to_lowercase();
+
+    if lower == "now(" || lower == "now" || lower.starts_with("current_timestamp") {
+        return ParsedDefault::Now;
+    }
+    if lower == "null" || lower.starts_with("nextval(") {
+        return ParsedDefault::Unknown;
+    }
+    if lower == "true" {
+        return ParsedDefault::Value(true.into());
+    }
+    if lower == "false" {
+        return ParsedDefault::Value(false.into());
+    }
+    if let Ok(n) = expr.parse::<i64>() {
+        return ParsedDefault::Value(n.into());
+    }
+    if let Ok(f) = expr.parse::<f64>() {
+        return ParsedDefault::Value(f.into());
+    }
+    if expr.len() >= 2 && expr.starts_with('\'') && expr.ends_with('\'') {
+        let inner = expr[1..expr.len() - 1].replace("''", "'");
+        return ParsedDefault::Value(inner.into());
+    }
+    ParsedDefault::Unknown
+}
+
+/// `true` for a PostgreSQL cast to a numeric type (`integer`, `numeric(10,2)`, …).
+fn is_numeric_cast(cast: &str) -> bool {
+    let lower = cast.trim_end_matches(')').to_lowercase();
+    matches!(
+        lower.split('(').next().unwrap_or_default().trim(),
+        "smallint"
+            | "integer"
+            | "bigint"
+            | "int2"
+            | "int4"
+            | "int8"
+            | "numeric"
+            | "decimal"
+            | "real"
+            | "double precision"
+            | "float4"
+            | "float8"
+    )
+}
+
+fn parse_action(rule: &str) -> ForeignKeyAction {
+    match rule.to_uppercase().as_str() {
+        "CASCADE" => ForeignKeyAction::Cascade,
+        "SET NULL" => ForeignKeyAction::SetNull,
+        "SET DEFAULT" => ForeignKeyAction::SetDefault,
+        "RESTRICT" => ForeignKeyAction::Restrict,
+        _ => ForeignKeyAction::NoAction,
+    }
+}
+
+fn get_string(row: &QueryResult, idx: usize) -> Option<String> {
+    row.try_get_by_index::<Option<String>>(idx).ok().flatten()
+}
+
+fn get_u32(row: &QueryResult, idx: usize) -> Option<u32> {
+    row.try_get_by_index::<Option<i64>>(idx)
+        .ok()
+        .flatten()
+        .or_else(|| {
+            row.try_get_by_index::<Option<i32>>(idx)
+                .ok()
+                .flatten()
+                .map(i64::from)
+        })
+        .and_then(|n| u32::try_from(n).ok())
+}
+
+async fn query(
+    db: &DatabaseConnection,
+    sql: &str,
+    table_name: &str,
+) -> Result<Vec<QueryResult>, DbErr> {
+    let backend = db.get_database_backend();
+    db.query_all_raw(Statement::from_sql_and_values(
+        backend,
+        sql,
+        [table_name.into()],
+    ))
+    .await
+}
+
+// ── PostgreSQL ───────────────────────────────────────────────────────────────
+
+async fn postgres_columns(
+    db: &DatabaseConnection,
+    table_name: &str,
+) -> Result<Vec<RawColumn>, DbErr> {
+    let rows = query(
+        db,
+        "SELECT column_name::text, udt_name::text, character_maximum_length::bigint, \
+         numeric_precision::bigint, numeric_scale::bigint, is_nullable::text, \
+         column_default::text, is_identity::text \
+         FROM information_schema.columns \
+         WHERE table_schema = current_schema() AND table_name = $1 \
+         ORDER BY ordinal_position",
+        table_name,
+    )
+    .await?;
+
+    let mut columns: Vec<RawColumn> = rows
+        .iter()
+        .map(|row| {
+            let udt = get_string(row, 1).unwrap_or_default();
+            let sql_type = match (udt.as_str(), get_u32(row, 3), get_u32(row, 4)) {
+                ("numeric", Some(p), Some(s)) => format!("numeric({},{})", p, s),
+                _ => udt,
+            };
+            let default = get_string(row, 6);
+            let serial = default
+                .as_deref()
+                .is_some_and(|d| d.starts_with("nextval("));
+            RawColumn {
+                name: get_string(row, 0).unwrap_or_default(),
+                col_type: sql_type_to_column_type(&sql_type, get_u32(row, 2)),
+                nullable: get_string(row, 5).as_deref() == Some("YES"),
+                auto_increment: serial || get_string(row, 7).as_deref() == Some("YES"),
+                default,
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    let constraints = query(
+        db,
+        "SELECT kcu.column_name::text, tc.constraint_type::text, tc.constraint_name::text \
+         FROM information_schema.table_constraints tc \
+         JOIN information_schema.key_column_usage kcu \
+           ON tc.constraint_name = kcu.constraint_name \
+          AND tc.table_schema = kcu.table_schema \
+          AND tc.table_name = kcu.table_name \
+         WHERE tc.table_schema = current_schema() AND tc.table_name = $1 \
+           AND tc.constraint_type IN ('PRIMARY KEY', 'UNIQUE') \
+         ORDER BY tc.constraint_name, kcu.ordinal_position",
+        table_name,
+    )
+    .await?;
+    apply_key_constraints(&mut columns, &constraints);
+
+    Ok(columns)
+}
+
+async fn postgres_foreign_keys(
+    db: &DatabaseConnection,
+    table_name: &str,
+) -> Result<Vec<ForeignKeyDef>, DbErr> {
+    let rows = query(
+        db,
+        "SELECT kcu.column_name::text, ref.table_name::text, ref.column_name::text, \
+         rc.delete_rule::text, rc.update_rule::text \
+         FROM information_schema.referential_constraints rc \
+         JOIN information_schema.key_column_usage kcu \
+           ON rc.constraint_name = kcu.constraint_name \
+          AND rc.constraint_schema = kcu.constraint_schema \
+         JOIN information_schema.key_column_usage ref \
+           ON rc.unique_constraint_name = ref.constraint_name \
+          AND rc.unique_constraint_schema = ref.constraint_schema \
+          AND ref.ordinal_position = kcu.position_in_unique_constraint \
+         WHERE kcu.table_schema = current_schema() AND kcu.table_name = $1 \
+         ORDER BY kcu.constraint_name, kcu.ordinal_position",
+        table_name,
+    )
+    .await?;
+    Ok(rows.iter().map(foreign_key_from_row).collect())
+}
+
+// ── MySQL / MariaDB ──────────────────────────────────────────────────────────
+
+async fn mysql_columns(db: &DatabaseConnection, table_name: &str) -> Result<Vec<RawColumn>, DbErr> {
+    let rows = query(
+        db,
+        "SELECT CAST(column_name AS CHAR), CAST(column_type AS CHAR), \
+         CAST(is_nullable AS CHAR), CAST(column_default AS CHAR), CAST(extra AS CHAR) \
+         FROM information_schema.columns \
+         WHERE table_schema = DATABASE() AND table_name = ? \
+         ORDER BY ordinal_position",
+        table_name,
+    )
+    .await?;
+
+    let mut columns: Vec<RawColumn> = rows
+        .iter()
+        .map(|row| {
+            let col_type = sql_type_to_column_type(&get_string(row, 1).unwrap_or_default(), None);
+            let extra = get_string(row, 4).unwrap_or_default().to_lowercase();
+            RawColumn {
+                name: get_string(row, 0).unwrap_or_default(),
+                nullable: get_string(row, 2).as_deref() == Some("YES"),
+                default: get_string(row, 3).map(|d| mysql_default(d, &extra, &col_type)),
+                auto_increment: extra.contains("auto_increment"),
+                col_type,
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    let constraints = query(
+        db,
+        "SELECT CAST(kcu.column_name AS CHAR), CAST(tc.constraint_type AS CHAR), \
+         CAST(tc.constraint_name AS CHAR) \
+         FROM information_schema.table_constraints tc \
+         JOIN information_schema.key_column_usage kcu \
+           ON tc.constraint_name = kcu.constraint_name \
+          AND tc.table_schema = kcu.table_schema \
+          AND tc.table_name = kcu.table_name \
+         WHERE tc.table_schema = DATABASE() AND tc.table_name = ? \
+           AND tc.constraint_type IN ('PRIMARY KEY', 'UNIQUE') \
+         ORDER BY tc.constraint_name, kcu.ordinal_position",
+        table_name,
+    )
+    .await?;
+    apply_key_constraints(&mut columns, &constraints);
+
+    Ok(columns)
+}
+
+async fn mysql_foreign_keys(
+    db: &DatabaseConnection,
+    table_name: &str,
+) -> Result<Vec<ForeignKeyDef>, DbErr> {
+    let rows = query(
+        db,
+        "SELECT CAST(kcu.column_name AS CHAR), CAST(kcu.referenced_table_name AS CHAR), \
+         CAST(kcu.referenced_column_name AS CHAR), CAST(rc.delete_rule AS CHAR), \
+         CAST(rc.update_rule AS CHAR) \
+         FROM information_schema.key_column_usage kcu \
+         JOIN information_schema.referential_constraints rc \
+           ON rc.constraint_name = kcu.constraint_name \
+          AND rc.constraint_schema = kcu.constraint_schema \
+         WHERE kcu.table_schema = DATABASE() AND kcu.table_name = ? \
+           AND kcu.referenced_table_name IS NOT NULL",
+        table_name,
+    )
+    .await?;
+    Ok(rows.iter().map(foreign_key_from_row).collect())
+}
+
+/// MySQL 8 reports literal defaults unquoted (`draft`, `2024-01-01`) while
+/// MariaDB quotes them (`'draft'`). Expression defaults are flagged
+/// `DEFAULT_GENERATED` in `extra` and kept as-is; other unquoted literals are
+/// quoted so [`parse_default`] reads them as strings.
+fn mysql_default(raw: String, extra: &str, col_type: &ColumnType) -> String {
+    if extra.contains("default_generated")
+        || raw.starts_with('\'')
+        || raw.eq_ignore_ascii_case("null")
+    {
+        return raw;
+    }
+    let textual = matches!(
+        col_type,
+        ColumnType::String(_) | ColumnType::Char(_) | ColumnType::Text
+    );
+    if textual || matches!(parse_default(&raw), ParsedDefault::Unknown) {
+        format!("'{}'", raw.replace('\'', "''"))
+    } else {
+        raw
+    }
+}
+
+// ── SQLite ───────────────────────────────────────────────────────────────────
+
+async fn sqlite_columns(
+    db: &DatabaseConnection,
+    table_name: &str,
+) -> Result<Vec<RawColumn>, DbErr> {
+    let rows = query(
+        db,
+        "SELECT name, type, \"notnull\", dflt_value, pk FROM pragma_table_info(?)",
+        table_name,
+    )
+    .await?;
+
+    let mut columns: Vec<RawColumn> = rows
+        .iter()
+        .map(|row| {
+            let not_null = row.try_get_by_index::<i32>(2).unwrap_or(0) != 0;
+            let pk_pos = row.try_get_by_index::<i32>(4).unwrap_or(0);
+            RawColumn {
+                name: get_string(row, 0).unwrap_or_default(),
+                col_type: sql_type_to_column_type(&get_string(row, 1).unwrap_or_default(), None),
+                nullable: !not_null && pk_pos == 0,
+                default: get_string(row, 3),
+                primary_key: pk_pos > 0,
+                pk_position: u32::try_from(pk_pos).unwrap_or(0),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    // A single INTEGER primary key is an alias of the rowid: auto-incremented by SQLite
+    let pk_count = columns.iter().filter(|c| c.primary_key).count();
+    if pk_count == 1
+        && let Some(pk) = columns.iter_mut().find(|c| c.primary_key)
+        && pk.col_type == ColumnType::Integer
+    {
+        pk.auto_increment = true;
+    }
+
+    let indexes = query(
+        db,
+        "SELECT il.name, ii.name FROM pragma_index_list(?) il \
+         JOIN pragma_index_info(il.name) ii \
+         WHERE il.\"unique\" = 1 AND il.origin = 'u'",
+        table_name,
+    )
+    .await?;
+    let mut per_index: std::collections::HashMap<String, Vec<String>> =
+        std::collections::HashMap::new();
+    for row in &indexes {
+        if let (Some(index), Some(column)) = (get_string(row, 0), get_string(row, 1)) {
+            per_index.entry(index).or_default().push(column);
+        }
+    }
+    for cols in per_index.values().filter(|cols| cols.len() == 1) {
+        if let Some(col) = columns.iter_mut().find(|c| c.name == cols[0]) {
+            col.unique = true;
+        }
+    }
+
+    Ok(columns)
+}
+
+async fn sqlite_foreign_keys(
+    db: &DatabaseConnection,
+    table_name: &str,
+) -> Result<Vec<ForeignKeyDef>, DbErr> {
+    let rows = query(
+        db,
+        "SELECT \"from\", \"table\", \"to\", on_delete, on_update FROM pragma_foreign_key_list(?)",
+        table_name,
+    )
+    .await?;
+    Ok(rows.iter().map(foreign_key_from_row).collect())
+}
+
+// ── Shared ───────────────────────────────────────────────────────────────────
+
+/// Marks primary key members and single-column unique constraints.
+/// Rows are `(column_name, constraint_type, constraint_name)`, in key order.
+fn apply_key_constraints(columns: &mut [RawColumn], rows: &[QueryResult]) {
+    let mut unique: std::collections::HashMap<String, Vec<String>> =
+        std::collections::HashMap::new();
+    let mut pk_position = 0;
+    for row in rows {
+        let (Some(column), Some(kind), Some(name)) =
+            (get_string(row, 0), get_string(row, 1), get_string(row, 2))
+        else {
+            continue;
+        };
+        if kind == "PRIMARY KEY" {
+            if let Some(col) = columns.iter_mut().find(|c| c.name == column) {
+                pk_position += 1;
+                col.primary_key = true;
+                col.pk_position = pk_position;
+            }
+        } else {
+            unique.entry(name).or_default().push(column);
+        }
+    }
+    for cols in unique.values().filter(|cols| cols.len() == 1) {
+        if let Some(col) = columns
+            .iter_mut()
+            .find(|c| c.name == cols[0] && !c.primary_key)
+        {
+            col.unique = true;
+        }
+    }
+}
+
+/// Rows are `(from_column, to_table, to_column, on_delete, on_update)`.
+fn foreign_key_from_row(row: &QueryResult) -> ForeignKeyDef {
+    ForeignKeyDef::new(get_string(row, 0).unwrap_or_default())
+        .references(get_string(row, 1).unwrap_or_default())
+        .to_column(get_string(row, 2).unwrap_or_else(|| "id".to_string()))
+        .on_delete(parse_action(&get_string(row, 3).unwrap_or_default()))
+        .on_update(parse_action(&get_string(row, 4).unwrap_or_default()))
+}
+
+#[cfg(test)]
+mod mysql_default_tests {
+    use super::{ParsedDefault, mysql_default, parse_default};
+    use sea_query::{ColumnType, StringLen};
+
+    fn parsed(raw: &str, extra: &str, col_type: ColumnType) -> ParsedDefault {
+        parse_default(&mysql_default(raw.to_string(), extra, &col_type))
+    }
+
+    #[test]
+    fn unquoted_mysql8_string_is_a_value() {
+        let ParsedDefault::Value(v) = parsed("draft", "", ColumnType::String(StringLen::N(20)))
+        else {
+            panic!("défaut texte non reconnu");
+        };
+        assert_eq!(v, "draft".into());
+        // Un nombre dans une colonne texte reste une chaîne
+        let ParsedDefault::Value(v) = parsed("42", "", ColumnType::Text) else {
+            panic!("défaut texte numérique non reconnu");
+        };
+        assert_eq!(v, "42".into());
+    }
+
+    #[test]
+    fn mariadb_quoted_and_numbers_untouched() {
+        let ParsedDefault::Value(v) = parsed("'it''s'", "", ColumnType::Text) else {
+            panic!("défaut MariaDB non reconnu");
+        };
+        assert_eq!(v, "it's".into());
+        let ParsedDefault::Value(v) = parsed("0", "", ColumnType::Integer) else {
+            panic!("défaut entier non reconnu");
+        };
+        assert_eq!(v, 0i64.into());
+        let ParsedDefault::Value(v) = parsed("2024-01-01", "", ColumnType::Date) else {
+            panic!("défaut date non reconnu");
+        };
+        assert_eq!(v, "2024-01-01".into());
+    }
+
+    #[test]
+    fn generated_expressions_stay_expressions() {
+        assert!(matches!(
+            parsed(
+                "CURRENT_TIMESTAMP",
+                "default_generated",
+                ColumnType::DateTime
+            ),
+            ParsedDefault::Now
+        ));
+        assert!(matches!(
+            parsed(
+                "(uuid())",
+                "default_generated",
+                ColumnType::String(StringLen::None)
+            ),
+            ParsedDefault::Unknown
+        ));
+        assert!(matches!(
+            parsed("NULL", "", ColumnType::Text),
+            ParsedDefault::Unknown
+        ));
+    }
+}
+
+#[cfg(test)]
+mod postgres_default_tests {
+    use super::{ParsedDefault, parse_default};
+
+    fn value(raw: &str) -> sea_query::Value {
+        let ParsedDefault::Value(v) = parse_default(raw) else {
+            panic!("défaut non reconnu : {raw}");
+        };
+        v
+    }
+
+    #[test]
+    fn quoted_negative_numbers_are_numbers() {
+        assert_eq!(value("'-1'::integer"), (-1i64).into());
+        assert_eq!(value("'-1.5'::numeric"), (-1.5f64).into());
+        assert_eq!(value("'-2'::bigint"), (-2i64).into());
+        assert_eq!(value("'-0.25'::numeric(10,2)"), (-0.25f64).into());
+    }
+
+    #[test]
+    fn quoted_text_casts_stay_strings() {
+        assert_eq!(value("'-1'::character varying"), "-1".into());
+        assert_eq!(value("'draft'::text"), "draft".into());
+        assert_eq!(value("0::integer"), 0i64.into());
+    }
+}
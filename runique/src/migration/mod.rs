@@ -7,6 +7,10 @@ pub mod column;
 pub mod foreign_key;
 pub mod hooks;
 pub mod index;
+#[cfg(feature = "orm")]
+pub mod introspect;
+#[cfg(feature = "orm")]
+pub mod plan;
 
 pub mod primary_key;
 pub mod relation;
@@ -18,6 +22,8 @@ pub use column::*;
 pub use foreign_key::*;
 pub use hooks::*;
 pub use index::*;
+#[cfg(feature = "orm")]
+pub use introspect::*;
 pub use primary_key::*;
 pub use relation::*;
 pub use schema::*;
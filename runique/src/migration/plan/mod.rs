@@ -0,0 +1,433 @@
+//! Migration plan (`migrate --dry-run`) and status — renders the SQL of pending
+//! migrations without executing it, lists applied / pending / orphaned migrations.
+//!
+//! The applied versions are read from the real connection (read-only: the migrations
+//! table is never created). Each pending `up()` then runs against a mock connection of
+//! the same backend, which records every statement instead of sending it. A migration
+//! that reads from the database (`has_table`, `SELECT`…) cannot be planned.
+//!
+//! [`migration_status`] reads the same table to list applied, pending and orphaned
+//! migrations (`showmigrations`); [`rollback_to`] rolls back until a given migration is
+//! the latest applied one.
+//!
+//! In the migration crate, replace `cli::run_cli` with [`run_cli`] so that
+//! `runique migration up --dry-run` and `runique migration status` can reach the `Migrator`:
+//!
+//! ```rust,ignore
+//! #[tokio::main]
+//! async fn main() {
+//!     runique::migration::plan::run_cli(migration::Migrator).await;
+//! }
+//! ```
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use dialoguer::{Confirm, theme::ColorfulTheme};
+
+use sea_orm::{
+    ConnectionTrait, Database, DatabaseConnection, DbBackend, DbErr, MockDatabase, MockExecResult,
+    Statement,
+};
+use sea_orm_migration::{MigrationTrait, MigratorTrait, SchemaManager};
+use sea_query::{Alias, Query};
+
+use crate::utils::trad::{Lang, set_lang, t, tf};
+
+/// Pending migration with the SQL its `up()` would run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedMigration {
+    pub name: String,
+    /// Statements in execution order, rendered for the connection's backend.
+    pub statements: Vec<String>,
+    /// One entry per statement that loses data (dropped table/column, type change…).
+    pub warnings: Vec<String>,
+}
+
+/// Upper bound of statements recorded for one migration.
+const MAX_STATEMENTS: usize = 4096;
+
+/// Runs `migration.up()` (or `down()`) on a mock connection of `backend` and
+/// returns the statements it sent, rendered to SQL.
+async fn record(
+    migration: &dyn MigrationTrait,
+    backend: DbBackend,
+    up: bool,
+) -> Result<Vec<String>, DbErr> {
+    let conn = MockDatabase::new(backend)
+        .append_exec_results((0..MAX_STATEMENTS).map(|_| MockExecResult::default()))
+        .into_connection();
+    let manager = SchemaManager::new(&conn);
+    if up {
+        migration.up(&manager).await?;
+    } else {
+        migration.down(&manager).await?;
+    }
+    Ok(conn
+        .into_transaction_log()
+        .iter()
+        .flat_map(|txn| txn.statements())
+        .map(|stmt| stmt.to_string())
+        .collect())
+}
+
+/// Records `migration` and flags the statements that lose data.
+async fn planned(
+    migration: &dyn MigrationTrait,
+    backend: DbBackend,
+    up: bool,
+) -> Result<PlannedMigration, DbErr> {
+    let name = migration.name().to_string();
+    let statements = record(migration, backend, up)
+        .await
+        .map_err(|e| DbErr::Migration(format!("{}: {}", name, e)))?;
+    let warnings = statements.iter().filter_map(|sql| data_loss(sql)).collect();
+    Ok(PlannedMigration {
+        name,
+        statements,
+        warnings,
+    })
+}
+
+/// Plans the migrations of `M` not yet applied on `db`, in `Migrator` order.
+///
+/// Nothing is written: no transaction is opened and a database without the
+/// migrations table simply has every migration pending.
+pub async fn plan_pending<M: MigratorTrait>(
+    db: &DatabaseConnection,
+) -> Result<Vec<PlannedMigration>, DbErr> {
+    let applied = applied_migrations::<M>(db).await?;
+    let backend = db.get_database_backend();
+
+    let mut plan = Vec::new();
+    for migration in M::migrations() {
+        if !applied.contains_key(migration.name()) {
+            plan.push(planned(migration.as_ref(), backend, true).await?);
+        }
+    }
+    Ok(plan)
+}
+
+/// Migrations to roll back so that `target` becomes the latest applied one,
+/// newest first, each with the SQL of its `down()`. Read-only, like [`plan_pending`].
+///
+/// Fails when `target` is unknown to the `Migrator` or not applied, and when an
+/// orphaned migration is recorded (it has no `down()` left to run).
+pub async fn plan_rollback_to<M: MigratorTrait>(
+    db: &DatabaseConnection,
+    target: &str,
+) -> Result<Vec<PlannedMigration>, DbErr> {
+    let status = migration_status::<M>(db).await?;
+    if let Some(orphan) = status.iter().find(|r| r.state == MigrationState::Orphaned) {
+        return Err(DbErr::Migration(format!(
+            "`{}` is applied but missing from the Migrator: it cannot be rolled back",
+            orphan.name
+        )));
+    }
+    let position = status
+        .iter()
+        .position(|r| r.name == target)
+        .ok_or_else(|| DbErr::Migration(format!("unknown migration `{}`", target)))?;
+    if status[position].state != MigrationState::Applied {
+        return Err(DbErr::Migration(format!(
+            "`{}` is not applied: there is nothing to roll back to",
+            target
+        )));
+    }
+
+    // Without orphans, `status` follows `M::migrations()` index for index
+    let backend = db.get_database_backend();
+    let migrations = M::migrations();
+    let mut plan = Vec::new();
+    for (record, migration) in status.iter().zip(&migrations).skip(position + 1).rev() {
+        if record.state == MigrationState::Applied {
+            plan.push(planned(migration.as_ref(), backend, false).await?);
+        }
+    }
+    Ok(plan)
+}
+
+/// Rolls back every migration applied after `target` (see [`plan_rollback_to`])
+/// through `Migrator::down`, and returns how many were rolled back.
+pub async fn rollback_to<M: MigratorTrait>(
+    db: &DatabaseConnection,
+    target: &str,
+) -> Result<usize, DbErr> {
+    let steps = plan_rollback_to::<M>(db, target).await?.len();
+    if steps > 0 {
+        M::down(db, Some(u32::try_from(steps).unwrap_or(u32::MAX))).await?;
+    }
+    Ok(steps)
+}
+
+/// `version → applied_at` (Unix seconds) from the migrations table; empty when
+/// the table does not exist yet.
+async fn applied_migrations<M: MigratorTrait>(
+    db: &DatabaseConnection,
+) -> Result<HashMap<String, i64>, DbErr> {
+    let table = M::migration_table_name().to_string();
+    // Checked first: `MigratorTrait::install` would create the table
+    let backend = db.get_database_backend();
+    let sql = match backend {
+        DbBackend::Postgres => {
+            "SELECT COUNT(*) AS n FROM information_schema.tables \
+             WHERE table_schema = current_schema() AND table_name = $1"
+        }
+        DbBackend::MySql => {
+            "SELECT COUNT(*) AS n FROM information_schema.tables \
+             WHERE table_schema = DATABASE() AND table_name = ?"
+        }
+        DbBackend::Sqlite => {
+            "SELECT COUNT(*) AS n FROM sqlite_master WHERE type = 'table' AND name = ?"
+        }
+        other => {
+            return Err(DbErr::BackendNotSupported {
+                db: other.as_str(),
+                ctx: "plan_pending",
+            });
+        }
+    };
+    let exists = Statement::from_sql_and_values(backend, sql, [table.clone().into()]);
+    let found = match db.query_one_raw(exists).await? {
+        Some(row) => row.try_get::<i64>("", "n")? > 0,
+        None => false,
+    };
+    if !found {
+        return Ok(HashMap::new());
+    }
+
+    let select = Query::select()
+        .columns([Alias::new("version"), Alias::new("applied_at")])
+        .from(Alias::new(table))
+        .to_owned();
+    db.query_all(&select)
+        .await?
+        .iter()
+        .map(|row| {
+            Ok((
+                row.try_get::<String>("", "version")?,
+                row.try_get::<i64>("", "applied_at")?,
+            ))
+        })
+        .collect()
+}
+
+/// State of a migration in [`migration_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+    Applied,
+    Pending,
+    /// Recorded as applied in the database but absent from the `Migrator`.
+    Orphaned,
+}
+
+/// One line of `showmigrations`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationRecord {
+    pub name: String,
+    pub state: MigrationState,
+    /// When it was applied — `None` for a pending migration.
+    pub applied_at: Option<DateTime<Utc>>,
+}
+
+/// Every migration of `M` in `Migrator` order with its state, followed by the
+/// orphaned ones (oldest first). Read-only, like [`plan_pending`].
+pub async fn migration_status<M: MigratorTrait>(
+    db: &DatabaseConnection,
+) -> Result<Vec<MigrationRecord>, DbErr> {
+    let mut applied = applied_migrations::<M>(db).await?;
+    let at = |secs: i64| DateTime::from_timestamp(secs, 0);
+
+    let mut records: Vec<MigrationRecord> = M::migrations()
+        .iter()
+        .map(|migration| {
+            let name = migration.name().to_string();
+            match applied.remove(&name) {
+                Some(secs) => MigrationRecord {
+                    name,
+                    state: MigrationState::Applied,
+                    applied_at: at(secs),
+                },
+                None => MigrationRecord {
+                    name,
+                    state: MigrationState::Pending,
+                    applied_at: None,
+                },
+            }
+        })
+        .collect();
+
+    // What is left in the table is unknown to the Migrator
+    let mut orphaned: Vec<(String, i64)> = applied.into_iter().collect();
+    orphaned.sort_by(|a, b| (a.1, &a.0).cmp(&(b.1, &b.0)));
+    records.extend(orphaned.into_iter().map(|(name, secs)| MigrationRecord {
+        name,
+        state: MigrationState::Orphaned,
+        applied_at: at(secs),
+    }));
+    Ok(records)
+}
+
+/// Prints `[X]` applied / `[ ]` pending / `[!]` orphaned migrations and the totals.
+pub fn print_status(records: &[MigrationRecord]) {
+    let width = records.iter().map(|r| r.name.len()).max().unwrap_or(0);
+    for record in records {
+        let mark = match record.state {
+            MigrationState::Applied => "[X]",
+            MigrationState::Pending => "[ ]",
+            MigrationState::Orphaned => "[!]",
+        };
+        let applied_at = record
+            .applied_at
+            .map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_default();
+        let line = format!("{} {:<width$}  {}", mark, record.name, applied_at);
+        if record.state == MigrationState::Orphaned {
+            println!("{}  {}", line, t("migrate.status_orphaned"));
+        } else {
+            println!("{}", line.trim_end());
+        }
+    }
+
+    let count = |state| records.iter().filter(|r| r.state == state).count();
+    println!(
+        "\n{}",
+        tf(
+            "migrate.status_totals",
+            &[
+                count(MigrationState::Applied),
+                count(MigrationState::Pending),
+                count(MigrationState::Orphaned)
+            ]
+        )
+    );
+}
+
+/// Why `sql` loses data, if it does.
+fn data_loss(sql: &str) -> Option<String> {
+    let upper = sql.to_uppercase();
+    let reason = if upper.starts_with("DROP TABLE") {
+        "drops a table and its rows"
+    } else if upper.contains(" DROP COLUMN ") {
+        "drops a column and its data"
+    } else if upper.contains(" ALTER COLUMN ") && upper.contains(" TYPE ") {
+        "changes a column type (values may be truncated or rejected)"
+    } else if upper.contains(" MODIFY COLUMN ") {
+        "redefines a column (type or nullability; values may be truncated)"
+    } else if upper.starts_with("TRUNCATE") || upper.starts_with("DELETE FROM") {
+        "deletes rows"
+    } else {
+        return None;
+    };
+    Some(format!("{}: {}", reason, sql))
+}
+
+/// Prints the plan: migration name, then its statements; data-loss warnings on stderr.
+pub fn print_plan(plan: &[PlannedMigration]) {
+    if plan.is_empty() {
+        println!("{}", t("migrate.plan_empty"));
+        return;
+    }
+    print_statements(plan);
+}
+
+fn print_statements(plan: &[PlannedMigration]) {
+    for migration in plan {
+        println!("-- {}", migration.name);
+        for sql in &migration.statements {
+            println!("{};", sql.trim_end_matches(';'));
+        }
+        for warning in &migration.warnings {
+            eprintln!("  {}", tf("migrate.plan_data_loss", &[warning]));
+        }
+        println!();
+    }
+}
+
+/// `rollback-to <migration> [--yes]`: prints the plan, asks for confirmation
+/// unless `--yes`, then rolls back.
+async fn run_rollback_to<M: MigratorTrait>(
+    db: &DatabaseConnection,
+    args: &[String],
+) -> Result<(), DbErr> {
+    let target = args
+        .iter()
+        .find(|a| !a.starts_with("--"))
+        .ok_or_else(|| DbErr::Custom(t("migrate.rollback_to_usage").into_owned()))?;
+    let yes = args.iter().any(|a| a == "--yes");
+
+    let plan = plan_rollback_to::<M>(db, target).await?;
+    if plan.is_empty() {
+        println!("{}", tf("migrate.rollback_to_current", &[target]));
+        return Ok(());
+    }
+    println!(
+        "{}\n",
+        tf(
+            "migrate.rollback_to_plan",
+            &[&plan.len().to_string(), target]
+        )
+    );
+    print_statements(&plan);
+
+    if !yes {
+        let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt(t("migrate.rollback_to_confirm"))
+            .default(false)
+            .interact()
+            .map_err(|e| DbErr::Custom(e.to_string()))?;
+        if !confirmed {
+            println!("{}", t("migrate.rollback_to_aborted"));
+            return Ok(());
+        }
+    }
+
+    let steps = rollback_to::<M>(db, target).await?;
+    println!(
+        "{}",
+        tf("migrate.rollback_to_done", &[&steps.to_string(), target])
+    );
+    Ok(())
+}
+
+/// `cli::run_cli` with extra commands, every other one going to sea-orm-migration:
+///
+/// - `cargo run -- plan` prints the pending SQL (see [`plan_pending`]),
+/// - `cargo run -- showmigrations` prints each migration's state (see [`migration_status`]),
+/// - `cargo run -- rollback-to <migration> [--yes]` rolls back until `<migration>` is the
+///   latest applied (see [`rollback_to`]), after printing the plan and asking for confirmation.
+pub async fn run_cli<M: MigratorTrait + Sync>(migrator: M) {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = args.first().cloned().unwrap_or_default();
+    if !matches!(command.as_str(), "plan" | "showmigrations" | "rollback-to") {
+        sea_orm_migration::cli::run_cli(migrator).await;
+        return;
+    }
+
+    dotenvy::dotenv().ok();
+    // Same language as the `runique` CLI that spawned this process
+    if let Some(lang) = ["LANG", "LC_ALL", "LC_MESSAGES"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok())
+    {
+        set_lang(Lang::from(lang.as_str()));
+    }
+    let result = async {
+        let url = std::env::var("DATABASE_URL")
+            .map_err(|_| DbErr::Custom(t("migrate.database_url_missing").into_owned()))?;
+        let db = Database::connect(&url).await?;
+        match command.as_str() {
+            "plan" => print_plan(&plan_pending::<M>(&db).await?),
+            "showmigrations" => print_status(&migration_status::<M>(&db).await?),
+            _ => run_rollback_to::<M>(&db, &args[1..]).await?,
+        }
+        Ok::<(), DbErr>(())
+    }
+    .await;
+    if let Err(e) = result {
+        eprintln!(
+            "{}",
+            tf("migrate.command_failed", &[command, e.to_string()])
+        );
+        std::process::exit(1);
+    }
+}
@@ -1,12 +1,17 @@
-//! Primary key definition — type (i32, i64, UUID), auto-increment, and SeaQuery generation.
+//! Primary key definition — type (i32, i64, UUID), auto-increment, composite keys, and SeaQuery generation.
 use sea_query::ColumnType;
 
 /// Table primary key definition.
+///
+/// A single-column key uses `name`/`col_type`. A composite key (built with
+/// [`PrimaryKeyDef::composite`]) lists every member in `composite`; `name` and
+/// `col_type` then mirror the first member.
 #[derive(Debug, Clone)]
 pub struct PrimaryKeyDef {
     pub name: String,
     pub col_type: ColumnType,
     pub auto_increment: bool,
+    pub composite: Vec<(String, ColumnType)>,
 }
 
 impl PrimaryKeyDef {
@@ -15,22 +20,77 @@ impl PrimaryKeyDef {
             name: name.into(),
             col_type: ColumnType::Integer,
             auto_increment: true,
+            composite: Vec::new(),
         }
     }
 
-    pub fn i32(mut self) -> Self {
-        self.col_type = ColumnType::Integer;
-        self
+    /// Multi-column primary key, e.g. a join table keyed on `(user_id, role_id)`.
+    /// Auto-increment is disabled; [`validate`](Self::validate) rejects re-enabling it.
+    pub fn composite(columns: Vec<(String, ColumnType)>) -> Self {
+        let (name, col_type) = columns
+            .first()
+            .cloned()
+            .unwrap_or_else(|| (String::new(), ColumnType::Integer));
+        Self {
+            name,
+            col_type,
+            auto_increment: false,
+            composite: columns,
+        }
     }
 
-    pub fn i64(mut self) -> Self {
-        self.col_type = ColumnType::BigInteger;
-        self
+    pub fn is_composite(&self) -> bool {
+        self.composite.len() > 1
+    }
+
+    /// Members of the key as `(name, type)`, in declaration order.
+    pub fn members(&self) -> Vec<(String, ColumnType)> {
+        if self.composite.is_empty() {
+            vec![(self.name.clone(), self.col_type.clone())]
+        } else {
+            self.composite.clone()
+        }
+    }
+
+    /// Names of the key columns, in declaration order.
+    pub fn column_names(&self) -> Vec<String> {
+        self.members().into_iter().map(|(name, _)| name).collect()
+    }
+
+    /// Checks the key is coherent: it names at least one column, and a
+    /// composite key cannot be auto-incremented.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.members().iter().any(|(name, _)| name.is_empty()) {
+            return Err("primary key must name at least one column".to_string());
+        }
+        if self.is_composite() && self.auto_increment {
+            return Err(format!(
+                "composite primary key ({}) cannot use auto_increment",
+                self.column_names().join(", ")
+            ));
+        }
+        Ok(())
+    }
+
+    /// On a composite key, the type setters (`i32`, `i64`, `uuid`) apply to every member.
+    pub fn i32(self) -> Self {
+        self.with_type(ColumnType::Integer)
+    }
+
+    pub fn i64(self) -> Self {
+        self.with_type(ColumnType::BigInteger)
     }
 
     pub fn uuid(mut self) -> Self {
-        self.col_type = ColumnType::Uuid;
         self.auto_increment = false;
+        self.with_type(ColumnType::Uuid)
+    }
+
+    fn with_type(mut self, col_type: ColumnType) -> Self {
+        for (_, member_type) in &mut self.composite {
+            *member_type = col_type.clone();
+        }
+        self.col_type = col_type;
         self
     }
 
@@ -44,8 +104,16 @@ impl PrimaryKeyDef {
         self
     }
 
-    /// Generates the corresponding SeaQuery ColumnDef
+    /// Generates the corresponding SeaQuery ColumnDef (single-column key).
+    ///
+    /// # Panics
+    /// On a composite key — use [`to_sea_statements`](Self::to_sea_statements).
     pub fn to_sea_column(&self) -> sea_query::ColumnDef {
+        assert!(
+            !self.is_composite(),
+            "to_sea_column called on composite primary key ({}); use to_sea_statements",
+            self.column_names().join(", ")
+        );
         let mut col = sea_query::ColumnDef::new_with_type(
             sea_query::Alias::new(&self.name),
             self.col_type.clone(),
@@ -56,4 +124,34 @@ impl PrimaryKeyDef {
         }
         col
     }
+
+    /// Generates the SeaQuery definitions for the whole key: one `ColumnDef` per
+    /// member, plus a table-level primary key when the key is composite.
+    pub fn to_sea_statements(
+        &self,
+    ) -> (
+        Vec<sea_query::ColumnDef>,
+        Option<sea_query::IndexCreateStatement>,
+    ) {
+        if !self.is_composite() {
+            return (vec![self.to_sea_column()], None);
+        }
+
+        let mut index = sea_query::Index::create();
+        let columns = self
+            .composite
+            .iter()
+            .map(|(name, col_type)| {
+                index.col(sea_query::Alias::new(name));
+                let mut col = sea_query::ColumnDef::new_with_type(
+                    sea_query::Alias::new(name),
+                    col_type.clone(),
+                );
+                col.not_null();
+                col
+            })
+            .collect();
+
+        (columns, Some(index.primary().to_owned()))
+    }
 }
@@ -1,6 +1,10 @@
 //! SeaORM relation definitions — HasOne, HasMany, BelongsTo, ManyToMany.
 //!
 //! These structs feed the `to_model()` code generation in [`crate::migration::ModelSchema`].
+//! A `ManyToMany` also becomes a multiple-choice field in `fill_form`, whose
+//! selection [`ModelSchema::save_many_to_many`](crate::migration::ModelSchema::save_many_to_many)
+//! writes back to the junction table.
+use crate::migration::{column::label_from_name, utils::to_snake_case};
 
 /// Types of relations between two entities.
 #[derive(Debug, Clone)]
@@ -11,26 +15,55 @@ pub enum RelationKind {
     ManyToMany { via: String },
 }
 
+/// Widget of the form field generated for a `ManyToMany`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ManyToManyWidget {
+    /// `<select multiple>`
+    #[default]
+    MultiSelect,
+    /// One checkbox per target row
+    Checkboxes,
+}
+
+/// Form settings of a `ManyToMany`: the junction columns and the target rows
+/// offered as choices. Every `None` falls back to the naming convention.
+#[derive(Debug, Clone, Default)]
+pub struct ManyToManyForm {
+    /// Junction columns `(owner, target)` — default `{model}_id`, `{target}_id`.
+    pub through: Option<(String, String)>,
+    /// Target table — default `target` in snake_case.
+    pub target_table: Option<String>,
+    /// Target column shown as the option label — default its `id`.
+    pub label_column: Option<String>,
+    pub widget: ManyToManyWidget,
+}
+
 /// Definition of a SeaORM relation
 #[derive(Debug, Clone)]
 pub struct RelationDef {
     pub kind: RelationKind,
     pub target: String,
+    /// Form field name of a `ManyToMany`, set by [`as_name`](Self::as_name) — default `target`.
+    pub name: Option<String>,
+    pub m2m: ManyToManyForm,
 }
 
 impl RelationDef {
-    pub fn has_one(target: impl Into<String>) -> Self {
+    fn new(kind: RelationKind, target: impl Into<String>) -> Self {
         Self {
-            kind: RelationKind::HasOne,
+            kind,
             target: target.into(),
+            name: None,
+            m2m: ManyToManyForm::default(),
         }
     }
 
+    pub fn has_one(target: impl Into<String>) -> Self {
+        Self::new(RelationKind::HasOne, target)
+    }
+
     pub fn has_many(target: impl Into<String>) -> Self {
-        Self {
-            kind: RelationKind::HasMany,
-            target: target.into(),
-        }
+        Self::new(RelationKind::HasMany, target)
     }
 
     pub fn belongs_to(
@@ -38,24 +71,153 @@ impl RelationDef {
         from: impl Into<String>,
         to: impl Into<String>,
     ) -> Self {
-        Self {
-            kind: RelationKind::BelongsTo {
+        Self::new(
+            RelationKind::BelongsTo {
                 from: from.into(),
                 to: to.into(),
             },
-            target: target.into(),
-        }
+            target,
+        )
     }
 
     pub fn many_to_many(target: impl Into<String>, via: impl Into<String>) -> Self {
-        Self {
-            kind: RelationKind::ManyToMany { via: via.into() },
-            target: target.into(),
-        }
+        Self::new(RelationKind::ManyToMany { via: via.into() }, target)
+    }
+
+    /// Display name: admin UI, and field name of a `ManyToMany` in generated
+    /// forms. No effect on migration generation.
+    pub fn as_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
     }
 
-    /// Display name for admin UI — no effect on migration generation.
-    pub fn as_name(self, _name: impl Into<String>) -> Self {
+    // ── ManyToMany form settings ────────────────────────────────────────────
+
+    /// Junction columns pointing to this model and to the target.
+    pub fn through(
+        mut self,
+        owner_column: impl Into<String>,
+        target_column: impl Into<String>,
+    ) -> Self {
+        self.m2m.through = Some((owner_column.into(), target_column.into()));
         self
     }
+
+    /// Table of the target rows, when it differs from `target`.
+    pub fn target_table(mut self, table: impl Into<String>) -> Self {
+        self.m2m.target_table = Some(table.into());
+        self
+    }
+
+    /// Target column shown as the option label.
+    pub fn label_column(mut self, column: impl Into<String>) -> Self {
+        self.m2m.label_column = Some(column.into());
+        self
+    }
+
+    pub fn widget(mut self, widget: ManyToManyWidget) -> Self {
+        self.m2m.widget = widget;
+        self
+    }
+
+    /// Form field name: [`as_name`](Self::as_name), otherwise `target`.
+    pub fn field_name(&self) -> &str {
+        self.name
+            .as_deref()
+            .filter(|name| !name.is_empty())
+            .unwrap_or(&self.target)
+    }
+
+    /// `(junction, owner column, target column)` of a `ManyToMany` declared on
+    /// `model_name`; `None` for the other kinds.
+    pub fn junction(&self, model_name: &str) -> Option<(String, String, String)> {
+        let RelationKind::ManyToMany { via } = &self.kind else {
+            return None;
+        };
+        let (owner, target) = self.m2m.through.clone().unwrap_or_else(|| {
+            (
+                format!("{}_id", to_snake_case(model_name)),
+                format!("{}_id", to_snake_case(&self.target)),
+            )
+        });
+        Some((to_snake_case(via), owner, target))
+    }
+
+    /// Multiple-choice field of a `ManyToMany` (`None` for the other kinds),
+    /// its options loaded from the target table by
+    /// [`Forms::prepare`](crate::forms::Forms::prepare). The submitted value is
+    /// the comma-separated list of selected ids.
+    pub fn to_form_field(&self) -> Option<crate::forms::generic::GenericField> {
+        use crate::forms::base::FormField;
+        use crate::forms::fields::{CheckboxField, ChoiceField};
+
+        if !matches!(self.kind, RelationKind::ManyToMany { .. }) {
+            return None;
+        }
+        let table = self
+            .m2m
+            .target_table
+            .clone()
+            .unwrap_or_else(|| to_snake_case(&self.target));
+        let label = self
+            .m2m
+            .label_column
+            .clone()
+            .unwrap_or_else(|| "id".to_string());
+        let loader = move |db: sea_orm::DatabaseConnection| {
+            let (table, label) = (table.clone(), label.clone());
+            async move { target_choices(&db, &table, &label).await }
+        };
+
+        let name = self.field_name();
+        let mut field: crate::forms::generic::GenericField = match self.m2m.widget {
+            ManyToManyWidget::MultiSelect => {
+                ChoiceField::from_query(name, loader).multiple().into()
+            }
+            ManyToManyWidget::Checkboxes => CheckboxField::from_query(name, loader).into(),
+        };
+        field.set_label(&label_from_name(name));
+        Some(field)
+    }
+}
+
+/// `(id, label)` of every row of `table`, ordered by label.
+async fn target_choices(
+    db: &sea_orm::DatabaseConnection,
+    table: &str,
+    label: &str,
+) -> Result<Vec<(String, String)>, sea_orm::DbErr> {
+    use sea_orm::ConnectionTrait;
+    use sea_query::{Alias, Expr, Order, Query};
+
+    let select = Query::select()
+        .expr_as(Expr::col(Alias::new("id")), Alias::new("choice_id"))
+        .expr_as(Expr::col(Alias::new(label)), Alias::new("choice_label"))
+        .from(Alias::new(table))
+        .order_by(Alias::new(label), Order::Asc)
+        .to_owned();
+    db.query_all(&select)
+        .await?
+        .iter()
+        .map(|row| {
+            Ok((
+                cell_to_string(row, "choice_id")?,
+                cell_to_string(row, "choice_label")?,
+            ))
+        })
+        .collect()
+}
+
+/// Integer, UUID or text cell rendered as the string a form submits.
+pub(crate) fn cell_to_string(
+    row: &sea_orm::QueryResult,
+    column: &str,
+) -> Result<String, sea_orm::DbErr> {
+    if let Ok(v) = row.try_get::<i64>("", column) {
+        return Ok(v.to_string());
+    }
+    if let Ok(v) = row.try_get::<uuid::Uuid>("", column) {
+        return Ok(v.to_string());
+    }
+    row.try_get::<String>("", column)
 }
@@ -133,28 +133,82 @@ impl ModelSchema {
     // ── Build ───────────────────────────────────────────────────────────────
 
     pub fn build(self) -> Result<ModelSchema, String> {
-        if self.primary_key.is_none() {
+        let Some(ref pk) = self.primary_key else {
             return Err(format!(
                 "ModelSchema '{}' : missing primary key",
                 self.model_name
             ));
+        };
+        pk.validate()
+            .map_err(|e| format!("ModelSchema '{}' : {}", self.model_name, e))?;
+        let fk_columns = self
+            .missing_fk_columns()
+            .map_err(|e| format!("ModelSchema '{}' : {}", self.model_name, e))?;
+        let mut schema = self;
+        schema.columns.extend(fk_columns);
+        Ok(schema)
+    }
+
+    /// Columns to create for foreign keys whose `from_column` is not declared
+    /// (see [`ForeignKeyDef::to_column_def`]). Fails when a declared column
+    /// cannot hold the referenced key.
+    pub fn missing_fk_columns(&self) -> Result<Vec<ColumnDef>, String> {
+        let pk_members = self
+            .primary_key
+            .as_ref()
+            .map(|pk| pk.members())
+            .unwrap_or_default();
+        let mut missing: Vec<ColumnDef> = Vec::new();
+        for fk in &self.foreign_keys {
+            let declared = self
+                .columns
+                .iter()
+                .find(|c| c.name == fk.from_column)
+                .map(|c| &c.col_type)
+                .or_else(|| {
+                    pk_members
+                        .iter()
+                        .find(|(name, _)| *name == fk.from_column)
+                        .map(|(_, col_type)| col_type)
+                });
+            match declared {
+                Some(col_type) => fk.check_column_type(col_type)?,
+                None if missing.iter().any(|c| c.name == fk.from_column) => {}
+                None => missing.push(fk.to_column_def()),
+            }
         }
-        Ok(self)
+        Ok(missing)
     }
 
     // ── Migration generation ─────────────────────────────────────────────────
 
     /// Generates the SeaQuery TableCreateStatement from the schema
     /// This replaces the syn parser — the source of truth is here
+    ///
+    /// Foreign keys whose column is not declared get one, see
+    /// [`missing_fk_columns`](Self::missing_fk_columns).
+    ///
+    /// # Panics
+    /// When the primary key fails [`PrimaryKeyDef::validate`], or a foreign key
+    /// column has a type incompatible with the referenced key — schemas built
+    /// through [`build`](Self::build) are already checked.
     pub fn to_migration(&self) -> sea_query::TableCreateStatement {
         let mut table = sea_query::Table::create();
         table
             .table(sea_query::Alias::new(&self.table_name))
             .if_not_exists();
 
-        // Primary key
+        // Primary key (one column per member, table-level constraint if composite)
+        let mut pk_index = None;
         if let Some(ref pk) = self.primary_key {
-            table.col(pk.to_sea_column());
+            if let Err(e) = pk.validate() {
+                panic!("ModelSchema '{}' : {}", self.model_name, e);
+            }
+            let (pk_cols, index) = pk.to_sea_statements();
+            for col in pk_cols {
+                table.col(col);
+            }
+            pk_index = index;
         }
 
         // Columns (ignored fields are skipped)
@@ -164,6 +218,18 @@ impl ModelSchema {
             }
         }
 
+        // Undeclared foreign key columns
+        let fk_columns = self
+            .missing_fk_columns()
+            .unwrap_or_else(|e| panic!("ModelSchema '{}' : {}", self.model_name, e));
+        for col in &fk_columns {
+            table.col(col.to_sea_column());
+        }
+
+        if let Some(mut index) = pk_index {
+            table.primary_key(&mut index);
+        }
+
         // Foreign keys
         for fk in &self.foreign_keys {
             table.foreign_key(&mut fk.to_sea_foreign_key(&self.table_name));
@@ -172,6 +238,102 @@ impl ModelSchema {
         table.to_owned()
     }
 
+    /// Generates the reverse of [`to_migration`](Self::to_migration): drops the table.
+    pub fn to_down_migration(&self) -> sea_query::TableDropStatement {
+        sea_query::Table::drop()
+            .table(sea_query::Alias::new(&self.table_name))
+            .if_exists()
+            .to_owned()
+    }
+
+    /// `CREATE INDEX` statements for the declared indexes, to run after
+    /// [`to_migration`](Self::to_migration). Fails on the first index the
+    /// engine cannot express (partial index on MySQL, `USING GIN` on SQLite…).
+    #[cfg(feature = "orm")]
+    pub fn to_index_migrations(
+        &self,
+        engine: &crate::db::DatabaseEngine,
+    ) -> Result<Vec<sea_query::IndexCreateStatement>, String> {
+        self.indexes
+            .iter()
+            .map(|idx| idx.to_sea_index_for(&self.table_name, engine))
+            .collect()
+    }
+
+    /// Full SQL of the table creation for `engine`: `CREATE TABLE`, its indexes,
+    /// then on PostgreSQL one `COMMENT ON COLUMN` per commented column (MySQL gets
+    /// the comment inline from `to_sea_column`; SQLite has no column comments).
+    ///
+    /// On PostgreSQL the table is qualified with [`schema`](Self::schema) when set.
+    #[cfg(feature = "orm")]
+    pub fn to_migration_sql(
+        &self,
+        engine: &crate::db::DatabaseEngine,
+    ) -> Result<Vec<String>, String> {
+        use crate::db::DatabaseEngine;
+        use sea_query::{MysqlQueryBuilder, PostgresQueryBuilder, SqliteQueryBuilder};
+
+        let mut create = self.to_migration();
+        let mut indexes = self.to_index_migrations(engine)?;
+        let pg_schema = match engine {
+            DatabaseEngine::PostgreSQL => self.schema.as_deref(),
+            _ => None,
+        };
+        if let Some(schema) = pg_schema {
+            let qualified = || {
+                (
+                    sea_query::Alias::new(schema),
+                    sea_query::Alias::new(&self.table_name),
+                )
+            };
+            create.table(qualified());
+            for idx in &mut indexes {
+                idx.table(qualified());
+            }
+        }
+
+        let mut sql = Vec::with_capacity(1 + indexes.len());
+        match engine {
+            DatabaseEngine::PostgreSQL => {
+                sql.push(create.to_string(PostgresQueryBuilder));
+                sql.extend(indexes.iter().map(|i| i.to_string(PostgresQueryBuilder)));
+                sql.extend(self.comment_statements(pg_schema));
+            }
+            DatabaseEngine::MySQL | DatabaseEngine::MariaDB => {
+                sql.push(create.to_string(MysqlQueryBuilder));
+                sql.extend(indexes.iter().map(|i| i.to_string(MysqlQueryBuilder)));
+            }
+            DatabaseEngine::SQLite => {
+                sql.push(create.to_string(SqliteQueryBuilder));
+                sql.extend(indexes.iter().map(|i| i.to_string(SqliteQueryBuilder)));
+            }
+        }
+        Ok(sql)
+    }
+
+    /// PostgreSQL `COMMENT ON COLUMN` statements for commented columns.
+    #[cfg(feature = "orm")]
+    fn comment_statements(&self, schema: Option<&str>) -> Vec<String> {
+        let table = match schema {
+            Some(schema) => format!("\"{}\".\"{}\"", schema, self.table_name),
+            None => format!("\"{}\"", self.table_name),
+        };
+        self.columns
+            .iter()
+            .filter(|c| !c.ignored)
+            .filter_map(|c| {
+                c.comment.as_ref().map(|text| {
+                    format!(
+                        "COMMENT ON COLUMN {}.\"{}\" IS '{}'",
+                        table,
+                        c.name,
+                        text.replace('\'', "''")
+                    )
+                })
+            })
+            .collect()
+    }
+
     /// Fills a Forms with fields generated from the schema.
     /// - `fields`: whitelist (only these fields are included, in this order)
     /// - `exclude`: blacklist (these fields are excluded)
@@ -184,18 +346,25 @@ impl ModelSchema {
         fields: Option<&[&str]>,
         exclude: Option<&[&str]>,
     ) {
-        // Columns always auto-excluded: PK
-        let pk_name = self.primary_key.as_ref().map(|pk| pk.name.as_str());
+        // Columns always auto-excluded: PK (every member of a composite key)
+        let pk_names = self
+            .primary_key
+            .as_ref()
+            .map(|pk| pk.column_names())
+            .unwrap_or_default();
 
         if let Some(field_names) = fields {
             // Whitelist: respect the order given by the developer
             for &field_name in field_names {
                 let col = self.columns.iter().find(|c| c.name == field_name);
                 match col {
-                    None => panic!(
-                        "ModelForm '{}' : field '{}' does not exist in the schema",
-                        self.model_name, field_name
-                    ),
+                    None => match self.many_to_many_field(field_name) {
+                        Some(generic) => form.field_generic(generic),
+                        None => panic!(
+                            "ModelForm '{}' : field '{}' does not exist in the schema",
+                            self.model_name, field_name
+                        ),
+                    },
                     Some(col) => {
                         if let Some(generic) = col.to_form_field() {
                             form.field_generic(generic);
@@ -209,7 +378,7 @@ impl ModelSchema {
 
             for col in &self.columns {
                 // Skip PK
-                if pk_name == Some(col.name.as_str()) {
+                if pk_names.contains(&col.name) {
                     continue;
                 }
                 // Skip excluded
@@ -220,13 +389,163 @@ impl ModelSchema {
                     form.field_generic(generic);
                 }
             }
+
+            // ManyToMany relations, after the columns
+            for rel in &self.relations {
+                if excluded.contains(&rel.field_name()) {
+                    continue;
+                }
+                if let Some(generic) = rel.to_form_field() {
+                    form.field_generic(generic);
+                }
+            }
         }
     }
 
+    fn many_to_many_field(&self, name: &str) -> Option<crate::forms::generic::GenericField> {
+        self.relations
+            .iter()
+            .find(|rel| rel.field_name() == name)
+            .and_then(|rel| rel.to_form_field())
+    }
+
+    /// Writes the selection of each `ManyToMany` field of `form` to its junction
+    /// table for the row `owner_id`: inserts the ids newly selected, deletes the
+    /// unselected ones — an empty selection removes every association. Fields
+    /// absent from the form are left alone.
+    ///
+    /// Call it from `on_save`, with its transaction, once the owner row exists:
+    ///
+    /// ```rust,ignore
+    /// async fn on_save(&mut self, txn: &DatabaseTransaction) -> Result<(), DbErr> {
+    ///     let article = article::ActiveModel {
+    ///         title: Set(self.cleaned_string("title").unwrap_or_default()),
+    ///         ..Default::default()
+    ///     }
+    ///     .insert(txn)
+    ///     .await?;
+    ///     Self::schema().save_many_to_many(txn, self.get_form(), article.id).await
+    /// }
+    /// ```
+    #[cfg(feature = "orm")]
+    pub async fn save_many_to_many(
+        &self,
+        txn: &sea_orm::DatabaseTransaction,
+        form: &crate::forms::Forms,
+        owner_id: impl Into<sea_orm::Value>,
+    ) -> Result<(), sea_orm::DbErr> {
+        use sea_orm::ConnectionTrait;
+        use sea_query::{Alias, Expr, ExprTrait, Query};
+
+        let owner_id: sea_orm::Value = owner_id.into();
+        for rel in &self.relations {
+            let Some((junction, owner_col, target_col)) = rel.junction(&self.model_name) else {
+                continue;
+            };
+            let Some(field) = form.fields.get(rel.field_name()) else {
+                continue;
+            };
+            let selected: Vec<&str> = field
+                .value()
+                .split(',')
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .collect();
+            let current = junction_ids(txn, &junction, &owner_col, &target_col, &owner_id).await?;
+
+            let removed: Vec<sea_orm::Value> = current
+                .iter()
+                .filter(|id| !selected.contains(&id.as_str()))
+                .map(|id| id_value(id))
+                .collect();
+            if !removed.is_empty() {
+                let delete = Query::delete()
+                    .from_table(Alias::new(&junction))
+                    .and_where(Expr::col(Alias::new(&owner_col)).eq(owner_id.clone()))
+                    .and_where(Expr::col(Alias::new(&target_col)).is_in(removed))
+                    .to_owned();
+                txn.execute(&delete).await?;
+            }
+
+            let added: Vec<&str> = selected
+                .iter()
+                .copied()
+                .filter(|id| !current.iter().any(|c| c == id))
+                .collect();
+            if !added.is_empty() {
+                let mut insert = Query::insert();
+                insert
+                    .into_table(Alias::new(&junction))
+                    .columns([Alias::new(&owner_col), Alias::new(&target_col)]);
+                for id in added {
+                    insert.values_panic([owner_id.clone().into(), id_value(id).into()]);
+                }
+                txn.execute(&insert).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Edit forms: selects, in each `ManyToMany` field of `form`, the targets
+    /// currently associated with the row `owner_id`.
+    #[cfg(feature = "orm")]
+    pub async fn load_many_to_many<C: sea_orm::ConnectionTrait>(
+        &self,
+        db: &C,
+        form: &mut crate::forms::Forms,
+        owner_id: impl Into<sea_orm::Value>,
+    ) -> Result<(), sea_orm::DbErr> {
+        let owner_id: sea_orm::Value = owner_id.into();
+        for rel in &self.relations {
+            let Some((junction, owner_col, target_col)) = rel.junction(&self.model_name) else {
+                continue;
+            };
+            if let Some(field) = form.fields.get_mut(rel.field_name()) {
+                let ids = junction_ids(db, &junction, &owner_col, &target_col, &owner_id).await?;
+                field.set_value(&ids.join(","));
+            }
+        }
+        Ok(())
+    }
+
     /// Diff between two ModelSchema — returns the changes to apply
     pub fn diff(&self, other: &ModelSchema) -> SchemaDiff {
+        self.diff_with_renames(other, &[])
+    }
+
+    /// Same as [`diff`](Self::diff), with explicit `(old, new)` rename hints: a
+    /// hinted pair is reported in `renamed_columns` instead of drop + add.
+    /// A hint is ignored when `old` is still present or `new` already existed.
+    pub fn diff_with_renames(&self, other: &ModelSchema, renames: &[(&str, &str)]) -> SchemaDiff {
         let mut diff = SchemaDiff::new(&self.table_name);
 
+        let find = |schema: &ModelSchema, name: &str| -> Option<ColumnDef> {
+            schema.columns.iter().find(|c| c.name == name).cloned()
+        };
+
+        // Renames: the old/new names are excluded from drop/add
+        let mut renamed_old = std::collections::HashSet::new();
+        let mut renamed_new = std::collections::HashSet::new();
+        for &(old, new) in renames {
+            if let (Some(before), Some(after)) = (find(self, old), find(other, new))
+                && find(other, old).is_none()
+                && find(self, new).is_none()
+            {
+                diff.renamed_columns
+                    .push((old.to_string(), new.to_string()));
+                renamed_old.insert(old);
+                renamed_new.insert(new);
+                // Keyed by the new name: the rename is applied before the alteration
+                let before = ColumnDef {
+                    name: after.name.clone(),
+                    ..before
+                };
+                if !before.changed_attributes(&after).is_empty() {
+                    diff.modified_columns.push((before, after));
+                }
+            }
+        }
+
         // Added columns
         let self_cols: std::collections::HashSet<&str> =
             self.columns.iter().map(|c| c.name.as_str()).collect();
@@ -234,12 +553,32 @@ impl ModelSchema {
             other.columns.iter().map(|c| c.name.as_str()).collect();
 
         for name in other_cols.difference(&self_cols) {
+            if renamed_new.contains(name) {
+                continue;
+            }
             let col = other.columns.iter().find(|c| c.name == *name).unwrap();
             diff.added_columns.push(col.clone());
         }
 
         for name in self_cols.difference(&other_cols) {
-            diff.dropped_columns.push(name.to_string());
+            if renamed_old.contains(name) {
+                continue;
+            }
+            let col = self.columns.iter().find(|c| c.name == *name).unwrap();
+            diff.dropped_columns.push(col.clone());
+        }
+
+        // Modified columns: present on both sides, compared attribute by attribute.
+        // An enum that only gained variants is extended in place, not altered.
+        for after in &other.columns {
+            if let Some(before) = self.columns.iter().find(|c| c.name == after.name) {
+                let changes = before.changed_attributes(after);
+                if changes == ["col_type"] && before.enum_variants_added(after).is_some() {
+                    diff.extended_enums.push((before.clone(), after.clone()));
+                } else if !changes.is_empty() {
+                    diff.modified_columns.push((before.clone(), after.clone()));
+                }
+            }
         }
 
         diff
@@ -280,7 +619,7 @@ impl ModelSchema {
         // Imports
         out.push_str("use sea_orm::entity::prelude::*;\n");
         out.push_str("use serde::{Serialize, Deserialize};\n");
-        out.push_str("use runique::impl_objects;\n\n");
+        out.push_str("use runique::{impl_objects, impl_signals};\n\n");
 
         // Struct Model
         out.push_str(
@@ -289,18 +628,20 @@ impl ModelSchema {
         out.push_str(&format!("#[sea_orm(table_name = \"{}\")]\n", table_name));
         out.push_str("pub struct Model {\n");
 
-        // Primary key
+        // Primary key: one `#[sea_orm(primary_key)]` field per member
         if let Some(ref pk) = self.primary_key {
-            if pk.auto_increment {
-                out.push_str("    #[sea_orm(primary_key)]\n");
-            } else {
-                out.push_str("    #[sea_orm(primary_key, auto_increment = false)]\n");
+            for (name, col_type) in pk.members() {
+                if pk.auto_increment && !pk.is_composite() {
+                    out.push_str("    #[sea_orm(primary_key)]\n");
+                } else {
+                    out.push_str("    #[sea_orm(primary_key, auto_increment = false)]\n");
+                }
+                out.push_str(&format!(
+                    "    pub {}: {},\n",
+                    name,
+                    Self::pk_to_rust_type(&col_type)
+                ));
             }
-            out.push_str(&format!(
-                "    pub {}: {},\n",
-                pk.name,
-                Self::pk_to_rust_type(pk)
-            ));
         }
 
         // Columns
@@ -314,52 +655,118 @@ impl ModelSchema {
 
         out.push_str("}\n\n");
 
-        // Relation
-        out.push_str("#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]\n");
-        out.push_str("pub enum Relation {\n");
+        out.push_str(&self.relation_code());
+        out.push('\n');
+
+        // ActiveModelBehavior emitting the lifecycle signals (+ stamping timestamps)
+        if self.has_auto_timestamps() {
+            let columns = |cols: Vec<&ColumnDef>| {
+                cols.iter()
+                    .map(|c| format!("Column::{}", to_pascal_case(&c.name)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            out.push_str(&format!(
+                "impl_signals!(\n    ActiveModel,\n    auto_now = [{}],\n    auto_now_update = [{}],\n);\n\n",
+                columns(self.auto_now_columns()),
+                columns(self.auto_now_update_columns()),
+            ));
+        } else {
+            out.push_str("impl_signals!(ActiveModel);\n\n");
+        }
+
+        // impl_objects (soft-delete manager when a marker column exists)
+        match self.columns.iter().find(|c| c.soft_delete) {
+            Some(col) => out.push_str(&format!(
+                "impl_objects!(Entity, soft_delete = Column::{});\n",
+                to_pascal_case(&col.name)
+            )),
+            None => out.push_str("impl_objects!(Entity);\n"),
+        }
+
+        out
+    }
+
+    /// `Relation` enum and `Related` impls of [`to_model`](Self::to_model). Targets
+    /// are sibling entity modules (`super::{target}`); a `ManyToMany` is declared
+    /// as `has_many` on its junction entity, whose `Relation` must hold a
+    /// `belongs_to` variant for each side. One variant per name and one `Related`
+    /// impl per target: the first relation declared wins.
+    fn relation_code(&self) -> String {
+        let mut variants: Vec<(String, String)> = Vec::new();
+        let mut related: Vec<(String, String)> = Vec::new();
+        let mut push_variant = |name: String, attr: String| {
+            if !variants.iter().any(|(n, _)| *n == name) {
+                variants.push((name, attr));
+            }
+        };
+        let this = to_pascal_case(&to_snake_case(&self.model_name));
+
         for rel in &self.relations {
-            match &rel.kind {
+            let target = to_snake_case(&rel.target);
+            let variant = to_pascal_case(&target);
+            let body = match &rel.kind {
                 RelationKind::BelongsTo { from, to } => {
-                    out.push_str(&format!(
-                        "    #[sea_orm(belongs_to = \"super::{}::Entity\", from = \"Column::{}\", to = \"super::{}::Column::{}\")]\n    {},\n",
-                        rel.target,
-                        to_pascal_case(from),
-                        rel.target,
-                        to_pascal_case(to),
-                        to_pascal_case(&rel.target)
-                    ));
+                    push_variant(
+                        variant.clone(),
+                        format!(
+                            "belongs_to = \"super::{}::Entity\", from = \"Column::{}\", to = \"super::{}::Column::{}\"",
+                            target,
+                            to_pascal_case(from),
+                            target,
+                            to_pascal_case(to)
+                        ),
+                    );
+                    format!("        Relation::{}.def()\n", variant)
                 }
                 RelationKind::HasMany | RelationKind::HasOne => {
-                    out.push_str(&format!(
-                        "    #[sea_orm(has_many = \"super::{}::Entity\")]\n    {},\n",
-                        rel.target,
-                        to_pascal_case(&rel.target)
-                    ));
+                    let kind = match rel.kind {
+                        RelationKind::HasOne => "has_one",
+                        _ => "has_many",
+                    };
+                    push_variant(
+                        variant.clone(),
+                        format!("{} = \"super::{}::Entity\"", kind, target),
+                    );
+                    format!("        Relation::{}.def()\n", variant)
                 }
                 RelationKind::ManyToMany { via } => {
-                    out.push_str(&format!(
-                        "    #[sea_orm(many_to_many = \"super::{}::Entity\", via = \"super::{}::Entity\")]\n    {},\n",
-                        rel.target,
-                        via,
-                        to_pascal_case(&rel.target)
-                    ));
+                    let junction = to_snake_case(via);
+                    push_variant(
+                        to_pascal_case(&junction),
+                        format!("has_many = \"super::{}::Entity\"", junction),
+                    );
+                    format!(
+                        "        super::{j}::Relation::{}.def()\n    }}\n\n    fn via() -> Option<RelationDef> {{\n        Some(super::{j}::Relation::{}.def().rev())\n",
+                        variant,
+                        this,
+                        j = junction
+                    )
                 }
+            };
+            if !related.iter().any(|(t, _)| *t == target) {
+                related.push((target, body));
             }
         }
-        out.push_str("}\n\n");
-
-        // ActiveModelBehavior
-        out.push_str("impl ActiveModelBehavior for ActiveModel {}\n\n");
-
-        // impl_objects
-        out.push_str("impl_objects!(Entity);\n");
 
+        let mut out = String::from("#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]\n");
+        out.push_str("pub enum Relation {\n");
+        for (name, attr) in &variants {
+            out.push_str(&format!("    #[sea_orm({})]\n    {},\n", attr, name));
+        }
+        out.push_str("}\n");
+        for (target, body) in &related {
+            out.push_str(&format!(
+                "\nimpl Related<super::{}::Entity> for Entity {{\n    fn to() -> RelationDef {{\n{}    }}\n}}\n",
+                target, body
+            ));
+        }
         out
     }
 
-    fn pk_to_rust_type(pk: &PrimaryKeyDef) -> &'static str {
+    fn pk_to_rust_type(col_type: &sea_query::ColumnType) -> &'static str {
         use sea_query::ColumnType::*;
-        match &pk.col_type {
+        match col_type {
             Integer | TinyInteger | SmallInteger => "i32",
             BigInteger => "i64",
             Unsigned => "u32",
@@ -381,6 +788,42 @@ impl ModelSchema {
     pub fn has_auto_timestamps(&self) -> bool {
         self.columns.iter().any(|c| c.auto_now || c.auto_now_update)
     }
+
+    /// Column of this model holding the FK to `parent`: the `foreign_keys` entry
+    /// referencing the parent table, otherwise the `belongs_to` relation targeting it.
+    /// `None` when there is none, or several (ambiguous).
+    pub fn fk_column_to(&self, parent: &ModelSchema) -> Option<String> {
+        fn single(mut cols: Vec<&str>) -> Option<String> {
+            cols.sort_unstable();
+            cols.dedup();
+            match cols.as_slice() {
+                [col] => Some((*col).to_string()),
+                _ => None,
+            }
+        }
+
+        let by_fk: Vec<&str> = self
+            .foreign_keys
+            .iter()
+            .filter(|fk| fk.to_table == parent.table_name)
+            .map(|fk| fk.from_column.as_str())
+            .collect();
+        if !by_fk.is_empty() {
+            return single(by_fk);
+        }
+
+        let parent_model = to_snake_case(&parent.model_name);
+        let by_relation: Vec<&str> = self
+            .relations
+            .iter()
+            .filter(|rel| rel.target == parent.table_name || rel.target == parent_model)
+            .filter_map(|rel| match &rel.kind {
+                RelationKind::BelongsTo { from, .. } => Some(from.as_str()),
+                _ => None,
+            })
+            .collect();
+        single(by_relation)
+    }
 }
 
 /// Result of the diff between two ModelSchema
@@ -388,8 +831,15 @@ impl ModelSchema {
 pub struct SchemaDiff {
     pub table_name: String,
     pub added_columns: Vec<ColumnDef>,
-    pub dropped_columns: Vec<String>,
+    /// Full definition of each dropped column, so the down migration can re-add it.
+    pub dropped_columns: Vec<ColumnDef>,
     pub modified_columns: Vec<(ColumnDef, ColumnDef)>, // (before, after)
+    /// Column renames: (old_name, new_name). Emitted as `RENAME COLUMN` (no data loss).
+    pub renamed_columns: Vec<(String, String)>,
+    /// Enum columns that only gained variants: (before, after). Not part of
+    /// [`to_up_migration`](Self::to_up_migration), see
+    /// [`to_enum_migration_sql`](Self::to_enum_migration_sql).
+    pub extended_enums: Vec<(ColumnDef, ColumnDef)>,
 }
 
 impl SchemaDiff {
@@ -399,6 +849,8 @@ impl SchemaDiff {
             added_columns: Vec::new(),
             dropped_columns: Vec::new(),
             modified_columns: Vec::new(),
+            renamed_columns: Vec::new(),
+            extended_enums: Vec::new(),
         }
     }
 
@@ -406,6 +858,284 @@ impl SchemaDiff {
         self.added_columns.is_empty()
             && self.dropped_columns.is_empty()
             && self.modified_columns.is_empty()
+            && self.renamed_columns.is_empty()
+            && self.extended_enums.is_empty()
+    }
+
+    /// True when every change is a comment update: nothing structural to alter.
+    pub fn is_metadata_only(&self) -> bool {
+        self.added_columns.is_empty()
+            && self.dropped_columns.is_empty()
+            && self.renamed_columns.is_empty()
+            && self.extended_enums.is_empty()
+            && !self.modified_columns.is_empty()
+            && self
+                .modified_columns
+                .iter()
+                .all(|(before, after)| before.is_metadata_only_change(after))
+    }
+
+    /// Opt-in rename heuristic: when the diff holds exactly one dropped and one
+    /// added column with identical type and constraints, treat them as a rename.
+    /// Any other shape is left untouched.
+    pub fn detect_renames(mut self) -> Self {
+        if let ([dropped], [added]) = (
+            self.dropped_columns.as_slice(),
+            self.added_columns.as_slice(),
+        ) && dropped.changed_attributes(added).is_empty()
+        {
+            self.renamed_columns
+                .push((dropped.name.clone(), added.name.clone()));
+            self.dropped_columns.clear();
+            self.added_columns.clear();
+        }
+        self
+    }
+
+    /// Generates the forward ALTER statements: applies renames (`RENAME COLUMN`,
+    /// no data loss), adds new columns, alters modified ones, then drops removed ones.
+    /// One statement per operation (SQLite accepts a single change per ALTER).
+    pub fn to_up_migration(&self) -> Vec<sea_query::TableAlterStatement> {
+        let table = || sea_query::Alias::new(&self.table_name);
+        let mut statements = Vec::new();
+
+        // Renames first: modified columns are keyed by their new name
+        for (old, new) in &self.renamed_columns {
+            statements.push(
+                sea_query::Table::alter()
+                    .table(table())
+                    .rename_column(sea_query::Alias::new(old), sea_query::Alias::new(new))
+                    .to_owned(),
+            );
+        }
+
+        for col in &self.added_columns {
+            if col.ignored {
+                continue;
+            }
+            statements.push(
+                sea_query::Table::alter()
+                    .table(table())
+                    .add_column(col.to_sea_column())
+                    .to_owned(),
+            );
+        }
+
+        for (_, after) in &self.modified_columns {
+            statements.push(
+                sea_query::Table::alter()
+                    .table(table())
+                    .modify_column(after.to_sea_column())
+                    .to_owned(),
+            );
+        }
+
+        for col in &self.dropped_columns {
+            if col.ignored {
+                continue;
+            }
+            statements.push(
+                sea_query::Table::alter()
+                    .table(table())
+                    .drop_column(sea_query::Alias::new(&col.name))
+                    .to_owned(),
+            );
+        }
+
+        statements
+    }
+
+    /// Generates the inverse ALTER statements: restores modified columns, reverts
+    /// renames, re-adds dropped ones from their stored `ColumnDef`, then drops added ones.
+    /// One statement per operation (SQLite accepts a single change per ALTER).
+    ///
+    /// Columns that cannot be restored are reported by [`down_warnings`](Self::down_warnings),
+    /// which the caller is expected to surface.
+    pub fn to_down_migration(&self) -> Vec<sea_query::TableAlterStatement> {
+        let table = || sea_query::Alias::new(&self.table_name);
+        let mut statements = Vec::new();
+
+        for (before, _) in &self.modified_columns {
+            statements.push(
+                sea_query::Table::alter()
+                    .table(table())
+                    .modify_column(before.to_sea_column())
+                    .to_owned(),
+            );
+        }
+
+        for (old, new) in &self.renamed_columns {
+            statements.push(
+                sea_query::Table::alter()
+                    .table(table())
+                    .rename_column(sea_query::Alias::new(new), sea_query::Alias::new(old))
+                    .to_owned(),
+            );
+        }
+
+        for col in &self.dropped_columns {
+            if col.ignored {
+                continue;
+            }
+            statements.push(
+                sea_query::Table::alter()
+                    .table(table())
+                    .add_column(col.to_sea_column())
+                    .to_owned(),
+            );
+        }
+
+        for col in &self.added_columns {
+            if col.ignored {
+                continue;
+            }
+            statements.push(
+                sea_query::Table::alter()
+                    .table(table())
+                    .drop_column(sea_query::Alias::new(&col.name))
+                    .to_owned(),
+            );
+        }
+
+        statements
+    }
+
+    /// SQL adding the new variants of [`extended_enums`](Self::extended_enums) without
+    /// touching the rows:
+    /// - PostgreSQL: `ALTER TYPE … ADD VALUE`, placed `BEFORE` the next existing
+    ///   variant when inserted in the middle,
+    /// - MySQL / MariaDB: `MODIFY COLUMN` with the new `ENUM(...)` list,
+    /// - SQLite: nothing, the enum is stored as plain text without constraint.
+    #[cfg(feature = "orm")]
+    pub fn to_enum_migration_sql(&self, engine: &crate::db::DatabaseEngine) -> Vec<String> {
+        use crate::db::DatabaseEngine;
+
+        let mut sql = Vec::new();
+        for (before, after) in &self.extended_enums {
+            match engine {
+                DatabaseEngine::PostgreSQL => sql.extend(add_enum_values(before, after)),
+                DatabaseEngine::MySQL | DatabaseEngine::MariaDB => sql.push(
+                    sea_query::Table::alter()
+                        .table(sea_query::Alias::new(&self.table_name))
+                        .modify_column(after.to_sea_column())
+                        .to_string(sea_query::MysqlQueryBuilder),
+                ),
+                DatabaseEngine::SQLite => {}
+            }
+        }
+        sql
+    }
+
+    /// Enum columns whose variants were removed or reordered: existing rows may
+    /// hold a value the new type rejects, so the alteration is unsafe.
+    pub fn unsafe_changes(&self) -> Vec<String> {
+        self.modified_columns
+            .iter()
+            .filter(|(before, after)| {
+                matches!(before.col_type, sea_query::ColumnType::Enum { .. })
+                    && matches!(after.col_type, sea_query::ColumnType::Enum { .. })
+                    && before.enum_variants != after.enum_variants
+                    && before.enum_variants_added(after).is_none()
+            })
+            .map(|(before, after)| {
+                format!(
+                    "{}.{}: enum variants removed or reordered ({} → {}), existing rows may no longer match",
+                    self.table_name,
+                    after.name,
+                    before.enum_variants.join(", "),
+                    after.enum_variants.join(", ")
+                )
+            })
+            .collect()
+    }
+
+    /// Dropped columns the down migration cannot faithfully restore: NOT NULL
+    /// without a default means the original data is lost and re-adding fails on
+    /// a table that still has rows.
+    pub fn down_warnings(&self) -> Vec<String> {
+        self.dropped_columns
+            .iter()
+            .filter(|c| {
+                !c.ignored
+                    && !c.nullable
+                    && c.default.is_none()
+                    && !c.auto_now
+                    && !c.auto_now_update
+            })
+            .map(|c| lossy_down_warning(&self.table_name, &c.name))
+            .collect()
+    }
+}
+
+/// PostgreSQL `ALTER TYPE … ADD VALUE` for each variant `after` adds to `before`.
+pub(crate) fn add_enum_values(before: &ColumnDef, after: &ColumnDef) -> Vec<String> {
+    let sea_query::ColumnType::Enum { ref name, .. } = after.col_type else {
+        return Vec::new();
+    };
+    let quote = |v: &str| format!("'{}'", v.replace('\'', "''"));
+    after
+        .enum_variants
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| !before.enum_variants.contains(v))
+        .map(|(i, v)| {
+            let next = after.enum_variants[i + 1..]
+                .iter()
+                .find(|n| before.enum_variants.contains(n));
+            let position = next
+                .map(|n| format!(" BEFORE {}", quote(n)))
+                .unwrap_or_default();
+            format!(
+                "ALTER TYPE \"{}\" ADD VALUE IF NOT EXISTS {}{}",
+                name,
+                quote(v),
+                position
+            )
+        })
+        .collect()
+}
+
+/// Warning for a dropped NOT NULL column without default: shared by [`SchemaDiff::down_warnings`]
+/// and the `makemigrations` generators so the CLI and the API report the same message.
+pub fn lossy_down_warning(table: &str, column: &str) -> String {
+    format!(
+        "{}.{}: NOT NULL column without default cannot be restored by the down migration (data lost, re-adding fails on non-empty tables)",
+        table, column
+    )
+}
+
+/// Target ids associated with `owner_id` in a junction table, as submitted strings.
+#[cfg(feature = "orm")]
+async fn junction_ids<C: sea_orm::ConnectionTrait>(
+    db: &C,
+    junction: &str,
+    owner_col: &str,
+    target_col: &str,
+    owner_id: &sea_orm::Value,
+) -> Result<Vec<String>, sea_orm::DbErr> {
+    use sea_query::{Alias, Expr, ExprTrait, Query};
+
+    let select = Query::select()
+        .expr_as(Expr::col(Alias::new(target_col)), Alias::new("target_id"))
+        .from(Alias::new(junction))
+        .and_where(Expr::col(Alias::new(owner_col)).eq(owner_id.clone()))
+        .to_owned();
+    db.query_all(&select)
+        .await?
+        .iter()
+        .map(|row| crate::migration::relation::cell_to_string(row, "target_id"))
+        .collect()
+}
+
+/// Submitted id bound with its type: integer, UUID, otherwise text.
+#[cfg(feature = "orm")]
+fn id_value(id: &str) -> sea_orm::Value {
+    if let Ok(n) = id.parse::<i64>() {
+        return n.into();
+    }
+    match uuid::Uuid::parse_str(id) {
+        Ok(uuid) => uuid.into(),
+        Err(_) => id.into(),
     }
 }
 
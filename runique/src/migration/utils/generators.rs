@@ -1,8 +1,11 @@
 //! SeaORM migration Rust code generation — `up`/`down` files, CREATE TABLE, FK, indexes, triggers.
+use crate::migration::column::ColumnDef as SchemaColumn;
+use crate::migration::schema::{SchemaDiff, add_enum_values};
 use crate::migration::utils::{
     helpers::col_type_to_method,
     types::{Changes, DbKind, ParsedColumn, ParsedSchema},
 };
+use sea_query::{ColumnType, StringLen};
 
 /// Generates the migration file for a CREATE TABLE — no FK constraints (they go in the relations file).
 /// Also used for snapshots (via `DbKind::Other`) to enable FK diffing on subsequent runs.
@@ -494,6 +497,7 @@ fn build_alter_bodies(change: &Changes, db_kind: &DbKind) -> (String, String) {
     }
 
     // 6) Recreate dropped columns in DOWN (now correct because we store ParsedColumn)
+    push_down_warnings(&mut down, change);
     for col in &change.dropped_columns {
         push_add_column(&mut down, &change.table_name, col);
     }
@@ -639,6 +643,7 @@ fn append_down_ops(change: &Changes, buf: &mut String) {
         push_drop_column(buf, &change.table_name, &col.name);
     }
     // recreate dropped columns
+    push_down_warnings(buf, change);
     for col in &change.dropped_columns {
         push_add_column(buf, &change.table_name, col);
     }
@@ -780,6 +785,13 @@ fn build_updated_at_trigger_drops(schema: &ParsedSchema, db_kind: &DbKind) -> St
     )
 }
 
+/// `// WARNING:` lines read back and printed by `migrate rollback` before it runs the block.
+fn push_down_warnings(buf: &mut String, change: &Changes) {
+    for warning in change.down_warnings() {
+        buf.push_str(&format!("        // WARNING: {}\n", warning));
+    }
+}
+
 fn push_drop_index(buf: &mut String, table: &str, idx_name: &str) {
     buf.push_str(&format!(
         "        manager\n            .drop_index(Index::drop().name(\"{idx}\").table(Alias::new(\"{table}\")).to_owned())\n            .await?;\n\n",
@@ -901,3 +913,231 @@ fn render_create_index_stmt(
         uniq = uniq_line
     )
 }
+
+// ── SchemaDiff (ModelSchema API) ─────────────────────────────────────────────
+
+/// Generates the migration file of a [`SchemaDiff`]: the same statements, in the
+/// same order, as [`SchemaDiff::to_up_migration`] / [`SchemaDiff::to_down_migration`],
+/// written as SeaORM builder code. The `down` reverses each change.
+pub fn generate_schema_diff_file(diff: &SchemaDiff) -> String {
+    let table = diff.table_name.as_str();
+    let mut up = String::new();
+    let mut down = String::new();
+
+    for warning in diff.unsafe_changes() {
+        up.push_str(&format!("        // WARNING: {}\n", warning));
+    }
+    for (old, new) in &diff.renamed_columns {
+        push_rename_column(&mut up, table, old, new);
+    }
+    for col in diff.added_columns.iter().filter(|c| !c.ignored) {
+        push_alter_column(&mut up, table, "add_column", &render_schema_column(col));
+    }
+    for (_, after) in &diff.modified_columns {
+        push_alter_column(
+            &mut up,
+            table,
+            "modify_column",
+            &render_schema_column(after),
+        );
+    }
+    for col in diff.dropped_columns.iter().filter(|c| !c.ignored) {
+        push_drop_column(&mut up, table, &col.name);
+    }
+    for (before, after) in &diff.extended_enums {
+        push_extend_enum(&mut up, table, &add_enum_values(before, after), after);
+    }
+
+    for warning in diff.down_warnings() {
+        down.push_str(&format!("        // WARNING: {}\n", warning));
+    }
+    for (before, _) in &diff.modified_columns {
+        push_alter_column(
+            &mut down,
+            table,
+            "modify_column",
+            &render_schema_column(before),
+        );
+    }
+    for (old, new) in &diff.renamed_columns {
+        push_rename_column(&mut down, table, new, old);
+    }
+    for col in diff.dropped_columns.iter().filter(|c| !c.ignored) {
+        push_alter_column(&mut down, table, "add_column", &render_schema_column(col));
+    }
+    for col in diff.added_columns.iter().filter(|c| !c.ignored) {
+        push_drop_column(&mut down, table, &col.name);
+    }
+    for (before, _) in &diff.extended_enums {
+        push_extend_enum(&mut down, table, &[], before);
+    }
+
+    let param = |body: &str| {
+        if body.trim().is_empty() {
+            "_manager"
+        } else {
+            "manager"
+        }
+    };
+
+    format!(
+        "use sea_orm_migration::prelude::*;\n\n#[derive(DeriveMigrationName)]\npub struct Migration;\n\n#[async_trait::async_trait]\nimpl MigrationTrait for Migration {{\n    async fn up(&self, {up_param}: &SchemaManager) -> Result<(), DbErr> {{\n{up}\n        Ok(())\n    }}\n\n    async fn down(&self, {down_param}: &SchemaManager) -> Result<(), DbErr> {{\n{down}\n        Ok(())\n    }}\n}}\n",
+        up_param = param(&up),
+        down_param = param(&down),
+        up = up.trim_end(),
+        down = down.trim_end()
+    )
+}
+
+/// Enum extension, resolved at run time on the connection backend: `pg_sql`
+/// (`ALTER TYPE … ADD VALUE`) on PostgreSQL, `MODIFY COLUMN` to `col` on MySQL,
+/// nothing on SQLite (plain text column). PostgreSQL cannot drop an enum value,
+/// so the down side passes no `pg_sql`.
+fn push_extend_enum(buf: &mut String, table: &str, pg_sql: &[String], col: &SchemaColumn) {
+    let mut modify = String::new();
+    push_alter_column(
+        &mut modify,
+        table,
+        "modify_column",
+        &render_schema_column(col),
+    );
+    buf.push_str("        match manager.get_database_backend() {\n");
+    if !pg_sql.is_empty() {
+        buf.push_str("            sea_orm::DbBackend::Postgres => {\n");
+        for sql in pg_sql {
+            buf.push_str(&format!(
+                "                manager\n                    .get_connection()\n                    .execute_unprepared({:?})\n                    .await?;\n",
+                sql
+            ));
+        }
+        buf.push_str("            }\n");
+    }
+    buf.push_str("            sea_orm::DbBackend::MySql => {\n");
+    for line in modify.trim_end().lines() {
+        buf.push_str(&format!("        {}\n", line));
+    }
+    buf.push_str("            }\n            _ => {}\n        }\n\n");
+}
+
+fn push_alter_column(buf: &mut String, table: &str, op: &str, coldef: &str) {
+    buf.push_str(&format!(
+        "        manager\n            .alter_table(\n                Table::alter()\n                    .table(Alias::new(\"{table}\"))\n                    .{op}({coldef})\n                    .to_owned(),\n            )\n            .await?;\n\n",
+        table = table,
+        op = op,
+        coldef = coldef,
+    ));
+}
+
+/// Builder code of [`ColumnDef::to_sea_column`](crate::migration::ColumnDef::to_sea_column):
+/// exact type, nullability, unique, `CHECK`, comment and default.
+fn render_schema_column(col: &SchemaColumn) -> String {
+    let mut s = format!(
+        "ColumnDef::new_with_type(Alias::new({name:?}), {ty})",
+        name = col.name,
+        ty = render_column_type(&col.col_type)
+    );
+    s.push_str(if col.nullable {
+        ".null()"
+    } else {
+        ".not_null()"
+    });
+    if col.unique {
+        s.push_str(".unique_key()");
+    }
+    // Same rule as `ColumnDef::check_expr`: explicit SQL, else the integer bounds
+    let bound =
+        |op: &str, v: i64| format!("Expr::col(Alias::new({:?})).{}({}i64)", col.name, op, v);
+    let check = match (&col.check, col.min_value, col.max_value) {
+        (Some(raw), _, _) => Some(format!("Expr::cust({:?})", raw)),
+        (None, Some(min), Some(max)) => {
+            Some(format!("{}.and({})", bound("gte", min), bound("lte", max)))
+        }
+        (None, Some(min), None) => Some(bound("gte", min)),
+        (None, None, Some(max)) => Some(bound("lte", max)),
+        (None, None, None) => None,
+    };
+    if let Some(check) = check {
+        s.push_str(&format!(".check({})", check));
+    }
+    if let Some(comment) = &col.comment {
+        s.push_str(&format!(".comment({:?})", comment));
+    }
+    if let Some(value) = &col.default {
+        s.push_str(&format!(".default({})", render_default(value)));
+    } else if col.auto_now {
+        s.push_str(".extra(\"DEFAULT CURRENT_TIMESTAMP\")");
+    } else if col.auto_now_update {
+        s.push_str(".extra(\"DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP\")");
+    }
+    s
+}
+
+fn render_column_type(ty: &ColumnType) -> String {
+    let string_len = |len: &StringLen| match len {
+        StringLen::N(n) => format!("StringLen::N({})", n),
+        StringLen::Max => "StringLen::Max".to_string(),
+        StringLen::None => "StringLen::None".to_string(),
+    };
+    let iden = |i: &sea_query::DynIden| format!("Alias::new({:?}).into_iden()", i.to_string());
+    match ty {
+        ColumnType::String(len) => format!("ColumnType::String({})", string_len(len)),
+        ColumnType::VarBinary(len) => format!("ColumnType::VarBinary({})", string_len(len)),
+        ColumnType::Interval(fields, precision) => format!(
+            "ColumnType::Interval({}, {:?})",
+            fields
+                .as_ref()
+                .map_or("None".to_string(), |f| format!("Some(PgInterval::{:?})", f)),
+            precision
+        ),
+        ColumnType::Custom(name) => format!("ColumnType::Custom({})", iden(name)),
+        ColumnType::Enum { name, variants } => format!(
+            "ColumnType::Enum {{ name: {}, variants: vec![{}] }}",
+            iden(name),
+            variants.iter().map(iden).collect::<Vec<_>>().join(", ")
+        ),
+        ColumnType::Array(inner) => {
+            format!("ColumnType::Array({}.into())", render_column_type(inner))
+        }
+        // Unit variants and `Option<u32>` / `u32` / `Option<(u32, u32)>` payloads:
+        // their `Debug` form is valid Rust.
+        other => format!("ColumnType::{:?}", other),
+    }
+}
+
+fn render_default(value: &sea_query::Value) -> String {
+    use sea_query::Value;
+    match value {
+        Value::Bool(Some(v)) => v.to_string(),
+        Value::TinyInt(Some(v)) => format!("{}i8", v),
+        Value::SmallInt(Some(v)) => format!("{}i16", v),
+        Value::Int(Some(v)) => format!("{}i32", v),
+        Value::BigInt(Some(v)) => format!("{}i64", v),
+        Value::TinyUnsigned(Some(v)) => format!("{}u8", v),
+        Value::SmallUnsigned(Some(v)) => format!("{}u16", v),
+        Value::Unsigned(Some(v)) => format!("{}u32", v),
+        Value::BigUnsigned(Some(v)) => format!("{}u64", v),
+        Value::Float(Some(v)) => format!("{:?}f32", v),
+        Value::Double(Some(v)) => format!("{:?}f64", v),
+        Value::String(Some(v)) => format!("{:?}", v),
+        Value::Char(Some(v)) => format!("{:?}", v.to_string()),
+        Value::Bytes(Some(v)) => format!("Vec::<u8>::from({:?})", v),
+        other if !other.is_some() => r#"Expr::cust("NULL")"#.to_string(),
+        // Dates, UUID, JSON, decimals…: the file runs on every backend, so no
+        // backend-specific literal is baked in. A quoted literal becomes a Rust
+        // string, quoted by the executing backend; a bare one (decimal) is portable.
+        other => {
+            use sea_query::EscapeBuilder;
+            let literal = other.to_string();
+            match literal
+                .strip_prefix('\'')
+                .and_then(|rest| rest.strip_suffix('\''))
+            {
+                // `Value`'s Display escapes like MySQL (backslashes), hence the unescape
+                Some(quoted) => {
+                    format!("{:?}", sea_query::MysqlQueryBuilder.unescape_string(quoted))
+                }
+                None => format!("Expr::cust({:?})", literal),
+            }
+        }
+    }
+}
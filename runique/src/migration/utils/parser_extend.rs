@@ -138,6 +138,14 @@ impl Parse for DslExtend {
         let table: LitStr = input.parse()?;
         input.parse::<Token![,]>()?;
 
+        // form_hooks: true optional — form-only, nothing to migrate.
+        if input.peek(Ident) && input.fork().parse::<Ident>()? == "form_hooks" {
+            input.parse::<Ident>()?;
+            input.parse::<Token![:]>()?;
+            input.parse::<syn::LitBool>()?;
+            input.parse::<Token![,]>()?;
+        }
+
         // enums: { ... } optional — same shape and resolution as parser_builder.
         let mut enum_types: Vec<(String, String, Vec<String>)> = Vec::new();
         if input.peek(Ident) {
@@ -98,4 +98,21 @@ impl Changes {
             && self.enum_value_adds.is_empty()
             && self.enum_value_drops.is_empty()
     }
+
+    /// Dropped columns the down migration cannot restore (NOT NULL, no default):
+    /// same rule and message as [`SchemaDiff::down_warnings`](crate::migration::schema::SchemaDiff::down_warnings).
+    pub fn down_warnings(&self) -> Vec<String> {
+        self.dropped_columns
+            .iter()
+            .filter(|c| {
+                !c.ignored
+                    && !c.nullable
+                    && c.default_value.is_none()
+                    && !c.has_default_now
+                    && !c.created_at
+                    && !c.updated_at
+            })
+            .map(|c| crate::migration::schema::lossy_down_warning(&self.table_name, &c.name))
+            .collect()
+    }
 }
@@ -0,0 +1,255 @@
+//! Model lifecycle signals — handlers connected at runtime with [`connect`], fired by
+//! the `ActiveModelBehavior` that [`impl_signals!`](crate::impl_signals) generates.
+//!
+//! ```rust,ignore
+//! async fn welcome(db: ADb, event: SignalEvent) -> Result<(), TaskError> {
+//!     if event.created {
+//!         let email: String = event.get("email").unwrap_or_default();
+//!         send_welcome(&db, &email).await?;
+//!     }
+//!     Ok(())
+//! }
+//!
+//! signals::connect(Signal::PostSave, "users", welcome);
+//! ```
+use crate::tasks::TaskError;
+use crate::utils::aliases::ADb;
+use futures_util::{
+    FutureExt,
+    future::{BoxFuture, ready},
+};
+use sea_orm::sea_query::sea_value_to_json_value;
+use sea_orm::{ActiveModelTrait, DbErr, EntityName, EntityTrait, IdenStatic, Iterable, ModelTrait};
+use serde::de::DeserializeOwned;
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    panic::AssertUnwindSafe,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+/// Moment of the model lifecycle a handler is connected to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Signal {
+    /// Before `insert` / `update` — `data` holds the values being written.
+    PreSave,
+    /// After `insert` / `update` — `data` holds the row as stored.
+    PostSave,
+    /// Before `delete` — `data` holds the values known to the `ActiveModel`.
+    PreDelete,
+    /// After `delete`, same data as `PreDelete`.
+    PostDelete,
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::PreSave => "pre_save",
+            Self::PostSave => "post_save",
+            Self::PreDelete => "pre_delete",
+            Self::PostDelete => "post_delete",
+        })
+    }
+}
+
+/// What a handler receives besides the connection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalEvent {
+    pub signal: Signal,
+    /// Table name of the model (`eihwaz_users` for the builtin users).
+    pub model: &'static str,
+    /// `true` for an `insert`, `false` for an `update` — always `false` on delete.
+    pub created: bool,
+    /// Column name → value. Columns left `NotSet` on the `ActiveModel` are absent.
+    pub data: serde_json::Map<String, serde_json::Value>,
+}
+
+impl SignalEvent {
+    /// Value of `column`, `None` when absent or not convertible to `T`.
+    pub fn get<T: DeserializeOwned>(&self, column: &str) -> Option<T> {
+        self.data
+            .get(column)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+}
+
+type HandlerFn =
+    Arc<dyn Fn(ADb, SignalEvent) -> BoxFuture<'static, Result<(), TaskError>> + Send + Sync>;
+
+#[derive(Clone)]
+struct Receiver {
+    name: &'static str,
+    critical: bool,
+    handler: HandlerFn,
+}
+
+#[derive(Default)]
+struct Registry {
+    receivers: HashMap<(Signal, String), Vec<Receiver>>,
+    db: Option<ADb>,
+}
+
+static REGISTRY: LazyLock<RwLock<Registry>> = LazyLock::new(Default::default);
+
+fn add<F, Fut>(signal: Signal, model: &str, handler: F, critical: bool)
+where
+    F: Fn(ADb, SignalEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), TaskError>> + Send + 'static,
+{
+    let receiver = Receiver {
+        name: std::any::type_name::<F>(),
+        critical,
+        handler: Arc::new(move |db, event| handler(db, event).boxed()),
+    };
+    REGISTRY
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .receivers
+        .entry((signal, model.to_string()))
+        .or_default()
+        .push(receiver);
+}
+
+/// Connects `handler` to `signal` on the table `model`. Handlers run one after the
+/// other, in registration order; an `Err` or a panic is logged and the operation
+/// goes on.
+pub fn connect<F, Fut>(signal: Signal, model: &str, handler: F)
+where
+    F: Fn(ADb, SignalEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), TaskError>> + Send + 'static,
+{
+    add(signal, model, handler, false);
+}
+
+/// Like [`connect`], but a failure stops the remaining handlers and fails the
+/// operation with `DbErr::Custom` — a `pre_*` handler prevents the write, and the
+/// caller's transaction, if any, is rolled back.
+pub fn connect_critical<F, Fut>(signal: Signal, model: &str, handler: F)
+where
+    F: Fn(ADb, SignalEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), TaskError>> + Send + 'static,
+{
+    add(signal, model, handler, true);
+}
+
+/// Removes every handler of `signal` on `model`, returns how many.
+pub fn disconnect(signal: Signal, model: &str) -> usize {
+    REGISTRY
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .receivers
+        .remove(&(signal, model.to_string()))
+        .map_or(0, |r| r.len())
+}
+
+/// Connection given to the handlers — installed by `RuniqueAppBuilder::build`.
+pub fn set_db(db: ADb) {
+    REGISTRY.write().unwrap_or_else(|e| e.into_inner()).db = Some(db);
+}
+
+fn receivers(signal: Signal, model: &str) -> Option<(Vec<Receiver>, Option<ADb>)> {
+    let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+    registry
+        .receivers
+        .get(&(signal, model.to_string()))
+        .filter(|r| !r.is_empty())
+        .map(|r| (r.clone(), registry.db.clone()))
+}
+
+async fn dispatch(
+    receivers: Vec<Receiver>,
+    db: Option<ADb>,
+    event: SignalEvent,
+) -> Result<(), DbErr> {
+    let (signal, model) = (event.signal, event.model);
+    for receiver in receivers {
+        let error = match &db {
+            None => "no database connection installed".to_string(),
+            Some(db) => {
+                match AssertUnwindSafe((receiver.handler)(db.clone(), event.clone()))
+                    .catch_unwind()
+                    .await
+                {
+                    Ok(Ok(())) => continue,
+                    Ok(Err(e)) => e.0,
+                    Err(_) => "panicked".to_string(),
+                }
+            }
+        };
+        tracing::error!(%signal, model, handler = receiver.name, %error, "signal handler failed");
+        if receiver.critical {
+            return Err(DbErr::Custom(format!(
+                "{signal} handler `{}` on `{model}`: {error}",
+                receiver.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn table_name<E: EntityTrait>() -> &'static str {
+    EntityName::table_name(&E::default())
+}
+
+/// Emits `signal` with the values set on `active` — `pre_save` and the delete signals.
+#[doc(hidden)]
+pub fn emit_active<A: ActiveModelTrait>(
+    signal: Signal,
+    active: &A,
+    created: bool,
+) -> BoxFuture<'static, Result<(), DbErr>> {
+    let model = table_name::<A::Entity>();
+    let Some((receivers, db)) = receivers(signal, model) else {
+        return ready(Ok(())).boxed();
+    };
+    let data = <A::Entity as EntityTrait>::Column::iter()
+        .filter_map(|col| {
+            let value = active.get(col).into_value()?;
+            Some((col.as_str().to_string(), sea_value_to_json_value(&value)))
+        })
+        .collect();
+    dispatch(
+        receivers,
+        db,
+        SignalEvent {
+            signal,
+            model,
+            created,
+            data,
+        },
+    )
+    .boxed()
+}
+
+/// Emits `signal` with the stored row — `post_save`.
+#[doc(hidden)]
+pub fn emit_model<M: ModelTrait>(
+    signal: Signal,
+    row: &M,
+    created: bool,
+) -> BoxFuture<'static, Result<(), DbErr>> {
+    let model = table_name::<M::Entity>();
+    let Some((receivers, db)) = receivers(signal, model) else {
+        return ready(Ok(())).boxed();
+    };
+    let data = <M::Entity as EntityTrait>::Column::iter()
+        .map(|col| {
+            (
+                col.as_str().to_string(),
+                sea_value_to_json_value(&row.get(col)),
+            )
+        })
+        .collect();
+    dispatch(
+        receivers,
+        db,
+        SignalEvent {
+            signal,
+            model,
+            created,
+            data,
+        },
+    )
+    .boxed()
+}
@@ -0,0 +1,66 @@
+//! Task storage — the `TaskBackend` trait and the in-memory default.
+use crate::tasks::task::{EnqueueError, Task};
+use async_trait::async_trait;
+use tokio::sync::{Mutex, mpsc};
+
+/// A queued [`Task`] and the number of attempts already made.
+pub struct Job {
+    pub task: Box<dyn Task>,
+    pub attempt: u32,
+}
+
+impl Job {
+    pub fn new(task: impl Task) -> Self {
+        Self {
+            task: Box::new(task),
+            attempt: 0,
+        }
+    }
+}
+
+/// Where jobs wait for a worker. [`MemoryBackend`] by default; a persistent backend
+/// (database table, Redis…) implements the same two methods.
+#[async_trait]
+pub trait TaskBackend: Send + Sync + 'static {
+    /// Stores a job — new, or back for a retry.
+    async fn push(&self, job: Job) -> Result<(), EnqueueError>;
+
+    /// Next job, waiting for one. `None` stops the worker.
+    async fn pop(&self) -> Option<Job>;
+}
+
+/// In-process queue (`tokio::mpsc`) — jobs are lost on restart.
+pub struct MemoryBackend {
+    tx: mpsc::UnboundedSender<Job>,
+    rx: Mutex<mpsc::UnboundedReceiver<Job>>,
+}
+
+impl MemoryBackend {
+    pub fn new() -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        Self {
+            tx,
+            rx: Mutex::new(rx),
+        }
+    }
+}
+
+impl Default for MemoryBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TaskBackend for MemoryBackend {
+    async fn push(&self, job: Job) -> Result<(), EnqueueError> {
+        self.tx
+            .send(job)
+            .map_err(|_| EnqueueError::Backend("queue closed".to_string()))
+    }
+
+    async fn pop(&self) -> Option<Job> {
+        // Workers take turns on the receiver: one waits, the others wait for the lock
+        self.rx.lock().await.recv().await
+    }
+}
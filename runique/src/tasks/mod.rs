@@ -0,0 +1,11 @@
+//! Background tasks — `Task` trait, in-process queue, workers with retry/backoff, drain,
+//! and periodic jobs (`every` / `cron`).
+pub mod backend;
+pub mod queue;
+pub mod schedule;
+pub mod task;
+
+pub use backend::{Job, MemoryBackend, TaskBackend};
+pub use queue::{TaskConfig, TaskQueue};
+pub use schedule::{ScheduleEntry, Scheduler, SchedulerHandle};
+pub use task::{EnqueueError, Task, TaskError};
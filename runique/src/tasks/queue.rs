@@ -0,0 +1,184 @@
+//! `TaskQueue` — enqueue handle shared through the engine, worker loop, retry
+//! with exponential backoff and drain for graceful shutdown.
+use crate::tasks::backend::{Job, MemoryBackend, TaskBackend};
+use crate::tasks::task::{EnqueueError, Task};
+use crate::utils::aliases::ADb;
+use futures_util::FutureExt;
+use std::{fmt, panic::AssertUnwindSafe, sync::Arc, time::Duration};
+use tokio::sync::watch;
+
+/// Worker and retry policy.
+///
+/// ```rust,ignore
+/// RuniqueApp::builder(config)
+///     .with_worker(2)
+///     .with_tasks(|t| t.max_retries(5).backoff(Duration::from_secs(2)))
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskConfig {
+    /// Concurrent workers (tokio tasks) — `0`: no worker, `enqueue` is refused.
+    pub workers: usize,
+    /// Retries after the first failure — `0`: a failed task is dropped.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled on each following one.
+    pub backoff: Duration,
+    /// Ceiling of the retry delay.
+    pub max_backoff: Duration,
+}
+
+impl Default for TaskConfig {
+    fn default() -> Self {
+        Self {
+            workers: 0,
+            max_retries: 3,
+            backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(300),
+        }
+    }
+}
+
+impl TaskConfig {
+    pub fn workers(mut self, workers: usize) -> Self {
+        self.workers = workers;
+        self
+    }
+
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    pub fn backoff(mut self, delay: Duration) -> Self {
+        self.backoff = delay;
+        self
+    }
+
+    pub fn max_backoff(mut self, delay: Duration) -> Self {
+        self.max_backoff = delay;
+        self
+    }
+
+    /// Delay before retry number `attempt + 1`: `backoff × 2^attempt`, capped.
+    pub fn retry_delay(&self, attempt: u32) -> Duration {
+        self.backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+/// Handle on the task queue — `engine.tasks`. Cheap to clone.
+///
+/// ```rust,ignore
+/// async fn signup(req: Request) -> AppResult<Response> {
+///     // …user saved
+///     req.engine.tasks.enqueue(WelcomeEmail { to: user.email }).await?;
+///     Ok(Redirect::to("/").into_response())
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct TaskQueue(Option<Arc<Inner>>);
+
+struct Inner {
+    backend: Arc<dyn TaskBackend>,
+    config: TaskConfig,
+    /// Jobs enqueued and not finished — a job waiting for a retry counts.
+    pending: watch::Sender<usize>,
+}
+
+impl fmt::Debug for TaskQueue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskQueue")
+            .field("config", &self.0.as_ref().map(|inner| inner.config))
+            .field("pending", &self.pending())
+            .finish()
+    }
+}
+
+impl TaskQueue {
+    /// Starts `config.workers` workers over `backend` — [`MemoryBackend`] when `None`.
+    /// Must be called inside a tokio runtime. With `0` workers the queue stays disabled.
+    pub fn start(db: ADb, config: TaskConfig, backend: Option<Arc<dyn TaskBackend>>) -> Self {
+        if config.workers == 0 {
+            return Self::default();
+        }
+        let inner = Arc::new(Inner {
+            backend: backend.unwrap_or_else(|| Arc::new(MemoryBackend::new())),
+            config,
+            pending: watch::Sender::new(0),
+        });
+        for _ in 0..config.workers {
+            tokio::spawn(worker(inner.clone(), db.clone()));
+        }
+        Self(Some(inner))
+    }
+
+    /// `true` if workers are running.
+    pub fn is_enabled(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Queues `task` for the workers — returns once stored, not once run.
+    pub async fn enqueue(&self, task: impl Task) -> Result<(), EnqueueError> {
+        let inner = self.0.as_ref().ok_or(EnqueueError::NoWorker)?;
+        inner.pending.send_modify(|n| *n += 1);
+        inner.backend.push(Job::new(task)).await.inspect_err(|_| {
+            inner.pending.send_modify(|n| *n -= 1);
+        })
+    }
+
+    /// Jobs enqueued and not finished yet (running or waiting for a retry included).
+    pub fn pending(&self) -> usize {
+        self.0.as_ref().map_or(0, |inner| *inner.pending.borrow())
+    }
+
+    /// Waits until every enqueued job is finished — succeeded or out of retries.
+    pub async fn drain(&self) {
+        if let Some(inner) = &self.0 {
+            let mut rx = inner.pending.subscribe();
+            // The sender lives in `inner`: `wait_for` cannot fail while it is borrowed
+            let _ = rx.wait_for(|pending| *pending == 0).await;
+        }
+    }
+
+    /// [`drain`](Self::drain) bounded by `timeout` — `false` if jobs are still pending.
+    pub async fn drain_timeout(&self, timeout: Duration) -> bool {
+        tokio::time::timeout(timeout, self.drain()).await.is_ok()
+    }
+}
+
+async fn worker(inner: Arc<Inner>, db: ADb) {
+    while let Some(job) = inner.backend.pop().await {
+        run_job(&inner, &db, job).await;
+    }
+}
+
+async fn run_job(inner: &Arc<Inner>, db: &ADb, mut job: Job) {
+    let name = job.task.name();
+    let error = match AssertUnwindSafe(job.task.run(db)).catch_unwind().await {
+        Ok(Ok(())) => {
+            inner.pending.send_modify(|n| *n -= 1);
+            return;
+        }
+        Ok(Err(e)) => e.to_string(),
+        Err(_) => "panicked".to_string(),
+    };
+
+    if job.attempt >= inner.config.max_retries {
+        tracing::error!(task = name, attempts = job.attempt + 1, error = %error, "task failed — giving up");
+        inner.pending.send_modify(|n| *n -= 1);
+        return;
+    }
+
+    let delay = inner.config.retry_delay(job.attempt);
+    tracing::warn!(task = name, attempt = job.attempt + 1, retry_in = ?delay, error = %error, "task failed — retrying");
+    job.attempt += 1;
+    // Off the worker: the delay does not hold up the other jobs
+    let inner = inner.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(delay).await;
+        if let Err(e) = inner.backend.push(job).await {
+            tracing::error!(task = name, error = %e, "task retry lost");
+            inner.pending.send_modify(|n| *n -= 1);
+        }
+    });
+}
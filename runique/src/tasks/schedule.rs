@@ -0,0 +1,269 @@
+//! Periodic jobs — `every(duration)` / `cron("0 3 * * *")` registered with
+//! `with_schedule`, fired by the scheduler tokio task until shutdown.
+use crate::tasks::task::TaskError;
+use crate::utils::aliases::ADb;
+use chrono::{DateTime, Utc};
+use croner::Cron;
+use futures_util::{FutureExt, future::BoxFuture};
+use std::{
+    fmt,
+    future::Future,
+    panic::AssertUnwindSafe,
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+use tokio::{sync::watch, time::Instant};
+
+type JobFn = Arc<dyn Fn(ADb) -> BoxFuture<'static, Result<(), TaskError>> + Send + Sync>;
+
+enum Trigger {
+    Every(Duration),
+    /// Evaluated in UTC.
+    Cron(Box<Cron>),
+}
+
+struct ScheduledJob {
+    name: String,
+    trigger: Trigger,
+    allow_overlap: bool,
+    job: JobFn,
+    /// Runs in progress — a new run is skipped while > 0 unless `allow_overlap`.
+    running: AtomicUsize,
+}
+
+/// Periodic jobs, declared in `with_schedule`.
+///
+/// ```rust,ignore
+/// async fn cleanup(db: ADb) -> Result<(), TaskError> {
+///     sessions::Entity::delete_many()
+///         .filter(sessions::Column::ExpiresAt.lt(Utc::now()))
+///         .exec(db.as_ref())
+///         .await?;
+///     Ok(())
+/// }
+///
+/// RuniqueApp::builder(config).with_schedule(|s| {
+///     s.every(Duration::from_secs(3600)).run(cleanup);
+///     s.cron("0 3 * * *").run(nightly);
+/// })
+/// ```
+#[derive(Default)]
+pub struct Scheduler {
+    jobs: Vec<ScheduledJob>,
+    errors: Vec<String>,
+}
+
+impl fmt::Debug for Scheduler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scheduler")
+            .field(
+                "jobs",
+                &self.jobs.iter().map(|j| &j.name).collect::<Vec<_>>(),
+            )
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
+impl Scheduler {
+    /// Job run every `interval` — the first run comes one interval after startup.
+    pub fn every(&mut self, interval: Duration) -> ScheduleEntry<'_> {
+        let trigger = if interval.is_zero() {
+            Err("every(): the interval must be greater than zero".to_string())
+        } else {
+            Ok(Trigger::Every(interval))
+        };
+        ScheduleEntry::new(self, trigger)
+    }
+
+    /// Job run on a 5-field cron expression (`minute hour day month weekday`), in UTC.
+    /// An invalid expression fails `build()`.
+    pub fn cron(&mut self, expression: &str) -> ScheduleEntry<'_> {
+        let trigger = Cron::from_str(expression)
+            .map(|cron| Trigger::Cron(Box::new(cron)))
+            .map_err(|e| format!("cron(\"{expression}\"): {e}"));
+        ScheduleEntry::new(self, trigger)
+    }
+
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// Invalid declarations (bad cron expression, zero interval).
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    /// Spawns the scheduler task. Must be called inside a tokio runtime;
+    /// without jobs nothing is spawned.
+    pub fn start(self, db: ADb) -> SchedulerHandle {
+        if self.jobs.is_empty() {
+            return SchedulerHandle::default();
+        }
+        let inner = Arc::new(HandleInner {
+            stop: watch::Sender::new(false),
+            in_flight: watch::Sender::new(0),
+        });
+        let jobs = self.jobs.into_iter().map(Arc::new).collect();
+        tokio::spawn(scheduler_loop(jobs, db, inner.clone()));
+        SchedulerHandle(Some(inner))
+    }
+}
+
+/// A job being declared — [`run`](Self::run) registers it.
+pub struct ScheduleEntry<'a> {
+    scheduler: &'a mut Scheduler,
+    trigger: Result<Trigger, String>,
+    allow_overlap: bool,
+    name: Option<String>,
+}
+
+impl<'a> ScheduleEntry<'a> {
+    fn new(scheduler: &'a mut Scheduler, trigger: Result<Trigger, String>) -> Self {
+        Self {
+            scheduler,
+            trigger,
+            allow_overlap: false,
+            name: None,
+        }
+    }
+
+    /// Starts a run even if the previous one is still going — by default it is skipped.
+    pub fn allow_overlap(mut self) -> Self {
+        self.allow_overlap = true;
+        self
+    }
+
+    /// Name in the logs — the function path by default.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Registers `job`, called with the database connection at each occurrence.
+    /// An `Err` or a panic is logged; the next occurrence runs as planned.
+    pub fn run<F, Fut>(self, job: F)
+    where
+        F: Fn(ADb) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), TaskError>> + Send + 'static,
+    {
+        match self.trigger {
+            Ok(trigger) => self.scheduler.jobs.push(ScheduledJob {
+                name: self
+                    .name
+                    .unwrap_or_else(|| std::any::type_name::<F>().to_string()),
+                trigger,
+                allow_overlap: self.allow_overlap,
+                job: Arc::new(move |db| job(db).boxed()),
+                running: AtomicUsize::new(0),
+            }),
+            Err(e) => self.scheduler.errors.push(e),
+        }
+    }
+}
+
+/// Handle on the running scheduler — `engine.scheduler`.
+#[derive(Clone, Default)]
+pub struct SchedulerHandle(Option<Arc<HandleInner>>);
+
+struct HandleInner {
+    stop: watch::Sender<bool>,
+    /// Job runs in progress, all jobs together.
+    in_flight: watch::Sender<usize>,
+}
+
+impl fmt::Debug for SchedulerHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchedulerHandle")
+            .field("running", &self.is_running())
+            .finish()
+    }
+}
+
+impl SchedulerHandle {
+    /// `true` until [`shutdown`](Self::shutdown) — `false` without jobs.
+    pub fn is_running(&self) -> bool {
+        self.0.as_ref().is_some_and(|inner| !*inner.stop.borrow())
+    }
+
+    /// Stops firing jobs, then waits up to `timeout` for the runs in progress.
+    /// `false` if some are still running (they are not cancelled).
+    pub async fn shutdown(&self, timeout: Duration) -> bool {
+        let Some(inner) = &self.0 else { return true };
+        inner.stop.send_replace(true);
+        let mut rx = inner.in_flight.subscribe();
+        tokio::time::timeout(timeout, rx.wait_for(|n| *n == 0))
+            .await
+            .is_ok()
+    }
+}
+
+/// Next occurrence of `job` after `base`.
+fn next_after(job: &ScheduledJob, base: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    match &job.trigger {
+        Trigger::Every(interval) => Some(base + chrono::Duration::from_std(*interval).ok()?),
+        Trigger::Cron(cron) => cron.find_next_occurrence(&base, false).ok(),
+    }
+}
+
+async fn scheduler_loop(jobs: Vec<Arc<ScheduledJob>>, db: ADb, inner: Arc<HandleInner>) {
+    let mut stop = inner.stop.subscribe();
+    let start = Utc::now();
+    let mut next: Vec<Option<DateTime<Utc>>> =
+        jobs.iter().map(|job| next_after(job, start)).collect();
+
+    loop {
+        let Some(at) = next.iter().flatten().min().copied() else {
+            return;
+        };
+        let wait = (at - Utc::now()).to_std().unwrap_or_default();
+        tokio::select! {
+            biased;
+            _ = stop.wait_for(|stopped| *stopped) => return,
+            _ = tokio::time::sleep_until(Instant::now() + wait) => {}
+        }
+
+        // From the planned time (no double run on an early wake-up), or from now
+        // after a long pause (missed occurrences are skipped, not replayed)
+        let base = at.max(Utc::now());
+        for (job, slot) in jobs.iter().zip(next.iter_mut()) {
+            if slot.is_some_and(|planned| planned <= at) {
+                fire(job, &db, &inner);
+                *slot = next_after(job, base);
+            }
+        }
+    }
+}
+
+fn fire(job: &Arc<ScheduledJob>, db: &ADb, inner: &Arc<HandleInner>) {
+    if !job.allow_overlap && job.running.load(Ordering::SeqCst) > 0 {
+        tracing::debug!(job = %job.name, "scheduled job skipped — previous run still in progress");
+        return;
+    }
+    // Counted before checking `stop`: `shutdown` either sees this run or stops it
+    inner.in_flight.send_modify(|n| *n += 1);
+    if *inner.stop.borrow() {
+        inner.in_flight.send_modify(|n| *n -= 1);
+        return;
+    }
+    job.running.fetch_add(1, Ordering::SeqCst);
+
+    let (job, db, inner) = (job.clone(), db.clone(), inner.clone());
+    tokio::spawn(async move {
+        match AssertUnwindSafe((job.job)(db)).catch_unwind().await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => tracing::error!(job = %job.name, error = %e, "scheduled job failed"),
+            Err(_) => tracing::error!(job = %job.name, "scheduled job panicked"),
+        }
+        job.running.fetch_sub(1, Ordering::SeqCst);
+        inner.in_flight.send_modify(|n| *n -= 1);
+    });
+}
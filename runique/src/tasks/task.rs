@@ -0,0 +1,75 @@
+//! `Task` trait — a unit of background work — and its error types.
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use std::fmt;
+
+/// Background work run by the task worker (`.with_worker(n)`).
+///
+/// An `Err` (or a panic) is retried with exponential backoff, up to
+/// [`TaskConfig::max_retries`](crate::tasks::TaskConfig::max_retries) — `run` must be
+/// safe to call again after a partial failure.
+///
+/// ```rust,ignore
+/// struct WelcomeEmail { to: String }
+///
+/// #[async_trait]
+/// impl Task for WelcomeEmail {
+///     async fn run(&self, _db: &DatabaseConnection) -> Result<(), TaskError> {
+///         dispatch_email(&self.to, "Welcome", "Thanks for signing up!")
+///             .await
+///             .map_err(TaskError::new)?;
+///         Ok(())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Task: Send + Sync + 'static {
+    async fn run(&self, db: &DatabaseConnection) -> Result<(), TaskError>;
+
+    /// Name in the worker logs — the type name by default.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+}
+
+/// Failure of a [`Task`] — any `std::error::Error` converts with `?`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskError(pub String);
+
+impl TaskError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+}
+
+impl fmt::Display for TaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<E: std::error::Error> From<E> for TaskError {
+    fn from(error: E) -> Self {
+        Self(error.to_string())
+    }
+}
+
+/// Refusal of [`TaskQueue::enqueue`](crate::tasks::TaskQueue::enqueue).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnqueueError {
+    /// No worker started — see `RuniqueAppBuilder::with_worker`.
+    NoWorker,
+    /// The backend refused the job (closed, storage error…).
+    Backend(String),
+}
+
+impl fmt::Display for EnqueueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoWorker => f.write_str("no task worker — enable it with `.with_worker(n)`"),
+            Self::Backend(e) => write!(f, "task backend: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for EnqueueError {}
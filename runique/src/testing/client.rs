@@ -0,0 +1,277 @@
+//! `TestClient` — in-process HTTP client for end-to-end handler tests.
+use crate::app::RuniqueApp;
+use crate::utils::aliases::AEngine;
+use crate::utils::constante::session::CSRF_TOKEN_KEY;
+use axum::{
+    Router,
+    body::{Body, Bytes},
+    extract::ConnectInfo,
+    http::{HeaderMap, HeaderValue, Method, Request, StatusCode, header},
+};
+use std::{collections::BTreeMap, net::SocketAddr, sync::Mutex};
+use tower::ServiceExt;
+
+/// Response header carrying the (masked) CSRF token, set on every response.
+const CSRF_HEADER: &str = "X-CSRF-Token";
+
+/// Sends requests to a `Router` without a server. Cookies set by responses
+/// (session, remember-me…) are sent back on the next calls, and the latest
+/// CSRF token is added to `post` forms and `post_json` headers.
+///
+/// ```rust,ignore
+/// let app = RuniqueApp::builder(config).routes(url::routes()).with_database(test_db().await).build().await?;
+/// let client = TestClient::from_app(app);
+/// client.get("/login").await;
+/// let resp = client.post("/login", &[("username", "admin"), ("password", "…")]).await;
+/// assert_eq!(resp.location(), Some("/"));
+/// assert_eq!(client.get("/profile").await.status(), StatusCode::OK);
+/// ```
+pub struct TestClient {
+    router: Router,
+    engine: Option<AEngine>,
+    cookies: Mutex<BTreeMap<String, String>>,
+    csrf_token: Mutex<Option<String>>,
+}
+
+impl TestClient {
+    pub fn new(router: Router) -> Self {
+        Self {
+            router,
+            engine: None,
+            cookies: Mutex::new(BTreeMap::new()),
+            csrf_token: Mutex::new(None),
+        }
+    }
+
+    /// Client over a built app — its engine (and database) stay reachable via
+    /// [`TestClient::engine`].
+    pub fn from_app(app: RuniqueApp) -> Self {
+        let mut client = Self::new(app.router);
+        client.engine = Some(app.engine);
+        client
+    }
+
+    pub fn engine(&self) -> Option<&AEngine> {
+        self.engine.as_ref()
+    }
+
+    /// Latest CSRF token received (masked, as sent to browsers).
+    pub fn csrf_token(&self) -> Option<String> {
+        self.csrf_token.lock().ok()?.clone()
+    }
+
+    /// Value of a stored cookie.
+    pub fn cookie(&self, name: &str) -> Option<String> {
+        self.cookies.lock().ok()?.get(name).cloned()
+    }
+
+    pub async fn get(&self, path: &str) -> TestResponse {
+        self.send(Method::GET, path, None, Body::empty()).await
+    }
+
+    /// URL-encoded form POST. `csrf_token` is added from the latest response
+    /// unless `form` already has one; with no token yet, `path` is fetched first.
+    pub async fn post(&self, path: &str, form: &[(&str, &str)]) -> TestResponse {
+        let mut fields: Vec<(&str, String)> =
+            form.iter().map(|(k, v)| (*k, v.to_string())).collect();
+        if !form.iter().any(|(k, _)| *k == CSRF_TOKEN_KEY)
+            && let Some(token) = self.ensure_csrf_token(path).await
+        {
+            fields.push((CSRF_TOKEN_KEY, token));
+        }
+        let body = serde_urlencoded::to_string(&fields).unwrap_or_default();
+        self.send(
+            Method::POST,
+            path,
+            Some("application/x-www-form-urlencoded"),
+            Body::from(body),
+        )
+        .await
+    }
+
+    /// JSON POST, with the CSRF token in the `X-CSRF-Token` header.
+    pub async fn post_json(&self, path: &str, value: &serde_json::Value) -> TestResponse {
+        let token = self.ensure_csrf_token(path).await;
+        let mut req = Request::builder()
+            .method(Method::POST)
+            .uri(path)
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(token) = token {
+            req = req.header(CSRF_HEADER, token);
+        }
+        let req = req
+            .body(Body::from(value.to_string()))
+            .expect("valid test request");
+        self.request(req).await
+    }
+
+    /// Sends a hand-built request — cookies, `Host` and the peer address are
+    /// added, the response's cookies and CSRF token are recorded.
+    pub async fn request(&self, mut req: Request<Body>) -> TestResponse {
+        let headers = req.headers_mut();
+        if !headers.contains_key(header::HOST) {
+            headers.insert(header::HOST, HeaderValue::from_static("localhost"));
+        }
+        if !headers.contains_key(header::COOKIE)
+            && let Some(cookie) = self.cookie_header()
+            && let Ok(value) = HeaderValue::from_str(&cookie)
+        {
+            headers.insert(header::COOKIE, value);
+        }
+        req.extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 0))));
+
+        let resp = self
+            .router
+            .clone()
+            .oneshot(req)
+            .await
+            .unwrap_or_else(|e| match e {});
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap_or_default();
+        self.record(&headers);
+        TestResponse {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        content_type: Option<&str>,
+        body: Body,
+    ) -> TestResponse {
+        let mut req = Request::builder().method(method).uri(path);
+        if let Some(ct) = content_type {
+            req = req.header(header::CONTENT_TYPE, ct);
+        }
+        self.request(req.body(body).expect("valid test request"))
+            .await
+    }
+
+    async fn ensure_csrf_token(&self, path: &str) -> Option<String> {
+        if let Some(token) = self.csrf_token() {
+            return Some(token);
+        }
+        self.get(path).await;
+        self.csrf_token()
+    }
+
+    fn cookie_header(&self) -> Option<String> {
+        let cookies = self.cookies.lock().ok()?;
+        if cookies.is_empty() {
+            return None;
+        }
+        Some(
+            cookies
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>()
+                .join("; "),
+        )
+    }
+
+    fn record(&self, headers: &HeaderMap) {
+        if let Ok(mut cookies) = self.cookies.lock() {
+            for set_cookie in headers.get_all(header::SET_COOKIE) {
+                let Ok(raw) = set_cookie.to_str() else {
+                    continue;
+                };
+                let mut parts = raw.split(';').map(str::trim);
+                let Some((name, value)) = parts.next().and_then(|kv| kv.split_once('=')) else {
+                    continue;
+                };
+                let expired = parts.any(|attr| {
+                    attr.eq_ignore_ascii_case("max-age=0")
+                        || attr
+                            .to_ascii_lowercase()
+                            .starts_with("expires=thu, 01 jan 1970")
+                });
+                if expired || value.is_empty() {
+                    cookies.remove(name);
+                } else {
+                    cookies.insert(name.to_string(), value.to_string());
+                }
+            }
+        }
+        if let Some(token) = headers.get(CSRF_HEADER).and_then(|v| v.to_str().ok())
+            && let Ok(mut csrf) = self.csrf_token.lock()
+        {
+            *csrf = Some(token.to_string());
+        }
+    }
+}
+
+/// Buffered response returned by [`TestClient`].
+#[derive(Debug)]
+pub struct TestResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl TestResponse {
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// Redirect target (`Location` header).
+    pub fn location(&self) -> Option<&str> {
+        self.header(header::LOCATION.as_str())
+    }
+
+    pub fn bytes(&self) -> &Bytes {
+        &self.body
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Body parsed as JSON. Panics if it is not valid JSON for `T`.
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> T {
+        serde_json::from_slice(&self.body).unwrap_or_else(|e| {
+            panic!(
+                "response body is not the expected JSON ({e}): {}",
+                self.text()
+            )
+        })
+    }
+
+    /// Value of the `csrf_token` hidden input rendered in the page, to resubmit
+    /// a form by hand.
+    pub fn form_csrf_token(&self) -> Option<String> {
+        let html = self.text();
+        let marker = format!(r#"name="{CSRF_TOKEN_KEY}" value=""#);
+        let start = html.find(&marker)? + marker.len();
+        let end = html[start..].find('"')?;
+        Some(html[start..start + end].to_string())
+    }
+}
+
+/// Fresh in-memory SQLite database — one connection, so every query sees the
+/// same data.
+#[cfg(feature = "sqlite")]
+pub async fn test_db() -> sea_orm::DatabaseConnection {
+    let mut opts = sea_orm::ConnectOptions::new("sqlite::memory:");
+    opts.max_connections(1)
+        .min_connections(1)
+        .sqlx_logging(false);
+    sea_orm::Database::connect(opts)
+        .await
+        .expect("sqlite::memory: connect")
+}
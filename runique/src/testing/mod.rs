@@ -0,0 +1,8 @@
+//! Integration-test helpers — [`TestClient`] drives a built `Router` in-process
+//! (`tower::ServiceExt::oneshot`), carrying session cookies and the CSRF token
+//! across calls.
+pub mod client;
+
+#[cfg(feature = "sqlite")]
+pub use client::test_db;
+pub use client::{TestClient, TestResponse};
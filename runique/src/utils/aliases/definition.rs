@@ -5,7 +5,7 @@ use crate::context::template::AppError;
 use crate::engine::RuniqueEngine;
 use crate::prelude::{HostPolicy, PermissionsPolicy, SecurityPolicy};
 use crate::utils::{csp_nonce::CspNonce, csrf::CsrfToken};
-use sea_orm::DatabaseConnection;
+use sea_orm::{DatabaseConnection, DatabaseTransaction};
 use std::{collections::HashMap, result::Result, sync::Arc, sync::RwLock};
 use tera::{Result as TeraResult, Tera, Value};
 use tower_sessions::{SessionManagerLayer, SessionStore};
@@ -28,6 +28,9 @@ pub type ADb = Arc<DatabaseConnection>;
 pub type Bdd = Option<DatabaseConnection>;
 pub type OADb = Option<ADb>;
 
+/// Database Transaction shared by the closures writing into it
+pub type ATxn = Arc<DatabaseTransaction>;
+
 /// Security Policy CSP
 pub type ASecurityCsp = Arc<SecurityPolicy>;
 pub type OSecurityCsp = Option<ASecurityCsp>;
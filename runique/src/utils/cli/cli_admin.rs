@@ -1,8 +1,10 @@
-//! CLI for creating an admin superuser with a choice of hashing algorithm.
+//! CLI for creating an admin superuser — interactive wizard with a choice of hashing
+//! algorithm, or `--noinput` from environment variables.
 use crate::auth::{
     session::UserEntity,
-    user::{ActiveModel, BuiltinUserEntity},
+    user::{ActiveModel, BuiltinUserEntity, Model},
 };
+use crate::forms::PasswordRules;
 use crate::utils::{
     password::{BaseHash, Manual},
     trad::{t, tf},
@@ -12,6 +14,9 @@ use dialoguer::{Input, Password, Select, theme::ColorfulTheme};
 use sea_orm::{ActiveModelTrait, DatabaseConnection, Set};
 use std::io::Write;
 
+/// Shortest password accepted, whatever the [`PasswordRules`].
+const MIN_PASSWORD_LEN: usize = 10;
+
 // ─── Types ────────────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone)]
@@ -139,15 +144,23 @@ async fn step_email(db: &DatabaseConnection) -> Option<String> {
     }
 }
 
-fn step_password() -> Option<String> {
+/// Minimum length, then the strength rules — first failure as a translated message.
+fn check_password(password: &str, rules: &PasswordRules) -> Option<String> {
+    if password.chars().count() < MIN_PASSWORD_LEN {
+        return Some(t("admin.superuser_wizard.password_too_short").into_owned());
+    }
+    rules.check(password)
+}
+
+fn step_password(rules: &PasswordRules) -> Option<String> {
     loop {
         let pass1 = Password::with_theme(&ColorfulTheme::default())
             .with_prompt(t("admin.superuser_wizard.password_prompt"))
             .interact()
             .ok()?;
 
-        if pass1.len() < 10 {
-            println!("{}", t("admin.superuser_wizard.password_too_short"));
+        if let Some(msg) = check_password(&pass1, rules) {
+            println!("{}", msg);
             continue;
         }
 
@@ -278,13 +291,103 @@ fn hash_via_provider(password: &str, provider_path: &str) -> Result<String, Stri
         .map_err(|e| format!("Invalid provider output (UTF-8): {}", e))
 }
 
+// ─── Non-interactive ──────────────────────────────────────────────────────────
+
+/// Superuser account data for [`insert_superuser`].
+#[derive(Debug, Clone)]
+pub struct SuperuserInput {
+    pub username: String,
+    pub email: String,
+    pub password: String,
+}
+
+impl SuperuserInput {
+    /// Reads `RUNIQUE_SUPERUSER_USERNAME`, `RUNIQUE_SUPERUSER_EMAIL` and
+    /// `RUNIQUE_SUPERUSER_PASSWORD` — all three required.
+    pub fn from_env() -> Result<Self> {
+        let var = |key: &str| {
+            std::env::var(key)
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+                .ok_or_else(|| anyhow::anyhow!(tf("admin.superuser_wizard.env_missing", &[&key])))
+        };
+        Ok(Self {
+            username: var("RUNIQUE_SUPERUSER_USERNAME")?,
+            email: var("RUNIQUE_SUPERUSER_EMAIL")?,
+            password: var("RUNIQUE_SUPERUSER_PASSWORD")?,
+        })
+    }
+}
+
+/// Validates `input` as the wizard does (free username and email, password length
+/// and `rules`), hashes the password with Argon2 and inserts the superuser.
+pub async fn insert_superuser(
+    db: &DatabaseConnection,
+    input: SuperuserInput,
+    rules: &PasswordRules,
+) -> Result<Model> {
+    let username = input.username.trim().to_string();
+    let email = input.email.trim().to_lowercase();
+    if username.is_empty() {
+        anyhow::bail!(t("admin.superuser_wizard.username_empty"));
+    }
+    if BuiltinUserEntity::find_by_username(db, &username)
+        .await
+        .is_some()
+    {
+        anyhow::bail!(t("admin.superuser_wizard.username_taken"));
+    }
+    if !email.contains('@') {
+        anyhow::bail!(t("admin.superuser_wizard.email_invalid"));
+    }
+    if BuiltinUserEntity::find_by_email(db, &email).await.is_some() {
+        anyhow::bail!(t("admin.superuser_wizard.email_taken"));
+    }
+    if let Some(msg) = check_password(&input.password, rules) {
+        anyhow::bail!(msg);
+    }
+    let hashed = hash_password(&input.password, &AlgoChoice::Argon2)
+        .map_err(|e| anyhow::anyhow!("Hashing error: {}", e))?;
+    save_superuser(db, username, email, hashed).await
+}
+
+async fn save_superuser(
+    db: &DatabaseConnection,
+    username: String,
+    email: String,
+    hashed: String,
+) -> Result<Model> {
+    let now = chrono::Utc::now().naive_utc();
+    let new_user = ActiveModel {
+        username: Set(username),
+        email: Set(email),
+        password: Set(hashed),
+        is_active: Set(true),
+        is_staff: Set(true),
+        is_superuser: Set(true),
+        created_at: Set(Some(now)),
+        updated_at: Set(Some(now)),
+        ..Default::default()
+    };
+    Ok(new_user.insert(db).await?)
+}
+
 // ─── Entry point ──────────────────────────────────────────────────────────────
 
-pub async fn create_superuser() -> Result<()> {
+/// `runique create-superuser [--noinput]`. Password strength rules come from
+/// [`PasswordRules::from_env`].
+pub async fn create_superuser(noinput: bool) -> Result<()> {
     dotenvy::dotenv_override().ok();
 
     let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be defined in .env");
     let db = sea_orm::Database::connect(&database_url).await?;
+    let rules = PasswordRules::from_env();
+
+    if noinput {
+        let inserted = insert_superuser(&db, SuperuserInput::from_env()?, &rules).await?;
+        print_created(&inserted);
+        return Ok(());
+    }
 
     println!("{}", t("admin.superuser_wizard.title"));
 
@@ -328,7 +431,7 @@ pub async fn create_superuser() -> Result<()> {
                 }
             },
 
-            Step::Password => match step_password() {
+            Step::Password => match step_password(&rules) {
                 None => step = Step::Email,
                 Some(p) => {
                     state.password = Some(p);
@@ -355,30 +458,22 @@ pub async fn create_superuser() -> Result<()> {
     let hashed =
         hash_password(password, algo).map_err(|e| anyhow::anyhow!("Hashing error: {}", e))?;
 
-    let username = state.username.unwrap();
-    let email = state.email.unwrap();
-
-    let new_user = ActiveModel {
-        username: Set(username.clone()),
-        email: Set(email.clone()),
-        password: Set(hashed),
-        is_active: Set(true),
-        is_staff: Set(true),
-        is_superuser: Set(true),
-        created_at: Set(Some(chrono::Utc::now().naive_utc())),
-        updated_at: Set(Some(chrono::Utc::now().naive_utc())),
-        ..Default::default()
-    };
+    let inserted =
+        save_superuser(&db, state.username.unwrap(), state.email.unwrap(), hashed).await?;
+    print_created(&inserted);
 
-    let inserted = new_user.insert(&db).await?;
+    Ok(())
+}
 
+fn print_created(user: &Model) {
     println!("\n{}", t("admin.superuser_wizard.success"));
-    println!("{}", tf("admin.superuser_wizard.id_line", &[&inserted.id]));
+    println!("{}", tf("admin.superuser_wizard.id_line", &[&user.id]));
     println!(
         "{}",
-        tf("admin.superuser_wizard.username_line", &[&username])
+        tf("admin.superuser_wizard.username_line", &[&user.username])
+    );
+    println!(
+        "{}",
+        tf("admin.superuser_wizard.email_line", &[&user.email])
     );
-    println!("{}", tf("admin.superuser_wizard.email_line", &[&email]));
-
-    Ok(())
 }
@@ -337,6 +337,35 @@ pub fn seaorm_alter_file_path(migrations_path: &str, timestamp: &str, table: &st
     )
 }
 
+/// Writes the migration of a [`SchemaDiff`] built with the `ModelSchema` API
+/// (`old.diff(&new)`): `m{timestamp}_alter_{table}_table.rs` in `migrations_path`,
+/// registered in the `Migrator` of `lib.rs`. The `down` reverses each change.
+///
+/// Returns the module name, `None` when the diff is empty (nothing written).
+pub fn write_schema_diff_migration(
+    migrations_path: &str,
+    diff: &SchemaDiff,
+) -> Result<Option<String>> {
+    if diff.is_empty() {
+        return Ok(None);
+    }
+
+    let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
+    let module_name = seaorm_alter_module_name(&timestamp, &diff.table_name);
+    let path = seaorm_alter_file_path(migrations_path, &timestamp, &diff.table_name);
+    if Path::new(&path).exists() {
+        anyhow::bail!("Migration already exists: {}", path);
+    }
+
+    fs::create_dir_all(migrations_path)
+        .with_context(|| format!("Failed to create: {}", migrations_path))?;
+    fs::write(&path, generate_schema_diff_file(diff))
+        .with_context(|| format!("Failed to write: {}", path))?;
+    update_migration_lib(migrations_path, &module_name)?;
+
+    Ok(Some(module_name))
+}
+
 pub fn seaorm_extend_module_name(timestamp: &str, table: &str) -> String {
     format!("m{}_extend_{}_table", timestamp, table)
 }
@@ -462,6 +491,11 @@ pub fn run(entities_path: &str, migrations_path: &str, force: bool) -> Result<()
 
     println!("{}", tf("makemigrations.files_ready", &[module_count]));
 
+    // Down blocks that cannot restore data — also embedded as `// WARNING:` in the files
+    for warning in destructive_set.iter().flat_map(Changes::down_warnings) {
+        eprintln!("  WARNING: {}", warning);
+    }
+
     Ok(())
 }
 
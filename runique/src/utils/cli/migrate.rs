@@ -2,7 +2,9 @@
 use crate::utils::config::TraceResult;
 use crate::utils::trad::{t, tf};
 use anyhow::{Context, Result};
-use sea_orm::{ConnectionTrait, Database, DatabaseConnection, DbBackend, TransactionTrait};
+use sea_orm::{
+    ConnectionTrait, Database, DatabaseConnection, DatabaseTransaction, DbBackend, TransactionTrait,
+};
 use std::{fs, path::Path};
 
 // ============================================================
@@ -34,6 +36,65 @@ pub async fn up(migrations_path: &str) -> Result<()> {
     Ok(())
 }
 
+/// Dry run of `up`: prints the SQL of each pending migration, without executing it.
+pub async fn plan(migrations_path: &str) -> Result<()> {
+    let migration_dir = migration_dir(migrations_path);
+    println!("{}", tf("migrate.planning", &[migration_dir]));
+    run_migration_command(migration_dir, &["plan"]).await
+}
+
+/// Lists the migrations of the `Migrator` as applied `[X]` or pending `[ ]`, with
+/// the time they were applied, and flags the orphaned ones (applied, no longer in
+/// the `Migrator`).
+pub async fn show(migrations_path: &str) -> Result<()> {
+    let migration_dir = migration_dir(migrations_path);
+    println!("{}", tf("migrate.status_for", &[migration_dir]));
+    run_migration_command(migration_dir, &["showmigrations"]).await
+}
+
+/// Rolls back the migrations applied after `target` (a `Migrator` migration name)
+/// until it is the latest applied one. Refused when `target` is not applied; the
+/// plan is printed and confirmation asked unless `yes`.
+pub async fn rollback_to(migrations_path: &str, target: &str, yes: bool) -> Result<()> {
+    let migration_dir = migration_dir(migrations_path);
+    let mut args = vec!["rollback-to", target];
+    if yes {
+        args.push("--yes");
+    }
+    run_migration_command(migration_dir, &args).await
+}
+
+fn migration_dir(migrations_path: &str) -> &str {
+    migrations_path
+        .trim_end_matches("/src")
+        .trim_end_matches("\\src")
+}
+
+/// Runs `cargo run -- <args>` in the migration crate, whose `main` must call
+/// [`crate::migration::plan::run_cli`].
+async fn run_migration_command(migration_dir: &str, args: &[&str]) -> Result<()> {
+    dotenvy::dotenv().ok();
+
+    let manifest = format!("{}/Cargo.toml", migration_dir);
+    let status = tokio::process::Command::new("cargo")
+        .args(["run", "--quiet", "--manifest-path", &manifest, "--"])
+        .args(args)
+        .status()
+        .await
+        .with_context(|| t("migrate.cargo_launch_failed"))?;
+
+    if !status.success() {
+        anyhow::bail!(
+            "{}",
+            tf(
+                "migrate.command_exit",
+                &[args[0], &format!("{:?}", status.code()), migration_dir]
+            )
+        );
+    }
+    Ok(())
+}
+
 pub async fn down(migrations_path: &str, files: Vec<String>, batch: Option<String>) -> Result<()> {
     if files.is_empty() && batch.is_none() {
         list_available(migrations_path)?;
@@ -61,6 +122,80 @@ pub async fn down(migrations_path: &str, files: Vec<String>, batch: Option<Strin
     Ok(())
 }
 
+/// Rolls back the `steps` most recent batches, newest first, replaying each
+/// batch's `down()` statements in a single transaction. Rolled-back batch files
+/// are renamed to `<timestamp>.rs.rolled_back` so the next rollback moves on to
+/// the previous batch.
+///
+/// MySQL / MariaDB commit DDL implicitly: there a failing step can still leave
+/// the earlier ones applied.
+pub async fn rollback(migrations_path: &str, steps: usize) -> Result<()> {
+    if steps == 0 {
+        anyhow::bail!("Nothing to rollback: steps must be at least 1.");
+    }
+
+    let by_time_dir = format!("{}/applied/by_time", migrations_path);
+    let mut batches = list_files_in_dir(&by_time_dir)?;
+    batches.sort();
+
+    if steps > batches.len() {
+        anyhow::bail!(
+            "Cannot rollback {} batch(es): only {} available.\nRun 'runique migration status' to list available batches.",
+            steps,
+            batches.len()
+        );
+    }
+
+    dotenvy::dotenv().ok();
+
+    let db_url = std::env::var("DATABASE_URL")
+        .with_context(|| "DATABASE_URL not set. Add it to your .env file.")?;
+
+    let db = Database::connect(&db_url)
+        .await
+        .with_context(|| "Failed to connect to database.")?;
+    let backend = db.get_database_backend();
+
+    // Newest first: each batch is undone before the one it was built on
+    let selected: Vec<&String> = batches.iter().rev().take(steps).collect();
+    let mut plan = Vec::with_capacity(selected.len());
+    for batch in &selected {
+        let batch_file = format!("{}/{}", by_time_dir, batch);
+        let source = fs::read_to_string(&batch_file)
+            .with_context(|| format!("Cannot read batch file: {}", batch_file))?;
+        let down_block = extract_fn_block(&source, "down").unwrap_or_default();
+        for warning in down_warnings(&down_block) {
+            eprintln!("  WARNING: {}", warning);
+        }
+        let statements = extract_statements_from_block(&down_block, &source, backend);
+        plan.push((batch.trim_end_matches(".rs").to_string(), statements));
+    }
+
+    let txn = db.begin().await.context("Failed to begin transaction")?;
+    let result: Result<()> = async {
+        for (timestamp, statements) in &plan {
+            println!("{}", tf("migrate.rolling_back_batch", &[timestamp]));
+            if statements.is_empty() {
+                println!("  {}", t("migrate.no_down"));
+            }
+            execute_statements(&txn, statements).await?;
+        }
+        Ok(())
+    }
+    .await;
+    finish_transaction(txn, result).await?;
+
+    for (batch, (timestamp, _)) in selected.iter().zip(&plan) {
+        let batch_file = format!("{}/{}", by_time_dir, batch);
+        fs::rename(&batch_file, format!("{}.rolled_back", batch_file))
+            .with_context(|| format!("Cannot mark batch as rolled back: {}", batch_file))?;
+        println!("  {}", tf("migrate.done", &[timestamp]));
+    }
+
+    println!("\n{}", t("migrate.complete"));
+    Ok(())
+}
+
 pub fn status(migrations_path: &str) -> Result<()> {
     println!("{}", tf("migrate.available_for", &[migrations_path]));
     list_available(migrations_path)?;
@@ -247,6 +382,7 @@ fn list_available(migrations_path: &str) -> Result<()> {
         println!("\n{}", t("migrate.usage"));
         println!("  {}", t("migrate.usage_files"));
         println!("  {}", t("migrate.usage_batch"));
+        println!("  {}", t("migrate.usage_rollback"));
     }
 
     Ok(())
@@ -261,6 +397,9 @@ async fn execute_down_block(source: &str, db: &DatabaseConnection) -> Result<()>
 
     // 1) capture down() block safely
     let down_block = extract_fn_block(source, "down").unwrap_or_default();
+    for warning in down_warnings(&down_block) {
+        eprintln!("  WARNING: {}", warning);
+    }
 
     // 2) build SQL statements from down() content
     let statements = extract_statements_from_block(&down_block, source, backend);
@@ -271,18 +410,22 @@ async fn execute_down_block(source: &str, db: &DatabaseConnection) -> Result<()>
     }
 
     let txn = db.begin().await.context("Failed to begin transaction")?;
+    let result = execute_statements(&txn, &statements).await;
+    finish_transaction(txn, result).await
+}
 
-    let result: Result<()> = async {
-        for sql in &statements {
-            println!("  {}", tf("migrate.executing", &[sql]));
-            txn.execute_unprepared(sql)
-                .await
-                .with_context(|| format!("Failed to execute: {}", sql))?;
-        }
-        Ok(())
+async fn execute_statements(txn: &DatabaseTransaction, statements: &[String]) -> Result<()> {
+    for sql in statements {
+        println!("  {}", tf("migrate.executing", &[sql]));
+        txn.execute_unprepared(sql)
+            .await
+            .with_context(|| format!("Failed to execute: {}", sql))?;
     }
-    .await;
+    Ok(())
+}
 
+/// Commits on success, rolls back (and returns the original error) otherwise.
+async fn finish_transaction(txn: DatabaseTransaction, result: Result<()>) -> Result<()> {
     match result {
         Ok(()) => txn.commit().await.context("Failed to commit transaction")?,
         Err(e) => {
@@ -296,10 +439,19 @@ async fn execute_down_block(source: &str, db: &DatabaseConnection) -> Result<()>
             return Err(e);
         }
     }
-
     Ok(())
 }
 
+/// `// WARNING:` lines written by the generators (e.g. NOT NULL columns the
+/// down block re-adds without their data).
+fn down_warnings(down_block: &str) -> Vec<&str> {
+    down_block
+        .lines()
+        .filter_map(|l| l.trim().strip_prefix("// WARNING:"))
+        .map(str::trim)
+        .collect()
+}
+
 // ============================================================
 // Extract function block
 // ============================================================
@@ -1,4 +1,4 @@
-//! Runique CLI commands — new project, migration generation, server startup.
+//! Runique CLI commands — new project, app scaffolding, migration generation, server startup.
 pub mod new_project;
 pub use new_project::create_new_project;
 
@@ -12,5 +12,8 @@ pub mod migrate;
 pub mod start;
 pub use start::runique_start;
 
+pub mod start_app;
+pub use start_app::create_app;
+
 pub mod cli_admin;
-pub use cli_admin::create_superuser;
+pub use cli_admin::{SuperuserInput, create_superuser, insert_superuser};
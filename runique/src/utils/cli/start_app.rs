@@ -0,0 +1,110 @@
+//! `startapp` command — scaffolds an app module (`models`, `forms`, `views`, `url`,
+//! `admin` stub) and its template inside an existing project.
+use crate::utils::trad::{t, tf};
+use anyhow::Result;
+use std::{fs, path::Path};
+
+/// App files (template, destination) — `{{APP_NAME}}` / `{{MODEL_NAME}}` are substituted
+/// in both.
+const APP_FILES: &[(&str, &str)] = &[
+    (
+        include_str!("../../composant-bin/app/mod.rs"),
+        "src/{{APP_NAME}}/mod.rs",
+    ),
+    (
+        include_str!("../../composant-bin/app/models.rs"),
+        "src/{{APP_NAME}}/models.rs",
+    ),
+    (
+        include_str!("../../composant-bin/app/forms.rs"),
+        "src/{{APP_NAME}}/forms.rs",
+    ),
+    (
+        include_str!("../../composant-bin/app/views.rs"),
+        "src/{{APP_NAME}}/views.rs",
+    ),
+    (
+        include_str!("../../composant-bin/app/url.rs"),
+        "src/{{APP_NAME}}/url.rs",
+    ),
+    (
+        include_str!("../../composant-bin/app/admin.rs"),
+        "src/{{APP_NAME}}/admin.rs",
+    ),
+    (
+        include_str!("../../composant-bin/app/index.html"),
+        "templates/{{APP_NAME}}/index.html",
+    ),
+];
+
+/// Generates the `name` app under `project_dir` (the project root, holding `src/`).
+/// Nothing is written if one of the files already exists.
+pub fn create_app(project_dir: &Path, name: &str) -> Result<()> {
+    validate_app_name(name)?;
+    if !project_dir.join("src").is_dir() {
+        anyhow::bail!("{}", t("cli.app_no_src"));
+    }
+
+    let model = model_name(name);
+    let fill = |s: &str| {
+        s.replace("{{APP_NAME}}", name)
+            .replace("{{MODEL_NAME}}", &model)
+    };
+    let files: Vec<(String, String)> = APP_FILES
+        .iter()
+        .map(|(content, path)| (fill(path), fill(content)))
+        .collect();
+    if let Some((path, _)) = files.iter().find(|(p, _)| project_dir.join(p).exists()) {
+        anyhow::bail!("{}", tf("cli.app_file_exists", &[path]));
+    }
+
+    println!("🦀 {}", tf("cli.creating_app", &[&name]));
+    for (path, content) in &files {
+        let dest = project_dir.join(path);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(dest, content)?;
+        println!("  + {}", path);
+    }
+
+    println!("\n  {}", tf("cli.app_created", &[&name]));
+    println!("\n  {}", t("cli.app_mod_hint"));
+    println!("      mod {};", name);
+    println!("\n  {}", t("cli.app_routes_hint"));
+    println!("      .merge(crate::{}::url::routes())", name);
+    println!("\n  {}", tf("cli.app_admin_hint", &[&name]));
+    Ok(())
+}
+
+/// App names become module names: lowercase ASCII, digits and `_`, not starting
+/// with a digit, not a Rust keyword.
+fn validate_app_name(name: &str) -> Result<()> {
+    const RESERVED: &[&str] = &[
+        "admin", "crate", "entities", "fn", "impl", "main", "mod", "self", "super", "type", "url",
+        "use", "views",
+    ];
+    let valid = name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && name.starts_with(|c: char| c.is_ascii_lowercase())
+        && !RESERVED.contains(&name);
+    if !valid {
+        anyhow::bail!("{}", tf("cli.app_name_invalid", &[&name]));
+    }
+    Ok(())
+}
+
+/// `blog_post` → `BlogPost`
+fn model_name(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
@@ -5,6 +5,8 @@ pub mod integrity;
 pub use integrity::build_integrity_map;
 pub mod pk;
 pub use pk::Pk;
+pub mod static_manifest;
+pub use static_manifest::StaticManifest;
 
 pub mod trace_ext;
 pub use trace_ext::TraceResult;
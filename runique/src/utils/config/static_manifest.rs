@@ -0,0 +1,87 @@
+//! Static files manifest — short content hash of every file, built once at startup
+//! for cache-busting URLs (`static_url("css/app.css")` → `/static/css/app.css?v=…`)
+//! and Subresource Integrity (`sri_hash("js/app.js")` → `integrity="sha384-…"`).
+use base64::{Engine, engine::general_purpose::STANDARD};
+use sha2::{Digest, Sha256, Sha384};
+use std::{collections::HashMap, fs::File, io::Read, path::Path, sync::Arc};
+
+/// Length of the hex hash appended as `?v=`.
+const HASH_LEN: usize = 12;
+
+/// Hashes of one static file.
+#[derive(Clone, Debug)]
+struct StaticEntry {
+    /// Short SHA-256, appended as `?v=`.
+    version: String,
+    /// `sha384-<base64>`, value of the `integrity` attribute.
+    integrity: String,
+}
+
+/// Relative path (`css/app.css`) → content hashes, shared by the templates and the
+/// static files service.
+#[derive(Clone, Debug, Default)]
+pub struct StaticManifest(Arc<HashMap<String, StaticEntry>>);
+
+impl StaticManifest {
+    /// Hashes every file under `dir` — a missing directory gives an empty manifest.
+    pub fn build(dir: &Path) -> Self {
+        let mut map = HashMap::new();
+        for entry in walkdir::WalkDir::new(dir).into_iter().flatten() {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let Ok(relative) = entry.path().strip_prefix(dir) else {
+                continue;
+            };
+            if let Some(hashes) = hash_file(entry.path()) {
+                map.insert(relative.to_string_lossy().replace('\\', "/"), hashes);
+            }
+        }
+        Self(Arc::new(map))
+    }
+
+    /// Hash of `path`, relative to the static directory (leading `/` ignored).
+    pub fn hash(&self, path: &str) -> Option<&str> {
+        self.entry(path).map(|e| e.version.as_str())
+    }
+
+    /// SRI value of `path` — `sha384-<base64>` of the whole content.
+    pub fn integrity(&self, path: &str) -> Option<&str> {
+        self.entry(path).map(|e| e.integrity.as_str())
+    }
+
+    fn entry(&self, path: &str) -> Option<&StaticEntry> {
+        self.0.get(path.trim_start_matches('/'))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Streams the file through SHA-256 and SHA-384 in one pass — large assets are
+/// never loaded whole.
+fn hash_file(path: &Path) -> Option<StaticEntry> {
+    let mut file = File::open(path).ok()?;
+    let mut sha256 = Sha256::new();
+    let mut sha384 = Sha384::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).ok()?;
+        if read == 0 {
+            break;
+        }
+        sha256.update(&buffer[..read]);
+        sha384.update(&buffer[..read]);
+    }
+    let mut version = hex::encode(sha256.finalize());
+    version.truncate(HASH_LEN);
+    Some(StaticEntry {
+        version,
+        integrity: format!("sha384-{}", STANDARD.encode(sha384.finalize())),
+    })
+}
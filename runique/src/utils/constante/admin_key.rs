@@ -17,6 +17,8 @@ pub mod admin_context {
         pub const OBJECT_ID: &str = "object_id";
         pub const FORM_FIELDS: &str = "form_fields";
         pub const IS_EDIT: &str = "is_edit";
+        /// Related rows edited on the create/edit form (`Vec<InlineFormset>`), one per inline.
+        pub const INLINE_FORMSETS: &str = "inline_formsets";
     }
 
     pub mod permission {
@@ -48,6 +50,8 @@ pub mod admin_context {
         pub const SORT_DIR_TOGGLE: &str = "sort_dir_toggle";
         pub const SEARCH: &str = "search";
         pub const FILTER_VALUES: &str = "filter_values";
+        /// `values` (one link per value) or `date_range` (from / to inputs), per filter column.
+        pub const FILTER_KINDS: &str = "filter_kinds";
         pub const ACTIVE_FILTERS: &str = "active_filters";
         pub const FILTER_QS: &str = "filter_qs";
         pub const FILTER_META: &str = "filter_meta";
@@ -56,6 +60,10 @@ pub mod admin_context {
         pub const RETURN_QS: &str = "return_qs";
         /// Group actions declared in `admin!{}` — `Vec<GroupAction>` iterated as `ga` in the template.
         pub const GROUP_ACTIONS: &str = "group_actions";
+        /// Custom bulk actions — `Vec<BulkAction>` (`name`, `label`) listed in the bulk dropdown.
+        pub const BULK_ACTIONS: &str = "bulk_actions";
+        /// Names of the computed columns — rendered without a sort link.
+        pub const COMPUTED_COLUMNS: &str = "computed_columns";
 
         /// Mandatory keys for overriding this template
         pub const REQUIRED: &[&str] = &[
@@ -78,7 +86,7 @@ pub mod admin_context {
 
     /// `create` template — creation form
     pub mod create {
-        pub use super::common::{FORM_FIELDS, IS_EDIT, LANG};
+        pub use super::common::{FORM_FIELDS, INLINE_FORMSETS, IS_EDIT, LANG};
 
         /// Mandatory keys for overriding this template
         pub const REQUIRED: &[&str] = &[FORM_FIELDS];
@@ -86,7 +94,7 @@ pub mod admin_context {
 
     /// `edit` template — edition form
     pub mod edit {
-        pub use super::common::{FORM_FIELDS, IS_EDIT, LANG, OBJECT_ID};
+        pub use super::common::{FORM_FIELDS, INLINE_FORMSETS, IS_EDIT, LANG, OBJECT_ID};
         /// Injected on GET from the stored object, and on POST re-injected on validation failure.
         /// Used by the optimistic locking check (`__original_updated_at` hidden field).
         pub const ORIG_UPDATED_AT: &str = "orig_updated_at";
@@ -133,10 +141,14 @@ pub const ADMIN_MESSAGE_KEYS: &[&str] = &[
     "admin.login.subtitle",
     "admin.login.label_username",
     "admin.login.label_password",
+    "admin.login.label_remember",
     "admin.login.btn_submit",
     "admin.login.error_session",
     "admin.login.error_credentials",
     "admin.login.error_locked",
+    "admin.login.label_totp",
+    "admin.login.totp_prompt",
+    "admin.login.error_totp",
     // logout
     "admin.logout.success",
     // access
@@ -206,6 +218,9 @@ pub const ADMIN_MESSAGE_KEYS: &[&str] = &[
     "admin.delete.warning.desc",
     "admin.delete.warning.of",
     "admin.delete.warning.irreversible",
+    // inline rows
+    "admin.inline.delete",
+    "admin.inline.add",
     // base
     "admin.base.title",
     "admin.base.breadcrumb",
@@ -23,16 +23,50 @@ pub static FORM_FIELD_REGEX: LazyLock<Regex> =
 pub static FORM_FULL_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\{%\s*form\.([a-zA-Z0-9_]+)\s*%}").unwrap());
 
-pub static MARKDOWN_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"\{\{\s*([^|{}\n]+?)\s*\|\s*markdown\s*\}\}").unwrap());
+/// Matches `{{ expr | markdown }}` / `{{ expr | render_markdown }}` — rewritten with
+/// `| safe`, the filter output being sanitized HTML.
+pub static MARKDOWN_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\{\{\s*([^|{}\n]+?)\s*\|\s*(markdown|render_markdown)\s*\}\}").unwrap()
+});
 
-/// Matches `{{ expr | sanitize }}` — re-sanitizes stored rich HTML at render time.
-/// Rewritten to `{{ expr | sanitize | safe }}` so the (ammonia-cleaned) output is
-/// emitted as HTML. Security is applied on output, never trusted from storage.
-pub static SANITIZE_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"\{\{\s*([^|{}\n]+?)\s*\|\s*sanitize\s*\}\}").unwrap());
+/// Matches `{{ expr | sanitize }}` / `{{ expr | sanitize(policy="blog") }}` — re-sanitizes
+/// stored rich HTML at render time. Rewritten to `{{ expr | sanitize | safe }}` so the
+/// (ammonia-cleaned) output is emitted as HTML. Security is applied on output, never
+/// trusted from storage.
+pub static SANITIZE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\{\{\s*([^|{}\n]+?)\s*\|\s*sanitize\s*(\([^()\n]*\))?\s*\}\}").unwrap()
+});
 
 /// Matches `{{ form_fields.html }}` — Runique-generated HTML, never user input.
 /// Rewrites to `{{ form_fields.html | safe }}` during template preprocessing.
 pub static ADMIN_FORM_HTML_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\{\{\s*form_fields\.html\s*\}\}").unwrap());
+
+/// Matches `{{ script_nonce() }}` — rewritten to the `nonce="…"` attribute of the
+/// current request (`csp.html`), the value `security_headers_middleware` put in the header.
+pub static SCRIPT_NONCE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{\s*script_nonce\(\s*\)\s*\}\}").unwrap());
+
+/// Matches `{% script %}` / `{% style %}` — opening tag of an inline block carrying the nonce.
+pub static NONCE_BLOCK_OPEN_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{%\s*(?P<tag>script|style)\s*%}").unwrap());
+
+/// Matches `{% endscript %}` / `{% endstyle %}`.
+pub static NONCE_BLOCK_CLOSE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{%\s*end(?P<tag>script|style)\s*%}").unwrap());
+
+/// Matches `static_url("css/app.css")` — Tera functions only take named arguments,
+/// rewritten to `static_url(path="css/app.css")`.
+pub static STATIC_URL_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"static_url\(\s*(?P<q>["'])(?P<path>[^"']+)["']\s*\)"#).unwrap());
+
+/// Matches `sri_hash("js/app.js")` / `script_tag("js/app.js")` — rewritten to named
+/// arguments, `script_tag` also receiving the request `csp_nonce`.
+pub static SRI_FUNCTION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?P<func>sri_hash|script_tag)\(\s*(?P<q>["'])(?P<path>[^"']+)["']\s*\)"#).unwrap()
+});
+
+/// Matches `{% trans "nav.home" %}` — rewritten to
+/// `{{ trans(key="nav.home", locale=locale | default(value="")) }}`.
+pub static TRANS_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\{%\s*trans\s+(?P<q>["'])(?P<key>[^"']+)["']\s*%}"#).unwrap());
@@ -8,6 +8,8 @@ pub mod session {
     pub const NONCE_KEY: &str = "csp_nonce";
     pub const SESSION_USER_ID_KEY: &str = "user_id";
     pub const SESSION_ACTIVE_KEY: &str = "session_active";
+    pub const SESSION_REMEMBER_ME_KEY: &str = "_remember_me";
+    pub const SESSION_EPOCH_KEY: &str = "_session_epoch";
     pub const SESSION_USER_USERNAME_KEY: &str = "username";
     pub const SESSION_USER_IS_STAFF_KEY: &str = "is_staff";
     pub const SESSION_USER_IS_SUPERUSER_KEY: &str = "is_superuser";
@@ -15,4 +17,11 @@ pub mod session {
     pub const SESSION_USER_DROITS_KEY: &str = "droits";
     pub const IS_ACTIVE: &str = "is_active";
     pub const HP_FIELD_KEY: &str = "_hp";
+    pub const SESSION_TOTP_PENDING_KEY: &str = "_totp_pending_user_id";
+    pub const SESSION_TOTP_ATTEMPTS_KEY: &str = "_totp_attempts";
+    pub const SESSION_TOTP_REMEMBER_KEY: &str = "_totp_remember";
+    // Set by every completed login — a session without it is not authenticated.
+    // `true` when a TOTP code was entered, `false` for an account without 2FA.
+    pub const SESSION_TOTP_VERIFIED_KEY: &str = "_totp_verified";
+    pub const SESSION_LOCALE_KEY: &str = "locale";
 }
@@ -4,6 +4,7 @@ use crate::tpls;
 pub const SIMPLE_TEMPLATES: &[(&str, &str)] = tpls![
     ("base_index.html", "runique_index/base_index.html"),
     ("message.html", "message/message.html"),
+    ("403.html", "errors/403.html"),
     ("404.html", "errors/404.html"),
     ("429.html", "errors/429.html"),
     ("500.html", "errors/500.html"),
@@ -29,10 +30,15 @@ pub const ERROR_CORPS: &[(&str, &str)] = tpls![
 ];
 
 pub const FIELD_TEMPLATES: &[(&str, &str)] = tpls![
+    (
+        "base_autocomplete.html",
+        "field_html/base_autocomplete.html"
+    ),
     ("base_boolean.html", "field_html/base_boolean.html"),
     ("base_checkbox.html", "field_html/base_checkbox.html"),
     ("base_color.html", "field_html/base_color.html"),
     ("base_datetime.html", "field_html/base_datetime.html"),
+    ("base_daterange.html", "field_html/base_daterange.html"),
     ("base_file.html", "field_html/base_file.html"),
     ("base_number.html", "field_html/base_number.html"),
     ("base_radio.html", "field_html/base_radio.html"),
@@ -41,6 +47,9 @@ pub const FIELD_TEMPLATES: &[(&str, &str)] = tpls![
     ("base_string.html", "field_html/base_string.html"),
     ("base_hidden.html", "field_html/base_hidden.html"),
     ("base_honeypot.html", "field_html/base_honeypot.html"),
+    ("base_display.html", "field_html/base_display.html"),
+    ("base_formset.html", "field_html/base_formset.html"),
+    ("field_help_text.html", "field_html/help_text.html"),
 ];
 
 pub const AUTH_TEMPLATES: &[(&str, &str)] = tpls![
@@ -77,6 +86,10 @@ pub const ADMIN_TEMPLATES: &[(&str, &str)] = tpls![
         "admin/composant/history_batch.html"
     ),
     ("admin/bulk_edit.html", "admin/composant/bulk_edit.html"),
+    (
+        "admin/inline_formset.html",
+        "admin/composant/inline_formset.html"
+    ),
     (
         "admin/reset_password_email.html",
         "admin/reset_password_email.html"
@@ -293,4 +293,24 @@ mod staging_tests {
 
         let _ = std::fs::remove_dir_all(&media);
     }
+
+    /// Un upload qui dépasse `max_upload_mb` est rejeté en cours de stream (413),
+    /// sans bufferiser le fichier, et le staging partiel est supprimé.
+    #[tokio::test]
+    async fn parse_multipart_rejects_oversized_upload_while_streaming() {
+        let media = std::env::temp_dir().join(format!("rq_pm_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&media).unwrap();
+
+        let content = "X".repeat(1024 * 1024 + 1);
+        let req = multipart_req("BNDRY", "avatar", "big.png", &content);
+        let mp = Multipart::from_request(req, &()).await.unwrap();
+
+        let res = parse_multipart(mp, &media, 1, 64).await.unwrap_err();
+        assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let leftovers = std::fs::read_dir(&media).unwrap().count();
+        assert_eq!(leftovers, 0, "staging partiel nettoyé");
+
+        let _ = std::fs::remove_dir_all(&media);
+    }
 }
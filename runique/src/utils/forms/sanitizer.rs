@@ -2,7 +2,10 @@
 /// Pre-configured ammonia builder — initialized once, reused for each request.
 use crate::utils::constante::parse::{ALLOWED_ATTRS, ALLOWED_TAGS, RICH_CONTENT_FIELDS};
 use ammonia::Builder;
-use std::{collections::HashSet, sync::LazyLock};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, LazyLock, RwLock},
+};
 
 /// Pre-configured ammonia builder — initialized once, reused for each request.
 static RICH_BUILDER: LazyLock<Builder<'static>> = LazyLock::new(|| {
@@ -110,6 +113,183 @@ pub fn sanitize_markdown(html: &str) -> String {
     MARKDOWN_BUILDER.clean(html).to_string()
 }
 
+/// Renders Markdown to HTML (tables, strikethrough, heading ids), cleaned by
+/// [`sanitize_markdown`]: the result can be emitted unescaped, whatever raw HTML
+/// or `javascript:` URL the source contains. Backs the `| markdown` and
+/// `| render_markdown` Tera filters.
+#[must_use]
+pub fn render_markdown(markdown: &str) -> String {
+    use pulldown_cmark::{Options, Parser, html};
+
+    let mut opts = Options::empty();
+    opts.insert(Options::ENABLE_TABLES);
+    opts.insert(Options::ENABLE_STRIKETHROUGH);
+    opts.insert(Options::ENABLE_HEADING_ATTRIBUTES);
+    let mut output = String::new();
+    html::push_html(&mut output, Parser::new_ext(markdown, opts));
+    sanitize_markdown(&output)
+}
+
+// =============================
+// CUSTOM POLICY — OPT-IN ALLOWLIST
+// =============================
+
+/// Tags whose content ammonia drops along with them — never allowed by a policy.
+const NEVER_ALLOWED_TAGS: &[&str] = &["script", "style"];
+
+/// Allowlist of tags, attributes per tag and URL schemes kept by the sanitizer —
+/// everything else is stripped. Opted into per field
+/// ([`TextField::sanitize_policy`](crate::forms::fields::TextField::sanitize_policy))
+/// or per render (`{{ body | sanitize(policy="blog") }}` once registered with
+/// [`register_sanitize_policy`]).
+///
+/// `SanitizePolicy::default()` is the built-in rich-text allowlist,
+/// `SanitizePolicy::empty()` keeps no tag at all. Whatever the allowlist,
+/// `<script>`/`<style>`, `on*` event handlers and `rel` stay stripped, relative
+/// URLs are refused and links get `rel="noopener noreferrer"`.
+///
+/// ```rust,ignore
+/// let blog = SanitizePolicy::default()
+///     .allow_tags(["figure", "figcaption", "span"])
+///     .allow_attributes("span", ["class"])
+///     .allow_url_schemes(["mailto"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SanitizePolicy {
+    tags: HashSet<String>,
+    attributes: HashMap<String, HashSet<String>>,
+    url_schemes: HashSet<String>,
+}
+
+impl Default for SanitizePolicy {
+    /// Same allowlist as [`sanitize_rich`].
+    fn default() -> Self {
+        Self {
+            tags: ALLOWED_TAGS.iter().map(|t| t.to_string()).collect(),
+            attributes: ALLOWED_ATTRS
+                .iter()
+                .map(|(tag, attrs)| {
+                    (
+                        tag.to_string(),
+                        attrs.iter().map(|a| a.to_string()).collect(),
+                    )
+                })
+                .collect(),
+            url_schemes: HashSet::from(["http".to_string(), "https".to_string()]),
+        }
+    }
+}
+
+impl SanitizePolicy {
+    /// No tag, attribute or scheme allowed — only text survives.
+    pub fn empty() -> Self {
+        Self {
+            tags: HashSet::new(),
+            attributes: HashMap::new(),
+            url_schemes: HashSet::new(),
+        }
+    }
+
+    /// Keeps `tags` — `script` and `style` are ignored.
+    pub fn allow_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tags.extend(
+            tags.into_iter()
+                .map(|t| t.into().to_ascii_lowercase())
+                .filter(|t| !NEVER_ALLOWED_TAGS.contains(&t.as_str())),
+        );
+        self
+    }
+
+    /// Strips `tags`, along with their allowed attributes.
+    pub fn deny_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for tag in tags {
+            let tag = tag.into().to_ascii_lowercase();
+            self.tags.remove(&tag);
+            self.attributes.remove(&tag);
+        }
+        self
+    }
+
+    /// Keeps `attributes` on `tag` — `on*` handlers and `rel` are ignored.
+    pub fn allow_attributes<I, S>(mut self, tag: &str, attributes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.attributes
+            .entry(tag.to_ascii_lowercase())
+            .or_default()
+            .extend(
+                attributes
+                    .into_iter()
+                    .map(|a| a.into().to_ascii_lowercase())
+                    .filter(|a| !a.starts_with("on") && a != "rel"),
+            );
+        self
+    }
+
+    /// Keeps URLs with these schemes in `href`/`src` (`"mailto"`, `"tel"`…).
+    pub fn allow_url_schemes<I, S>(mut self, schemes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.url_schemes.extend(
+            schemes
+                .into_iter()
+                .map(|s| s.into().trim_end_matches(':').to_ascii_lowercase()),
+        );
+        self
+    }
+
+    /// Cleans `input` with this allowlist.
+    #[must_use]
+    pub fn clean(&self, input: &str) -> String {
+        if input.is_empty() {
+            return String::new();
+        }
+        let mut builder = Builder::new();
+        builder.tags(self.tags.iter().map(String::as_str).collect());
+        builder.tag_attributes(
+            self.attributes
+                .iter()
+                .map(|(tag, attrs)| (tag.as_str(), attrs.iter().map(String::as_str).collect()))
+                .collect(),
+        );
+        builder.url_schemes(self.url_schemes.iter().map(String::as_str).collect());
+        builder.url_relative(ammonia::UrlRelative::Deny);
+        builder.link_rel(Some("noopener noreferrer"));
+        builder.add_tag_attribute_values("a", "target", &["_blank"]);
+        builder.strip_comments(true);
+        builder.clean(input).to_string().trim().to_string()
+    }
+}
+
+/// Named policies usable from templates: `{{ body | sanitize(policy="blog") }}`.
+static SANITIZE_POLICIES: LazyLock<RwLock<HashMap<String, Arc<SanitizePolicy>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Registers `policy` under `name` for the `| sanitize(policy="…")` filter — call
+/// before `build()`. A second registration under the same name replaces the first.
+pub fn register_sanitize_policy(name: impl Into<String>, policy: SanitizePolicy) {
+    if let Ok(mut policies) = SANITIZE_POLICIES.write() {
+        policies.insert(name.into(), Arc::new(policy));
+    }
+}
+
+/// Policy registered under `name`, if any.
+pub fn get_sanitize_policy(name: &str) -> Option<Arc<SanitizePolicy>> {
+    SANITIZE_POLICIES.read().ok()?.get(name).cloned()
+}
+
 // =============================
 // SINGLE ENTRY POINT
 // =============================
@@ -260,6 +440,17 @@ mod tests {
         assert!(!out.contains("<")); // Eveything must be escaped, regardless of the word
     }
 
+    #[test]
+    fn policy_never_allows_script_or_handlers() {
+        let policy = SanitizePolicy::empty()
+            .allow_tags(["b", "script", "SPAN"])
+            .allow_attributes("span", ["class", "onclick", "rel"]);
+        let out = policy.clean(
+            r#"<b>ok</b><script>alert(1)</script><span class="x" onclick="alert(1)">s</span>"#,
+        );
+        assert_eq!(out, r#"<b>ok</b><span class="x">s</span>"#);
+    }
+
     #[test]
     fn sanitize_routing_works() {
         assert_eq!(sanitize("content", "<p>Test</p>"), "<p>Test</p>");
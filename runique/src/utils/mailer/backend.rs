@@ -0,0 +1,264 @@
+//! Mailer backends — `Mailer` trait, SMTP (`lettre`), console and in-memory outbox.
+use crate::utils::mailer::MailerConfig;
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Mailbox, MultiPart, SinglePart},
+    transport::smtp::authentication::Credentials,
+};
+use std::sync::{Arc, Mutex};
+
+/// An email ready to be delivered — built by [`Email::send`](super::Email::send),
+/// sender resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub from: String,
+    pub to: String,
+    pub subject: String,
+    pub html: Option<String>,
+    pub text: Option<String>,
+    pub reply_to: Option<String>,
+}
+
+impl EmailMessage {
+    /// HTML body, or the text body when there is none.
+    pub fn body(&self) -> &str {
+        self.html.as_deref().or(self.text.as_deref()).unwrap_or("")
+    }
+}
+
+/// Delivers emails — installed with `.with_mailer(..)`, `.with_mailer_from_env()`
+/// or `.with_mailer_backend(..)`.
+#[async_trait]
+pub trait Mailer: Send + Sync + 'static {
+    /// Sender used when the email has no `.from(..)`.
+    fn default_from(&self) -> &str;
+
+    async fn deliver(&self, message: EmailMessage) -> Result<(), String>;
+
+    /// Checks that the backend can deliver — used by the `/readyz` probe.
+    async fn ping(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Name in the logs.
+    fn backend(&self) -> &'static str {
+        "custom"
+    }
+}
+
+// ─── SMTP ─────────────────────────────────────────────────────────────────────
+
+/// Real delivery through an SMTP relay.
+#[derive(Debug, Clone)]
+pub struct SmtpMailer {
+    config: MailerConfig,
+}
+
+impl SmtpMailer {
+    pub fn new(config: MailerConfig) -> Self {
+        Self { config }
+    }
+
+    fn transport(&self) -> Result<AsyncSmtpTransport<Tokio1Executor>, String> {
+        let config = &self.config;
+        let creds = Credentials::new(config.username.clone(), config.password.clone());
+        let relay = if config.starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)
+        };
+        Ok(relay
+            .map_err(|e| format!("SMTP connection failed: {e}"))?
+            .port(config.port)
+            .credentials(creds)
+            .build())
+    }
+}
+
+fn mailbox(address: &str, what: &str) -> Result<Mailbox, String> {
+    address
+        .parse::<Mailbox>()
+        .map_err(|e| format!("Invalid {what}: {e}"))
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    fn default_from(&self) -> &str {
+        &self.config.from
+    }
+
+    async fn deliver(&self, message: EmailMessage) -> Result<(), String> {
+        let mut builder = Message::builder()
+            .from(mailbox(&message.from, "sender address")?)
+            .to(mailbox(&message.to, "recipient address")?)
+            .subject(message.subject);
+
+        if let Some(reply_to) = &message.reply_to {
+            builder = builder.reply_to(mailbox(reply_to, "Reply-To")?);
+        }
+
+        // Both bodies: multipart/alternative, the client picks the one it can show
+        let email = match (message.html, message.text) {
+            (Some(html), Some(text)) => {
+                builder.multipart(MultiPart::alternative_plain_html(text, html))
+            }
+            (Some(html), None) => builder.singlepart(SinglePart::html(html)),
+            (None, Some(text)) => builder.singlepart(SinglePart::plain(text)),
+            (None, None) => return Err("Email without content".to_string()),
+        }
+        .map_err(|e| format!("Error constructing email: {e}"))?;
+
+        self.transport()?
+            .send(email)
+            .await
+            .map_err(|e| format!("Error sending email: {e}"))?;
+        Ok(())
+    }
+
+    /// Opens a connection to the relay (`EHLO`, `STARTTLS`, `AUTH`) without sending.
+    async fn ping(&self) -> Result<(), String> {
+        match self.transport()?.test_connection().await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err("SMTP relay refused the connection".to_string()),
+            Err(e) => Err(format!("SMTP connection failed: {e}")),
+        }
+    }
+
+    fn backend(&self) -> &'static str {
+        "smtp"
+    }
+}
+
+// ─── Console ──────────────────────────────────────────────────────────────────
+
+/// Prints the email to stdout — development without an SMTP server.
+#[derive(Debug, Clone)]
+pub struct ConsoleMailer {
+    from: String,
+}
+
+impl ConsoleMailer {
+    pub fn new(from: impl Into<String>) -> Self {
+        Self { from: from.into() }
+    }
+}
+
+#[async_trait]
+impl Mailer for ConsoleMailer {
+    fn default_from(&self) -> &str {
+        &self.from
+    }
+
+    async fn deliver(&self, message: EmailMessage) -> Result<(), String> {
+        println!(
+            "\n{}\n  From:    {}\n  To:      {}\n  Subject: {}{}\n\n{}\n{}",
+            "─".repeat(60),
+            message.from,
+            message.to,
+            message.subject,
+            message
+                .reply_to
+                .as_deref()
+                .map(|r| format!("\n  Reply-To: {r}"))
+                .unwrap_or_default(),
+            message.body(),
+            "─".repeat(60),
+        );
+        Ok(())
+    }
+
+    fn backend(&self) -> &'static str {
+        "console"
+    }
+}
+
+// ─── Memory ───────────────────────────────────────────────────────────────────
+
+/// Keeps sent emails in memory instead of delivering them — for tests.
+/// Clones share the same outbox.
+///
+/// ```rust,ignore
+/// let outbox = MemoryMailer::new();
+/// let app = RuniqueAppBuilder::new(config)
+///     .with_mailer_backend(outbox.clone())
+///     // ...
+/// // POST /forgot-password ...
+/// assert_eq!(outbox.sent_to("alice@example.com").len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MemoryMailer {
+    from: String,
+    outbox: Arc<Mutex<Vec<EmailMessage>>>,
+}
+
+impl Default for MemoryMailer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryMailer {
+    pub fn new() -> Self {
+        Self {
+            from: "noreply@localhost".to_string(),
+            outbox: Arc::default(),
+        }
+    }
+
+    /// Default sender — `noreply@localhost` otherwise.
+    pub fn from(mut self, address: impl Into<String>) -> Self {
+        self.from = address.into();
+        self
+    }
+
+    /// Every email sent, oldest first.
+    pub fn sent(&self) -> Vec<EmailMessage> {
+        self.outbox
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Emails sent to `address`.
+    pub fn sent_to(&self, address: &str) -> Vec<EmailMessage> {
+        self.sent()
+            .into_iter()
+            .filter(|m| m.to.eq_ignore_ascii_case(address))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.outbox.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.outbox
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+#[async_trait]
+impl Mailer for MemoryMailer {
+    fn default_from(&self) -> &str {
+        &self.from
+    }
+
+    async fn deliver(&self, message: EmailMessage) -> Result<(), String> {
+        self.outbox
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(message);
+        Ok(())
+    }
+
+    fn backend(&self) -> &'static str {
+        "memory"
+    }
+}
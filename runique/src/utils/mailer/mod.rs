@@ -1,9 +1,13 @@
-//! E-mail client (`lettre`) — SMTP + console backends, async sending, Tera template support.
-use lettre::{
-    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor, message::header::ContentType,
-    transport::smtp::authentication::Credentials,
+//! E-mail client — `Email` builder, pluggable `Mailer` backends (SMTP, console, memory),
+//! async sending, Tera template support.
+pub mod backend;
+
+pub use backend::{ConsoleMailer, EmailMessage, Mailer, MemoryMailer, SmtpMailer};
+
+use std::{
+    env::var,
+    sync::{Arc, OnceLock, RwLock},
 };
-use std::{env::var, sync::OnceLock};
 
 // ─── Backend ─────────────────────────────────────────────────────────────────
 
@@ -16,6 +20,7 @@ pub enum MailerBackend {
 
 // ─── Config ──────────────────────────────────────────────────────────────────
 
+/// Config given to [`mailer_init`] — unset when the mailer comes from [`set_mailer`].
 pub static MAILER_CONFIG: OnceLock<MailerConfig> = OnceLock::new();
 
 #[derive(Clone)]
@@ -85,8 +90,20 @@ impl MailerConfig {
     }
 }
 
+/// Mailer used by [`Email::send`] — `None` until configured.
+static MAILER: RwLock<Option<Arc<dyn Mailer>>> = RwLock::new(None);
+
+/// Installs the SMTP or console mailer described by `config`.
+/// Ignored if a mailer is already installed.
 pub fn mailer_init(config: MailerConfig) {
-    MAILER_CONFIG.set(config).ok();
+    if mailer_configured() {
+        return;
+    }
+    MAILER_CONFIG.set(config.clone()).ok();
+    match config.backend {
+        MailerBackend::Console => set_mailer(ConsoleMailer::new(config.from)),
+        MailerBackend::Smtp => set_mailer(SmtpMailer::new(config)),
+    }
 }
 
 pub fn mailer_init_from_env() {
@@ -95,13 +112,23 @@ pub fn mailer_init_from_env() {
     }
 }
 
+/// Installs `mailer`, replacing the current one.
+pub fn set_mailer(mailer: impl Mailer) {
+    *MAILER.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(mailer));
+}
+
 pub fn mailer_configured() -> bool {
-    MAILER_CONFIG.get().is_some()
+    MAILER.read().unwrap_or_else(|e| e.into_inner()).is_some()
+}
+
+pub(crate) fn current_mailer() -> Option<Arc<dyn Mailer>> {
+    MAILER.read().unwrap_or_else(|e| e.into_inner()).clone()
 }
 
 // ─── Email builder ────────────────────────────────────────────────────────────
 
 pub struct Email {
+    from: Option<String>,
     to: String,
     subject: String,
     html: Option<String>,
@@ -112,6 +139,7 @@ pub struct Email {
 impl Email {
     pub fn new() -> Self {
         Self {
+            from: None,
             to: String::new(),
             subject: String::new(),
             html: None,
@@ -120,6 +148,12 @@ impl Email {
         }
     }
 
+    /// Sender — the mailer's `from` address by default.
+    pub fn from(mut self, address: impl Into<String>) -> Self {
+        self.from = Some(address.into());
+        self
+    }
+
     pub fn to(mut self, address: impl Into<String>) -> Self {
         self.to = address.into();
         self
@@ -163,20 +197,28 @@ impl Email {
         template_name: &str,
         ctx: tera::Context,
     ) -> Result<Self, String> {
-        let rendered = tera
-            .render(template_name, &ctx)
-            .map_err(|e| format!("Template error ({template_name}): {e}"))?;
-        self.html = Some(rendered);
+        self.html = Some(render(tera, template_name, &ctx)?);
+        Ok(self)
+    }
+
+    /// Same as [`template`](Self::template), for the plain-text body.
+    pub fn text_template(
+        mut self,
+        tera: &tera::Tera,
+        template_name: &str,
+        ctx: tera::Context,
+    ) -> Result<Self, String> {
+        self.text = Some(render(tera, template_name, &ctx)?);
         Ok(self)
     }
 
     pub async fn send(self) -> Result<(), String> {
-        let config = MAILER_CONFIG.get().ok_or_else(|| {
-            if let Some(level) = crate::utils::runique_log::get_log()
-                .mailer
-                .as_ref()
-                .and_then(|m| m.send)
-            {
+        let log_level = crate::utils::runique_log::get_log()
+            .mailer
+            .as_ref()
+            .and_then(|m| m.send);
+        let mailer = current_mailer().ok_or_else(|| {
+            if let Some(level) = log_level {
                 crate::runique_log!(level, to = %self.to, subject = %self.subject, "send — mailer not configured");
             }
             "Mailer not configured — call .with_mailer_from_env() in the builder or set EMAIL_BACKEND/SMTP_* in .env"
@@ -187,112 +229,33 @@ impl Email {
             return Err("Email without content".to_string());
         }
 
-        let backend = match config.backend {
-            MailerBackend::Console => "console",
-            MailerBackend::Smtp => "smtp",
-        };
-        if let Some(level) = crate::utils::runique_log::get_log()
-            .mailer
-            .as_ref()
-            .and_then(|m| m.send)
-        {
+        let backend = mailer.backend();
+        if let Some(level) = log_level {
             crate::runique_log!(level, to = %self.to, subject = %self.subject, backend, "send");
         }
-        let result = match config.backend {
-            MailerBackend::Console => self.send_console(config),
-            MailerBackend::Smtp => self.send_smtp(config).await,
+        let message = EmailMessage {
+            from: self
+                .from
+                .unwrap_or_else(|| mailer.default_from().to_string()),
+            to: self.to,
+            subject: self.subject,
+            html: self.html,
+            text: self.text,
+            reply_to: self.reply_to,
         };
+        let result = mailer.deliver(message).await;
         if let Err(ref e) = result
-            && let Some(level) = crate::utils::runique_log::get_log()
-                .mailer
-                .as_ref()
-                .and_then(|m| m.send)
+            && let Some(level) = log_level
         {
             crate::runique_log!(level, error = %e, backend, "send error");
         }
         result
     }
+}
 
-    fn send_console(self, config: &MailerConfig) -> Result<(), String> {
-        let body = self
-            .html
-            .as_deref()
-            .or(self.text.as_deref())
-            .unwrap_or("(no content)");
-
-        println!(
-            "\n{}\n  From:    {}\n  To:      {}\n  Subject: {}{}\n\n{}\n{}",
-            "─".repeat(60),
-            config.from,
-            self.to,
-            self.subject,
-            self.reply_to
-                .as_deref()
-                .map(|r| format!("\n  Reply-To: {r}"))
-                .unwrap_or_default(),
-            body,
-            "─".repeat(60),
-        );
-        Ok(())
-    }
-
-    async fn send_smtp(self, config: &MailerConfig) -> Result<(), String> {
-        let from = config
-            .from
-            .parse::<lettre::message::Mailbox>()
-            .map_err(|e| format!("Invalid sender address: {e}"))?;
-
-        let to = self
-            .to
-            .parse::<lettre::message::Mailbox>()
-            .map_err(|e| format!("Invalid recipient address: {e}"))?;
-
-        let mut builder = Message::builder().from(from).to(to).subject(self.subject);
-
-        if let Some(reply_to) = &self.reply_to {
-            let rt = reply_to
-                .parse::<lettre::message::Mailbox>()
-                .map_err(|e| format!("Invalid Reply-To: {e}"))?;
-            builder = builder.reply_to(rt);
-        }
-
-        let message = if let Some(html) = self.html {
-            builder
-                .header(ContentType::TEXT_HTML)
-                .body(html)
-                .map_err(|e| format!("Error constructing email: {e}"))?
-        } else if let Some(text) = self.text {
-            builder
-                .header(ContentType::TEXT_PLAIN)
-                .body(text)
-                .map_err(|e| format!("Error constructing email: {e}"))?
-        } else {
-            return Err("Email without content".to_string());
-        };
-
-        let creds = Credentials::new(config.username.clone(), config.password.clone());
-
-        let transport = if config.starttls {
-            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
-                .map_err(|e| format!("SMTP connection failed: {e}"))?
-                .port(config.port)
-                .credentials(creds)
-                .build()
-        } else {
-            AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)
-                .map_err(|e| format!("SMTP connection failed: {e}"))?
-                .port(config.port)
-                .credentials(creds)
-                .build()
-        };
-
-        transport
-            .send(message)
-            .await
-            .map_err(|e| format!("Error sending email: {e}"))?;
-
-        Ok(())
-    }
+fn render(tera: &tera::Tera, template_name: &str, ctx: &tera::Context) -> Result<String, String> {
+    tera.render(template_name, ctx)
+        .map_err(|e| format!("Template error ({template_name}): {e}"))
 }
 
 impl Default for Email {
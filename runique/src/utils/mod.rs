@@ -1,4 +1,4 @@
-//! Cross-cutting framework utilities — type aliases, constants, i18n, password, CSRF, mailer, CLI…
+//! Cross-cutting framework utilities — type aliases, constants, i18n, password, CSRF, mailer, file storage, slugs, CLI…
 pub mod acme;
 pub mod aliases;
 pub mod cli;
@@ -11,6 +11,8 @@ pub mod middleware;
 pub mod password;
 pub mod reset_token;
 pub mod resolve_ogimage;
+pub mod slug;
+pub mod storage;
 
 pub mod trad;
 
@@ -23,9 +25,12 @@ pub use env::is_debug;
 pub use forms::*;
 pub use init_error::init_logging;
 pub use mailer::{
-    Email, MailerConfig, dispatch_email, mailer_configured, mailer_init, mailer_init_from_env,
+    Email, Mailer, MailerConfig, MemoryMailer, dispatch_email, mailer_configured, mailer_init,
+    mailer_init_from_env, set_mailer,
 };
 pub use middleware::*;
 pub use password::*;
 pub use pk::Pk;
 pub use resolve_ogimage::resolve_og_image;
+pub use slug::{slugify, slugify_max};
+pub use storage::{LocalStorage, Storage, StorageError, set_storage};
@@ -0,0 +1,90 @@
+//! `slugify` — URL-friendly slugs (lowercase, ASCII-folded, hyphen-separated).
+
+/// Turns any text into an ASCII slug: accents folded (`é` → `e`, `ß` → `ss`),
+/// lowercase, whitespace/`_`/`-` runs collapsed into a single `-`, other
+/// punctuation dropped, no leading/trailing dash.
+///
+/// ```rust
+/// use runique::utils::slugify;
+/// assert_eq!(slugify("  Hello, Wörld — Été 2024! "), "hello-world-ete-2024");
+/// assert_eq!(slugify("L'œuvre d'art"), "loeuvre-dart");
+/// ```
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    let mut pending_dash = false;
+    for c in text.chars() {
+        let mut buf = [0; 4];
+        let part: &str = if c.is_ascii_alphanumeric() {
+            c.to_ascii_lowercase().encode_utf8(&mut buf)
+        } else if c.is_whitespace() || matches!(c, '-' | '_' | '—' | '–') {
+            pending_dash = true;
+            continue;
+        } else if let Some(folded) = fold_char(c) {
+            folded
+        } else {
+            continue;
+        };
+        if pending_dash && !slug.is_empty() {
+            slug.push('-');
+        }
+        pending_dash = false;
+        slug.push_str(part);
+    }
+    slug
+}
+
+/// Like [`slugify`], cut to at most `max_length` characters on a word boundary
+/// when possible (never ends with a dash).
+pub fn slugify_max(text: &str, max_length: usize) -> String {
+    truncate_slug(&slugify(text), max_length)
+}
+
+/// Cuts an existing slug to `max_length` characters, preferring the last `-`.
+pub(crate) fn truncate_slug(slug: &str, max_length: usize) -> String {
+    if slug.len() <= max_length {
+        return slug.to_string();
+    }
+    // ASCII only: byte index == char index
+    let cut = &slug[..max_length];
+    let cut = match cut.rfind('-') {
+        Some(i) if i > 0 && slug.as_bytes()[max_length] != b'-' => &cut[..i],
+        _ => cut,
+    };
+    cut.trim_end_matches('-').to_string()
+}
+
+/// ASCII replacement for an accented Latin letter, `None` for anything else.
+fn fold_char(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' | 'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å'
+        | 'Ā' | 'Ă' | 'Ą' => "a",
+        'æ' | 'Æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' | 'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => "c",
+        'ď' | 'đ' | 'ð' | 'Ď' | 'Đ' | 'Ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' | 'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ'
+        | 'Ė' | 'Ę' | 'Ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' | 'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => "g",
+        'ĥ' | 'ħ' | 'Ĥ' | 'Ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' | 'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī'
+        | 'Ĭ' | 'Į' | 'İ' => "i",
+        'ĵ' | 'Ĵ' => "j",
+        'ķ' | 'Ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' | 'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' | 'Ñ' | 'Ń' | 'Ņ' | 'Ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' | 'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø'
+        | 'Ō' | 'Ŏ' | 'Ő' => "o",
+        'œ' | 'Œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' | 'Ŕ' | 'Ŗ' | 'Ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'Ś' | 'Ŝ' | 'Ş' | 'Š' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' | 'Ţ' | 'Ť' | 'Ŧ' => "t",
+        'þ' | 'Þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' | 'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ'
+        | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => "u",
+        'ŵ' | 'Ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' | 'Ý' | 'Ŷ' | 'Ÿ' => "y",
+        'ź' | 'ż' | 'ž' | 'Ź' | 'Ż' | 'Ž' => "z",
+        _ => return None,
+    };
+    Some(folded)
+}
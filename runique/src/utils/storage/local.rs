@@ -0,0 +1,80 @@
+//! `LocalStorage` — files under a directory on disk, served by `ServeDir`.
+use crate::config::static_files::resolve_media_root;
+use crate::utils::storage::{Storage, StorageError, clean_path};
+use async_trait::async_trait;
+use std::{io::ErrorKind, path::PathBuf};
+
+/// Files under `root`, public at `base_url` — the default storage, over
+/// `MEDIA_ROOT` / `MEDIA_URL`.
+#[derive(Debug, Clone)]
+pub struct LocalStorage {
+    root: PathBuf,
+    base_url: String,
+}
+
+impl LocalStorage {
+    pub fn new(root: impl Into<PathBuf>, base_url: impl Into<String>) -> Self {
+        Self {
+            root: root.into(),
+            base_url: base_url.into(),
+        }
+    }
+
+    /// `MEDIA_ROOT` (see [`resolve_media_root`]) served at `MEDIA_URL` (`/media`).
+    pub fn from_env() -> Self {
+        let base_url = std::env::var("MEDIA_URL").unwrap_or_else(|_| "/media".to_string());
+        Self::new(resolve_media_root(), base_url)
+    }
+
+    fn resolve(&self, path: &str) -> Result<PathBuf, StorageError> {
+        Ok(self.root.join(clean_path(path)?))
+    }
+}
+
+fn io_error(path: &str, e: std::io::Error) -> StorageError {
+    match e.kind() {
+        ErrorKind::NotFound => StorageError::NotFound(path.to_string()),
+        _ => StorageError::Backend(format!("{path}: {e}")),
+    }
+}
+
+#[async_trait]
+impl Storage for LocalStorage {
+    async fn save(&self, path: &str, bytes: Vec<u8>) -> Result<String, StorageError> {
+        let dest = self.resolve(path)?;
+        if let Some(dir) = dest.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .map_err(|e| io_error(path, e))?;
+        }
+        tokio::fs::write(&dest, bytes)
+            .await
+            .map_err(|e| io_error(path, e))?;
+        Ok(self.url_for(path))
+    }
+
+    async fn open(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+        tokio::fs::read(self.resolve(path)?)
+            .await
+            .map_err(|e| io_error(path, e))
+    }
+
+    async fn delete(&self, path: &str) -> Result<(), StorageError> {
+        match tokio::fs::remove_file(self.resolve(path)?).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(io_error(path, e)),
+            _ => Ok(()),
+        }
+    }
+
+    fn url_for(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    fn local_root(&self) -> Option<PathBuf> {
+        Some(self.root.clone())
+    }
+}
@@ -0,0 +1,141 @@
+//! File storage — `Storage` trait behind `FileField` uploads and `/media` serving:
+//! local disk by default, S3-compatible object storage with the `s3` feature.
+pub mod local;
+#[cfg(feature = "s3")]
+pub mod s3;
+
+pub use local::LocalStorage;
+#[cfg(feature = "s3")]
+pub use s3::{S3Config, S3Storage};
+
+use async_trait::async_trait;
+use std::{
+    fmt,
+    path::PathBuf,
+    sync::{Arc, RwLock},
+};
+
+// ─── Trait ────────────────────────────────────────────────────────────────────
+
+/// Where uploaded files live. Paths are relative (`"avatars/photo.png"`), as stored in
+/// the database by `FileField`.
+#[async_trait]
+pub trait Storage: Send + Sync + 'static {
+    /// Writes `bytes` at `path`, replacing any existing file — returns its URL.
+    async fn save(&self, path: &str, bytes: Vec<u8>) -> Result<String, StorageError>;
+
+    async fn open(&self, path: &str) -> Result<Vec<u8>, StorageError>;
+
+    /// Removes `path` — a missing file is not an error.
+    async fn delete(&self, path: &str) -> Result<(), StorageError>;
+
+    /// Public URL of `path`.
+    fn url_for(&self, path: &str) -> String;
+
+    /// Directory holding the files when they are on this machine: uploads are moved
+    /// there directly and `/media` is served from it. `None` for a remote storage,
+    /// whose uploads go through [`save`](Self::save) and whose `/media` URLs redirect
+    /// to [`url_for`](Self::url_for).
+    fn local_root(&self) -> Option<PathBuf> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageError {
+    NotFound(String),
+    /// Path outside the storage (`..`, absolute, empty).
+    InvalidPath(String),
+    /// I/O or remote service failure.
+    Backend(String),
+}
+
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(path) => write!(f, "file not found: {path}"),
+            Self::InvalidPath(path) => write!(f, "invalid storage path: {path}"),
+            Self::Backend(e) => write!(f, "storage: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
+/// Normalizes a storage path: no leading `/`, no `.` / `..` segment, no backslash.
+pub fn clean_path(path: &str) -> Result<String, StorageError> {
+    let trimmed = path.trim().trim_matches('/');
+    let valid = !trimmed.is_empty()
+        && !trimmed.contains('\\')
+        && trimmed
+            .split('/')
+            .all(|segment| !matches!(segment, "" | "." | ".."));
+    if valid {
+        Ok(trimmed.to_string())
+    } else {
+        Err(StorageError::InvalidPath(path.to_string()))
+    }
+}
+
+/// File operation left by `FileField::finalize` for a remote storage — run by
+/// `Forms::store_files` once the form is valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageOp {
+    /// Uploads the staged file at `path`, then removes it from the staging dir.
+    Save { staged: PathBuf, path: String },
+    /// Removes a file replaced by a new upload.
+    Delete(String),
+}
+
+impl StorageOp {
+    pub async fn run(self, storage: &dyn Storage) -> Result<(), StorageError> {
+        match self {
+            Self::Save { staged, path } => {
+                let bytes = tokio::fs::read(&staged)
+                    .await
+                    .map_err(|e| StorageError::Backend(format!("{}: {e}", staged.display())))?;
+                storage.save(&path, bytes).await?;
+                if let Err(e) = tokio::fs::remove_file(&staged).await {
+                    tracing::warn!(path = %staged.display(), error = %e, "staged upload removal failed");
+                }
+            }
+            Self::Delete(path) => storage.delete(&path).await?,
+        }
+        Ok(())
+    }
+}
+
+// ─── Installed storage ────────────────────────────────────────────────────────
+
+/// Storage set with `.with_storage(..)` — `None`: `LocalStorage::from_env()`.
+static STORAGE: RwLock<Option<Arc<dyn Storage>>> = RwLock::new(None);
+
+/// Installs `storage`, replacing the current one.
+pub fn set_storage(storage: Arc<dyn Storage>) {
+    *STORAGE.write().unwrap_or_else(|e| e.into_inner()) = Some(storage);
+}
+
+/// The installed storage, or the local one over `MEDIA_ROOT` / `MEDIA_URL`.
+pub fn storage() -> Arc<dyn Storage> {
+    STORAGE
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_else(|| Arc::new(LocalStorage::from_env()))
+}
+
+/// Storage described by `STORAGE_BACKEND` (`local` by default, `s3` with the `s3`
+/// feature and the `S3_*` variables) — `Ok(None)` for the local default.
+pub fn storage_from_env() -> Result<Option<Arc<dyn Storage>>, String> {
+    match std::env::var("STORAGE_BACKEND")
+        .as_deref()
+        .unwrap_or("local")
+    {
+        "local" => Ok(None),
+        #[cfg(feature = "s3")]
+        "s3" => Ok(Some(Arc::new(S3Storage::new(S3Config::from_env()?)))),
+        #[cfg(not(feature = "s3"))]
+        "s3" => Err("STORAGE_BACKEND=s3 requires the `s3` feature of runique".to_string()),
+        other => Err(format!("unknown STORAGE_BACKEND: {other}")),
+    }
+}
@@ -0,0 +1,329 @@
+//! `S3Storage` — S3-compatible object storage (AWS, MinIO, R2…) over its REST API,
+//! requests signed with AWS Signature V4.
+use crate::utils::storage::{Storage, StorageError, clean_path};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, KeyInit, Mac};
+use reqwest::{Method, StatusCode};
+use sha2::{Digest, Sha256};
+use std::{env::var, fmt, time::Duration};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+
+/// Bucket, credentials and URL policy.
+///
+/// Environment (`S3Config::from_env`): `S3_BUCKET`, `S3_REGION` (`us-east-1`),
+/// `S3_ACCESS_KEY_ID`, `S3_SECRET_ACCESS_KEY`, `S3_ENDPOINT`, `S3_PATH_STYLE`,
+/// `S3_PUBLIC_URL`, `S3_URL_EXPIRY` (seconds).
+#[derive(Clone)]
+pub struct S3Config {
+    pub bucket: String,
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    /// Service URL — `https://s3.{region}.amazonaws.com` when `None`.
+    pub endpoint: Option<String>,
+    /// `{endpoint}/{bucket}/{key}` instead of `{bucket}.{endpoint host}/{key}` —
+    /// for MinIO and most self-hosted services.
+    pub path_style: bool,
+    /// Public base URL of the objects (CDN, public bucket) used by `url_for`.
+    pub public_url: Option<String>,
+    /// `url_for` returns presigned URLs valid this long — for a private bucket.
+    pub url_expiry: Option<Duration>,
+}
+
+// Manual Debug: never print the secret key (leak through logs).
+impl fmt::Debug for S3Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("S3Config")
+            .field("bucket", &self.bucket)
+            .field("region", &self.region)
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"***")
+            .field("endpoint", &self.endpoint)
+            .field("path_style", &self.path_style)
+            .field("public_url", &self.public_url)
+            .field("url_expiry", &self.url_expiry)
+            .finish()
+    }
+}
+
+impl S3Config {
+    pub fn new(
+        bucket: impl Into<String>,
+        region: impl Into<String>,
+        access_key_id: impl Into<String>,
+        secret_access_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            bucket: bucket.into(),
+            region: region.into(),
+            access_key_id: access_key_id.into(),
+            secret_access_key: secret_access_key.into(),
+            endpoint: None,
+            path_style: false,
+            public_url: None,
+            url_expiry: None,
+        }
+    }
+
+    pub fn endpoint(mut self, url: impl Into<String>) -> Self {
+        self.endpoint = Some(url.into());
+        self
+    }
+
+    pub fn path_style(mut self) -> Self {
+        self.path_style = true;
+        self
+    }
+
+    pub fn public_url(mut self, url: impl Into<String>) -> Self {
+        self.public_url = Some(url.into());
+        self
+    }
+
+    pub fn signed_urls(mut self, expiry: Duration) -> Self {
+        self.url_expiry = Some(expiry);
+        self
+    }
+
+    pub fn from_env() -> Result<Self, String> {
+        let required = |name: &str| var(name).map_err(|_| format!("{name} is not set"));
+        let mut config = Self::new(
+            required("S3_BUCKET")?,
+            var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+            required("S3_ACCESS_KEY_ID")?,
+            required("S3_SECRET_ACCESS_KEY")?,
+        );
+        config.endpoint = var("S3_ENDPOINT").ok();
+        config.path_style = var("S3_PATH_STYLE").is_ok_and(|v| v == "true");
+        config.public_url = var("S3_PUBLIC_URL").ok();
+        config.url_expiry = match var("S3_URL_EXPIRY") {
+            Ok(secs) => Some(Duration::from_secs(secs.parse().map_err(|_| {
+                format!("S3_URL_EXPIRY: invalid number of seconds: {secs}")
+            })?)),
+            Err(_) => None,
+        };
+        Ok(config)
+    }
+}
+
+/// Objects in an S3 bucket. `/media/{path}` redirects to [`url_for`](Storage::url_for).
+///
+/// ```rust,ignore
+/// let s3 = S3Config::new("my-bucket", "eu-west-3", key_id, secret)
+///     .signed_urls(Duration::from_secs(3600));
+/// RuniqueApp::builder(config).with_storage(S3Storage::new(s3))
+/// ```
+#[derive(Debug, Clone)]
+pub struct S3Storage {
+    config: S3Config,
+    client: reqwest::Client,
+}
+
+/// Request target: scheme + host, and the URI-encoded path.
+struct Target {
+    origin: String,
+    host: String,
+    path: String,
+}
+
+impl S3Storage {
+    pub fn new(config: S3Config) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    fn target(&self, key: &str) -> Target {
+        let endpoint = self
+            .config
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| format!("https://s3.{}.amazonaws.com", self.config.region));
+        let endpoint = endpoint.trim_end_matches('/');
+        let (scheme, host) = endpoint.split_once("://").unwrap_or(("https", endpoint));
+        let key = encode_key(key);
+        if self.config.path_style {
+            Target {
+                origin: format!("{scheme}://{host}"),
+                host: host.to_string(),
+                path: format!("/{}/{key}", self.config.bucket),
+            }
+        } else {
+            let host = format!("{}.{host}", self.config.bucket);
+            Target {
+                origin: format!("{scheme}://{host}"),
+                host,
+                path: format!("/{key}"),
+            }
+        }
+    }
+
+    fn scope(&self, at: DateTime<Utc>) -> String {
+        format!(
+            "{}/{}/s3/aws4_request",
+            at.format("%Y%m%d"),
+            self.config.region
+        )
+    }
+
+    fn signature(&self, at: DateTime<Utc>, canonical_request: &str) -> String {
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            at.format("%Y%m%dT%H%M%SZ"),
+            self.scope(at),
+            hex::encode(Sha256::digest(canonical_request))
+        );
+        let key = [
+            at.format("%Y%m%d").to_string().as_str(),
+            &self.config.region,
+            "s3",
+            "aws4_request",
+        ]
+        .iter()
+        .fold(
+            format!("AWS4{}", self.config.secret_access_key).into_bytes(),
+            |key, part| hmac(&key, part),
+        );
+        hex::encode(hmac(&key, &string_to_sign))
+    }
+
+    /// Presigned GET URL of `path`, valid `expires` from `at`.
+    pub fn presigned_url_at(&self, path: &str, expires: Duration, at: DateTime<Utc>) -> String {
+        let target = self.target(path.trim_start_matches('/'));
+        let credential = format!("{}/{}", self.config.access_key_id, self.scope(at));
+        // Already sorted by name, as the canonical query requires
+        let query = format!(
+            "X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential={}&X-Amz-Date={}&X-Amz-Expires={}&X-Amz-SignedHeaders=host",
+            urlencoding::encode(&credential),
+            at.format("%Y%m%dT%H%M%SZ"),
+            expires.as_secs()
+        );
+        let canonical_request = format!(
+            "GET\n{}\n{query}\nhost:{}\n\nhost\n{UNSIGNED_PAYLOAD}",
+            target.path, target.host
+        );
+        let signature = self.signature(at, &canonical_request);
+        format!(
+            "{}{}?{query}&X-Amz-Signature={signature}",
+            target.origin, target.path
+        )
+    }
+
+    /// Presigned GET URL of `path`, valid `expires` from now.
+    pub fn presigned_url(&self, path: &str, expires: Duration) -> String {
+        self.presigned_url_at(path, expires, Utc::now())
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        key: &str,
+        body: Vec<u8>,
+    ) -> Result<reqwest::Response, StorageError> {
+        let target = self.target(key);
+        let at = Utc::now();
+        let amz_date = at.format("%Y%m%dT%H%M%SZ").to_string();
+        let payload_hash = hex::encode(Sha256::digest(&body));
+        let canonical_request = format!(
+            "{method}\n{}\n\nhost:{}\nx-amz-content-sha256:{payload_hash}\nx-amz-date:{amz_date}\n\nhost;x-amz-content-sha256;x-amz-date\n{payload_hash}",
+            target.path, target.host
+        );
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders=host;x-amz-content-sha256;x-amz-date, Signature={}",
+            self.config.access_key_id,
+            self.scope(at),
+            self.signature(at, &canonical_request)
+        );
+
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", target.origin, target.path))
+            .header("x-amz-content-sha256", payload_hash)
+            .header("x-amz-date", amz_date)
+            .header("authorization", authorization);
+        if !body.is_empty() {
+            let mime = mime_guess::from_path(key).first_or_octet_stream();
+            request = request.header("content-type", mime.as_ref()).body(body);
+        }
+        request
+            .send()
+            .await
+            .map_err(|e| StorageError::Backend(format!("S3 {key}: {e}")))
+    }
+}
+
+fn hmac(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// URI-encodes each segment of an object key, keeping the `/` separators.
+fn encode_key(key: &str) -> String {
+    key.split('/')
+        .map(|segment| urlencoding::encode(segment).into_owned())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+async fn status_error(key: &str, response: reqwest::Response) -> StorageError {
+    let status = response.status();
+    if status == StatusCode::NOT_FOUND {
+        return StorageError::NotFound(key.to_string());
+    }
+    let body = response.text().await.unwrap_or_default();
+    StorageError::Backend(format!("S3 {key}: {status} {body}"))
+}
+
+#[async_trait]
+impl Storage for S3Storage {
+    async fn save(&self, path: &str, bytes: Vec<u8>) -> Result<String, StorageError> {
+        let key = clean_path(path)?;
+        let response = self.request(Method::PUT, &key, bytes).await?;
+        if !response.status().is_success() {
+            return Err(status_error(&key, response).await);
+        }
+        Ok(self.url_for(&key))
+    }
+
+    async fn open(&self, path: &str) -> Result<Vec<u8>, StorageError> {
+        let key = clean_path(path)?;
+        let response = self.request(Method::GET, &key, Vec::new()).await?;
+        if !response.status().is_success() {
+            return Err(status_error(&key, response).await);
+        }
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| StorageError::Backend(format!("S3 {key}: {e}")))
+    }
+
+    async fn delete(&self, path: &str) -> Result<(), StorageError> {
+        let key = clean_path(path)?;
+        let response = self.request(Method::DELETE, &key, Vec::new()).await?;
+        match response.status() {
+            status if status.is_success() || status == StatusCode::NOT_FOUND => Ok(()),
+            _ => Err(status_error(&key, response).await),
+        }
+    }
+
+    fn url_for(&self, path: &str) -> String {
+        let key = path.trim_start_matches('/');
+        if let Some(base) = &self.config.public_url {
+            return format!("{}/{}", base.trim_end_matches('/'), encode_key(key));
+        }
+        match self.config.url_expiry {
+            Some(expires) => self.presigned_url(key, expires),
+            None => {
+                let target = self.target(key);
+                format!("{}{}", target.origin, target.path)
+            }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! Project translation catalogs — `<locale>.json` / `<locale>.po` files of `LOCALE_DIR`,
+//! looked up by `{% trans %}` and by views through the active locale.
+use crate::config::I18nConfig;
+use serde_json::Value;
+use std::{collections::HashMap, fs};
+
+/// Translations of every locale, with the fallback chain
+/// active locale → default locale → the key itself.
+#[derive(Debug, Clone, Default)]
+pub struct Catalogs {
+    default_locale: String,
+    messages: HashMap<String, HashMap<String, String>>,
+}
+
+impl Catalogs {
+    /// Empty catalogs — every lookup falls back to the key.
+    pub fn new(default_locale: impl Into<String>) -> Self {
+        Self {
+            default_locale: default_locale.into(),
+            messages: HashMap::new(),
+        }
+    }
+
+    /// Loads every `<locale>.json` and `<locale>.po` of `config.locale_dir`.
+    /// A missing directory gives empty catalogs; a malformed file is an error.
+    pub fn load(config: &I18nConfig) -> Result<Self, String> {
+        let mut catalogs = Self::new(config.default_locale.clone());
+        let Ok(entries) = fs::read_dir(&config.locale_dir) else {
+            return Ok(catalogs);
+        };
+        let mut paths: Vec<_> = entries.flatten().map(|e| e.path()).collect();
+        paths.sort();
+        for path in paths {
+            let (Some(locale), Some(ext)) = (
+                path.file_stem().and_then(|s| s.to_str()),
+                path.extension().and_then(|s| s.to_str()),
+            ) else {
+                continue;
+            };
+            let parsed = match ext {
+                "json" => fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| parse_json(&content)),
+                "po" => fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| parse_po(&content)),
+                _ => continue,
+            };
+            let messages = parsed.map_err(|e| format!("{}: {e}", path.display()))?;
+            catalogs.extend(locale, messages);
+        }
+        Ok(catalogs)
+    }
+
+    /// Adds translations to `locale` — later keys replace earlier ones.
+    pub fn extend(&mut self, locale: &str, messages: impl IntoIterator<Item = (String, String)>) {
+        self.messages
+            .entry(locale.to_string())
+            .or_default()
+            .extend(messages);
+    }
+
+    pub fn default_locale(&self) -> &str {
+        &self.default_locale
+    }
+
+    /// Locales with a catalog, plus the default locale.
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        let default = (!self.messages.contains_key(&self.default_locale))
+            .then_some(self.default_locale.as_str());
+        self.messages.keys().map(String::as_str).chain(default)
+    }
+
+    /// Translation of `key` in `locale` only — no fallback.
+    pub fn lookup(&self, locale: &str, key: &str) -> Option<&str> {
+        self.messages.get(locale)?.get(key).map(String::as_str)
+    }
+
+    /// Translation of `key` in `locale`, else in the default locale, else `key` itself.
+    pub fn translate<'a>(&'a self, locale: &str, key: &'a str) -> &'a str {
+        self.lookup(locale, key)
+            .or_else(|| self.lookup(&self.default_locale, key))
+            .unwrap_or(key)
+    }
+
+    /// The known locale matching `tag` (`fr-FR`, `fr_fr`, `FR`) — exact match first,
+    /// then the primary language (`fr-FR` → `fr`).
+    pub fn supported(&self, tag: &str) -> Option<&str> {
+        let tag = tag.trim().replace('_', "-").to_lowercase();
+        if tag.is_empty() {
+            return None;
+        }
+        let find = |wanted: &str| {
+            self.locales()
+                .find(|locale| locale.replace('_', "-").eq_ignore_ascii_case(wanted))
+        };
+        find(&tag).or_else(|| find(tag.split('-').next()?))
+    }
+
+    /// Best known locale of an `Accept-Language` header, by descending `q`.
+    pub fn negotiate(&self, accept_language: &str) -> Option<&str> {
+        let mut ranges: Vec<(&str, f32)> = accept_language
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';');
+                let tag = parts.next()?.trim();
+                let q = parts
+                    .find_map(|p| p.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+                (q > 0.0 && tag != "*").then_some((tag, q))
+            })
+            .collect();
+        // Stable: equal weights keep the header order
+        ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranges.into_iter().find_map(|(tag, _)| self.supported(tag))
+    }
+}
+
+/// Flattens a (possibly nested) JSON object into dotted keys: `{"nav": {"home": ".."}}` → `nav.home`.
+fn parse_json(content: &str) -> Result<HashMap<String, String>, String> {
+    fn flatten(
+        prefix: &str,
+        value: &Value,
+        out: &mut HashMap<String, String>,
+    ) -> Result<(), String> {
+        match value {
+            Value::String(s) => {
+                out.insert(prefix.to_string(), s.clone());
+            }
+            Value::Object(map) => {
+                for (key, value) in map {
+                    let key = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{prefix}.{key}")
+                    };
+                    flatten(&key, value, out)?;
+                }
+            }
+            _ => return Err(format!("`{prefix}`: expected a string or an object")),
+        }
+        Ok(())
+    }
+
+    let value: Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    if !value.is_object() {
+        return Err("expected a JSON object".to_string());
+    }
+    let mut out = HashMap::new();
+    flatten("", &value, &mut out)?;
+    Ok(out)
+}
+
+/// Reads the `msgid` / `msgstr` pairs of a gettext `.po` file. Untranslated and
+/// `fuzzy` entries are skipped (they fall back); plural entries keep `msgstr[0]`.
+fn parse_po(content: &str) -> Result<HashMap<String, String>, String> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Field {
+        None,
+        Id,
+        Str,
+        Ignored,
+    }
+
+    #[derive(Default)]
+    struct Entry {
+        id: String,
+        text: String,
+        fuzzy: bool,
+        /// `msgstr` seen: a comment, `msgctxt` or `msgid` starts the next entry
+        complete: bool,
+    }
+
+    impl Entry {
+        fn finish(self, out: &mut HashMap<String, String>) {
+            if !self.fuzzy && !self.id.is_empty() && !self.text.is_empty() {
+                out.insert(self.id, self.text);
+            }
+        }
+    }
+
+    let mut out = HashMap::new();
+    let mut entry = Entry::default();
+    let mut field = Field::None;
+
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let error = |msg: &str| format!("line {}: {msg}", number + 1);
+
+        let (keyword, rest) = match line.split_once(char::is_whitespace) {
+            Some((keyword, rest)) if !line.starts_with(['"', '#']) => (keyword, rest.trim()),
+            _ => ("", line),
+        };
+        let starts_entry = line.starts_with('#') || matches!(keyword, "msgctxt" | "msgid");
+        if starts_entry && entry.complete {
+            std::mem::take(&mut entry).finish(&mut out);
+            field = Field::None;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            entry.fuzzy |= comment.starts_with(',') && comment.contains("fuzzy");
+            continue;
+        }
+
+        let value = unquote(rest).ok_or_else(|| error("expected a quoted string"))?;
+        field = match keyword {
+            "msgid" => {
+                entry.id = value;
+                Field::Id
+            }
+            "msgstr" | "msgstr[0]" => {
+                entry.text = value;
+                entry.complete = true;
+                Field::Str
+            }
+            "msgctxt" | "msgid_plural" => Field::Ignored,
+            k if k.starts_with("msgstr[") => {
+                entry.complete = true;
+                Field::Ignored
+            }
+            "" => {
+                match field {
+                    Field::Id => entry.id.push_str(&value),
+                    Field::Str => entry.text.push_str(&value),
+                    Field::Ignored => {}
+                    Field::None => return Err(error("string outside of an entry")),
+                }
+                field
+            }
+            other => return Err(error(&format!("unknown keyword `{other}`"))),
+        };
+    }
+    entry.finish(&mut out);
+    Ok(out)
+}
+
+/// `"a \"b\"\n"` → `a "b"` + newline.
+fn unquote(s: &str) -> Option<String> {
+    let inner = s.strip_prefix('"')?.strip_suffix('"')?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => out.push('\n'),
+            't' => out.push('\t'),
+            'r' => out.push('\r'),
+            other => out.push(other),
+        }
+    }
+    Some(out)
+}
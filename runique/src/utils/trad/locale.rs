@@ -0,0 +1,54 @@
+//! Active locale of a request — URL prefix, then session, then `Accept-Language`,
+//! then the default locale of the [`Catalogs`].
+use crate::utils::constante::session_key::session::SESSION_LOCALE_KEY;
+use crate::utils::trad::Catalogs;
+use axum::http::{HeaderMap, header};
+use tower_sessions::Session;
+
+/// Locale of the request, among the known locales:
+/// 1. first path segment (`/fr/about` → `fr`);
+/// 2. session value set by [`set_locale`];
+/// 3. `Accept-Language` header;
+/// 4. default locale.
+pub async fn resolve_locale(
+    catalogs: &Catalogs,
+    path: &str,
+    headers: &HeaderMap,
+    session: &Session,
+) -> String {
+    let from_path = path
+        .trim_start_matches('/')
+        .split('/')
+        .next()
+        .and_then(|segment| catalogs.supported(segment));
+    if let Some(locale) = from_path {
+        return locale.to_string();
+    }
+    if let Ok(Some(stored)) = session.get::<String>(SESSION_LOCALE_KEY).await
+        && let Some(locale) = catalogs.supported(&stored)
+    {
+        return locale.to_string();
+    }
+    headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| catalogs.negotiate(value))
+        .unwrap_or(catalogs.default_locale())
+        .to_string()
+}
+
+/// Stores the visitor's choice (language switcher) — used by the following requests
+/// unless the URL carries a locale prefix.
+///
+/// ```rust,ignore
+/// async fn switch(req: Request, Path(lang): Path<String>) -> impl IntoResponse {
+///     set_locale(&req.session, &lang).await.ok();
+///     Redirect::to("/")
+/// }
+/// ```
+pub async fn set_locale(
+    session: &Session,
+    locale: &str,
+) -> Result<(), tower_sessions::session::Error> {
+    session.insert(SESSION_LOCALE_KEY, locale).await
+}
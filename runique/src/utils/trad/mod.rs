@@ -1,5 +1,10 @@
-//! Internationalization — global language selection (`set_lang`), `t()` / `tf()` helpers.
+//! Internationalization — global language selection (`set_lang`), `t()` / `tf()` helpers,
+//! project catalogs (`Catalogs`) and per-request locale resolution.
+pub mod catalog;
+pub mod locale;
 pub mod switch_lang;
 
+pub use catalog::Catalogs;
+pub use locale::{resolve_locale, set_locale};
 pub use switch_lang::{Lang, current_lang, set_lang};
 pub use switch_lang::{t, tf};
@@ -84,6 +84,15 @@
       return;
     }
 
+    if (action === "custom") {
+      const select = document.getElementById("bulk-custom-select");
+      if (!select || !select.value) return;
+      actionInput.value = select.value;
+      idsInput.value = collectIds();
+      bulkForm.submit();
+      return;
+    }
+
     actionInput.value = action;
     idsInput.value = collectIds();
     bulkForm.submit();
@@ -0,0 +1,94 @@
+/**
+ * Runique AutocompleteField — search box replacing a large <select>.
+ * Typing queries data-autocomplete (?q=term, debounced) for [{id, label}];
+ * picking a result stores its id in the hidden input. Clearing the box clears
+ * the id. Bound through data-autocomplete attributes, safe for CSP (no inline JS).
+ */
+(function () {
+    var DELAY = 250;
+
+    function initAutocomplete(container) {
+        if (container.hasAttribute('data-autocomplete-ready')) return;
+        container.setAttribute('data-autocomplete-ready', '');
+        var url = container.getAttribute('data-autocomplete');
+        var hidden = container.querySelector('[data-autocomplete-value]');
+        var search = container.querySelector('[data-autocomplete-search]');
+        var results = container.querySelector('[data-autocomplete-results]');
+        if (!url || !hidden || !search || !results) return;
+        var timer = null;
+        var pending = null;
+
+        function close() {
+            results.hidden = true;
+            results.innerHTML = '';
+            search.setAttribute('aria-expanded', 'false');
+        }
+
+        function pick(item) {
+            hidden.value = String(item.id);
+            search.value = item.label;
+            close();
+            hidden.dispatchEvent(new Event('change', { bubbles: true }));
+        }
+
+        function show(items) {
+            results.innerHTML = '';
+            items.forEach(function (item) {
+                var li = document.createElement('li');
+                li.setAttribute('role', 'option');
+                li.className = 'autocomplete-results__item';
+                li.textContent = item.label;
+                li.addEventListener('mousedown', function (e) {
+                    e.preventDefault();
+                    pick(item);
+                });
+                results.appendChild(li);
+            });
+            results.hidden = items.length === 0;
+            search.setAttribute('aria-expanded', items.length ? 'true' : 'false');
+        }
+
+        function query(term) {
+            if (pending) pending.abort();
+            pending = new AbortController();
+            var sep = url.indexOf('?') === -1 ? '?' : '&';
+            fetch(url + sep + 'q=' + encodeURIComponent(term), {
+                headers: { 'Accept': 'application/json' },
+                credentials: 'same-origin',
+                signal: pending.signal
+            })
+                .then(function (r) { return r.ok ? r.json() : []; })
+                .then(function (items) { show(Array.isArray(items) ? items : []); })
+                .catch(function () {});
+        }
+
+        search.addEventListener('input', function () {
+            // Typing invalidates the previous choice until a result is picked
+            hidden.value = '';
+            clearTimeout(timer);
+            var term = search.value.trim();
+            if (!term) {
+                close();
+                return;
+            }
+            timer = setTimeout(function () { query(term); }, DELAY);
+        });
+        search.addEventListener('focus', function () {
+            if (!search.value.trim()) query('');
+        });
+        search.addEventListener('blur', close);
+        search.addEventListener('keydown', function (e) {
+            if (e.key === 'Escape') close();
+        });
+    }
+
+    function initAll() {
+        document.querySelectorAll('[data-autocomplete]').forEach(initAutocomplete);
+    }
+
+    if (document.readyState === 'loading') {
+        document.addEventListener('DOMContentLoaded', initAll);
+    } else {
+        initAll();
+    }
+})();
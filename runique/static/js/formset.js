@@ -0,0 +1,42 @@
+/**
+ * Runique FormSet — "add another" button of a formset.
+ * Clones the blank row template, replaces the __prefix__ index and bumps
+ * {prefix}-TOTAL_FORMS. Triggered by data-formset attributes, safe for CSP (no inline JS).
+ */
+(function () {
+    function initFormset(container) {
+        var prefix = container.getAttribute('data-formset');
+        var total = container.querySelector('input[name="' + prefix + '-TOTAL_FORMS"]');
+        var rows = container.querySelector('[data-formset-rows]');
+        var template = container.querySelector('template[data-formset-empty]');
+        var button = container.querySelector('[data-formset-add]');
+        if (!total || !rows || !template || !button) return;
+        var max = parseInt(container.getAttribute('data-formset-max'), 10);
+
+        function refresh() {
+            button.disabled = !isNaN(max) && parseInt(total.value, 10) >= max;
+        }
+
+        button.addEventListener('click', function () {
+            var index = parseInt(total.value, 10) || 0;
+            if (!isNaN(max) && index >= max) return;
+            rows.insertAdjacentHTML(
+                'beforeend',
+                template.innerHTML.replace(/__prefix__/g, String(index))
+            );
+            total.value = String(index + 1);
+            refresh();
+        });
+        refresh();
+    }
+
+    function initAll() {
+        document.querySelectorAll('[data-formset]').forEach(initFormset);
+    }
+
+    if (document.readyState === 'loading') {
+        document.addEventListener('DOMContentLoaded', initAll);
+    } else {
+        initAll();
+    }
+})();
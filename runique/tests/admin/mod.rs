@@ -1,5 +1,13 @@
 pub mod test_admin_nested_scope;
 pub mod test_admin_registry;
+pub mod test_autocomplete;
+pub mod test_bulk_action;
+pub mod test_csv_export;
+pub mod test_edit_password;
 pub mod test_form_filter;
 pub mod test_form_renderer;
+pub mod test_history;
+pub mod test_inline_formset;
+pub mod test_list_filter;
+pub mod test_readonly_computed;
 pub mod test_url_registry;
@@ -0,0 +1,362 @@
+//! Tests — admin/admin_main/handle_autocomplete.rs : FK éditée par une boîte de recherche
+//! Couvre : endpoint JSON `{id, label}` (recherche insensible à la casse, label_field),
+//!          droits (formulaire de la ressource + lecture de la cible, sinon 403),
+//!          champ inconnu 404, boîte à la place du select avec le libellé choisi,
+//!          id inexistant refusé à l'enregistrement
+
+use std::sync::{Arc, LazyLock, Mutex};
+
+use axum::extract::{Path, Query};
+use axum::http::{HeaderMap, Method, StatusCode};
+use axum::{Extension, response::Response};
+use runique::admin::helper::DynForm;
+use runique::admin::helper::resource_entry::{
+    AutocompleteFn, FormBuilder, GetFn, ResourceEntry, UpdateFn,
+};
+use runique::admin::permissions::{Groupe, Permission};
+use runique::admin::registry::AdminRegistry;
+use runique::admin::resource::AdminResource;
+use runique::admin::{AdminConfig, PrototypeAdminState, admin_get, admin_get_id, admin_post_id};
+use runique::app::LiveTera;
+use runique::auth::session::CurrentUser;
+use runique::engine::RuniqueEngine;
+use runique::forms::{field::RuniqueForm, fields::text::TextField, form::Forms};
+use runique::utils::aliases::{ADb, ATera, StrMap};
+use runique::utils::constante::FIELD_TEMPLATES;
+use sea_orm::{ConnectionTrait, EntityTrait, QuerySelect};
+use serde_json::{Value, json};
+use tera::Tera;
+
+use crate::helpers::{assert::body_str, request::build_handler_req, server::build_engine};
+
+mod author {
+    use sea_orm::entity::prelude::*;
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+    #[sea_orm(table_name = "authors")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+struct ArticleForm {
+    form: Forms,
+}
+
+impl RuniqueForm for ArticleForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&TextField::text("title"));
+        form.field(&TextField::text("author_id").label("Auteur"));
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+struct ArticleDynForm(ArticleForm);
+
+#[async_trait::async_trait]
+impl DynForm for ArticleDynForm {
+    async fn is_valid(&mut self) -> bool {
+        self.0.is_valid().await
+    }
+    async fn save(&mut self, _db: &sea_orm::DatabaseConnection) -> Result<(), sea_orm::DbErr> {
+        Ok(())
+    }
+    fn get_form(&self) -> &Forms {
+        self.0.get_form()
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        self.0.get_form_mut()
+    }
+}
+
+/// Données reçues par `update_fn`, par id d'article.
+static SAVED: LazyLock<Mutex<Vec<(String, StrMap)>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+fn articles_entry() -> ResourceEntry {
+    let meta = AdminResource::new("articles", "M", "F", "Articles", vec![])
+        .autocomplete("author_id", "authors");
+    let form_builder: FormBuilder = Arc::new(
+        |_db: ADb, _keys, data: StrMap, tera: ATera, csrf: String, method: Method| {
+            Box::pin(async move {
+                let form = ArticleForm::build_with_data(&data, tera, &csrf, method).await;
+                Box::new(ArticleDynForm(form)) as Box<dyn DynForm>
+            })
+        },
+    );
+    let get_fn: GetFn = Arc::new(|_, id| {
+        Box::pin(async move { Ok(Some(json!({ "id": id, "title": "Titre", "author_id": 2 }))) })
+    });
+    let update_fn: UpdateFn = Arc::new(|_, id, data| {
+        Box::pin(async move {
+            SAVED.lock().unwrap().push((id, data));
+            Ok(())
+        })
+    });
+    ResourceEntry::new(meta, form_builder)
+        .with_get_fn(get_fn)
+        .with_update_fn(update_fn)
+}
+
+/// Closures telles que générées par le daemon pour une cible d'autocomplete.
+fn authors_entry() -> ResourceEntry {
+    let meta = AdminResource::new("authors", "M", "F", "Auteurs", vec![]).label_field("name");
+    let form_builder: FormBuilder = Arc::new(|_, _, _, _, _, _| Box::pin(async { unreachable!() }));
+    let get_fn: GetFn = Arc::new(|db: ADb, id: String| {
+        Box::pin(async move {
+            let id = id
+                .parse::<i32>()
+                .map_err(|_| sea_orm::DbErr::Custom("invalid id".to_string()))?;
+            let row = author::Entity::find_by_id(id).one(&*db).await?;
+            Ok(row.map(|r| serde_json::to_value(r).unwrap_or(Value::Null)))
+        })
+    });
+    let autocomplete_fn: AutocompleteFn =
+        Arc::new(|db: ADb, term: String, label: String, limit: u64| {
+            Box::pin(async move {
+                use sea_orm::{
+                    QueryFilter,
+                    sea_query::{Alias, Expr, ExprTrait, Func},
+                };
+                let mut query = author::Entity::find();
+                if !term.is_empty() {
+                    let text = Expr::col(Alias::new(label.as_str())).cast_as(Alias::new("TEXT"));
+                    query = query.filter(
+                        Expr::expr(Func::lower(text)).like(format!("%{}%", term.to_lowercase())),
+                    );
+                }
+                let rows = query.limit(limit).all(&*db).await?;
+                Ok(rows
+                    .into_iter()
+                    .map(|r| {
+                        let v = serde_json::to_value(r).unwrap_or_default();
+                        let id = runique::admin::fk_key(&v["id"]).unwrap_or_default();
+                        let text = runique::admin::fk_key(&v[label.as_str()])
+                            .unwrap_or_else(|| id.clone());
+                        (id, text)
+                    })
+                    .collect())
+            })
+        });
+    ResourceEntry::new(meta, form_builder)
+        .with_get_fn(get_fn)
+        .with_autocomplete_fn(autocomplete_fn)
+}
+
+fn state() -> Arc<PrototypeAdminState> {
+    let mut registry = AdminRegistry::new();
+    registry.register(articles_entry());
+    registry.register(authors_entry());
+    Arc::new(PrototypeAdminState {
+        registry: Arc::new(registry),
+        config: Arc::new(AdminConfig::new()),
+    })
+}
+
+fn root() -> CurrentUser {
+    CurrentUser {
+        id: 1,
+        username: "root".into(),
+        is_staff: true,
+        is_superuser: true,
+        groupes: vec![],
+    }
+}
+
+fn perm(resource: &str, can_create: bool, can_read: bool) -> Permission {
+    Permission {
+        resource_key: resource.to_string(),
+        can_create,
+        can_read,
+        can_update: false,
+        can_delete: false,
+        can_update_own: false,
+        can_delete_own: false,
+    }
+}
+
+fn staff(permissions: Vec<Permission>) -> CurrentUser {
+    CurrentUser {
+        id: 2,
+        username: "redacteur".into(),
+        is_staff: true,
+        is_superuser: false,
+        groupes: vec![Groupe {
+            id: 1,
+            nom: "redaction".into(),
+            permissions,
+        }],
+    }
+}
+
+/// Templates de champs réels, table `authors` peuplée ; l'édition se réduit au formulaire.
+async fn engine() -> Arc<RuniqueEngine> {
+    let mut tera = Tera::default();
+    tera.add_raw_templates(FIELD_TEMPLATES.iter().copied())
+        .unwrap();
+    tera.register_filter("runique_static", |v: &Value, _: &_| Ok(v.clone()));
+    tera.add_raw_templates([
+        ("csrf.html", ""),
+        ("admin/edit.html", "{{ form_fields.html | safe }}"),
+    ])
+    .unwrap();
+    let mut engine = Arc::try_unwrap(build_engine().await).unwrap_or_else(|_| unreachable!());
+    engine.tera = LiveTera::new(tera);
+    engine
+        .db
+        .execute_unprepared(
+            "CREATE TABLE authors (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+             INSERT INTO authors (id, name) VALUES (1, 'Alice'), (2, 'Bob'), (3, 'Alban');",
+        )
+        .await
+        .unwrap();
+    Arc::new(engine)
+}
+
+async fn search(user: CurrentUser, query: &[(&str, &str)]) -> Response {
+    let params: StrMap = query
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let req = build_handler_req(engine().await, Some(user.clone()), StrMap::new()).await;
+    admin_get(
+        Path(("articles".to_string(), "autocomplete".to_string())),
+        Extension(state()),
+        Extension(user),
+        Query(params),
+        HeaderMap::new(),
+        req,
+    )
+    .await
+    .unwrap_or_else(|_| panic!("autocomplete failed"))
+}
+
+async fn search_json(user: CurrentUser, query: &[(&str, &str)]) -> Value {
+    let resp = search(user, query).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    serde_json::from_str(&body_str(resp).await).unwrap()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Endpoint JSON
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_recherche_insensible_a_la_casse() {
+    let rows = search_json(root(), &[("field", "author_id"), ("q", "AL")]).await;
+    assert_eq!(
+        rows,
+        json!([{ "id": "1", "label": "Alice" }, { "id": "3", "label": "Alban" }])
+    );
+}
+
+#[tokio::test]
+async fn test_terme_vide_liste_les_premieres_lignes() {
+    let rows = search_json(root(), &[("field", "author_id")]).await;
+    assert_eq!(rows.as_array().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_champ_inconnu_404() {
+    let resp = search(root(), &[("field", "title"), ("q", "a")]).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_droits_du_formulaire_et_de_la_cible() {
+    // Création d'articles + lecture des auteurs → autorisé
+    let allowed = staff(vec![
+        perm("articles", true, true),
+        perm("authors", false, true),
+    ]);
+    assert_eq!(
+        search_json(allowed, &[("field", "author_id"), ("q", "bob")]).await,
+        json!([{ "id": "2", "label": "Bob" }])
+    );
+
+    // Pas de lecture des auteurs → 403
+    let no_target = staff(vec![perm("articles", true, true)]);
+    let resp = search(no_target, &[("field", "author_id"), ("q", "bob")]).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    // Lecture seule des articles : aucun formulaire → 403 (pas de redirection)
+    let read_only = staff(vec![
+        perm("articles", false, true),
+        perm("authors", false, true),
+    ]);
+    let resp = search(read_only, &[("field", "author_id"), ("q", "bob")]).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Formulaire d'édition
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_edition_affiche_la_boite_et_le_libelle() {
+    let req = build_handler_req(engine().await, Some(root()), StrMap::new()).await;
+    let resp = admin_get_id(
+        Path(("articles".to_string(), "5".to_string(), "edit".to_string())),
+        Extension(state()),
+        Extension(root()),
+        req,
+    )
+    .await
+    .unwrap_or_else(|_| panic!("GET edit failed"));
+    let html = body_str(resp).await;
+    assert!(html.contains("data-autocomplete="));
+    assert!(html.contains("autocomplete?field=author_id"));
+    assert!(html.contains(r#"type="hidden" name="author_id""#));
+    assert!(html.contains(r#"value="Bob""#));
+    assert!(!html.contains("<select"));
+}
+
+async fn post_edit(id: &str, author: &str) -> Response {
+    let mut data = StrMap::new();
+    data.insert("title".into(), "Titre".into());
+    data.insert("author_id".into(), author.into());
+    data.insert("csrf_token".into(), "test-csrf-token".into());
+    let req = build_handler_req(engine().await, Some(root()), data).await;
+    admin_post_id(
+        HeaderMap::new(),
+        Path(("articles".to_string(), id.to_string(), "edit".to_string())),
+        Extension(state()),
+        Extension(root()),
+        req,
+    )
+    .await
+    .unwrap_or_else(|_| panic!("POST edit failed"))
+}
+
+#[tokio::test]
+async fn test_id_existant_enregistre() {
+    let resp = post_edit("21", "3").await;
+    assert!(resp.status().is_redirection());
+    let saved = SAVED.lock().unwrap();
+    let (_, data) = saved.iter().find(|(id, _)| id == "21").unwrap();
+    assert_eq!(data["author_id"], "3");
+}
+
+#[tokio::test]
+async fn test_id_inexistant_refuse() {
+    let resp = post_edit("22", "99").await;
+    assert!(!resp.status().is_redirection());
+    let html = body_str(resp).await;
+    assert!(html.contains("field-error-message"));
+    assert!(!SAVED.lock().unwrap().iter().any(|(id, _)| id == "22"));
+}
@@ -0,0 +1,70 @@
+//! Tests — admin/helper/resource_entry.rs : actions de masse personnalisées
+//! Couvre : BulkAction (exécution, sérialisation sans closure), with_bulk_action
+//!          (noms réservés et doublons ignorés), AdminRegistry::add_bulk_action
+
+use std::sync::Arc;
+
+use runique::admin::helper::resource_entry::{BulkAction, FormBuilder, ResourceEntry};
+use runique::admin::registry::AdminRegistry;
+use runique::admin::resource::AdminResource;
+
+use crate::helpers::db;
+
+fn make_entry(key: &'static str) -> ResourceEntry {
+    let meta = AdminResource::new(key, "module::Model", "module::Form", "Articles", vec![]);
+    let form_builder: FormBuilder = Arc::new(|_, _, _, _, _, _| Box::pin(async { unreachable!() }));
+    ResourceEntry::new(meta, form_builder)
+}
+
+fn publish() -> BulkAction {
+    BulkAction::new("publish", "Publier", |_db, ids| async move {
+        Ok(format!(
+            "{} article(s) publié(s) : {}",
+            ids.len(),
+            ids.join(",")
+        ))
+    })
+}
+
+#[tokio::test]
+async fn test_action_execute_la_closure_et_retourne_le_resume() {
+    let entry = make_entry("blog").with_bulk_action(publish());
+    let action = entry.bulk_action("publish").unwrap();
+    let conn = Arc::new(db::fresh_db().await);
+    let summary = (action.run)(conn, vec!["1".into(), "3".into()])
+        .await
+        .unwrap();
+    assert_eq!(summary, "2 article(s) publié(s) : 1,3");
+}
+
+#[test]
+fn test_serialisation_sans_closure() {
+    let json = serde_json::to_value(publish()).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "name": "publish", "label": "Publier" })
+    );
+}
+
+#[test]
+fn test_noms_reserves_et_doublons_ignores() {
+    let noop = |name: &str| BulkAction::new(name, "X", |_, _| async { Ok(String::new()) });
+    let entry = make_entry("blog")
+        .with_bulk_action(publish())
+        .with_bulk_action(noop("delete"))
+        .with_bulk_action(noop("group_set"))
+        .with_bulk_action(noop("publish"));
+    assert_eq!(entry.bulk_actions.len(), 1);
+    assert_eq!(entry.bulk_action("publish").unwrap().label, "Publier");
+    assert!(entry.bulk_action("delete").is_none());
+}
+
+#[test]
+fn test_registry_ajoute_sur_cle_existante_seulement() {
+    let mut registry = AdminRegistry::new();
+    registry.register(make_entry("blog"));
+    registry.add_bulk_action("blog", publish());
+    registry.add_bulk_action("inconnu", publish());
+    assert_eq!(registry.get("blog").unwrap().bulk_actions.len(), 1);
+    assert!(!registry.contains("inconnu"));
+}
@@ -0,0 +1,191 @@
+//! Tests — admin/handle_crud.rs : nouveau mot de passe saisi à l'édition
+//! Couvre : sessions de l'utilisateur déconnectées pour une ressource déclarée
+//!          `user_resource`, sessions intactes pour une autre ressource portant
+//!          un champ mot de passe (son id n'est pas un id utilisateur)
+
+use std::sync::Arc;
+
+use axum::http::{HeaderMap, Method};
+use axum::{Extension, Router, body::Body, extract::Path, http::Request, middleware, routing::get};
+use runique::admin::helper::DynForm;
+use runique::admin::helper::resource_entry::{FormBuilder, GetFn, ResourceEntry, UpdateFn};
+use runique::admin::registry::AdminRegistry;
+use runique::admin::resource::AdminResource;
+use runique::admin::{AdminConfig, PrototypeAdminState, admin_post_id};
+use runique::auth::session::CurrentUser;
+use runique::auth::{is_authenticated, load_user_middleware, login, register_session_epoch};
+use runique::forms::{field::RuniqueForm, fields::text::TextField, form::Forms};
+use runique::utils::aliases::{ADb, ATera, StrMap};
+use runique::utils::pk::Pk;
+use serde_json::json;
+use tower::ServiceExt;
+use tower_sessions::{MemoryStore, Session, SessionManagerLayer};
+
+use crate::helpers::{
+    assert::body_str,
+    db,
+    request::build_handler_req,
+    server::build_engine,
+    users::{MockEntity, MockUser, set_password_hash},
+};
+
+struct CompteForm {
+    form: Forms,
+}
+
+impl RuniqueForm for CompteForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&TextField::text("username"));
+        form.field(&TextField::password("password"));
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+struct CompteDynForm(CompteForm);
+
+#[async_trait::async_trait]
+impl DynForm for CompteDynForm {
+    async fn is_valid(&mut self) -> bool {
+        self.0.is_valid().await
+    }
+    async fn save(&mut self, _db: &sea_orm::DatabaseConnection) -> Result<(), sea_orm::DbErr> {
+        Ok(())
+    }
+    fn get_form(&self) -> &Forms {
+        self.0.get_form()
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        self.0.get_form_mut()
+    }
+}
+
+/// Ressource `key` dont l'édition enregistre un nouveau hash pour la ligne `id`,
+/// comme le ferait une table stockant ses propres mots de passe.
+fn entry(key: &'static str) -> ResourceEntry {
+    let meta = AdminResource::new(key, "M", "F", key, vec![]);
+    let form_builder: FormBuilder = Arc::new(
+        |_db: ADb, _keys, data: StrMap, tera: ATera, csrf: String, method: Method| {
+            Box::pin(async move {
+                let form = CompteForm::build_with_data(&data, tera, &csrf, method).await;
+                Box::new(CompteDynForm(form)) as Box<dyn DynForm>
+            })
+        },
+    );
+    let get_fn: GetFn = Arc::new(|_, id| {
+        Box::pin(async move { Ok(Some(json!({ "id": id, "username": "cible" }))) })
+    });
+    let update_fn: UpdateFn = Arc::new(|_, id, _| {
+        Box::pin(async move {
+            set_password_hash(id.parse().unwrap(), "nouveau-hash");
+            Ok(())
+        })
+    });
+    ResourceEntry::new(meta, form_builder)
+        .with_get_fn(get_fn)
+        .with_update_fn(update_fn)
+}
+
+fn state() -> Arc<PrototypeAdminState> {
+    let mut registry = AdminRegistry::new();
+    registry.register(entry("users"));
+    registry.register(entry("api_clients"));
+    Arc::new(PrototypeAdminState {
+        registry: Arc::new(registry),
+        config: Arc::new(AdminConfig::new().user_resource("users")),
+    })
+}
+
+fn root() -> CurrentUser {
+    CurrentUser {
+        id: 1,
+        username: "root".into(),
+        is_staff: true,
+        is_superuser: true,
+        groupes: vec![],
+    }
+}
+
+/// Connecte `user_id`, édite la ligne `user_id` de `resource` avec un nouveau mot
+/// de passe, puis indique si la session ouverte avant l'édition est encore valide.
+async fn session_conservee_apres_edition(resource: &str, user_id: Pk) -> bool {
+    register_session_epoch::<MockEntity>();
+    MockUser::new(user_id, "cible")
+        .with_hash("ancien-hash")
+        .save();
+
+    let store = MemoryStore::default();
+    let session = Session::new(None, Arc::new(store.clone()), None);
+    login(
+        &session,
+        &db::fresh_db().await,
+        user_id,
+        "cible",
+        false,
+        false,
+        None,
+        false,
+    )
+    .await
+    .unwrap();
+    session.save().await.unwrap();
+    let session_id = session.id().unwrap();
+
+    let mut data = StrMap::new();
+    data.insert("username".into(), "cible".into());
+    data.insert("password".into(), "Nouveau-mot-de-passe-2026".into());
+    data.insert("csrf_token".into(), "test-csrf-token".into());
+    let req = build_handler_req(build_engine().await, Some(root()), data).await;
+    let resp = admin_post_id(
+        HeaderMap::new(),
+        Path((
+            resource.to_string(),
+            user_id.to_string(),
+            "edit".to_string(),
+        )),
+        Extension(state()),
+        Extension(root()),
+        req,
+    )
+    .await
+    .unwrap_or_else(|_| panic!("POST edit failed"));
+    assert!(resp.status().is_redirection());
+
+    let app = Router::new()
+        .route(
+            "/me",
+            get(|session: Session| async move { is_authenticated(&session).await.to_string() }),
+        )
+        .layer(middleware::from_fn_with_state(
+            Arc::new(db::fresh_db().await),
+            load_user_middleware,
+        ))
+        .layer(SessionManagerLayer::new(store).with_secure(false));
+    let resp = app
+        .oneshot(
+            Request::get("/me")
+                .header("cookie", format!("id={session_id}"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    body_str(resp).await == "true"
+}
+
+#[tokio::test]
+async fn test_edition_ressource_utilisateur_deconnecte_les_sessions() {
+    assert!(!session_conservee_apres_edition("users", 9401).await);
+}
+
+#[tokio::test]
+async fn test_edition_autre_ressource_garde_les_sessions() {
+    assert!(session_conservee_apres_edition("api_clients", 9402).await);
+}
@@ -0,0 +1,268 @@
+//! Tests — admin/history.rs : journal d'audit des créations / éditions / suppressions
+//! Couvre : valeurs saisies à la création, diff à l'édition, valeurs supprimées,
+//!          mot de passe jamais journalisé, AdminConfig::history(false)
+
+use std::sync::Arc;
+
+use axum::http::{HeaderMap, Method};
+use axum::{Extension, extract::Path, response::Response};
+use runique::admin::helper::DynForm;
+use runique::admin::helper::resource_entry::{
+    CreateFn, DeleteFn, FormBuilder, GetFn, ResourceEntry, UpdateFn,
+};
+use runique::admin::history::{self, created_fields, deleted_fields, diff_fields};
+use runique::admin::registry::AdminRegistry;
+use runique::admin::resource::AdminResource;
+use runique::admin::{
+    AdminConfig, PrototypeAdminState, admin_post, admin_post_id, create_eihwaz_history_table,
+};
+use runique::auth::session::CurrentUser;
+use runique::engine::RuniqueEngine;
+use runique::forms::{field::RuniqueForm, fields::text::TextField, form::Forms};
+use runique::utils::aliases::{ADb, ATera, StrMap};
+use sea_orm::{ConnectionTrait, EntityTrait};
+use serde_json::{Value, json};
+
+use crate::helpers::{request::build_handler_req, server::build_engine};
+
+struct ArticleForm {
+    form: Forms,
+}
+
+impl RuniqueForm for ArticleForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&TextField::text("title"));
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+struct ArticleDynForm(ArticleForm);
+
+#[async_trait::async_trait]
+impl DynForm for ArticleDynForm {
+    async fn is_valid(&mut self) -> bool {
+        self.0.is_valid().await
+    }
+    async fn save(&mut self, _db: &sea_orm::DatabaseConnection) -> Result<(), sea_orm::DbErr> {
+        Ok(())
+    }
+    fn get_form(&self) -> &Forms {
+        self.0.get_form()
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        self.0.get_form_mut()
+    }
+}
+
+fn articles_entry() -> ResourceEntry {
+    let meta = AdminResource::new("articles", "M", "F", "Articles", vec![]);
+    let form_builder: FormBuilder = Arc::new(
+        |_db: ADb, _keys, data: StrMap, tera: ATera, csrf: String, method: Method| {
+            Box::pin(async move {
+                let form = ArticleForm::build_with_data(&data, tera, &csrf, method).await;
+                Box::new(ArticleDynForm(form)) as Box<dyn DynForm>
+            })
+        },
+    );
+    let get_fn: GetFn =
+        Arc::new(|_, id| Box::pin(async move { Ok(Some(json!({ "id": id, "title": "Ancien" }))) }));
+    let create_fn: CreateFn = Arc::new(|_, _| Box::pin(async { Ok(()) }));
+    let update_fn: UpdateFn = Arc::new(|_, _, _| Box::pin(async { Ok(()) }));
+    let delete_fn: DeleteFn = Arc::new(|_, _| Box::pin(async { Ok(()) }));
+    ResourceEntry::new(meta, form_builder)
+        .with_get_fn(get_fn)
+        .with_create_fn(create_fn)
+        .with_update_fn(update_fn)
+        .with_delete_fn(delete_fn)
+}
+
+fn state(config: AdminConfig) -> Arc<PrototypeAdminState> {
+    let mut registry = AdminRegistry::new();
+    registry.register(articles_entry());
+    Arc::new(PrototypeAdminState {
+        registry: Arc::new(registry),
+        config: Arc::new(config),
+    })
+}
+
+fn root() -> CurrentUser {
+    CurrentUser {
+        id: 1,
+        username: "root".into(),
+        is_staff: true,
+        is_superuser: true,
+        groupes: vec![],
+    }
+}
+
+/// Moteur de test avec la table `eihwaz_history` créée.
+async fn engine() -> Arc<RuniqueEngine> {
+    let engine = build_engine().await;
+    engine
+        .db
+        .execute(&create_eihwaz_history_table())
+        .await
+        .unwrap();
+    engine
+}
+
+fn form_data(title: &str) -> StrMap {
+    let mut data = StrMap::new();
+    data.insert("title".into(), title.into());
+    data.insert("csrf_token".into(), "test-csrf-token".into());
+    data
+}
+
+async fn post(
+    engine: Arc<RuniqueEngine>,
+    config: AdminConfig,
+    id: Option<&str>,
+    action: &str,
+    data: StrMap,
+) -> Response {
+    let req = build_handler_req(engine, Some(root()), data).await;
+    let resp = match id {
+        Some(id) => {
+            admin_post_id(
+                HeaderMap::new(),
+                Path(("articles".to_string(), id.to_string(), action.to_string())),
+                Extension(state(config)),
+                Extension(root()),
+                req,
+            )
+            .await
+        }
+        None => {
+            admin_post(
+                HeaderMap::new(),
+                Path(("articles".to_string(), action.to_string())),
+                Extension(state(config)),
+                Extension(root()),
+                req,
+            )
+            .await
+        }
+    };
+    resp.unwrap_or_else(|_| panic!("POST {action} failed"))
+}
+
+async fn entries(engine: &RuniqueEngine) -> Vec<history::Model> {
+    history::Entity::find().all(&*engine.db).await.unwrap()
+}
+
+fn summary(entry: &history::Model) -> Value {
+    serde_json::from_str(entry.summary.as_deref().unwrap()).unwrap()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Journalisation par les handlers
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_creation_journalise_les_valeurs() {
+    let engine = engine().await;
+    let resp = post(
+        engine.clone(),
+        AdminConfig::new(),
+        None,
+        "create",
+        form_data("Neuf"),
+    )
+    .await;
+    assert!(resp.status().is_redirection());
+
+    let logged = entries(&engine).await;
+    assert_eq!(logged.len(), 1);
+    assert_eq!(logged[0].action, "create");
+    assert_eq!(logged[0].username, "root");
+    assert_eq!(summary(&logged[0]), json!({ "title": { "new": "Neuf" } }));
+}
+
+#[tokio::test]
+async fn test_edition_journalise_le_diff() {
+    let engine = engine().await;
+    let resp = post(
+        engine.clone(),
+        AdminConfig::new(),
+        Some("7"),
+        "edit",
+        form_data("Nouveau"),
+    )
+    .await;
+    assert!(resp.status().is_redirection());
+
+    let logged = entries(&engine).await;
+    assert_eq!(logged.len(), 1);
+    assert_eq!(logged[0].action, "edit");
+    assert_eq!(logged[0].object_pk, "7");
+    assert_eq!(
+        summary(&logged[0]),
+        json!({ "title": { "old": "Ancien", "new": "Nouveau" } })
+    );
+}
+
+#[tokio::test]
+async fn test_suppression_journalise_les_valeurs_supprimees() {
+    let engine = engine().await;
+    let resp = post(
+        engine.clone(),
+        AdminConfig::new(),
+        Some("7"),
+        "delete",
+        form_data(""),
+    )
+    .await;
+    assert!(resp.status().is_redirection());
+
+    let logged = entries(&engine).await;
+    assert_eq!(logged.len(), 1);
+    assert_eq!(logged[0].action, "delete");
+    assert_eq!(logged[0].object_pk, "7");
+    assert_eq!(
+        summary(&logged[0]),
+        json!({ "id": { "old": "7" }, "title": { "old": "Ancien" } })
+    );
+}
+
+#[tokio::test]
+async fn test_historique_desactive() {
+    let engine = engine().await;
+    let config = || AdminConfig::new().history(false);
+    post(engine.clone(), config(), None, "create", form_data("Neuf")).await;
+    post(
+        engine.clone(),
+        config(),
+        Some("7"),
+        "edit",
+        form_data("Nouveau"),
+    )
+    .await;
+    post(engine.clone(), config(), Some("7"), "delete", form_data("")).await;
+    assert!(entries(&engine).await.is_empty());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Résumés
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_champs_internes_et_mot_de_passe_ignores() {
+    let mut body = form_data("Neuf");
+    body.insert("password".into(), "secret".into());
+    body.insert("__original_updated_at".into(), "2026-01-01".into());
+    let created: Value = serde_json::from_str(&created_fields(&body).unwrap()).unwrap();
+    assert_eq!(created, json!({ "title": { "new": "Neuf" } }));
+
+    let old = json!({ "title": "Neuf", "password": "hash" });
+    assert_eq!(diff_fields(&old, &body), None);
+    let deleted: Value = serde_json::from_str(&deleted_fields(&old).unwrap()).unwrap();
+    assert_eq!(deleted, json!({ "title": { "old": "Neuf" } }));
+}
@@ -0,0 +1,399 @@
+//! Tests — admin/admin_main/handle_inline_formset.rs : lignes liées éditées sur le formulaire parent
+//! Couvre : rendu des lignes existantes + lignes vides, ajout / modification / suppression
+//!          dans la transaction du parent, id de ligne étranger refusé, rollback complet,
+//!          suppression désactivée par l'inline
+
+use std::sync::{Arc, LazyLock, Mutex};
+
+use axum::extract::Path;
+use axum::http::{HeaderMap, Method};
+use axum::{Extension, response::Response};
+use runique::admin::helper::DynForm;
+use runique::admin::helper::resource_entry::{
+    FormBuilder, GetFn, ListFn, ResourceEntry, TxDeleteFn, TxSaveFn,
+};
+use runique::admin::registry::AdminRegistry;
+use runique::admin::resource::{AdminResource, InlineDef};
+use runique::admin::{AdminConfig, PrototypeAdminState, admin_get_id, admin_post_id};
+use runique::app::LiveTera;
+use runique::auth::session::CurrentUser;
+use runique::engine::RuniqueEngine;
+use runique::forms::{field::RuniqueForm, fields::text::TextField, form::Forms};
+use runique::utils::aliases::{ADb, ATera, ATxn, StrMap};
+use runique::utils::constante::{ADMIN_TEMPLATES, FIELD_TEMPLATES};
+use sea_orm::{ConnectionTrait, DbErr};
+use serde_json::{Value, json};
+use tera::Tera;
+
+use crate::helpers::{
+    assert::body_str, db::count, request::build_handler_req, server::build_engine,
+};
+
+macro_rules! simple_form {
+    ($form:ident, $dyn:ident, [$($field:literal),*]) => {
+        struct $form {
+            form: Forms,
+        }
+
+        impl RuniqueForm for $form {
+            fn register_fields(form: &mut Forms) {
+                $(form.field(&TextField::text($field));)*
+            }
+            fn from_form(form: Forms) -> Self {
+                Self { form }
+            }
+            fn get_form(&self) -> &Forms {
+                &self.form
+            }
+            fn get_form_mut(&mut self) -> &mut Forms {
+                &mut self.form
+            }
+        }
+
+        struct $dyn($form);
+
+        #[async_trait::async_trait]
+        impl DynForm for $dyn {
+            async fn is_valid(&mut self) -> bool {
+                self.0.is_valid().await
+            }
+            async fn save(&mut self, _db: &sea_orm::DatabaseConnection) -> Result<(), DbErr> {
+                Ok(())
+            }
+            fn get_form(&self) -> &Forms {
+                self.0.get_form()
+            }
+            fn get_form_mut(&mut self) -> &mut Forms {
+                self.0.get_form_mut()
+            }
+        }
+    };
+}
+
+simple_form!(ArticleForm, ArticleDynForm, ["title"]);
+simple_form!(CommentForm, CommentDynForm, ["article_id", "body"]);
+
+/// Écritures faites dans la transaction : (ressource, action, id, données).
+type Write = (&'static str, &'static str, Option<String>, StrMap);
+static WRITES: LazyLock<Mutex<Vec<Write>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Écritures d'un article (le parent, puis ses commentaires), dans l'ordre.
+fn writes_of(article: &str) -> Vec<Write> {
+    WRITES
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(res, _, id, data)| match *res {
+            "articles" => id.as_deref() == Some(article),
+            _ => data.get("article_id").map(String::as_str) == Some(article),
+        })
+        .cloned()
+        .collect()
+}
+
+fn comment(id: u32, article: &str, body: &str) -> Value {
+    json!({ "id": id, "article_id": article, "body": body })
+}
+
+fn articles_entry(inline: InlineDef) -> ResourceEntry {
+    let meta = AdminResource::new("articles", "M", "F", "Articles", vec![]).inline_def(inline);
+    let form_builder: FormBuilder = Arc::new(
+        |_db: ADb, _keys, data: StrMap, tera: ATera, csrf: String, method: Method| {
+            Box::pin(async move {
+                let form = ArticleForm::build_with_data(&data, tera, &csrf, method).await;
+                Box::new(ArticleDynForm(form)) as Box<dyn DynForm>
+            })
+        },
+    );
+    let get_fn: GetFn =
+        Arc::new(|_, id| Box::pin(async move { Ok(Some(json!({ "id": id, "title": "Titre" }))) }));
+    // Le parent trace son passage dans une vraie table : visible seulement si la
+    // transaction est validée.
+    let tx_save_fn: TxSaveFn = Arc::new(|txn: ATxn, id: Option<String>, data: StrMap| {
+        Box::pin(async move {
+            let id = id.unwrap_or_else(|| "new".to_string());
+            txn.execute_unprepared(&format!("INSERT INTO audit (article_id) VALUES ('{id}')"))
+                .await?;
+            WRITES
+                .lock()
+                .unwrap()
+                .push(("articles", "save", Some(id.clone()), data));
+            Ok(id)
+        })
+    });
+    ResourceEntry::new(meta, form_builder)
+        .with_get_fn(get_fn)
+        .with_update_fn(Arc::new(|_, _, _| Box::pin(async { Ok(()) })))
+        .with_tx_save_fn(tx_save_fn)
+}
+
+fn comments_entry() -> ResourceEntry {
+    let meta = AdminResource::new("comments", "M", "F", "Commentaires", vec![]);
+    let form_builder: FormBuilder = Arc::new(
+        |_db: ADb, _keys, data: StrMap, tera: ATera, csrf: String, method: Method| {
+            Box::pin(async move {
+                let form = CommentForm::build_with_data(&data, tera, &csrf, method).await;
+                Box::new(CommentDynForm(form)) as Box<dyn DynForm>
+            })
+        },
+    );
+    // Deux commentaires par article, filtrés sur le scope reçu.
+    let list_fn: ListFn = Arc::new(|_, params| {
+        Box::pin(async move {
+            Ok(match params.scope {
+                Some((col, article)) if col == "article_id" => vec![
+                    comment(1, &article, "Premier"),
+                    comment(2, &article, "Second"),
+                ],
+                _ => Vec::new(),
+            })
+        })
+    });
+    let tx_save_fn: TxSaveFn = Arc::new(|_txn: ATxn, id: Option<String>, data: StrMap| {
+        Box::pin(async move {
+            if data.get("body").map(String::as_str) == Some("boom") {
+                return Err(DbErr::Custom("boom".to_string()));
+            }
+            WRITES
+                .lock()
+                .unwrap()
+                .push(("comments", "save", id.clone(), data));
+            Ok(id.unwrap_or_else(|| "99".to_string()))
+        })
+    });
+    let tx_delete_fn: TxDeleteFn = Arc::new(|_txn: ATxn, id: String| {
+        Box::pin(async move {
+            let mut data = StrMap::new();
+            data.insert("article_id".into(), "deleted".into());
+            WRITES
+                .lock()
+                .unwrap()
+                .push(("comments", "delete", Some(id), data));
+            Ok(())
+        })
+    });
+    ResourceEntry::new(meta, form_builder)
+        .with_list_fn(list_fn)
+        .with_tx_save_fn(tx_save_fn)
+        .with_tx_delete_fn(tx_delete_fn)
+}
+
+fn state(inline: InlineDef) -> Arc<PrototypeAdminState> {
+    let mut registry = AdminRegistry::new();
+    registry.register(articles_entry(inline));
+    registry.register(comments_entry());
+    Arc::new(PrototypeAdminState {
+        registry: Arc::new(registry),
+        config: Arc::new(AdminConfig::new()),
+    })
+}
+
+fn comments_inline() -> InlineDef {
+    InlineDef::new("comments").fk_column("article_id").extra(1)
+}
+
+fn root() -> CurrentUser {
+    CurrentUser {
+        id: 1,
+        username: "root".into(),
+        is_staff: true,
+        is_superuser: true,
+        groupes: vec![],
+    }
+}
+
+/// Templates de champs et partiel inline réels ; la page d'édition se réduit aux inlines.
+async fn engine() -> Arc<RuniqueEngine> {
+    let mut tera = Tera::default();
+    tera.add_raw_templates(FIELD_TEMPLATES.iter().copied())
+        .unwrap();
+    tera.add_raw_templates(
+        ADMIN_TEMPLATES
+            .iter()
+            .copied()
+            .filter(|(name, _)| *name == "admin/inline_formset.html"),
+    )
+    .unwrap();
+    tera.add_raw_templates([
+        ("csrf.html", ""),
+        (
+            "admin/edit.html",
+            r#"{% include "admin/inline_formset.html" %}"#,
+        ),
+    ])
+    .unwrap();
+    let mut engine = Arc::try_unwrap(build_engine().await).unwrap_or_else(|_| unreachable!());
+    engine.tera = LiveTera::new(tera);
+    engine
+        .db
+        .execute_unprepared("CREATE TABLE audit (article_id TEXT)")
+        .await
+        .unwrap();
+    Arc::new(engine)
+}
+
+async fn post_edit(
+    engine: Arc<RuniqueEngine>,
+    inline: InlineDef,
+    article: &str,
+    body: &[(&str, &str)],
+) -> Response {
+    let mut data: StrMap = body
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    data.insert("csrf_token".into(), "test-csrf-token".into());
+    let req = build_handler_req(engine, Some(root()), data).await;
+    admin_post_id(
+        HeaderMap::new(),
+        Path((
+            "articles".to_string(),
+            article.to_string(),
+            "edit".to_string(),
+        )),
+        Extension(state(inline)),
+        Extension(root()),
+        req,
+    )
+    .await
+    .unwrap_or_else(|_| panic!("POST edit failed"))
+}
+
+#[tokio::test]
+async fn test_edition_affiche_lignes_existantes_et_vides() {
+    let req = build_handler_req(engine().await, Some(root()), StrMap::new()).await;
+    let resp = admin_get_id(
+        Path(("articles".to_string(), "7".to_string(), "edit".to_string())),
+        Extension(state(comments_inline())),
+        Extension(root()),
+        req,
+    )
+    .await
+    .unwrap_or_else(|_| panic!("GET edit failed"));
+    let html = body_str(resp).await;
+
+    assert!(html.contains(r#"name="comments-TOTAL_FORMS" value="3""#));
+    assert!(html.contains(r#"name="comments-0-id" value="1""#));
+    assert!(html.contains(r#"name="comments-1-id" value="2""#));
+    assert!(html.contains(r#"name="comments-0-body""#));
+    assert!(html.contains("Premier"));
+    // Ligne vide + gabarit « ajouter » ; la FK vers le parent n'est jamais saisie.
+    assert!(html.contains(r#"name="comments-2-body""#));
+    assert!(html.contains(r#"name="comments-__prefix__-body""#));
+    assert!(html.contains(r#"name="comments-0-DELETE""#));
+    assert!(!html.contains("article_id"));
+}
+
+#[tokio::test]
+async fn test_ajout_modification_suppression_dans_la_transaction() {
+    let engine = engine().await;
+    let resp = post_edit(
+        engine.clone(),
+        comments_inline(),
+        "11",
+        &[
+            ("title", "Titre"),
+            ("comments-TOTAL_FORMS", "4"),
+            ("comments-0-id", "1"),
+            ("comments-0-body", "Premier modifié"),
+            ("comments-1-id", "2"),
+            ("comments-1-body", "Second"),
+            ("comments-1-DELETE", "on"),
+            ("comments-2-body", "Nouveau"),
+            ("comments-3-body", ""),
+        ],
+    )
+    .await;
+    assert!(resp.status().is_redirection());
+    assert_eq!(count(&engine.db, "audit").await, 1);
+
+    let writes = writes_of("11");
+    assert_eq!(writes.len(), 3);
+    let (res, _, id, data) = &writes[0];
+    assert_eq!((*res, id.as_deref()), ("articles", Some("11")));
+    assert_eq!(data["title"], "Titre");
+    assert!(!data.contains_key("comments-TOTAL_FORMS"));
+
+    let (_, _, id, data) = &writes[1];
+    assert_eq!(id.as_deref(), Some("1"));
+    assert_eq!(data["body"], "Premier modifié");
+    let (_, _, id, data) = &writes[2];
+    assert_eq!(id, &None);
+    assert_eq!(data["body"], "Nouveau");
+
+    let deleted = WRITES
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(_, action, id, _)| *action == "delete" && id.as_deref() == Some("2"));
+    assert!(deleted);
+}
+
+#[tokio::test]
+async fn test_id_de_ligne_etranger_refuse() {
+    let engine = engine().await;
+    let resp = post_edit(
+        engine.clone(),
+        comments_inline(),
+        "12",
+        &[
+            ("title", "Titre"),
+            ("comments-TOTAL_FORMS", "1"),
+            ("comments-0-id", "42"),
+            ("comments-0-body", "Détourné"),
+        ],
+    )
+    .await;
+    assert!(resp.status().is_success());
+    assert!(writes_of("12").is_empty());
+    assert_eq!(count(&engine.db, "audit").await, 0);
+}
+
+#[tokio::test]
+async fn test_echec_d_une_ligne_annule_le_parent() {
+    let engine = engine().await;
+    let result = admin_post_id(
+        HeaderMap::new(),
+        Path(("articles".to_string(), "13".to_string(), "edit".to_string())),
+        Extension(state(comments_inline())),
+        Extension(root()),
+        build_handler_req(
+            engine.clone(),
+            Some(root()),
+            [
+                ("csrf_token", "test-csrf-token"),
+                ("title", "Titre"),
+                ("comments-TOTAL_FORMS", "1"),
+                ("comments-0-body", "boom"),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        )
+        .await,
+    )
+    .await;
+    assert!(result.is_err());
+    assert_eq!(count(&engine.db, "audit").await, 0);
+}
+
+#[tokio::test]
+async fn test_suppression_desactivee_par_l_inline() {
+    let engine = engine().await;
+    let resp = post_edit(
+        engine.clone(),
+        comments_inline().can_delete(false),
+        "14",
+        &[
+            ("title", "Titre"),
+            ("comments-TOTAL_FORMS", "1"),
+            ("comments-0-id", "1"),
+            ("comments-0-body", "Premier"),
+            ("comments-0-DELETE", "on"),
+        ],
+    )
+    .await;
+    assert!(resp.status().is_success());
+    assert!(body_str(resp).await.contains(r#"<ul class="form-errors">"#));
+    assert_eq!(count(&engine.db, "audit").await, 0);
+}
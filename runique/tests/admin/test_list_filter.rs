@@ -0,0 +1,114 @@
+//! Tests — admin/helper/list_filter.rs : filtres de la sidebar de liste
+//! Couvre : détection du type de filtre (valeurs / plage de dates), column_filter_condition
+//!          (égalité, bornes de dates inclusives, AND, colonnes hors liste ignorées,
+//!          bornes invalides ignorées)
+
+use runique::admin::helper::{FilterKind, column_filter_condition, filter_kind};
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection,
+    sea_query::{Alias, Expr, Query},
+};
+
+use crate::helpers::db;
+
+const SCHEMA: &str = "CREATE TABLE articles (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    status TEXT NOT NULL,
+    created_at TEXT NOT NULL
+)";
+
+const ALLOWED: &[&str] = &["status", "created_at"];
+
+async fn seeded() -> DatabaseConnection {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    db::exec(
+        &conn,
+        "INSERT INTO articles (status, created_at) VALUES
+            ('draft', '2026-01-01 09:00:00'),
+            ('published', '2026-01-05 23:59:00'),
+            ('published', '2026-01-06 00:00:00'),
+            ('draft', '2026-02-10 12:30:00')",
+    )
+    .await;
+    conn
+}
+
+fn filters(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+async fn count(conn: &DatabaseConnection, pairs: &[(&str, &str)]) -> i64 {
+    let stmt = Query::select()
+        .expr(Expr::cust("COUNT(*)"))
+        .from(Alias::new("articles"))
+        .cond_where(column_filter_condition(&filters(pairs), ALLOWED))
+        .to_owned();
+    conn.query_one(&stmt)
+        .await
+        .unwrap()
+        .unwrap()
+        .try_get_by_index::<i64>(0)
+        .unwrap()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Type de filtre
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_filter_kind_dates_et_valeurs() {
+    let dates = vec!["2026-01-01 09:00:00".to_string(), "2026-02-10".to_string()];
+    assert_eq!(filter_kind(&dates), FilterKind::DateRange);
+
+    let mixed = vec!["2026-01-01 09:00:00".to_string(), "draft".to_string()];
+    assert_eq!(filter_kind(&mixed), FilterKind::Values);
+    assert_eq!(filter_kind(&["true".to_string()]), FilterKind::Values);
+    assert_eq!(filter_kind(&[]), FilterKind::Values);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Conditions
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_filtre_egalite_et_sans_filtre() {
+    let conn = seeded().await;
+    assert_eq!(count(&conn, &[]).await, 4);
+    assert_eq!(count(&conn, &[("status", "draft")]).await, 2);
+}
+
+#[tokio::test]
+async fn test_plage_de_dates_bornes_incluses() {
+    let conn = seeded().await;
+    let range = [
+        ("created_at__gte", "2026-01-01"),
+        ("created_at__lte", "2026-01-05"),
+    ];
+    assert_eq!(count(&conn, &range).await, 2);
+    assert_eq!(count(&conn, &[("created_at__gte", "2026-01-06")]).await, 2);
+    assert_eq!(count(&conn, &[("created_at__lte", "2026-01-06")]).await, 3);
+}
+
+#[tokio::test]
+async fn test_filtres_combines_en_and() {
+    let conn = seeded().await;
+    let pairs = [("status", "published"), ("created_at__gte", "2026-01-06")];
+    assert_eq!(count(&conn, &pairs).await, 1);
+}
+
+#[tokio::test]
+async fn test_colonnes_et_bornes_invalides_ignorees() {
+    let conn = seeded().await;
+    assert_eq!(count(&conn, &[("id", "1")]).await, 4);
+    assert_eq!(
+        count(&conn, &[("status; DROP TABLE articles", "x")]).await,
+        4
+    );
+    assert_eq!(
+        count(&conn, &[("created_at__gte", "pas-une-date")]).await,
+        4
+    );
+}
@@ -0,0 +1,279 @@
+//! Tests — admin/admin_main/handle_crud.rs : champs en lecture seule et colonnes calculées
+//! Couvre : readonly_fields (texte à sa place dans le formulaire d'édition, valeur postée
+//!          ignorée), ComputedField (contexte liste / détail, colonne exclue, doublon ignoré)
+
+use std::sync::{Arc, LazyLock, Mutex};
+
+use axum::extract::{Path, Query};
+use axum::http::{HeaderMap, Method};
+use axum::{Extension, response::Response};
+use runique::admin::helper::DynForm;
+use runique::admin::helper::resource_entry::{
+    ComputedField, FormBuilder, GetFn, ListFn, ResourceEntry, UpdateFn,
+};
+use runique::admin::registry::AdminRegistry;
+use runique::admin::resource::{AdminResource, DisplayConfig};
+use runique::admin::{AdminConfig, PrototypeAdminState, admin_get, admin_get_id, admin_post_id};
+use runique::app::LiveTera;
+use runique::auth::session::CurrentUser;
+use runique::engine::RuniqueEngine;
+use runique::forms::{field::RuniqueForm, fields::text::TextField, form::Forms};
+use runique::utils::aliases::{ADb, ATera, StrMap};
+use runique::utils::constante::FIELD_TEMPLATES;
+use serde_json::{Value, json};
+use tera::Tera;
+
+use crate::helpers::{assert::body_str, request::build_handler_req, server::build_engine};
+
+struct ArticleForm {
+    form: Forms,
+}
+
+impl RuniqueForm for ArticleForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&TextField::text("title"));
+        form.field(&TextField::text("created_at"));
+        form.field(&TextField::text("notes"));
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+struct ArticleDynForm(ArticleForm);
+
+#[async_trait::async_trait]
+impl DynForm for ArticleDynForm {
+    async fn is_valid(&mut self) -> bool {
+        self.0.is_valid().await
+    }
+    async fn save(&mut self, _db: &sea_orm::DatabaseConnection) -> Result<(), sea_orm::DbErr> {
+        Ok(())
+    }
+    fn get_form(&self) -> &Forms {
+        self.0.get_form()
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        self.0.get_form_mut()
+    }
+}
+
+/// Données reçues par `update_fn`, par id d'article.
+static SAVED: LazyLock<Mutex<Vec<(String, StrMap)>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+fn row(id: u32) -> Value {
+    json!({
+        "id": id,
+        "title": "Ancien titre",
+        "created_at": "2026-01-01 10:00:00",
+        "notes": "note",
+        "words": 120,
+    })
+}
+
+fn reading_time() -> ComputedField {
+    ComputedField::new("reading_time", "Lecture", |row| {
+        format!("{} min", row["words"].as_u64().unwrap_or(0) / 60)
+    })
+}
+
+fn articles_entry(display: DisplayConfig) -> ResourceEntry {
+    let meta = AdminResource::new("articles", "M", "F", "Articles", vec![]).display(display);
+    let form_builder: FormBuilder = Arc::new(
+        |_db: ADb, _keys, data: StrMap, tera: ATera, csrf: String, method: Method| {
+            Box::pin(async move {
+                let form = ArticleForm::build_with_data(&data, tera, &csrf, method).await;
+                Box::new(ArticleDynForm(form)) as Box<dyn DynForm>
+            })
+        },
+    );
+    let get_fn: GetFn =
+        Arc::new(|_, id| Box::pin(async move { Ok(Some(row(id.parse().unwrap_or(0)))) }));
+    let list_fn: ListFn = Arc::new(|_, _| Box::pin(async { Ok(vec![row(1), row(2)]) }));
+    let update_fn: UpdateFn = Arc::new(|_, id, data| {
+        Box::pin(async move {
+            SAVED.lock().unwrap().push((id, data));
+            Ok(())
+        })
+    });
+    ResourceEntry::new(meta, form_builder)
+        .with_get_fn(get_fn)
+        .with_list_fn(list_fn)
+        .with_update_fn(update_fn)
+        .with_computed_field(reading_time())
+}
+
+fn state(entry: ResourceEntry) -> Arc<PrototypeAdminState> {
+    let mut registry = AdminRegistry::new();
+    registry.register(entry);
+    Arc::new(PrototypeAdminState {
+        registry: Arc::new(registry),
+        config: Arc::new(AdminConfig::new()),
+    })
+}
+
+fn root() -> CurrentUser {
+    CurrentUser {
+        id: 1,
+        username: "root".into(),
+        is_staff: true,
+        is_superuser: true,
+        groupes: vec![],
+    }
+}
+
+/// Templates de champs réels ; pages admin réduites aux valeurs du contexte testées.
+async fn engine() -> Arc<RuniqueEngine> {
+    let mut tera = Tera::default();
+    tera.add_raw_templates(FIELD_TEMPLATES.iter().copied())
+        .unwrap();
+    tera.add_raw_templates([
+        ("csrf.html", ""),
+        ("admin/edit.html", "{{ form_fields.html | safe }}"),
+        (
+            "admin/list.html",
+            "{{ visible_columns | join(sep=',') }}|{{ computed_columns | join(sep=',') }}|\
+             {{ column_labels.reading_time }}|{{ entries.0.reading_time }}",
+        ),
+        ("admin/detail.html", "{{ entry.reading_time }}"),
+    ])
+    .unwrap();
+    let mut engine = Arc::try_unwrap(build_engine().await).unwrap_or_else(|_| unreachable!());
+    engine.tera = LiveTera::new(tera);
+    Arc::new(engine)
+}
+
+fn readonly_display() -> DisplayConfig {
+    DisplayConfig::new().readonly_fields(vec!["created_at"])
+}
+
+async fn get_member(entry: ResourceEntry, id: &str, action: &str) -> String {
+    let req = build_handler_req(engine().await, Some(root()), StrMap::new()).await;
+    let resp = admin_get_id(
+        Path(("articles".to_string(), id.to_string(), action.to_string())),
+        Extension(state(entry)),
+        Extension(root()),
+        req,
+    )
+    .await
+    .unwrap_or_else(|_| panic!("GET {action} failed"));
+    body_str(resp).await
+}
+
+async fn post_edit(id: &str, body: &[(&str, &str)]) -> Response {
+    let mut data: StrMap = body
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    data.insert("csrf_token".into(), "test-csrf-token".into());
+    let req = build_handler_req(engine().await, Some(root()), data).await;
+    admin_post_id(
+        HeaderMap::new(),
+        Path(("articles".to_string(), id.to_string(), "edit".to_string())),
+        Extension(state(articles_entry(readonly_display()))),
+        Extension(root()),
+        req,
+    )
+    .await
+    .unwrap_or_else(|_| panic!("POST edit failed"))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// readonly_fields
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_edition_affiche_le_champ_en_texte_a_sa_place() {
+    let html = get_member(articles_entry(readonly_display()), "7", "edit").await;
+    assert!(!html.contains(r#"name="created_at""#));
+    assert!(html.contains(r#"name="title""#));
+
+    let title = html.find(r#"id="container_title""#).unwrap();
+    let created = html.find(r#"id="container_created_at""#).unwrap();
+    let notes = html.find(r#"id="container_notes""#).unwrap();
+    assert!(title < created && created < notes);
+    assert!(html[created..notes].contains("2026-01-01 10:00:00"));
+}
+
+#[tokio::test]
+async fn test_valeur_postee_ignoree_a_l_enregistrement() {
+    let resp = post_edit(
+        "8",
+        &[
+            ("title", "Nouveau titre"),
+            ("created_at", "1999-12-31 23:59:59"),
+            ("notes", "note"),
+        ],
+    )
+    .await;
+    assert!(resp.status().is_redirection());
+
+    let saved = SAVED.lock().unwrap();
+    let (_, data) = saved.iter().find(|(id, _)| id == "8").unwrap();
+    assert_eq!(data["title"], "Nouveau titre");
+    assert_eq!(data["created_at"], "2026-01-01 10:00:00");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// ComputedField
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_colonne_calculee_dans_la_liste_et_le_detail() {
+    let req = build_handler_req(engine().await, Some(root()), StrMap::new()).await;
+    let resp = admin_get(
+        Path(("articles".to_string(), "list".to_string())),
+        Extension(state(articles_entry(DisplayConfig::new()))),
+        Extension(root()),
+        Query(StrMap::new()),
+        HeaderMap::new(),
+        req,
+    )
+    .await
+    .unwrap_or_else(|_| panic!("list failed"));
+    let html = body_str(resp).await;
+    let parts: Vec<&str> = html.split('|').collect();
+    assert!(parts[0].split(',').any(|c| c == "reading_time"));
+    assert_eq!(parts[1..], ["reading_time", "Lecture", "2 min"]);
+
+    let html = get_member(articles_entry(DisplayConfig::new()), "3", "detail").await;
+    assert_eq!(html, "2 min");
+}
+
+#[tokio::test]
+async fn test_colonne_calculee_exclue_de_la_liste() {
+    let display = DisplayConfig::new().columns_exclude(vec!["reading_time"]);
+    let req = build_handler_req(engine().await, Some(root()), StrMap::new()).await;
+    let resp = admin_get(
+        Path(("articles".to_string(), "list".to_string())),
+        Extension(state(articles_entry(display))),
+        Extension(root()),
+        Query(StrMap::new()),
+        HeaderMap::new(),
+        req,
+    )
+    .await
+    .unwrap_or_else(|_| panic!("list failed"));
+    let html = body_str(resp).await;
+    assert!(!html.split('|').next().unwrap().contains("reading_time"));
+}
+
+#[test]
+fn test_colonne_calculee_doublon_ignore() {
+    let entry = articles_entry(DisplayConfig::new()).with_computed_field(reading_time());
+    assert_eq!(entry.computed_fields.len(), 1);
+
+    let mut rows = vec![row(1)];
+    entry.apply_computed_fields(&mut rows);
+    assert_eq!(rows[0]["reading_time"], "2 min");
+    assert_eq!(
+        serde_json::to_value(reading_time()).unwrap(),
+        json!({ "name": "reading_time", "label": "Lecture" })
+    );
+}
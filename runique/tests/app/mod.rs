@@ -1,3 +1,11 @@
+pub mod test_cache;
 pub mod test_engine;
+pub mod test_health;
 pub mod test_robots_txt;
+pub mod test_route_list;
 pub mod test_runique_app;
+pub mod test_schedule;
+pub mod test_tasks;
+pub mod test_template_reload;
+pub mod test_tera_hooks;
+pub mod test_test_client;
@@ -0,0 +1,344 @@
+//! Tests — cache/ : cache applicatif
+//! Couvre : MemoryCache (TTL, delete, delete_prefix, clones partagés), get_or_set
+//!          (calcul unique, erreur non mise en cache), `.with_cache(..)` installé sur
+//!          `engine.cache`, `RouterExt::cache_page` (hit, clé path+query, réponses
+//!          privées ignorées, `invalidate_page`, nonce CSP d'un `{% script %}` conservé,
+//!          HTML et JSON de `respond` séparés selon Accept)
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, header},
+    response::IntoResponse,
+    routing::get,
+};
+use runique::app::RuniqueAppBuilder;
+use runique::cache::{Cache, MemoryCache, invalidate_page, page_key};
+use runique::config::app::RuniqueConfig;
+use runique::context::Request as TplRequest;
+use runique::macros::RouterExt;
+use runique::testing::test_db;
+use runique::utils::aliases::AEngine;
+use std::fs;
+use std::sync::{
+    Arc,
+    atomic::{AtomicU32, Ordering},
+};
+use std::time::Duration;
+use tower::ServiceExt;
+
+const MINUTE: Duration = Duration::from_secs(60);
+
+// ═══════════════════════════════════════════════════════════════
+// MemoryCache
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_memoire_set_get_expiration() {
+    let cache = MemoryCache::new();
+    cache
+        .set_with_ttl("a", b"1".to_vec(), Duration::from_millis(30))
+        .await
+        .unwrap();
+    cache
+        .set_with_ttl("b", b"2".to_vec(), MINUTE)
+        .await
+        .unwrap();
+    assert_eq!(cache.get("a").await.unwrap(), Some(b"1".to_vec()));
+    assert_eq!(cache.len(), 2);
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(cache.get("a").await.unwrap(), None);
+    assert_eq!(cache.len(), 1);
+
+    // Les clones partagent les entrées
+    let clone = cache.clone();
+    clone.delete("b").await.unwrap();
+    assert!(cache.is_empty());
+    assert!(cache.delete("absente").await.is_ok());
+}
+
+#[tokio::test]
+async fn test_memoire_delete_prefix() {
+    let cache = MemoryCache::new();
+    for key in ["blog:1", "blog:2", "blogroll", "shop:1"] {
+        cache.set_with_ttl(key, Vec::new(), MINUTE).await.unwrap();
+    }
+    assert_eq!(cache.delete_prefix("blog:").await.unwrap(), 2);
+    assert_eq!(cache.get("blogroll").await.unwrap(), Some(Vec::new()));
+    assert_eq!(cache.get("blog:1").await.unwrap(), None);
+    assert_eq!(cache.len(), 2);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// get_or_set
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_get_or_set_calcule_une_seule_fois() {
+    let cache: Arc<dyn Cache> = Arc::new(MemoryCache::new());
+    let calls = AtomicU32::new(0);
+    let compute = || async {
+        calls.fetch_add(1, Ordering::SeqCst);
+        Ok::<_, String>(vec!["article 1".to_string(), "article 2".to_string()])
+    };
+
+    let first = cache.get_or_set("accueil", MINUTE, compute).await.unwrap();
+    let second = cache.get_or_set("accueil", MINUTE, compute).await.unwrap();
+    assert_eq!(first, second);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        cache.get_value::<Vec<String>>("accueil").await.unwrap(),
+        Some(first)
+    );
+}
+
+#[tokio::test]
+async fn test_get_or_set_erreur_non_mise_en_cache() {
+    let cache: Arc<dyn Cache> = Arc::new(MemoryCache::new());
+    let err = cache
+        .get_or_set("k", MINUTE, || async { Err::<u32, _>("base indisponible") })
+        .await;
+    assert_eq!(err, Err("base indisponible"));
+    assert_eq!(cache.get("k").await.unwrap(), None);
+
+    let ok = cache
+        .get_or_set("k", MINUTE, || async { Ok::<_, &str>(7u32) })
+        .await;
+    assert_eq!(ok, Ok(7));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Application : engine.cache + cache_page
+// ═══════════════════════════════════════════════════════════════
+
+async fn app(cache: MemoryCache, routes: Router) -> (Router, AEngine) {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    let app = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .with_cache(cache)
+        .routes(routes)
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    (app.router, app.engine)
+}
+
+async fn body_of(router: &Router, uri: &str) -> String {
+    let res = router
+        .clone()
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(res.status(), 200, "{uri}");
+    let bytes = axum::body::to_bytes(res.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+fn compteur() -> Arc<AtomicU32> {
+    Arc::new(AtomicU32::new(0))
+}
+
+#[tokio::test]
+async fn test_with_cache_installe_sur_engine() {
+    let cache = MemoryCache::new();
+    let (_, engine) = app(cache.clone(), Router::new()).await;
+    engine
+        .cache
+        .set_value("installe", &true, MINUTE)
+        .await
+        .unwrap();
+    assert_eq!(cache.len(), 1);
+    assert_eq!(engine.cache.backend(), "memory");
+}
+
+#[tokio::test]
+async fn test_cache_page_hit_et_cle_par_query() {
+    let calls = compteur();
+    let c = calls.clone();
+    let routes = Router::new().cache_page(
+        "/accueil",
+        "accueil_cache",
+        get(move || async move { format!("rendu {}", c.fetch_add(1, Ordering::SeqCst)) }),
+        MINUTE,
+    );
+    let cache = MemoryCache::new();
+    let (router, _) = app(cache.clone(), routes).await;
+
+    assert_eq!(body_of(&router, "/accueil").await, "rendu 0");
+    assert_eq!(body_of(&router, "/accueil").await, "rendu 0");
+    assert_eq!(body_of(&router, "/accueil?page=2").await, "rendu 1");
+    assert_eq!(body_of(&router, "/accueil?page=2").await, "rendu 1");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert!(
+        cache
+            .get(&page_key("/accueil", Some("page=2")))
+            .await
+            .unwrap()
+            .is_some()
+    );
+
+    // Toutes les variantes de la page sont invalidées
+    assert_eq!(invalidate_page(&cache, "/accueil").await.unwrap(), 2);
+    assert_eq!(body_of(&router, "/accueil").await, "rendu 2");
+}
+
+#[tokio::test]
+async fn test_cache_page_conserve_les_entetes() {
+    let routes = Router::new().cache_page(
+        "/flux.json",
+        "flux_cache",
+        get(|| async { ([(header::CONTENT_TYPE, "application/json")], "[]") }),
+        MINUTE,
+    );
+    let (router, _) = app(MemoryCache::new(), routes).await;
+    for _ in 0..2 {
+        let res = router
+            .clone()
+            .oneshot(Request::get("/flux.json").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "application/json");
+    }
+}
+
+#[tokio::test]
+async fn test_cache_page_ignore_les_reponses_privees() {
+    let calls = compteur();
+    let c = calls.clone();
+    let routes = Router::new().cache_page(
+        "/compte",
+        "compte_cache",
+        get(move || async move {
+            c.fetch_add(1, Ordering::SeqCst);
+            ([(header::CACHE_CONTROL, "private")], "données personnelles").into_response()
+        }),
+        MINUTE,
+    );
+    let cache = MemoryCache::new();
+    let (router, _) = app(cache.clone(), routes).await;
+
+    body_of(&router, "/compte").await;
+    body_of(&router, "/compte").await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert!(cache.is_empty());
+}
+
+async fn page_script(mut req: TplRequest) -> impl IntoResponse {
+    req.render("page.html")
+}
+
+/// `nonce="…"` du premier bloc inline de la page, tel que le lit le navigateur.
+fn body_nonce(body: &str) -> String {
+    let start = body.find("nonce=\"").expect("nonce dans la page") + 7;
+    body[start..start + body[start..].find('"').unwrap()].replace("&#x2F;", "/")
+}
+
+#[tokio::test]
+async fn test_cache_page_conserve_le_nonce_csp() {
+    let dir = TestTempDir::new("runique_test_cache", "nonce");
+    fs::write(
+        dir.join("page.html"),
+        "{% script %}console.log('mis en cache');{% endscript %}",
+    )
+    .unwrap();
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    config.static_files.templates_dir = vec![dir.as_str().to_string()];
+    let router = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_template_autoreload(false)
+        .with_database(test_db().await)
+        .with_cache(MemoryCache::new())
+        .routes(Router::new().cache_page("/script", "script_cache", get(page_script), MINUTE))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"))
+        .router;
+
+    let mut nonces = Vec::new();
+    for _ in 0..2 {
+        let res = router
+            .clone()
+            .oneshot(Request::get("/script").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let csp = res.headers()[header::CONTENT_SECURITY_POLICY]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let bytes = axum::body::to_bytes(res.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(bytes.to_vec()).unwrap();
+        let nonce = body_nonce(&body);
+        // Le nonce du HTML (éventuellement rejoué) est celui autorisé par l'en-tête
+        assert!(csp.contains(&format!("'nonce-{nonce}'")), "{csp}\n{body}");
+        nonces.push(nonce);
+    }
+    assert_eq!(nonces[0], nonces[1], "la seconde réponse vient du cache");
+}
+
+async fn page_negociee(
+    mut req: TplRequest,
+) -> runique::utils::aliases::AppResult<axum::response::Response> {
+    req.respond("page.html")
+}
+
+#[tokio::test]
+async fn test_cache_page_separe_html_et_json() {
+    let dir = TestTempDir::new("runique_test_cache", "negociation");
+    fs::write(dir.join("page.html"), "<h1>page</h1>").unwrap();
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    config.static_files.templates_dir = vec![dir.as_str().to_string()];
+    let cache = MemoryCache::new();
+    let router = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_template_autoreload(false)
+        .with_database(test_db().await)
+        .with_cache(cache.clone())
+        .routes(Router::new().cache_page("/liste", "liste_cache", get(page_negociee), MINUTE))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"))
+        .router;
+
+    let get_as = |accept: &'static str| {
+        let router = router.clone();
+        async move {
+            let res = router
+                .oneshot(
+                    Request::get("/liste")
+                        .header(header::ACCEPT, accept)
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(res.headers()[header::VARY], "Accept");
+            let bytes = axum::body::to_bytes(res.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            String::from_utf8(bytes.to_vec()).unwrap()
+        }
+    };
+
+    // Le JSON mis en cache n'est pas servi au navigateur, ni l'inverse
+    assert!(get_as("application/json").await.starts_with('{'));
+    assert!(get_as("text/html").await.contains("<h1>page</h1>"));
+    assert!(get_as("application/json").await.starts_with('{'));
+    assert_eq!(cache.len(), 2);
+    assert!(
+        cache
+            .get(&format!("{}#json", page_key("/liste", None)))
+            .await
+            .unwrap()
+            .is_some()
+    );
+}
@@ -1,6 +1,7 @@
 //! Tests — engine/core.rs : RuniqueEngine::attach_middlewares()
 
 use axum::Router;
+use runique::app::LiveTera;
 use runique::engine::RuniqueEngine;
 
 use crate::helpers::server::build_engine;
@@ -34,14 +35,20 @@ async fn test_attach_middlewares_with_host_validation_enabled() {
 
     let engine = Arc::new(RuniqueEngine {
         config,
-        tera: Arc::new(Tera::default()),
+        tera: LiveTera::new(Tera::default()),
         db: Arc::new(db),
+        tasks: Default::default(),
+        scheduler: Default::default(),
+        cache: Default::default(),
         url_registry: Arc::new(std::sync::RwLock::new(std::collections::HashMap::new())),
+        i18n: Arc::new(runique::utils::trad::Catalogs::default()),
         features,
         security_csp: Arc::new(SecurityPolicy::default()),
         security_hosts: Arc::new(HostPolicy::new(vec!["localhost".to_string()], false)),
         csrf_exempt_paths: Arc::new(vec![]),
+        trusted_origins: Arc::new(vec![]),
         permissions_policy: Arc::new(runique::middleware::PermissionsPolicy::default()),
+        security_headers: Arc::new(runique::middleware::SecurityHeaders::default()),
         trusted_proxies: Arc::new(runique::middleware::TrustedProxies::default()),
         session_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
         session_db_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
@@ -68,14 +75,20 @@ async fn test_attach_middlewares_with_https_redirect() {
 
     let engine = Arc::new(RuniqueEngine {
         config,
-        tera: Arc::new(Tera::default()),
+        tera: LiveTera::new(Tera::default()),
         db: Arc::new(db),
+        tasks: Default::default(),
+        scheduler: Default::default(),
+        cache: Default::default(),
         url_registry: Arc::new(std::sync::RwLock::new(std::collections::HashMap::new())),
+        i18n: Arc::new(runique::utils::trad::Catalogs::default()),
         features: MiddlewareConfig::default(),
         security_csp: Arc::new(SecurityPolicy::default()),
         security_hosts: Arc::new(HostPolicy::new(vec![], true)),
         csrf_exempt_paths: Arc::new(vec![]),
+        trusted_origins: Arc::new(vec![]),
         permissions_policy: Arc::new(runique::middleware::PermissionsPolicy::default()),
+        security_headers: Arc::new(runique::middleware::SecurityHeaders::default()),
         trusted_proxies: Arc::new(runique::middleware::TrustedProxies::default()),
         session_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
         session_db_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
@@ -0,0 +1,195 @@
+//! Tests — app/health.rs : sondes `/healthz` et `/readyz`
+//! Couvre : liveness toujours 200, readiness avec `SELECT 1`, état du pool sur option, contrôle
+//!          du cache (erreur et dépassement du délai → 503 + nom du contrôle, sans le détail),
+//!          sondes hors allowed hosts, chemins personnalisés et validation
+
+use async_trait::async_trait;
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+    routing::get,
+};
+use runique::app::{HealthConfig, RuniqueAppBuilder};
+use runique::cache::{Cache, CacheError};
+use runique::config::app::RuniqueConfig;
+use runique::testing::test_db;
+use serde_json::Value;
+use std::time::Duration;
+use tower::ServiceExt;
+
+/// Cache dont le `ping` échoue ou ne répond pas.
+enum CacheEnPanne {
+    Erreur,
+    Lent,
+}
+
+#[async_trait]
+impl Cache for CacheEnPanne {
+    async fn get(&self, _key: &str) -> Result<Option<Vec<u8>>, CacheError> {
+        match self {
+            Self::Erreur => Err(CacheError::Backend("connexion refusée".into())),
+            Self::Lent => {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(None)
+            }
+        }
+    }
+    async fn set_with_ttl(&self, _: &str, _: Vec<u8>, _: Duration) -> Result<(), CacheError> {
+        Ok(())
+    }
+    async fn delete(&self, _: &str) -> Result<(), CacheError> {
+        Ok(())
+    }
+    async fn delete_prefix(&self, _: &str) -> Result<usize, CacheError> {
+        Ok(0)
+    }
+}
+
+fn builder() -> RuniqueAppBuilder {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    RuniqueAppBuilder::new(config)
+        .no_statics()
+        .routes(Router::new().route("/", get(|| async { "accueil" })))
+}
+
+async fn app(builder: RuniqueAppBuilder) -> Router {
+    builder
+        .with_database(test_db().await)
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"))
+        .router
+}
+
+async fn sonde(router: &Router, uri: &str, host: &str) -> (StatusCode, Value) {
+    let res = router
+        .clone()
+        .oneshot(
+            Request::get(uri)
+                .header(header::HOST, host)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = res.status();
+    if status.is_success() || status == StatusCode::SERVICE_UNAVAILABLE {
+        assert_eq!(res.headers()[header::CACHE_CONTROL], "no-store");
+    }
+    let bytes = axum::body::to_bytes(res.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+#[tokio::test]
+async fn test_healthz_et_readyz_ok() {
+    let router = app(builder().with_health()).await;
+
+    let (status, body) = sonde(&router, "/healthz", "localhost").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "ok");
+
+    let (status, body) = sonde(&router, "/readyz", "localhost").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["checks"]["database"], "ok");
+    assert!(body["checks"].get("cache").is_none());
+    // Sonde non authentifiée : pas d'état du pool sans opt-in
+    assert!(body.get("pool").is_none(), "{body}");
+}
+
+#[tokio::test]
+async fn test_readyz_etat_du_pool_sur_option() {
+    let router = app(builder().with_health_config(HealthConfig::expose_pool_stats)).await;
+
+    let (status, body) = sonde(&router, "/readyz", "localhost").await;
+    assert_eq!(status, StatusCode::OK);
+    // État du pool sqlx (la connexion du `SELECT 1` peut ne pas être encore rendue)
+    let pool = &body["pool"];
+    assert!(pool["size"].as_u64().unwrap() >= 1, "{body}");
+    assert_eq!(
+        pool["idle"].as_u64().unwrap() + pool["in_use"].as_u64().unwrap(),
+        pool["size"].as_u64().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_sondes_hors_allowed_hosts() {
+    let router = app(builder()
+        .with_health()
+        .middleware(|m| m.with_allowed_hosts(|h| h.enabled(true).host("exemple.com"))))
+    .await;
+
+    let (status, _) = sonde(&router, "/", "malveillant.com").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    // Les sondes Kubernetes interrogent l'IP du pod, pas le domaine
+    let (status, _) = sonde(&router, "/healthz", "10.0.0.7:8080").await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = sonde(&router, "/readyz", "10.0.0.7:8080").await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_readyz_cache_en_erreur_503() {
+    let router = app(builder()
+        .with_cache(CacheEnPanne::Erreur)
+        .with_health_config(HealthConfig::check_cache))
+    .await;
+
+    let (status, body) = sonde(&router, "/readyz", "localhost").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["status"], "unavailable");
+    assert_eq!(body["checks"]["database"], "ok");
+    assert_eq!(body["checks"]["cache"], "unavailable");
+    assert_eq!(body["reason"], "cache");
+    // L'erreur du backend reste dans les logs
+    assert!(!body.to_string().contains("connexion refusée"), "{body}");
+    // La liveness ne dépend pas des dépendances
+    assert_eq!(
+        sonde(&router, "/healthz", "localhost").await.0,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn test_readyz_delai_depasse_503() {
+    let router = app(builder()
+        .with_cache(CacheEnPanne::Lent)
+        .with_health_config(|h| {
+            h.timeout(Duration::from_millis(50))
+                .check_cache()
+                .liveness_path("/sante")
+                .readiness_path("/pret")
+        }))
+    .await;
+
+    let (status, body) = sonde(&router, "/pret", "localhost").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["reason"], "cache");
+    assert_eq!(body["checks"]["cache"], "unavailable");
+    assert_eq!(
+        sonde(&router, "/sante", "localhost").await.0,
+        StatusCode::OK
+    );
+    assert_eq!(
+        sonde(&router, "/healthz", "localhost").await.0,
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[tokio::test]
+async fn test_chemin_invalide_refuse_au_build() {
+    let err = builder()
+        .with_database(test_db().await)
+        .with_health_config(|h| h.readiness_path("readyz"))
+        .build()
+        .await
+        .err()
+        .expect("chemin sans `/` refusé");
+    assert!(err.to_string().contains("health"), "{err}");
+}
@@ -0,0 +1,164 @@
+//! Tests — app/route_list.rs : liste des routes nommées `/__routes`
+//! Couvre : montée en debug uniquement, JSON sur `Accept: application/json`,
+//!          tableau HTML par défaut, méthodes lues sans exécuter les handlers,
+//!          routes de l'admin sans méthodes
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+    routing::get,
+};
+use runique::app::RuniqueApp;
+use runique::auth::session::{AdminAuth, AdminLoginResult};
+use runique::config::RuniqueConfig;
+use runique::macros::reverse;
+use runique::testing::test_db;
+use runique::urlpatterns;
+use sea_orm::DatabaseConnection;
+use serde_json::{Value, json};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tower::ServiceExt;
+
+struct MockAdminAuth;
+
+#[async_trait::async_trait]
+impl AdminAuth for MockAdminAuth {
+    async fn authenticate(
+        &self,
+        _username: &str,
+        _password: &str,
+        _db: &DatabaseConnection,
+    ) -> Option<AdminLoginResult> {
+        None
+    }
+}
+
+static APPELS: AtomicUsize = AtomicUsize::new(0);
+
+async fn compte() -> &'static str {
+    APPELS.fetch_add(1, Ordering::SeqCst);
+    "ok"
+}
+
+async fn app(debug: bool) -> RuniqueApp {
+    let mut config = RuniqueConfig {
+        debug,
+        ..Default::default()
+    };
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    RuniqueApp::builder(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .routes(urlpatterns! {
+            "/articles/{slug}" => get(compte), name = "rl_article",
+            "/connexion" => get(compte).post(compte), name = "rl_connexion",
+        })
+        // `admin` est inscrit directement dans le registre du moteur par `build()`
+        .with_admin(|a| a.prefix("/gestion").auth(MockAdminAuth))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"))
+}
+
+/// Les noms de `urlpatterns!` passent par un registre global drainé par le premier
+/// `build()` venu : un test parallèle peut les capter, on reconstruit alors.
+async fn app_debug() -> Router {
+    for _ in 0..5 {
+        let app = app(true).await;
+        if reverse(&app.engine, "rl_article").is_some() {
+            return app.router;
+        }
+    }
+    panic!("noms de routes captés par un autre build");
+}
+
+async fn routes(router: &Router, accept: &str) -> (StatusCode, Option<String>, String) {
+    let res = router
+        .clone()
+        .oneshot(
+            Request::get("/__routes")
+                .header(header::ACCEPT, accept)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = res.status();
+    let content_type = res
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .map(|v| v.to_str().unwrap().to_string());
+    let bytes = axum::body::to_bytes(res.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        content_type,
+        String::from_utf8_lossy(&bytes).to_string(),
+    )
+}
+
+fn entree<'a>(liste: &'a Value, name: &str) -> &'a Value {
+    liste
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["name"] == name)
+        .unwrap_or_else(|| panic!("`{name}` absent : {liste}"))
+}
+
+#[tokio::test]
+async fn test_json_liste_noms_motifs_et_methodes() {
+    let router = app_debug().await;
+    let (status, content_type, body) = routes(&router, "application/json").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(content_type.unwrap().starts_with("application/json"));
+
+    let liste: Value = serde_json::from_str(&body).unwrap();
+    let article = entree(&liste, "rl_article");
+    assert_eq!(article["pattern"], "/articles/{slug}");
+    assert_eq!(article["methods"], json!(["GET", "HEAD"]));
+    assert_eq!(
+        entree(&liste, "rl_connexion")["methods"],
+        json!(["GET", "HEAD", "POST"])
+    );
+    // Routes de l'admin : derrière leurs propres layers, listées sans méthodes
+    let admin = entree(&liste, "admin");
+    assert_eq!(admin["pattern"], "/gestion");
+    assert_eq!(admin["methods"], json!([]));
+}
+
+#[tokio::test]
+async fn test_sondage_n_execute_pas_les_handlers() {
+    let avant = APPELS.load(Ordering::SeqCst);
+    let router = app_debug().await;
+    routes(&router, "application/json").await;
+    assert_eq!(APPELS.load(Ordering::SeqCst), avant);
+}
+
+#[tokio::test]
+async fn test_html_par_defaut() {
+    let router = app_debug().await;
+    let (status, content_type, body) = routes(&router, "text/html,*/*;q=0.8").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(content_type.unwrap().starts_with("text/html"));
+    assert!(body.contains("<table>"), "{body}");
+    assert!(
+        body.contains(
+            "<td><code>rl_connexion</code></td><td><code>/connexion</code></td><td>GET, HEAD, POST</td>"
+        ),
+        "{body}"
+    );
+    assert!(
+        body.contains("<td><code>admin</code></td><td><code>/gestion</code></td><td>—</td>"),
+        "{body}"
+    );
+}
+
+#[tokio::test]
+async fn test_absente_hors_debug() {
+    let router = app(false).await.router;
+    let (status, _, _) = routes(&router, "application/json").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
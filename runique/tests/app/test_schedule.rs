@@ -0,0 +1,216 @@
+//! Tests — tasks/schedule.rs : tâches périodiques
+//! Couvre : `every()` déclenché jusqu'à l'arrêt, exécution encore en cours → occurrence
+//!          sautée (`allow_overlap` pour chevaucher), erreur / panique sans arrêt du
+//!          planificateur, arrêt qui attend l'exécution en cours, cron invalide → build refusé
+
+use runique::app::{RuniqueApp, RuniqueAppBuilder};
+use runique::config::app::RuniqueConfig;
+use runique::tasks::{Scheduler, SchedulerHandle, TaskError};
+use runique::testing::test_db;
+use runique::utils::aliases::ADb;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use std::time::{Duration, Instant};
+
+async fn build(f: impl FnOnce(&mut Scheduler)) -> Result<RuniqueApp, String> {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .with_schedule(f)
+        .build()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn scheduler(f: impl FnOnce(&mut Scheduler)) -> SchedulerHandle {
+    build(f)
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"))
+        .engine
+        .scheduler
+        .clone()
+}
+
+/// Compte les exécutions et la concurrence maximale observée.
+#[derive(Clone, Default)]
+struct Sonde {
+    runs: Arc<AtomicUsize>,
+    current: Arc<AtomicUsize>,
+    max: Arc<AtomicUsize>,
+}
+
+impl Sonde {
+    async fn run(&self, duration: Duration) {
+        let now = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max.fetch_max(now, Ordering::SeqCst);
+        tokio::time::sleep(duration).await;
+        self.current.fetch_sub(1, Ordering::SeqCst);
+        self.runs.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn runs(&self) -> usize {
+        self.runs.load(Ordering::SeqCst)
+    }
+
+    fn max(&self) -> usize {
+        self.max.load(Ordering::SeqCst)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// every()
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_every_declenche_jusqu_a_l_arret() {
+    let sonde = Sonde::default();
+    let s = sonde.clone();
+    let handle = scheduler(move |sched| {
+        sched.every(Duration::from_millis(40)).run(move |_db: ADb| {
+            let s = s.clone();
+            async move {
+                s.run(Duration::ZERO).await;
+                Ok(())
+            }
+        });
+    })
+    .await;
+    assert!(handle.is_running());
+
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    assert!(sonde.runs() >= 3, "{} exécutions", sonde.runs());
+
+    assert!(handle.shutdown(Duration::from_secs(1)).await);
+    assert!(!handle.is_running());
+    let after = sonde.runs();
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(sonde.runs(), after, "déclenché après l'arrêt");
+}
+
+#[tokio::test]
+async fn test_sans_tache_planificateur_inactif() {
+    let handle = scheduler(|_| {}).await;
+    assert!(!handle.is_running());
+    assert!(handle.shutdown(Duration::from_millis(10)).await);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Chevauchement
+// ═══════════════════════════════════════════════════════════════
+
+async fn lente(allow_overlap: bool) -> (Sonde, SchedulerHandle) {
+    let sonde = Sonde::default();
+    let s = sonde.clone();
+    let handle = scheduler(move |sched| {
+        let entry = sched.every(Duration::from_millis(20)).name("lente");
+        let entry = if allow_overlap {
+            entry.allow_overlap()
+        } else {
+            entry
+        };
+        entry.run(move |_db| {
+            let s = s.clone();
+            async move {
+                s.run(Duration::from_millis(120)).await;
+                Ok(())
+            }
+        });
+    })
+    .await;
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    (sonde, handle)
+}
+
+#[tokio::test]
+async fn test_execution_en_cours_occurrence_sautee() {
+    let (sonde, handle) = lente(false).await;
+    assert!(handle.shutdown(Duration::from_secs(1)).await);
+    assert_eq!(sonde.max(), 1);
+    assert!(sonde.runs() >= 2, "{} exécutions", sonde.runs());
+}
+
+#[tokio::test]
+async fn test_allow_overlap_chevauche() {
+    let (sonde, handle) = lente(true).await;
+    assert!(handle.shutdown(Duration::from_secs(1)).await);
+    assert!(sonde.max() > 1, "concurrence max {}", sonde.max());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Échecs et arrêt
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_erreur_et_panique_n_arretent_pas_le_planificateur() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let r = runs.clone();
+    let handle = scheduler(move |sched| {
+        sched.every(Duration::from_millis(30)).run(move |_db| {
+            let n = r.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if n.is_multiple_of(2) {
+                    panic!("tâche planifiée en panique");
+                }
+                Err(TaskError::new("échec"))
+            }
+        });
+    })
+    .await;
+
+    // Une panique imprime sa trace : attente bornée plutôt que fixe
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while runs.load(Ordering::SeqCst) < 4 && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(runs.load(Ordering::SeqCst) >= 4);
+    assert!(handle.shutdown(Duration::from_secs(1)).await);
+}
+
+#[tokio::test]
+async fn test_arret_attend_l_execution_en_cours() {
+    let sonde = Sonde::default();
+    let s = sonde.clone();
+    let handle = scheduler(move |sched| {
+        sched.every(Duration::from_millis(20)).run(move |_db| {
+            let s = s.clone();
+            async move {
+                s.run(Duration::from_millis(300)).await;
+                Ok(())
+            }
+        });
+    })
+    .await;
+    tokio::time::sleep(Duration::from_millis(60)).await;
+
+    // Trop court : l'exécution continue, elle n'est pas annulée
+    assert!(!handle.shutdown(Duration::from_millis(10)).await);
+    assert!(handle.shutdown(Duration::from_secs(1)).await);
+    assert_eq!(sonde.runs(), 1);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Déclaration
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_cron_invalide_refuse_le_build() {
+    let err = build(|sched| {
+        sched.cron("0 3 * * *").run(|_db| async { Ok(()) });
+        sched.cron("pas une expression").run(|_db| async { Ok(()) });
+    })
+    .await
+    .err()
+    .expect("build accepté");
+    assert!(err.contains("pas une expression"), "{err}");
+
+    let mut sched = Scheduler::default();
+    sched.cron("0 3 * * *").run(|_db| async { Ok(()) });
+    sched.cron("*/15 * * * 1-5").run(|_db| async { Ok(()) });
+    sched.every(Duration::ZERO).run(|_db| async { Ok(()) });
+    assert_eq!(sched.len(), 2);
+    assert_eq!(sched.errors().len(), 1);
+}
@@ -0,0 +1,171 @@
+//! Tests — tasks/ : file de tâches en arrière-plan
+//! Couvre : tâche exécutée par le worker de `.with_worker(n)`, enqueue sans worker
+//!          refusé, nouvelle tentative avec backoff puis succès, abandon après
+//!          `max_retries` (panique comprise), drain, délai de backoff plafonné
+
+use runique::app::RuniqueAppBuilder;
+use runique::config::app::RuniqueConfig;
+use runique::context::AppError;
+use runique::sea_orm::DatabaseConnection;
+use runique::tasks::{EnqueueError, Task, TaskConfig, TaskError, TaskQueue};
+use runique::testing::test_db;
+use std::sync::{
+    Arc,
+    atomic::{AtomicU32, Ordering},
+};
+use std::time::Duration;
+
+/// Échoue `failures` fois puis réussit ; compte les tentatives.
+struct Flaky {
+    attempts: Arc<AtomicU32>,
+    failures: u32,
+}
+
+#[runique::async_trait::async_trait]
+impl Task for Flaky {
+    async fn run(&self, _db: &DatabaseConnection) -> Result<(), TaskError> {
+        let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+        if attempt < self.failures {
+            return Err(TaskError::new(format!("échec {attempt}")));
+        }
+        Ok(())
+    }
+}
+
+struct Panique(Arc<AtomicU32>);
+
+#[runique::async_trait::async_trait]
+impl Task for Panique {
+    async fn run(&self, _db: &DatabaseConnection) -> Result<(), TaskError> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        panic!("tâche en panique");
+    }
+}
+
+fn flaky(failures: u32) -> (Flaky, Arc<AtomicU32>) {
+    let attempts = Arc::new(AtomicU32::new(0));
+    let task = Flaky {
+        attempts: attempts.clone(),
+        failures,
+    };
+    (task, attempts)
+}
+
+async fn queue(config: TaskConfig) -> TaskQueue {
+    let mut app_config = RuniqueConfig::default();
+    app_config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    let app = RuniqueAppBuilder::new(app_config)
+        .no_statics()
+        .with_database(test_db().await)
+        .with_worker(config.workers)
+        .with_tasks(|_| config)
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    app.engine.tasks.clone()
+}
+
+fn rapide() -> TaskConfig {
+    TaskConfig::default()
+        .workers(2)
+        .backoff(Duration::from_millis(10))
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Worker
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_tache_executee_par_le_worker() {
+    let tasks = queue(rapide()).await;
+    assert!(tasks.is_enabled());
+
+    let (task, attempts) = flaky(0);
+    tasks.enqueue(task).await.unwrap();
+    assert!(tasks.drain_timeout(Duration::from_secs(2)).await);
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    assert_eq!(tasks.pending(), 0);
+}
+
+#[tokio::test]
+async fn test_enqueue_sans_worker_refuse() {
+    let tasks = queue(TaskConfig::default()).await;
+    assert!(!tasks.is_enabled());
+
+    let (task, attempts) = flaky(0);
+    assert_eq!(tasks.enqueue(task).await, Err(EnqueueError::NoWorker));
+    // `?` dans un handler : erreur 500
+    let err: Box<AppError> = EnqueueError::NoWorker.into();
+    assert_eq!(err.context.status_code, 500);
+    // Rien en attente : drain rend la main immédiatement
+    assert!(tasks.drain_timeout(Duration::from_millis(50)).await);
+    assert_eq!(attempts.load(Ordering::SeqCst), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Nouvelles tentatives
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_nouvelle_tentative_puis_succes() {
+    let tasks = queue(rapide().max_retries(3)).await;
+
+    let (task, attempts) = flaky(2);
+    tasks.enqueue(task).await.unwrap();
+    // En attente d'une nouvelle tentative : toujours comptée
+    assert!(tasks.drain_timeout(Duration::from_secs(2)).await);
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_abandon_apres_max_retries() {
+    let tasks = queue(rapide().max_retries(2)).await;
+
+    let (task, attempts) = flaky(u32::MAX);
+    tasks.enqueue(task).await.unwrap();
+    let paniques = Arc::new(AtomicU32::new(0));
+    tasks.enqueue(Panique(paniques.clone())).await.unwrap();
+
+    assert!(tasks.drain_timeout(Duration::from_secs(2)).await);
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    assert_eq!(paniques.load(Ordering::SeqCst), 3);
+
+    // Le worker survit à la panique
+    let (task, attempts) = flaky(0);
+    tasks.enqueue(task).await.unwrap();
+    assert!(tasks.drain_timeout(Duration::from_secs(2)).await);
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_drain_attend_le_backoff() {
+    let tasks = queue(
+        TaskConfig::default()
+            .workers(1)
+            .backoff(Duration::from_millis(300)),
+    )
+    .await;
+
+    let (task, attempts) = flaky(1);
+    tasks.enqueue(task).await.unwrap();
+    assert!(!tasks.drain_timeout(Duration::from_millis(100)).await);
+    assert_eq!(tasks.pending(), 1);
+    assert!(tasks.drain_timeout(Duration::from_secs(2)).await);
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Backoff
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_delai_exponentiel_plafonne() {
+    let config = TaskConfig::default()
+        .backoff(Duration::from_secs(1))
+        .max_backoff(Duration::from_secs(5));
+    assert_eq!(config.retry_delay(0), Duration::from_secs(1));
+    assert_eq!(config.retry_delay(1), Duration::from_secs(2));
+    assert_eq!(config.retry_delay(2), Duration::from_secs(4));
+    assert_eq!(config.retry_delay(3), Duration::from_secs(5));
+    assert_eq!(config.retry_delay(40), Duration::from_secs(5));
+}
@@ -0,0 +1,110 @@
+//! Tests — app/template_reload.rs : rechargement des templates
+//! Couvre : LiveTera (instantané conservé pendant un swap), template modifié visible
+//!          sans redémarrage, erreur de parsing → version précédente conservée,
+//!          rechargement désactivé → instance compilée au démarrage
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use axum::{Router, response::IntoResponse, routing::get};
+use runique::app::{LiveTera, RuniqueAppBuilder};
+use runique::config::app::RuniqueConfig;
+use runique::context::Request as TplRequest;
+use runique::testing::{TestClient, test_db};
+use std::fs;
+use std::time::{Duration, Instant};
+use tera::{Context, Tera};
+
+async fn page(mut req: TplRequest) -> impl IntoResponse {
+    req.render("page.html")
+}
+
+async fn client(dir: &TestTempDir, autoreload: bool) -> TestClient {
+    fs::write(dir.join("page.html"), "v1").unwrap();
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    config.static_files.templates_dir = vec![dir.as_str().to_string()];
+    let app = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_template_autoreload(autoreload)
+        .with_database(test_db().await)
+        .routes(Router::new().route("/page", get(page)))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    TestClient::from_app(app)
+}
+
+/// Attend que `/page` rende `expected` (le watcher tourne sur un thread dédié).
+async fn wait_for(client: &TestClient, expected: &str) -> bool {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        if client.get("/page").await.text() == expected {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    false
+}
+
+fn tera_with(content: &str) -> Tera {
+    let mut tera = Tera::default();
+    tera.add_raw_template("t", content).unwrap();
+    tera
+}
+
+// ═══════════════════════════════════════════════════════════════
+// LiveTera
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_live_tera_swap_conserve_l_instantane() {
+    let live = LiveTera::new(tera_with("avant"));
+    let snapshot = live.current();
+
+    live.clone().swap(tera_with("après"));
+    let ctx = Context::new();
+    assert_eq!(snapshot.render("t", &ctx).unwrap(), "avant");
+    assert_eq!(live.current().render("t", &ctx).unwrap(), "après");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Watcher
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_template_modifie_recharge() {
+    let dir = TestTempDir::new("runique_test_template_reload", "modifie");
+    let client = client(&dir, true).await;
+    assert_eq!(client.get("/page").await.text(), "v1");
+
+    fs::write(dir.join("page.html"), "v2").unwrap();
+    assert!(wait_for(&client, "v2").await, "template non rechargé");
+}
+
+#[tokio::test]
+async fn test_erreur_de_parsing_garde_la_version_precedente() {
+    let dir = TestTempDir::new("runique_test_template_reload", "erreur");
+    let client = client(&dir, true).await;
+
+    fs::write(dir.join("page.html"), "{% if %}cassé").unwrap();
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    let resp = client.get("/page").await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resp.text(), "v1");
+
+    // Corrigé : le rechargement reprend
+    fs::write(dir.join("page.html"), "v3").unwrap();
+    assert!(
+        wait_for(&client, "v3").await,
+        "template non rechargé après correction"
+    );
+}
+
+#[tokio::test]
+async fn test_rechargement_desactive() {
+    let dir = TestTempDir::new("runique_test_template_reload", "desactive");
+    let client = client(&dir, false).await;
+
+    fs::write(dir.join("page.html"), "v2").unwrap();
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    assert_eq!(client.get("/page").await.text(), "v1");
+}
@@ -0,0 +1,109 @@
+//! Tests — RuniqueAppBuilder::with_tera : filtres et fonctions Tera personnalisés
+//! Couvre : filtre utilisateur rendu, remplacement d'un filtre intégré,
+//!          hooks cumulés dans l'ordre, hook réappliqué après rechargement
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use axum::{Router, response::IntoResponse, routing::get};
+use runique::app::RuniqueAppBuilder;
+use runique::config::app::RuniqueConfig;
+use runique::context::Request as TplRequest;
+use runique::testing::{TestClient, test_db};
+use std::collections::HashMap;
+use std::fs;
+use std::time::{Duration, Instant};
+use tera::{Tera, Value};
+
+async fn page(mut req: TplRequest) -> impl IntoResponse {
+    req.render("page.html")
+}
+
+fn money(value: &Value, _: &HashMap<String, Value>) -> tera::Result<Value> {
+    Ok(Value::String(format!(
+        "{:.2} €",
+        value.as_f64().unwrap_or_default()
+    )))
+}
+
+async fn client(
+    dir: &TestTempDir,
+    template: &str,
+    configure: impl FnOnce(RuniqueAppBuilder) -> RuniqueAppBuilder,
+) -> TestClient {
+    fs::write(dir.join("page.html"), template).unwrap();
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    config.static_files.templates_dir = vec![dir.as_str().to_string()];
+    let builder = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_template_autoreload(false);
+    let app = configure(builder)
+        .with_database(test_db().await)
+        .routes(Router::new().route("/page", get(page)))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    TestClient::from_app(app)
+}
+
+#[tokio::test]
+async fn test_filtre_personnalise_rendu() {
+    let dir = TestTempDir::new("runique_test_tera_hooks", "filtre");
+    let client = client(&dir, "{{ 12.5 | money }}", |b| {
+        b.with_tera(|tera: &mut Tera| tera.register_filter("money", money))
+    })
+    .await;
+
+    assert_eq!(client.get("/page").await.text(), "12.50 €");
+}
+
+#[tokio::test]
+async fn test_remplace_un_filtre_integre() {
+    let dir = TestTempDir::new("runique_test_tera_hooks", "remplace");
+    let client = client(&dir, "{{ 3 | humanize }}", |b| {
+        b.with_tera(|tera: &mut Tera| {
+            tera.register_filter("humanize", |_: &Value, _: &HashMap<String, Value>| {
+                Ok(Value::String("remplacé".into()))
+            })
+        })
+    })
+    .await;
+
+    assert_eq!(client.get("/page").await.text(), "remplacé");
+}
+
+#[tokio::test]
+async fn test_hooks_cumules_dans_l_ordre() {
+    let dir = TestTempDir::new("runique_test_tera_hooks", "ordre");
+    let client = client(&dir, "{{ 1 | money }}{{ signature() }}", |b| {
+        b.with_tera(|tera: &mut Tera| tera.register_filter("money", money))
+            .with_tera(|tera: &mut Tera| {
+                tera.register_function("signature", |_: &HashMap<String, Value>| {
+                    Ok(Value::String(" — ok".into()))
+                })
+            })
+    })
+    .await;
+
+    assert_eq!(client.get("/page").await.text(), "1.00 € — ok");
+}
+
+#[tokio::test]
+async fn test_hook_reapplique_apres_rechargement() {
+    let dir = TestTempDir::new("runique_test_tera_hooks", "rechargement");
+    let client = client(&dir, "{{ 1 | money }}", |b| {
+        b.with_template_autoreload(true)
+            .with_tera(|tera: &mut Tera| tera.register_filter("money", money))
+    })
+    .await;
+    assert_eq!(client.get("/page").await.text(), "1.00 €");
+
+    fs::write(dir.join("page.html"), "v2 {{ 2 | money }}").unwrap();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while client.get("/page").await.text() != "v2 2.00 €" {
+        assert!(
+            Instant::now() < deadline,
+            "filtre absent après rechargement"
+        );
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
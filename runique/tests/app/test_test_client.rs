@@ -0,0 +1,128 @@
+//! Tests — testing/client.rs : TestClient sur une RuniqueApp complète
+//! Couvre : cookies de session conservés (connexion puis page protégée),
+//!          token CSRF ajouté aux formulaires et au header JSON, POST sans token
+//!          refusé, extraction du token d'une page, base SQLite mémoire injectée
+
+use axum::{
+    Json, Router,
+    http::StatusCode,
+    response::{IntoResponse, Redirect, Response},
+    routing::{get, post},
+};
+use runique::app::RuniqueAppBuilder;
+use runique::config::app::RuniqueConfig;
+use runique::context::Request;
+use runique::testing::{TestClient, test_db};
+use sea_orm::ConnectionTrait;
+use serde_json::{Value, json};
+
+const USER_KEY: &str = "test_client_user";
+
+async fn login_page(req: Request) -> String {
+    let token = req.csrf_token.masked().unwrap_or(req.csrf_token.clone());
+    format!(
+        r#"<form method="post"><input type="hidden" name="csrf_token" value="{}"></form>"#,
+        token.as_str()
+    )
+}
+
+async fn login(req: Request) -> Response {
+    let Some(data) = req.prisme.checked_data() else {
+        return StatusCode::FORBIDDEN.into_response();
+    };
+    let username = data.get("username").cloned().unwrap_or_default();
+    req.session.insert(USER_KEY, username).await.unwrap();
+    Redirect::to("/private").into_response()
+}
+
+async fn private(req: Request) -> Response {
+    match req.session.get::<String>(USER_KEY).await.ok().flatten() {
+        Some(user) => format!("bonjour {user}").into_response(),
+        None => StatusCode::UNAUTHORIZED.into_response(),
+    }
+}
+
+async fn api(req: Request) -> Json<Value> {
+    let user = req.session.get::<String>(USER_KEY).await.ok().flatten();
+    Json(json!({ "user": user }))
+}
+
+async fn client() -> TestClient {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    let routes = Router::new()
+        .route("/login", get(login_page).post(login))
+        .route("/private", get(private))
+        .route("/api", post(api));
+    let db = test_db().await;
+    let app = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .core(|c| c.with_database(db))
+        .routes(routes)
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    TestClient::from_app(app)
+}
+
+#[tokio::test]
+async fn test_connexion_puis_page_protegee() {
+    let client = client().await;
+    assert_eq!(
+        client.get("/private").await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+
+    let resp = client.post("/login", &[("username", "alice")]).await;
+    assert!(resp.status().is_redirection());
+    assert_eq!(resp.location(), Some("/private"));
+
+    let resp = client.get("/private").await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.text(), "bonjour alice");
+}
+
+#[tokio::test]
+async fn test_post_json_avec_header_csrf() {
+    let client = client().await;
+    client.post("/login", &[("username", "bob")]).await;
+    let resp = client.post_json("/api", &json!({})).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.json::<Value>(), json!({ "user": "bob" }));
+}
+
+#[tokio::test]
+async fn test_token_invalide_refuse() {
+    let client = client().await;
+    let resp = client
+        .post("/login", &[("username", "eve"), ("csrf_token", "faux")])
+        .await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    assert_eq!(
+        client.get("/private").await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[tokio::test]
+async fn test_token_extrait_de_la_page() {
+    let client = client().await;
+    let page = client.get("/login").await;
+    let token = page.form_csrf_token().unwrap();
+    let resp = client
+        .post("/login", &[("username", "carol"), ("csrf_token", &token)])
+        .await;
+    assert!(resp.status().is_redirection());
+    assert_eq!(client.get("/private").await.text(), "bonjour carol");
+}
+
+#[tokio::test]
+async fn test_base_memoire_injectee() {
+    let client = client().await;
+    let db = &client.engine().unwrap().db;
+    db.execute_unprepared("CREATE TABLE t (id INTEGER PRIMARY KEY); INSERT INTO t VALUES (1);")
+        .await
+        .unwrap();
+    // Connexion unique : la table créée ici est visible des requêtes suivantes
+    assert_eq!(crate::helpers::db::count(db, "t").await, 1);
+}
@@ -1,11 +1,17 @@
 pub mod test_admin_auth;
+pub mod test_api_token;
 pub mod test_current_user;
 pub mod test_default_admin_auth;
 pub mod test_login_form;
 pub mod test_middlewares;
 pub mod test_password_reset;
+pub mod test_permission_required;
 pub mod test_permissions_cache;
 pub mod test_permissions_db;
+pub mod test_remember_me;
 pub mod test_session_auth;
+pub mod test_session_epoch;
 pub mod test_session_security;
+pub mod test_signed_reset;
+pub mod test_totp;
 pub mod test_user_model;
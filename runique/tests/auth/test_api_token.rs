@@ -0,0 +1,269 @@
+//! Tests — auth/api_token : authentification par jeton Bearer
+//! Couvre : hash / génération, DbTokenStore (create, find, touch, revoke), middleware
+//!          (CurrentUser injecté, 401 jeton inconnu / expiré / compte inactif,
+//!          passage sans header, login_required satisfait par le jeton, POST JSON
+//!          Bearer sans cookie accepté par la pile complète — CSRF non requis)
+
+use axum::{
+    Extension, Router,
+    body::Body,
+    http::{Request, StatusCode},
+    middleware,
+    response::Response,
+    routing::{get, post},
+};
+use runique::app::RuniqueAppBuilder;
+use runique::auth::api_token::{
+    ApiToken, ApiTokenAuth, DbTokenStore, TokenStore, api_token_middleware, generate_api_token,
+    hash_api_token,
+};
+use runique::auth::session::CurrentUser;
+use runique::config::app::RuniqueConfig;
+use runique::macros::RouterExt;
+use runique::testing::{TestClient, test_db};
+use std::collections::HashMap;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use std::time::Duration;
+use tower::ServiceExt;
+use tower_sessions::{MemoryStore, SessionManagerLayer};
+
+use crate::helpers::{
+    assert::{assert_redirect, body_str},
+    db,
+    users::{MockEntity, MockUser},
+};
+
+const SCHEMA: &str = "CREATE TABLE eihwaz_api_tokens (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    token_hash TEXT NOT NULL UNIQUE,
+    user_id INTEGER NOT NULL,
+    expires_at TEXT NULL,
+    last_used_at TEXT NULL
+)";
+
+// ═══════════════════════════════════════════════════════════════
+// Jetons
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_jeton_genere_unique_et_hash_stable() {
+    let token = generate_api_token();
+    assert_eq!(token.len(), 43);
+    assert_ne!(token, generate_api_token());
+    assert_eq!(hash_api_token(&token), hash_api_token(&token));
+    assert_ne!(hash_api_token(&token), token);
+}
+
+#[tokio::test]
+async fn test_db_store_stocke_le_hash_et_revoque() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    let store = DbTokenStore::new(Arc::new(conn));
+    let token = store.create(7, None).await.unwrap();
+
+    let found = store.find(&hash_api_token(&token)).await.unwrap();
+    assert_eq!(found.user_id, 7);
+    assert_eq!(found.expires_at, None);
+    assert!(store.find(&token).await.is_none());
+
+    assert!(store.revoke(&token).await.unwrap());
+    assert!(store.find(&hash_api_token(&token)).await.is_none());
+}
+
+#[tokio::test]
+async fn test_db_store_touch_et_revoke_all() {
+    let conn = Arc::new(db::fresh_db_with_schema(SCHEMA).await);
+    let store = DbTokenStore::new(conn.clone());
+    let token = store
+        .create(8, Some(Duration::from_secs(60)))
+        .await
+        .unwrap();
+    store.create(8, None).await.unwrap();
+
+    store.touch(&hash_api_token(&token)).await;
+    db::exec_expect(
+        &conn,
+        "UPDATE eihwaz_api_tokens SET user_id = 8 WHERE last_used_at IS NOT NULL",
+        1,
+    )
+    .await;
+
+    assert_eq!(store.revoke_all(8).await.unwrap(), 2);
+    db::assert_count(&conn, "eihwaz_api_tokens", 0).await;
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Middleware
+// ═══════════════════════════════════════════════════════════════
+
+/// Jetons en mémoire : "valide" → 9101, "expire" → 9101 périmé, "inactif" → 9102
+struct MemStore {
+    tokens: HashMap<String, ApiToken>,
+    touched: Arc<AtomicUsize>,
+}
+
+impl MemStore {
+    fn new(touched: Arc<AtomicUsize>) -> Self {
+        let past = chrono::Utc::now().naive_utc() - chrono::Duration::hours(1);
+        let tokens = [
+            ("valide", 9101, None),
+            ("expire", 9101, Some(past)),
+            ("inactif", 9102, None),
+        ]
+        .into_iter()
+        .map(|(raw, user_id, expires_at)| {
+            let token_hash = hash_api_token(raw);
+            (
+                token_hash.clone(),
+                ApiToken {
+                    token_hash,
+                    user_id,
+                    expires_at,
+                },
+            )
+        })
+        .collect();
+        Self { tokens, touched }
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenStore for MemStore {
+    async fn find(&self, token_hash: &str) -> Option<ApiToken> {
+        self.tokens.get(token_hash).cloned()
+    }
+    async fn touch(&self, _token_hash: &str) {
+        self.touched.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+async fn app(touched: Arc<AtomicUsize>) -> Router {
+    MockUser::new(9101, "mobile").save();
+    MockUser::new(9102, "mobile").inactive().save();
+    let auth = ApiTokenAuth::new(Arc::new(db::fresh_db().await))
+        .store(MemStore::new(touched))
+        .track_last_used(true);
+    Router::new()
+        .route(
+            "/me",
+            get(|user: Option<Extension<CurrentUser>>| async move {
+                user.map_or("anonyme".to_string(), |Extension(u)| {
+                    format!("{}:{}", u.id, u.username)
+                })
+            }),
+        )
+        .login_required("/private", "private", get(|| async { "prive" }), "/login")
+        .layer(middleware::from_fn_with_state(
+            Arc::new(auth),
+            api_token_middleware::<MockEntity>,
+        ))
+        .layer(SessionManagerLayer::new(MemoryStore::default()).with_secure(false))
+}
+
+async fn call(uri: &str, bearer: Option<&str>) -> (Response, Arc<AtomicUsize>) {
+    let touched = Arc::new(AtomicUsize::new(0));
+    let mut req = Request::builder().uri(uri);
+    if let Some(token) = bearer {
+        req = req.header("authorization", format!("Bearer {token}"));
+    }
+    let resp = app(touched.clone())
+        .await
+        .oneshot(req.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    (resp, touched)
+}
+
+#[tokio::test]
+async fn test_jeton_valide_injecte_current_user() {
+    let (resp, touched) = call("/me", Some("valide")).await;
+    assert_eq!(body_str(resp).await, "9101:mobile");
+    assert_eq!(touched.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_sans_header_passe_en_anonyme() {
+    let (resp, touched) = call("/me", None).await;
+    assert_eq!(body_str(resp).await, "anonyme");
+    assert_eq!(touched.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn test_jetons_refuses_401() {
+    for token in ["inconnu", "expire", "inactif"] {
+        let (resp, touched) = call("/me", Some(token)).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED, "{token}");
+        assert_eq!(
+            resp.headers().get("www-authenticate").unwrap(),
+            "Bearer",
+            "{token}"
+        );
+        assert_eq!(touched.load(Ordering::SeqCst), 0);
+    }
+}
+
+#[tokio::test]
+async fn test_login_required_accepte_le_jeton() {
+    let (resp, _) = call("/private", Some("valide")).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(body_str(resp).await, "prive");
+
+    let (resp, _) = call("/private", None).await;
+    assert_redirect(&resp, "/login");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Pile complète : CSRF
+// ═══════════════════════════════════════════════════════════════
+
+/// Client sans cookie : `POST /api/notes` en JSON, avec ou sans jeton.
+async fn post_note(bearer: Option<&str>) -> runique::testing::TestResponse {
+    MockUser::new(9101, "mobile").save();
+    let auth = ApiTokenAuth::new(Arc::new(db::fresh_db().await))
+        .store(MemStore::new(Arc::new(AtomicUsize::new(0))));
+    let routes = Router::new()
+        .route(
+            "/api/notes",
+            post(|user: Option<Extension<CurrentUser>>| async move {
+                user.map_or("anonyme".to_string(), |Extension(u)| {
+                    format!("{}:{}", u.id, u.username)
+                })
+            }),
+        )
+        .layer(middleware::from_fn_with_state(
+            Arc::new(auth),
+            api_token_middleware::<MockEntity>,
+        ));
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    let app = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .routes(routes)
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+
+    let mut req = Request::post("/api/notes").header("content-type", "application/json");
+    if let Some(token) = bearer {
+        req = req.header("authorization", format!("Bearer {token}"));
+    }
+    TestClient::from_app(app)
+        .request(req.body(Body::from(r#"{"texte":"note"}"#)).unwrap())
+        .await
+}
+
+#[tokio::test]
+async fn test_post_json_bearer_sans_jeton_csrf() {
+    let resp = post_note(Some("valide")).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.text(), "9101:mobile");
+}
+
+#[tokio::test]
+async fn test_post_json_sans_bearer_exige_le_csrf() {
+    let resp = post_note(None).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
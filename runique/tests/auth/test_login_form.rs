@@ -43,6 +43,7 @@ fn test_register_fields_cree_username_et_password() {
     LoginAdmin::register_fields(&mut form);
     assert!(form.fields.contains_key("username"));
     assert!(form.fields.contains_key("password"));
+    assert!(form.fields.contains_key("remember_me"));
 }
 
 #[test]
@@ -0,0 +1,214 @@
+//! Tests — auth/permission.rs + RouterExt::permission_required
+//! Couvre : correspondance avec wildcards, permissions dérivées des droits de groupe,
+//!          CurrentUser::has_permission, garde de route (redirection si anonyme,
+//!          403 si permission absente, accès sinon — CurrentUser injecté ou session)
+
+use axum::{
+    Extension, Router,
+    body::Body,
+    http::{Request, StatusCode},
+    middleware::{self, Next},
+    response::Response,
+    routing::get,
+};
+use runique::admin::permissions::{Groupe, Permission};
+use runique::auth::session::CurrentUser;
+use runique::auth::{granted_permissions, login, permission_matches};
+use runique::macros::RouterExt;
+use runique::utils::pk::Pk;
+use tower::ServiceExt;
+use tower_sessions::{MemoryStore, Session, SessionManagerLayer};
+
+use crate::helpers::{
+    assert::{assert_redirect, assert_status, body_str},
+    server::build_engine,
+};
+
+// ═══════════════════════════════════════════════════════════════
+// Correspondance
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_permission_exacte_et_wildcards() {
+    assert!(permission_matches("blog.update", "blog.update"));
+    assert!(permission_matches("blog.*", "blog.update"));
+    assert!(permission_matches("blog.*", "blog.post.delete"));
+    assert!(permission_matches("*", "media.create"));
+    assert!(!permission_matches("blog.update", "blog.delete"));
+    assert!(!permission_matches("blog.*", "blog"));
+    assert!(!permission_matches("blog.*", "blogs.update"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Permissions dérivées des droits de groupe
+// ═══════════════════════════════════════════════════════════════
+
+fn droit(resource_key: &str, crud: bool) -> Permission {
+    Permission {
+        can_create: crud,
+        can_read: true,
+        can_update: crud,
+        can_delete: crud,
+        ..Permission::zeroed(resource_key.to_string())
+    }
+}
+
+fn groupe(nom: &str, permissions: Vec<Permission>) -> Groupe {
+    Groupe {
+        id: 1,
+        nom: nom.to_string(),
+        permissions,
+    }
+}
+
+fn user(id: Pk, is_superuser: bool, groupes: Vec<Groupe>) -> CurrentUser {
+    CurrentUser {
+        id,
+        username: "alice".to_string(),
+        is_staff: false,
+        is_superuser,
+        groupes,
+    }
+}
+
+#[test]
+fn test_droits_accordes_par_action() {
+    let mut lecture = droit("blog", false);
+    lecture.can_update_own = true;
+    let mut granted = granted_permissions(&lecture);
+    granted.sort();
+    assert_eq!(granted, vec!["blog.read", "blog.update_own"]);
+}
+
+#[test]
+fn test_crud_complet_accorde_le_wildcard() {
+    let granted = granted_permissions(&droit("blog", true));
+    assert!(granted.contains(&"blog.*".to_string()));
+    assert!(granted.contains(&"blog.delete".to_string()));
+}
+
+#[test]
+fn test_has_permission_fusionne_les_groupes() {
+    let u = user(
+        1,
+        false,
+        vec![
+            groupe("lecteurs", vec![droit("blog", false)]),
+            groupe("editeurs", vec![droit("media", true)]),
+        ],
+    );
+    assert!(u.has_permission("blog.read"));
+    assert!(!u.has_permission("blog.update"));
+    assert!(u.has_permission("media.delete"));
+    assert!(u.has_permission("media.*"));
+    assert!(!u.has_permission("shop.read"));
+}
+
+#[test]
+fn test_has_permission_superuser() {
+    assert!(user(2, true, vec![]).has_permission("shop.update"));
+    assert!(!user(3, false, vec![]).has_permission("shop.read"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Garde de route
+// ═══════════════════════════════════════════════════════════════
+
+fn header(req: &Request<Body>, name: &str) -> Option<String> {
+    req.headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+/// `x-current-user: editeur|lecteur` injecte un `CurrentUser` (comme
+/// `load_user_middleware` ou le jeton API) ; `x-session-user: <id>` connecte
+/// l'id en session, superuser si `x-superuser` est présent.
+async fn fake_auth(session: Session, mut req: Request<Body>, next: Next) -> Response {
+    let injected = header(&req, "x-current-user");
+    let session_user = header(&req, "x-session-user").and_then(|v| v.parse::<Pk>().ok());
+    let superuser = header(&req, "x-superuser").is_some();
+
+    match injected.as_deref() {
+        Some("editeur") => {
+            req.extensions_mut().insert(user(
+                35_001,
+                false,
+                vec![groupe("editeurs", vec![droit("blog", true)])],
+            ));
+        }
+        Some(_) => {
+            req.extensions_mut().insert(user(
+                35_002,
+                false,
+                vec![groupe("lecteurs", vec![droit("blog", false)])],
+            ));
+        }
+        None => {}
+    }
+    if let Some(id) = session_user {
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        login(&session, &db, id, "alice", false, superuser, None, false)
+            .await
+            .unwrap();
+    }
+    next.run(req).await
+}
+
+async fn app() -> Router {
+    Router::new()
+        .permission_required(
+            "/blog/edit",
+            "blog_edit",
+            get(|| async { "edition" }),
+            "blog.update",
+            "/login",
+        )
+        .layer(middleware::from_fn(fake_auth))
+        .layer(Extension(build_engine().await))
+        .layer(SessionManagerLayer::new(MemoryStore::default()).with_secure(false))
+}
+
+async fn get_with(headers: &[(&str, &str)]) -> Response {
+    let mut req = Request::builder().uri("/blog/edit");
+    for (name, value) in headers {
+        req = req.header(*name, *value);
+    }
+    app()
+        .await
+        .oneshot(req.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_garde_anonyme_redirige_vers_login() {
+    let resp = get_with(&[]).await;
+    assert_redirect(&resp, "/login");
+}
+
+#[tokio::test]
+async fn test_garde_permission_absente_403() {
+    let resp = get_with(&[("x-current-user", "lecteur")]).await;
+    assert_status(&resp, 403);
+    assert!(body_str(resp).await.contains("403"));
+}
+
+#[tokio::test]
+async fn test_garde_droits_du_groupe_autorisent() {
+    let resp = get_with(&[("x-current-user", "editeur")]).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(body_str(resp).await, "edition");
+}
+
+#[tokio::test]
+async fn test_garde_session_sans_groupe_403() {
+    let resp = get_with(&[("x-session-user", "35003")]).await;
+    assert_status(&resp, 403);
+}
+
+#[tokio::test]
+async fn test_garde_session_superuser_autorise() {
+    let resp = get_with(&[("x-session-user", "35004"), ("x-superuser", "1")]).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
@@ -0,0 +1,84 @@
+//! Tests — auth/session.rs : sessions « se souvenir de moi »
+//! Couvre : login_user_full (drapeau de session, durée d'expiration prolongée),
+//!          login classique non mémorisé, logout qui efface le drapeau
+
+use axum::{Router, response::IntoResponse, routing::get};
+use runique::auth::{is_remembered, login, login_user_full, logout};
+use tower_sessions::{Expiry, MemoryStore, Session, SessionManagerLayer};
+
+use crate::helpers::{
+    assert::{assert_body_str, assert_status},
+    request,
+};
+
+fn build_app(handler: axum::routing::MethodRouter) -> Router {
+    Router::new()
+        .route("/test", get(handler))
+        .layer(SessionManagerLayer::new(MemoryStore::default()).with_secure(false))
+}
+
+fn inactivity_secs(session: &Session) -> i64 {
+    match session.expiry() {
+        Some(Expiry::OnInactivity(d)) => d.whole_seconds(),
+        other => panic!("expiration inattendue : {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_login_remember_prolonge_la_session() {
+    async fn handler(session: Session) -> impl IntoResponse {
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        login(&session, &db, 1, "alice", false, false, None, false)
+            .await
+            .unwrap();
+        let standard = inactivity_secs(&session);
+        let remembered_before = is_remembered(&session).await;
+
+        login_user_full(&session, &db, 1, "alice", false, false, None, false, true)
+            .await
+            .unwrap();
+        let remember = inactivity_secs(&session);
+
+        format!(
+            "{remembered_before}:{}:{}",
+            is_remembered(&session).await,
+            remember > standard
+        )
+    }
+
+    let res = request::get(build_app(get(handler)), "/test").await;
+    assert_status(&res, 200);
+    assert_body_str(res, "false:true:true").await;
+}
+
+#[tokio::test]
+async fn test_login_sans_remember_retire_le_drapeau() {
+    async fn handler(session: Session) -> impl IntoResponse {
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        login_user_full(&session, &db, 1, "alice", false, false, None, false, true)
+            .await
+            .unwrap();
+        login_user_full(&session, &db, 1, "alice", false, false, None, false, false)
+            .await
+            .unwrap();
+        format!("{}", is_remembered(&session).await)
+    }
+
+    let res = request::get(build_app(get(handler)), "/test").await;
+    assert_body_str(res, "false").await;
+}
+
+#[tokio::test]
+async fn test_logout_efface_remember() {
+    async fn handler(session: Session) -> impl IntoResponse {
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        login_user_full(&session, &db, 1, "alice", false, false, None, false, true)
+            .await
+            .unwrap();
+        logout(&session, None).await.unwrap();
+        format!("{}", is_remembered(&session).await)
+    }
+
+    let res = request::get(build_app(get(handler)), "/test").await;
+    assert_body_str(res, "false").await;
+}
@@ -0,0 +1,130 @@
+//! Tests — auth/epoch.rs : invalidation des sessions par époque
+//! Couvre : password_epoch, RuniqueUser::session_epoch par défaut, estampillage au login,
+//!          load_user_middleware (session conservée si l'époque est inchangée,
+//!          déconnexion après changement + bump_session_epoch)
+
+use axum::{
+    Extension, Router,
+    body::Body,
+    http::Request,
+    middleware::{self, Next},
+    response::Response,
+    routing::get,
+};
+use runique::auth::session::CurrentUser;
+use runique::auth::user_trait::RuniqueUser;
+use runique::auth::{
+    bump_session_epoch, is_authenticated, load_user_middleware, login, password_epoch,
+    register_session_epoch,
+};
+use runique::utils::pk::Pk;
+use std::sync::Arc;
+use tower::ServiceExt;
+use tower_sessions::{MemoryStore, Session, SessionManagerLayer};
+
+use crate::helpers::{
+    assert::body_str,
+    db,
+    users::{MockEntity, MockUser, set_password_hash},
+};
+
+// ═══════════════════════════════════════════════════════════════
+// Époque par défaut
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_password_epoch_suit_le_hash() {
+    let epoch = password_epoch("$argon2id$v=19$m=19456,t=2,p=1$abc$def");
+    assert_eq!(
+        epoch,
+        password_epoch("$argon2id$v=19$m=19456,t=2,p=1$abc$def")
+    );
+    assert_ne!(
+        epoch,
+        password_epoch("$argon2id$v=19$m=19456,t=2,p=1$abc$xyz")
+    );
+    assert!(!epoch.contains("argon2"));
+}
+
+#[test]
+fn test_session_epoch_par_defaut_derive_du_hash() {
+    let user = MockUser::new(1, "epoch").with_hash("hash-1");
+    assert_eq!(user.session_epoch(), password_epoch("hash-1"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// load_user_middleware
+// ═══════════════════════════════════════════════════════════════
+
+/// Connecte `user_id` puis, si `x-change-password` est présent, change son hash
+/// (comme un reset ailleurs) avant que `load_user_middleware` ne vérifie la session.
+async fn login_then_maybe_change(
+    Extension(user_id): Extension<Pk>,
+    session: Session,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let conn = db::fresh_db().await;
+    login(&session, &conn, user_id, "epoch", false, false, None, false)
+        .await
+        .unwrap();
+    if req.headers().contains_key("x-change-password") {
+        set_password_hash(user_id, "nouveau-hash");
+        bump_session_epoch(user_id);
+    }
+    next.run(req).await
+}
+
+async fn call(user_id: Pk, change_password: bool) -> String {
+    register_session_epoch::<MockEntity>();
+    MockUser::new(user_id, "epoch")
+        .with_hash("ancien-hash")
+        .save();
+
+    let app = Router::new()
+        .route(
+            "/me",
+            get(
+                |session: Session, user: Option<Extension<CurrentUser>>| async move {
+                    let name = user.map_or("anonyme".to_string(), |Extension(u)| u.username);
+                    format!("{name}:{}", is_authenticated(&session).await)
+                },
+            ),
+        )
+        .layer(middleware::from_fn_with_state(
+            Arc::new(db::fresh_db().await),
+            load_user_middleware,
+        ))
+        .layer(middleware::from_fn(login_then_maybe_change))
+        .layer(Extension(user_id))
+        .layer(SessionManagerLayer::new(MemoryStore::default()).with_secure(false));
+
+    let mut req = Request::builder().uri("/me");
+    if change_password {
+        req = req.header("x-change-password", "1");
+    }
+    let resp = app.oneshot(req.body(Body::empty()).unwrap()).await.unwrap();
+    body_str(resp).await
+}
+
+#[tokio::test]
+async fn test_epoque_inchangee_garde_la_session() {
+    assert_eq!(call(9301, false).await, "epoch:true");
+}
+
+#[tokio::test]
+async fn test_changement_de_mot_de_passe_deconnecte() {
+    assert_eq!(call(9302, true).await, "anonyme:false");
+}
+
+#[tokio::test]
+async fn test_utilisateur_inconnu_non_affecte() {
+    register_session_epoch::<MockEntity>();
+    let conn = db::fresh_db().await;
+    let store = MemoryStore::default();
+    let session = Session::new(None, Arc::new(store), None);
+    login(&session, &conn, 9399, "epoch", false, false, None, false)
+        .await
+        .unwrap();
+    assert!(is_authenticated(&session).await);
+}
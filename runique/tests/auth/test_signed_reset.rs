@@ -0,0 +1,146 @@
+//! Tests — auth/signed_reset.rs et SetPasswordForm
+//! Couvre : jeton signé valide, expiration, usage unique (hash du mot de passe changé),
+//!          clé secrète différente, rotation de clé (clés de secours), jeton altéré,
+//!          reset_token_user_id, SetPasswordForm::clean()
+
+use runique::auth::SetPasswordForm;
+use runique::auth::signed_reset::{
+    DEFAULT_RESET_TOKEN_TTL, make_reset_token, make_reset_token_at, reset_token_user_id,
+    verify_reset_token_with, verify_reset_token_with_keys,
+};
+use runique::forms::{field::RuniqueForm, form::Forms};
+use std::time::Duration;
+
+const KEY: &str = "une-cle-secrete-de-test-suffisamment-longue";
+const HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$ancien";
+const NOW: i64 = 1_700_000_000;
+
+// ═══════════════════════════════════════════════════════════════
+// Jeton signé
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_jeton_valide_retourne_user_id() {
+    let token = make_reset_token_at(KEY, 42, HASH, DEFAULT_RESET_TOKEN_TTL, NOW);
+    assert_eq!(
+        verify_reset_token_with(KEY, &token, HASH, NOW + 60),
+        Some(42)
+    );
+    assert_eq!(reset_token_user_id(&token), Some(42));
+}
+
+#[test]
+fn test_jeton_expire() {
+    let token = make_reset_token_at(KEY, 42, HASH, Duration::from_secs(600), NOW);
+    assert_eq!(
+        verify_reset_token_with(KEY, &token, HASH, NOW + 600),
+        Some(42)
+    );
+    assert_eq!(verify_reset_token_with(KEY, &token, HASH, NOW + 601), None);
+}
+
+#[test]
+fn test_jeton_invalide_apres_changement_de_mot_de_passe() {
+    let token = make_reset_token_at(KEY, 42, HASH, DEFAULT_RESET_TOKEN_TTL, NOW);
+    let new_hash = "$argon2id$v=19$m=19456,t=2,p=1$nouveau";
+    assert_eq!(verify_reset_token_with(KEY, &token, new_hash, NOW), None);
+}
+
+#[test]
+fn test_jeton_autre_cle_refuse() {
+    let token = make_reset_token_at(KEY, 42, HASH, DEFAULT_RESET_TOKEN_TTL, NOW);
+    assert_eq!(
+        verify_reset_token_with("autre-cle", &token, HASH, NOW),
+        None
+    );
+}
+
+#[test]
+fn test_rotation_ancienne_cle_acceptee() {
+    let ancienne = "ancienne-cle-secrete-de-test-suffisamment-longue";
+    let token = make_reset_token_at(ancienne, 42, HASH, DEFAULT_RESET_TOKEN_TTL, NOW);
+    // Clé principale puis clé de secours : le jeton signé par l'ancienne passe
+    assert_eq!(
+        verify_reset_token_with_keys(&[KEY, ancienne], &token, HASH, NOW),
+        Some(42)
+    );
+    // Une fois l'ancienne clé retirée, il ne passe plus
+    assert_eq!(
+        verify_reset_token_with_keys(&[KEY], &token, HASH, NOW),
+        None
+    );
+    assert_eq!(verify_reset_token_with_keys(&[], &token, HASH, NOW), None);
+}
+
+#[test]
+fn test_rotation_cle_principale_toujours_acceptee() {
+    let token = make_reset_token_at(KEY, 42, HASH, DEFAULT_RESET_TOKEN_TTL, NOW);
+    assert_eq!(
+        verify_reset_token_with_keys(&[KEY, "ancienne"], &token, HASH, NOW),
+        Some(42)
+    );
+    // L'expiration s'applique quelle que soit la clé
+    assert_eq!(
+        verify_reset_token_with_keys(&["ancienne", KEY], &token, HASH, NOW + 3601),
+        None
+    );
+}
+
+#[test]
+fn test_jeton_altere_refuse() {
+    let token = make_reset_token(KEY, 42, HASH, DEFAULT_RESET_TOKEN_TTL);
+    // Changer l'utilisateur ou l'expiration casse la signature
+    let forged = token.replacen("42.", "43.", 1);
+    assert_eq!(verify_reset_token_with(KEY, &forged, HASH, NOW), None);
+    let (_, rest) = token.split_once('.').unwrap();
+    let (_, sig) = rest.split_once('.').unwrap();
+    let extended = format!("42.{}.{sig}", i64::MAX);
+    assert_eq!(verify_reset_token_with(KEY, &extended, HASH, NOW), None);
+    assert_eq!(
+        verify_reset_token_with(KEY, "n'importe.quoi", HASH, NOW),
+        None
+    );
+    assert_eq!(reset_token_user_id("abc.1.x"), None);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// SetPasswordForm
+// ═══════════════════════════════════════════════════════════════
+
+fn make_set_form(password: &str, confirm: &str) -> SetPasswordForm {
+    let mut form = SetPasswordForm {
+        form: Forms::new("csrf"),
+    };
+    SetPasswordForm::register_fields(&mut form.form);
+    form.form.add_value("password", password);
+    form.form.add_value("confirm", confirm);
+    form
+}
+
+#[tokio::test]
+async fn test_set_password_form_valide() {
+    let mut form = make_set_form("strongpassword1", "strongpassword1");
+    assert!(form.is_valid().await);
+}
+
+#[tokio::test]
+async fn test_set_password_form_trop_court() {
+    let mut form = make_set_form("short", "short");
+    assert!(!form.is_valid().await);
+    assert!(form.form.errors().contains_key("password"));
+}
+
+#[tokio::test]
+async fn test_set_password_form_longueur_en_caracteres() {
+    // 8 caractères, 16 octets : refusé
+    let mut form = make_set_form("éééééééé", "éééééééé");
+    assert!(!form.is_valid().await);
+    assert!(form.form.errors().contains_key("password"));
+}
+
+#[tokio::test]
+async fn test_set_password_form_confirmation_differente() {
+    let mut form = make_set_form("strongpassword1", "strongpassword2");
+    assert!(!form.is_valid().await);
+    assert!(form.form.errors().contains_key("confirm"));
+}
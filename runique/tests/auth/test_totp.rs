@@ -0,0 +1,292 @@
+//! Tests — auth/totp.rs : TOTP (RFC 6238) et connexion en deux étapes
+//! Couvre : vecteurs RFC 6238 (SHA1), tolérance ±1 pas, codes malformés, secret généré,
+//!          URI de provisioning, login_with_totp / complete_totp_login, limite d'essais,
+//!          rejeu d'un code, login / login_user_full arrêtés à l'étape TOTP
+
+use axum::{Router, response::IntoResponse, routing::get};
+use runique::auth::session::{is_authenticated, login, logout};
+use runique::auth::totp::{
+    LoginStep, TOTP_MAX_ATTEMPTS, TOTP_PERIOD_SECS, complete_totp_login, generate_totp_secret,
+    is_totp_pending, is_totp_verified, login_with_totp, register_totp, totp_code_at,
+    totp_provisioning_uri, verify_totp, verify_totp_at,
+};
+use runique::utils::constante::session_key::session::SESSION_USER_ID_KEY;
+use runique::utils::pk::Pk;
+use sea_orm::DatabaseConnection;
+use tower_sessions::{MemoryStore, Session, SessionManagerLayer};
+
+use crate::helpers::{
+    assert::assert_body_str,
+    request,
+    users::{MockEntity, MockUser, persisted_totp_steps},
+};
+
+/// Secret RFC 6238 : "12345678901234567890" en base32
+const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+// ═══════════════════════════════════════════════════════════════
+// Codes
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_totp_vecteurs_rfc6238() {
+    for (t, code) in [
+        (59, "287082"),
+        (1_111_111_109, "081804"),
+        (1_111_111_111, "050471"),
+        (1_234_567_890, "005924"),
+        (2_000_000_000, "279037"),
+    ] {
+        assert_eq!(totp_code_at(RFC_SECRET, t).as_deref(), Some(code));
+    }
+}
+
+#[test]
+fn test_totp_tolerance_un_pas() {
+    let code = totp_code_at(RFC_SECRET, 1_000_000_000).unwrap();
+    let step = 1_000_000_000 / TOTP_PERIOD_SECS;
+    assert_eq!(
+        verify_totp_at(RFC_SECRET, &code, 1_000_000_000, None),
+        Some(step)
+    );
+    assert_eq!(
+        verify_totp_at(RFC_SECRET, &code, 1_000_000_030, None),
+        Some(step)
+    );
+    assert_eq!(
+        verify_totp_at(RFC_SECRET, &code, 999_999_970, None),
+        Some(step)
+    );
+    assert_eq!(verify_totp_at(RFC_SECRET, &code, 1_000_000_090, None), None);
+}
+
+#[test]
+fn test_totp_code_deja_accepte_refuse() {
+    let code = totp_code_at(RFC_SECRET, 1_000_000_000).unwrap();
+    let step = 1_000_000_000 / TOTP_PERIOD_SECS;
+    assert_eq!(
+        verify_totp_at(RFC_SECRET, &code, 1_000_000_000, Some(step)),
+        None
+    );
+    assert_eq!(
+        verify_totp_at(RFC_SECRET, &code, 1_000_000_030, Some(step + 1)),
+        None
+    );
+    assert_eq!(
+        verify_totp_at(RFC_SECRET, &code, 1_000_000_000, Some(step - 1)),
+        Some(step)
+    );
+}
+
+#[test]
+fn test_totp_codes_malformes_refuses() {
+    let code = totp_code_at(RFC_SECRET, 59).unwrap();
+    assert!(verify_totp_at(RFC_SECRET, &format!(" {code} "), 59, None).is_some());
+    assert!(verify_totp_at(RFC_SECRET, "28708", 59, None).is_none());
+    assert!(verify_totp_at(RFC_SECRET, "2870822", 59, None).is_none());
+    assert!(verify_totp_at(RFC_SECRET, "28708a", 59, None).is_none());
+    assert!(verify_totp_at("pas du base32!", "287082", 59, None).is_none());
+}
+
+#[test]
+fn test_totp_secret_genere_et_minuscules() {
+    let secret = generate_totp_secret();
+    assert_eq!(secret.len(), 32);
+    assert_ne!(secret, generate_totp_secret());
+    let now = chrono::Utc::now().timestamp() as u64;
+    let code = totp_code_at(&secret, now).unwrap();
+    assert!(verify_totp(&secret.to_lowercase(), &code, None).is_some());
+}
+
+#[test]
+fn test_totp_provisioning_uri() {
+    let uri = totp_provisioning_uri("ABC", "alice@example.com", "Mon site");
+    assert_eq!(
+        uri,
+        "otpauth://totp/Mon%20site:alice%40example.com?secret=ABC&issuer=Mon%20site\
+         &algorithm=SHA1&digits=6&period=30"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Connexion en deux étapes
+// ═══════════════════════════════════════════════════════════════
+
+/// Utilisateur 2FA `id` (un id par test : le dernier pas accepté est par utilisateur)
+fn alice(id: Pk) -> Pk {
+    MockUser::new(id, "alice").with_totp(RFC_SECRET).save()
+}
+
+fn build_app(handler: axum::routing::MethodRouter) -> Router {
+    let session_layer = SessionManagerLayer::new(MemoryStore::default()).with_secure(false);
+    Router::new()
+        .route("/test", get(handler))
+        .layer(session_layer)
+}
+
+fn current_code() -> String {
+    totp_code_at(RFC_SECRET, chrono::Utc::now().timestamp() as u64).unwrap()
+}
+
+async fn db() -> DatabaseConnection {
+    sea_orm::Database::connect("sqlite::memory:").await.unwrap()
+}
+
+async fn start(session: &Session, db: &DatabaseConnection, user_id: Pk) -> LoginStep {
+    login_with_totp::<MockEntity>(session, db, user_id, None, false, false)
+        .await
+        .unwrap()
+}
+
+async fn complete(session: &Session, db: &DatabaseConnection, code: &str) -> bool {
+    complete_totp_login::<MockEntity>(session, db, code, None, false)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_login_sans_secret_authentifie_directement() {
+    async fn handler(session: Session) -> impl IntoResponse {
+        let db = db().await;
+        let id = MockUser::new(7_200, "alice").save();
+        assert_eq!(start(&session, &db, id).await, LoginStep::Authenticated);
+        assert!(!is_totp_verified(&session).await);
+        format!("{}", is_authenticated(&session).await)
+    }
+    let res = request::get(build_app(get(handler)), "/test").await;
+    assert_body_str(res, "true").await;
+}
+
+#[tokio::test]
+async fn test_login_avec_secret_demande_le_code() {
+    async fn handler(session: Session) -> impl IntoResponse {
+        let db = db().await;
+        assert_eq!(
+            start(&session, &db, alice(7_101)).await,
+            LoginStep::TotpRequired
+        );
+        assert!(is_totp_pending(&session).await);
+        assert!(!is_authenticated(&session).await);
+
+        assert!(complete(&session, &db, &current_code()).await);
+        assert!(!is_totp_pending(&session).await);
+        format!(
+            "{}/{}",
+            is_authenticated(&session).await,
+            is_totp_verified(&session).await
+        )
+    }
+    let res = request::get(build_app(get(handler)), "/test").await;
+    assert_body_str(res, "true/true").await;
+}
+
+#[tokio::test]
+async fn test_code_faux_puis_limite_d_essais() {
+    async fn handler(session: Session) -> impl IntoResponse {
+        let db = db().await;
+        start(&session, &db, alice(7_102)).await;
+        for _ in 0..TOTP_MAX_ATTEMPTS {
+            assert!(!complete(&session, &db, "000000x").await);
+        }
+        // Étape abandonnée : même le bon code est refusé
+        let ok = complete(&session, &db, &current_code()).await;
+        format!("{ok}/{}", is_authenticated(&session).await)
+    }
+    let res = request::get(build_app(get(handler)), "/test").await;
+    assert_body_str(res, "false/false").await;
+}
+
+#[tokio::test]
+async fn test_login_compte_inactif_refuse() {
+    async fn handler(session: Session) -> impl IntoResponse {
+        let db = db().await;
+        let id = MockUser::new(7_300, "alice")
+            .with_totp(RFC_SECRET)
+            .inactive()
+            .save();
+        assert_eq!(start(&session, &db, id).await, LoginStep::Refused);
+        format!("{}", is_totp_pending(&session).await)
+    }
+    let res = request::get(build_app(get(handler)), "/test").await;
+    assert_body_str(res, "false").await;
+}
+
+#[tokio::test]
+async fn test_code_rejoue_refuse() {
+    async fn handler(session: Session) -> impl IntoResponse {
+        let db = db().await;
+        let code = current_code();
+        start(&session, &db, alice(7_103)).await;
+        assert!(complete(&session, &db, &code).await);
+        logout(&session, None).await.unwrap();
+
+        // Même code, nouvelle connexion : déjà accepté pour cet utilisateur
+        start(&session, &db, 7_103).await;
+        let ok = complete(&session, &db, &code).await;
+        format!("{ok}/{}", is_authenticated(&session).await)
+    }
+    let res = request::get(build_app(get(handler)), "/test").await;
+    assert_body_str(res, "false/false").await;
+}
+
+#[tokio::test]
+async fn test_dernier_pas_persiste() {
+    async fn handler(session: Session) -> impl IntoResponse {
+        let db = db().await;
+        start(&session, &db, alice(7_104)).await;
+        assert!(complete(&session, &db, &current_code()).await);
+        let persisted = !persisted_totp_steps(7_104).is_empty();
+
+        // Pas persisté au-delà du code courant : refusé
+        logout(&session, None).await.unwrap();
+        let id = MockUser::new(7_400, "alice")
+            .with_totp(RFC_SECRET)
+            .with_totp_last_step(u64::MAX)
+            .save();
+        start(&session, &db, id).await;
+        let ok = complete(&session, &db, &current_code()).await;
+        format!("{persisted}/{ok}")
+    }
+    let res = request::get(build_app(get(handler)), "/test").await;
+    assert_body_str(res, "true/false").await;
+}
+
+#[tokio::test]
+async fn test_login_direct_arrete_a_l_etape_totp() {
+    async fn handler(session: Session) -> impl IntoResponse {
+        let db = db().await;
+        register_totp::<MockEntity>();
+        login(
+            &session,
+            &db,
+            alice(7_105),
+            "alice",
+            true,
+            true,
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+        assert!(is_totp_pending(&session).await);
+        assert!(!is_authenticated(&session).await);
+
+        assert!(complete(&session, &db, &current_code()).await);
+        format!("{}", is_authenticated(&session).await)
+    }
+    let res = request::get(build_app(get(handler)), "/test").await;
+    assert_body_str(res, "true").await;
+}
+
+#[tokio::test]
+async fn test_session_sans_etape_totp_non_authentifiee() {
+    async fn handler(session: Session) -> impl IntoResponse {
+        session
+            .insert(SESSION_USER_ID_KEY, 7_106 as Pk)
+            .await
+            .unwrap();
+        format!("{}", is_authenticated(&session).await)
+    }
+    let res = request::get(build_app(get(handler)), "/test").await;
+    assert_body_str(res, "false").await;
+}
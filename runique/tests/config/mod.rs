@@ -1,5 +1,8 @@
 pub mod test_app_config;
 pub mod test_builder;
+pub mod test_config_file;
+pub mod test_config_profile;
+pub mod test_config_validate;
 pub mod test_router;
 pub mod test_security_config;
 pub mod test_server_config;
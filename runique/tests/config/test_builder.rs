@@ -315,6 +315,28 @@ async fn test_core_staging_with_database_is_ready() {
     assert!(core.validate().is_ok());
 }
 
+#[test]
+fn test_core_staging_pool_min_superieur_au_max_refuse() {
+    use runique::db::DatabaseConfig;
+    let config = DatabaseConfig::from_url("sqlite://test.db")
+        .unwrap()
+        .pool_size(12, 4)
+        .build();
+    let err = CoreStaging::new()
+        .with_database_config(config)
+        .validate()
+        .unwrap_err();
+    let BuildErrorKind::CheckFailed(report) = &err.kind else {
+        panic!("Attendu CheckFailed, obtenu {:?}", err.kind);
+    };
+    assert!(
+        report
+            .errors
+            .iter()
+            .any(|e| e.component == "Database" && e.message.contains("DB_MIN_CONNECTIONS (12)"))
+    );
+}
+
 // ════════════════════════════════════════════════════════════════
 // MiddlewareConfig (development / production profiles)
 // ════════════════════════════════════════════════════════════════
@@ -0,0 +1,200 @@
+// Tests pour RuniqueConfig::from_toml / DatabaseConfig::from_toml (config/file.rs)
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use crate::utils::env::{del_env, set_env};
+use runique::config::{ConfigFile, ConfigFileError, RuniqueConfig};
+use runique::db::DatabaseConfig;
+use serial_test::serial;
+use std::fs;
+
+fn fichier(name: &str, content: &str) -> (TestTempDir, String) {
+    let dir = TestTempDir::new("runique_test_config_file", name);
+    let path = dir.join("runique.toml");
+    fs::write(&path, content).unwrap();
+    let path = path.to_string_lossy().to_string();
+    (dir, path)
+}
+
+const FICHIER: &str = r#"
+debug = false
+timezone = "Europe/Paris"
+
+[server]
+ip_server = "0.0.0.0"
+port = 8100
+secret_key = "${RUNIQUE_TEST_TOML_SECRET}"
+
+[security]
+enforce_https = true
+allowed_hosts = ["exemple.fr", ".exemple.fr"]
+
+[static]
+static_url = "/assets"
+templates_dir = ["templates", "themes"]
+max_upload_mb = 20
+
+[database]
+url = "sqlite://toml_test.sqlite?mode=rwc"
+max_connections = 7
+"#;
+
+fn nettoie_env() {
+    for var in [
+        "DEBUG",
+        "TZ",
+        "IP_SERVER",
+        "PORT",
+        "SECRET_KEY",
+        "ENFORCE_HTTPS",
+        "ALLOWED_HOSTS",
+        "STATIC_URL",
+        "TEMPLATES_DIR",
+        "RUNIQUE_MAX_UPLOAD_MB",
+        "DATABASE_URL",
+        "DB_MAX_CONNECTIONS",
+    ] {
+        del_env(var);
+    }
+    set_env(
+        "RUNIQUE_TEST_TOML_SECRET",
+        "toml_secret_key_at_least_32_characters",
+    );
+}
+
+// ── Lecture des sections ───────────────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn test_from_toml_lit_les_sections() {
+    nettoie_env();
+    let (_dir, path) = fichier("sections", FICHIER);
+    let config = RuniqueConfig::from_toml(&path).unwrap();
+
+    assert!(!config.debug);
+    assert_eq!(config.timezone, "Europe/Paris");
+    assert_eq!(config.server.ip_server, "0.0.0.0");
+    assert_eq!(config.server.port, 8100);
+    assert_eq!(config.server.domain_server, "0.0.0.0:8100");
+    assert_eq!(
+        config.server.secret_key,
+        "toml_secret_key_at_least_32_characters"
+    );
+    assert!(config.security.enforce_https);
+    assert_eq!(config.security.allowed_hosts, ["exemple.fr", ".exemple.fr"]);
+    assert_eq!(config.static_files.static_url, "/assets");
+    assert_eq!(config.static_files.templates_dir, ["templates", "themes"]);
+    assert_eq!(config.static_files.max_upload_mb, 20);
+    // Clé absente du fichier : valeur par défaut de from_env
+    assert_eq!(config.static_files.media_url, "/media");
+}
+
+#[test]
+#[serial]
+fn test_from_toml_section_database() {
+    nettoie_env();
+    let (_dir, path) = fichier("database", FICHIER);
+    let db = DatabaseConfig::from_toml(&path).unwrap().build();
+    assert_eq!(db.url, "sqlite://toml_test.sqlite?mode=rwc");
+    assert_eq!(db.max_connections, 7);
+}
+
+// ── Priorité de l'environnement ────────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn test_variable_d_environnement_prioritaire() {
+    nettoie_env();
+    set_env("PORT", "9100");
+    set_env("DB_MAX_CONNECTIONS", "12");
+    let (_dir, path) = fichier("priorite", FICHIER);
+
+    let config = RuniqueConfig::from_toml(&path).unwrap();
+    assert_eq!(config.server.port, 9100);
+    assert_eq!(config.server.ip_server, "0.0.0.0");
+    assert_eq!(
+        DatabaseConfig::from_toml(&path)
+            .unwrap()
+            .build()
+            .max_connections,
+        12
+    );
+    del_env("PORT");
+    del_env("DB_MAX_CONNECTIONS");
+}
+
+// ── Interpolation ${VAR} ───────────────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn test_interpolation_variable_absente() {
+    nettoie_env();
+    del_env("RUNIQUE_TEST_TOML_ABSENTE");
+    let err = ConfigFile::parse(
+        "[server]\nsecret_key = \"prefixe-${RUNIQUE_TEST_TOML_ABSENTE}\"",
+        "t.toml",
+    )
+    .unwrap_err();
+    assert!(
+        matches!(&err, ConfigFileError::MissingVariable { key, var }
+            if key == "server.secret_key" && var == "RUNIQUE_TEST_TOML_ABSENTE"),
+        "{err}"
+    );
+}
+
+#[test]
+#[serial]
+fn test_interpolation_au_milieu_d_une_valeur() {
+    nettoie_env();
+    set_env("RUNIQUE_TEST_TOML_HOTE", "exemple.fr");
+    let file = ConfigFile::parse(
+        "[security]\nallowed_hosts = [\"${RUNIQUE_TEST_TOML_HOTE}\", \"api.${RUNIQUE_TEST_TOML_HOTE}\"]",
+        "t.toml",
+    )
+    .unwrap();
+    assert_eq!(
+        file.var("ALLOWED_HOSTS").as_deref(),
+        Some("exemple.fr,api.exemple.fr")
+    );
+}
+
+// ── Clés inconnues ─────────────────────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn test_cles_inconnues_erreur_en_debug() {
+    nettoie_env();
+    let err = ConfigFile::parse(
+        "debug = true\nsecrte = 1\n[server]\nprot = 80\n[cache]\nttl = 3",
+        "t.toml",
+    )
+    .unwrap_err();
+    match err {
+        ConfigFileError::UnknownKeys(mut keys) => {
+            keys.sort();
+            assert_eq!(keys, ["cache", "secrte", "server.prot"]);
+        }
+        other => panic!("{other}"),
+    }
+}
+
+#[test]
+#[serial]
+fn test_cles_inconnues_ignorees_hors_debug() {
+    nettoie_env();
+    let file =
+        ConfigFile::parse("debug = false\n[server]\nprot = 80\nport = 81", "t.toml").unwrap();
+    assert_eq!(file.var("PORT").as_deref(), Some("81"));
+}
+
+#[test]
+#[serial]
+fn test_fichier_absent_ou_invalide() {
+    assert!(matches!(
+        RuniqueConfig::from_toml("/chemin/inexistant/runique.toml"),
+        Err(ConfigFileError::Io { .. })
+    ));
+    assert!(matches!(
+        ConfigFile::parse("[server\nport = 1", "t.toml"),
+        Err(ConfigFileError::Parse { .. })
+    ));
+}
@@ -0,0 +1,246 @@
+// Tests pour les profils d'environnement (RUNIQUE_ENV, from_toml_profile, .profile())
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use crate::utils::env::{del_env, set_env};
+use axum::Router;
+use runique::app::{BuildErrorKind, RuniqueAppBuilder};
+use runique::config::file::profile_path;
+use runique::config::{ConfigFile, ConfigFileError, RuniqueConfig};
+use runique::db::DatabaseConfig;
+use runique::testing::test_db;
+use serial_test::serial;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+const BASE: &str = r#"
+debug = true
+
+[server]
+port = 8000
+secret_key = "base_secret_key_at_least_32_characters"
+
+[security]
+strict_csp = false
+allowed_hosts = ["localhost"]
+"#;
+
+fn projet(name: &str, profils: &[(&str, &str)]) -> (TestTempDir, String) {
+    let dir = TestTempDir::new("runique_test_config_profile", name);
+    let base = dir.join("runique.toml");
+    fs::write(&base, BASE).unwrap();
+    for (profil, content) in profils {
+        fs::write(profile_path(&base, profil), content).unwrap();
+    }
+    let base = base.to_string_lossy().to_string();
+    (dir, base)
+}
+
+fn nettoie_env() {
+    for var in [
+        "RUNIQUE_ENV",
+        "DEBUG",
+        "STRICT_CSP",
+        "PORT",
+        "SECRET_KEY",
+        "ALLOWED_HOSTS",
+        "DB_LOGGING",
+        "DB_SLOW_QUERY_MS",
+        "DATABASE_URL",
+    ] {
+        del_env(var);
+    }
+}
+
+#[test]
+fn test_profile_path() {
+    assert_eq!(
+        profile_path("conf/runique.toml", "prod"),
+        Path::new("conf/runique.prod.toml")
+    );
+    assert_eq!(profile_path("runique", "dev"), Path::new("runique.dev"));
+}
+
+// ── Fusion clé par clé ─────────────────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn test_profil_surcharge_cle_par_cle() {
+    nettoie_env();
+    let (_dir, base) = projet(
+        "fusion",
+        &[(
+            "staging",
+            "[server]\nport = 8100\n[security]\nallowed_hosts = [\"staging.exemple.fr\"]",
+        )],
+    );
+    let config = RuniqueConfig::from_toml_profile(&base, "staging").unwrap();
+
+    assert_eq!(config.profile.as_deref(), Some("staging"));
+    assert_eq!(config.server.port, 8100);
+    assert_eq!(config.security.allowed_hosts, ["staging.exemple.fr"]);
+    // Clés absentes du profil : celles du fichier de base
+    assert!(config.debug);
+    assert!(!config.security.strict_csp);
+    assert_eq!(
+        config.server.secret_key,
+        "base_secret_key_at_least_32_characters"
+    );
+}
+
+#[test]
+#[serial]
+fn test_profil_sans_fichier() {
+    nettoie_env();
+    let (_dir, base) = projet("sans_fichier", &[]);
+    let config = RuniqueConfig::from_toml_profile(&base, "dev").unwrap();
+    assert_eq!(config.profile.as_deref(), Some("dev"));
+    assert_eq!(config.server.port, 8000);
+    assert!(config.debug);
+}
+
+// ── Valeurs sûres du profil prod ───────────────────────────────────────────────
+
+#[test]
+#[serial]
+fn test_prod_desactive_debug_et_durcit_csp() {
+    nettoie_env();
+    let (_dir, base) = projet("prod_defauts", &[]);
+    let config = RuniqueConfig::from_toml_profile(&base, "prod").unwrap();
+    assert!(!config.debug);
+    assert!(config.security.strict_csp);
+}
+
+#[test]
+#[serial]
+fn test_prod_coupe_les_logs_sql() {
+    nettoie_env();
+    let (_dir, base) = projet("prod_logs_sql", &[]);
+    let mut content = fs::read_to_string(&base).unwrap();
+    content.push_str(
+        "\n[database]\nurl = \"sqlite://profil.sqlite?mode=rwc\"\nlogging = true\nslow_query_ms = 100\n",
+    );
+    fs::write(&base, content).unwrap();
+
+    let dev = DatabaseConfig::from_toml(&base).unwrap().build();
+    assert!(dev.sqlx_logging);
+    assert_eq!(dev.slow_query_threshold, Some(Duration::from_millis(100)));
+
+    set_env("RUNIQUE_ENV", "prod");
+    let prod = DatabaseConfig::from_toml(&base).unwrap().build();
+    del_env("RUNIQUE_ENV");
+    assert!(!prod.sqlx_logging);
+    assert_eq!(prod.slow_query_threshold, None);
+}
+
+#[test]
+#[serial]
+fn test_prod_surchargeable_par_fichier_et_env() {
+    nettoie_env();
+    let (_dir, base) = projet(
+        "prod_surcharge",
+        &[("prod", "[security]\nstrict_csp = false")],
+    );
+    let config = RuniqueConfig::from_toml_profile(&base, "prod").unwrap();
+    assert!(!config.security.strict_csp);
+    assert!(!config.debug);
+
+    set_env("DEBUG", "true");
+    let config = RuniqueConfig::from_toml_profile(&base, "prod").unwrap();
+    assert!(config.debug);
+    del_env("DEBUG");
+}
+
+// ── Priorité : base < profil < environnement ───────────────────────────────────
+
+#[test]
+#[serial]
+fn test_environnement_prioritaire_sur_profil() {
+    nettoie_env();
+    let (_dir, base) = projet("priorite", &[("prod", "[server]\nport = 80")]);
+    set_env("PORT", "9000");
+    let config = RuniqueConfig::from_toml_profile(&base, "prod").unwrap();
+    assert_eq!(config.server.port, 9000);
+    del_env("PORT");
+}
+
+#[test]
+#[serial]
+fn test_runique_env_choisit_le_profil() {
+    nettoie_env();
+    let (_dir, base) = projet("runique_env", &[("prod", "[server]\nport = 80")]);
+    set_env("RUNIQUE_ENV", "prod");
+    let config = RuniqueConfig::from_toml(&base).unwrap();
+    let file = ConfigFile::load(&base).unwrap();
+    del_env("RUNIQUE_ENV");
+
+    assert_eq!(config.profile.as_deref(), Some("prod"));
+    assert_eq!(config.server.port, 80);
+    assert!(!config.debug);
+    assert_eq!(file.profile(), Some("prod"));
+    assert_eq!(file.var("PORT").as_deref(), Some("80"));
+}
+
+#[test]
+#[serial]
+fn test_cle_inconnue_dans_le_profil() {
+    nettoie_env();
+    let (_dir, base) = projet("inconnue", &[("dev", "[server]\nprot = 80")]);
+    let err = RuniqueConfig::from_toml_profile(&base, "dev").unwrap_err();
+    assert!(
+        matches!(&err, ConfigFileError::UnknownKeys(keys) if keys == &["server.prot"]),
+        "{err}"
+    );
+}
+
+// ── Builder .profile() ─────────────────────────────────────────────────────────
+
+#[tokio::test]
+#[serial]
+async fn test_builder_profile_recharge_le_fichier() {
+    nettoie_env();
+    let (_dir, base) = projet("builder", &[("prod", "[server]\nport = 80")]);
+    let config = RuniqueConfig::from_toml(&base).unwrap();
+    assert!(config.debug);
+
+    let app = RuniqueAppBuilder::new(config)
+        .profile("prod")
+        .with_database(test_db().await)
+        .routes(Router::new())
+        .no_statics()
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+
+    assert_eq!(app.engine.config.profile.as_deref(), Some("prod"));
+    assert_eq!(app.engine.config.server.port, 80);
+    assert!(!app.engine.config.debug);
+    assert!(app.engine.config.security.strict_csp);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_builder_profile_invalide_echoue_au_build() {
+    nettoie_env();
+    let (dir, base) = projet("builder_invalide", &[]);
+    let config = RuniqueConfig::from_toml(&base).unwrap();
+    fs::write(dir.join("runique.prod.toml"), "[server\nport = 80").unwrap();
+
+    let err = match RuniqueAppBuilder::new(config)
+        .profile("prod")
+        .with_database(test_db().await)
+        .routes(Router::new())
+        .build()
+        .await
+    {
+        Ok(_) => panic!("build() doit échouer"),
+        Err(e) => e,
+    };
+    match &err.kind {
+        BuildErrorKind::ValidationFailed(msg) => {
+            assert!(msg.starts_with("profile:"), "{msg}");
+            assert!(msg.contains("runique.prod.toml"), "{msg}");
+        }
+        other => panic!("Attendu ValidationFailed, obtenu {other:?}"),
+    }
+}
@@ -0,0 +1,132 @@
+// Tests pour RuniqueConfig::validate (config/validate.rs) — boot validation
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use axum::Router;
+use runique::app::{BuildErrorKind, CheckReport, RuniqueAppBuilder};
+use runique::config::RuniqueConfig;
+use runique::testing::test_db;
+
+fn config_valide() -> RuniqueConfig {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    config.server.port = 3000;
+    config.static_files.static_url = "/static".to_string();
+    config.static_files.media_url = "/media".to_string();
+    config.security.allowed_hosts = vec!["localhost:3000".to_string(), ".exemple.fr".to_string()];
+    config
+}
+
+fn composants(report: &CheckReport) -> Vec<&str> {
+    report.errors.iter().map(|e| e.component.as_str()).collect()
+}
+
+#[test]
+fn test_config_valide() {
+    let templates = TestTempDir::new("runique_test_validate", "valide");
+    let mut config = config_valide();
+    config.static_files.templates_dir = vec![templates.as_str().to_string()];
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_toutes_les_erreurs_collectees() {
+    let mut config = config_valide();
+    config.server.secret_key = "default_secret_key".to_string();
+    config.static_files.templates_dir = vec!["/chemin/inexistant/templates".to_string()];
+    config.static_files.static_url = "static".to_string();
+    config.security.allowed_hosts = vec!["https://exemple.fr".to_string()];
+
+    let report = config.validate().unwrap_err();
+    assert_eq!(
+        composants(&report),
+        ["Security", "Templates", "Static", "AllowedHosts"]
+    );
+    assert!(report.errors.iter().all(|e| e.suggestion.is_some()));
+    assert!(
+        report.errors[1]
+            .message
+            .contains("/chemin/inexistant/templates")
+    );
+}
+
+#[test]
+fn test_cle_faible_toleree_en_debug() {
+    let mut config = config_valide();
+    config.debug = true;
+    config.server.secret_key = String::new();
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_cle_de_secours_faible_refusee() {
+    let mut config = config_valide();
+    config.server.secret_key_fallbacks =
+        vec!["ancienne_cle_secrete_d_au_moins_32_caracteres".to_string()];
+    assert!(config.validate().is_ok());
+
+    config
+        .server
+        .secret_key_fallbacks
+        .push("courte".to_string());
+    let report = config.validate().unwrap_err();
+    assert_eq!(composants(&report), ["Security"]);
+    assert!(report.errors[0].message.contains("SECRET_KEY_FALLBACKS"));
+}
+
+#[test]
+fn test_templates_dir_fichier_refuse() {
+    let dir = TestTempDir::new("runique_test_validate", "fichier");
+    let file = dir.join("base.html");
+    std::fs::write(&file, "").unwrap();
+    let mut config = config_valide();
+    config.static_files.templates_dir = vec![file.to_string_lossy().to_string()];
+
+    let report = config.validate().unwrap_err();
+    assert!(
+        report.errors[0].message.contains("is not a directory"),
+        "{}",
+        report.errors[0]
+    );
+}
+
+#[test]
+fn test_urls_statiques_mal_formees() {
+    for url in ["/", "static", "/st atic", "/static?v=1"] {
+        let mut config = config_valide();
+        config.static_files.media_url = url.to_string();
+        let report = config.validate().unwrap_err();
+        assert_eq!(composants(&report), ["Static"], "{url}");
+        assert!(report.errors[0].message.starts_with("MEDIA_URL"));
+    }
+    // Vide : non servi, pas d'erreur
+    let mut config = config_valide();
+    config.static_files.static_runique_url = String::new();
+    assert!(config.validate().is_ok());
+}
+
+#[tokio::test]
+async fn test_build_rapporte_la_configuration() {
+    let mut config = config_valide();
+    config.server.secret_key = "courte".to_string();
+    config.static_files.templates_dir = vec!["/chemin/inexistant/templates".to_string()];
+
+    let err = match RuniqueAppBuilder::new(config)
+        .with_database(test_db().await)
+        .routes(Router::new())
+        .no_statics()
+        .build()
+        .await
+    {
+        Ok(_) => panic!("build() doit échouer"),
+        Err(e) => e,
+    };
+    match &err.kind {
+        BuildErrorKind::CheckFailed(report) => {
+            assert_eq!(composants(report), ["Security", "Templates"]);
+        }
+        other => panic!("Attendu CheckFailed, obtenu {other:?}"),
+    }
+    let message = err.to_string();
+    assert!(message.contains("2 check error(s)"), "{message}");
+    assert!(message.contains("TEMPLATES_DIR"), "{message}");
+}
@@ -73,6 +73,25 @@ fn test_server_config_secret_key_personnalise() {
     del_env("SECRET_KEY");
 }
 
+#[test]
+#[serial]
+fn test_server_config_secret_key_fallbacks() {
+    del_env("SECRET_KEY_FALLBACKS");
+    assert!(ServerConfig::from_env().secret_key_fallbacks.is_empty());
+
+    set_env("SECRET_KEY", "cle_actuelle");
+    set_env("SECRET_KEY_FALLBACKS", "ancienne_1, ancienne_2,");
+    let config = ServerConfig::from_env();
+    assert_eq!(config.secret_key_fallbacks, ["ancienne_1", "ancienne_2"]);
+    // La clé principale d'abord, puis les anciennes dans l'ordre
+    assert_eq!(
+        config.secret_keys(),
+        ["cle_actuelle", "ancienne_1", "ancienne_2"]
+    );
+    del_env("SECRET_KEY");
+    del_env("SECRET_KEY_FALLBACKS");
+}
+
 #[test]
 #[serial]
 fn test_server_config_domain_server_avec_ip_et_port_personnalises() {
@@ -102,11 +121,13 @@ fn test_server_config_clone() {
         domain_server: "192.168.1.1:3000".to_string(),
         port: 3000,
         secret_key: "secret".to_string(),
+        secret_key_fallbacks: vec!["ancienne".to_string()],
     };
     let cloned = config.clone();
     assert_eq!(cloned.ip_server, config.ip_server);
     assert_eq!(cloned.port, config.port);
     assert_eq!(cloned.secret_key, config.secret_key);
+    assert_eq!(cloned.secret_key_fallbacks, config.secret_key_fallbacks);
 }
 
 #[test]
@@ -3,12 +3,24 @@
 //! | Fichier                  | Ce qui est testé                          |
 //! | ------------------------ | ----------------------------------------- |
 //! | `test_csp_function`      | nonce_function : nonce CSP pour templates |
+//! | `test_form_theme`        | FormTheme : Plain / Bootstrap5 / Tailwind |
+//! | `test_render_form`       | Fonction Tera render_form()               |
+//! | `test_sse`               | sse_stream() : cadrage, heartbeat, déco.  |
+//! | `test_sri`               | sri_hash() / script_tag() : SRI, nonce    |
 //! | `test_static_tera`       | Filtres Tera : mask, csrf_field, static… |
+//! | `test_static_url`        | static_url() : manifeste, cache immutable |
 //! | `test_url_function`      | LinkFunction : résolution d'URLs nommées  |
+//! | `test_ws`                | WsUpgrade / ws() : auth, Origin, salons   |
 
 pub mod test_app_error;
+pub mod test_form_theme;
+pub mod test_render_form;
 pub mod test_request_extensions;
 pub mod test_runique_context;
+pub mod test_sri;
+pub mod test_sse;
 pub mod test_static_tera;
+pub mod test_static_url;
 pub mod test_template_request;
 pub mod test_url_function;
+pub mod test_ws;
@@ -0,0 +1,284 @@
+//! Tests — forms/theme.rs + render_form(theme=…) : thèmes CSS du rendu de formulaire
+//! Couvre : Plain par défaut (sortie inchangée), Bootstrap5 / Tailwind via
+//!          Forms::set_theme ou argument `theme`, form-check des booléens,
+//!          select / radio, champ en erreur, repli sur le template, parsing
+
+use runique::config::RuniqueConfig;
+use runique::context::tera::form::RenderFormFunction;
+use runique::forms::{
+    FormTheme,
+    base::FormField,
+    fields::{
+        RadioField, boolean::BooleanField, choice::ChoiceField, datetime::DateField,
+        text::TextField,
+    },
+    form::Forms,
+    renderer::FormRenderer,
+};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tera::{Function, Tera};
+
+fn field_tera() -> Arc<Tera> {
+    let mut tera = Tera::default();
+    for name in ["base_string.html", "base_boolean.html", "base_select.html"] {
+        tera.add_raw_template(name, "<input name=\"{{ field.name }}\" class=\"tpl\">")
+            .unwrap();
+    }
+    tera.add_raw_template(
+        "base_datetime.html",
+        "<input type=\"date\" name=\"{{ field.name }}\" class=\"tpl-date\">",
+    )
+    .unwrap();
+    tera.add_raw_template(
+        "csrf.html",
+        "<input type=\"hidden\" name=\"csrf_token\" value=\"{{ field.value }}\">",
+    )
+    .unwrap();
+    tera.add_raw_template("js_files.html", "").unwrap();
+    Arc::new(tera)
+}
+
+fn profile_form(theme: Option<FormTheme>) -> Forms {
+    let mut form = Forms::new("form-token");
+    form.set_renderer(FormRenderer::new(field_tera()));
+    if let Some(theme) = theme {
+        form.set_theme(theme);
+    }
+    let mut name = TextField::text("name").label("Nom").required();
+    name.set_help_text("Tel qu'affiché");
+    form.field(&name);
+    form.field(
+        &BooleanField::new("newsletter")
+            .label("Newsletter")
+            .required(),
+    );
+    form.field(
+        &ChoiceField::new("country")
+            .label("Pays")
+            .add_choice("fr", "France")
+            .add_choice("be", "Belgique"),
+    );
+    form
+}
+
+fn render(form: &Forms, extra: &[(&str, Value)]) -> String {
+    let mut args: HashMap<String, Value> = HashMap::new();
+    args.insert("form".to_string(), serde_json::to_value(form).unwrap());
+    for (k, v) in extra {
+        args.insert(k.to_string(), v.clone());
+    }
+    RenderFormFunction::default()
+        .call(&args)
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Plain (défaut)
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_theme_plain_par_defaut() {
+    let form = profile_form(None);
+    assert_eq!(form.theme(), None);
+    let html = render(&form, &[]);
+    assert!(html.starts_with(r#"<div class="runique-form runique-form--stacked">"#));
+    assert!(html.contains(r#"<div class="runique-field runique-field--text" data-field="name">"#));
+    // Chaque champ garde son template
+    assert!(html.contains(r#"class="tpl""#));
+    assert!(!html.contains("form-control"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Bootstrap5
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_theme_bootstrap_input_et_label() {
+    let html = render(&profile_form(Some(FormTheme::Bootstrap5)), &[]);
+    assert!(html.contains(r#"<div class="mb-3" data-field="name">"#));
+    assert!(html.contains(r#"<label for="id_name" class="form-label">Nom</label>"#));
+    assert!(html.contains(r#"class="form-control" required"#));
+    assert!(html.contains(r#"<div class="form-text">Tel qu&#x27;affiché</div>"#));
+    assert!(!html.contains(r#"class="tpl""#));
+}
+
+#[test]
+fn test_theme_bootstrap_booleen_form_check() {
+    let html = render(&profile_form(Some(FormTheme::Bootstrap5)), &[]);
+    assert!(html.contains(r#"<div class="form-check"><input type="checkbox" id="id_newsletter""#));
+    assert!(html.contains(r#"class="form-check-input""#));
+    assert!(
+        html.contains(r#"<label for="id_newsletter" class="form-check-label">Newsletter</label>"#)
+    );
+    // required d'un booléen = NOT NULL, jamais « doit être coché »
+    let checkbox = &html[html.find("id=\"id_newsletter\"").unwrap()..];
+    assert!(!checkbox[..checkbox.find('>').unwrap()].contains("required"));
+}
+
+#[test]
+fn test_theme_bootstrap_select() {
+    let mut form = profile_form(Some(FormTheme::Bootstrap5));
+    form.add_value("country", "be");
+    let html = render(&form, &[]);
+    assert!(html.contains(r#"<select id="id_country" name="country" class="form-select">"#));
+    assert!(html.contains(r#"<option value="fr">France</option>"#));
+    assert!(html.contains(r#"<option value="be" selected>Belgique</option>"#));
+}
+
+#[test]
+fn test_theme_bootstrap_select_groupes_dans_l_ordre() {
+    let mut form = Forms::new("form-token");
+    form.set_theme(FormTheme::Bootstrap5);
+    form.field(
+        &ChoiceField::new("country")
+            .add_group("Europe", vec![("fr", "France")])
+            .add_choice("other", "Autre"),
+    );
+    form.add_value("country", "fr");
+    let html = render(&form, &[]);
+    assert!(html.contains(
+        r#"<optgroup label="Europe"><option value="fr" selected>France</option></optgroup><option value="other">Autre</option>"#
+    ));
+}
+
+#[test]
+fn test_theme_bootstrap_champ_en_erreur() {
+    let mut form = profile_form(Some(FormTheme::Bootstrap5));
+    if let Some(field) = form.fields.get_mut("name") {
+        field.set_error("Nom requis".to_string());
+    }
+    form.errors.push("Formulaire incomplet".to_string());
+    let html = render(&form, &[]);
+    assert!(html.contains(r#"class="form-control is-invalid""#));
+    assert!(html.contains(r#"<div class="invalid-feedback d-block">Nom requis</div>"#));
+    assert!(html.contains(
+        r#"<ul class="alert alert-danger list-unstyled"><li>Formulaire incomplet</li></ul>"#
+    ));
+}
+
+#[test]
+fn test_theme_bootstrap_radio_fieldset() {
+    let mut form = Forms::new("form-token");
+    form.set_theme(FormTheme::Bootstrap5);
+    form.field(
+        &RadioField::new("size")
+            .label("Taille")
+            .add_choice("s", "Petit")
+            .add_choice("l", "Grand"),
+    );
+    form.add_value("size", "l");
+    let html = render(&form, &[]);
+    assert!(html.contains(
+        r#"<fieldset class="mb-3" data-field="size"><legend class="form-label">Taille</legend>"#
+    ));
+    assert!(html.contains(r#"<input type="radio" id="id_size_1" name="size" value="l" checked class="form-check-input">"#));
+    assert!(html.contains(r#"<label for="id_size_0" class="form-check-label">Petit</label>"#));
+}
+
+#[test]
+fn test_theme_bootstrap_mot_de_passe_non_reaffiche() {
+    let mut form = Forms::new("form-token");
+    form.set_theme(FormTheme::Bootstrap5);
+    form.field(&TextField::password("password"));
+    form.add_value("password", "s3cret!");
+    let html = render(&form, &[]);
+    assert!(html.contains(r#"type="password""#));
+    assert!(!html.contains("s3cret!"));
+}
+
+#[test]
+fn test_theme_champ_date_garde_son_template() {
+    let mut form = Forms::new("form-token");
+    form.set_renderer(FormRenderer::new(field_tera()));
+    form.set_theme(FormTheme::Bootstrap5);
+    form.field(&DateField::new("birthday"));
+    let html = render(&form, &[]);
+    assert!(html.contains(
+        r#"<div class="mb-3" data-field="birthday"><input type="date" name="birthday" class="tpl-date"></div>"#
+    ));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Tailwind / argument `theme`
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_theme_argument_prioritaire_sur_le_formulaire() {
+    let html = render(
+        &profile_form(Some(FormTheme::Bootstrap5)),
+        &[("theme", json!("tailwind")), ("layout", json!("inline"))],
+    );
+    assert!(html.starts_with(r#"<div class="runique-form flex flex-wrap items-end gap-4">"#));
+    assert!(
+        html.contains(r#"<label for="id_name" class="block text-sm font-medium text-gray-700">"#)
+    );
+    assert!(!html.contains("form-control"));
+}
+
+#[test]
+fn test_theme_par_defaut_de_l_application() {
+    let mut args: HashMap<String, Value> = HashMap::new();
+    args.insert(
+        "form".to_string(),
+        serde_json::to_value(profile_form(None)).unwrap(),
+    );
+    let html = RenderFormFunction {
+        default_theme: FormTheme::Bootstrap5,
+    }
+    .call(&args)
+    .unwrap();
+    assert!(html.as_str().unwrap().contains(r#"class="form-control""#));
+
+    // Le thème du formulaire reste prioritaire
+    args.insert(
+        "form".to_string(),
+        serde_json::to_value(profile_form(Some(FormTheme::Tailwind))).unwrap(),
+    );
+    let html = RenderFormFunction {
+        default_theme: FormTheme::Bootstrap5,
+    }
+    .call(&args)
+    .unwrap();
+    assert!(!html.as_str().unwrap().contains("form-control"));
+}
+
+#[test]
+fn test_theme_argument_inconnu_erreur() {
+    let mut args = HashMap::new();
+    args.insert(
+        "form".to_string(),
+        serde_json::to_value(profile_form(None)).unwrap(),
+    );
+    args.insert("theme".to_string(), json!("bulma"));
+    let err = RenderFormFunction::default().call(&args).unwrap_err();
+    assert!(err.to_string().contains("bulma"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// FormTheme / configuration
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_form_theme_parse_et_nom() {
+    assert_eq!("Bootstrap5".parse::<FormTheme>(), Ok(FormTheme::Bootstrap5));
+    assert_eq!("tailwind".parse::<FormTheme>(), Ok(FormTheme::Tailwind));
+    assert!("bulma".parse::<FormTheme>().is_err());
+    assert_eq!(FormTheme::Tailwind.as_str(), "tailwind");
+    assert_eq!(json!(FormTheme::Bootstrap5), json!("bootstrap5"));
+}
+
+#[test]
+fn test_form_theme_serialise_avec_le_formulaire() {
+    let value = serde_json::to_value(profile_form(Some(FormTheme::Tailwind))).unwrap();
+    assert_eq!(value["theme"], "tailwind");
+}
+
+#[test]
+fn test_runique_config_theme_plain_par_defaut() {
+    assert_eq!(RuniqueConfig::default().form_theme, FormTheme::Plain);
+}
@@ -0,0 +1,204 @@
+//! Tests — context/tera/form.rs : fonction Tera `render_form()`
+//! Couvre : enveloppe par champ (classes, ordre), layout stacked/inline,
+//!          erreurs inline et globales, CSRF du contexte, sortie non échappée
+
+use runique::context::register_asset_filters;
+use runique::context::tera::form::RenderFormFunction;
+use runique::forms::FormTheme;
+use runique::forms::{fields::text::TextField, form::Forms, renderer::FormRenderer};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tera::{Context, Function, Tera};
+
+fn field_tera() -> Arc<Tera> {
+    let mut tera = Tera::default();
+    tera.add_raw_template(
+        "base_string.html",
+        "<label for=\"id_{{ field.name }}\">{{ field.label }}</label>\
+         <input name=\"{{ field.name }}\" value=\"{{ field.value }}\">\
+         {% if field.error %}<span class=\"error\">{{ field.error }}</span>{% endif %}",
+    )
+    .unwrap();
+    tera.add_raw_template(
+        "csrf.html",
+        "<input type=\"hidden\" name=\"csrf_token\" value=\"{{ field.value }}\">",
+    )
+    .unwrap();
+    tera.add_raw_template("js_files.html", "").unwrap();
+    Arc::new(tera)
+}
+
+fn contact_form() -> Forms {
+    let mut form = Forms::new("form-token");
+    form.set_renderer(FormRenderer::new(field_tera()));
+    form.field(&TextField::text("name").label("Nom").required());
+    form.field(&TextField::email("email").label("Email"));
+    form
+}
+
+fn render(form: &Forms, extra: &[(&str, Value)]) -> String {
+    let mut args: HashMap<String, Value> = HashMap::new();
+    args.insert("form".to_string(), serde_json::to_value(form).unwrap());
+    for (k, v) in extra {
+        args.insert(k.to_string(), v.clone());
+    }
+    RenderFormFunction::default()
+        .call(&args)
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Structure
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_render_form_enveloppe_et_ordre() {
+    let html = render(&contact_form(), &[]);
+    assert!(html.starts_with(r#"<div class="runique-form runique-form--stacked">"#));
+    assert!(html.contains(r#"<div class="runique-field runique-field--text" data-field="name">"#));
+    assert!(html.contains(r#"data-field="email""#));
+    assert!(html.find("name=\"name\"").unwrap() < html.find("name=\"email\"").unwrap());
+    assert!(html.contains("<label for=\"id_name\">Nom</label>"));
+}
+
+#[test]
+fn test_render_form_layout_inline() {
+    let html = render(&contact_form(), &[("layout", json!("inline"))]);
+    assert!(html.starts_with(r#"<div class="runique-form runique-form--inline">"#));
+}
+
+#[test]
+fn test_render_form_layout_inconnu_erreur() {
+    let mut args = HashMap::new();
+    args.insert(
+        "form".to_string(),
+        serde_json::to_value(contact_form()).unwrap(),
+    );
+    args.insert("layout".to_string(), json!("grid"));
+    assert!(RenderFormFunction::default().call(&args).is_err());
+}
+
+#[test]
+fn test_render_form_sans_form_erreur() {
+    assert!(RenderFormFunction::default().call(&HashMap::new()).is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// CSRF
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_render_form_csrf_du_formulaire() {
+    let html = render(&contact_form(), &[]);
+    assert_eq!(html.matches(r#"name="csrf_token""#).count(), 1);
+    assert!(html.contains(r#"value="form-token""#));
+}
+
+#[test]
+fn test_render_form_csrf_du_contexte() {
+    let html = render(&contact_form(), &[("csrf_token", json!("ctx-token"))]);
+    assert_eq!(html.matches(r#"name="csrf_token""#).count(), 1);
+    assert!(html.contains(r#"value="ctx-token""#));
+    assert!(!html.contains("form-token"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Erreurs
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_render_form_erreur_inline_sur_le_bon_champ() {
+    let mut form = contact_form();
+    form.add_value("email", "pas-un-email");
+    assert!(form.is_valid().is_err());
+
+    let html = render(&form, &[]);
+    let name_start = html.find(r#"data-field="name""#).unwrap();
+    let email_start = html.find(r#"data-field="email""#).unwrap();
+    let name_block = &html[name_start..email_start];
+    let email_block = &html[email_start..];
+
+    assert!(html.contains(r#"runique-field--text runique-field--error" data-field="name""#));
+    assert!(name_block.contains(r#"<span class="error">"#));
+    assert!(email_block.contains(r#"<span class="error">"#));
+}
+
+#[test]
+fn test_render_form_erreurs_globales_echappees() {
+    let mut form = contact_form();
+    form.errors.push("<b>Échec</b>".to_string());
+    let html = render(&form, &[]);
+    assert!(
+        html.contains(r#"<ul class="runique-form__errors"><li>&lt;b&gt;Échec&lt;/b&gt;</li></ul>"#)
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Enregistrement Tera
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_render_form_enregistre_et_non_echappe() {
+    let mut tera = Tera::default();
+    register_asset_filters(
+        &mut tera,
+        "/static".to_string(),
+        "/media".to_string(),
+        "/runique-static".to_string(),
+        "/runique-media".to_string(),
+        Arc::new(RwLock::new(HashMap::new())),
+        FormTheme::Plain,
+    );
+    tera.add_raw_template(
+        "page.html",
+        "{{ render_form(form=form, csrf_token=csrf_token) }}",
+    )
+    .unwrap();
+
+    let mut ctx = Context::new();
+    ctx.insert("form", &contact_form());
+    ctx.insert("csrf_token", "ctx-token");
+    let html = tera.render("page.html", &ctx).unwrap();
+    assert!(html.starts_with("<div class=\"runique-form"));
+    assert!(html.contains(r#"value="ctx-token""#));
+}
+
+#[test]
+fn test_render_form_theme_propre_a_chaque_instance_tera() {
+    let themed = |theme| {
+        let mut tera = Tera::default();
+        register_asset_filters(
+            &mut tera,
+            "/static".to_string(),
+            "/media".to_string(),
+            "/runique-static".to_string(),
+            "/runique-media".to_string(),
+            Arc::new(RwLock::new(HashMap::new())),
+            theme,
+        );
+        tera.add_raw_template("page.html", "{{ render_form(form=form) }}")
+            .unwrap();
+        tera
+    };
+    let bootstrap = themed(FormTheme::Bootstrap5);
+    let plain = themed(FormTheme::Plain);
+
+    let mut ctx = Context::new();
+    ctx.insert("form", &contact_form());
+    assert!(
+        bootstrap
+            .render("page.html", &ctx)
+            .unwrap()
+            .contains("form-control")
+    );
+    assert!(
+        !plain
+            .render("page.html", &ctx)
+            .unwrap()
+            .contains("form-control")
+    );
+}
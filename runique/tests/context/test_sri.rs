@@ -0,0 +1,173 @@
+//! Tests — utils/config/static_manifest.rs (SRI) + fonctions Tera sri_hash() / script_tag()
+//! Couvre : empreinte sha384 du manifeste, attribut integrity, balise script complète
+//!          (src haché, integrity, crossorigin, nonce), fichier absent → balise sans integrity,
+//!          réécriture `sri_hash("…")` / `script_tag("…")` du préprocesseur
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use axum::{Router, response::IntoResponse, routing::get};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use runique::app::RuniqueAppBuilder;
+use runique::config::app::RuniqueConfig;
+use runique::context::{Request as TplRequest, ScriptTagFunction, SriHashFunction};
+use runique::testing::{TestClient, test_db};
+use runique::utils::StaticManifest;
+use sha2::{Digest, Sha384};
+use std::fs;
+use std::path::Path;
+use tera::{Context, Tera};
+
+const APP_JS: &str = "console.log('app');";
+
+fn static_dir(name: &str) -> TestTempDir {
+    let dir = TestTempDir::new("runique_test_sri", name);
+    fs::create_dir_all(dir.join("js")).unwrap();
+    fs::write(dir.join("js/app.js"), APP_JS).unwrap();
+    dir
+}
+
+fn sha384(content: &str) -> String {
+    format!(
+        "sha384-{}",
+        STANDARD.encode(Sha384::digest(content.as_bytes()))
+    )
+}
+
+fn render(manifest: StaticManifest, template: &str) -> String {
+    let mut tera = Tera::default();
+    tera.register_function("sri_hash", SriHashFunction::new(manifest.clone()));
+    tera.register_function(
+        "script_tag",
+        ScriptTagFunction::new("/static/".to_string(), manifest),
+    );
+    tera.add_raw_template("t", template).unwrap();
+    tera.render("t", &Context::new()).unwrap()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Manifeste
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_manifeste_empreinte_sha384() {
+    let dir = static_dir("manifeste");
+    let manifest = StaticManifest::build(Path::new(dir.as_str()));
+    assert_eq!(
+        manifest.integrity("js/app.js"),
+        Some(sha384(APP_JS).as_str())
+    );
+    assert_eq!(
+        manifest.integrity("/js/app.js"),
+        Some(sha384(APP_JS).as_str())
+    );
+    assert_eq!(manifest.integrity("js/absent.js"), None);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Fonctions Tera
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_sri_hash_attribut_integrity() {
+    let dir = static_dir("sri_hash");
+    let manifest = StaticManifest::build(Path::new(dir.as_str()));
+    assert_eq!(
+        render(manifest, r#"{{ sri_hash(path="js/app.js") }}"#),
+        format!(r#"integrity="{}""#, sha384(APP_JS))
+    );
+    assert_eq!(
+        render(
+            StaticManifest::default(),
+            r#"{{ sri_hash(path="js/app.js") }}"#
+        ),
+        ""
+    );
+}
+
+#[test]
+fn test_script_tag_complete() {
+    let dir = static_dir("script_tag");
+    let manifest = StaticManifest::build(Path::new(dir.as_str()));
+    let hash = manifest.hash("js/app.js").unwrap().to_string();
+
+    let tag = render(
+        manifest,
+        r#"{{ script_tag(path="js/app.js", nonce="n0nce") }}"#,
+    );
+    assert_eq!(
+        tag,
+        format!(
+            r#"<script src="/static/js/app.js?v={hash}" integrity="{}" crossorigin="anonymous" nonce="n0nce"></script>"#,
+            sha384(APP_JS)
+        )
+    );
+}
+
+#[test]
+fn test_script_tag_fichier_absent_sans_integrity() {
+    let tag = render(
+        StaticManifest::default(),
+        r#"{{ script_tag(path="js/absent.js", nonce="") }}"#,
+    );
+    assert_eq!(tag, r#"<script src="/static/js/absent.js"></script>"#);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Application : préprocesseur + nonce de la requête
+// ═══════════════════════════════════════════════════════════════
+
+async fn page(mut req: TplRequest) -> impl IntoResponse {
+    req.render("page.html")
+}
+
+#[tokio::test]
+async fn test_page_script_tag_et_sri_hash() {
+    let statics = static_dir("app_statics");
+    let templates = TestTempDir::new("runique_test_sri", "app_templates");
+    fs::write(
+        templates.join("page.html"),
+        r#"{{ script_tag("js/app.js") }}<link {{ sri_hash('js/app.js') }}>{{ script_tag("js/absent.js") }}"#,
+    )
+    .unwrap();
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    config.static_files.templates_dir = vec![templates.as_str().to_string()];
+    config.static_files.static_url = "/static".to_string();
+    config.static_files.staticfiles_dirs = statics.as_str().to_string();
+    config.static_files.media_url = "/media".to_string();
+    config.static_files.media_root = statics.as_str().to_string();
+    config.static_files.static_runique_url = String::new();
+    let app = RuniqueAppBuilder::new(config)
+        .with_database(test_db().await)
+        .routes(Router::new().route("/page", get(page)))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    let client = TestClient::from_app(app);
+
+    let resp = client.get("/page").await;
+    let csp = resp.header("content-security-policy").unwrap_or_default();
+    let html = resp.text();
+    let integrity = format!(r#"integrity="{}""#, sha384(APP_JS));
+
+    assert!(html.contains(&format!("<link {integrity}>")), "{html}");
+    let tag = html.split("<link").next().unwrap();
+    assert!(tag.contains(&integrity), "{tag}");
+    assert!(tag.contains(r#"crossorigin="anonymous""#), "{tag}");
+
+    // Le nonce de la balise est celui de l'en-tête CSP
+    let nonce = tag
+        .split(r#"nonce=""#)
+        .nth(1)
+        .unwrap()
+        .split('"')
+        .next()
+        .unwrap();
+    assert!(!nonce.is_empty());
+    assert!(csp.contains(&format!("'nonce-{nonce}'")), "{csp}");
+
+    // Fichier absent : balise valide, sans integrity
+    assert!(
+        html.contains(r#"<script src="/static/js/absent.js" nonce=""#),
+        "{html}"
+    );
+}
@@ -0,0 +1,107 @@
+//! Tests — context/sse.rs : réponses Server-Sent Events
+//! Couvre : en-têtes et cadrage `text/event-stream` derrière la pile Runique,
+//!          commentaire heartbeat pendant l'inactivité, déconnexion du client
+//!          → flux abandonné (récepteur broadcast libéré)
+
+use axum::{
+    Router,
+    body::Body,
+    http::Request as HttpRequest,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use futures_util::stream;
+use http_body_util::BodyExt;
+use runique::app::RuniqueAppBuilder;
+use runique::config::app::RuniqueConfig;
+use runique::context::sse::{Event, sse_stream, sse_stream_with};
+use runique::testing::{TestClient, test_db};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tower::ServiceExt;
+
+async fn counts() -> impl IntoResponse {
+    sse_stream(stream::iter(
+        (1..=2).map(|n| Event::default().event("count").data(n.to_string())),
+    ))
+}
+
+async fn lent() -> Response {
+    let late = stream::once(async {
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        Event::default().data("fin")
+    });
+    sse_stream_with(late, Duration::from_millis(20))
+}
+
+#[tokio::test]
+async fn test_sse_en_tetes_et_cadrage() {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    let app = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .routes(Router::new().route("/events", get(counts)))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    let client = TestClient::from_app(app);
+
+    let resp = client.get("/events").await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resp.header("content-type"), Some("text/event-stream"));
+    assert_eq!(resp.header("cache-control"), Some("no-cache"));
+    assert_eq!(resp.header("x-accel-buffering"), Some("no"));
+    assert_eq!(
+        resp.text(),
+        "event: count\ndata: 1\n\nevent: count\ndata: 2\n\n"
+    );
+}
+
+#[tokio::test]
+async fn test_sse_heartbeat_pendant_l_inactivite() {
+    let app = Router::new().route("/lent", get(lent));
+    let resp = app
+        .oneshot(HttpRequest::get("/lent").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let body = resp.into_body().collect().await.unwrap().to_bytes();
+    let body = String::from_utf8_lossy(&body);
+
+    assert!(body.starts_with(": heartbeat\n\n"), "{body}");
+    assert!(body.ends_with("data: fin\n\n"), "{body}");
+}
+
+#[tokio::test]
+async fn test_sse_deconnexion_abandonne_le_flux() {
+    let (tx, _) = broadcast::channel::<String>(8);
+    let sender = tx.clone();
+    let app = Router::new().route(
+        "/live",
+        get(move || {
+            let rx = sender.subscribe();
+            async move {
+                let events = stream::unfold(rx, |mut rx| async move {
+                    let data = rx.recv().await.ok()?;
+                    Some((Event::default().data(data), rx))
+                });
+                sse_stream(events)
+            }
+        }),
+    );
+
+    let resp = app
+        .oneshot(HttpRequest::get("/live").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(tx.receiver_count(), 1);
+
+    let mut body = resp.into_body();
+    tx.send("42".to_string()).unwrap();
+    let frame = body.frame().await.unwrap().unwrap().into_data().unwrap();
+    assert_eq!(&frame[..], b"data: 42\n\n");
+
+    // Client parti : le corps de réponse est abandonné, le récepteur avec
+    drop(body);
+    assert_eq!(tx.receiver_count(), 0);
+}
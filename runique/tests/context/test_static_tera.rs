@@ -1,7 +1,9 @@
 //! Tests — context/tera/static_tera.rs
-//! Couvre : register_asset_filters, mask_filter, csrf_filter, register_filter
+//! Couvre : register_asset_filters, mask_filter, csrf_filter, register_filter,
+//!          markdown / render_markdown
 
 use runique::context::register_asset_filters;
+use runique::forms::FormTheme;
 use runique::utils::env::css_token;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
@@ -19,6 +21,7 @@ fn make_tera() -> Tera {
         "/runique-static".to_string(),
         "/runique-media".to_string(),
         registry,
+        FormTheme::Plain,
     );
     tera
 }
@@ -210,6 +213,7 @@ fn test_static_base_url_trailing_slash_normalise() {
         "/runique-static/".to_string(),
         "/runique-media/".to_string(),
         registry,
+        FormTheme::Plain,
     );
     tera.add_raw_template("t", "{{ file | static }}").unwrap();
     let mut ctx = Context::new();
@@ -217,3 +221,65 @@ fn test_static_base_url_trailing_slash_normalise() {
     let result = tera.render("t", &ctx).unwrap();
     assert_eq!(result, format!("/static/style.css?v={}", css_token()));
 }
+
+// ═══════════════════════════════════════════════════════════════
+// Filtre sanitize — politique nommée
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_sanitize_avec_politique_nommee() {
+    runique::utils::register_sanitize_policy(
+        "test_static_tera_span",
+        runique::utils::SanitizePolicy::empty()
+            .allow_tags(["span"])
+            .allow_attributes("span", ["class"]),
+    );
+    let mut tera = make_tera();
+    tera.add_raw_template(
+        "t",
+        r#"{{ val | sanitize(policy="test_static_tera_span") | safe }}|{{ val | sanitize | safe }}"#,
+    )
+    .unwrap();
+    let mut ctx = Context::new();
+    ctx.insert("val", r#"<span class="a">s</span><b>g</b>"#);
+    assert_eq!(
+        tera.render("t", &ctx).unwrap(),
+        r#"<span class="a">s</span>g|s<b>g</b>"#
+    );
+
+    tera.add_raw_template("inconnue", r#"{{ val | sanitize(policy="absente") }}"#)
+        .unwrap();
+    assert!(tera.render("inconnue", &ctx).is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Filtres markdown / render_markdown
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_render_markdown_rend_et_sanitise() {
+    let mut tera = make_tera();
+    tera.add_raw_template("t", "{{ val | render_markdown | safe }}")
+        .unwrap();
+    let mut ctx = Context::new();
+    ctx.insert("val", "**ok** <script>alert(1)</script>");
+    let result = tera.render("t", &ctx).unwrap();
+    assert!(result.contains("<strong>ok</strong>"));
+    assert!(!result.contains("<script"));
+}
+
+#[test]
+fn test_markdown_et_render_markdown_identiques() {
+    let mut tera = make_tera();
+    tera.add_raw_template(
+        "t",
+        "{{ val | markdown | safe }}|{{ val | render_markdown | safe }}",
+    )
+    .unwrap();
+    let mut ctx = Context::new();
+    ctx.insert("val", "[x](javascript:alert(1)) _a_");
+    let result = tera.render("t", &ctx).unwrap();
+    let (a, b) = result.split_once('|').unwrap();
+    assert_eq!(a, b);
+    assert!(!a.contains("javascript"));
+}
@@ -0,0 +1,145 @@
+//! Tests — utils/config/static_manifest.rs + fonction Tera static_url() + middleware/cache_busting.rs
+//! Couvre : manifeste (hash par fichier, sous-dossiers, dossier absent), URL hachée,
+//!          fichier absent → URL simple, réécriture `static_url("…")` du préprocesseur,
+//!          Cache-Control immutable sur le hash courant uniquement
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use axum::{Router, http::StatusCode, response::IntoResponse, routing::get};
+use runique::app::RuniqueAppBuilder;
+use runique::config::app::RuniqueConfig;
+use runique::context::{Request as TplRequest, StaticUrlFunction};
+use runique::middleware::IMMUTABLE_CACHE;
+use runique::testing::{TestClient, test_db};
+use runique::utils::StaticManifest;
+use std::fs;
+use std::path::Path;
+use tera::{Context, Tera};
+
+fn static_dir(name: &str) -> TestTempDir {
+    let dir = TestTempDir::new("runique_test_static_url", name);
+    fs::create_dir_all(dir.join("css")).unwrap();
+    fs::write(dir.join("css/app.css"), "body { color: red }").unwrap();
+    fs::write(dir.join("logo.svg"), "<svg/>").unwrap();
+    dir
+}
+
+fn render(manifest: StaticManifest, template: &str) -> String {
+    let mut tera = Tera::default();
+    tera.register_function(
+        "static_url",
+        StaticUrlFunction::new("/static/".to_string(), manifest),
+    );
+    tera.add_raw_template("t", template).unwrap();
+    tera.render("t", &Context::new()).unwrap()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Manifeste
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_manifeste_hash_par_fichier() {
+    let dir = static_dir("manifeste");
+    let manifest = StaticManifest::build(Path::new(dir.as_str()));
+    assert_eq!(manifest.len(), 2);
+
+    let css = manifest.hash("css/app.css").unwrap().to_string();
+    assert_eq!(css.len(), 12);
+    assert!(css.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_eq!(manifest.hash("/css/app.css"), Some(css.as_str()));
+    assert_ne!(manifest.hash("logo.svg"), Some(css.as_str()));
+
+    // Contenu modifié → nouveau hash au prochain démarrage
+    fs::write(dir.join("css/app.css"), "body { color: blue }").unwrap();
+    let rebuilt = StaticManifest::build(Path::new(dir.as_str()));
+    assert_ne!(rebuilt.hash("css/app.css"), Some(css.as_str()));
+}
+
+#[test]
+fn test_manifeste_dossier_absent_vide() {
+    let manifest = StaticManifest::build(Path::new("/chemin/inexistant/runique"));
+    assert!(manifest.is_empty());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Fonction Tera
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_static_url_ajoute_le_hash() {
+    let dir = static_dir("fonction");
+    let manifest = StaticManifest::build(Path::new(dir.as_str()));
+    let hash = manifest.hash("css/app.css").unwrap().to_string();
+
+    let url = render(manifest, r#"{{ static_url(path="/css/app.css") }}"#);
+    assert_eq!(url, format!("/static/css/app.css?v={hash}"));
+}
+
+#[test]
+fn test_static_url_fichier_absent_url_simple() {
+    let url = render(
+        StaticManifest::default(),
+        r#"{{ static_url(path="js/absent.js") }}"#,
+    );
+    assert_eq!(url, "/static/js/absent.js");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Application : préprocesseur + Cache-Control
+// ═══════════════════════════════════════════════════════════════
+
+async fn page(mut req: TplRequest) -> impl IntoResponse {
+    req.render("page.html")
+}
+
+async fn client(statics: &TestTempDir, templates: &TestTempDir) -> TestClient {
+    fs::write(
+        templates.join("page.html"),
+        r#"<link href="{{ static_url("css/app.css") }}"><img src="{{ static_url('absent.png') }}">"#,
+    )
+    .unwrap();
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    config.static_files.templates_dir = vec![templates.as_str().to_string()];
+    config.static_files.static_url = "/static".to_string();
+    config.static_files.staticfiles_dirs = statics.as_str().to_string();
+    config.static_files.media_url = "/media".to_string();
+    config.static_files.media_root = statics.as_str().to_string();
+    config.static_files.static_runique_url = String::new();
+    let app = RuniqueAppBuilder::new(config)
+        .static_files(|s| s.enable().static_cache("no-cache"))
+        .with_database(test_db().await)
+        .routes(Router::new().route("/page", get(page)))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    TestClient::from_app(app)
+}
+
+#[tokio::test]
+async fn test_page_url_hachee_servie_immutable() {
+    let statics = static_dir("app_statics");
+    let templates = TestTempDir::new("runique_test_static_url", "app_templates");
+    let client = client(&statics, &templates).await;
+    let hash = StaticManifest::build(Path::new(statics.as_str()))
+        .hash("css/app.css")
+        .unwrap()
+        .to_string();
+
+    let html = client.get("/page").await.text().replace("&#x2F;", "/");
+    let url = format!("/static/css/app.css?v={hash}");
+    assert!(html.contains(&url), "{url} absent de {html}");
+    assert!(html.contains(r#"src="/static/absent.png""#));
+
+    let resp = client.get(&url).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.header("cache-control"), Some(IMMUTABLE_CACHE));
+    assert_eq!(resp.text(), "body { color: red }");
+
+    // Hash périmé ou absent : politique `static_cache` configurée
+    for url in ["/static/css/app.css?v=000000000000", "/static/css/app.css"] {
+        let resp = client.get(url).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.header("cache-control"), Some("no-cache"));
+    }
+}
@@ -1,5 +1,6 @@
 // Tests pour context::template::Request — from_request_parts, is_get/post/put/delete,
-// render (erreur et succès), insert, render_with, map_tera.
+// render (erreur et succès), insert, render_with, map_tera, respond (HTML / JSON, Vary),
+// path typé (segment optionnel, 404 si non numérique).
 //
 // Deux stacks :
 //  • csrf_router  — csrf_middleware (GET seulement, token validé)
@@ -16,6 +17,7 @@ use axum::{
     routing::{delete, get, post, put},
 };
 use runique::{
+    app::LiveTera,
     context::{RequestExtensions, template::Request as TplRequest},
     middleware::security::csrf::csrf_middleware,
     utils::aliases::AEngine,
@@ -163,6 +165,13 @@ async fn handler_render_ok(mut tpl: TplRequest) -> Response {
         .unwrap_or_else(|e| e.into_response())
 }
 
+/// respond() — HTML ou JSON selon Accept / ?format=.
+async fn handler_respond(mut tpl: TplRequest) -> Response {
+    tpl.context.insert("title", "Accueil");
+    tpl.respond("hello.html")
+        .unwrap_or_else(|e| e.into_response())
+}
+
 // ── Tests — from_request_parts + méthodes ───────────────────────────────────
 
 #[tokio::test]
@@ -239,15 +248,21 @@ async fn app_with_template() -> Router {
     };
 
     let engine_with_tpl = Arc::new(RuniqueEngine {
-        tera: Arc::new(tera),
+        tera: LiveTera::new(tera),
         config: engine.config.clone(),
         db: engine.db.clone(),
+        tasks: Default::default(),
+        scheduler: Default::default(),
+        cache: Default::default(),
         url_registry: engine.url_registry.clone(),
+        i18n: engine.i18n.clone(),
         features: MiddlewareConfig::default(),
         security_csp: Arc::new(SecurityPolicy::default()),
         security_hosts: Arc::new(HostPolicy::new(vec![], true)),
         csrf_exempt_paths: Arc::new(vec![]),
+        trusted_origins: Arc::new(vec![]),
         permissions_policy: Arc::new(runique::middleware::PermissionsPolicy::default()),
+        security_headers: Arc::new(runique::middleware::SecurityHeaders::default()),
         trusted_proxies: Arc::new(runique::middleware::TrustedProxies::default()),
         session_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
         session_db_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
@@ -257,6 +272,7 @@ async fn app_with_template() -> Router {
     let session_layer = SessionManagerLayer::new(MemoryStore::default());
     Router::new()
         .route("/render_ok", get(handler_render_ok))
+        .route("/respond", get(handler_respond))
         .layer(middleware::from_fn_with_state(
             engine_with_tpl.clone(),
             csrf_middleware,
@@ -282,6 +298,85 @@ async fn test_render_success_returns_html_content() {
     assert!(body_str(resp).await.contains("Hello"));
 }
 
+// ── Tests — respond (négociation de contenu) ─────────────────────────────────
+
+#[tokio::test]
+async fn test_respond_sans_accept_rend_le_html() {
+    let resp = request::get(app_with_template().await, "/respond").await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers()["vary"], "Accept");
+    assert!(body_str(resp).await.contains("<h1>Hello</h1>"));
+}
+
+#[tokio::test]
+async fn test_respond_accept_tout_rend_le_html() {
+    let resp =
+        request::get_with_header(app_with_template().await, "/respond", "accept", "*/*").await;
+    assert!(body_str(resp).await.contains("<h1>Hello</h1>"));
+}
+
+#[tokio::test]
+async fn test_respond_accept_json_sans_cles_internes() {
+    let resp = request::get_with_header(
+        app_with_template().await,
+        "/respond",
+        "accept",
+        "application/json",
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    // Même URL, autre représentation : les caches doivent distinguer selon Accept
+    assert_eq!(resp.headers()["vary"], "Accept");
+    let json: serde_json::Value = serde_json::from_str(&body_str(resp).await).unwrap();
+    assert_eq!(json["title"], "Accueil");
+    for key in [
+        "csrf_token",
+        "csp_nonce",
+        "debug",
+        "messages",
+        "current_path",
+    ] {
+        assert!(json.get(key).is_none(), "{key} exposé");
+    }
+}
+
+#[tokio::test]
+async fn test_respond_navigateur_prefere_le_html() {
+    let resp = request::get_with_header(
+        app_with_template().await,
+        "/respond",
+        "accept",
+        "text/html,application/xhtml+xml,application/json;q=0.9,*/*;q=0.8",
+    )
+    .await;
+    assert!(body_str(resp).await.contains("<h1>Hello</h1>"));
+}
+
+#[tokio::test]
+async fn test_respond_format_json_prioritaire_sur_accept() {
+    let resp = request::get_with_header(
+        app_with_template().await,
+        "/respond?format=json",
+        "accept",
+        "text/html",
+    )
+    .await;
+    let json: serde_json::Value = serde_json::from_str(&body_str(resp).await).unwrap();
+    assert_eq!(json["title"], "Accueil");
+}
+
+#[tokio::test]
+async fn test_respond_format_html_prioritaire_sur_accept() {
+    let resp = request::get_with_header(
+        app_with_template().await,
+        "/respond?format=html",
+        "accept",
+        "application/json",
+    )
+    .await;
+    assert!(body_str(resp).await.contains("<h1>Hello</h1>"));
+}
+
 // ── Tests — extraction sans engine → 500 ─────────────────────────────────────
 
 #[tokio::test]
@@ -301,3 +396,50 @@ async fn test_request_extraction_sans_engine_retourne_500() {
     let resp = request::get(app, "/").await;
     assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
 }
+
+// ── Tests — path typé ────────────────────────────────────────────────────────
+
+#[derive(serde::Deserialize)]
+struct Archive {
+    year: u32,
+    month: Option<u32>,
+}
+
+async fn handler_archive(tpl: TplRequest) -> runique::utils::aliases::AppResult<String> {
+    let archive: Archive = tpl.path()?;
+    Ok(format!(
+        "{}|{}",
+        archive.year,
+        archive.month.map(|m| m.to_string()).unwrap_or_default()
+    ))
+}
+
+#[tokio::test]
+async fn test_path_type_avec_segment_optionnel() {
+    let engine = build_engine().await;
+    let app = runique::urlpatterns! {
+        "/archives/{year}/{month?}" => get(handler_archive),
+    }
+    .layer(middleware::from_fn_with_state(
+        engine.clone(),
+        csrf_middleware,
+    ))
+    .layer(middleware::from_fn_with_state(
+        engine.clone(),
+        engine_inject,
+    ))
+    .layer(SessionManagerLayer::new(MemoryStore::default()));
+
+    let resp = request::get(app.clone(), "/archives/2024").await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(body_str(resp).await, "2024|");
+
+    let resp = request::get(app.clone(), "/archives/2024/5").await;
+    assert_eq!(body_str(resp).await, "2024|5");
+
+    // Segment non numérique : page absente, pas erreur serveur
+    for uri in ["/archives/abc", "/archives/2024/mai"] {
+        let resp = request::get(app.clone(), uri).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND, "{uri}");
+    }
+}
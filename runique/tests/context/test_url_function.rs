@@ -188,3 +188,19 @@ fn test_link_query_other_type_appended() {
     let s = result.as_str().unwrap();
     assert!(s.starts_with("/items/"));
 }
+
+#[test]
+fn test_link_segment_optionnel() {
+    let func = LinkFunction {
+        url_registry: make_registry(&[("archives", "/archives/{year}/{month?}")]),
+    };
+    let args = make_args(&[("link", json!("archives")), ("year", json!(2024))]);
+    assert_eq!(func.call(&args).unwrap(), json!("/archives/2024"));
+
+    let args = make_args(&[
+        ("link", json!("archives")),
+        ("year", json!(2024)),
+        ("month", json!(5)),
+    ]);
+    assert_eq!(func.call(&args).unwrap(), json!("/archives/2024/5"));
+}
@@ -0,0 +1,292 @@
+//! Tests — context/ws.rs : routes WebSocket
+//! Couvre : utilisateur connu à la poignée de main (anonyme / connecté),
+//!          `Origin` vérifié (même hôte / origine CORS, sinon 403),
+//!          `login_required` → 401 sans session (redirection hors WebSocket),
+//!          salon broadcast : diffusion entre clients, désabonnement à la déconnexion
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request as HttpRequest, StatusCode},
+    response::Response,
+    routing::get,
+};
+use futures_util::{SinkExt, StreamExt};
+use runique::app::RuniqueAppBuilder;
+use runique::auth::login;
+use runique::config::app::RuniqueConfig;
+use runique::context::Request as TplRequest;
+use runique::context::ws::{Message, WebSocket, WsUpgrade, ws};
+use runique::macros::RouterExt;
+use runique::testing::{TestClient, test_db};
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+use tokio_tungstenite::tungstenite::{self, client::IntoClientRequest};
+
+async fn bonjour(ws: WsUpgrade) -> Response {
+    let name = ws
+        .user
+        .as_ref()
+        .map_or("anonyme".to_string(), |u| u.username.clone());
+    ws.on_upgrade(move |mut socket: WebSocket| async move {
+        let _ = socket
+            .send(Message::Text(format!("bonjour {name}").into()))
+            .await;
+    })
+}
+
+async fn connexion(req: TplRequest) -> StatusCode {
+    login(
+        &req.session,
+        &req.engine.db,
+        1,
+        "alice",
+        false,
+        false,
+        None,
+        false,
+    )
+    .await
+    .unwrap();
+    StatusCode::OK
+}
+
+/// Salon : chaque message reçu est diffusé à tous ; le récepteur est libéré
+/// quand la tâche se termine (déconnexion).
+async fn salon(socket: WebSocket, tx: broadcast::Sender<String>) {
+    let mut rx = tx.subscribe();
+    let (mut sender, mut receiver) = socket.split();
+    loop {
+        tokio::select! {
+            incoming = receiver.next() => match incoming {
+                Some(Ok(Message::Text(text))) => {
+                    let _ = tx.send(text.to_string());
+                }
+                Some(Ok(_)) => {}
+                _ => break,
+            },
+            Ok(text) = rx.recv() => {
+                if sender.send(Message::Text(text.into())).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+fn routes(tx: broadcast::Sender<String>) -> Router {
+    Router::new()
+        .route("/chat", ws(bonjour))
+        .route("/connexion", get(connexion))
+        .route(
+            "/salon",
+            ws(move |ws: WsUpgrade| async move {
+                let tx = tx.clone();
+                ws.on_upgrade(move |socket| salon(socket, tx))
+            }),
+        )
+        .login_required("/prive", "ws_prive", ws(bonjour), "/login")
+}
+
+async fn app(tx: broadcast::Sender<String>) -> runique::app::RuniqueApp {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .routes(routes(tx))
+        .middleware(|m| m.with_cors(|c| c.origin("https://front.exemple.com")))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"))
+}
+
+/// Vrai serveur : la montée en WebSocket exige une connexion hyper.
+async fn serve(tx: broadcast::Sender<String>) -> SocketAddr {
+    let router = app(tx).await.router;
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .unwrap();
+    });
+    addr
+}
+
+type Client =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// Poignée de main depuis une page servie par l'application (`Origin` = même hôte).
+async fn connect(addr: SocketAddr, path: &str, cookie: Option<&str>) -> Result<Client, StatusCode> {
+    connect_from(addr, path, cookie, Some("http://localhost")).await
+}
+
+async fn connect_from(
+    addr: SocketAddr,
+    path: &str,
+    cookie: Option<&str>,
+    origin: Option<&str>,
+) -> Result<Client, StatusCode> {
+    let mut req = format!("ws://{addr}{path}").into_client_request().unwrap();
+    req.headers_mut()
+        .insert("host", "localhost".parse().unwrap());
+    if let Some(origin) = origin {
+        req.headers_mut().insert("origin", origin.parse().unwrap());
+    }
+    if let Some(cookie) = cookie {
+        req.headers_mut().insert("cookie", cookie.parse().unwrap());
+    }
+    match tokio_tungstenite::connect_async(req).await {
+        Ok((socket, _)) => Ok(socket),
+        Err(tungstenite::Error::Http(resp)) => Err(resp.status()),
+        Err(e) => panic!("connexion WebSocket : {e}"),
+    }
+}
+
+async fn next_text(socket: &mut Client) -> String {
+    match tokio::time::timeout(Duration::from_secs(2), socket.next()).await {
+        Ok(Some(Ok(tungstenite::Message::Text(text)))) => text.to_string(),
+        other => panic!("message texte attendu, reçu {other:?}"),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Poignée de main
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_ws_utilisateur_connu_a_la_poignee_de_main() {
+    let (tx, _) = broadcast::channel(8);
+    let addr = serve(tx).await;
+
+    let mut anonyme = connect(addr, "/chat", None).await.unwrap();
+    assert_eq!(next_text(&mut anonyme).await, "bonjour anonyme");
+
+    let resp = reqwest::get(format!("http://{addr}/connexion"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let cookie = resp
+        .headers()
+        .get_all("set-cookie")
+        .iter()
+        .filter_map(|v| v.to_str().ok()?.split(';').next())
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    let mut alice = connect(addr, "/prive", Some(&cookie)).await.unwrap();
+    assert_eq!(next_text(&mut alice).await, "bonjour alice");
+}
+
+#[tokio::test]
+async fn test_ws_origine_etrangere_refusee() {
+    let (tx, _) = broadcast::channel(8);
+    let addr = serve(tx).await;
+
+    let resp = reqwest::get(format!("http://{addr}/connexion"))
+        .await
+        .unwrap();
+    let cookie = resp
+        .headers()
+        .get_all("set-cookie")
+        .iter()
+        .filter_map(|v| v.to_str().ok()?.split(';').next())
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    // Une page tierce ouvre le WebSocket avec le cookie du visiteur
+    assert_eq!(
+        connect_from(
+            addr,
+            "/prive",
+            Some(&cookie),
+            Some("https://malveillant.com")
+        )
+        .await
+        .err(),
+        Some(StatusCode::FORBIDDEN)
+    );
+    assert_eq!(
+        connect_from(addr, "/chat", None, None).await.err(),
+        Some(StatusCode::FORBIDDEN)
+    );
+    assert_eq!(
+        connect_from(addr, "/chat", None, Some("null")).await.err(),
+        Some(StatusCode::FORBIDDEN)
+    );
+
+    // Origine déclarée dans la configuration CORS
+    let mut front = connect_from(
+        addr,
+        "/prive",
+        Some(&cookie),
+        Some("https://front.exemple.com"),
+    )
+    .await
+    .unwrap();
+    assert_eq!(next_text(&mut front).await, "bonjour alice");
+}
+
+#[tokio::test]
+async fn test_ws_login_required_refuse_sans_session() {
+    let (tx, _) = broadcast::channel(8);
+    let addr = serve(tx.clone()).await;
+    assert_eq!(
+        connect(addr, "/prive", None).await.err(),
+        Some(StatusCode::UNAUTHORIZED)
+    );
+
+    // Hors poignée de main WebSocket : redirection habituelle
+    let client = TestClient::from_app(app(tx).await);
+    let resp = client.get("/prive").await;
+    assert!(resp.status().is_redirection());
+    assert_eq!(resp.location(), Some("/login"));
+
+    let req = HttpRequest::get("/prive")
+        .header("connection", "upgrade")
+        .header("upgrade", "websocket")
+        .header("sec-websocket-version", "13")
+        .header("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ==")
+        .body(Body::empty())
+        .unwrap();
+    assert_eq!(client.request(req).await.status(), StatusCode::UNAUTHORIZED);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Salon broadcast
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_ws_salon_diffuse_et_se_desabonne() {
+    let (tx, _) = broadcast::channel(8);
+    let addr = serve(tx.clone()).await;
+
+    let mut a = connect(addr, "/salon", None).await.unwrap();
+    let mut b = connect(addr, "/salon", None).await.unwrap();
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while tx.receiver_count() < 2 && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(tx.receiver_count(), 2);
+
+    a.send(tungstenite::Message::Text("salut".into()))
+        .await
+        .unwrap();
+    assert_eq!(next_text(&mut b).await, "salut");
+    assert_eq!(next_text(&mut a).await, "salut");
+
+    a.close(None).await.unwrap();
+    drop(a);
+    b.close(None).await.unwrap();
+    drop(b);
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while tx.receiver_count() > 0 && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(tx.receiver_count(), 0, "récepteurs non libérés");
+}
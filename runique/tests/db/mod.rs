@@ -1,4 +1,13 @@
+pub mod test_bulk;
 pub mod test_db_config;
+pub mod test_fixture;
 pub mod test_mariadb;
+pub mod test_paginator;
 pub mod test_postgres;
+pub mod test_raw;
+pub mod test_related;
+pub mod test_replica;
+pub mod test_signals;
 pub mod test_sqlite;
+pub mod test_timestamps;
+pub mod test_transaction;
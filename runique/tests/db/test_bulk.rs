@@ -0,0 +1,251 @@
+//! Tests — macros/bdd : `objects.bulk_create` / `bulk_create_with`
+//!
+//! Couverture :
+//!   - entrée vide : 0, aucune erreur
+//!   - découpage en lots (`batch_size`), `0` = taille maximale
+//!   - lot plus grand que la limite de paramètres du moteur : réduit automatiquement
+//!   - conflit sans politique : erreur unique, transaction annulée
+//!   - `BulkConflict::Ignore` : lignes en conflit ignorées et non comptées
+//!   - `BulkConflict::Update` : upsert des colonnes demandées
+//!   - manager soft delete (`SoftObjects`)
+
+use crate::helpers::db;
+use runique::impl_objects;
+use runique::macros::bdd::objects::{BulkConflict, ObjectsError};
+use sea_orm::{ActiveValue::Set, DatabaseConnection, EntityTrait};
+
+mod article {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "bulk_article")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub slug: String,
+        pub title: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    super::impl_objects!(Entity);
+}
+
+mod note {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "bulk_note")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub title: String,
+        pub deleted_at: Option<DateTime>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    super::impl_objects!(Entity, soft_delete = Column::DeletedAt);
+}
+
+async fn base() -> DatabaseConnection {
+    db::fresh_db_with_schema(
+        "CREATE TABLE bulk_article (id INTEGER PRIMARY KEY AUTOINCREMENT, \
+         slug TEXT NOT NULL UNIQUE, title TEXT NOT NULL)",
+    )
+    .await
+}
+
+fn article(slug: &str, title: &str) -> article::ActiveModel {
+    article::ActiveModel {
+        slug: Set(slug.to_string()),
+        title: Set(title.to_string()),
+        ..Default::default()
+    }
+}
+
+fn articles(n: usize) -> Vec<article::ActiveModel> {
+    (0..n)
+        .map(|i| article(&format!("a-{i}"), "titre"))
+        .collect()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Insertion
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_bulk_create_vide_retourne_zero() {
+    let conn = base().await;
+    let n = article::Entity::objects
+        .bulk_create(&conn, Vec::new(), 100)
+        .await
+        .unwrap();
+    assert_eq!(n, 0);
+    db::assert_count(&conn, "bulk_article", 0).await;
+}
+
+#[tokio::test]
+async fn test_bulk_create_par_lots() {
+    let conn = base().await;
+    let n = article::Entity::objects
+        .bulk_create(&conn, articles(250), 100)
+        .await
+        .unwrap();
+    assert_eq!(n, 250);
+    db::assert_count(&conn, "bulk_article", 250).await;
+
+    let last = article::Entity::find_by_id(250).one(&conn).await.unwrap();
+    assert_eq!(last.unwrap().slug, "a-249");
+}
+
+#[tokio::test]
+async fn test_bulk_create_batch_size_zero_et_limite_parametres() {
+    // 3 colonnes × 20 000 lignes = 60 000 paramètres : au-delà de la limite
+    // SQLite (32 766), les lots doivent être réduits.
+    let conn = base().await;
+    let n = article::Entity::objects
+        .bulk_create(&conn, articles(20_000), 0)
+        .await
+        .unwrap();
+    assert_eq!(n, 20_000);
+
+    let n = article::Entity::objects
+        .bulk_create(
+            &conn,
+            (0..20_000)
+                .map(|i| article(&format!("b-{i}"), "t"))
+                .collect(),
+            50_000,
+        )
+        .await
+        .unwrap();
+    assert_eq!(n, 20_000);
+    db::assert_count(&conn, "bulk_article", 40_000).await;
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Conflits
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_bulk_create_conflit_annule_la_transaction() {
+    let conn = base().await;
+    let mut models = articles(10);
+    models.push(article("a-3", "doublon"));
+
+    let err = article::Entity::objects
+        .bulk_create(&conn, models, 4)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ObjectsError::UniqueViolation(_)), "{err:?}");
+    // Les lots déjà insérés sont annulés avec le reste.
+    db::assert_count(&conn, "bulk_article", 0).await;
+}
+
+#[tokio::test]
+async fn test_bulk_create_ignore_les_conflits() {
+    let conn = base().await;
+    article::Entity::objects
+        .bulk_create(&conn, articles(3), 10)
+        .await
+        .unwrap();
+
+    let models = vec![
+        article("a-1", "ignoré"),
+        article("nouveau", "ajouté"),
+        article("a-2", "ignoré"),
+    ];
+    let n = article::Entity::objects
+        .bulk_create_with(
+            &conn,
+            models,
+            10,
+            BulkConflict::Ignore {
+                target: vec![article::Column::Slug],
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(n, 1);
+    db::assert_count(&conn, "bulk_article", 4).await;
+
+    // Lot entièrement en conflit : 0, pas d'erreur.
+    let n = article::Entity::objects
+        .bulk_create_with(
+            &conn,
+            vec![article("a-0", "ignoré")],
+            10,
+            BulkConflict::Ignore {
+                target: vec![article::Column::Slug],
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(n, 0);
+}
+
+#[tokio::test]
+async fn test_bulk_create_update_en_conflit() {
+    let conn = base().await;
+    article::Entity::objects
+        .bulk_create(&conn, articles(2), 10)
+        .await
+        .unwrap();
+
+    article::Entity::objects
+        .bulk_create_with(
+            &conn,
+            vec![article("a-0", "modifié"), article("a-9", "nouveau")],
+            10,
+            BulkConflict::Update {
+                target: vec![article::Column::Slug],
+                columns: vec![article::Column::Title],
+            },
+        )
+        .await
+        .unwrap();
+    db::assert_count(&conn, "bulk_article", 3).await;
+
+    let rows = article::Entity::find().all(&conn).await.unwrap();
+    let titre = |slug: &str| {
+        rows.iter()
+            .find(|r| r.slug == slug)
+            .map(|r| r.title.clone())
+            .unwrap()
+    };
+    assert_eq!(titre("a-0"), "modifié");
+    assert_eq!(titre("a-1"), "titre");
+    assert_eq!(titre("a-9"), "nouveau");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Soft delete
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_bulk_create_soft_objects() {
+    let conn = db::fresh_db_with_schema(
+        "CREATE TABLE bulk_note (id INTEGER PRIMARY KEY AUTOINCREMENT, \
+         title TEXT NOT NULL, deleted_at TIMESTAMP NULL)",
+    )
+    .await;
+    let models = (0..5)
+        .map(|i| note::ActiveModel {
+            title: Set(format!("n-{i}")),
+            ..Default::default()
+        })
+        .collect();
+    let n = note::Entity::objects
+        .bulk_create(&conn, models, 2)
+        .await
+        .unwrap();
+    assert_eq!(n, 5);
+    assert_eq!(note::Entity::objects.count(&conn).await.unwrap(), 5);
+}
@@ -0,0 +1,139 @@
+//! Tests — db/fixture.rs (load_fixture)
+//!
+//! Couverture :
+//!   - insertion de toutes les lignes + nombre retourné
+//!   - champs omis → NotSet (défaut SQL, auto-increment)
+//!   - rollback complet si une ligne échoue
+//!   - fichier absent / JSON qui n'est pas un tableau
+
+use crate::helpers::db;
+use crate::utils::clean_tpm_test::TestTempDir;
+use runique::db::{FixtureError, load_fixture};
+use std::fs;
+
+mod article {
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+    #[sea_orm(table_name = "article")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub title: String,
+        pub views: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+const SCHEMA: &str = "CREATE TABLE article (
+    id    INTEGER PRIMARY KEY AUTOINCREMENT,
+    title TEXT    NOT NULL UNIQUE,
+    views INTEGER NOT NULL DEFAULT 0
+)";
+
+fn write_fixture(dir: &TestTempDir, json: &str) -> std::path::PathBuf {
+    let path = dir.join("articles.json");
+    fs::write(&path, json).unwrap();
+    path
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Chargement nominal
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_fixture_insere_toutes_les_lignes() {
+    let dir = TestTempDir::new("runique_test_fixture", "ok");
+    let path = write_fixture(
+        &dir,
+        r#"[
+            {"id": 1, "title": "Premier", "views": 3},
+            {"id": 2, "title": "Second", "views": 0}
+        ]"#,
+    );
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+
+    let n = load_fixture::<article::Entity>(&conn, &path).await.unwrap();
+
+    assert_eq!(n, 2);
+    db::assert_count(&conn, "article", 2).await;
+}
+
+#[tokio::test]
+async fn test_fixture_champs_omis_restent_not_set() {
+    use sea_orm::EntityTrait;
+
+    let dir = TestTempDir::new("runique_test_fixture", "omis");
+    let path = write_fixture(&dir, r#"[{"title": "Sans id ni vues"}]"#);
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+
+    load_fixture::<article::Entity>(&conn, &path).await.unwrap();
+
+    let rows = article::Entity::find().all(&conn).await.unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].id, 1);
+    assert_eq!(rows[0].views, 0);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Erreurs
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_fixture_rollback_si_une_ligne_echoue() {
+    let dir = TestTempDir::new("runique_test_fixture", "rollback");
+    // Doublon sur la contrainte UNIQUE de title → la 3e ligne échoue
+    let path = write_fixture(
+        &dir,
+        r#"[
+            {"title": "A"},
+            {"title": "B"},
+            {"title": "A"}
+        ]"#,
+    );
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+
+    let err = load_fixture::<article::Entity>(&conn, &path)
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("row 2"), "{}", err);
+    // Le DbErr d'origine est conservé, pas aplati en chaîne
+    let FixtureError::Row { index, source, .. } = &err else {
+        panic!("FixtureError::Row attendu : {:?}", err);
+    };
+    assert_eq!(*index, 2);
+    assert!(matches!(
+        source.sql_err(),
+        Some(sea_orm::SqlErr::UniqueConstraintViolation(_))
+    ));
+    db::assert_count(&conn, "article", 0).await;
+}
+
+#[tokio::test]
+async fn test_fixture_fichier_absent() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    let path = std::env::temp_dir().join("runique_fixture_inexistante.json");
+    let err = load_fixture::<article::Entity>(&conn, &path)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, FixtureError::Io { .. }), "{:?}", err);
+}
+
+#[tokio::test]
+async fn test_fixture_json_non_tableau() {
+    let dir = TestTempDir::new("runique_test_fixture", "objet");
+    let path = write_fixture(&dir, r#"{"title": "Seul"}"#);
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+
+    let err = load_fixture::<article::Entity>(&conn, &path)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("JSON array"), "{}", err);
+    assert!(matches!(err, FixtureError::Json { .. }));
+}
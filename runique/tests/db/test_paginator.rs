@@ -0,0 +1,160 @@
+//! Tests — db/paginator.rs (Paginator, Page)
+//!
+//! Couverture :
+//!   - découpage LIMIT/OFFSET + métadonnées (total_pages, has_next, has_prev)
+//!   - dernière page incomplète
+//!   - aucun résultat / page hors limites → items vides, pas d'erreur
+//!   - sérialisation pour un contexte Tera
+
+use crate::helpers::db;
+use runique::db::Paginator;
+use sea_orm::{EntityTrait, QueryOrder};
+
+mod note {
+    use sea_orm::entity::prelude::*;
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize)]
+    #[sea_orm(table_name = "note")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub body: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+const SCHEMA: &str = "CREATE TABLE note (id INTEGER PRIMARY KEY AUTOINCREMENT, body TEXT NOT NULL)";
+
+async fn db_with_notes(n: usize) -> sea_orm::DatabaseConnection {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    for i in 1..=n {
+        db::exec(
+            &conn,
+            &format!("INSERT INTO note (body) VALUES ('note {i}')"),
+        )
+        .await;
+    }
+    conn
+}
+
+fn paginator(per_page: u64) -> Paginator<note::Entity> {
+    Paginator::new(
+        note::Entity::find().order_by_asc(note::Column::Id),
+        per_page,
+    )
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Pages et métadonnées
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_paginator_premiere_page() {
+    let conn = db_with_notes(7).await;
+    let page = paginator(3).page(&conn, 1).await.unwrap();
+
+    assert_eq!(page.items.len(), 3);
+    assert_eq!(page.items[0].id, 1);
+    assert_eq!(page.total_items, 7);
+    assert_eq!(page.total_pages, 3);
+    assert!(page.has_next);
+    assert!(!page.has_prev);
+    assert_eq!(page.next_page(), Some(2));
+    assert_eq!(page.prev_page(), None);
+}
+
+#[tokio::test]
+async fn test_paginator_derniere_page_incomplete() {
+    let conn = db_with_notes(7).await;
+    let page = paginator(3).page(&conn, 3).await.unwrap();
+
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].id, 7);
+    assert!(!page.has_next);
+    assert!(page.has_prev);
+}
+
+#[test]
+fn test_paginator_per_page_zero_ramene_a_un() {
+    assert_eq!(paginator(0).per_page(), 1);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Cas limites — jamais d'erreur
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_paginator_aucun_resultat() {
+    let conn = db_with_notes(0).await;
+    let page = paginator(10).page(&conn, 1).await.unwrap();
+
+    assert!(page.items.is_empty());
+    assert_eq!(page.total_items, 0);
+    assert_eq!(page.total_pages, 0);
+    assert!(!page.has_next);
+    assert!(!page.has_prev);
+}
+
+#[tokio::test]
+async fn test_paginator_page_hors_limites() {
+    let conn = db_with_notes(4).await;
+
+    let after = paginator(2).page(&conn, 9).await.unwrap();
+    assert!(after.items.is_empty());
+    assert_eq!(after.total_pages, 2);
+    assert!(!after.has_next);
+    // Le lien « précédent » ramène sur la dernière page existante
+    assert_eq!(after.prev_page(), Some(2));
+
+    let zero = paginator(2).page(&conn, 0).await.unwrap();
+    assert!(zero.items.is_empty());
+    assert!(zero.has_next);
+    assert!(!zero.has_prev);
+}
+
+#[tokio::test]
+async fn test_paginator_dans_une_transaction() {
+    use sea_orm::TransactionTrait;
+    let conn = db_with_notes(3).await;
+    let txn = conn.begin().await.unwrap();
+    let page = paginator(2).page(&txn, 2).await.unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.prev_page(), Some(1));
+    txn.commit().await.unwrap();
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Intégration Tera / RuniqueQueryBuilder
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_paginator_serialisable_pour_tera() {
+    let conn = db_with_notes(2).await;
+    let page = paginator(1).page(&conn, 2).await.unwrap();
+
+    let ctx: tera::Context = runique::context! { "page" => &page }.into();
+    let html = tera::Tera::one_off(
+        "{% for n in page.items %}{{ n.body }}{% endfor %}|{{ page.current }}/{{ page.total_pages }}",
+        &ctx,
+        false,
+    )
+    .unwrap();
+    assert_eq!(html, "note 2|2/2");
+}
+
+#[tokio::test]
+async fn test_query_builder_paginate() {
+    let conn = db_with_notes(5).await;
+    let page = runique::macros::bdd::query::RuniqueQueryBuilder::new(note::Entity::find())
+        .paginate(2)
+        .page(&conn, 2)
+        .await
+        .unwrap();
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.total_pages, 3);
+}
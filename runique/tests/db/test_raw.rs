@@ -0,0 +1,261 @@
+//! Tests — db/raw.rs (raw_query, raw_execute, variantes nommées, raw_statement)
+//!
+//! Couverture :
+//!   - lignes typées via FromQueryResult, paramètres liés
+//!   - une valeur malveillante reste une valeur (pas d'injection)
+//!   - `?` / `:nom` ignorés dans les chaînes, identifiants et commentaires
+//!   - réécriture `$1, $2…` pour PostgreSQL, `?` pour MySQL / SQLite, cast `::`
+//!   - erreurs : nombre de paramètres, nom manquant ou inutilisé, styles mélangés
+
+use crate::helpers::db;
+use runique::db::{
+    raw_execute, raw_execute_named, raw_query, raw_query_named, raw_statement, raw_statement_named,
+};
+use sea_orm::{DatabaseConnection, DbBackend, DbErr, FromQueryResult, Value};
+
+#[derive(Debug, PartialEq, FromQueryResult)]
+struct Membre {
+    id: i32,
+    nom: String,
+}
+
+#[derive(Debug, PartialEq, FromQueryResult)]
+struct Total {
+    role: String,
+    nb: i64,
+}
+
+async fn base() -> DatabaseConnection {
+    db::fresh_db_with_schema(
+        "CREATE TABLE membre (id INTEGER PRIMARY KEY, nom TEXT NOT NULL, role TEXT NOT NULL)",
+    )
+    .await
+}
+
+async fn remplir(conn: &DatabaseConnection) {
+    for (id, nom, role) in [
+        (1, "ada", "admin"),
+        (2, "bob", "membre"),
+        (3, "eve", "membre"),
+    ] {
+        raw_execute(
+            conn,
+            "INSERT INTO membre (id, nom, role) VALUES (?, ?, ?)",
+            [id.into(), nom.into(), role.into()],
+        )
+        .await
+        .unwrap();
+    }
+}
+
+fn message(err: DbErr) -> String {
+    match err {
+        DbErr::Custom(msg) => msg,
+        other => panic!("DbErr::Custom attendu, obtenu {other:?}"),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Exécution
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_raw_query_lignes_typees() {
+    let conn = base().await;
+    remplir(&conn).await;
+
+    let membres: Vec<Membre> = raw_query(
+        &conn,
+        "SELECT id, nom FROM membre WHERE role = ? AND id > ? ORDER BY id",
+        ["membre".into(), 1.into()],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        membres,
+        [
+            Membre {
+                id: 2,
+                nom: "bob".into()
+            },
+            Membre {
+                id: 3,
+                nom: "eve".into()
+            },
+        ]
+    );
+
+    let totaux: Vec<Total> = raw_query(
+        &conn,
+        "SELECT role, COUNT(*) AS nb FROM membre GROUP BY role ORDER BY role",
+        [],
+    )
+    .await
+    .unwrap();
+    assert_eq!(totaux.len(), 2);
+    assert_eq!(
+        totaux[1],
+        Total {
+            role: "membre".into(),
+            nb: 2
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_raw_execute_renvoie_les_lignes_touchees() {
+    let conn = base().await;
+    remplir(&conn).await;
+    let n = raw_execute(
+        &conn,
+        "UPDATE membre SET role = ? WHERE role = ?",
+        ["invite".into(), "membre".into()],
+    )
+    .await
+    .unwrap();
+    assert_eq!(n, 2);
+}
+
+#[tokio::test]
+async fn test_valeur_malveillante_reste_une_valeur() {
+    let conn = base().await;
+    remplir(&conn).await;
+
+    let membres: Vec<Membre> = raw_query(
+        &conn,
+        "SELECT id, nom FROM membre WHERE nom = ?",
+        ["x' OR '1'='1".into()],
+    )
+    .await
+    .unwrap();
+    assert!(membres.is_empty());
+
+    let n = raw_execute(
+        &conn,
+        "DELETE FROM membre WHERE nom = ?",
+        ["ada'; DROP TABLE membre; --".into()],
+    )
+    .await
+    .unwrap();
+    assert_eq!(n, 0);
+    db::assert_count(&conn, "membre", 3).await;
+}
+
+#[tokio::test]
+async fn test_variantes_nommees() {
+    let conn = base().await;
+    remplir(&conn).await;
+
+    let membres: Vec<Membre> = raw_query_named(
+        &conn,
+        "SELECT id, nom FROM membre WHERE nom = :nom OR (role = :role AND nom <> :nom) ORDER BY id",
+        [("nom", "ada".into()), ("role", "membre".into())],
+    )
+    .await
+    .unwrap();
+    assert_eq!(membres.len(), 3);
+
+    let n = raw_execute_named(
+        &conn,
+        "DELETE FROM membre WHERE id = :id",
+        [("id", 2.into())],
+    )
+    .await
+    .unwrap();
+    assert_eq!(n, 1);
+}
+
+#[tokio::test]
+async fn test_points_d_interrogation_dans_une_chaine() {
+    let conn = base().await;
+    raw_execute(
+        &conn,
+        "INSERT INTO membre (id, nom, role) VALUES (?, 'qui?', ':pas_un_nom') -- ça aussi ?",
+        [9.into()],
+    )
+    .await
+    .unwrap();
+    let membres: Vec<Membre> = raw_query(&conn, "SELECT id, nom FROM membre /* ? */", [])
+        .await
+        .unwrap();
+    assert_eq!(
+        membres,
+        [Membre {
+            id: 9,
+            nom: "qui?".into()
+        }]
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// raw_statement — réécriture par backend
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_reecriture_postgres() {
+    let stmt = raw_statement(
+        DbBackend::Postgres,
+        "SELECT * FROM t WHERE a = ? AND b = 'é?' AND c::text = ?",
+        [1.into(), "x".into()],
+    )
+    .unwrap();
+    assert_eq!(
+        stmt.sql,
+        "SELECT * FROM t WHERE a = $1 AND b = 'é?' AND c::text = $2"
+    );
+    assert_eq!(stmt.values.unwrap().0.len(), 2);
+
+    let stmt = raw_statement_named(
+        DbBackend::Postgres,
+        "SELECT * FROM t WHERE a = :a OR b = :a OR \"col:x\" = :b",
+        [("a", 1.into()), ("b", 2.into())],
+    )
+    .unwrap();
+    assert_eq!(
+        stmt.sql,
+        "SELECT * FROM t WHERE a = $1 OR b = $2 OR \"col:x\" = $3"
+    );
+    let valeurs = stmt.values.unwrap().0;
+    assert_eq!(valeurs, [Value::from(1), Value::from(1), Value::from(2)]);
+}
+
+#[test]
+fn test_reecriture_mysql_et_sqlite() {
+    for backend in [DbBackend::MySql, DbBackend::Sqlite] {
+        let stmt = raw_statement_named(
+            backend,
+            "SELECT * FROM `t?` WHERE a = :a AND b = ?x",
+            [("a", 1.into())],
+        );
+        // `?` hors chaîne dans une requête nommée : refusé
+        assert!(message(stmt.unwrap_err()).contains("named query"));
+
+        let stmt = raw_statement_named(
+            backend,
+            "SELECT * FROM `t?` WHERE a = :a",
+            [("a", 1.into())],
+        )
+        .unwrap();
+        assert_eq!(stmt.sql, "SELECT * FROM `t?` WHERE a = ?");
+    }
+}
+
+#[test]
+fn test_erreurs_de_parametres() {
+    let err = raw_statement(DbBackend::Sqlite, "SELECT ? + ?", [1.into()]).unwrap_err();
+    assert!(message(err).contains("2 placeholder(s) but 1 value(s)"));
+
+    let err = raw_statement(DbBackend::Sqlite, "SELECT :a", [1.into()]).unwrap_err();
+    assert!(message(err).contains("_named"));
+
+    let err = raw_statement_named(DbBackend::Sqlite, "SELECT :a", [("b", 1.into())]).unwrap_err();
+    assert!(message(err).contains("no value given for `:a`"));
+
+    let err = raw_statement_named(
+        DbBackend::Sqlite,
+        "SELECT :a",
+        [("a", 1.into()), ("b", 2.into())],
+    )
+    .unwrap_err();
+    assert!(message(err).contains("`:b` is not used"));
+}
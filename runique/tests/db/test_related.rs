@@ -0,0 +1,284 @@
+//! Tests — db/related.rs (select_related, prefetch_related)
+//!
+//! Couverture :
+//!   - to-one : chaque parent avec son auteur, None si clé NULL
+//!   - to-many : chaque parent avec ses commentaires, liste vide sinon
+//!   - filtre soft-delete du manager lié respecté
+//!   - ordre des parents conservé, liste vide sans requête
+//!   - Page::select_related / prefetch_related garde les métadonnées
+
+use crate::helpers::db;
+use runique::db::{Paginator, prefetch_related, select_related};
+use runique::impl_objects;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryOrder};
+
+mod auteur {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "rel_auteur")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub nom: String,
+        pub deleted_at: Option<DateTime>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(has_many = "super::article::Entity")]
+        Article,
+    }
+
+    impl Related<super::article::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Article.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod article {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "rel_article")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub titre: String,
+        pub auteur_id: Option<i32>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(
+            belongs_to = "super::auteur::Entity",
+            from = "Column::AuteurId",
+            to = "super::auteur::Column::Id"
+        )]
+        Auteur,
+        #[sea_orm(has_many = "super::commentaire::Entity")]
+        Commentaire,
+    }
+
+    impl Related<super::auteur::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Auteur.def()
+        }
+    }
+
+    impl Related<super::commentaire::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Commentaire.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod commentaire {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "rel_commentaire")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub article_id: i32,
+        pub texte: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(
+            belongs_to = "super::article::Entity",
+            from = "Column::ArticleId",
+            to = "super::article::Column::Id"
+        )]
+        Article,
+    }
+
+    impl Related<super::article::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Article.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+impl_objects!(auteur::Entity, soft_delete = auteur::Column::DeletedAt);
+impl_objects!(article::Entity);
+impl_objects!(commentaire::Entity);
+
+async fn base() -> DatabaseConnection {
+    let conn = db::fresh_db().await;
+    for sql in [
+        "CREATE TABLE rel_auteur (id INTEGER PRIMARY KEY, nom TEXT NOT NULL, deleted_at TEXT NULL)",
+        "CREATE TABLE rel_article (id INTEGER PRIMARY KEY, titre TEXT NOT NULL, auteur_id INTEGER NULL)",
+        "CREATE TABLE rel_commentaire (id INTEGER PRIMARY KEY, article_id INTEGER NOT NULL, texte TEXT NOT NULL)",
+        "INSERT INTO rel_auteur (id, nom) VALUES (1, 'Ada'), (2, 'Linus')",
+        "INSERT INTO rel_auteur (id, nom, deleted_at) VALUES (3, 'Effacé', '2026-01-01 00:00:00')",
+        "INSERT INTO rel_article (id, titre, auteur_id) VALUES (1, 'a1', 2), (2, 'a2', 1), (3, 'a3', NULL), (4, 'a4', 3), (5, 'a5', 2)",
+        "INSERT INTO rel_commentaire (id, article_id, texte) VALUES (1, 1, 'c1'), (2, 2, 'c2'), (3, 1, 'c3')",
+    ] {
+        db::exec(&conn, sql).await;
+    }
+    conn
+}
+
+fn titres_et_auteurs(
+    rows: &[(article::Model, Option<auteur::Model>)],
+) -> Vec<(&str, Option<&str>)> {
+    rows.iter()
+        .map(|(a, au)| (a.titre.as_str(), au.as_ref().map(|au| au.nom.as_str())))
+        .collect()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// select_related (to-one)
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_select_related_associe_chaque_parent() {
+    let conn = base().await;
+    let rows = article::Entity::objects
+        .all()
+        .desc(article::Column::Id)
+        .select_related(&conn, auteur::Entity::find())
+        .await
+        .unwrap();
+    assert_eq!(
+        titres_et_auteurs(&rows),
+        [
+            ("a5", Some("Linus")),
+            ("a4", Some("Effacé")),
+            ("a3", None),
+            ("a2", Some("Ada")),
+            ("a1", Some("Linus")),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_select_related_respecte_le_soft_delete() {
+    let conn = base().await;
+    let rows = article::Entity::objects
+        .filter(article::Column::Id.is_in([2, 4]))
+        .select_related(&conn, auteur::Entity::objects.all())
+        .await
+        .unwrap();
+    assert_eq!(
+        titres_et_auteurs(&rows),
+        [("a2", Some("Ada")), ("a4", None)]
+    );
+}
+
+#[tokio::test]
+async fn test_select_related_sans_parent() {
+    let conn = base().await;
+    let rows = select_related(&conn, Vec::<article::Model>::new(), auteur::Entity::find())
+        .await
+        .unwrap();
+    assert!(rows.is_empty());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// prefetch_related (to-many)
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_prefetch_related_regroupe_les_enfants() {
+    let conn = base().await;
+    let articles = article::Entity::find()
+        .order_by_asc(article::Column::Id)
+        .all(&conn)
+        .await
+        .unwrap();
+    let rows = prefetch_related(
+        &conn,
+        articles,
+        commentaire::Entity::objects
+            .all()
+            .desc(commentaire::Column::Id),
+    )
+    .await
+    .unwrap();
+
+    let textes: Vec<(&str, Vec<&str>)> = rows
+        .iter()
+        .map(|(a, cs)| {
+            (
+                a.titre.as_str(),
+                cs.iter().map(|c| c.texte.as_str()).collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        textes,
+        [
+            ("a1", vec!["c3", "c1"]),
+            ("a2", vec!["c2"]),
+            ("a3", vec![]),
+            ("a4", vec![]),
+            ("a5", vec![]),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_prefetch_related_depuis_le_manager_soft_delete() {
+    let conn = base().await;
+    let rows = auteur::Entity::objects
+        .prefetch_related(&conn, article::Entity::find())
+        .await
+        .unwrap();
+    // L'auteur supprimé n'apparaît pas côté parent
+    let noms: Vec<(&str, usize)> = rows
+        .iter()
+        .map(|(au, arts)| (au.nom.as_str(), arts.len()))
+        .collect();
+    assert_eq!(noms, [("Ada", 1), ("Linus", 2)]);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Pagination
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_page_select_related_garde_les_metadonnees() {
+    let conn = base().await;
+    let page = Paginator::new(article::Entity::find().order_by_asc(article::Column::Id), 2)
+        .page(&conn, 2)
+        .await
+        .unwrap()
+        .select_related(&conn, auteur::Entity::objects.all())
+        .await
+        .unwrap();
+
+    assert_eq!(page.current, 2);
+    assert_eq!(page.total_items, 5);
+    assert_eq!(page.total_pages, 3);
+    assert!(page.has_next && page.has_prev);
+    assert_eq!(titres_et_auteurs(&page.items), [("a3", None), ("a4", None)]);
+}
+
+#[tokio::test]
+async fn test_page_prefetch_related() {
+    let conn = base().await;
+    let page = article::Entity::objects
+        .all()
+        .asc(article::Column::Id)
+        .paginate(2)
+        .page(&conn, 1)
+        .await
+        .unwrap()
+        .prefetch_related(&conn, commentaire::Entity::find())
+        .await
+        .unwrap();
+    let compte: Vec<usize> = page.items.iter().map(|(_, cs)| cs.len()).collect();
+    assert_eq!(compte, [2, 1]);
+    assert_eq!(page.total_pages, 3);
+}
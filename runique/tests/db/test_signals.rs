@@ -0,0 +1,298 @@
+//! Tests — signals/ : signaux du cycle de vie des modèles
+//!
+//! Couverture :
+//!   - pre_save / post_save sur insert et update (created, données)
+//!   - pre_delete / post_delete
+//!   - handlers exécutés dans l'ordre d'enregistrement
+//!   - erreur / panic journalisés sans interrompre l'opération
+//!   - handler critique : écriture refusée, transaction annulée
+//!   - `objects.get_or_create` émet aussi les signaux
+//!
+//! Le registre est global au binaire de test : chaque test a sa propre table.
+
+use crate::helpers::db;
+use runique::impl_objects;
+use runique::signals::{self, Signal, SignalEvent};
+use runique::tasks::TaskError;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, DatabaseConnection, DbErr, ModelTrait, TransactionTrait,
+};
+use std::sync::{Arc, Mutex};
+
+macro_rules! entite {
+    ($module:ident, $table:literal) => {
+        mod $module {
+            use sea_orm::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = $table)]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub title: String,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            runique::impl_signals!(ActiveModel);
+            super::impl_objects!(Entity);
+
+            pub const SCHEMA: &str = concat!(
+                "CREATE TABLE ",
+                $table,
+                " (id INTEGER PRIMARY KEY AUTOINCREMENT, title TEXT NOT NULL)"
+            );
+        }
+    };
+}
+
+entite!(ordre, "signal_ordre");
+entite!(suppression, "signal_suppression");
+entite!(erreur, "signal_erreur");
+entite!(critique_pre, "signal_critique_pre");
+entite!(critique_txn, "signal_critique_txn");
+entite!(manager, "signal_manager");
+
+type Journal = Arc<Mutex<Vec<String>>>;
+
+async fn base(schema: &str) -> DatabaseConnection {
+    let conn = db::fresh_db_with_schema(schema).await;
+    signals::set_db(Arc::new(conn.clone()));
+    conn
+}
+
+/// Handler qui note `<étiquette> <signal> created=<bool> title=<title>`.
+fn noter(
+    journal: &Journal,
+    label: &'static str,
+) -> impl Fn(runique::utils::aliases::ADb, SignalEvent) -> std::future::Ready<Result<(), TaskError>>
++ Send
++ Sync
++ 'static {
+    let journal = journal.clone();
+    move |_db, event| {
+        let title: Option<String> = event.get("title");
+        journal.lock().unwrap().push(format!(
+            "{label} {} created={} title={}",
+            event.signal,
+            event.created,
+            title.unwrap_or_default()
+        ));
+        std::future::ready(Ok(()))
+    }
+}
+
+fn lire(journal: &Journal) -> Vec<String> {
+    journal.lock().unwrap().clone()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Sauvegarde
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_save_emet_pre_et_post_dans_l_ordre() {
+    let conn = base(ordre::SCHEMA).await;
+    let journal = Journal::default();
+    signals::connect(Signal::PreSave, "signal_ordre", noter(&journal, "a"));
+    signals::connect(Signal::PostSave, "signal_ordre", noter(&journal, "b"));
+    signals::connect(Signal::PostSave, "signal_ordre", noter(&journal, "c"));
+
+    let row = ordre::ActiveModel {
+        title: Set("Bonjour".into()),
+        ..Default::default()
+    }
+    .insert(&conn)
+    .await
+    .unwrap();
+
+    let mut active: ordre::ActiveModel = row.into();
+    active.title = Set("Modifié".into());
+    active.update(&conn).await.unwrap();
+
+    assert_eq!(
+        lire(&journal),
+        [
+            "a pre_save created=true title=Bonjour",
+            "b post_save created=true title=Bonjour",
+            "c post_save created=true title=Bonjour",
+            "a pre_save created=false title=Modifié",
+            "b post_save created=false title=Modifié",
+            "c post_save created=false title=Modifié",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_donnees_pre_save_et_post_save() {
+    let conn = base(manager::SCHEMA).await;
+    let events: Arc<Mutex<Vec<SignalEvent>>> = Arc::default();
+    for signal in [Signal::PreSave, Signal::PostSave] {
+        let events = events.clone();
+        signals::connect(signal, "signal_manager", move |_db, event| {
+            events.lock().unwrap().push(event);
+            async { Ok(()) }
+        });
+    }
+
+    // Le manager `objects` passe par l'ActiveModel : mêmes signaux
+    let (row, created) = manager::Entity::objects
+        .get_or_create(
+            &conn,
+            [(manager::Column::Title, "Nouveau".into())],
+            std::iter::empty(),
+        )
+        .await
+        .unwrap();
+    assert!(created);
+
+    let events = events.lock().unwrap().clone();
+    assert_eq!(events.len(), 2);
+    // pre_save : l'id n'est pas encore fixé (NotSet) → absent
+    assert_eq!(events[0].model, "signal_manager");
+    assert!(!events[0].data.contains_key("id"));
+    // post_save : la ligne telle qu'enregistrée
+    assert_eq!(events[1].get::<i32>("id"), Some(row.id));
+    assert_eq!(events[1].get::<String>("title").as_deref(), Some("Nouveau"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Suppression
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_delete_emet_pre_et_post_delete() {
+    let conn = base(suppression::SCHEMA).await;
+    let journal = Journal::default();
+    signals::connect(
+        Signal::PreDelete,
+        "signal_suppression",
+        noter(&journal, "x"),
+    );
+    signals::connect(
+        Signal::PostDelete,
+        "signal_suppression",
+        noter(&journal, "x"),
+    );
+
+    let row = suppression::ActiveModel {
+        title: Set("Éphémère".into()),
+        ..Default::default()
+    }
+    .insert(&conn)
+    .await
+    .unwrap();
+    row.delete(&conn).await.unwrap();
+
+    assert_eq!(
+        lire(&journal),
+        [
+            "x pre_delete created=false title=Éphémère",
+            "x post_delete created=false title=Éphémère",
+        ]
+    );
+    assert_eq!(
+        signals::disconnect(Signal::PreDelete, "signal_suppression"),
+        1
+    );
+    assert_eq!(
+        signals::disconnect(Signal::PreDelete, "signal_suppression"),
+        0
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Erreurs
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_erreur_non_critique_journalisee_sans_interrompre() {
+    let conn = base(erreur::SCHEMA).await;
+    let journal = Journal::default();
+    signals::connect(Signal::PreSave, "signal_erreur", |_db, _event| async {
+        Err(TaskError::new("smtp indisponible"))
+    });
+    signals::connect(Signal::PreSave, "signal_erreur", |_db, _event| async {
+        panic!("handler en panique")
+    });
+    signals::connect(Signal::PreSave, "signal_erreur", noter(&journal, "suivant"));
+
+    erreur::ActiveModel {
+        title: Set("Conservé".into()),
+        ..Default::default()
+    }
+    .insert(&conn)
+    .await
+    .unwrap();
+
+    db::assert_count(&conn, "signal_erreur", 1).await;
+    assert_eq!(
+        lire(&journal),
+        ["suivant pre_save created=true title=Conservé"]
+    );
+}
+
+#[tokio::test]
+async fn test_critique_pre_save_refuse_l_ecriture() {
+    let conn = base(critique_pre::SCHEMA).await;
+    let journal = Journal::default();
+    signals::connect_critical(
+        Signal::PreSave,
+        "signal_critique_pre",
+        |_db, event| async move {
+            match event.get::<String>("title") {
+                Some(title) if title.is_empty() => Err(TaskError::new("titre vide")),
+                _ => Ok(()),
+            }
+        },
+    );
+    signals::connect(
+        Signal::PreSave,
+        "signal_critique_pre",
+        noter(&journal, "après"),
+    );
+
+    let err = critique_pre::ActiveModel {
+        title: Set(String::new()),
+        ..Default::default()
+    }
+    .insert(&conn)
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(&err, DbErr::Custom(m) if m.contains("titre vide")),
+        "{err}"
+    );
+    db::assert_count(&conn, "signal_critique_pre", 0).await;
+    // Les handlers suivants ne sont pas exécutés
+    assert!(lire(&journal).is_empty());
+}
+
+#[tokio::test]
+async fn test_critique_post_save_annule_la_transaction() {
+    let conn = base(critique_txn::SCHEMA).await;
+    signals::connect_critical(
+        Signal::PostSave,
+        "signal_critique_txn",
+        |_db, _event| async { Err(TaskError::new("quota atteint")) },
+    );
+
+    let result = conn
+        .transaction::<_, (), DbErr>(|txn| {
+            Box::pin(async move {
+                critique_txn::ActiveModel {
+                    title: Set("Annulé".into()),
+                    ..Default::default()
+                }
+                .insert(txn)
+                .await?;
+                Ok(())
+            })
+        })
+        .await;
+
+    assert!(result.is_err());
+    db::assert_count(&conn, "signal_critique_txn", 0).await;
+}
@@ -0,0 +1,240 @@
+//! Tests — db/timestamps.rs (`auto_now` / `auto_now_update` via `impl_signals!`)
+//!
+//! Couverture :
+//!   - insert : `auto_now` et `auto_now_update` renseignés
+//!   - update : `auto_now` conservé, `auto_now_update` rafraîchi
+//!   - valeur explicitement `Set` conservée (import, opt-out sur update)
+//!   - colonnes avec fuseau (`DateTimeWithTimeZone`, `DateTimeUtc`) et `Date`
+//!   - colonne non temporelle : erreur de type
+//!   - entité générée par `model!` (`[auto_now]` / `[auto_now_update]`)
+
+use crate::helpers::db;
+use chrono::{NaiveDate, Utc};
+use sea_orm::{ActiveModelTrait, ActiveValue::Set, DbErr, IntoActiveModel};
+
+mod horodate {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "ts_horodate")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub title: String,
+        pub created_at: Option<DateTime>,
+        pub updated_at: Option<DateTime>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    runique::impl_signals!(
+        ActiveModel,
+        auto_now = [Column::CreatedAt],
+        auto_now_update = [Column::UpdatedAt],
+    );
+
+    pub const SCHEMA: &str = "CREATE TABLE ts_horodate (id INTEGER PRIMARY KEY AUTOINCREMENT, \
+         title TEXT NOT NULL, created_at TIMESTAMP NULL, updated_at TIMESTAMP NULL)";
+}
+
+mod fuseau {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "ts_fuseau")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub created_at: DateTimeWithTimeZone,
+        pub updated_at: DateTimeUtc,
+        pub day: Date,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    runique::impl_signals!(
+        ActiveModel,
+        auto_now = [Column::CreatedAt, Column::Day],
+        auto_now_update = [Column::UpdatedAt],
+    );
+
+    pub const SCHEMA: &str = "CREATE TABLE ts_fuseau (id INTEGER PRIMARY KEY AUTOINCREMENT, \
+         created_at TIMESTAMPTZ NOT NULL, updated_at TIMESTAMPTZ NOT NULL, day DATE NOT NULL)";
+}
+
+mod texte {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "ts_texte")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub created_at: Option<String>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    runique::impl_signals!(ActiveModel, auto_now = [Column::CreatedAt]);
+
+    pub const SCHEMA: &str =
+        "CREATE TABLE ts_texte (id INTEGER PRIMARY KEY AUTOINCREMENT, created_at TEXT NULL)";
+}
+
+mod billet {
+    use runique::prelude::*;
+
+    model! {
+        Billet,
+        table: "ts_billets",
+        pk: id => i32,
+        {
+            title:      text     [required],
+            created_at: datetime [auto_now],
+            updated_at: datetime [auto_now_update],
+        }
+    }
+
+    pub const SCHEMA: &str = "CREATE TABLE ts_billets (id INTEGER PRIMARY KEY AUTOINCREMENT, \
+         title TEXT NOT NULL, created_at TIMESTAMP NULL, updated_at TIMESTAMP NULL)";
+}
+
+fn article(title: &str) -> horodate::ActiveModel {
+    horodate::ActiveModel {
+        title: Set(title.to_string()),
+        ..Default::default()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Insert / update
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_insert_renseigne_les_deux_colonnes() {
+    let conn = db::fresh_db_with_schema(horodate::SCHEMA).await;
+    let avant = Utc::now().naive_utc();
+
+    let model = article("a").insert(&conn).await.unwrap();
+
+    let created = model.created_at.expect("created_at renseigné");
+    let updated = model.updated_at.expect("updated_at renseigné");
+    assert!(created >= avant && created <= Utc::now().naive_utc());
+    assert_eq!(created, updated);
+}
+
+#[tokio::test]
+async fn test_update_conserve_created_et_rafraichit_updated() {
+    let conn = db::fresh_db_with_schema(horodate::SCHEMA).await;
+    let model = article("a").insert(&conn).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+
+    let mut active = model.clone().into_active_model();
+    active.title = Set("b".to_string());
+    let modifie = active.update(&conn).await.unwrap();
+
+    assert_eq!(modifie.created_at, model.created_at);
+    assert!(modifie.updated_at > model.updated_at);
+}
+
+#[tokio::test]
+async fn test_save_passe_aussi_par_les_horodatages() {
+    let conn = db::fresh_db_with_schema(horodate::SCHEMA).await;
+    let saved = article("a").save(&conn).await.unwrap();
+    assert!(saved.created_at.unwrap().is_some());
+    assert!(saved.updated_at.unwrap().is_some());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Valeur explicite (opt-out)
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_valeur_explicite_conservee_a_l_insert() {
+    let conn = db::fresh_db_with_schema(horodate::SCHEMA).await;
+    let historique = NaiveDate::from_ymd_opt(2020, 1, 2)
+        .unwrap()
+        .and_hms_opt(3, 4, 5)
+        .unwrap();
+
+    let mut active = article("import");
+    active.created_at = Set(Some(historique));
+    let model = active.insert(&conn).await.unwrap();
+
+    assert_eq!(model.created_at, Some(historique));
+    assert_ne!(model.updated_at, Some(historique));
+}
+
+#[tokio::test]
+async fn test_updated_at_explicite_non_ecrase_a_l_update() {
+    let conn = db::fresh_db_with_schema(horodate::SCHEMA).await;
+    let model = article("a").insert(&conn).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+
+    let mut active = model.clone().into_active_model();
+    active.title = Set("correction".to_string());
+    active.updated_at = Set(model.updated_at);
+    let modifie = active.update(&conn).await.unwrap();
+
+    assert_eq!(modifie.title, "correction");
+    assert_eq!(modifie.updated_at, model.updated_at);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Type des colonnes
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_colonnes_avec_fuseau_et_date() {
+    let conn = db::fresh_db_with_schema(fuseau::SCHEMA).await;
+    let avant = Utc::now();
+
+    let model = fuseau::ActiveModel {
+        ..Default::default()
+    }
+    .insert(&conn)
+    .await
+    .unwrap();
+
+    assert_eq!(model.created_at.offset().local_minus_utc(), 0);
+    assert!(model.created_at >= avant);
+    assert!(model.updated_at >= avant);
+    assert_eq!(model.day, Utc::now().date_naive());
+}
+
+#[tokio::test]
+async fn test_colonne_non_temporelle_refusee() {
+    let conn = db::fresh_db_with_schema(texte::SCHEMA).await;
+    let err = texte::ActiveModel {
+        ..Default::default()
+    }
+    .insert(&conn)
+    .await
+    .unwrap_err();
+    match err {
+        DbErr::Type(msg) => assert!(msg.contains("created_at"), "{msg}"),
+        other => panic!("DbErr::Type attendu, obtenu {other:?}"),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// model!
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_model_macro_renseigne_les_horodatages() {
+    let conn = db::fresh_db_with_schema(billet::SCHEMA).await;
+    let model = billet::ActiveModel {
+        title: Set("premier".to_string()),
+        ..Default::default()
+    }
+    .insert(&conn)
+    .await
+    .unwrap();
+
+    assert!(model.created_at.is_some());
+    assert_eq!(model.created_at, model.updated_at);
+}
@@ -0,0 +1,158 @@
+//! Tests — db/transaction.rs (Transaction, with_transaction)
+//!
+//! Couverture :
+//!   - with_transaction : commit sur Ok, rollback sur Err, force_rollback
+//!   - extracteur : commit sur 2xx, rollback sur 4xx/5xx
+//!   - décision forcée par le handler (rollback en 200, commit en 400)
+//!   - plusieurs extractions dans une requête → même transaction
+
+use crate::helpers::db;
+use axum::{
+    Router,
+    body::Body,
+    extract::Path,
+    http::{Request, StatusCode},
+    routing::get,
+};
+use runique::app::RuniqueApp;
+use runique::config::RuniqueConfig;
+use runique::db::{Transaction, with_transaction};
+use runique::testing::test_db;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr};
+use tower::ServiceExt;
+
+const SCHEMA: &str =
+    "CREATE TABLE ecriture (id INTEGER PRIMARY KEY AUTOINCREMENT, libelle TEXT NOT NULL)";
+
+async fn insere(txn: &Transaction, libelle: &str) {
+    txn.execute_unprepared(&format!(
+        "INSERT INTO ecriture (libelle) VALUES ('{libelle}')"
+    ))
+    .await
+    .unwrap();
+}
+
+// ═══════════════════════════════════════════════════════════════
+// with_transaction
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_with_transaction_commit_sur_ok() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    let n = with_transaction(&conn, |txn| async move {
+        insere(&txn, "a").await;
+        insere(&txn, "b").await;
+        Ok::<_, DbErr>(2)
+    })
+    .await
+    .unwrap();
+    assert_eq!(n, 2);
+    db::assert_count(&conn, "ecriture", 2).await;
+}
+
+#[tokio::test]
+async fn test_with_transaction_rollback_sur_err() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    let res: Result<(), DbErr> = with_transaction(&conn, |txn| async move {
+        insere(&txn, "a").await;
+        Err(DbErr::Custom("échec métier".into()))
+    })
+    .await;
+    assert!(matches!(res, Err(DbErr::Custom(msg)) if msg == "échec métier"));
+    db::assert_count(&conn, "ecriture", 0).await;
+}
+
+#[tokio::test]
+async fn test_with_transaction_force_rollback() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    with_transaction(&conn, |txn| async move {
+        insere(&txn, "a").await;
+        txn.force_rollback();
+        Ok::<_, DbErr>(())
+    })
+    .await
+    .unwrap();
+    db::assert_count(&conn, "ecriture", 0).await;
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Extracteur Transaction
+// ═══════════════════════════════════════════════════════════════
+
+async fn ecrit(Path(cas): Path<String>, txn: Transaction, autre: Transaction) -> StatusCode {
+    insere(&txn, &cas).await;
+    insere(&autre, &cas).await;
+    match cas.as_str() {
+        "erreur" => StatusCode::INTERNAL_SERVER_ERROR,
+        "invalide" => StatusCode::BAD_REQUEST,
+        "essai" => {
+            txn.force_rollback();
+            StatusCode::OK
+        }
+        "journal" => {
+            txn.force_commit();
+            StatusCode::BAD_REQUEST
+        }
+        _ => StatusCode::OK,
+    }
+}
+
+async fn app() -> RuniqueApp {
+    let conn = test_db().await;
+    db::exec(&conn, SCHEMA).await;
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    RuniqueApp::builder(config)
+        .no_statics()
+        .with_database(conn)
+        .routes(Router::new().route("/tx/{cas}", get(ecrit)))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"))
+}
+
+async fn requete(app: &RuniqueApp, cas: &str) -> StatusCode {
+    app.router
+        .clone()
+        .oneshot(
+            Request::get(format!("/tx/{cas}"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .status()
+}
+
+async fn lignes(conn: &DatabaseConnection) -> i64 {
+    db::count(conn, "ecriture").await
+}
+
+#[tokio::test]
+async fn test_extracteur_commit_sur_succes() {
+    let app = app().await;
+    assert_eq!(requete(&app, "ok").await, StatusCode::OK);
+    // Deux extractions, une seule transaction : les deux lignes sont validées
+    assert_eq!(lignes(&app.engine.db).await, 2);
+}
+
+#[tokio::test]
+async fn test_extracteur_rollback_sur_erreur() {
+    let app = app().await;
+    assert_eq!(
+        requete(&app, "erreur").await,
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+    assert_eq!(requete(&app, "invalide").await, StatusCode::BAD_REQUEST);
+    assert_eq!(lignes(&app.engine.db).await, 0);
+}
+
+#[tokio::test]
+async fn test_extracteur_decision_forcee() {
+    let app = app().await;
+    assert_eq!(requete(&app, "essai").await, StatusCode::OK);
+    assert_eq!(lignes(&app.engine.db).await, 0);
+
+    assert_eq!(requete(&app, "journal").await, StatusCode::BAD_REQUEST);
+    assert_eq!(lignes(&app.engine.db).await, 2);
+}
@@ -8,26 +8,44 @@
 //! | `test_special_fields`  | Champs spéciaux (fichier, datetime, …)  |
 //! | `test_password`        | Hashage et vérification de mots de passe|
 //! | `test_integration`     | Flux complets formulaire → validation   |
+//! | `test_many_to_many`    | Champ ManyToMany, table de jonction     |
+//! | `test_choice_enum`     | Choix tirés d'une enum Rust             |
+//! | `test_date_range_field`| Plage de dates début / fin              |
+//! | `test_markdown_field`  | Champ Markdown, aperçu en direct        |
 
 pub mod test_aegis;
+pub mod test_autocomplete_field;
 pub mod test_base_field;
 pub mod test_bool_choice;
 pub mod test_boolean_field;
+pub mod test_choice_enum;
 pub mod test_choice_fields;
+pub mod test_choice_query;
 pub mod test_cleaned_values;
+pub mod test_date_range_field;
 pub mod test_datetime_fields;
+pub mod test_errors_json;
 pub mod test_file_field;
+pub mod test_form_hooks;
 pub mod test_form_methods;
 pub mod test_forms;
+pub mod test_formset;
 pub mod test_generic_field;
 pub mod test_hidden_field;
+pub mod test_honeypot;
 pub mod test_hooks;
+pub mod test_many_to_many;
+pub mod test_markdown_field;
 pub mod test_model_form;
+pub mod test_must_match;
 pub mod test_number_fields;
 pub mod test_password;
+pub mod test_password_rules;
 pub mod test_prisme_extractor;
 pub mod test_prisme_rules;
 pub mod test_prisme_sentinel;
 pub mod test_renderer;
+pub mod test_slug_from;
 pub mod test_special_fields;
+pub mod test_unique_in;
 pub mod test_validator;
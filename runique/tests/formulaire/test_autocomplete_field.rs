@@ -0,0 +1,175 @@
+//! Tests — AutocompleteField : boîte de recherche à la place d'un <select> FK
+//! Couvre : id existant accepté + libellé affiché, id inexistant refusé,
+//!          requis, erreur DB remontée, prepare() qui résout le libellé,
+//!          lookup personnalisé, rendu (url, libellé, valeur cachée)
+
+use crate::helpers::db;
+use runique::forms::{
+    RowLookup, base::FormField, field::RuniqueForm, fields::choice::AutocompleteField, form::Forms,
+};
+use runique::sea_orm::DatabaseConnection;
+use runique::utils::constante::FIELD_TEMPLATES;
+use std::sync::Arc;
+use tera::Tera;
+
+mod author {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "author")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub username: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+const SCHEMA: &str =
+    "CREATE TABLE author (id INTEGER PRIMARY KEY AUTOINCREMENT, username TEXT NOT NULL)";
+
+async fn db_with_authors() -> DatabaseConnection {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    db::exec(
+        &conn,
+        "INSERT INTO author (username) VALUES ('alice'), ('bob')",
+    )
+    .await;
+    conn
+}
+
+fn author_field() -> AutocompleteField {
+    AutocompleteField::new("author", "/authors/search")
+        .label("Auteur")
+        .lookup_in::<author::Entity>(author::Column::Username)
+}
+
+struct PostForm {
+    form: Forms,
+}
+
+impl RuniqueForm for PostForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&author_field().required());
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+fn post(author: &str) -> PostForm {
+    let mut form = PostForm {
+        form: Forms::new("csrf"),
+    };
+    PostForm::register_fields(&mut form.form);
+    form.get_form_mut().add_value("author", author);
+    form
+}
+
+fn selected_label(form: &Forms) -> String {
+    form.fields["author"].to_json_meta()["selected_label"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// RuniqueForm::is_valid_with_db
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_id_existant_accepte_et_libelle() {
+    let conn = db_with_authors().await;
+    let mut form = post("2");
+    assert!(form.is_valid_with_db(&conn).await);
+    assert_eq!(form.cleaned_i32("author"), Some(2));
+    assert_eq!(selected_label(form.get_form()), "bob");
+}
+
+#[tokio::test]
+async fn test_id_inexistant_refuse() {
+    let conn = db_with_authors().await;
+    let mut form = post("42");
+    assert!(!form.is_valid_with_db(&conn).await);
+    assert!(form.get_form().fields["author"].error().is_some());
+}
+
+#[test]
+fn test_requis_vide_refuse() {
+    let mut form = Forms::new("csrf");
+    form.field(&author_field().required());
+    form.add_value("author", "");
+    assert!(form.is_valid().is_err());
+    assert!(form.fields["author"].error().is_some());
+}
+
+#[tokio::test]
+async fn test_erreur_db_globale() {
+    let conn = db::fresh_db().await;
+    let mut form = post("1");
+    assert!(!form.is_valid_with_db(&conn).await);
+    assert!(!form.get_form().errors.is_empty());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Forms::prepare / lookup personnalisé
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_prepare_resout_le_libelle() {
+    let conn = db_with_authors().await;
+    let mut form = Forms::new("csrf");
+    form.field(&author_field());
+    form.add_value("author", "1");
+    form.prepare(&conn).await.unwrap();
+    assert_eq!(selected_label(&form), "alice");
+}
+
+#[tokio::test]
+async fn test_lookup_personnalise() {
+    let conn = db::fresh_db().await;
+    let mut form = Forms::new("csrf");
+    form.field(
+        &AutocompleteField::new("author", "/authors/search").lookup(RowLookup::new(
+            |_db, id| async move { Ok((id == "7").then(|| "Sept".to_string())) },
+        )),
+    );
+    form.add_value("author", "7");
+    assert!(form.check_lookups(&conn).await);
+    assert_eq!(selected_label(&form), "Sept");
+
+    form.add_value("author", "8");
+    assert!(!form.check_lookups(&conn).await);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Rendu
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_rendu_url_libelle_et_valeur() {
+    let mut tera = Tera::default();
+    tera.add_raw_templates(FIELD_TEMPLATES.iter().copied())
+        .unwrap();
+    tera.register_filter("runique_static", |v: &tera::Value, _: &_| Ok(v.clone()));
+    let tera = Arc::new(tera);
+
+    let mut field = AutocompleteField::new("author", "search").label("Auteur");
+    field.set_value("2");
+    field.set_selected_label("bob");
+    let html = field.render(&tera).unwrap();
+    assert!(html.contains(r#"data-autocomplete="search""#));
+    assert!(html.contains(r#"type="hidden""#));
+    assert!(html.contains(r#"value="2""#));
+    assert!(html.contains(r#"value="bob""#));
+}
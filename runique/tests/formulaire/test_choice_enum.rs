@@ -0,0 +1,146 @@
+//! Tests — ChoiceEnum : choix tirés des variantes d'une enum Rust
+//! Couvre : #[derive(ChoiceEnum)] (valeur, libellé, discriminant, nom du type),
+//!          ChoiceField::from_enum (options, validation), ColumnDef::enum_of,
+//!          enums entières générées par `model!` (mêmes variantes côté schéma et formulaire)
+
+use runique::forms::{ChoiceEnum, fields::choice::ChoiceField, form::Forms};
+use runique::migration::column::ColumnDef;
+
+#[derive(runique::ChoiceEnum)]
+enum Status {
+    #[choice(label = "Brouillon")]
+    Draft,
+    #[choice(value = "pub", label = "Publié")]
+    Published,
+    Archived,
+}
+
+#[derive(runique::ChoiceEnum)]
+#[choice(name = "niveau")]
+enum Level {
+    Low = 1,
+    #[choice(label = "Élevé")]
+    High = 10,
+}
+
+mod tache {
+    use runique::prelude::*;
+
+    model! {
+        Tache,
+        table: "ce_taches",
+        pk: id => i32,
+        enums: {
+            Priority: i32 [Low = 1, High = (3, "Haute")],
+            Etat: i64 [Ouvert = (0, "Ouvert !"), Ferme = 1],
+        },
+        {
+            titre:    text   [required],
+            priority: choice [enum(Priority)],
+            etat:     choice [enum(Etat)],
+        }
+    }
+}
+
+fn pairs(v: &[(&str, &str)]) -> Vec<(String, String)> {
+    v.iter()
+        .map(|(a, b)| (a.to_string(), b.to_string()))
+        .collect()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// #[derive(ChoiceEnum)]
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_derive_valeurs_et_libelles() {
+    assert_eq!(
+        Status::choices(),
+        pairs(&[
+            ("Draft", "Brouillon"),
+            ("pub", "Publié"),
+            ("Archived", "Archived")
+        ])
+    );
+}
+
+#[test]
+fn test_derive_discriminant_comme_valeur() {
+    assert_eq!(Level::choices(), pairs(&[("1", "Low"), ("10", "Élevé")]));
+    assert_eq!(Level::Low.choice_value(), "1");
+    assert_eq!(Level::High.choice_value(), "10");
+}
+
+#[test]
+fn test_derive_nom_du_type() {
+    assert_eq!(Status::ENUM_NAME, "status");
+    assert_eq!(Level::ENUM_NAME, "niveau");
+}
+
+#[test]
+fn test_derive_choice_value() {
+    assert_eq!(Status::Draft.choice_value(), "Draft");
+    assert_eq!(Status::Published.choice_value(), "pub");
+    assert_eq!(Status::Archived.choice_value(), "Archived");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// ChoiceField::from_enum
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_from_enum_options_dans_l_ordre() {
+    let field = ChoiceField::from_enum::<Status>("status");
+    let values: Vec<&str> = field.all_choices().map(|c| c.value.as_str()).collect();
+    assert_eq!(values, ["Draft", "pub", "Archived"]);
+    assert_eq!(field.all_choices().nth(1).unwrap().label, "Publié");
+}
+
+#[test]
+fn test_from_enum_validation() {
+    let mut form = Forms::new("csrf");
+    form.field(&ChoiceField::from_enum::<Status>("status"));
+    form.add_value("status", "pub");
+    assert!(form.is_valid().is_ok());
+
+    let mut form = Forms::new("csrf");
+    form.field(&ChoiceField::from_enum::<Status>("status"));
+    form.add_value("status", "Published");
+    assert!(form.is_valid().is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// ColumnDef::enum_of
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_enum_of_memes_variantes_que_le_formulaire() {
+    let col = ColumnDef::new("status").enum_of::<Status>();
+    assert_eq!(col.enum_variants, ["Draft", "pub", "Archived"]);
+    match col.col_type {
+        sea_query::ColumnType::Enum { name, .. } => assert_eq!(name.to_string(), "status"),
+        other => panic!("type enum attendu, obtenu {other:?}"),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Enums de model!
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_model_enum_entiere() {
+    assert_eq!(
+        tache::Priority::choices(),
+        pairs(&[("1", "Low"), ("3", "Haute")])
+    );
+    assert_eq!(tache::Priority::High.choice_value(), "3");
+}
+
+#[test]
+fn test_model_enum_i64() {
+    assert_eq!(
+        tache::Etat::choices(),
+        pairs(&[("0", "Ouvert !"), ("1", "Ferme")])
+    );
+    assert_eq!(tache::Etat::ENUM_NAME, "etat");
+}
@@ -112,6 +112,129 @@ fn test_choice_field_label() {
     assert_eq!(field.base.label, "Pays");
 }
 
+// ═══════════════════════════════════════════════════════════════
+// ChoiceField — groupes <optgroup>
+// ═══════════════════════════════════════════════════════════════
+
+fn pays_groupes() -> ChoiceField {
+    ChoiceField::new("pays")
+        .add_choice("", "Aucun")
+        .add_group("Europe", vec![("fr", "France"), ("de", "Allemagne")])
+        .add_group("Asie", vec![("jp", "Japon")])
+}
+
+#[test]
+fn test_choice_field_add_group() {
+    let field = pays_groupes();
+    assert_eq!(field.choices.len(), 1);
+    assert_eq!(field.choices[0].label, "Aucun");
+    assert_eq!(field.groups.len(), 2);
+    assert_eq!(field.groups[0].label, "Europe");
+    assert_eq!(field.groups[0].choices[1].value, "de");
+    assert_eq!(field.groups[0].position, 1);
+    let values: Vec<&str> = field.all_choices().map(|c| c.value.as_str()).collect();
+    assert_eq!(values, ["", "fr", "de", "jp"]);
+}
+
+#[test]
+fn test_choice_field_groupe_valeur_valide() {
+    let mut field = pays_groupes();
+    field.set_value("jp");
+    assert!(field.validate());
+}
+
+#[test]
+fn test_choice_field_groupe_valeur_absente() {
+    let mut field = pays_groupes();
+    field.set_value("us");
+    assert!(!field.validate());
+    assert!(field.error().is_some());
+}
+
+#[test]
+fn test_choice_field_groupe_et_choix_plats() {
+    let mut field = ChoiceField::new("pays")
+        .add_group("Europe", vec![("fr", "France")])
+        .add_choice("autre", "Autre");
+    field.set_value("autre");
+    assert!(field.validate());
+    field.set_value("fr");
+    assert!(field.validate());
+}
+
+#[test]
+fn test_choice_field_meta_ordre_d_insertion() {
+    let field = ChoiceField::new("pays")
+        .add_group("Europe", vec![("fr", "France")])
+        .add_choice("autre", "Autre");
+    let meta = field.to_json_meta();
+    assert_eq!(meta["choices"][0]["value"], "autre");
+    assert!(meta["choices"][0].get("kind").is_none());
+    assert_eq!(meta["groups"][0]["choices"][0]["value"], "fr");
+    assert_eq!(meta["groups"][0]["position"], 0);
+}
+
+#[test]
+fn test_choice_field_groupe_entre_deux_choix_plats() {
+    let mut field = ChoiceField::new("pays")
+        .add_choice("", "Aucun")
+        .add_group("Europe", vec![("fr", "France")])
+        .add_choice("autre", "Autre")
+        .multiple();
+    let values: Vec<&str> = field.all_choices().map(|c| c.value.as_str()).collect();
+    assert_eq!(values, ["", "fr", "autre"]);
+
+    field.set_value("fr,autre");
+    assert!(field.groups[0].choices[0].selected);
+    assert!(field.choices[1].selected);
+    assert!(!field.choices[0].selected);
+}
+
+#[test]
+fn test_choice_field_rendu_optgroup() {
+    let mut tera = Tera::default();
+    tera.add_raw_template(
+        "base_select.html",
+        include_str!("../../templates/field_html/base_select.html"),
+    )
+    .unwrap();
+    tera.add_raw_template(
+        "field_help_text.html",
+        include_str!("../../templates/field_html/help_text.html"),
+    )
+    .unwrap();
+    let mut field = pays_groupes();
+    field.set_value("de");
+
+    let html = field.render(&Arc::new(tera)).unwrap();
+    assert!(html.contains(r#"<optgroup label="Europe">"#));
+    assert!(html.contains(r#"<optgroup label="Asie">"#));
+    assert!(html.contains(r#"<option value="de" selected>"#));
+    assert!(html.find("Aucun").unwrap() < html.find("Europe").unwrap());
+    assert!(html.find("Europe").unwrap() < html.find("Asie").unwrap());
+}
+
+#[test]
+fn test_choice_field_rendu_groupe_avant_choix_plat() {
+    let mut tera = Tera::default();
+    tera.add_raw_template(
+        "base_select.html",
+        include_str!("../../templates/field_html/base_select.html"),
+    )
+    .unwrap();
+    tera.add_raw_template(
+        "field_help_text.html",
+        include_str!("../../templates/field_html/help_text.html"),
+    )
+    .unwrap();
+    let field = ChoiceField::new("pays")
+        .add_group("Europe", vec![("fr", "France")])
+        .add_choice("autre", "Autre");
+
+    let html = field.render(&Arc::new(tera)).unwrap();
+    assert!(html.find("Europe").unwrap() < html.find("Autre").unwrap());
+}
+
 // ═══════════════════════════════════════════════════════════════
 // RadioField
 // ═══════════════════════════════════════════════════════════════
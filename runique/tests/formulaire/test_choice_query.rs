@@ -0,0 +1,253 @@
+//! Tests — ChoiceField::from_query : choix chargés depuis la base
+//! Couvre : Forms::prepare() (chargement + cache), is_valid_with_db() qui
+//!          recharge la liste, valeur supprimée rejetée, erreur DB remontée,
+//!          is_valid() / rendu sans prepare() refusés explicitement,
+//!          RadioField / CheckboxField::from_query
+
+use crate::helpers::db;
+use runique::context::tera::form::RenderFormFunction;
+use runique::forms::{
+    field::RuniqueForm,
+    fields::choice::{CheckboxField, ChoiceField, RadioField},
+    form::Forms,
+    renderer::FormRenderer,
+};
+use runique::sea_orm::{DatabaseConnection, EntityTrait};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tera::{Function, Tera};
+
+mod author {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "author")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub username: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+const SCHEMA: &str =
+    "CREATE TABLE author (id INTEGER PRIMARY KEY AUTOINCREMENT, username TEXT NOT NULL)";
+
+async fn db_with_authors() -> DatabaseConnection {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    db::exec(
+        &conn,
+        "INSERT INTO author (username) VALUES ('alice'), ('bob')",
+    )
+    .await;
+    conn
+}
+
+fn author_field() -> ChoiceField {
+    ChoiceField::from_query("author", |db| async move {
+        let rows = author::Entity::find().all(&db).await?;
+        Ok(rows
+            .into_iter()
+            .map(|a| (a.id.to_string(), a.username))
+            .collect())
+    })
+}
+
+struct PostForm {
+    form: Forms,
+}
+
+impl RuniqueForm for PostForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&author_field().required());
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+fn post(author: &str) -> PostForm {
+    let mut form = PostForm {
+        form: Forms::new("csrf"),
+    };
+    PostForm::register_fields(&mut form.form);
+    form.get_form_mut().add_value("author", author);
+    form
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Forms::prepare
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_prepare_charge_les_choix() {
+    let conn = db_with_authors().await;
+    let mut form = Forms::new("csrf");
+    form.field(&author_field());
+    form.prepare(&conn).await.unwrap();
+
+    let field = &form.fields["author"];
+    assert!(field.choice_source().is_some());
+    form.add_value("author", "2");
+    assert!(form.is_valid().is_ok());
+}
+
+#[tokio::test]
+async fn test_prepare_une_seule_requete() {
+    let conn = db_with_authors().await;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let mut form = Forms::new("csrf");
+    form.field(&ChoiceField::from_query("author", move |_db| {
+        let counter = counter.clone();
+        async move {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![("1".to_string(), "alice".to_string())])
+        }
+    }));
+
+    form.prepare(&conn).await.unwrap();
+    form.prepare(&conn).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_prepare_erreur_db() {
+    // Table absente → prepare() renvoie l'erreur
+    let conn = db::fresh_db().await;
+    let mut form = Forms::new("csrf");
+    form.field(&author_field());
+    assert!(form.prepare(&conn).await.is_err());
+}
+
+#[test]
+fn test_sans_prepare_valeur_rejetee() {
+    let mut form = Forms::new("csrf");
+    form.field(&author_field());
+    form.add_value("author", "1");
+    assert!(form.is_valid().is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// RuniqueForm::is_valid_with_db
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_is_valid_with_db_ligne_existante() {
+    let conn = db_with_authors().await;
+    let mut form = post("1");
+    assert!(form.is_valid_with_db(&conn).await);
+    assert_eq!(form.cleaned_i32("author"), Some(1));
+}
+
+#[tokio::test]
+async fn test_is_valid_with_db_ligne_supprimee() {
+    let conn = db_with_authors().await;
+    db::exec(&conn, "DELETE FROM author WHERE id = 2").await;
+    let mut form = post("2");
+    assert!(!form.is_valid_with_db(&conn).await);
+    assert!(form.get_form().fields["author"].error().is_some());
+}
+
+#[tokio::test]
+async fn test_is_valid_with_db_erreur_db_globale() {
+    let conn = db::fresh_db().await;
+    let mut form = post("1");
+    assert!(!form.is_valid_with_db(&conn).await);
+    assert_eq!(form.get_form().errors.len(), 1);
+}
+
+#[tokio::test]
+async fn test_is_valid_sans_db_erreur_explicite() {
+    let mut form = post("1");
+    assert!(!form.is_valid().await);
+    // Pas de « choix invalide » sur le champ : l'erreur globale nomme la cause
+    assert!(form.get_form().fields["author"].error().is_none());
+    assert_eq!(form.get_form().errors.len(), 1);
+    assert!(form.get_form().errors[0].contains("author"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Rendu sans prepare()
+// ═══════════════════════════════════════════════════════════════
+
+fn select_tera() -> Arc<Tera> {
+    let mut tera = Tera::default();
+    tera.add_raw_template("base_select.html", "<select name=\"{{ field.name }}\">")
+        .unwrap();
+    tera.add_raw_template("csrf.html", "").unwrap();
+    tera.add_raw_template("js_files.html", "").unwrap();
+    Arc::new(tera)
+}
+
+#[tokio::test]
+async fn test_rendu_refuse_sans_prepare() {
+    let conn = db_with_authors().await;
+    let mut form = Forms::new("csrf");
+    form.set_renderer(FormRenderer::new(select_tera()));
+    form.field(&author_field());
+
+    let err = form.render().unwrap_err();
+    assert!(err.contains("author"));
+    assert!(err.contains("prepare"));
+
+    let mut args = HashMap::new();
+    args.insert("form".to_string(), serde_json::to_value(&form).unwrap());
+    assert!(RenderFormFunction::default().call(&args).is_err());
+
+    form.prepare(&conn).await.unwrap();
+    form.render().unwrap();
+    assert!(form.unprepared_choices().is_empty());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// RadioField / CheckboxField::from_query
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_radio_from_query() {
+    let conn = db_with_authors().await;
+    let mut form = Forms::new("csrf");
+    form.field(&RadioField::from_query("author", |db| async move {
+        let rows = author::Entity::find().all(&db).await?;
+        Ok(rows
+            .into_iter()
+            .map(|a| (a.id.to_string(), a.username))
+            .collect())
+    }));
+    form.prepare(&conn).await.unwrap();
+    form.add_value("author", "2");
+    assert!(form.is_valid().is_ok());
+    form.add_value("author", "9");
+    assert!(form.is_valid().is_err());
+}
+
+#[tokio::test]
+async fn test_checkbox_from_query() {
+    let conn = db_with_authors().await;
+    let mut form = Forms::new("csrf");
+    form.field(&CheckboxField::from_query("authors", |db| async move {
+        let rows = author::Entity::find().all(&db).await?;
+        Ok(rows
+            .into_iter()
+            .map(|a| (a.id.to_string(), a.username))
+            .collect())
+    }));
+    form.add_value("authors", "1,2");
+    form.prepare(&conn).await.unwrap();
+    assert!(form.is_valid().is_ok());
+    let meta = form.fields["authors"].to_json_meta();
+    assert_eq!(meta["choices"][0]["selected"], true);
+    assert_eq!(meta["choices"][1]["selected"], true);
+}
@@ -0,0 +1,269 @@
+//! Tests — DateRangeField : champ composite début / fin
+//! Couvre : lecture des entrées `{nom}_start` / `{nom}_end` par `fill`, ordre
+//!          `fin >= début`, bornes, erreurs rattachées à l'entrée fautive,
+//!          plage ouverte, cleaned_date_range, rendu, formset
+
+use axum::http::Method;
+use chrono::NaiveDate;
+use runique::forms::{
+    FormSet,
+    base::FormField,
+    field::RuniqueForm,
+    fields::datetime::{DateRangeField, parse_date_range},
+    form::Forms,
+};
+use runique::utils::aliases::StrMap;
+use runique::utils::constante::FIELD_TEMPLATES;
+use std::sync::Arc;
+use tera::Tera;
+
+struct BookingForm {
+    form: Forms,
+}
+
+impl RuniqueForm for BookingForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&DateRangeField::new("stay").label("Séjour").required());
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn post(pairs: &[(&str, &str)]) -> StrMap {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn tera() -> Arc<Tera> {
+    let mut tera = Tera::default();
+    tera.add_raw_templates(FIELD_TEMPLATES.iter().copied())
+        .unwrap();
+    Arc::new(tera)
+}
+
+fn validated(field: DateRangeField, value: &str) -> DateRangeField {
+    let mut field = field;
+    field.set_value(value);
+    field.validate();
+    field
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Lecture des deux entrées
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_fill_combine_debut_et_fin() {
+    let mut form = Forms::new("csrf");
+    form.field(&DateRangeField::new("stay"));
+    form.fill(
+        &post(&[("stay_start", "2024-07-01"), ("stay_end", "2024-07-10")]),
+        Method::POST,
+    );
+    assert_eq!(form.fields["stay"].value(), "2024-07-01/2024-07-10");
+}
+
+#[test]
+fn test_fill_accepte_la_valeur_complete() {
+    let mut form = Forms::new("csrf");
+    form.field(&DateRangeField::new("stay"));
+    form.fill(&post(&[("stay", "2024-07-01/2024-07-10")]), Method::POST);
+    assert_eq!(form.fields["stay"].value(), "2024-07-01/2024-07-10");
+}
+
+#[test]
+fn test_fill_entrees_vides_valeur_vide() {
+    let mut form = Forms::new("csrf");
+    form.field(&DateRangeField::new("stay"));
+    form.fill(
+        &post(&[("stay_start", ""), ("stay_end", " ")]),
+        Method::POST,
+    );
+    assert_eq!(form.fields["stay"].value(), "");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Validation
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_plage_valide() {
+    let field = validated(DateRangeField::new("stay"), "2024-07-01/2024-07-10");
+    assert!(field.error().is_none());
+    assert_eq!(
+        field.range(),
+        Some((date(2024, 7, 1), Some(date(2024, 7, 10))))
+    );
+}
+
+#[test]
+fn test_meme_jour_accepte() {
+    let field = validated(DateRangeField::new("stay"), "2024-07-01/2024-07-01");
+    assert!(field.error().is_none());
+}
+
+#[test]
+fn test_fin_avant_debut_erreur_sur_la_fin() {
+    let field = validated(DateRangeField::new("stay"), "2024-07-10/2024-07-01");
+    assert!(field.error().is_some());
+    assert!(field.start_error.is_none());
+    assert!(field.end_error.is_some());
+}
+
+#[test]
+fn test_debut_invalide_erreur_sur_le_debut() {
+    let field = validated(DateRangeField::new("stay"), "2024-13-01/2024-07-01");
+    assert!(field.start_error.is_some());
+    assert!(field.end_error.is_none());
+}
+
+#[test]
+fn test_deux_erreurs_rattachees_chacune_a_son_entree() {
+    let field = validated(
+        DateRangeField::new("stay")
+            .min(date(2024, 1, 1), "Trop tôt")
+            .max(date(2024, 12, 31), "Trop tard"),
+        "2023-06-01/2025-01-01",
+    );
+    assert_eq!(field.start_error.as_deref(), Some("Trop tôt"));
+    assert_eq!(field.end_error.as_deref(), Some("Trop tard"));
+    assert_eq!(field.error().unwrap(), "Trop tôt");
+}
+
+#[test]
+fn test_fin_absente_refusee_par_defaut() {
+    let field = validated(DateRangeField::new("stay"), "2024-07-01/");
+    assert!(field.end_error.is_some());
+}
+
+#[test]
+fn test_plage_ouverte() {
+    let field = validated(DateRangeField::new("stay").open_ended(), "2024-07-01/");
+    assert!(field.error().is_none());
+    assert_eq!(field.range(), Some((date(2024, 7, 1), None)));
+}
+
+#[test]
+fn test_debut_absent_refuse() {
+    let field = validated(DateRangeField::new("stay").open_ended(), "/2024-07-01");
+    assert!(field.start_error.is_some());
+}
+
+#[test]
+fn test_vide_non_requis() {
+    let field = validated(DateRangeField::new("stay"), "");
+    assert!(field.error().is_none());
+}
+
+#[test]
+fn test_vide_requis() {
+    let mut form = Forms::new("csrf");
+    form.field(&DateRangeField::new("stay").required());
+    form.fill(&post(&[("stay_start", ""), ("stay_end", "")]), Method::POST);
+    assert!(form.is_valid().is_err());
+    assert!(form.fields["stay"].error().is_some());
+}
+
+#[test]
+fn test_parse_date_range() {
+    assert_eq!(
+        parse_date_range("2024-07-01/2024-07-10").unwrap(),
+        (date(2024, 7, 1), Some(date(2024, 7, 10)))
+    );
+    assert_eq!(
+        parse_date_range("2024-07-01").unwrap(),
+        (date(2024, 7, 1), None)
+    );
+    assert!(parse_date_range("bientôt").is_err());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// cleaned_date_range et formset
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_cleaned_date_range() {
+    let mut booking = BookingForm::build(tera(), "csrf");
+    booking.get_form_mut().fill(
+        &post(&[("stay_start", "2024-07-01"), ("stay_end", "2024-07-10")]),
+        Method::POST,
+    );
+    assert!(booking.get_form_mut().is_valid().is_ok());
+    assert_eq!(
+        booking.cleaned_date_range("stay"),
+        Some((date(2024, 7, 1), Some(date(2024, 7, 10))))
+    );
+}
+
+#[tokio::test]
+async fn test_formset_entrees_prefixees() {
+    let mut formset: FormSet<BookingForm> = FormSet::new("stays", tera(), "csrf");
+    formset.fill(
+        &post(&[
+            ("stays-TOTAL_FORMS", "2"),
+            ("stays-0-stay_start", "2024-07-01"),
+            ("stays-0-stay_end", "2024-07-03"),
+            ("stays-1-stay_start", "2024-08-10"),
+            ("stays-1-stay_end", "2024-08-01"),
+        ]),
+        Method::POST,
+    );
+    assert!(!formset.is_valid().await);
+    let errors = formset.row_errors();
+    assert!(errors[0].is_empty());
+    assert!(errors[1].contains_key("stay"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Rendu
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_rendu_deux_entrees_et_bornes() {
+    let mut field = DateRangeField::new("stay")
+        .label("Séjour")
+        .min(date(2024, 1, 1), "");
+    field.set_value("2024-07-01/2024-07-10");
+    let html = field.render(&tera()).unwrap();
+    assert!(html.contains(r#"name="stay_start""#));
+    assert!(html.contains(r#"name="stay_end""#));
+    assert!(html.contains(r#"value="2024-07-01""#));
+    assert!(html.contains(r#"value="2024-07-10""#));
+    assert_eq!(html.matches(r#"min="2024-01-01""#).count(), 2);
+}
+
+#[test]
+fn test_rendu_erreur_sous_l_entree_fautive() {
+    let field = validated(
+        DateRangeField::new("stay").label("Séjour"),
+        "2024-07-10/2024-07-01",
+    );
+    let html = field.render(&tera()).unwrap();
+    let end = html.find(r#"name="stay_end""#).unwrap();
+    let error = html.find("field-error-message").unwrap();
+    assert!(error > end);
+    assert_eq!(html.matches("field-error-message").count(), 1);
+}
+
+#[test]
+fn test_meta_expose_les_deux_entrees() {
+    let field = validated(DateRangeField::new("stay"), "2024-07-10/2024-07-01");
+    let meta = field.to_json_meta();
+    assert_eq!(meta["start"]["name"], "stay_start");
+    assert_eq!(meta["end"]["value"], "2024-07-01");
+    assert!(meta["end"]["error"].is_string());
+    assert_eq!(meta["open_ended"], false);
+}
@@ -1,8 +1,12 @@
 // Tests — DateField, TimeField, DateTimeField, DurationField
 
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime, NaiveTime};
 use runique::forms::base::FormField;
 use runique::forms::fields::datetime::{DateField, DateTimeField, DurationField, TimeField};
+use runique::forms::form::Forms;
+use runique::utils::constante::FIELD_TEMPLATES;
+use std::sync::Arc;
+use tera::Tera;
 
 // ═══════════════════════════════════════════════════════════════
 // DateField
@@ -362,6 +366,172 @@ fn test_datetime_field_label() {
     assert_eq!(field.base.label, "Rendez-vous");
 }
 
+// ═══════════════════════════════════════════════════════════════
+// Bornes relatives (aujourd'hui / maintenant), formats ISO 8601, rendu
+// ═══════════════════════════════════════════════════════════════
+
+fn tera() -> Arc<Tera> {
+    let mut tera = Tera::default();
+    tera.add_raw_templates(FIELD_TEMPLATES.iter().copied())
+        .unwrap();
+    Arc::new(tera)
+}
+
+fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+#[test]
+fn test_date_field_min_today_accepte_aujourd_hui() {
+    let mut field = DateField::new("evenement").min_today("");
+    field.set_value(&today().format("%Y-%m-%d").to_string());
+    assert!(field.validate());
+}
+
+#[test]
+fn test_date_field_min_today_rejette_hier() {
+    let mut field = DateField::new("evenement").min_today("");
+    field.set_value(&(today() - Duration::days(1)).format("%Y-%m-%d").to_string());
+    assert!(!field.validate());
+    assert!(field.error().is_some());
+}
+
+#[test]
+fn test_date_field_min_today_message_custom() {
+    let mut field = DateField::new("evenement").min_today("Pas dans le passé");
+    field.set_value("2000-01-01");
+    assert!(!field.validate());
+    assert_eq!(field.error().unwrap(), "Pas dans le passé");
+}
+
+#[test]
+fn test_date_field_min_today_et_min_la_borne_la_plus_tardive() {
+    // `min` dans le futur : c'est son message qui s'applique
+    let min = today() + Duration::days(10);
+    let mut field = DateField::new("evenement")
+        .min(min, "Trop tôt")
+        .min_today("Passé");
+    field.set_value(&(today() + Duration::days(2)).format("%Y-%m-%d").to_string());
+    assert!(!field.validate());
+    assert_eq!(field.error().unwrap(), "Trop tôt");
+
+    // `min` dans le passé : aujourd'hui l'emporte
+    let min = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    let mut field = DateField::new("evenement")
+        .min(min, "Trop tôt")
+        .min_today("Passé");
+    field.set_value("2010-01-01");
+    assert!(!field.validate());
+    assert_eq!(field.error().unwrap(), "Passé");
+}
+
+#[test]
+fn test_date_field_default_today() {
+    let field = DateField::new("evenement").default_today();
+    assert_eq!(field.base.value, today().format("%Y-%m-%d").to_string());
+}
+
+#[test]
+fn test_date_field_accepte_iso_8601() {
+    let mut field = DateField::new("evenement");
+    field.set_value("2024-06-15T14:30:00Z");
+    assert!(field.validate());
+}
+
+#[test]
+fn test_date_field_date_inexistante() {
+    let mut field = DateField::new("evenement");
+    field.set_value("2024-02-30");
+    assert!(!field.validate());
+    assert!(field.error().is_some());
+}
+
+#[test]
+fn test_date_field_rendu_min_max() {
+    let field = DateField::new("evenement")
+        .min(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), "")
+        .max(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(), "");
+    let html = field.render(&tera()).unwrap();
+    assert!(html.contains(r#"min="2024-01-01""#));
+    assert!(html.contains(r#"max="2024-12-31""#));
+}
+
+#[test]
+fn test_date_field_rendu_min_today() {
+    let html = DateField::new("evenement")
+        .min_today("")
+        .render(&tera())
+        .unwrap();
+    assert!(html.contains(&format!(r#"min="{}""#, today().format("%Y-%m-%d"))));
+}
+
+#[test]
+fn test_datetime_field_min_now_rejette_le_passe() {
+    let mut field = DateTimeField::new("rdv").min_now("");
+    field.set_value("2000-01-01T10:00");
+    assert!(!field.validate());
+    assert!(field.error().is_some());
+
+    let demain = Local::now().naive_local() + Duration::days(1);
+    field.set_value(&demain.format("%Y-%m-%dT%H:%M").to_string());
+    assert!(field.validate());
+}
+
+#[test]
+fn test_datetime_field_default_now_valide_avec_min_now() {
+    let mut field = DateTimeField::new("rdv").min_now("").default_now();
+    assert!(!field.base.value.is_empty());
+    assert!(field.validate());
+}
+
+#[test]
+fn test_datetime_field_accepte_iso_8601() {
+    for val in [
+        "2024-06-15T14:30:05",
+        "2024-06-15T14:30:05.250",
+        "2024-06-15T14:30:05+02:00",
+        "2024-06-15T14:30:05Z",
+    ] {
+        let mut field = DateTimeField::new("rdv");
+        field.set_value(val);
+        assert!(field.validate(), "{val} devrait être accepté");
+    }
+}
+
+#[test]
+fn test_datetime_field_rendu_min_max() {
+    let min = NaiveDateTime::parse_from_str("2024-01-01T08:00", "%Y-%m-%dT%H:%M").unwrap();
+    let max = NaiveDateTime::parse_from_str("2024-12-31T18:00", "%Y-%m-%dT%H:%M").unwrap();
+    let html = DateTimeField::new("rdv")
+        .min(min, "")
+        .max(max, "")
+        .render(&tera())
+        .unwrap();
+    assert!(html.contains(r#"min="2024-01-01T08:00""#));
+    assert!(html.contains(r#"max="2024-12-31T18:00""#));
+}
+
+#[test]
+fn test_forms_rejette_date_hors_bornes() {
+    let mut form = Forms::new("csrf");
+    form.field(&DateField::new("evenement").min_today("Pas dans le passé"));
+    form.add_value("evenement", "2000-01-01");
+    assert!(form.is_valid().is_err());
+    assert_eq!(
+        form.fields["evenement"].error().unwrap(),
+        "Pas dans le passé"
+    );
+}
+
+#[test]
+fn test_forms_date_invalide_sans_panique() {
+    let mut form = Forms::new("csrf");
+    form.field(&DateTimeField::new("rdv"));
+    form.add_value("rdv", "2024-13-45T99:99");
+    assert!(form.is_valid().is_err());
+    assert!(form.fields["rdv"].error().is_some());
+}
+
 // ═══════════════════════════════════════════════════════════════
 // DurationField
 // ═══════════════════════════════════════════════════════════════
@@ -0,0 +1,103 @@
+//! Tests — Forms::errors_as_json / error_response : erreurs de validation pour API
+//! Couvre : forme `{ champ: [msg], "__all__": [...] }`, erreurs de `clean` hors champ
+//!          sous `__all__`, réponse 422 JSON, flux complet depuis un corps JSON
+
+use crate::helpers::{request::build_handler_req, server::build_engine};
+use axum::{http::StatusCode, response::IntoResponse};
+use runique::forms::{
+    field::RuniqueForm,
+    fields::{number::NumericField, text::TextField},
+    form::{Forms, NON_FIELD_ERRORS},
+};
+use runique::utils::aliases::StrMap;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+struct SignupForm {
+    form: Forms,
+}
+
+#[async_trait::async_trait]
+impl RuniqueForm for SignupForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&TextField::text("username").required());
+        form.field(&NumericField::integer("age"));
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+    async fn clean(&mut self) -> Result<(), HashMap<String, String>> {
+        if self.cleaned_string("username").as_deref() == Some("root") {
+            let mut errors = HashMap::new();
+            errors.insert("account".to_string(), "Compte réservé".to_string());
+            return Err(errors);
+        }
+        Ok(())
+    }
+}
+
+fn body(pairs: &[(&str, &str)]) -> StrMap {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_errors_as_json_formulaire_sans_erreur() {
+    let form = Forms::new("csrf");
+    assert_eq!(form.errors_as_json(), json!({}));
+}
+
+#[test]
+fn test_errors_as_json_champ_et_global() {
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::text("username"));
+    if let Some(field) = form.fields.get_mut("username") {
+        field.set_error("Nom requis".to_string());
+    }
+    form.errors.push("Formulaire incomplet".to_string());
+    assert_eq!(
+        form.errors_as_json(),
+        json!({ "username": ["Nom requis"], "__all__": ["Formulaire incomplet"] })
+    );
+}
+
+#[tokio::test]
+async fn test_errors_as_json_erreur_clean_sous_all() {
+    let engine = build_engine().await;
+    let req = build_handler_req(engine, None, body(&[("username", "root")])).await;
+    let mut form: SignupForm = req.form();
+    assert!(!form.is_valid().await);
+    let errors = form.get_form().errors_as_json();
+    assert_eq!(errors[NON_FIELD_ERRORS], json!(["Compte réservé"]));
+    assert!(errors.get("username").is_none());
+}
+
+#[tokio::test]
+async fn test_error_response_422_json() {
+    let engine = build_engine().await;
+    let req = build_handler_req(engine, None, body(&[("age", "abc")])).await;
+    let mut form: SignupForm = req.form();
+    assert!(!form.is_valid().await);
+
+    let response = form.get_form().error_response().into_response();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "application/json"
+    );
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let payload: Value = serde_json::from_slice(&bytes).unwrap();
+    assert!(payload["username"][0].is_string());
+    assert!(payload["age"][0].is_string());
+    assert!(payload.get(NON_FIELD_ERRORS).is_none());
+}
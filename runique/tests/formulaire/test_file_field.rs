@@ -1,9 +1,13 @@
 //! Tests — forms/fields/file.rs
-//! Couvre : AllowedExtensions, FileUploadConfig, IntoUploadPath, FileField builders, FileField::validate()
+//! Couvre : AllowedExtensions, FileUploadConfig, IntoUploadPath, FileField builders, FileField::validate(),
+//!          allowed_content_types (sniffing, types texte), max_dimensions
 
 use runique::config::StaticConfig;
 use runique::forms::base::FormField;
-use runique::forms::fields::file::{AllowedExtensions, FileField, FileSize, FileUploadConfig};
+use runique::forms::fields::file::{
+    AllowedExtensions, FileField, FileSize, FileUploadConfig, detect_content_type,
+    sniff_content_type,
+};
 
 // ═══════════════════════════════════════════════════════════════
 // AllowedExtensions
@@ -357,3 +361,169 @@ fn test_file_field_finalize_nonexistent_file_keeps_path() {
     assert!(f.finalize().is_ok());
     assert!(f.value().contains("nonexistent_xyz.pdf"));
 }
+
+// ═══════════════════════════════════════════════════════════════
+// allowed_content_types + sniff_content_type
+// ═══════════════════════════════════════════════════════════════
+
+const PNG_MAGIC: &[u8] = &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 0];
+
+fn temp_file(name: &str, bytes: &[u8]) -> String {
+    let dir = std::env::temp_dir().join(format!("runique_ct_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::write(&path, bytes).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+fn temp_png(name: &str, width: u32, height: u32) -> String {
+    let path = temp_file(name, b"");
+    image::RgbImage::new(width, height).save(&path).unwrap();
+    path
+}
+
+#[test]
+fn test_sniff_content_type_ignore_extension() {
+    let exe = temp_file("virus.png", b"MZ\x90\x00\x03\x00\x00\x00");
+    assert_eq!(sniff_content_type(&exe), Some("application/x-msdownload"));
+    let png = temp_file("photo.bin", PNG_MAGIC);
+    assert_eq!(sniff_content_type(&png), Some("image/png"));
+    assert_eq!(sniff_content_type("absent_abc.png"), None);
+}
+
+#[test]
+fn test_sniff_content_type_ftyp_inconnu() {
+    let mp4 = temp_file("clip.mp4", b"\0\0\0\x18ftypisom\0\0\0\0");
+    assert_eq!(sniff_content_type(&mp4), Some("video/mp4"));
+    let unknown = temp_file("clip.bin", b"\0\0\0\x18ftypzzzz\0\0\0\0");
+    assert_eq!(sniff_content_type(&unknown), None);
+}
+
+#[test]
+fn test_detect_content_type_texte_par_extension() {
+    let csv = temp_file("data.csv", b"id,nom\n1,Alice\n");
+    assert_eq!(detect_content_type(&csv), Some("text/csv"));
+    let json = temp_file("data.json", br#"{"ok": true}"#);
+    assert_eq!(detect_content_type(&json), Some("application/json"));
+    // Extension texte, contenu binaire → type inconnu
+    let binary = temp_file("data.txt", &[0xFF, 0xFE, 0x00, 0xC3]);
+    assert_eq!(detect_content_type(&binary), None);
+}
+
+#[test]
+fn test_file_field_content_type_texte_accepte() {
+    let path = temp_file("export.csv", b"a;b\n1;2\n");
+    let mut f = FileField::any("export").allowed_content_types(&["text/csv"]);
+    f.set_value(&path);
+    assert!(f.validate());
+}
+
+#[test]
+fn test_file_field_content_type_binaire_renomme_en_texte_rejete() {
+    let path = temp_file("notes.txt", b"MZ\x90\x00\x03\x00\x00\x00");
+    let mut f = FileField::any("notes").allowed_content_types(&["text/plain"]);
+    f.set_value(&path);
+    assert!(!f.validate());
+    assert!(f.error().unwrap().contains("application/x-msdownload"));
+    assert!(!std::path::Path::new(&path).exists());
+}
+
+#[test]
+fn test_file_field_content_type_ftyp_inconnu_rejete() {
+    let path = temp_file("clip.mp4", b"\0\0\0\x18ftypzzzz\0\0\0\0");
+    let mut f = FileField::any("clip").allowed_content_types(&["video/mp4"]);
+    f.set_value(&path);
+    assert!(!f.validate());
+    assert!(f.error().is_some());
+}
+
+#[test]
+fn test_file_field_allowed_content_types_builder() {
+    let f = FileField::any("doc").allowed_content_types(&["Application/PDF", "image/*"]);
+    assert_eq!(f.allowed_content_types, vec!["application/pdf", "image/*"]);
+}
+
+#[test]
+fn test_file_field_content_type_exe_renomme_rejete() {
+    let path = temp_file("virus.png", b"MZ\x90\x00\x03\x00\x00\x00");
+    let mut f = FileField::any("upload").allowed_content_types(&["image/png", "image/jpeg"]);
+    f.set_value(&path);
+    assert!(!f.validate());
+    assert!(f.error().unwrap().contains("application/x-msdownload"));
+    assert!(
+        !std::path::Path::new(&path).exists(),
+        "fichier rejeté supprimé"
+    );
+}
+
+#[test]
+fn test_file_field_content_type_accepte() {
+    let path = temp_file("scan.pdf", b"%PDF-1.7\n");
+    let mut f = FileField::document("scan").allowed_content_types(&["application/pdf"]);
+    f.set_value(&path);
+    assert!(f.validate());
+}
+
+#[test]
+fn test_file_field_content_type_wildcard() {
+    let path = temp_png("photo.png", 4, 4);
+    let mut f = FileField::image("photo").allowed_content_types(&["image/*"]);
+    f.set_value(&path);
+    assert!(f.validate());
+}
+
+#[test]
+fn test_file_field_erreurs_distinctes() {
+    let big = temp_file("big.pdf", &[b'%'; 2048]);
+    let mut f = FileField::document("doc")
+        .max_size(FileSize::kb(1))
+        .allowed_content_types(&["application/pdf"]);
+    f.set_value(&big);
+    assert!(!f.validate());
+    let too_large = f.error().unwrap().clone();
+
+    let zip = temp_file("doc.pdf", b"PK\x03\x04rest");
+    f.set_value(&zip);
+    assert!(!f.validate());
+    let blocked = f.error().unwrap().clone();
+
+    let fake = temp_file("fake.png", PNG_MAGIC);
+    let mut img = FileField::image("img").max_dimensions(100, 100);
+    img.set_value(&fake);
+    assert!(!img.validate());
+    let invalid = img.error().unwrap().clone();
+
+    assert_ne!(too_large, blocked);
+    assert_ne!(blocked, invalid);
+    assert_ne!(too_large, invalid);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// max_dimensions — en-tête réellement décodé
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_file_field_dimensions_ok() {
+    let path = temp_png("ok.png", 50, 40);
+    let mut f = FileField::image("img").max_dimensions(100, 100);
+    f.set_value(&path);
+    assert!(f.validate());
+}
+
+#[test]
+fn test_file_field_dimensions_trop_large() {
+    let path = temp_png("wide.png", 200, 10);
+    let mut f = FileField::image("img").max_dimensions(100, 100);
+    f.set_value(&path);
+    assert!(!f.validate());
+    assert!(f.error().unwrap().contains("200"));
+}
+
+#[test]
+fn test_file_field_magic_seul_pas_une_image() {
+    // Signature PNG valide mais en-tête tronqué → image invalide
+    let path = temp_file("trunc.png", PNG_MAGIC);
+    let mut f = FileField::image("img").max_dimensions(100, 100);
+    f.set_value(&path);
+    assert!(!f.validate());
+}
@@ -0,0 +1,95 @@
+//! Tests — `FormHooks` : hooks d'un formulaire généré par `model!`
+//! Couvre : `meta: { form_hooks: true }` fait passer `customize`, `clean_value`
+//!          et `clean` du formulaire généré par l'implémentation utilisateur.
+
+use runique::forms::field::{FormHooks, RuniqueForm};
+use runique::forms::form::Forms;
+use std::collections::HashMap;
+
+mod article {
+    use runique::prelude::*;
+
+    model! {
+        Article,
+        table: "hook_articles",
+        pk: id => i32,
+        fields: {
+            title: String [required],
+            summary: String,
+        },
+        meta: {
+            form_hooks: true,
+        }
+    }
+}
+
+use article::ArticleAdminForm;
+
+#[runique::async_trait::async_trait]
+impl FormHooks for ArticleAdminForm {
+    fn customize(form: &mut Forms) {
+        if let Some(field) = form.fields.get_mut("summary") {
+            field.set_label("Résumé");
+        }
+    }
+
+    fn clean_value(&self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "title" if value == "admin" => Err("Titre réservé".into()),
+            _ => Ok(()),
+        }
+    }
+
+    async fn clean(&mut self) -> Result<(), HashMap<String, String>> {
+        let title = self.cleaned_string("title").unwrap_or_default();
+        let summary = self.cleaned_string("summary").unwrap_or_default();
+        if title == summary {
+            let mut errors = HashMap::new();
+            errors.insert("summary".to_string(), "Identique au titre".to_string());
+            return Err(errors);
+        }
+        Ok(())
+    }
+}
+
+fn article_form(title: &str, summary: &str) -> ArticleAdminForm {
+    let mut form = Forms::new("csrf");
+    ArticleAdminForm::register_fields(&mut form);
+    form.add_value("title", title);
+    form.add_value("summary", summary);
+    ArticleAdminForm::from_form(form)
+}
+
+#[test]
+fn test_form_hooks_customize_appele() {
+    let form = article_form("", "");
+    assert_eq!(form.get_form().fields["summary"].label(), "Résumé");
+}
+
+#[tokio::test]
+async fn test_form_hooks_clean_value_surcharge() {
+    let mut form = article_form("admin", "Un résumé");
+    assert!(!form.is_valid().await);
+    assert_eq!(
+        form.get_form().fields["title"].error().map(String::as_str),
+        Some("Titre réservé")
+    );
+}
+
+#[tokio::test]
+async fn test_form_hooks_clean_surcharge() {
+    let mut form = article_form("Rust", "Rust");
+    assert!(!form.is_valid().await);
+    assert_eq!(
+        form.get_form().fields["summary"]
+            .error()
+            .map(String::as_str),
+        Some("Identique au titre")
+    );
+}
+
+#[tokio::test]
+async fn test_form_hooks_formulaire_valide() {
+    let mut form = article_form("Rust", "Un langage");
+    assert!(form.is_valid().await);
+}
@@ -506,4 +506,79 @@ mod tests {
         form.form.fill(&data, Method::POST);
         assert!(!form.is_valid().await);
     }
+
+    // ── RuniqueForm — clean_value() (règle métier sur la valeur) ────────────────
+
+    struct SlugForm {
+        form: Forms,
+    }
+
+    #[async_trait::async_trait]
+    impl RuniqueForm for SlugForm {
+        fn register_fields(form: &mut Forms) {
+            form.field(&TextField::text("slug").required());
+            form.field(&TextField::text("title"));
+        }
+        fn from_form(form: Forms) -> Self {
+            Self { form }
+        }
+        fn get_form(&self) -> &Forms {
+            &self.form
+        }
+        fn get_form_mut(&mut self) -> &mut Forms {
+            &mut self.form
+        }
+        fn clean_value(&self, name: &str, value: &str) -> Result<(), String> {
+            match name {
+                "slug" if value.contains(' ') => Err("Pas d'espace dans un slug".to_string()),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    fn slug_form(values: &[(&str, &str)]) -> SlugForm {
+        let mut form = SlugForm {
+            form: Forms::new("csrf"),
+        };
+        SlugForm::register_fields(&mut form.form);
+        let data: HashMap<String, String> = values
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        form.form.fill(&data, Method::POST);
+        form
+    }
+
+    #[tokio::test]
+    async fn test_clean_value_erreur_posee_sur_le_champ() {
+        let mut form = slug_form(&[("slug", "mon article"), ("title", "x")]);
+        assert!(!form.is_valid().await);
+
+        let errors = form.get_form().errors();
+        assert_eq!(
+            errors.get("slug").map(String::as_str),
+            Some("Pas d'espace dans un slug")
+        );
+        assert!(!errors.contains_key("title"));
+    }
+
+    #[tokio::test]
+    async fn test_clean_value_valeur_acceptee() {
+        let mut form = slug_form(&[("slug", "mon-article")]);
+        assert!(form.is_valid().await);
+    }
+
+    #[tokio::test]
+    async fn test_clean_value_garde_l_erreur_de_validation_du_champ() {
+        // Champ requis vide : l'erreur "required" reste, clean_value n'est pas appelé
+        let mut form = slug_form(&[("title", "x")]);
+        assert!(!form.is_valid().await);
+
+        let errors = form.get_form().errors();
+        assert_ne!(
+            errors.get("slug").map(String::as_str),
+            Some("Pas d'espace dans un slug")
+        );
+        assert!(errors.contains_key("slug"));
+    }
 }
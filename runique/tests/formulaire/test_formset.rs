@@ -0,0 +1,308 @@
+//! Tests — forms/formset.rs : FormSet (instances répétées d'un formulaire)
+//! Couvre : lignes vierges min + extra, préfixe `{prefix}-{i}-{champ}`, parsing via
+//!          TOTAL_FORMS, lignes vides ignorées, erreurs par ligne, bornes min / max,
+//!          données de gestion absentes, deux formsets sur une page, rendu,
+//!          Request::formset()
+
+use crate::helpers::{request::build_handler_req, server::build_engine};
+use axum::http::Method;
+use runique::forms::{
+    FormSet,
+    field::RuniqueForm,
+    fields::{number::NumericField, text::TextField},
+    form::Forms,
+};
+use runique::utils::aliases::StrMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tera::Tera;
+
+struct LineForm {
+    form: Forms,
+}
+
+impl RuniqueForm for LineForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&TextField::text("product").label("Produit").required());
+        form.field(&NumericField::integer("quantity").label("Quantité"));
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+fn formset_tera() -> Arc<Tera> {
+    let mut tera = Tera::default();
+    for name in ["base_string.html", "base_number.html", "base_hidden.html"] {
+        tera.add_raw_template(
+            name,
+            "<input name=\"{{ field.name }}\" value=\"{{ field.value }}\">",
+        )
+        .unwrap();
+    }
+    tera.add_raw_template(
+        "csrf.html",
+        "<input type=\"hidden\" name=\"csrf_token\" value=\"{{ field.value }}\">",
+    )
+    .unwrap();
+    tera.add_raw_template("js_files.html", "").unwrap();
+    tera.add_raw_template(
+        "base_formset.html",
+        include_str!("../../templates/field_html/base_formset.html"),
+    )
+    .unwrap();
+    tera.register_filter(
+        "runique_static",
+        |value: &tera::Value, _: &HashMap<String, tera::Value>| Ok(value.clone()),
+    );
+    Arc::new(tera)
+}
+
+fn lines(prefix: &str) -> FormSet<LineForm> {
+    FormSet::new(prefix, formset_tera(), "csrf")
+}
+
+fn post(pairs: &[(&str, &str)]) -> StrMap {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Lignes vierges
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_formset_lignes_vierges_min_plus_extra() {
+    let formset = lines("items").min(2).extra(1);
+    assert_eq!(formset.forms().count(), 3);
+    let third = formset.forms().nth(2).unwrap();
+    assert_eq!(third.get_form().fields["product"].name(), "items-2-product");
+    // Un seul jeton CSRF pour tout le formset
+    assert!(!third.get_form().fields.contains_key("csrf_token"));
+}
+
+#[test]
+fn test_formset_lignes_vierges_bornees_par_max() {
+    let formset = lines("items").extra(5).max(2);
+    assert_eq!(formset.forms().count(), 2);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Parsing et validation
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_formset_parse_et_cleaned() {
+    let mut formset = lines("items");
+    formset.fill(
+        &post(&[
+            ("items-TOTAL_FORMS", "2"),
+            ("items-0-product", "Clavier"),
+            ("items-0-quantity", "2"),
+            ("items-1-product", "Souris"),
+            ("items-1-quantity", "1"),
+        ]),
+        Method::POST,
+    );
+    assert!(formset.is_valid().await);
+    let rows = formset.cleaned();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(
+        rows[0].cleaned_string("product").as_deref(),
+        Some("Clavier")
+    );
+    assert_eq!(rows[1].cleaned_i32("quantity"), Some(1));
+}
+
+#[tokio::test]
+async fn test_formset_ligne_vide_ignoree() {
+    let mut formset = lines("items");
+    formset.fill(
+        &post(&[
+            ("items-TOTAL_FORMS", "3"),
+            ("items-0-product", "Clavier"),
+            ("items-1-product", ""),
+            ("items-2-product", "Écran"),
+        ]),
+        Method::POST,
+    );
+    assert!(formset.is_valid().await);
+    assert_eq!(formset.filled_count(), 2);
+    let products: Vec<String> = formset
+        .cleaned()
+        .iter()
+        .filter_map(|row| row.cleaned_string("product"))
+        .collect();
+    assert_eq!(products, ["Clavier", "Écran"]);
+}
+
+#[tokio::test]
+async fn test_formset_erreurs_par_ligne() {
+    let mut formset = lines("items");
+    formset.fill(
+        &post(&[
+            ("items-TOTAL_FORMS", "2"),
+            ("items-0-product", "Clavier"),
+            ("items-1-quantity", "3"),
+        ]),
+        Method::POST,
+    );
+    assert!(!formset.is_valid().await);
+    let errors = formset.row_errors();
+    assert!(errors[0].is_empty());
+    assert!(errors[1].contains_key("product"));
+    assert!(formset.errors.is_empty());
+}
+
+#[tokio::test]
+async fn test_formset_min_non_atteint() {
+    let mut formset = lines("items").min(2);
+    formset.fill(
+        &post(&[("items-TOTAL_FORMS", "2"), ("items-0-product", "Clavier")]),
+        Method::POST,
+    );
+    assert!(!formset.is_valid().await);
+    assert_eq!(formset.errors.len(), 1);
+    assert!(formset.errors[0].contains('2'));
+}
+
+#[tokio::test]
+async fn test_formset_max_depasse() {
+    let mut formset = lines("items").max(1);
+    formset.fill(
+        &post(&[
+            ("items-TOTAL_FORMS", "2"),
+            ("items-0-product", "Clavier"),
+            ("items-1-product", "Souris"),
+        ]),
+        Method::POST,
+    );
+    assert!(!formset.is_valid().await);
+    assert_eq!(formset.errors.len(), 1);
+}
+
+#[tokio::test]
+async fn test_formset_donnees_de_gestion_absentes() {
+    let mut formset = lines("items");
+    formset.fill(&post(&[("items-0-product", "Clavier")]), Method::POST);
+    assert!(!formset.is_valid().await);
+    assert_eq!(formset.forms().count(), 0);
+    assert_eq!(formset.errors.len(), 1);
+}
+
+#[tokio::test]
+async fn test_formset_get_sans_soumission_non_valide() {
+    let mut formset = lines("items").extra(2);
+    formset.fill(&StrMap::new(), Method::GET);
+    assert_eq!(formset.forms().count(), 2);
+    assert!(!formset.is_valid().await);
+    assert!(formset.errors.is_empty());
+}
+
+#[tokio::test]
+async fn test_formset_deux_prefixes_sur_une_page() {
+    let data = post(&[
+        ("items-TOTAL_FORMS", "1"),
+        ("items-0-product", "Clavier"),
+        ("gifts-TOTAL_FORMS", "1"),
+        ("gifts-0-product", "Stylo"),
+    ]);
+    let mut items = lines("items");
+    let mut gifts = lines("gifts");
+    items.fill(&data, Method::POST);
+    gifts.fill(&data, Method::POST);
+    assert!(items.is_valid().await && gifts.is_valid().await);
+    assert_eq!(
+        items.cleaned()[0].cleaned_string("product").as_deref(),
+        Some("Clavier")
+    );
+    assert_eq!(
+        gifts.cleaned()[0].cleaned_string("product").as_deref(),
+        Some("Stylo")
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Rendu
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_formset_rendu_gestion_lignes_et_modele() {
+    let html = lines("items").extra(2).max(5).render().unwrap();
+    assert!(html.contains(r#"data-formset="items" data-formset-max="5""#));
+    assert!(html.contains(r#"<input name="items-TOTAL_FORMS" value="2">"#));
+    assert!(html.contains(r#"<input name="items-MAX_NUM_FORMS" value="5">"#));
+    assert_eq!(html.matches(r#"name="csrf_token""#).count(), 1);
+    assert!(html.contains(r#"<input name="items-1-product" value="">"#));
+    assert!(html.contains(r#"<input name="items-__prefix__-product" value="">"#));
+    assert!(html.contains("data-formset-add"));
+    assert!(html.contains("formset.js"));
+}
+
+#[test]
+fn test_formset_serialise_noms_prefixes() {
+    let value = serde_json::to_value(lines("items")).unwrap();
+    assert_eq!(value["prefix"], "items");
+    assert_eq!(
+        value["forms"][0]["fields"]["product"]["name"],
+        "items-0-product"
+    );
+    assert!(
+        value["html"]
+            .as_str()
+            .unwrap()
+            .contains("items-TOTAL_FORMS")
+    );
+}
+
+#[test]
+fn test_forms_set_prefix_garde_les_cles() {
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::text("title"));
+    form.set_prefix("a-0");
+    assert_eq!(form.fields["title"].name(), "a-0-title");
+    assert_eq!(form.fields["csrf_token"].name(), "csrf_token");
+    // Idempotent : le préfixe remplace le précédent
+    form.set_prefix("a-1");
+    assert_eq!(form.fields["title"].name(), "a-1-title");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Request::formset()
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_request_formset_post() {
+    let engine = build_engine().await;
+    let req = build_handler_req(
+        engine,
+        None,
+        post(&[("items-TOTAL_FORMS", "1"), ("items-0-product", "Clavier")]),
+    )
+    .await;
+    let mut formset = req.formset::<LineForm>("items").min(1);
+    assert!(formset.is_valid().await);
+    assert_eq!(formset.cleaned().len(), 1);
+}
+
+#[tokio::test]
+async fn test_request_formset_honeypot_absent_rejete() {
+    let engine = build_engine().await;
+    let mut req = build_handler_req(
+        engine,
+        None,
+        post(&[("items-TOTAL_FORMS", "1"), ("items-0-product", "Clavier")]),
+    )
+    .await;
+    req.honeypot_field_name = Some("website".to_string());
+    let mut formset = req.formset::<LineForm>("items");
+    assert!(!formset.is_valid().await);
+}
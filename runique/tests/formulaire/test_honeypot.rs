@@ -0,0 +1,162 @@
+//! Tests — anti-spam honeypot
+//! Couvre : HoneypotField (doit rester vide, échec silencieux), Forms::is_spam(),
+//!          Request::form() avec le nom injecté par le middleware (rempli / absent / vide)
+
+use crate::helpers::{request::build_handler_req, server::build_engine};
+use runique::forms::{
+    base::FormField,
+    field::RuniqueForm,
+    fields::{HoneypotField, text::TextField},
+    form::Forms,
+};
+use runique::utils::aliases::StrMap;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+struct ContactForm {
+    form: Forms,
+}
+
+impl RuniqueForm for ContactForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&TextField::text("message").required());
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+fn form_with_trap(trap: &str) -> Forms {
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::text("message"));
+    form.field(&HoneypotField::new("website"));
+    form.add_value("message", "bonjour");
+    form.add_value("website", trap);
+    form
+}
+
+// ═══════════════════════════════════════════════════════════════
+// HoneypotField
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_honeypot_field_vide_valide() {
+    let mut field = HoneypotField::new("website");
+    assert!(field.is_honeypot());
+    assert!(field.validate());
+}
+
+#[test]
+fn test_honeypot_field_rempli_sans_message() {
+    let mut field = HoneypotField::new("website");
+    field.set_value("http://spam.example");
+    assert!(!field.validate());
+    assert!(field.error().is_none(), "aucun indice pour le bot");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Forms::is_spam
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_forms_piege_vide_pas_spam() {
+    let mut form = form_with_trap("");
+    assert!(!form.is_spam());
+    assert!(form.is_valid().unwrap());
+}
+
+#[test]
+fn test_forms_piege_rempli_spam_silencieux() {
+    let mut form = form_with_trap("http://spam.example");
+    assert!(form.is_spam());
+    assert!(!form.is_valid().unwrap());
+    assert!(form.errors.is_empty());
+    assert!(form.fields.values().all(|f| f.error().is_none()));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Request::form() — nom injecté par le middleware anti-bot
+// ═══════════════════════════════════════════════════════════════
+
+async fn submit(body: &[(&str, &str)]) -> ContactForm {
+    let data: StrMap = body
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let mut req = build_handler_req(build_engine().await, None, data).await;
+    req.honeypot_field_name = Some("a1b2c3d4e5f6a7b8".to_string());
+    req.form::<ContactForm>()
+}
+
+#[tokio::test]
+async fn test_request_piege_vide_accepte() {
+    let mut form = submit(&[("message", "bonjour"), ("a1b2c3d4e5f6a7b8", "")]).await;
+    assert!(!form.get_form().is_spam());
+    assert!(form.is_valid().await);
+}
+
+#[tokio::test]
+async fn test_request_piege_rempli_rejete() {
+    let mut form = submit(&[("message", "bonjour"), ("a1b2c3d4e5f6a7b8", "x")]).await;
+    assert!(form.get_form().is_spam());
+    assert!(!form.is_valid().await);
+}
+
+#[tokio::test]
+async fn test_request_piege_absent_rejete_malgre_csrf_valide() {
+    let (mut form, logs) = submit_logged(&[("message", "bonjour")]).await;
+    assert!(form.get_form().is_spam());
+    assert!(!form.is_valid().await);
+    assert!(form.get_form().errors.is_empty());
+    // Silencieux pour le visiteur, signalé au développeur
+    assert!(logs.contains("honeypot field absent"));
+}
+
+#[tokio::test]
+async fn test_request_piege_rempli_sans_avertissement() {
+    let (form, logs) = submit_logged(&[("message", "bonjour"), ("a1b2c3d4e5f6a7b8", "x")]).await;
+    assert!(form.get_form().is_spam());
+    assert!(!logs.contains("honeypot field absent"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Capture des logs de Request::form() (subscriber local au thread)
+// ═══════════════════════════════════════════════════════════════
+
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+async fn submit_logged(body: &[(&str, &str)]) -> (ContactForm, String) {
+    let data: StrMap = body
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let mut req = build_handler_req(build_engine().await, None, data).await;
+    req.honeypot_field_name = Some("a1b2c3d4e5f6a7b8".to_string());
+
+    let buffer = LogBuffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .finish();
+    let form = tracing::subscriber::with_default(subscriber, || req.form::<ContactForm>());
+    let logs = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    (form, logs)
+}
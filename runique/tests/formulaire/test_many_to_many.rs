@@ -0,0 +1,287 @@
+//! Tests — ManyToMany dans les formulaires générés depuis un ModelSchema
+//! Couvre : fill_form (select multiple / cases à cocher, fields / exclude),
+//!          choix chargés depuis la table cible, validation multiple,
+//!          save_many_to_many (ajout, retrait, sélection vide),
+//!          load_many_to_many (pré-remplissage d'un formulaire d'édition)
+
+use crate::helpers::db;
+use runique::forms::form::Forms;
+use runique::migration::column::ColumnDef;
+use runique::migration::primary_key::PrimaryKeyDef;
+use runique::migration::relation::{ManyToManyWidget, RelationDef};
+use runique::migration::schema::ModelSchema;
+use runique::sea_orm::{ConnectionTrait, DatabaseConnection, Statement, TransactionTrait};
+
+fn tags_relation() -> RelationDef {
+    RelationDef::many_to_many("tag", "article_tags")
+        .as_name("tags")
+        .label_column("name")
+}
+
+fn article_schema(rel: RelationDef) -> ModelSchema {
+    ModelSchema::new("Article")
+        .table_name("article")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .column(ColumnDef::new("title").string())
+        .relation(rel)
+}
+
+async fn db_with_tags() -> DatabaseConnection {
+    let conn = db::fresh_db_with_schema(
+        "CREATE TABLE tag (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL)",
+    )
+    .await;
+    db::exec(
+        &conn,
+        "CREATE TABLE article (id INTEGER PRIMARY KEY AUTOINCREMENT, title TEXT NOT NULL)",
+    )
+    .await;
+    db::exec(
+        &conn,
+        "CREATE TABLE article_tags (article_id INTEGER NOT NULL, tag_id INTEGER NOT NULL)",
+    )
+    .await;
+    db::exec(
+        &conn,
+        "INSERT INTO tag (name) VALUES ('web'), ('rust'), ('sql')",
+    )
+    .await;
+    db::exec(&conn, "INSERT INTO article (title) VALUES ('Hello')").await;
+    conn
+}
+
+async fn prepared_form(conn: &DatabaseConnection) -> Forms {
+    let mut form = Forms::new("csrf");
+    article_schema(tags_relation()).fill_form(&mut form, None, None);
+    form.prepare(conn).await.unwrap();
+    form
+}
+
+async fn tags_of(conn: &DatabaseConnection, article_id: i64) -> Vec<i64> {
+    let rows = conn
+        .query_all_raw(Statement::from_string(
+            conn.get_database_backend(),
+            format!(
+                "SELECT tag_id FROM article_tags WHERE article_id = {article_id} ORDER BY tag_id"
+            ),
+        ))
+        .await
+        .unwrap();
+    rows.iter()
+        .map(|row| row.try_get::<i64>("", "tag_id").unwrap())
+        .collect()
+}
+
+async fn save(conn: &DatabaseConnection, form: &Forms) {
+    let txn = conn.begin().await.unwrap();
+    article_schema(tags_relation())
+        .save_many_to_many(&txn, form, 1)
+        .await
+        .unwrap();
+    txn.commit().await.unwrap();
+}
+
+// ═══════════════════════════════════════════════════════════════
+// fill_form
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_fill_form_ajoute_un_select_multiple() {
+    let mut form = Forms::new("csrf");
+    article_schema(tags_relation()).fill_form(&mut form, None, None);
+    let field = &form.fields["tags"];
+    assert_eq!(field.field_type(), "select-multiple");
+    assert_eq!(field.label(), "Tags");
+    assert!(field.choice_source().is_some());
+}
+
+#[test]
+fn test_fill_form_cases_a_cocher() {
+    let mut form = Forms::new("csrf");
+    article_schema(tags_relation().widget(ManyToManyWidget::Checkboxes))
+        .fill_form(&mut form, None, None);
+    assert_eq!(form.fields["tags"].field_type(), "checkbox");
+}
+
+#[test]
+fn test_fill_form_nom_par_defaut_la_cible() {
+    let mut form = Forms::new("csrf");
+    article_schema(RelationDef::many_to_many("tag", "article_tags"))
+        .fill_form(&mut form, None, None);
+    assert!(form.fields.contains_key("tag"));
+}
+
+#[test]
+fn test_fill_form_exclude_retire_la_relation() {
+    let mut form = Forms::new("csrf");
+    article_schema(tags_relation()).fill_form(&mut form, None, Some(&["tags"]));
+    assert!(!form.fields.contains_key("tags"));
+    assert!(form.fields.contains_key("title"));
+}
+
+#[test]
+fn test_fill_form_whitelist_accepte_la_relation() {
+    let mut form = Forms::new("csrf");
+    article_schema(tags_relation()).fill_form(&mut form, Some(&["tags"]), None);
+    assert!(form.fields.contains_key("tags"));
+    assert!(!form.fields.contains_key("title"));
+}
+
+#[test]
+fn test_fill_form_ignore_les_autres_relations() {
+    let mut form = Forms::new("csrf");
+    article_schema(RelationDef::has_many("comment")).fill_form(&mut form, None, None);
+    assert!(!form.fields.contains_key("comment"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Choix et validation
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_choix_charges_depuis_la_cible_tries_par_libelle() {
+    let conn = db_with_tags().await;
+    let form = prepared_form(&conn).await;
+    let choices = form.fields["tags"].to_json_meta()["choices"].clone();
+    let labels: Vec<&str> = choices
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["label"].as_str().unwrap())
+        .collect();
+    assert_eq!(labels, ["rust", "sql", "web"]);
+    assert_eq!(choices[0]["value"], "2");
+}
+
+#[tokio::test]
+async fn test_selection_multiple_valide() {
+    let conn = db_with_tags().await;
+    let mut form = prepared_form(&conn).await;
+    form.add_value("title", "Hello");
+    form.add_value("tags", "1,3");
+    assert!(form.is_valid().is_ok());
+
+    let choices = form.fields["tags"].to_json_meta()["choices"].clone();
+    let selected: Vec<&str> = choices
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|c| c["selected"] == true)
+        .map(|c| c["value"].as_str().unwrap())
+        .collect();
+    assert_eq!(selected, ["3", "1"]);
+}
+
+#[tokio::test]
+async fn test_selection_multiple_id_inconnu_rejete() {
+    let conn = db_with_tags().await;
+    let mut form = prepared_form(&conn).await;
+    form.add_value("title", "Hello");
+    form.add_value("tags", "1,9");
+    assert!(form.is_valid().is_err());
+    assert!(form.fields["tags"].error().is_some());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// save_many_to_many
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_save_insere_la_selection() {
+    let conn = db_with_tags().await;
+    let mut form = prepared_form(&conn).await;
+    form.add_value("tags", "1,3");
+    save(&conn, &form).await;
+    assert_eq!(tags_of(&conn, 1).await, [1, 3]);
+}
+
+#[tokio::test]
+async fn test_save_ajoute_et_retire() {
+    let conn = db_with_tags().await;
+    db::exec(
+        &conn,
+        "INSERT INTO article_tags (article_id, tag_id) VALUES (1, 1), (1, 2)",
+    )
+    .await;
+    let mut form = prepared_form(&conn).await;
+    form.add_value("tags", "2,3");
+    save(&conn, &form).await;
+    assert_eq!(tags_of(&conn, 1).await, [2, 3]);
+}
+
+#[tokio::test]
+async fn test_save_selection_vide_retire_tout() {
+    let conn = db_with_tags().await;
+    db::exec(
+        &conn,
+        "INSERT INTO article_tags (article_id, tag_id) VALUES (1, 1), (1, 2), (2, 1)",
+    )
+    .await;
+    let form = prepared_form(&conn).await;
+    save(&conn, &form).await;
+    assert!(tags_of(&conn, 1).await.is_empty());
+    // Les associations des autres lignes sont intactes
+    assert_eq!(tags_of(&conn, 2).await, [1]);
+}
+
+#[tokio::test]
+async fn test_save_rollback_annule_la_synchronisation() {
+    let conn = db_with_tags().await;
+    let mut form = prepared_form(&conn).await;
+    form.add_value("tags", "1,2");
+    let txn = conn.begin().await.unwrap();
+    article_schema(tags_relation())
+        .save_many_to_many(&txn, &form, 1)
+        .await
+        .unwrap();
+    txn.rollback().await.unwrap();
+    assert!(tags_of(&conn, 1).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_save_colonnes_de_jonction_personnalisees() {
+    let conn = db_with_tags().await;
+    db::exec(
+        &conn,
+        "CREATE TABLE post_labels (post INTEGER NOT NULL, label INTEGER NOT NULL)",
+    )
+    .await;
+    let schema = article_schema(
+        RelationDef::many_to_many("Label", "post_labels")
+            .as_name("labels")
+            .target_table("tag")
+            .through("post", "label"),
+    );
+    let mut form = Forms::new("csrf");
+    schema.fill_form(&mut form, None, None);
+    form.prepare(&conn).await.unwrap();
+    form.add_value("labels", "2");
+
+    let txn = conn.begin().await.unwrap();
+    schema.save_many_to_many(&txn, &form, 1).await.unwrap();
+    txn.commit().await.unwrap();
+    assert_eq!(db::count(&conn, "post_labels").await, 1);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// load_many_to_many
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_load_preremplit_la_selection() {
+    let conn = db_with_tags().await;
+    db::exec(
+        &conn,
+        "INSERT INTO article_tags (article_id, tag_id) VALUES (1, 3), (1, 1)",
+    )
+    .await;
+    let mut form = prepared_form(&conn).await;
+    article_schema(tags_relation())
+        .load_many_to_many(&conn, &mut form, 1)
+        .await
+        .unwrap();
+
+    let mut ids: Vec<&str> = form.fields["tags"].value().split(',').collect();
+    ids.sort();
+    assert_eq!(ids, ["1", "3"]);
+}
@@ -0,0 +1,88 @@
+//! Tests — TextField::markdown (MarkdownField)
+//! Couvre : valeur Markdown brute conservée, validation, rendu en textarea,
+//!          crochet d'aperçu `with_preview`, rendu sûr via `render_markdown`
+
+use runique::forms::{base::FormField, fields::TextField, form::Forms};
+use runique::utils::constante::FIELD_TEMPLATES;
+use runique::utils::sanitizer::render_markdown;
+use std::sync::Arc;
+use tera::Tera;
+
+fn tera() -> Arc<Tera> {
+    let mut tera = Tera::default();
+    tera.add_raw_templates(FIELD_TEMPLATES.iter().copied())
+        .unwrap();
+    Arc::new(tera)
+}
+
+#[test]
+fn test_markdown_type_et_format() {
+    let field = TextField::markdown("body");
+    assert_eq!(field.base.type_field, "markdown");
+    assert_eq!(
+        field.format,
+        runique::forms::fields::SpecialFormat::Markdown
+    );
+}
+
+#[test]
+fn test_markdown_valeur_brute_conservee() {
+    let source = "Si `a < b` alors <kbd>Ctrl</kbd>\n\n```html\n<div>x</div>\n```";
+    let mut field = TextField::markdown("body");
+    field.set_value(source);
+    assert!(field.validate());
+    assert_eq!(field.value(), source.trim());
+}
+
+#[test]
+fn test_markdown_cleaned_est_le_markdown() {
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::markdown("body").required());
+    form.add_value("body", "# Titre\n\n<script>alert(1)</script>");
+    assert!(form.is_valid().is_ok());
+    assert_eq!(
+        form.fields["body"].value(),
+        "# Titre\n\n<script>alert(1)</script>"
+    );
+}
+
+#[test]
+fn test_markdown_requis_et_longueur() {
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::markdown("body").required().max_length(5, ""));
+    form.add_value("body", "trop long");
+    assert!(form.is_valid().is_err());
+    assert!(form.fields["body"].error().is_some());
+}
+
+#[test]
+fn test_markdown_stocke_reste_sur_au_rendu() {
+    let mut field = TextField::markdown("body");
+    field.set_value("[x](javascript:alert(1)) <img src=x onerror=alert(1)>");
+    let html = render_markdown(field.value());
+    assert!(!html.contains("javascript"));
+    assert!(!html.contains("onerror"));
+}
+
+#[test]
+fn test_markdown_rendu_textarea_echappe() {
+    let mut field = TextField::markdown("body").label("Article");
+    field.set_value("<script>alert(1)</script>");
+    let html = field.render(&tera()).unwrap();
+    assert!(html.contains("<textarea"));
+    assert!(html.contains("markdown-editor"));
+    assert!(!html.contains("<script>"));
+    assert!(html.contains("&lt;script&gt;"));
+    assert!(!html.contains("data-markdown-preview"));
+}
+
+#[test]
+fn test_markdown_with_preview() {
+    let html = TextField::markdown("body")
+        .with_preview()
+        .render(&tera())
+        .unwrap();
+    assert!(html.contains(r#"data-markdown-preview="id_body_preview""#));
+    assert!(html.contains(r#"id="id_body_preview""#));
+    assert!(html.contains(r#"data-markdown-preview-for="id_body""#));
+}
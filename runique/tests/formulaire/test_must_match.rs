@@ -0,0 +1,136 @@
+//! Tests — Forms::must_match(field, confirm, message) : champ de confirmation
+//! Couvre : erreur posée sur le champ de confirmation, vide == vide, champ
+//!          ajouté automatiquement (password, required), exclusion des cleaned_*,
+//!          message personnalisé
+
+use runique::forms::{field::RuniqueForm, fields::text::TextField, form::Forms};
+
+struct SignupForm {
+    form: Forms,
+}
+
+impl RuniqueForm for SignupForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&TextField::password("password").required().no_hash());
+        form.must_match("password", "password_confirm", "");
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+fn signup(password: &str, confirm: &str) -> SignupForm {
+    let mut form = SignupForm {
+        form: Forms::new("csrf"),
+    };
+    SignupForm::register_fields(&mut form.form);
+    form.get_form_mut().add_value("password", password);
+    form.get_form_mut().add_value("password_confirm", confirm);
+    form
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Validation
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_must_match_valeurs_identiques() {
+    let mut form = signup("secret123", "secret123");
+    assert!(form.is_valid().await);
+}
+
+#[tokio::test]
+async fn test_must_match_erreur_sur_la_confirmation() {
+    let mut form = signup("secret123", "secret124");
+    assert!(!form.is_valid().await);
+
+    let fields = &form.get_form().fields;
+    assert!(fields["password"].error().is_none());
+    assert!(fields["password_confirm"].error().is_some());
+}
+
+#[test]
+fn test_must_match_required_prioritaire() {
+    // Confirmation vide : l'erreur "required" reste, pas de message de correspondance
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::password("password").required().no_hash());
+    form.must_match("password", "password_confirm", "différent");
+    form.add_value("password", "secret123");
+
+    assert!(form.is_valid().is_err());
+    let err = form.fields["password_confirm"].error().unwrap();
+    assert_ne!(err, "différent");
+}
+
+#[test]
+fn test_must_match_vide_vide_correspond() {
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::text("code"));
+    form.must_match("code", "code_confirm", "");
+    form.add_value("code", "");
+    assert!(form.is_valid().is_ok());
+}
+
+#[test]
+fn test_must_match_message_personnalise() {
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::email("email"));
+    form.field(&TextField::email("email_confirm"));
+    form.must_match("email", "email_confirm", "Les adresses diffèrent");
+    form.add_value("email", "a@example.com");
+    form.add_value("email_confirm", "b@example.com");
+
+    assert!(form.is_valid().is_err());
+    assert_eq!(
+        form.fields["email_confirm"].error().unwrap(),
+        "Les adresses diffèrent"
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Champ de confirmation
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_must_match_ajoute_un_champ_password() {
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::password("password").required());
+    form.must_match("password", "password_confirm", "");
+
+    let confirm = &form.fields["password_confirm"];
+    assert_eq!(confirm.field_type(), "password");
+    assert!(confirm.required());
+}
+
+#[test]
+fn test_must_match_garde_un_champ_existant() {
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::text("code"));
+    form.field(&TextField::text("code_confirm").label("Répéter"));
+    form.must_match("code", "code_confirm", "");
+
+    assert_eq!(form.fields["code_confirm"].label(), "Répéter");
+    assert_eq!(form.fields["code_confirm"].field_type(), "text");
+}
+
+#[tokio::test]
+async fn test_must_match_confirmation_exclue_des_cleaned() {
+    let mut form = signup("secret123", "secret123");
+    assert!(form.is_valid().await);
+
+    assert_eq!(
+        form.cleaned_string("password").as_deref(),
+        Some("secret123")
+    );
+    assert!(form.cleaned_string("password_confirm").is_none());
+    assert_eq!(
+        form.get_form().fields["password_confirm"].value(),
+        "secret123"
+    );
+}
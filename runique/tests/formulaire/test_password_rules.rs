@@ -0,0 +1,102 @@
+//! Tests — règles de robustesse des mots de passe (PasswordRules)
+//! Couvre : require_uppercase / require_digit / require_symbol / reject_common,
+//!          message par règle, liste commune insensible à la casse,
+//!          exposition des exigences (password_requirements, meta)
+
+use runique::forms::{base::FormField, fields::text::TextField, options::PasswordRules};
+
+fn strong() -> TextField {
+    TextField::password("password")
+        .min_length(8, "")
+        .require_uppercase()
+        .require_digit()
+        .require_symbol()
+        .reject_common()
+}
+
+fn check(field: &TextField, value: &str) -> Option<String> {
+    let mut field = field.clone();
+    field.set_value(value);
+    field.validate();
+    field.error().cloned()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Validation
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_password_rules_valeur_conforme() {
+    assert_eq!(check(&strong(), "Correct-horse7"), None);
+}
+
+#[test]
+fn test_password_rules_un_message_par_regle() {
+    let field = strong();
+    let no_upper = check(&field, "correct-horse7").unwrap();
+    let no_digit = check(&field, "Correct-horse").unwrap();
+    let no_symbol = check(&field, "Correcthorse7").unwrap();
+
+    assert_ne!(no_upper, no_digit);
+    assert_ne!(no_digit, no_symbol);
+    assert_ne!(no_upper, no_symbol);
+}
+
+#[test]
+fn test_password_rules_min_length_avant_les_regles() {
+    let field = strong();
+    let short = check(&field, "A-1").unwrap();
+    assert_ne!(Some(short), check(&field, "correct-horse7"));
+}
+
+#[test]
+fn test_password_rules_reject_common_insensible_a_la_casse() {
+    let field = TextField::password("password").reject_common();
+    assert!(check(&field, "Password123").is_some());
+    assert!(check(&field, "AZERTY").is_some());
+    assert!(check(&field, "  qwerty  ").is_some());
+    assert!(check(&field, "pas-dans-la-liste").is_none());
+}
+
+#[test]
+fn test_password_rules_champ_vide_non_requis() {
+    let field = TextField::password("password").require_digit();
+    assert!(check(&field, "").is_none());
+}
+
+#[test]
+fn test_password_rules_sans_regle() {
+    assert!(PasswordRules::default().is_empty());
+    assert!(PasswordRules::default().check("a").is_none());
+    assert!(check(&TextField::password("password"), "password").is_none());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Exigences exposées au template
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_password_requirements_liste() {
+    assert_eq!(strong().password_requirements().len(), 5);
+    assert_eq!(
+        TextField::password("password")
+            .require_digit()
+            .password_requirements()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_password_requirements_vide_sans_regle() {
+    let field = TextField::password("password").min_length(8, "");
+    assert!(field.password_requirements().is_empty());
+    assert_eq!(field.to_json_meta(), serde_json::json!({}));
+}
+
+#[test]
+fn test_password_requirements_dans_meta() {
+    let meta = strong().to_json_meta();
+    let reqs = meta["requirements"].as_array().unwrap();
+    assert_eq!(reqs.len(), 5);
+}
@@ -28,7 +28,7 @@ async fn full_inject(
     let config = Arc::new(engine.config.clone());
     RequestExtensions::new()
         .with_engine(engine.clone())
-        .with_tera(engine.tera.clone())
+        .with_tera(engine.tera.current())
         .with_config(config)
         .inject_request(&mut req);
     next.run(req).await
@@ -42,7 +42,7 @@ async fn no_config_inject(
     // Omet ARuniqueConfig intentionnellement — prisme_pipeline échoue
     RequestExtensions::new()
         .with_engine(engine.clone())
-        .with_tera(engine.tera.clone())
+        .with_tera(engine.tera.current())
         .inject_request(&mut req);
     next.run(req).await
 }
@@ -0,0 +1,193 @@
+//! Tests — SlugField::from(source) : slug dérivé d'un autre champ
+//! Couvre : remplissage quand vide, valeur saisie conservée, max_length,
+//!          suffixes -2/-3 avec unique_in (une requête, premier libre, borne),
+//!          slug saisi en collision rejeté
+
+use crate::helpers::db;
+use runique::forms::{
+    field::RuniqueForm,
+    fields::{special::SlugField, text::TextField},
+    form::Forms,
+    options::SlugSource,
+};
+
+mod article {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "article")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub slug: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+const SCHEMA: &str =
+    "CREATE TABLE article (id INTEGER PRIMARY KEY AUTOINCREMENT, slug TEXT NOT NULL)";
+
+struct ArticleForm {
+    form: Forms,
+}
+
+impl RuniqueForm for ArticleForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(&TextField::text("title").required());
+        form.field(
+            &SlugField::new("slug")
+                .from("title")
+                .max_length(20)
+                .required()
+                .unique_in::<article::Entity>(article::Column::Slug, ""),
+        );
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+fn article(title: &str, slug: &str) -> ArticleForm {
+    let mut form = ArticleForm {
+        form: Forms::new("csrf"),
+    };
+    ArticleForm::register_fields(&mut form.form);
+    form.get_form_mut().add_value("title", title);
+    form.get_form_mut().add_value("slug", slug);
+    form
+}
+
+fn slug_of(form: &ArticleForm) -> String {
+    form.get_form().fields["slug"].value().to_string()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Dérivation (base vide : unique_in exige is_valid_with_db)
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_slug_derive_du_titre() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    let mut form = article("Été à Paris", "");
+    assert!(form.is_valid_with_db(&conn).await);
+    assert_eq!(slug_of(&form), "ete-a-paris");
+}
+
+#[tokio::test]
+async fn test_slug_saisi_conserve() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    let mut form = article("Été à Paris", "mon-slug");
+    assert!(form.is_valid_with_db(&conn).await);
+    assert_eq!(slug_of(&form), "mon-slug");
+}
+
+#[tokio::test]
+async fn test_slug_derive_respecte_max_length() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    let mut form = article("Un titre beaucoup trop long pour un slug", "");
+    assert!(form.is_valid_with_db(&conn).await);
+    assert_eq!(slug_of(&form), "un-titre-beaucoup");
+}
+
+#[tokio::test]
+async fn test_slug_saisi_trop_long_rejete() {
+    let mut form = article("Titre", "un-slug-saisi-bien-trop-long");
+    assert!(!form.is_valid().await);
+    assert!(form.get_form().fields["slug"].error().is_some());
+}
+
+#[test]
+fn test_max_length_avant_from() {
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::text("title"));
+    form.field(&SlugField::new("slug").max_length(5).from("title"));
+    form.add_value("title", "Bonjour le monde");
+    assert!(form.is_valid().unwrap());
+    assert_eq!(form.fields["slug"].value(), "bonjo");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Collisions (unique_in)
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_slug_derive_suffixe_en_collision() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    db::exec(
+        &conn,
+        "INSERT INTO article (slug) VALUES ('hello-world'), ('hello-world-2')",
+    )
+    .await;
+    let mut form = article("Hello World", "");
+    assert!(form.is_valid_with_db(&conn).await);
+    assert_eq!(slug_of(&form), "hello-world-3");
+}
+
+#[tokio::test]
+async fn test_slug_derive_premier_suffixe_libre() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    db::exec(
+        &conn,
+        "INSERT INTO article (slug) VALUES ('hello-world'), ('hello-world-3'), ('hello-worlds')",
+    )
+    .await;
+    let mut form = article("Hello World", "");
+    assert!(form.is_valid_with_db(&conn).await);
+    assert_eq!(slug_of(&form), "hello-world-2");
+}
+
+#[tokio::test]
+async fn test_slug_derive_suffixes_epuises_rejete() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    db::exec(
+        &conn,
+        "WITH RECURSIVE n(i) AS (SELECT 2 UNION ALL SELECT i + 1 FROM n WHERE i < 1000) \
+         INSERT INTO article (slug) SELECT 'ab' UNION ALL SELECT 'ab-' || i FROM n",
+    )
+    .await;
+    let mut form = article("Ab", "");
+    assert!(!form.is_valid_with_db(&conn).await);
+    assert!(form.get_form().fields["slug"].error().is_some());
+}
+
+#[test]
+fn test_slug_candidats_prefixe_commun() {
+    let source = SlugSource::new("title", Some(12));
+    let (prefix, candidates) = source.candidates("Un titre assez long", 1000);
+    assert_eq!(candidates.len(), 1000);
+    assert_eq!(candidates[0], "un-titre");
+    assert_eq!(candidates[1], "un-titre-2");
+    assert!(candidates.iter().all(|c| c.starts_with(&prefix)));
+}
+
+#[tokio::test]
+async fn test_slug_suffixe_dans_max_length() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    db::exec(
+        &conn,
+        "INSERT INTO article (slug) VALUES ('un-titre-beaucoup')",
+    )
+    .await;
+    let mut form = article("Un titre beaucoup trop long pour un slug", "");
+    assert!(form.is_valid_with_db(&conn).await);
+    assert_eq!(slug_of(&form), "un-titre-beaucoup-2");
+}
+
+#[tokio::test]
+async fn test_slug_saisi_en_collision_rejete() {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    db::exec(&conn, "INSERT INTO article (slug) VALUES ('hello-world')").await;
+    let mut form = article("Hello World", "hello-world");
+    assert!(!form.is_valid_with_db(&conn).await);
+    assert!(form.get_form().fields["slug"].error().is_some());
+}
@@ -0,0 +1,182 @@
+//! Tests — unique_in::<Entity>(column) : validation asynchrone d'unicité en base
+//! Couvre : Forms::check_unique(), RuniqueForm::is_valid_with_db(), message
+//!          personnalisé, champ vide optionnel ignoré, is_valid() sans DB refusé,
+//!          exclude_pk pour les formulaires d'édition
+
+use crate::helpers::db;
+use runique::forms::{
+    field::RuniqueForm, fields::text::TextField, form::Forms, generic::GenericField,
+};
+
+mod member {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "member")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub email: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+const SCHEMA: &str =
+    "CREATE TABLE member (id INTEGER PRIMARY KEY AUTOINCREMENT, email TEXT NOT NULL)";
+
+async fn db_with_member() -> sea_orm::DatabaseConnection {
+    let conn = db::fresh_db_with_schema(SCHEMA).await;
+    db::exec(
+        &conn,
+        "INSERT INTO member (email) VALUES ('taken@example.com')",
+    )
+    .await;
+    conn
+}
+
+struct SignupForm {
+    form: Forms,
+}
+
+impl RuniqueForm for SignupForm {
+    fn register_fields(form: &mut Forms) {
+        form.field(
+            &TextField::email("email")
+                .required()
+                .unique_in::<member::Entity>(member::Column::Email, ""),
+        );
+        form.field(
+            &TextField::text("backup_email")
+                .unique_in::<member::Entity>(member::Column::Email, "Adresse déjà utilisée"),
+        );
+    }
+    fn from_form(form: Forms) -> Self {
+        Self { form }
+    }
+    fn get_form(&self) -> &Forms {
+        &self.form
+    }
+    fn get_form_mut(&mut self) -> &mut Forms {
+        &mut self.form
+    }
+}
+
+fn signup(email: &str, backup: &str) -> SignupForm {
+    let mut form = SignupForm {
+        form: Forms::new("csrf"),
+    };
+    SignupForm::register_fields(&mut form.form);
+    form.get_form_mut().add_value("email", email);
+    form.get_form_mut().add_value("backup_email", backup);
+    form
+}
+
+// ═══════════════════════════════════════════════════════════════
+// RuniqueForm::is_valid_with_db
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_unique_in_valeur_libre() {
+    let conn = db_with_member().await;
+    let mut form = signup("free@example.com", "");
+    assert!(form.is_valid_with_db(&conn).await);
+}
+
+#[tokio::test]
+async fn test_unique_in_valeur_deja_prise() {
+    let conn = db_with_member().await;
+    let mut form = signup("taken@example.com", "");
+    assert!(!form.is_valid_with_db(&conn).await);
+    let err = form.get_form().fields["email"].error().cloned().unwrap();
+    assert!(err.contains("email"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_unique_in_message_personnalise() {
+    let conn = db_with_member().await;
+    let mut form = signup("free@example.com", "taken@example.com");
+    assert!(!form.is_valid_with_db(&conn).await);
+    assert_eq!(
+        form.get_form().fields["backup_email"].error().unwrap(),
+        "Adresse déjà utilisée"
+    );
+}
+
+#[tokio::test]
+async fn test_unique_in_refuse_sans_db() {
+    // is_valid() sans connexion : la règle ne peut pas être évaluée → refus explicite
+    let mut form = signup("free@example.com", "");
+    assert!(!form.is_valid().await);
+    assert_eq!(form.get_form().pending_db_checks(), vec!["email"]);
+    assert_eq!(form.get_form().errors.len(), 1);
+    assert!(
+        form.get_form().errors[0].contains("is_valid_with_db"),
+        "{}",
+        form.get_form().errors[0]
+    );
+}
+
+#[tokio::test]
+async fn test_unique_in_vide_sans_db_accepte() {
+    // Champ unique vide et optionnel : rien à vérifier en base
+    let mut form = SignupForm {
+        form: Forms::new("csrf"),
+    };
+    form.get_form_mut()
+        .field(&TextField::text("nickname").unique_in::<member::Entity>(member::Column::Email, ""));
+    form.get_form_mut().add_value("nickname", "");
+    assert!(form.get_form().pending_db_checks().is_empty());
+}
+
+#[tokio::test]
+async fn test_unique_in_exclude_pk_edition() {
+    let conn = db_with_member().await;
+    // Édition du membre 1 sans changer son email
+    let mut form = signup("taken@example.com", "");
+    form.get_form_mut().exclude_pk(1);
+    assert!(form.is_valid_with_db(&conn).await);
+
+    // Un autre membre ne peut pas reprendre cet email
+    let mut form = signup("taken@example.com", "");
+    form.get_form_mut().exclude_pk(2);
+    assert!(!form.is_valid_with_db(&conn).await);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Forms::check_unique
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_check_unique_champ_vide_optionnel_ignore() {
+    let conn = db_with_member().await;
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::text("email").unique_in::<member::Entity>(member::Column::Email, ""));
+    assert!(form.check_unique(&conn).await);
+    assert!(form.fields["email"].error().is_none());
+}
+
+#[tokio::test]
+async fn test_check_unique_generic_field() {
+    let conn = db_with_member().await;
+    let mut form = Forms::new("csrf");
+    let field: GenericField = TextField::email("email").into();
+    form.field_generic(field.unique_in::<member::Entity>(member::Column::Email, "pris"));
+    form.add_value("email", "taken@example.com");
+    assert!(!form.check_unique(&conn).await);
+    assert_eq!(form.fields["email"].error().unwrap(), "pris");
+}
+
+#[tokio::test]
+async fn test_check_unique_erreur_db_remontee() {
+    // Table absente → erreur DB ajoutée aux erreurs globales
+    let conn = db::fresh_db().await;
+    let mut form = Forms::new("csrf");
+    form.field(&TextField::text("email").unique_in::<member::Entity>(member::Column::Email, ""));
+    form.add_value("email", "x@example.com");
+    assert!(!form.check_unique(&conn).await);
+    assert_eq!(form.errors.len(), 1);
+}
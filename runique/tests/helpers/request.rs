@@ -105,9 +105,7 @@ pub async fn build_handler_req(
 
     HandlerReq {
         engine,
-        notices: Message {
-            session: session.clone(),
-        },
+        notices: Message::new(session.clone(), Default::default()),
         session,
         csrf_token: CsrfToken("test-csrf-token".to_string()),
         context,
@@ -119,6 +117,9 @@ pub async fn build_handler_req(
         prisme: Prisme::for_test(body, true),
         headers: Default::default(),
         honeypot_field_name: None,
+        client_ip: None,
+        locale: "en".to_string(),
+        request_id: None,
     }
 }
 
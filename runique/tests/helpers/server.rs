@@ -20,6 +20,7 @@ use axum::{
     routing::{delete, get, post},
 };
 use runique::{
+    app::LiveTera,
     config::app::RuniqueConfig,
     engine::RuniqueEngine,
     middleware::{
@@ -96,14 +97,20 @@ async fn build_engine_cfg(enforce_https: bool) -> Arc<RuniqueEngine> {
 
     Arc::new(RuniqueEngine {
         config,
-        tera: Arc::new(Tera::default()),
+        tera: LiveTera::new(Tera::default()),
         db: Arc::new(db),
+        tasks: Default::default(),
+        scheduler: Default::default(),
+        cache: Default::default(),
         url_registry: Arc::new(std::sync::RwLock::new(std::collections::HashMap::new())),
+        i18n: Arc::new(runique::utils::trad::Catalogs::default()),
         features: MiddlewareConfig::default(),
         security_csp: Arc::new(SecurityPolicy::default()),
         security_hosts: Arc::new(HostPolicy::new(vec![], true)),
         csrf_exempt_paths: Arc::new(vec![]),
+        trusted_origins: Arc::new(vec![]),
         permissions_policy: Arc::new(runique::middleware::PermissionsPolicy::default()),
+        security_headers: Arc::new(runique::middleware::SecurityHeaders::default()),
         trusted_proxies: Arc::new(runique::middleware::TrustedProxies::default()),
         session_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
         session_db_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
@@ -0,0 +1,147 @@
+//! Utilisateurs fictifs partagés par les tests d'auth : `MockUser` + `MockEntity`.
+//!
+//! `MockEntity` résout les utilisateurs enregistrés avec [`MockUser::save`]. Le
+//! registre est global au binaire de test : chaque test utilise ses propres ids.
+
+use runique::auth::session::UserEntity;
+use runique::auth::user_trait::RuniqueUser;
+use runique::utils::pk::Pk;
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+static USERS: LazyLock<Mutex<HashMap<Pk, MockUser>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Pas TOTP enregistrés par `MockEntity::set_totp_last_step`
+static TOTP_STEPS: Mutex<Vec<(Pk, u64)>> = Mutex::new(Vec::new());
+
+#[derive(Clone, Debug)]
+pub struct MockUser {
+    pub id: Pk,
+    pub username: String,
+    pub active: bool,
+    pub hash: String,
+    pub totp_secret: Option<String>,
+    pub totp_last_step: Option<u64>,
+}
+
+impl MockUser {
+    /// Compte actif, sans 2FA ni hash.
+    pub fn new(id: Pk, username: &str) -> Self {
+        Self {
+            id,
+            username: username.to_string(),
+            active: true,
+            hash: String::new(),
+            totp_secret: None,
+            totp_last_step: None,
+        }
+    }
+
+    pub fn inactive(mut self) -> Self {
+        self.active = false;
+        self
+    }
+
+    pub fn with_hash(mut self, hash: &str) -> Self {
+        self.hash = hash.to_string();
+        self
+    }
+
+    pub fn with_totp(mut self, secret: &str) -> Self {
+        self.totp_secret = Some(secret.to_string());
+        self
+    }
+
+    pub fn with_totp_last_step(mut self, step: u64) -> Self {
+        self.totp_last_step = Some(step);
+        self
+    }
+
+    /// Enregistre (ou remplace) l'utilisateur dans le registre de `MockEntity`.
+    pub fn save(self) -> Pk {
+        let id = self.id;
+        USERS.lock().unwrap().insert(id, self);
+        id
+    }
+}
+
+/// Change le hash d'un utilisateur enregistré (mot de passe changé ailleurs).
+pub fn set_password_hash(id: Pk, hash: &str) {
+    if let Some(user) = USERS.lock().unwrap().get_mut(&id) {
+        user.hash = hash.to_string();
+    }
+}
+
+/// Pas TOTP persistés pour `id`, dans l'ordre.
+pub fn persisted_totp_steps(id: Pk) -> Vec<u64> {
+    TOTP_STEPS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(user_id, _)| *user_id == id)
+        .map(|(_, step)| *step)
+        .collect()
+}
+
+impl RuniqueUser for MockUser {
+    fn user_id(&self) -> Pk {
+        self.id
+    }
+    fn username(&self) -> &str {
+        &self.username
+    }
+    fn email(&self) -> &str {
+        "mock@example.com"
+    }
+    fn password_hash(&self) -> &str {
+        &self.hash
+    }
+    fn is_active(&self) -> bool {
+        self.active
+    }
+    fn is_staff(&self) -> bool {
+        false
+    }
+    fn is_superuser(&self) -> bool {
+        false
+    }
+    fn totp_secret(&self) -> Option<&str> {
+        self.totp_secret.as_deref()
+    }
+    fn totp_last_step(&self) -> Option<u64> {
+        self.totp_last_step
+    }
+}
+
+pub struct MockEntity;
+
+#[async_trait::async_trait]
+impl UserEntity for MockEntity {
+    type Model = MockUser;
+
+    async fn find_by_id(_db: &DatabaseConnection, id: Pk) -> Option<MockUser> {
+        USERS.lock().unwrap().get(&id).cloned()
+    }
+    async fn find_by_username(_db: &DatabaseConnection, _username: &str) -> Option<MockUser> {
+        None
+    }
+    async fn find_by_email(_db: &DatabaseConnection, _email: &str) -> Option<MockUser> {
+        None
+    }
+    async fn update_password(
+        _db: &DatabaseConnection,
+        _email: &str,
+        _new_hash: &str,
+    ) -> Result<(), sea_orm::DbErr> {
+        Ok(())
+    }
+    async fn set_totp_last_step(
+        _db: &DatabaseConnection,
+        id: Pk,
+        step: u64,
+    ) -> Result<(), sea_orm::DbErr> {
+        TOTP_STEPS.lock().unwrap().push((id, step));
+        Ok(())
+    }
+}
@@ -4,7 +4,9 @@
 //! | ------------------------ | -------------------------------------------- |
 //! | `test_context_helper`    | ContextHelper : add, update, deref           |
 //! | `test_register_url`      | register_pending, reverse, reverse_with_params |
+//! | `test_route_group`       | group() : préfixes, middlewares, noms, contexte |
 
 pub mod test_context_helper;
 pub mod test_register_url;
+pub mod test_route_group;
 pub mod test_router_ext;
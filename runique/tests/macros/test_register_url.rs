@@ -1,7 +1,11 @@
 //! Tests — macros/routeur/register_url.rs
-//! Couvre : register_pending, PENDING_URLS drain, reverse, reverse_with_parameters
+//! Couvre : register_pending, PENDING_URLS drain, reverse, reverse_with_parameters,
+//!          reverse_with_query, urlpatterns! (namespace, noms en double),
+//!          segments optionnels (route_variants, reverse)
 
-use runique::macros::routeur::register_url::{register_pending, reverse, reverse_with_parameters};
+use runique::macros::routeur::register_url::{
+    register_pending, reverse, reverse_with_parameters, reverse_with_query, route_variants,
+};
 use runique::{config::app::RuniqueConfig, engine::RuniqueEngine};
 use sea_orm::Database;
 use std::sync::Arc;
@@ -95,3 +99,168 @@ async fn test_reverse_with_parameters_sans_substitution() {
     let url = reverse_with_parameters(&engine, "static_url", &[]);
     assert_eq!(url, Some("/about/".to_string()));
 }
+
+#[tokio::test]
+async fn test_reverse_with_parameters_encode_les_valeurs() {
+    use runique::macros::routeur::register_url::register_name_url;
+    let engine = make_engine().await;
+    register_name_url(&engine, "article", "/articles/{slug}/");
+    let url = reverse_with_parameters(&engine, "article", &[("slug", "mon article/été")]);
+    assert_eq!(
+        url,
+        Some("/articles/mon%20article%2F%C3%A9t%C3%A9/".to_string())
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// reverse_with_query
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_reverse_with_query_sans_parametre_de_chemin() {
+    use runique::macros::routeur::register_url::register_name_url;
+    let engine = make_engine().await;
+    register_name_url(&engine, "list", "/articles");
+    let url = reverse_with_query(&engine, "list", &[], &[("sort", "name"), ("page", "2")]);
+    // L'ordre donné est conservé
+    assert_eq!(url, Some("/articles?sort=name&page=2".to_string()));
+}
+
+#[tokio::test]
+async fn test_reverse_with_query_avec_parametres_et_encodage() {
+    use runique::macros::routeur::register_url::register_name_url;
+    let engine = make_engine().await;
+    register_name_url(&engine, "posts", "/users/{id}/posts");
+    let url = reverse_with_query(
+        &engine,
+        "posts",
+        &[("id", "5")],
+        &[("q", "rust & tera"), ("tag", "a=b")],
+    );
+    assert_eq!(
+        url,
+        Some("/users/5/posts?q=rust%20%26%20tera&tag=a%3Db".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_reverse_with_query_vide_et_url_inexistante() {
+    use runique::macros::routeur::register_url::register_name_url;
+    let engine = make_engine().await;
+    register_name_url(&engine, "home", "/");
+    assert_eq!(
+        reverse_with_query(&engine, "home", &[], &[]),
+        Some("/".to_string())
+    );
+    assert!(reverse_with_query(&engine, "inexistant", &[], &[("page", "1")]).is_none());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// urlpatterns! — namespace et noms en double
+// ═══════════════════════════════════════════════════════════════
+
+async fn ok() -> &'static str {
+    "ok"
+}
+
+#[tokio::test]
+async fn test_urlpatterns_namespace_route_les_requetes() {
+    use axum::{body::Body, http::Request, routing::get};
+    use tower::ServiceExt;
+
+    let router = runique::urlpatterns! {
+        namespace = "ns_blog",
+        "/blog/{slug}" => get(ok), name = "detail",
+        "/blog" => get(ok), name = "list",
+    };
+    let res = router
+        .oneshot(Request::get("/blog/bonjour").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(res.status(), 200);
+}
+
+#[test]
+fn test_urlpatterns_meme_nom_dans_deux_namespaces() {
+    use axum::routing::get;
+    let _: axum::Router =
+        runique::urlpatterns! { namespace = "ns_a", "/a" => get(ok), name = "detail" };
+    let _: axum::Router =
+        runique::urlpatterns! { namespace = "ns_b", "/b" => get(ok), name = "detail" };
+}
+
+#[test]
+#[should_panic(expected = "route name `ns_shop:detail` is declared twice")]
+fn test_urlpatterns_nom_en_double_dans_un_namespace_panique() {
+    use axum::routing::get;
+    let _: axum::Router = runique::urlpatterns! {
+        namespace = "ns_shop",
+        "/shop/{id}" => get(ok), name = "detail",
+        "/shop/{id}/bis" => get(ok), name = "detail",
+    };
+}
+
+#[test]
+#[should_panic(expected = "route name `dup_top` is declared twice")]
+fn test_urlpatterns_nom_en_double_sans_namespace_panique() {
+    use axum::routing::get;
+    let _: axum::Router = runique::urlpatterns! {
+        "/x" => get(ok), name = "dup_top",
+        "/y" => get(ok), name = "dup_top",
+    };
+}
+
+#[tokio::test]
+async fn test_reverse_nom_namespace() {
+    use runique::macros::routeur::register_url::register_name_url;
+    let engine = make_engine().await;
+    register_name_url(&engine, "blog:detail", "/blog/{slug}");
+    register_name_url(&engine, "detail", "/detail");
+    assert_eq!(
+        reverse_with_parameters(&engine, "blog:detail", &[("slug", "hello")]),
+        Some("/blog/hello".to_string())
+    );
+    assert_eq!(reverse(&engine, "detail"), Some("/detail".to_string()));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Segments optionnels
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_route_variants() {
+    assert_eq!(route_variants("/articles/{id}"), vec!["/articles/{id}"]);
+    assert_eq!(
+        route_variants("/archives/{year}/{month?}"),
+        vec!["/archives/{year}", "/archives/{year}/{month}"]
+    );
+    assert_eq!(
+        route_variants("/{year?}/{month?}"),
+        vec!["/", "/{year}", "/{year}/{month}"]
+    );
+}
+
+#[test]
+#[should_panic(expected = "`{slug}` follows an optional segment")]
+fn test_route_variants_segment_obligatoire_apres_optionnel() {
+    route_variants("/archives/{year?}/{slug}");
+}
+
+#[tokio::test]
+async fn test_reverse_with_parameters_segment_optionnel() {
+    use runique::macros::routeur::register_url::register_name_url;
+    let engine = make_engine().await;
+    register_name_url(&engine, "archives", "/archives/{year}/{month?}");
+    assert_eq!(
+        reverse_with_parameters(&engine, "archives", &[("year", "2024"), ("month", "5")]),
+        Some("/archives/2024/5".to_string())
+    );
+    assert_eq!(
+        reverse_with_parameters(&engine, "archives", &[("year", "2024")]),
+        Some("/archives/2024".to_string())
+    );
+    assert_eq!(
+        reverse_with_query(&engine, "archives", &[("year", "2024")], &[("page", "2")]),
+        Some("/archives/2024?page=2".to_string())
+    );
+}
@@ -0,0 +1,201 @@
+//! Tests — macros/routeur/group.rs : groupes de routes
+//! Couvre : préfixe et groupes imbriqués, ordre des middlewares, 404 hors route,
+//!          noms préfixés pour reverse, login_required, variables de template
+
+use axum::{
+    Router,
+    body::Body,
+    http::{HeaderValue, Request, StatusCode, header},
+    middleware::{Next, from_fn},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use runique::app::RuniqueApp;
+use runique::config::RuniqueConfig;
+use runique::context::Request as RuniqueRequest;
+use runique::macros::{RouteGroup, group, reverse, reverse_with_parameters};
+use runique::testing::test_db;
+use runique::urlpatterns;
+use tower::ServiceExt;
+
+/// Ajoute `etiquette` à l'en-tête `x-trace`, renvoyé par `echo_trace`.
+async fn trace(etiquette: &'static str, mut req: Request<Body>, next: Next) -> Response {
+    let trace = match req.headers().get("x-trace") {
+        Some(v) => format!("{},{etiquette}", v.to_str().unwrap()),
+        None => etiquette.to_string(),
+    };
+    req.headers_mut()
+        .insert("x-trace", HeaderValue::from_str(&trace).unwrap());
+    next.run(req).await
+}
+
+async fn echo_trace(req: Request<Body>) -> String {
+    req.headers()
+        .get("x-trace")
+        .map(|v| v.to_str().unwrap().to_string())
+        .unwrap_or_default()
+}
+
+async fn requete(router: &Router, uri: &str) -> (StatusCode, Option<String>, String) {
+    let res = router
+        .clone()
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = res.status();
+    let location = res
+        .headers()
+        .get(header::LOCATION)
+        .map(|v| v.to_str().unwrap().to_string());
+    let bytes = axum::body::to_bytes(res.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        location,
+        String::from_utf8_lossy(&bytes).to_string(),
+    )
+}
+
+fn groupes() -> RouteGroup {
+    group("/gr-app")
+        .middleware(from_fn(|req, next| trace("ext1", req, next)))
+        .middleware(from_fn(|req, next| trace("ext2", req, next)))
+        .routes(|| Router::new().route("/", get(echo_trace)))
+        .group(
+            group("gr-reglages/")
+                .middleware(from_fn(|req, next| trace("int", req, next)))
+                .routes(|| Router::new().route("/{id}", get(echo_trace))),
+        )
+}
+
+#[tokio::test]
+async fn test_prefixes_et_middlewares_cumules() {
+    let router: Router = groupes().into();
+
+    let (status, _, body) = requete(&router, "/gr-app").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "ext1,ext2");
+
+    let (status, _, body) = requete(&router, "/gr-app/gr-reglages/7").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "ext1,ext2,int");
+}
+
+#[tokio::test]
+async fn test_chemin_inconnu_sous_le_prefixe_reste_404() {
+    let router: Router = group("/gr-prive")
+        .middleware(from_fn(|_: Request<Body>, _: Next| async {
+            StatusCode::FORBIDDEN.into_response()
+        }))
+        .routes(|| Router::new().route("/page", get(|| async { "page" })))
+        .into();
+    assert_eq!(
+        requete(&router, "/gr-prive/page").await.0,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        requete(&router, "/gr-prive/absente").await.0,
+        StatusCode::NOT_FOUND
+    );
+}
+
+async fn section(req: RuniqueRequest) -> String {
+    ["section", "titre"]
+        .iter()
+        .map(|k| req.context.get(k).and_then(|v| v.as_str()).unwrap_or("-"))
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+async fn app() -> RuniqueApp {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    RuniqueApp::builder(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .routes(
+            urlpatterns! {
+                "/gr-accueil" => get(section), name = "gr_accueil",
+            }
+            .merge(
+                group("/gr-espace")
+                    .context("section", "espace")
+                    .context("titre", "Espace")
+                    .routes(|| {
+                        urlpatterns! {
+                            "/" => get(section), name = "gr_espace",
+                        }
+                    })
+                    .group(group("/infos").context("section", "infos").routes(|| {
+                        urlpatterns! {
+                            "/" => get(section), name = "gr_infos",
+                        }
+                    }))
+                    .group(
+                        group("/membres")
+                            .login_required("/connexion")
+                            .context("section", "membres")
+                            .routes(|| {
+                                urlpatterns! {
+                                    "/{id}" => get(section), name = "gr_membre",
+                                }
+                            }),
+                    ),
+            ),
+        )
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"))
+}
+
+/// Les noms de `urlpatterns!` passent par un registre global drainé par le premier
+/// `build()` venu : un test parallèle peut les capter, on reconstruit alors.
+async fn app_avec_noms() -> RuniqueApp {
+    for _ in 0..5 {
+        let app = app().await;
+        if reverse(&app.engine, "gr_membre").is_some() {
+            return app;
+        }
+    }
+    panic!("noms de routes captés par un autre build");
+}
+
+#[tokio::test]
+async fn test_noms_prefixes_pour_reverse() {
+    let app = app_avec_noms().await;
+    assert_eq!(
+        reverse(&app.engine, "gr_accueil").as_deref(),
+        Some("/gr-accueil")
+    );
+    assert_eq!(
+        reverse(&app.engine, "gr_espace").as_deref(),
+        Some("/gr-espace")
+    );
+    assert_eq!(
+        reverse_with_parameters(&app.engine, "gr_membre", &[("id", "3")]).as_deref(),
+        Some("/gr-espace/membres/3")
+    );
+}
+
+#[tokio::test]
+async fn test_login_required_sur_le_groupe() {
+    let app = app_avec_noms().await;
+    let (status, location, _) = requete(&app.router, "/gr-espace/membres/3").await;
+    assert!(status.is_redirection(), "{status}");
+    assert_eq!(location.as_deref(), Some("/connexion"));
+    // Le groupe parent n'exige pas de connexion
+    assert_eq!(requete(&app.router, "/gr-espace").await.0, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_variables_de_template_du_groupe() {
+    let app = app_avec_noms().await;
+    assert_eq!(requete(&app.router, "/gr-espace").await.2, "espace|Espace");
+    // Le groupe imbriqué remplace `section` et hérite de `titre`
+    assert_eq!(
+        requete(&app.router, "/gr-espace/infos").await.2,
+        "infos|Espace"
+    );
+    assert_eq!(requete(&app.router, "/gr-accueil").await.2, "-|-");
+}
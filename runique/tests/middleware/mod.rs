@@ -1,7 +1,11 @@
 pub mod test_allowed_hosts;
+pub mod test_append_slash;
 pub mod test_auth_session;
 pub mod test_cleaning_store;
+pub mod test_compression;
+pub mod test_conditional;
 pub mod test_config;
+pub mod test_cors;
 pub mod test_csp;
 pub mod test_csrf;
 pub mod test_csrf_crypto;
@@ -11,7 +15,10 @@ pub mod test_dev_cache;
 pub mod test_errors;
 pub mod test_login_guard;
 pub mod test_open_redirect;
+pub mod test_range;
 pub mod test_rate_limit;
+pub mod test_request_id;
+pub mod test_security_headers;
 pub mod test_session_config;
 pub mod test_session_db;
 pub mod test_user_trait;
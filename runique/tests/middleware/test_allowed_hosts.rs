@@ -1,7 +1,17 @@
 // Tests pour allowed_hosts middleware
 
-use axum::http::{HeaderMap, StatusCode, header};
-use runique::middleware::security::allowed_hosts::HostPolicy;
+use axum::{
+    Router,
+    body::Body,
+    http::{HeaderMap, Request, StatusCode, header},
+    routing::get,
+};
+use runique::app::RuniqueApp;
+use runique::config::RuniqueConfig;
+use runique::macros::group;
+use runique::middleware::security::allowed_hosts::{HostOverride, HostPolicy};
+use runique::testing::test_db;
+use tower::ServiceExt;
 
 #[test]
 fn test_host_policy_basic() {
@@ -118,3 +128,113 @@ fn test_make_error_message() {
     let msg = validator.validate(&HeaderMap::new()).err().unwrap().1;
     assert!(msg.contains("Bad Request"));
 }
+
+// ═══════════════════════════════════════════════════════════════
+// Surcharges par préfixe (route groups)
+// ═══════════════════════════════════════════════════════════════
+
+fn surcharge(prefix: &str, hosts: &[&str]) -> HostOverride {
+    HostOverride {
+        prefix: prefix.to_string(),
+        allowed_hosts: hosts.iter().map(|h| h.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_surcharge_prefixe_le_plus_long() {
+    let policy = HostPolicy::new(vec!["exemple.com".to_string()], true).with_overrides(vec![
+        surcharge("/api", &["api.exemple.com"]),
+        surcharge("/api/hooks", &["hooks.exemple.com"]),
+    ]);
+    assert!(policy.is_host_allowed_for("hooks.exemple.com", "/api/hooks/stripe"));
+    assert!(!policy.is_host_allowed_for("api.exemple.com", "/api/hooks"));
+    assert!(policy.is_host_allowed_for("api.exemple.com", "/api/items"));
+    // Limite de segment : `/apiv2` n'est pas sous `/api`
+    assert!(policy.is_host_allowed_for("exemple.com", "/apiv2"));
+    assert!(!policy.is_host_allowed_for("api.exemple.com", "/apiv2"));
+}
+
+async fn app() -> RuniqueApp {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    RuniqueApp::builder(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .routes(
+            Router::new()
+                .route("/ah-accueil", get(|| async { "accueil" }))
+                .merge(
+                    group("/ah-hooks")
+                        .allowed_hosts(["hooks.exemple.com"])
+                        .routes(|| Router::new().route("/stripe", get(|| async { "stripe" }))),
+                ),
+        )
+        .middleware(|m| m.with_allowed_hosts(|h| h.enabled(true).host("exemple.com")))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"))
+}
+
+/// Les surcharges passent par un registre global drainé par le premier `build()`
+/// venu : un test parallèle peut les capter, on reconstruit alors.
+async fn app_avec_surcharge() -> Router {
+    for _ in 0..5 {
+        let app = app().await;
+        if app
+            .engine
+            .security_hosts
+            .overrides
+            .iter()
+            .any(|o| o.prefix == "/ah-hooks")
+        {
+            return app.router;
+        }
+    }
+    panic!("surcharges captées par un autre build");
+}
+
+async fn statut(router: &Router, host: &str, uri: &str) -> StatusCode {
+    router
+        .clone()
+        .oneshot(
+            Request::get(uri)
+                .header(header::HOST, host)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .status()
+}
+
+#[tokio::test]
+async fn test_surcharge_autorise_un_hote_refuse_globalement() {
+    let router = app_avec_surcharge().await;
+    assert_eq!(
+        statut(&router, "hooks.exemple.com", "/ah-hooks/stripe").await,
+        StatusCode::OK
+    );
+    // Hors du préfixe, la liste globale s'applique
+    assert_eq!(
+        statut(&router, "hooks.exemple.com", "/ah-accueil").await,
+        StatusCode::BAD_REQUEST
+    );
+}
+
+#[tokio::test]
+async fn test_surcharge_refuse_un_hote_autorise_globalement() {
+    let router = app_avec_surcharge().await;
+    assert_eq!(
+        statut(&router, "exemple.com", "/ah-hooks/stripe").await,
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+        statut(&router, "exemple.com", "/ah-accueil").await,
+        StatusCode::OK
+    );
+    // Ni la surcharge ni la liste globale
+    assert_eq!(
+        statut(&router, "evil.com", "/ah-hooks/stripe").await,
+        StatusCode::BAD_REQUEST
+    );
+}
@@ -0,0 +1,137 @@
+//! Tests — middleware/append_slash.rs
+//! Couvre : ajout et retrait du slash final (301), 307 hors GET, query string conservée,
+//!          404 d'une route existante non redirigé, paramètres de chemin, désactivé par défaut
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Method, Request, StatusCode, header},
+    routing::get,
+};
+use runique::app::RuniqueApp;
+use runique::app::staging::MiddlewareStaging;
+use runique::config::RuniqueConfig;
+use runique::macros::reverse;
+use runique::testing::test_db;
+use runique::urlpatterns;
+use tower::ServiceExt;
+
+async fn introuvable() -> StatusCode {
+    StatusCode::NOT_FOUND
+}
+
+async fn app(m: fn(MiddlewareStaging) -> MiddlewareStaging) -> RuniqueApp {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    RuniqueApp::builder(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .routes(urlpatterns! {
+            "/as-about/" => get(|| async { "about" }), name = "as_about",
+            "/as-api/items" => get(|| async { "items" }).post(|| async { "cree" }), name = "as_items",
+            "/as-articles/{slug}/" => get(introuvable), name = "as_article",
+        })
+        .middleware(m)
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"))
+}
+
+/// Les noms de `urlpatterns!` passent par un registre global drainé par le premier
+/// `build()` venu : un test parallèle peut les capter, on reconstruit alors.
+async fn app_avec_noms(m: fn(MiddlewareStaging) -> MiddlewareStaging) -> Router {
+    for _ in 0..5 {
+        let app = app(m).await;
+        if reverse(&app.engine, "as_about").is_some() {
+            return app.router;
+        }
+    }
+    panic!("noms de routes captés par un autre build");
+}
+
+async fn requete(router: &Router, method: Method, uri: &str) -> (StatusCode, Option<String>) {
+    let res = router
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let location = res
+        .headers()
+        .get(header::LOCATION)
+        .map(|v| v.to_str().unwrap().to_string());
+    (res.status(), location)
+}
+
+fn actif(m: MiddlewareStaging) -> MiddlewareStaging {
+    // API sans jeton CSRF : le POST doit atteindre le routage
+    m.with_append_slash(true).csrf_exempt(vec!["/as-api/*"])
+}
+
+#[tokio::test]
+async fn test_ajoute_le_slash_final() {
+    let router = app_avec_noms(actif).await;
+    let (status, location) = requete(&router, Method::GET, "/as-about?page=2&tri=nom").await;
+    assert_eq!(status, StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(location.as_deref(), Some("/as-about/?page=2&tri=nom"));
+    assert_eq!(
+        requete(&router, Method::GET, "/as-about/").await.0,
+        StatusCode::OK
+    );
+}
+
+#[tokio::test]
+async fn test_retire_le_slash_final() {
+    let router = app_avec_noms(actif).await;
+    let (status, location) = requete(&router, Method::GET, "/as-api/items/").await;
+    assert_eq!(status, StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(location.as_deref(), Some("/as-api/items"));
+}
+
+#[tokio::test]
+async fn test_307_hors_get() {
+    let router = app_avec_noms(actif).await;
+    let (status, location) = requete(&router, Method::POST, "/as-api/items/").await;
+    assert_eq!(status, StatusCode::TEMPORARY_REDIRECT);
+    assert_eq!(location.as_deref(), Some("/as-api/items"));
+}
+
+#[tokio::test]
+async fn test_parametres_de_chemin() {
+    let router = app_avec_noms(actif).await;
+    let (status, location) = requete(&router, Method::GET, "/as-articles/bonjour").await;
+    assert_eq!(status, StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(location.as_deref(), Some("/as-articles/bonjour/"));
+    // Segment vide : aucun motif ne correspond
+    assert_eq!(
+        requete(&router, Method::GET, "/as-articles//").await.0,
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[tokio::test]
+async fn test_404_d_une_route_existante_non_redirige() {
+    let router = app_avec_noms(actif).await;
+    // La route correspond : le 404 vient du handler, pas d'une faute de slash
+    let (status, location) = requete(&router, Method::GET, "/as-articles/absent/").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(location.is_none());
+    // Ni l'une ni l'autre forme n'est une route
+    assert_eq!(
+        requete(&router, Method::GET, "/as-inconnue").await.0,
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[tokio::test]
+async fn test_desactive_par_defaut() {
+    let router = app_avec_noms(|m| m).await;
+    let (status, location) = requete(&router, Method::GET, "/as-about").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(location.is_none());
+}
@@ -0,0 +1,168 @@
+//! Tests — app/staging/compression_config.rs + middleware/compression.rs
+//! Couvre : négociation gzip / brotli via Accept-Encoding, seuil de taille, types déjà
+//!          compressés ignorés (SVG compressé), exclusions configurées, ETag affaibli
+//!          après compression, activation selon le profil et `.with_compression`
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use runique::app::staging::{CompressionConfig, MiddlewareStaging};
+use tower::ServiceExt;
+
+const PAGE: &str = "<p>runique</p>";
+
+fn big_body() -> String {
+    PAGE.repeat(200)
+}
+
+fn app(config: CompressionConfig) -> Router {
+    let router = Router::new()
+        .route(
+            "/html",
+            get(|| async { ([(header::CONTENT_TYPE, "text/html")], big_body()) }),
+        )
+        .route(
+            "/petit",
+            get(|| async { ([(header::CONTENT_TYPE, "text/html")], PAGE) }),
+        )
+        .route(
+            "/png",
+            get(|| async { ([(header::CONTENT_TYPE, "image/png")], big_body()) }),
+        )
+        .route(
+            "/svg",
+            get(|| async { ([(header::CONTENT_TYPE, "image/svg+xml")], big_body()) }),
+        )
+        .route(
+            "/pdf",
+            get(|| async { ([(header::CONTENT_TYPE, "application/pdf")], big_body()) }),
+        )
+        .route(
+            "/etag",
+            get(|| async {
+                (
+                    [
+                        (header::CONTENT_TYPE, "text/html"),
+                        (header::ETAG, "\"v1\""),
+                    ],
+                    big_body(),
+                )
+                    .into_response()
+            }),
+        );
+    config.apply(router)
+}
+
+async fn fetch(app: Router, path: &str, accept: Option<&str>) -> Response {
+    let mut req = Request::builder().uri(path);
+    if let Some(accept) = accept {
+        req = req.header(header::ACCEPT_ENCODING, accept);
+    }
+    app.oneshot(req.body(Body::empty()).unwrap()).await.unwrap()
+}
+
+fn encoding(resp: &Response) -> Option<&str> {
+    resp.headers()
+        .get(header::CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+}
+
+async fn body_len(resp: Response) -> usize {
+    axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap()
+        .len()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Négociation
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_gzip_negocie() {
+    let resp = fetch(app(CompressionConfig::default()), "/html", Some("gzip")).await;
+    assert_eq!(encoding(&resp), Some("gzip"));
+    assert!(resp.headers().get(header::CONTENT_LENGTH).is_none());
+    assert!(body_len(resp).await < big_body().len());
+}
+
+#[tokio::test]
+async fn test_brotli_prefere_puis_desactivable() {
+    let resp = fetch(app(CompressionConfig::default()), "/html", Some("gzip, br")).await;
+    assert_eq!(encoding(&resp), Some("br"));
+
+    let config = CompressionConfig::default().brotli(false);
+    let resp = fetch(app(config), "/html", Some("gzip, br")).await;
+    assert_eq!(encoding(&resp), Some("gzip"));
+}
+
+#[tokio::test]
+async fn test_sans_accept_encoding_non_compresse() {
+    let resp = fetch(app(CompressionConfig::default()), "/html", None).await;
+    assert!(encoding(&resp).is_none());
+    assert_eq!(body_len(resp).await, big_body().len());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Seuil et types de contenu
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_seuil_de_taille() {
+    let resp = fetch(app(CompressionConfig::default()), "/petit", Some("gzip")).await;
+    assert!(encoding(&resp).is_none());
+
+    let config = CompressionConfig::default().min_size(4);
+    let resp = fetch(app(config), "/petit", Some("gzip")).await;
+    assert_eq!(encoding(&resp), Some("gzip"));
+}
+
+#[tokio::test]
+async fn test_images_ignorees_sauf_svg() {
+    let resp = fetch(app(CompressionConfig::default()), "/png", Some("gzip")).await;
+    assert!(encoding(&resp).is_none());
+    let resp = fetch(app(CompressionConfig::default()), "/svg", Some("gzip")).await;
+    assert_eq!(encoding(&resp), Some("gzip"));
+}
+
+#[tokio::test]
+async fn test_exclusion_configuree() {
+    let resp = fetch(app(CompressionConfig::default()), "/pdf", Some("gzip")).await;
+    assert_eq!(encoding(&resp), Some("gzip"));
+
+    let config = CompressionConfig::default().exclude("application/pdf");
+    let resp = fetch(app(config), "/pdf", Some("gzip")).await;
+    assert!(encoding(&resp).is_none());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// ETag
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_etag_affaibli_seulement_si_compresse() {
+    let resp = fetch(app(CompressionConfig::default()), "/etag", Some("gzip")).await;
+    assert_eq!(resp.headers()[header::ETAG], "W/\"v1\"");
+
+    let resp = fetch(app(CompressionConfig::default()), "/etag", None).await;
+    assert_eq!(resp.headers()[header::ETAG], "\"v1\"");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Activation
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_activation_selon_profil_et_builder() {
+    assert!(MiddlewareStaging::new(false).features().enable_compression);
+    assert!(!MiddlewareStaging::new(true).features().enable_compression);
+
+    let ms = MiddlewareStaging::new(true).with_compression(|c| c.min_size(2048));
+    assert!(ms.features().enable_compression);
+    let ms = MiddlewareStaging::new(false).with_compression(|c| c.enabled(false));
+    assert!(!ms.features().enable_compression);
+}
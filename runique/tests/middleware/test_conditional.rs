@@ -0,0 +1,213 @@
+//! Tests — middleware/conditional.rs + Request::render_cached : GET conditionnel
+//! Couvre : ETag fort des fichiers statiques, 304 sur If-None-Match (forme faible
+//!          comprise), If-Modified-Since via mtime, If-None-Match prioritaire,
+//!          fichier modifié → nouvel ETag, page rendue en 304 sans CSP,
+//!          page avec messages flash sans ETag
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+    middleware,
+    response::{IntoResponse, Redirect, Response},
+    routing::get,
+};
+use runique::app::RuniqueAppBuilder;
+use runique::config::app::RuniqueConfig;
+use runique::context::Request as TplRequest;
+use runique::middleware::{EtagCache, static_etag_middleware};
+use runique::testing::{TestClient, test_db};
+use std::fs;
+use tower::ServiceExt;
+use tower_http::services::ServeDir;
+
+// ═══════════════════════════════════════════════════════════════
+// Fichiers statiques
+// ═══════════════════════════════════════════════════════════════
+
+fn static_app(dir: &TestTempDir) -> Router {
+    Router::new().nest_service(
+        "/static",
+        tower::ServiceBuilder::new()
+            .layer(middleware::from_fn_with_state(
+                EtagCache::default(),
+                static_etag_middleware,
+            ))
+            .service(ServeDir::new(dir.as_str())),
+    )
+}
+
+async fn get_with(app: Router, path: &str, headers: &[(header::HeaderName, &str)]) -> Response {
+    let mut req = Request::builder().uri(path);
+    for (name, value) in headers {
+        req = req.header(name, *value);
+    }
+    app.oneshot(req.body(Body::empty()).unwrap()).await.unwrap()
+}
+
+fn etag(resp: &Response) -> String {
+    resp.headers()[header::ETAG].to_str().unwrap().to_string()
+}
+
+async fn body_len(resp: Response) -> usize {
+    axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap()
+        .len()
+}
+
+#[tokio::test]
+async fn test_statique_etag_puis_304() {
+    let dir = TestTempDir::new("runique_test_conditional", "etag");
+    fs::write(dir.join("app.css"), "body { color: red }").unwrap();
+    let app = static_app(&dir);
+
+    let resp = get_with(app.clone(), "/static/app.css", &[]).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let tag = etag(&resp);
+    assert!(tag.starts_with('"') && !tag.starts_with("W/"));
+    assert!(resp.headers().contains_key(header::LAST_MODIFIED));
+
+    let resp = get_with(
+        app.clone(),
+        "/static/app.css",
+        &[(header::IF_NONE_MATCH, &tag)],
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(etag(&resp), tag);
+    assert!(resp.headers().get(header::CONTENT_TYPE).is_none());
+    assert_eq!(body_len(resp).await, 0);
+
+    // Forme faible renvoyée par le navigateur après une réponse compressée
+    let weak = format!("W/{tag}");
+    let resp = get_with(app, "/static/app.css", &[(header::IF_NONE_MATCH, &weak)]).await;
+    assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+}
+
+#[tokio::test]
+async fn test_statique_fichier_modifie_nouvel_etag() {
+    let dir = TestTempDir::new("runique_test_conditional", "modifie");
+    fs::write(dir.join("app.js"), "let a = 1;").unwrap();
+    let app = static_app(&dir);
+    let old = etag(&get_with(app.clone(), "/static/app.js", &[]).await);
+
+    fs::write(dir.join("app.js"), "let a = 22;").unwrap();
+    let resp = get_with(app, "/static/app.js", &[(header::IF_NONE_MATCH, &old)]).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_ne!(etag(&resp), old);
+    assert_eq!(body_len(resp).await, "let a = 22;".len());
+}
+
+#[tokio::test]
+async fn test_statique_if_modified_since() {
+    let dir = TestTempDir::new("runique_test_conditional", "ims");
+    fs::write(dir.join("logo.svg"), "<svg/>").unwrap();
+    let app = static_app(&dir);
+    let resp = get_with(app.clone(), "/static/logo.svg", &[]).await;
+    let modified = resp.headers()[header::LAST_MODIFIED]
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let resp = get_with(
+        app.clone(),
+        "/static/logo.svg",
+        &[(header::IF_MODIFIED_SINCE, &modified)],
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+
+    // If-None-Match présent et différent : If-Modified-Since est ignoré
+    let resp = get_with(
+        app,
+        "/static/logo.svg",
+        &[
+            (header::IF_MODIFIED_SINCE, &modified),
+            (header::IF_NONE_MATCH, "\"autre\""),
+        ],
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Templates — render_cached
+// ═══════════════════════════════════════════════════════════════
+
+async fn page(mut req: TplRequest) -> Response {
+    req.render_cached("page.html")
+        .unwrap_or_else(|e| e.into_response())
+}
+
+async fn flash(req: TplRequest) -> Redirect {
+    req.notices.success("enregistré").await;
+    Redirect::to("/page")
+}
+
+async fn template_client(dir: &TestTempDir) -> TestClient {
+    fs::write(
+        dir.join("page.html"),
+        r#"<script nonce="{{ csp_nonce }}">1</script>{% for m in messages %}{{ m.content }}{% endfor %}"#,
+    )
+    .unwrap();
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    config.static_files.templates_dir = vec![dir.as_str().to_string()];
+    let routes = Router::new()
+        .route("/page", get(page))
+        .route("/flash", get(flash));
+    let app = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .routes(routes)
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    TestClient::from_app(app)
+}
+
+fn if_none_match(path: &str, tag: &str) -> Request<Body> {
+    Request::builder()
+        .uri(path)
+        .header(header::IF_NONE_MATCH, tag)
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_page_rendue_304_sans_csp() {
+    let dir = TestTempDir::new("runique_test_conditional", "page");
+    let client = template_client(&dir).await;
+
+    let first = client.get("/page").await;
+    assert_eq!(first.status(), StatusCode::OK);
+    assert!(first.header("content-security-policy").is_some());
+    let tag = first.header("etag").unwrap().to_string();
+
+    // Nonce différent à chaque rendu, même ETag
+    let resp = client.request(if_none_match("/page", &tag)).await;
+    assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(resp.header("etag"), Some(tag.as_str()));
+    assert!(resp.header("content-security-policy").is_none());
+    assert!(resp.text().is_empty());
+}
+
+#[tokio::test]
+async fn test_page_avec_message_flash_sans_etag() {
+    let dir = TestTempDir::new("runique_test_conditional", "flash");
+    let client = template_client(&dir).await;
+    let tag = client
+        .get("/page")
+        .await
+        .header("etag")
+        .unwrap()
+        .to_string();
+
+    client.get("/flash").await;
+    let resp = client.request(if_none_match("/page", &tag)).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(resp.header("etag").is_none());
+    assert!(resp.text().contains("enregistré"));
+}
@@ -9,6 +9,8 @@ fn test_middleware_config_default() {
     assert!(config.enable_host_validation);
     assert!(config.enable_debug_errors);
     assert!(config.enable_cache);
+    assert!(config.enable_compression);
+    assert!(!config.append_slash);
 }
 
 #[test]
@@ -18,6 +20,7 @@ fn test_middleware_config_production() {
     assert!(config.enable_host_validation);
     assert!(config.enable_debug_errors);
     assert!(config.enable_cache);
+    assert!(config.enable_compression);
 }
 
 #[test]
@@ -27,6 +30,7 @@ fn test_middleware_config_development() {
     assert!(!config.enable_host_validation);
     assert!(config.enable_debug_errors);
     assert!(!config.enable_cache);
+    assert!(!config.enable_compression);
 }
 
 #[test]
@@ -36,6 +40,7 @@ fn test_middleware_config_api() {
     assert!(config.enable_host_validation);
     assert!(config.enable_debug_errors);
     assert!(config.enable_cache);
+    assert!(config.enable_compression);
 }
 
 #[test]
@@ -44,9 +49,11 @@ fn test_middleware_config_custom_chain() {
         .with_csp(false)
         .with_debug_errors(false)
         .with_cache(false)
-        .with_host_validation(false);
+        .with_host_validation(false)
+        .with_compression(false);
     assert!(!config.enable_csp);
     assert!(!config.enable_debug_errors);
     assert!(!config.enable_cache);
     assert!(!config.enable_host_validation);
+    assert!(!config.enable_compression);
 }
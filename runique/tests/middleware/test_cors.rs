@@ -0,0 +1,162 @@
+//! Tests — app/staging/cors_config.rs : CorsConfig et couche CORS
+//! Couvre : preflight OPTIONS court-circuité, origine renvoyée seulement si autorisée,
+//!          méthodes / headers configurés, credentials avec reflet du preflight,
+//!          validation (wildcard + credentials, valeurs invalides)
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+    response::Response,
+    routing::get,
+};
+use runique::app::staging::{CorsConfig, MiddlewareStaging};
+use tower::ServiceExt;
+
+fn cors_app(cors: CorsConfig) -> Router {
+    Router::new()
+        .route("/api", get(|| async { "ok" }))
+        .layer(cors.layer())
+}
+
+async fn preflight(app: Router, origin: &str, method: &str, headers: &str) -> Response {
+    let req = Request::builder()
+        .method("OPTIONS")
+        .uri("/api")
+        .header("origin", origin)
+        .header("access-control-request-method", method)
+        .header("access-control-request-headers", headers)
+        .body(Body::empty())
+        .unwrap();
+    app.oneshot(req).await.unwrap()
+}
+
+async fn get_from(app: Router, origin: &str) -> Response {
+    let req = Request::builder()
+        .uri("/api")
+        .header("origin", origin)
+        .body(Body::empty())
+        .unwrap();
+    app.oneshot(req).await.unwrap()
+}
+
+fn header<'a>(resp: &'a Response, name: &str) -> Option<&'a str> {
+    resp.headers().get(name).and_then(|v| v.to_str().ok())
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Origines
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_cors_origine_autorisee_renvoyee() {
+    let app = cors_app(CorsConfig::default().origin("https://app.example.com"));
+    let resp = get_from(app, "https://app.example.com").await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        header(&resp, "access-control-allow-origin"),
+        Some("https://app.example.com")
+    );
+}
+
+#[tokio::test]
+async fn test_cors_origine_inconnue_sans_header() {
+    let app = cors_app(CorsConfig::default().origin("https://app.example.com"));
+    let resp = get_from(app, "https://evil.example.com").await;
+    assert!(header(&resp, "access-control-allow-origin").is_none());
+}
+
+#[tokio::test]
+async fn test_cors_any_origin_etoile() {
+    let app = cors_app(CorsConfig::default().any_origin());
+    let resp = get_from(app, "https://n-importe-ou.example").await;
+    assert_eq!(header(&resp, "access-control-allow-origin"), Some("*"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Preflight
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_cors_preflight_court_circuite_avec_max_age() {
+    let app = cors_app(
+        CorsConfig::default()
+            .origin("https://app.example.com")
+            .max_age(600),
+    );
+    let resp = preflight(app, "https://app.example.com", "POST", "content-type").await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(header(&resp, "access-control-max-age"), Some("600"));
+    assert_eq!(
+        header(&resp, "access-control-allow-origin"),
+        Some("https://app.example.com")
+    );
+}
+
+#[tokio::test]
+async fn test_cors_methodes_et_headers_configures() {
+    let app = cors_app(
+        CorsConfig::default()
+            .origin("https://app.example.com")
+            .method("get")
+            .method("POST")
+            .header("Content-Type")
+            .header("x-csrf-token"),
+    );
+    let resp = preflight(app, "https://app.example.com", "POST", "content-type").await;
+    assert_eq!(
+        header(&resp, "access-control-allow-methods"),
+        Some("GET,POST")
+    );
+    assert_eq!(
+        header(&resp, "access-control-allow-headers"),
+        Some("content-type,x-csrf-token")
+    );
+}
+
+#[tokio::test]
+async fn test_cors_credentials_reflete_le_preflight() {
+    let app = cors_app(
+        CorsConfig::default()
+            .origin("https://app.example.com")
+            .allow_credentials(true),
+    );
+    let resp = preflight(app, "https://app.example.com", "PATCH", "x-csrf-token").await;
+    assert_eq!(
+        header(&resp, "access-control-allow-credentials"),
+        Some("true")
+    );
+    assert_eq!(header(&resp, "access-control-allow-methods"), Some("PATCH"));
+    assert_eq!(
+        header(&resp, "access-control-allow-headers"),
+        Some("x-csrf-token")
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Validation au build
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_cors_wildcard_et_credentials_rejete() {
+    let ms = MiddlewareStaging::new(false).with_cors(|c| c.any_origin().allow_credentials(true));
+    assert!(ms.validate().is_err());
+}
+
+#[test]
+fn test_cors_methode_invalide_rejetee() {
+    let ms = MiddlewareStaging::new(false)
+        .with_cors(|c| c.origin("https://app.example.com").method("GE T"));
+    assert!(ms.validate().is_err());
+}
+
+#[test]
+fn test_cors_configuration_valide() {
+    let ms = MiddlewareStaging::new(false).with_cors(|c| {
+        c.origin("https://app.example.com")
+            .method("DELETE")
+            .header("authorization")
+            .allow_credentials(true)
+    });
+    assert!(ms.validate().is_ok());
+}
@@ -66,6 +66,26 @@ fn test_to_header_value_with_nonce() {
     assert!(header.contains("'nonce-abc123'"));
 }
 
+#[test]
+fn test_to_header_value_nonce_restreint_aux_styles() {
+    let policy = SecurityPolicy {
+        use_nonce: true,
+        nonce_directives: vec!["style-src".to_string()],
+        ..Default::default()
+    };
+    let header = policy.to_header_value(Some("abc123"));
+    let script = header
+        .split(';')
+        .find(|d| d.trim().starts_with("script-src"))
+        .unwrap();
+    let style = header
+        .split(';')
+        .find(|d| d.trim().starts_with("style-src"))
+        .unwrap();
+    assert!(!script.contains("'nonce-abc123'"));
+    assert!(style.contains("'nonce-abc123'"));
+}
+
 #[test]
 fn test_to_header_value_contains_all_directives() {
     let policy = SecurityPolicy::default();
@@ -133,6 +153,16 @@ fn test_csp_config_nonce_false() {
     assert!(!csp.get_policy().use_nonce);
 }
 
+#[test]
+fn test_csp_config_nonce_for_normalise_et_active() {
+    let csp = CspConfig::default()
+        .with_nonce(false)
+        .nonce_for("style")
+        .nonce_for("style-src");
+    assert!(csp.get_policy().use_nonce);
+    assert_eq!(csp.get_policy().nonce_directives, vec!["style-src"]);
+}
+
 #[test]
 fn test_csp_config_nonce_true_par_defaut() {
     let csp = CspConfig::default();
@@ -399,12 +429,18 @@ async fn test_https_redirect_redirige_quand_actif() {
         config,
         tera: engine.tera.clone(),
         db: engine.db.clone(),
+        tasks: Default::default(),
+        scheduler: Default::default(),
+        cache: Default::default(),
         url_registry: engine.url_registry.clone(),
+        i18n: engine.i18n.clone(),
         features: MiddlewareConfig::default(),
         security_csp: Arc::new(SecurityPolicy::default()),
         security_hosts: Arc::new(HostPolicy::new(vec![], true)),
         csrf_exempt_paths: Arc::new(vec![]),
+        trusted_origins: Arc::new(vec![]),
         permissions_policy: Arc::new(runique::middleware::PermissionsPolicy::default()),
+        security_headers: Arc::new(runique::middleware::SecurityHeaders::default()),
         trusted_proxies: Arc::new(runique::middleware::TrustedProxies::default()),
         session_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
         session_db_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
@@ -440,12 +476,18 @@ async fn test_https_redirect_passe_si_deja_https() {
         config,
         tera: engine.tera.clone(),
         db: engine.db.clone(),
+        tasks: Default::default(),
+        scheduler: Default::default(),
+        cache: Default::default(),
         url_registry: engine.url_registry.clone(),
+        i18n: engine.i18n.clone(),
         features: MiddlewareConfig::default(),
         security_csp: Arc::new(SecurityPolicy::default()),
         security_hosts: Arc::new(HostPolicy::new(vec![], true)),
         csrf_exempt_paths: Arc::new(vec![]),
+        trusted_origins: Arc::new(vec![]),
         permissions_policy: Arc::new(runique::middleware::PermissionsPolicy::default()),
+        security_headers: Arc::new(runique::middleware::SecurityHeaders::default()),
         trusted_proxies: Arc::new(runique::middleware::TrustedProxies::default()),
         session_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
         session_db_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
@@ -1,16 +1,26 @@
-use crate::helpers::{assert::assert_status, server::build_engine};
+use crate::helpers::{
+    assert::{assert_status, body_str},
+    server::build_engine,
+};
 use axum::{
     Router,
     body::Body,
+    extract::State,
     http::Request,
-    middleware,
+    middleware::{self, Next},
+    response::Response,
     routing::{get, post},
 };
 use runique::{
+    context::{RequestExtensions, template::Request as TplRequest},
     engine::RuniqueEngine,
     middleware::{
         config::MiddlewareConfig,
-        security::{allowed_hosts::HostPolicy, csp::SecurityPolicy, csrf::csrf_middleware},
+        security::{
+            allowed_hosts::HostPolicy,
+            csp::SecurityPolicy,
+            csrf::{csrf_middleware, is_csrf_exempt},
+        },
     },
 };
 use std::sync::Arc;
@@ -25,12 +35,18 @@ async fn engine_with_exempt(paths: Vec<&str>) -> Arc<RuniqueEngine> {
         config: base.config.clone(),
         tera: base.tera.clone(),
         db: base.db.clone(),
+        tasks: Default::default(),
+        scheduler: Default::default(),
+        cache: Default::default(),
         url_registry: base.url_registry.clone(),
+        i18n: base.i18n.clone(),
         features: MiddlewareConfig::default(),
         security_csp: Arc::new(SecurityPolicy::default()),
         security_hosts: Arc::new(HostPolicy::new(vec![], true)),
         csrf_exempt_paths: Arc::new(paths.iter().map(|s| s.to_string()).collect()),
+        trusted_origins: Arc::new(vec![]),
         permissions_policy: Arc::new(runique::middleware::PermissionsPolicy::default()),
+        security_headers: Arc::new(runique::middleware::SecurityHeaders::default()),
         trusted_proxies: Arc::new(runique::middleware::TrustedProxies::default()),
         session_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
         session_db_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
@@ -140,3 +156,75 @@ async fn liste_vide_bloque_tout() {
     let resp = app.oneshot(json_post("/webhook/stripe")).await.unwrap();
     assert_status(&resp, 403);
 }
+
+// ── Motifs préfixe (`*`) ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn motif_prefixe_exempte_les_sous_chemins() {
+    let engine = engine_with_exempt(vec!["/webhook/*"]).await;
+    let app = csrf_app(engine);
+    for uri in ["/webhook/stripe", "/webhook/stripe/sub"] {
+        let resp = app.clone().oneshot(json_post(uri)).await.unwrap();
+        assert_status(&resp, 200);
+    }
+    let resp = app.oneshot(json_post("/submit")).await.unwrap();
+    assert_status(&resp, 403);
+}
+
+#[test]
+fn is_csrf_exempt_exact_et_prefixe() {
+    let patterns = vec!["/hook".to_string(), "/api/v1/*".to_string()];
+    assert!(is_csrf_exempt(&patterns, "/hook"));
+    assert!(!is_csrf_exempt(&patterns, "/hook/sub"));
+    assert!(is_csrf_exempt(&patterns, "/api/v1/events"));
+    assert!(!is_csrf_exempt(&patterns, "/api/v2/events"));
+}
+
+// ── Jeton toujours injecté sur un chemin exempté ─────────────────────────────
+
+#[tokio::test]
+async fn chemin_exempte_recoit_quand_meme_un_jeton() {
+    let engine = engine_with_exempt(vec!["/webhook/stripe"]).await;
+    let app = csrf_app(engine);
+    let resp = app.oneshot(json_post("/webhook/stripe")).await.unwrap();
+    assert_status(&resp, 200);
+    assert!(resp.headers().contains_key("X-CSRF-Token"));
+}
+
+#[tokio::test]
+async fn chemin_exempte_formulaire_accepte_sans_jeton() {
+    let engine = engine_with_exempt(vec!["/webhook/*"]).await;
+    let app = Router::new()
+        .route(
+            "/webhook/form",
+            post(|req: TplRequest| async move { req.prisme.csrf_valid.to_string() }),
+        )
+        .layer(middleware::from_fn_with_state(
+            engine.clone(),
+            csrf_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(engine, engine_inject))
+        .layer(SessionManagerLayer::new(MemoryStore::default()));
+    let req = Request::builder()
+        .method("POST")
+        .uri("/webhook/form")
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(Body::from("event=paid"))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(body_str(resp).await, "true");
+}
+
+/// Injecte engine + config (requis par le pipeline Prisme de `Request`).
+async fn engine_inject(
+    State(engine): State<Arc<RuniqueEngine>>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
+    let config = Arc::new(engine.config.clone());
+    RequestExtensions::new()
+        .with_engine(engine)
+        .with_config(config)
+        .inject_request(&mut req);
+    next.run(req).await
+}
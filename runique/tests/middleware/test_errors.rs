@@ -17,7 +17,7 @@ use crate::helpers::{request, server::build_engine};
 /// Router minimal avec error_handler_middleware en mode production (debug=false).
 async fn build_error_app() -> Router {
     let engine = build_engine().await;
-    let tera = engine.tera.clone();
+    let tera = engine.tera.current();
     let config = Arc::new(engine.config.clone()); // debug=false par défaut
 
     Router::new()
@@ -34,7 +34,7 @@ async fn build_error_app() -> Router {
 /// Même router mais avec debug=true.
 async fn build_debug_error_app() -> Router {
     let engine = build_engine().await;
-    let tera = engine.tera.clone();
+    let tera = engine.tera.current();
     let mut config = engine.config.clone();
     config.debug = true;
     let config = Arc::new(config);
@@ -1,4 +1,9 @@
-use runique::auth::guard::LoginGuard;
+use crate::helpers::server::build_engine;
+use runique::auth::guard::{LoginAttemptStore, LoginGuard, LoginOutcome, MemoryLoginAttemptStore};
+use runique::auth::session::{AdminAuth, AdminLoginResult};
+use sea_orm::DatabaseConnection;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 
 #[test]
 fn test_not_locked_initially() {
@@ -126,3 +131,134 @@ fn test_effective_key_anonymous_keys_are_independent() {
     assert!(guard.is_locked(&key_a));
     assert!(!guard.is_locked(&key_b)); // IP différente → non affectée
 }
+
+// ── LoginGuard::authenticate — verrouillage intégré à l'auth ─────────────────
+
+/// Accepte uniquement le mot de passe "secret" et compte les appels.
+struct CountingAuth {
+    calls: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl AdminAuth for CountingAuth {
+    async fn authenticate(
+        &self,
+        username: &str,
+        password: &str,
+        _db: &DatabaseConnection,
+    ) -> Option<AdminLoginResult> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        (password == "secret").then(|| AdminLoginResult {
+            user_id: 1,
+            username: username.to_string(),
+            is_staff: true,
+            is_superuser: false,
+        })
+    }
+}
+
+fn counting_auth() -> CountingAuth {
+    CountingAuth {
+        calls: AtomicUsize::new(0),
+    }
+}
+
+#[tokio::test]
+async fn test_authenticate_throttled_apres_echecs() {
+    let engine = build_engine().await;
+    let auth = counting_auth();
+    let guard = LoginGuard::new().max_attempts(2).lockout_secs(60);
+    for _ in 0..2 {
+        let outcome = guard
+            .authenticate(&auth, "alice", "faux", "1.1.1.1", &engine.db)
+            .await;
+        assert!(matches!(outcome, LoginOutcome::Invalid));
+    }
+    // Verrouillé : même le bon mot de passe est refusé, sans appeler l'auth
+    let outcome = guard
+        .authenticate(&auth, "alice", "secret", "1.1.1.1", &engine.db)
+        .await;
+    match outcome {
+        LoginOutcome::Throttled { retry_after } => assert!(retry_after > 0 && retry_after <= 60),
+        other => panic!("attendu Throttled, obtenu {other:?}"),
+    }
+    assert_eq!(auth.calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_authenticate_succes_remet_a_zero() {
+    let engine = build_engine().await;
+    let auth = counting_auth();
+    let guard = LoginGuard::new().max_attempts(3).lockout_secs(60);
+    let _ = guard
+        .authenticate(&auth, "bob", "faux", "1.1.1.1", &engine.db)
+        .await;
+    assert_eq!(guard.attempts("bob"), 1);
+    let outcome = guard
+        .authenticate(&auth, "bob", "secret", "1.1.1.1", &engine.db)
+        .await;
+    assert!(matches!(outcome, LoginOutcome::Authenticated(ref u) if u.username == "bob"));
+    assert_eq!(guard.attempts("bob"), 0);
+}
+
+#[tokio::test]
+async fn test_authenticate_per_ip_isole_les_adresses() {
+    let engine = build_engine().await;
+    let auth = counting_auth();
+    let guard = LoginGuard::new()
+        .max_attempts(1)
+        .lockout_secs(60)
+        .per_ip(true);
+    let _ = guard
+        .authenticate(&auth, "carol", "faux", "6.6.6.6", &engine.db)
+        .await;
+    let outcome = guard
+        .authenticate(&auth, "carol", "secret", "1.1.1.1", &engine.db)
+        .await;
+    assert!(matches!(outcome, LoginOutcome::Authenticated(_)));
+    assert!(guard.is_locked("carol@6.6.6.6"));
+}
+
+#[test]
+fn test_key_for() {
+    let guard = LoginGuard::new();
+    assert_eq!(guard.key_for("alice", "1.1.1.1"), "alice");
+    assert_eq!(guard.key_for("", "1.1.1.1"), "anonym:1.1.1.1");
+    let guard = guard.per_ip(true);
+    assert_eq!(guard.key_for("alice", "1.1.1.1"), "alice@1.1.1.1");
+    assert_eq!(guard.key_for(" ", "1.1.1.1"), "anonym:1.1.1.1");
+}
+
+// ── LoginAttemptStore — store personnalisé ────────────────────────────────────
+
+/// Store qui considère chaque clé comme ayant 9 échecs récents.
+struct AlwaysFailedStore;
+
+impl LoginAttemptStore for AlwaysFailedStore {
+    fn record_failure(&self, _key: &str) -> u32 {
+        9
+    }
+    fn reset(&self, _key: &str) {}
+    fn get(&self, _key: &str) -> Option<(u32, Duration)> {
+        Some((9, Duration::from_secs(10)))
+    }
+}
+
+#[test]
+fn test_store_personnalise() {
+    let guard = LoginGuard::new()
+        .max_attempts(5)
+        .lockout_secs(60)
+        .store(AlwaysFailedStore);
+    assert!(guard.is_locked("dave"));
+    assert_eq!(guard.remaining_lockout_secs("dave"), Some(50));
+}
+
+#[test]
+fn test_memory_store_purge() {
+    let store = MemoryLoginAttemptStore::default();
+    assert_eq!(store.record_failure("eve"), 1);
+    assert_eq!(store.record_failure("eve"), 2);
+    store.purge(Duration::from_secs(0));
+    assert!(store.get("eve").is_none());
+}
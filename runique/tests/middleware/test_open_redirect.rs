@@ -33,12 +33,18 @@ async fn engine_with_hosts(allowed: Vec<&str>) -> Arc<RuniqueEngine> {
         config: base.config.clone(),
         tera: base.tera.clone(),
         db: base.db.clone(),
+        tasks: Default::default(),
+        scheduler: Default::default(),
+        cache: Default::default(),
         url_registry: base.url_registry.clone(),
+        i18n: base.i18n.clone(),
         features: MiddlewareConfig::default(),
         security_csp: Arc::new(SecurityPolicy::default()),
         security_hosts: Arc::new(HostPolicy::new(hosts, true)),
         csrf_exempt_paths: Arc::new(vec![]),
+        trusted_origins: Arc::new(vec![]),
         permissions_policy: Arc::new(runique::middleware::PermissionsPolicy::default()),
+        security_headers: Arc::new(runique::middleware::SecurityHeaders::default()),
         trusted_proxies: Arc::new(runique::middleware::TrustedProxies::default()),
         session_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
         session_db_store: std::sync::LazyLock::new(|| std::sync::RwLock::new(None)),
@@ -0,0 +1,192 @@
+//! Tests — middleware/range.rs : requêtes Range sur les fichiers statiques
+//! Couvre : plage simple et suffixe (206 + Content-Range), plage non satisfiable (416),
+//!          multi-plages en multipart/byteranges, plages chevauchantes fusionnées,
+//!          If-Range (ETag et date), parsing de l'en-tête Range
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+    middleware,
+    response::Response,
+};
+use runique::middleware::{EtagCache, StaticRangeLayer, parse_ranges, static_etag_middleware};
+use std::fs;
+use tower::ServiceExt;
+use tower_http::services::ServeDir;
+
+/// 0123456789 répété : l'octet n vaut (n % 10).
+const CONTENT: &str = "0123456789012345678901234567890123456789";
+
+fn static_app(dir: &TestTempDir) -> Router {
+    fs::write(dir.join("cours.mp4"), CONTENT).unwrap();
+    Router::new().nest_service(
+        "/media",
+        tower::ServiceBuilder::new()
+            .layer(StaticRangeLayer)
+            .layer(middleware::from_fn_with_state(
+                EtagCache::default(),
+                static_etag_middleware,
+            ))
+            .service(ServeDir::new(dir.as_str())),
+    )
+}
+
+async fn get(app: &Router, headers: &[(header::HeaderName, &str)]) -> Response {
+    let mut req = Request::builder().uri("/media/cours.mp4");
+    for (name, value) in headers {
+        req = req.header(name, *value);
+    }
+    app.clone()
+        .oneshot(req.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+fn header(resp: &Response, name: header::HeaderName) -> Option<&str> {
+    resp.headers().get(name).and_then(|v| v.to_str().ok())
+}
+
+async fn body(resp: Response) -> String {
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Plage simple
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_plage_simple_206() {
+    let dir = TestTempDir::new("runique_test_range", "simple");
+    let app = static_app(&dir);
+
+    let resp = get(&app, &[(header::RANGE, "bytes=10-14")]).await;
+    assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(header(&resp, header::CONTENT_RANGE), Some("bytes 10-14/40"));
+    assert_eq!(header(&resp, header::ACCEPT_RANGES), Some("bytes"));
+    assert_eq!(body(resp).await, "01234");
+
+    let resp = get(&app, &[(header::RANGE, "bytes=-3")]).await;
+    assert_eq!(header(&resp, header::CONTENT_RANGE), Some("bytes 37-39/40"));
+    assert_eq!(body(resp).await, "789");
+}
+
+#[tokio::test]
+async fn test_plage_non_satisfiable_416() {
+    let dir = TestTempDir::new("runique_test_range", "416");
+    let app = static_app(&dir);
+
+    for range in ["bytes=100-200", "bytes=100-200, 300-"] {
+        let resp = get(&app, &[(header::RANGE, range)]).await;
+        assert_eq!(resp.status(), StatusCode::RANGE_NOT_SATISFIABLE, "{range}");
+        assert_eq!(header(&resp, header::CONTENT_RANGE), Some("bytes */40"));
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Multi-plages
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_multi_plages_multipart() {
+    let dir = TestTempDir::new("runique_test_range", "multi");
+    let app = static_app(&dir);
+
+    let resp = get(&app, &[(header::RANGE, "bytes=0-1, 20-22, -2")]).await;
+    assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+    let content_type = header(&resp, header::CONTENT_TYPE).unwrap().to_string();
+    let boundary = content_type
+        .strip_prefix("multipart/byteranges; boundary=")
+        .unwrap()
+        .to_string();
+    let length: usize = header(&resp, header::CONTENT_LENGTH)
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    let text = body(resp).await;
+    assert_eq!(text.len(), length);
+    assert_eq!(text.matches(&format!("--{boundary}\r\n")).count(), 3);
+    assert!(text.ends_with(&format!("\r\n--{boundary}--\r\n")));
+    for (range, data) in [("0-1/40", "01"), ("20-22/40", "012"), ("38-39/40", "89")] {
+        let part = format!("content-range: bytes {range}\r\n\r\n{data}\r\n");
+        assert!(text.contains(&part), "{part:?} absent de {text:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_plages_chevauchantes_fusionnees() {
+    let dir = TestTempDir::new("runique_test_range", "fusion");
+    let app = static_app(&dir);
+
+    let resp = get(&app, &[(header::RANGE, "bytes=5-9, 0-6")]).await;
+    assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(header(&resp, header::CONTENT_RANGE), Some("bytes 0-9/40"));
+    assert_eq!(body(resp).await, "0123456789");
+}
+
+// ═══════════════════════════════════════════════════════════════
+// If-Range
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_if_range_etag_et_date() {
+    let dir = TestTempDir::new("runique_test_range", "if_range");
+    let app = static_app(&dir);
+    let full = get(&app, &[]).await;
+    let etag = header(&full, header::ETAG).unwrap().to_string();
+    let modified = header(&full, header::LAST_MODIFIED).unwrap().to_string();
+
+    let resp = get(
+        &app,
+        &[(header::RANGE, "bytes=0-3"), (header::IF_RANGE, &etag)],
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(body(resp).await, "0123");
+
+    let resp = get(
+        &app,
+        &[(header::RANGE, "bytes=0-3"), (header::IF_RANGE, &modified)],
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::PARTIAL_CONTENT);
+
+    // Validateur périmé : fichier entier
+    let resp = get(
+        &app,
+        &[
+            (header::RANGE, "bytes=0-3"),
+            (header::IF_RANGE, "\"ancien\""),
+        ],
+    )
+    .await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(body(resp).await, CONTENT);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Parsing
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_parse_ranges() {
+    assert_eq!(parse_ranges("bytes=0-0", 10), Some(vec![(0, 0)]));
+    assert_eq!(parse_ranges("bytes=5-", 10), Some(vec![(5, 9)]));
+    assert_eq!(parse_ranges("bytes=-20", 10), Some(vec![(0, 9)]));
+    assert_eq!(parse_ranges("bytes=8-50", 10), Some(vec![(8, 9)]));
+    assert_eq!(
+        parse_ranges("bytes=6-7, 0-1, 2-3", 10),
+        Some(vec![(0, 3), (6, 7)])
+    );
+    assert_eq!(parse_ranges("bytes=20-30", 10), Some(vec![]));
+    assert_eq!(parse_ranges("items=0-1", 10), None);
+    assert_eq!(parse_ranges("bytes=3-1", 10), None);
+    assert_eq!(parse_ranges("bytes=a-b", 10), None);
+    let many = format!("bytes={}", vec!["0-0"; 17].join(","));
+    assert_eq!(parse_ranges(&many, 10), None);
+}
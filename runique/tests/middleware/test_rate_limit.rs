@@ -1,7 +1,11 @@
 use crate::helpers::{assert::assert_status, request};
 use axum::{Router, routing::get};
-use runique::middleware::rate_limit::{RateLimiter, rate_limit_middleware};
+use runique::middleware::rate_limit::{
+    MemoryRateLimitStore, RateLimitKey, RateLimitStore, RateLimiter, rate_limit_middleware,
+};
 use std::sync::Arc;
+use std::time::Duration;
+use tower_sessions::{MemoryStore, Session, session::Id};
 
 #[test]
 fn test_new_allows_first_request() {
@@ -223,3 +227,128 @@ async fn test_route_layer_does_not_affect_other_routes() {
     let autre = request::get(app.clone(), "/autre").await;
     assert_status(&autre, 200);
 }
+
+// ── RateLimitStore — store personnalisé ───────────────────────────────────────
+
+/// Store qui refuse une clé précise, pour vérifier que le limiteur délègue au store.
+struct DenyListStore;
+
+impl RateLimitStore for DenyListStore {
+    fn hit(&self, key: &str, _max: u32, _window: Duration) -> bool {
+        key != "banni"
+    }
+    fn retry_after_secs(&self, _key: &str, _window: Duration) -> u64 {
+        42
+    }
+}
+
+#[test]
+fn test_store_personnalise_utilise() {
+    let limiter = RateLimiter::new().max_requests(1).store(DenyListStore);
+    assert!(limiter.is_allowed("ip"));
+    assert!(limiter.is_allowed("ip")); // le store ignore max_requests
+    assert!(!limiter.is_allowed("banni"));
+    assert_eq!(limiter.retry_after_secs("banni"), 42);
+}
+
+#[test]
+fn test_memory_store_purge_fenetre_expiree() {
+    let store = MemoryRateLimitStore::default();
+    let window = Duration::from_secs(0);
+    assert!(store.hit("ip", 1, Duration::from_secs(60)));
+    store.purge(window);
+    assert_eq!(store.retry_after_secs("ip", Duration::from_secs(60)), 0);
+}
+
+// ── RateLimitKey::Session ─────────────────────────────────────────────────────
+
+/// Injecte une session d'id fixe, comme le ferait le SessionManagerLayer.
+fn session_app(
+    limiter: Arc<RateLimiter>,
+    store: Arc<MemoryStore>,
+    session_id: Option<Id>,
+) -> Router {
+    Router::new()
+        .route("/", get(|| async { "ok" }))
+        .layer(axum::middleware::from_fn_with_state(
+            limiter,
+            rate_limit_middleware,
+        ))
+        .layer(axum::middleware::from_fn(
+            move |mut req: axum::http::Request<axum::body::Body>, next: axum::middleware::Next| {
+                let session = Session::new(session_id, store.clone(), None);
+                req.extensions_mut().insert(session);
+                async move { next.run(req).await }
+            },
+        ))
+}
+
+/// Session enregistrée dans `store` — son id est connu côté serveur.
+async fn stored_session(store: &Arc<MemoryStore>) -> Id {
+    let session = Session::new(None, store.clone(), None);
+    session.insert("visite", true).await.unwrap();
+    session.save().await.unwrap();
+    session.id().unwrap()
+}
+
+#[tokio::test]
+async fn test_cle_session_compte_par_session() {
+    let limiter = Arc::new(
+        RateLimiter::new()
+            .max_requests(1)
+            .retry_after(60)
+            .key_by(RateLimitKey::Session),
+    );
+    let store = Arc::new(MemoryStore::default());
+    let first = stored_session(&store).await;
+    let second = stored_session(&store).await;
+    let resp = request::get(
+        session_app(limiter.clone(), store.clone(), Some(first)),
+        "/",
+    )
+    .await;
+    assert_status(&resp, 200);
+    let resp = request::get(
+        session_app(limiter.clone(), store.clone(), Some(first)),
+        "/",
+    )
+    .await;
+    assert_status(&resp, 429);
+    // Une autre session garde son propre quota, même IP
+    let resp = request::get(session_app(limiter.clone(), store, Some(second)), "/").await;
+    assert_status(&resp, 200);
+}
+
+#[tokio::test]
+async fn test_cle_session_inconnue_du_store_retombe_sur_ip() {
+    let limiter = Arc::new(
+        RateLimiter::new()
+            .max_requests(1)
+            .retry_after(60)
+            .key_by(RateLimitKey::Session),
+    );
+    let store = Arc::new(MemoryStore::default());
+    // Un cookie inventé à chaque requête ne donne pas un nouveau quota
+    let resp = request::get(
+        session_app(limiter.clone(), store.clone(), Some(Id(1))),
+        "/",
+    )
+    .await;
+    assert_status(&resp, 200);
+    let resp = request::get(session_app(limiter.clone(), store, Some(Id(2))), "/").await;
+    assert_status(&resp, 429);
+}
+
+#[tokio::test]
+async fn test_cle_session_sans_id_retombe_sur_ip() {
+    let limiter = Arc::new(
+        RateLimiter::new()
+            .max_requests(1)
+            .retry_after(60)
+            .key_by(RateLimitKey::Session),
+    );
+    let store = Arc::new(MemoryStore::default());
+    let resp = request::get(session_app(limiter.clone(), store, None), "/").await;
+    assert_status(&resp, 200);
+    assert!(!limiter.is_allowed("unknown"));
+}
@@ -0,0 +1,174 @@
+//! Tests — middleware/request_id.rs
+//! Couvre : id généré (UUID) ou repris de l'en-tête entrant, valeurs entrantes
+//!          refusées, renvoi dans la réponse, en-tête personnalisé, `req.request_id`
+//!          et contexte Tera, page d'erreur, span de tracing, nom d'en-tête invalide
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request as HttpRequest, StatusCode},
+    response::Response,
+    routing::get,
+};
+use runique::app::RuniqueAppBuilder;
+use runique::app::staging::MiddlewareStaging;
+use runique::config::app::RuniqueConfig;
+use runique::context::Request;
+use runique::testing::test_db;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+
+/// Renvoie l'id vu par le handler et celui du contexte Tera.
+async fn echo(req: Request) -> String {
+    tracing::info!("dans le handler");
+    let ctx = req
+        .context
+        .get("request_id")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    format!("{}|{ctx}", req.request_id.unwrap_or_default())
+}
+
+async fn app(m: impl FnOnce(MiddlewareStaging) -> MiddlewareStaging) -> Router {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .middleware(m)
+        .routes(Router::new().route("/", get(echo)))
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"))
+        .router
+}
+
+async fn call(router: &Router, uri: &str, headers: &[(&str, &str)]) -> (Response, String) {
+    let mut req = HttpRequest::get(uri);
+    for (k, v) in headers {
+        req = req.header(*k, *v);
+    }
+    let res = router
+        .clone()
+        .oneshot(req.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let (parts, body) = res.into_parts();
+    let bytes = axum::body::to_bytes(body, usize::MAX).await.unwrap();
+    (
+        Response::from_parts(parts, Body::empty()),
+        String::from_utf8_lossy(&bytes).to_string(),
+    )
+}
+
+#[tokio::test]
+async fn test_id_genere_et_renvoye() {
+    let router = app(|m| m.with_request_id()).await;
+    let (res, body) = call(&router, "/", &[]).await;
+
+    let id = res.headers()["x-request-id"].to_str().unwrap().to_string();
+    assert!(uuid::Uuid::parse_str(&id).is_ok(), "{id}");
+    // Handler et contexte Tera voient le même id
+    assert_eq!(body, format!("{id}|{id}"));
+
+    let (other, _) = call(&router, "/", &[]).await;
+    assert_ne!(other.headers()["x-request-id"], id.as_str());
+}
+
+#[tokio::test]
+async fn test_id_entrant_repris() {
+    let router = app(|m| m.with_request_id()).await;
+    let (res, body) = call(&router, "/", &[("x-request-id", "lb-7f3a.42")]).await;
+    assert_eq!(res.headers()["x-request-id"], "lb-7f3a.42");
+    assert_eq!(body, "lb-7f3a.42|lb-7f3a.42");
+}
+
+#[tokio::test]
+async fn test_id_entrant_invalide_remplace() {
+    let router = app(|m| m.with_request_id()).await;
+    let trop_long = "a".repeat(129);
+    for entrant in ["avec espace", trop_long.as_str()] {
+        let (res, _) = call(&router, "/", &[("x-request-id", entrant)]).await;
+        let id = res.headers()["x-request-id"].to_str().unwrap();
+        assert!(uuid::Uuid::parse_str(id).is_ok(), "{entrant} → {id}");
+    }
+}
+
+#[tokio::test]
+async fn test_en_tete_personnalise() {
+    let router = app(|m| m.with_request_id_header("X-Correlation-Id")).await;
+    let (res, body) = call(&router, "/", &[("x-correlation-id", "corr-1")]).await;
+    assert_eq!(res.headers()["x-correlation-id"], "corr-1");
+    assert!(res.headers().get("x-request-id").is_none());
+    assert_eq!(body, "corr-1|corr-1");
+}
+
+#[tokio::test]
+async fn test_desactive_par_defaut() {
+    let router = app(|m| m).await;
+    let (res, body) = call(&router, "/", &[("x-request-id", "ignore")]).await;
+    assert!(res.headers().get("x-request-id").is_none());
+    assert_eq!(body, "|");
+}
+
+#[tokio::test]
+async fn test_id_affiche_sur_la_page_d_erreur() {
+    let router = app(|m| m.with_request_id().with_debug_errors(true)).await;
+    let (res, body) = call(&router, "/absente", &[("x-request-id", "support-123")]).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    assert_eq!(res.headers()["x-request-id"], "support-123");
+    assert!(body.contains("support-123"), "{body}");
+}
+
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_logs_du_handler_portent_l_id() {
+    let router = app(|m| m.with_request_id()).await;
+
+    let buffer = LogBuffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .finish();
+    // Runtime mono-thread : le handler s'exécute sur ce thread
+    let guard = tracing::subscriber::set_default(subscriber);
+    call(&router, "/", &[("x-request-id", "trace-9")]).await;
+    drop(guard);
+
+    let logs = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    let ligne = logs
+        .lines()
+        .find(|l| l.contains("dans le handler"))
+        .unwrap_or_else(|| panic!("log absent : {logs}"));
+    assert!(ligne.contains("request_id=trace-9"), "{ligne}");
+}
+
+#[tokio::test]
+async fn test_nom_d_en_tete_invalide_refuse_au_build() {
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    let err = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .middleware(|m| m.with_request_id_header("X Request Id"))
+        .build()
+        .await
+        .err()
+        .expect("nom d'en-tête invalide refusé");
+    assert!(err.to_string().contains("request id"), "{err}");
+}
@@ -0,0 +1,121 @@
+//! Tests — middleware/security/security_headers.rs
+//! Couvre : valeurs par défaut, HSTS personnalisé, Referrer-Policy, en-têtes omis (`None`),
+//!          HSTS absent en HTTP simple et en debug sauf `hsts_in_debug`
+
+use axum::{
+    Router,
+    body::Body,
+    http::{HeaderMap, Request},
+    routing::get,
+};
+use runique::app::RuniqueApp;
+use runique::app::staging::MiddlewareStaging;
+use runique::config::RuniqueConfig;
+use runique::middleware::{Hsts, ReferrerPolicy};
+use runique::testing::test_db;
+use tower::ServiceExt;
+
+#[test]
+fn test_valeur_hsts() {
+    assert_eq!(
+        Hsts::default().to_header_value(),
+        "max-age=31536000; includeSubDomains; preload"
+    );
+    assert_eq!(Hsts::new(600).to_header_value(), "max-age=600");
+    assert_eq!(
+        Hsts::new(600).include_subdomains(true).to_header_value(),
+        "max-age=600; includeSubDomains"
+    );
+}
+
+async fn entetes(
+    https: bool,
+    debug: bool,
+    m: fn(MiddlewareStaging) -> MiddlewareStaging,
+) -> HeaderMap {
+    let mut config = RuniqueConfig {
+        debug,
+        ..Default::default()
+    };
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    config.security.enforce_https = https;
+    let app = RuniqueApp::builder(config)
+        .no_statics()
+        .with_database(test_db().await)
+        .routes(Router::new().route("/sh", get(|| async { "ok" })))
+        .middleware(m)
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    app.router
+        .oneshot(Request::get("/sh").body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+        .headers()
+        .clone()
+}
+
+fn valeur<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).map(|v| v.to_str().unwrap())
+}
+
+#[tokio::test]
+async fn test_valeurs_par_defaut() {
+    let headers = entetes(true, false, |m| m).await;
+    assert_eq!(
+        valeur(&headers, "strict-transport-security"),
+        Some("max-age=31536000; includeSubDomains; preload")
+    );
+    assert_eq!(
+        valeur(&headers, "referrer-policy"),
+        Some("strict-origin-when-cross-origin")
+    );
+    assert!(valeur(&headers, "permissions-policy").is_some());
+}
+
+#[tokio::test]
+async fn test_valeurs_personnalisees() {
+    let headers = entetes(true, false, |m| {
+        m.with_security_headers(|h| {
+            h.hsts(Some(Hsts::new(63_072_000).include_subdomains(true)))
+                .referrer_policy(Some(ReferrerPolicy::NoReferrer))
+        })
+    })
+    .await;
+    assert_eq!(
+        valeur(&headers, "strict-transport-security"),
+        Some("max-age=63072000; includeSubDomains")
+    );
+    assert_eq!(valeur(&headers, "referrer-policy"), Some("no-referrer"));
+}
+
+#[tokio::test]
+async fn test_en_tetes_omis() {
+    let headers = entetes(true, false, |m| {
+        m.with_security_headers(|h| h.hsts(None).referrer_policy(None).permissions_policy(false))
+    })
+    .await;
+    assert!(valeur(&headers, "strict-transport-security").is_none());
+    assert!(valeur(&headers, "referrer-policy").is_none());
+    assert!(valeur(&headers, "permissions-policy").is_none());
+    // Les autres en-têtes restent
+    assert_eq!(valeur(&headers, "x-content-type-options"), Some("nosniff"));
+}
+
+#[tokio::test]
+async fn test_hsts_absent_en_http_simple() {
+    let headers = entetes(false, false, |m| m).await;
+    assert!(valeur(&headers, "strict-transport-security").is_none());
+}
+
+#[tokio::test]
+async fn test_hsts_absent_en_debug_sauf_demande() {
+    let headers = entetes(true, true, |m| m).await;
+    assert!(valeur(&headers, "strict-transport-security").is_none());
+
+    let headers = entetes(true, true, |m| {
+        m.with_security_headers(|h| h.hsts_in_debug(true))
+    })
+    .await;
+    assert!(valeur(&headers, "strict-transport-security").is_some());
+}
@@ -13,7 +13,9 @@
 //! | `test_types`            | ParsedSchema et types de colonnes            |
 //! | `test_hooks_def`        | HooksDef, Hook, HookType                     |
 //! | `test_index_def`        | IndexDef                                     |
+//! | `test_introspect`       | ModelSchema::from_table, mapping des types   |
 //! | `test_model_schema`     | ModelSchema, SchemaDiff                      |
+//! | `test_plan`             | plan_pending, migration_status, rollback_to  |
 //! | `test_relation_def`     | RelationDef                                  |
 //! | `test_makemigrations`   | scan_entities, update_migration_lib, paths   |
 //! | `test_migration_flow`   | Flux complet end-to-end (scan→gen→fichiers)  |
@@ -27,6 +29,7 @@ pub mod test_generators;
 pub mod test_helpers;
 pub mod test_hooks_def;
 pub mod test_index_def;
+pub mod test_introspect;
 pub mod test_makemigrations;
 pub mod test_migrate;
 pub mod test_migration_flow;
@@ -37,6 +40,7 @@ pub mod test_parser_extend;
 pub mod test_parser_seaorm;
 pub mod test_parser_seaorm_extra;
 pub mod test_paths;
+pub mod test_plan;
 pub mod test_primary_key;
 pub mod test_relation_def;
 pub mod test_relation_kind;
@@ -299,3 +299,209 @@ fn test_format_label_triple() {
     let label = field.label();
     assert_eq!(label, "Date Of Birth");
 }
+
+// ═══════════════════════════════════════════════════════════════
+// check() — contraintes CHECK
+// ═══════════════════════════════════════════════════════════════
+
+fn create_sql(col: ColumnDef) -> String {
+    use runique::migration::schema::ModelSchema;
+    use sea_query::PostgresQueryBuilder;
+    ModelSchema::new("Review")
+        .column(col)
+        .to_migration()
+        .to_string(PostgresQueryBuilder)
+}
+
+#[test]
+fn test_check_explicite() {
+    let sql = create_sql(ColumnDef::new("email").string().check("email LIKE '%@%'"));
+    assert!(sql.contains("CHECK (email LIKE '%@%')"), "{}", sql);
+}
+
+#[test]
+fn test_check_derive_des_bornes() {
+    let sql = create_sql(ColumnDef::new("rating").integer().min_i64(1).max_i64(5));
+    assert!(
+        sql.contains(r#"CHECK ("rating" >= 1 AND "rating" <= 5)"#),
+        "{}",
+        sql
+    );
+}
+
+#[test]
+fn test_check_explicite_prioritaire_sur_bornes() {
+    let col = ColumnDef::new("rating")
+        .integer()
+        .max_i64(5)
+        .check("rating <> 3");
+    let sql = create_sql(col);
+    assert!(sql.contains("CHECK (rating <> 3)"), "{}", sql);
+    assert!(!sql.contains("<= 5"), "{}", sql);
+}
+
+#[test]
+fn test_check_absent_sans_bornes() {
+    assert!(ColumnDef::new("title").string().check_expr().is_none());
+}
+
+#[test]
+fn test_check_modifie_detecte_par_diff() {
+    let before = ColumnDef::new("rating").integer().check("rating > 0");
+    let after = ColumnDef::new("rating").integer().check("rating > 1");
+    assert_eq!(before.changed_attributes(&after), vec!["check"]);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// comment() — documentation de colonne
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_comment_inline_mysql() {
+    use runique::migration::schema::ModelSchema;
+    use sea_query::MysqlQueryBuilder;
+    let sql = ModelSchema::new("Price")
+        .column(ColumnDef::new("currency").string().comment("Code ISO 4217"))
+        .to_migration()
+        .to_string(MysqlQueryBuilder);
+    assert!(sql.contains("COMMENT 'Code ISO 4217'"), "{}", sql);
+}
+
+#[test]
+fn test_comment_on_column_postgres() {
+    use runique::db::DatabaseEngine;
+    use runique::migration::schema::ModelSchema;
+    let schema = ModelSchema::new("Price")
+        .column(ColumnDef::new("currency").string().comment("L'unité ISO"))
+        .column(ColumnDef::new("amount").integer());
+    let sql = schema
+        .to_migration_sql(&DatabaseEngine::PostgreSQL)
+        .unwrap();
+    assert_eq!(sql.len(), 2);
+    assert!(
+        sql[0].starts_with(r#"CREATE TABLE IF NOT EXISTS "price""#),
+        "{}",
+        sql[0]
+    );
+    assert_eq!(
+        sql[1],
+        r#"COMMENT ON COLUMN "price"."currency" IS 'L''unité ISO'"#
+    );
+    assert!(!create_sql(ColumnDef::new("currency").comment("x")).contains("COMMENT"));
+}
+
+#[test]
+fn test_comment_on_column_postgres_schema_qualifie() {
+    use runique::db::DatabaseEngine;
+    use runique::migration::schema::ModelSchema;
+    let sql = ModelSchema::new("Price")
+        .schema("billing")
+        .column(ColumnDef::new("currency").string().comment("Code ISO"))
+        .to_migration_sql(&DatabaseEngine::PostgreSQL)
+        .unwrap();
+    assert!(
+        sql[0].starts_with(r#"CREATE TABLE IF NOT EXISTS "billing"."price""#),
+        "{}",
+        sql[0]
+    );
+    assert_eq!(
+        sql[1],
+        r#"COMMENT ON COLUMN "billing"."price"."currency" IS 'Code ISO'"#
+    );
+}
+
+#[test]
+fn test_comment_absent_hors_postgres() {
+    use runique::db::DatabaseEngine;
+    use runique::migration::schema::ModelSchema;
+    let schema = ModelSchema::new("Price")
+        .schema("billing")
+        .column(ColumnDef::new("currency").string().comment("Code ISO"));
+    let sqlite = schema.to_migration_sql(&DatabaseEngine::SQLite).unwrap();
+    assert_eq!(sqlite.len(), 1);
+    assert!(!sqlite[0].contains("billing"));
+    assert!(!sqlite[0].contains("COMMENT"));
+    let mysql = schema.to_migration_sql(&DatabaseEngine::MySQL).unwrap();
+    assert_eq!(mysql.len(), 1);
+    assert!(mysql[0].contains("COMMENT 'Code ISO'"), "{}", mysql[0]);
+}
+
+#[test]
+fn test_comment_devient_help_text() {
+    let field = ColumnDef::new("currency")
+        .string()
+        .comment("Code ISO 4217")
+        .to_form_field()
+        .unwrap();
+    assert_eq!(field.help_text(), Some("Code ISO 4217"));
+}
+
+#[test]
+fn test_help_text_rendu_par_le_template_partage() {
+    let mut tera = tera::Tera::default();
+    tera.add_raw_template(
+        "base_string.html",
+        include_str!("../../templates/field_html/base_string.html"),
+    )
+    .unwrap();
+    tera.add_raw_template(
+        "field_help_text.html",
+        include_str!("../../templates/field_html/help_text.html"),
+    )
+    .unwrap();
+    let field = ColumnDef::new("currency")
+        .string()
+        .comment("Code ISO 4217")
+        .to_form_field()
+        .unwrap();
+    let html = field.render(&std::sync::Arc::new(tera)).unwrap();
+    assert!(
+        html.contains(r#"<small class="field-help-text">Code ISO 4217</small>"#),
+        "{}",
+        html
+    );
+}
+
+#[test]
+fn test_sans_comment_pas_de_help_text() {
+    let field = ColumnDef::new("currency").string().to_form_field().unwrap();
+    assert!(field.help_text().is_none());
+}
+
+#[test]
+fn test_comment_modifie_est_metadata_only() {
+    let before = ColumnDef::new("currency").string().comment("old");
+    let after = ColumnDef::new("currency").string().comment("new");
+    assert_eq!(before.changed_attributes(&after), vec!["comment"]);
+    assert!(before.is_metadata_only_change(&after));
+
+    let structural = ColumnDef::new("currency")
+        .string()
+        .nullable()
+        .comment("new");
+    assert!(!before.is_metadata_only_change(&structural));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// soft_delete_marker() — colonne deleted_at
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_soft_delete_marker_colonne_nullable() {
+    let col = ColumnDef::soft_delete_marker();
+    assert_eq!(col.name, "deleted_at");
+    assert!(col.nullable);
+    assert!(col.soft_delete);
+    assert!(matches!(col.col_type, ColumnType::DateTime));
+    let sql = create_sql(col);
+    assert!(
+        sql.contains(r#""deleted_at" timestamp without time zone NULL"#),
+        "{}",
+        sql
+    );
+}
+
+#[test]
+fn test_soft_delete_marker_exclu_du_formulaire() {
+    assert!(ColumnDef::soft_delete_marker().to_form_field().is_none());
+}
@@ -1,7 +1,9 @@
 // Tests pour ForeignKeyDef
 
 use runique::migration::foreign_key::ForeignKeyDef;
-use sea_query::ForeignKeyAction;
+use runique::migration::primary_key::PrimaryKeyDef;
+use runique::migration::schema::ModelSchema;
+use sea_query::{ColumnType, ForeignKeyAction};
 
 // ═══════════════════════════════════════════════════════════════
 // Valeurs par défaut
@@ -96,3 +98,58 @@ fn test_foreign_key_to_sea_foreign_key_compile() {
         .on_delete(ForeignKeyAction::Cascade);
     let _ = fk.to_sea_foreign_key("posts");
 }
+
+// ═══════════════════════════════════════════════════════════════
+// Colonne créée pour la FK — type, nullabilité, compatibilité
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_foreign_key_colonne_par_defaut_entier_non_null() {
+    let col = ForeignKeyDef::new("user_id")
+        .references("users")
+        .to_column_def();
+    assert_eq!(col.name, "user_id");
+    assert_eq!(col.col_type, ColumnType::Integer);
+    assert!(!col.nullable);
+}
+
+#[test]
+fn test_foreign_key_optional_et_set_null_nullables() {
+    assert!(
+        ForeignKeyDef::new("user_id")
+            .optional()
+            .to_column_def()
+            .nullable
+    );
+    assert!(
+        ForeignKeyDef::new("user_id")
+            .on_delete(ForeignKeyAction::SetNull)
+            .to_column_def()
+            .nullable
+    );
+}
+
+#[test]
+fn test_foreign_key_references_model_reprend_la_pk() {
+    let user = ModelSchema::new("User")
+        .table_name("users")
+        .primary_key(PrimaryKeyDef::new("uid").uuid());
+    let fk = ForeignKeyDef::new("user_id").references_model(&user);
+    assert_eq!(fk.to_table, "users");
+    assert_eq!(fk.to_column, "uid");
+    assert_eq!(fk.key_type, Some(ColumnType::Uuid));
+    assert_eq!(fk.to_column_def().col_type, ColumnType::Uuid);
+}
+
+#[test]
+fn test_foreign_key_check_largeurs_entieres_compatibles() {
+    let fk = ForeignKeyDef::new("user_id").key_type(ColumnType::BigInteger);
+    assert!(fk.check_column_type(&ColumnType::Integer).is_ok());
+    assert!(fk.check_column_type(&ColumnType::Uuid).is_err());
+}
+
+#[test]
+fn test_foreign_key_check_sans_type_connu_accepte_tout() {
+    let fk = ForeignKeyDef::new("user_id").references("users");
+    assert!(fk.check_column_type(&ColumnType::Uuid).is_ok());
+}
@@ -973,3 +973,47 @@ fn relations_plusieurs_schemas_toutes_fks_presentes() {
     assert!(content.contains("user_id"));
     assert!(content.contains("post_id"));
 }
+
+// ═══════════════════════════════════════════════════════════════
+// down_warnings — colonne NOT NULL supprimée sans défaut
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_changes_down_warnings_not_null_sans_defaut() {
+    let warnings = simple_changes("users").down_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(
+        warnings[0].starts_with("users.old_col: NOT NULL"),
+        "{}",
+        warnings[0]
+    );
+}
+
+#[test]
+fn test_changes_down_warnings_ignore_nullable_et_defaut() {
+    let mut changes = simple_changes("users");
+    changes.dropped_columns = vec![
+        col_nullable("bio", "String"),
+        ParsedColumn {
+            default_value: Some("0".to_string()),
+            ..col("score", "i32")
+        },
+    ];
+    assert!(changes.down_warnings().is_empty());
+}
+
+#[test]
+fn test_alter_et_batch_down_contiennent_le_warning() {
+    let changes = simple_changes("users");
+    for content in [
+        generate_alter_file(&changes, &DbKind::Other),
+        generate_batch_down_file(&[&changes], "20260101_120000"),
+    ] {
+        let down = &content[content.find("async fn down(").unwrap()..];
+        assert!(
+            down.contains("// WARNING: users.old_col: NOT NULL"),
+            "{}",
+            down
+        );
+    }
+}
@@ -1,6 +1,6 @@
 // Tests pour IndexDef
 
-use runique::migration::index::IndexDef;
+use runique::migration::index::{IndexDef, IndexMethod};
 
 // ═══════════════════════════════════════════════════════════════
 // Valeurs par défaut
@@ -88,3 +88,96 @@ fn test_index_to_sea_index_unique() {
     let idx = IndexDef::new(vec!["email"]).unique();
     let _ = idx.to_sea_index("users");
 }
+
+// ═══════════════════════════════════════════════════════════════
+// Index partiels (WHERE) et méthode d'accès (USING)
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_index_partiel_postgres() {
+    use sea_query::PostgresQueryBuilder;
+    let sql = IndexDef::new(vec!["email"])
+        .unique()
+        .where_clause("deleted_at IS NULL")
+        .to_sea_index("users")
+        .to_string(PostgresQueryBuilder);
+    assert_eq!(
+        sql,
+        r#"CREATE UNIQUE INDEX "idx_users_email" ON "users" ("email") WHERE deleted_at IS NULL"#
+    );
+}
+
+#[test]
+fn test_index_partiel_sqlite() {
+    use sea_query::SqliteQueryBuilder;
+    let sql = IndexDef::new(vec!["email"])
+        .where_clause("deleted_at IS NULL")
+        .to_sea_index("users")
+        .to_string(SqliteQueryBuilder);
+    assert!(sql.ends_with("WHERE deleted_at IS NULL"), "{}", sql);
+}
+
+#[test]
+fn test_index_using_gin_postgres() {
+    use sea_query::PostgresQueryBuilder;
+    let sql = IndexDef::new(vec!["tags"])
+        .using(IndexMethod::Gin)
+        .to_sea_index("posts")
+        .to_string(PostgresQueryBuilder);
+    assert!(sql.contains("USING GIN"), "{}", sql);
+}
+
+#[test]
+fn test_index_partiel_refuse_sur_mysql() {
+    use runique::db::DatabaseEngine;
+    let idx = IndexDef::new(vec!["email"]).where_clause("deleted_at IS NULL");
+    let err = idx
+        .to_sea_index_for("users", &DatabaseEngine::MySQL)
+        .unwrap_err();
+    assert!(err.contains("partial"), "{}", err);
+    assert!(idx.validate_for(&DatabaseEngine::SQLite).is_ok());
+    assert!(idx.validate_for(&DatabaseEngine::PostgreSQL).is_ok());
+}
+
+#[test]
+fn test_index_using_refuse_sur_sqlite() {
+    use runique::db::DatabaseEngine;
+    let gin = IndexDef::new(vec!["tags"]).using(IndexMethod::Gin);
+    assert!(gin.validate_for(&DatabaseEngine::SQLite).is_err());
+    assert!(gin.validate_for(&DatabaseEngine::MySQL).is_err());
+
+    let hash = IndexDef::new(vec!["token"]).using(IndexMethod::Hash);
+    assert!(hash.validate_for(&DatabaseEngine::MySQL).is_ok());
+    assert!(hash.validate_for(&DatabaseEngine::SQLite).is_err());
+    assert!(
+        IndexDef::new(vec!["token"])
+            .using(IndexMethod::BTree)
+            .validate_for(&DatabaseEngine::SQLite)
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_schema_to_index_migrations_erreur_backend() {
+    use runique::db::DatabaseEngine;
+    use runique::migration::{column::ColumnDef, schema::ModelSchema};
+    let schema = ModelSchema::new("User")
+        .column(ColumnDef::new("email").string())
+        .index(
+            IndexDef::new(vec!["email"])
+                .unique()
+                .where_clause("deleted_at IS NULL"),
+        );
+    assert_eq!(
+        schema
+            .to_index_migrations(&DatabaseEngine::PostgreSQL)
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(
+        schema
+            .to_index_migrations(&DatabaseEngine::MariaDB)
+            .is_err()
+    );
+}
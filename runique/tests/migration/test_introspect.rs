@@ -0,0 +1,272 @@
+//! Tests — introspection (`ModelSchema::from_table`, `sql_type_to_column_type`)
+
+use crate::helpers::{db, db_postgres};
+use runique::migration::introspect::sql_type_to_column_type;
+use runique::migration::schema::ModelSchema;
+use sea_query::{ColumnType, ForeignKeyAction, StringLen};
+
+// ═══════════════════════════════════════════════════════════════
+// sql_type_to_column_type — types spécifiques aux backends
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_type_postgres_udt() {
+    assert_eq!(sql_type_to_column_type("int4", None), ColumnType::Integer);
+    assert_eq!(
+        sql_type_to_column_type("int8", None),
+        ColumnType::BigInteger
+    );
+    assert_eq!(
+        sql_type_to_column_type("timestamptz", None),
+        ColumnType::TimestampWithTimeZone
+    );
+    assert_eq!(
+        sql_type_to_column_type("varchar", Some(50)),
+        ColumnType::String(StringLen::N(50))
+    );
+    assert_eq!(
+        sql_type_to_column_type("jsonb", None),
+        ColumnType::JsonBinary
+    );
+}
+
+#[test]
+fn test_type_mysql_column_type() {
+    assert_eq!(
+        sql_type_to_column_type("tinyint(1)", None),
+        ColumnType::Boolean
+    );
+    assert_eq!(
+        sql_type_to_column_type("int(10) unsigned", None),
+        ColumnType::Unsigned
+    );
+    assert_eq!(
+        sql_type_to_column_type("decimal(10,2)", None),
+        ColumnType::Decimal(Some((10, 2)))
+    );
+    assert_eq!(
+        sql_type_to_column_type("varchar(120)", None),
+        ColumnType::String(StringLen::N(120))
+    );
+}
+
+#[test]
+fn test_type_mysql8_tinyint_sans_largeur() {
+    // MySQL 8.0.19+ n'affiche plus la largeur : seul tinyint(1) est un booléen
+    assert_eq!(
+        sql_type_to_column_type("tinyint", None),
+        ColumnType::TinyInteger
+    );
+    assert_eq!(
+        sql_type_to_column_type("tinyint unsigned", None),
+        ColumnType::TinyUnsigned
+    );
+    assert_eq!(
+        sql_type_to_column_type("smallint unsigned", None),
+        ColumnType::SmallUnsigned
+    );
+}
+
+#[test]
+fn test_type_inconnu_fallback_string() {
+    assert_eq!(
+        sql_type_to_column_type("geometry", None),
+        ColumnType::String(StringLen::None)
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// from_table — SQLite en mémoire
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_from_table_sqlite_colonnes_pk_fk() {
+    let conn = db::fresh_db().await;
+    db::exec(
+        &conn,
+        "CREATE TABLE author (id INTEGER PRIMARY KEY, name TEXT)",
+    )
+    .await;
+    db::exec(
+        &conn,
+        "CREATE TABLE blog_post (
+            id INTEGER PRIMARY KEY,
+            title VARCHAR(200) NOT NULL,
+            slug VARCHAR(50) NOT NULL UNIQUE,
+            views INTEGER NOT NULL DEFAULT 0,
+            published BOOLEAN NOT NULL DEFAULT false,
+            created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            author_id INTEGER REFERENCES author(id) ON DELETE CASCADE
+        )",
+    )
+    .await;
+
+    let schema = ModelSchema::from_table(&conn, "blog_post").await.unwrap();
+    assert_eq!(schema.model_name, "BlogPost");
+    assert_eq!(schema.table_name, "blog_post");
+
+    let pk = schema.primary_key.as_ref().unwrap();
+    assert_eq!(pk.name, "id");
+    assert!(pk.auto_increment);
+
+    let col = |name: &str| schema.columns.iter().find(|c| c.name == name).unwrap();
+    assert_eq!(col("title").col_type, ColumnType::String(StringLen::N(200)));
+    assert!(!col("title").nullable);
+    assert!(col("slug").unique);
+    assert_eq!(col("views").default, Some(0i64.into()));
+    assert_eq!(col("published").col_type, ColumnType::Boolean);
+    assert!(col("created_at").auto_now);
+    assert!(col("author_id").nullable);
+
+    assert_eq!(schema.foreign_keys.len(), 1);
+    let fk = &schema.foreign_keys[0];
+    assert_eq!(fk.from_column, "author_id");
+    assert_eq!(fk.to_table, "author");
+    assert_eq!(fk.to_column, "id");
+    assert!(matches!(fk.on_delete, ForeignKeyAction::Cascade));
+
+    assert!(schema.to_model().contains("pub title: String,"));
+}
+
+#[tokio::test]
+async fn test_from_table_sqlite_pk_composite() {
+    let conn = db::fresh_db().await;
+    db::exec(
+        &conn,
+        "CREATE TABLE user_role (user_id INTEGER NOT NULL, role_id INTEGER NOT NULL, PRIMARY KEY (user_id, role_id))",
+    )
+    .await;
+
+    let schema = ModelSchema::from_table(&conn, "user_role").await.unwrap();
+    let pk = schema.primary_key.unwrap();
+    assert!(pk.is_composite());
+    assert!(!pk.auto_increment);
+    assert_eq!(pk.column_names(), vec!["user_id", "role_id"]);
+    assert!(schema.columns.is_empty());
+}
+
+#[tokio::test]
+async fn test_from_table_inexistante_retourne_err() {
+    let conn = db::fresh_db().await;
+    let result = ModelSchema::from_table(&conn, "nope").await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_from_table_sqlite_pk_ordre_de_la_contrainte() {
+    let conn = db::fresh_db().await;
+    db::exec(
+        &conn,
+        "CREATE TABLE membership (user_id INTEGER NOT NULL, group_id INTEGER NOT NULL, PRIMARY KEY (group_id, user_id))",
+    )
+    .await;
+
+    let schema = ModelSchema::from_table(&conn, "membership").await.unwrap();
+    assert_eq!(
+        schema.primary_key.unwrap().column_names(),
+        vec!["group_id", "user_id"]
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// from_table — PostgreSQL (ignoré sans DATABASE_URL_PG)
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_from_table_postgres_colonnes_et_defauts() {
+    let Some(conn) = db_postgres::connect().await else {
+        return;
+    };
+    db_postgres::exec(&conn, "DROP TABLE IF EXISTS inspect_pg_post").await;
+    db_postgres::exec(
+        &conn,
+        "CREATE TABLE inspect_pg_post (
+            id SERIAL PRIMARY KEY,
+            title VARCHAR(200) NOT NULL,
+            status VARCHAR(20) NOT NULL DEFAULT 'draft',
+            price NUMERIC(10,2),
+            created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+        )",
+    )
+    .await;
+
+    let schema = ModelSchema::from_table(&conn, "inspect_pg_post")
+        .await
+        .unwrap();
+    let pk = schema.primary_key.as_ref().unwrap();
+    assert_eq!(pk.name, "id");
+    assert!(pk.auto_increment);
+
+    let col = |name: &str| schema.columns.iter().find(|c| c.name == name).unwrap();
+    assert_eq!(col("title").col_type, ColumnType::String(StringLen::N(200)));
+    assert_eq!(col("status").default, Some("draft".into()));
+    assert_eq!(col("price").col_type, ColumnType::Decimal(Some((10, 2))));
+    assert!(col("price").nullable);
+    assert_eq!(
+        col("created_at").col_type,
+        ColumnType::TimestampWithTimeZone
+    );
+    assert!(col("created_at").auto_now);
+
+    db_postgres::exec(&conn, "DROP TABLE inspect_pg_post").await;
+}
+
+#[tokio::test]
+async fn test_from_table_postgres_pk_et_fk_composites() {
+    let Some(conn) = db_postgres::connect().await else {
+        return;
+    };
+    db_postgres::exec(
+        &conn,
+        "DROP TABLE IF EXISTS inspect_pg_grant, inspect_pg_membership",
+    )
+    .await;
+    db_postgres::exec(
+        &conn,
+        "CREATE TABLE inspect_pg_membership (
+            user_id INTEGER NOT NULL,
+            group_id INTEGER NOT NULL,
+            PRIMARY KEY (group_id, user_id)
+        )",
+    )
+    .await;
+    db_postgres::exec(
+        &conn,
+        "CREATE TABLE inspect_pg_grant (
+            id SERIAL PRIMARY KEY,
+            m_group INTEGER NOT NULL,
+            m_user INTEGER NOT NULL,
+            FOREIGN KEY (m_group, m_user)
+                REFERENCES inspect_pg_membership (group_id, user_id) ON DELETE CASCADE
+        )",
+    )
+    .await;
+
+    let membership = ModelSchema::from_table(&conn, "inspect_pg_membership")
+        .await
+        .unwrap();
+    assert_eq!(
+        membership.primary_key.unwrap().column_names(),
+        vec!["group_id", "user_id"]
+    );
+
+    // Une ligne par colonne de la FK, appariée par position (pas de produit croisé)
+    let grant = ModelSchema::from_table(&conn, "inspect_pg_grant")
+        .await
+        .unwrap();
+    let pairs: Vec<(&str, &str)> = grant
+        .foreign_keys
+        .iter()
+        .map(|fk| (fk.from_column.as_str(), fk.to_column.as_str()))
+        .collect();
+    assert_eq!(pairs, vec![("m_group", "group_id"), ("m_user", "user_id")]);
+    assert!(
+        grant
+            .foreign_keys
+            .iter()
+            .all(|fk| fk.to_table == "inspect_pg_membership"
+                && matches!(fk.on_delete, ForeignKeyAction::Cascade))
+    );
+
+    db_postgres::exec(&conn, "DROP TABLE inspect_pg_grant, inspect_pg_membership").await;
+}
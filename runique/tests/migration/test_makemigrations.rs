@@ -1,10 +1,18 @@
 //! Tests — migration/makemigrations.rs
 //! Couvre : seaorm_alter_module_name, seaorm_alter_file_path,
 //!          update_migration_lib (création + mise à jour), parse_create_file,
-//!          collect_destructive_messages
-
+//!          collect_destructive_messages,
+//!          write_schema_diff_migration / generate_schema_diff_file (SchemaDiff → ALTER)
+
+use runique::migration::utils::generators::generate_schema_diff_file;
+use runique::migration::{
+    column::ColumnDef,
+    primary_key::PrimaryKeyDef,
+    schema::{ModelSchema, SchemaDiff},
+};
 use runique::utils::cli::makemigration::{
     seaorm_alter_file_path, seaorm_alter_module_name, update_migration_lib,
+    write_schema_diff_migration,
 };
 use std::fs;
 
@@ -693,3 +701,175 @@ fn ensure_admin_builtin_idempotent() {
     assert_eq!(content.matches("EihwazUsersMigration").count(), 1);
     assert_eq!(content.matches("AdminTableMigration").count(), 1);
 }
+
+// ═══════════════════════════════════════════════════════════════
+// write_schema_diff_migration — SchemaDiff → fichier ALTER
+// ═══════════════════════════════════════════════════════════════
+
+fn article_diff() -> SchemaDiff {
+    let before = ModelSchema::new("Article")
+        .table_name("articles")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .column(ColumnDef::new("title").varchar(100))
+        .column(ColumnDef::new("views").integer())
+        .column(ColumnDef::new("legacy").text());
+    let after = ModelSchema::new("Article")
+        .table_name("articles")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .column(ColumnDef::new("headline").varchar(100))
+        .column(ColumnDef::new("views").integer().nullable().min_i64(0))
+        .column(ColumnDef::new("slug").varchar(200).unique());
+    before.diff_with_renames(&after, &[("title", "headline")])
+}
+
+/// Extrait le corps de `up` ou `down` du fichier généré.
+fn body<'a>(code: &'a str, fn_name: &str) -> &'a str {
+    let start = code
+        .find(&format!("async fn {}(", fn_name))
+        .expect("fonction présente");
+    let rest = &code[start..];
+    let end = rest.find("Ok(())").unwrap();
+    &rest[..end]
+}
+
+#[test]
+fn test_schema_diff_vide_n_ecrit_rien() {
+    let dir = temp_dir("diff_vide");
+    let schema = ModelSchema::new("Article").primary_key(PrimaryKeyDef::new("id"));
+    let written =
+        write_schema_diff_migration(dir.to_str().unwrap(), &schema.diff(&schema)).unwrap();
+    assert!(written.is_none());
+    assert!(!dir.join("lib.rs").exists());
+}
+
+#[test]
+fn test_schema_diff_ecrit_et_enregistre_la_migration() {
+    let dir = temp_dir("diff_ecrit");
+    let module = write_schema_diff_migration(dir.to_str().unwrap(), &article_diff())
+        .unwrap()
+        .expect("module écrit");
+
+    // m{YYYYMMDD}_{HHMMSS}_alter_articles_table
+    let stamp = module
+        .strip_prefix('m')
+        .and_then(|m| m.strip_suffix("_alter_articles_table"))
+        .expect("nom horodaté");
+    assert_eq!(stamp.len(), 15);
+    assert!(stamp.chars().all(|c| c.is_ascii_digit() || c == '_'));
+
+    assert!(dir.join(format!("{}.rs", module)).exists());
+    let lib = fs::read_to_string(dir.join("lib.rs")).unwrap();
+    assert!(lib.contains(&format!("mod {};", module)));
+    assert!(lib.contains(&format!("Box::new({}::Migration)", module)));
+}
+
+#[test]
+fn test_schema_diff_up_dans_l_ordre() {
+    let code = generate_schema_diff_file(&article_diff());
+    let up = body(&code, "up");
+
+    let rename = up
+        .find(r#".rename_column(Alias::new("title"), Alias::new("headline"))"#)
+        .expect("rename");
+    let add = up.find(".add_column(").expect("add");
+    let modify = up.find(".modify_column(").expect("modify");
+    let drop = up
+        .find(r#".drop_column(Alias::new("legacy"))"#)
+        .expect("drop");
+    assert!(rename < add && add < modify && modify < drop, "{up}");
+
+    assert!(up.contains(
+        r#"ColumnDef::new_with_type(Alias::new("slug"), ColumnType::String(StringLen::N(200))).not_null().unique_key()"#
+    ));
+    assert!(up.contains(
+        r#"ColumnDef::new_with_type(Alias::new("views"), ColumnType::Integer).null().check(Expr::col(Alias::new("views")).gte(0i64))"#
+    ));
+}
+
+#[test]
+fn test_schema_diff_down_inverse_chaque_changement() {
+    let code = generate_schema_diff_file(&article_diff());
+    let down = body(&code, "down");
+
+    assert!(down.contains(
+        r#".modify_column(ColumnDef::new_with_type(Alias::new("views"), ColumnType::Integer).not_null())"#
+    ));
+    assert!(down.contains(r#".rename_column(Alias::new("headline"), Alias::new("title"))"#));
+    assert!(down.contains(
+        r#".add_column(ColumnDef::new_with_type(Alias::new("legacy"), ColumnType::Text).not_null())"#
+    ));
+    assert!(down.contains(r#".drop_column(Alias::new("slug"))"#));
+    // `legacy` est NOT NULL sans défaut : le down le signale
+    assert!(down.contains("// WARNING: articles.legacy"), "{down}");
+}
+
+#[test]
+fn test_schema_diff_defaut_et_commentaire() {
+    let before = ModelSchema::new("Article").primary_key(PrimaryKeyDef::new("id"));
+    let after = ModelSchema::new("Article")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .column(
+            ColumnDef::new("status")
+                .varchar(20)
+                .default("draft".into())
+                .comment("Publication \"state\""),
+        );
+    let code = generate_schema_diff_file(&before.diff(&after));
+    assert!(
+        code.contains(r#".comment("Publication \"state\"").default("draft")"#),
+        "{code}"
+    );
+}
+
+#[test]
+fn test_schema_diff_defauts_portables_entre_backends() {
+    let date = chrono::NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+    let before = ModelSchema::new("Article").primary_key(PrimaryKeyDef::new("id"));
+    let after = ModelSchema::new("Article")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .column(ColumnDef::new("published").date().default(date.into()))
+        .column(
+            ColumnDef::new("meta")
+                .json()
+                .default(serde_json::json!({"tag": "l'été"}).into()),
+        )
+        .column(
+            ColumnDef::new("price")
+                .decimal()
+                .default(rust_decimal::Decimal::new(1999, 2).into()),
+        );
+    let code = generate_schema_diff_file(&before.diff(&after));
+
+    // Aucun littéral propre à PostgreSQL (E'…', '…'::json) : le backend cible quote à l'exécution
+    assert!(code.contains(r#".default("2026-01-15")"#), "{code}");
+    assert!(
+        code.contains(r#".default("{\"tag\":\"l'été\"}")"#),
+        "{code}"
+    );
+    assert!(code.contains(r#".default(Expr::cust("19.99"))"#), "{code}");
+    assert!(!code.contains("E'"), "{code}");
+}
+
+#[test]
+fn test_schema_diff_enum_etendu_selon_le_backend() {
+    let status = |variants: Vec<String>| {
+        ModelSchema::new("Article")
+            .column(ColumnDef::new("status").enum_type("article_status", variants))
+    };
+    let before = status(vec!["draft".into()]);
+    let after = status(vec!["draft".into(), "archived".into()]);
+    let code = generate_schema_diff_file(&before.diff(&after));
+    let up = body(&code, "up");
+    let down = body(&code, "down");
+
+    assert!(up.contains("match manager.get_database_backend()"), "{up}");
+    assert!(
+        up.contains(r#".execute_unprepared("ALTER TYPE \"article_status\" ADD VALUE IF NOT EXISTS 'archived'")"#),
+        "{up}"
+    );
+    assert!(up.contains("sea_orm::DbBackend::MySql =>"), "{up}");
+    assert!(up.contains(".modify_column("), "{up}");
+    // PostgreSQL ne sait pas retirer une valeur : seul MySQL restaure l'ancienne liste
+    assert!(!down.contains("Postgres"), "{down}");
+    assert!(down.contains("sea_orm::DbBackend::MySql =>"), "{down}");
+}
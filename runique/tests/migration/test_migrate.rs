@@ -11,7 +11,7 @@
 
 use crate::helpers::db_mariadb as db_maria;
 use crate::helpers::db_postgres as db_pg;
-use runique::utils::cli::migrate::{down, status, up};
+use runique::utils::cli::migrate::{down, rollback, status, up};
 use serial_test::serial;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -560,3 +560,109 @@ async fn test_down_batch_postgres() {
         std::env::remove_var("DATABASE_URL");
     }
 }
+
+// ═══════════════════════════════════════════════════════════════
+// rollback(steps) — validation avant connexion
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_rollback_zero_steps_retourne_err() {
+    let dir = temp_dir("rollback_zero");
+    let result = rollback(dir.to_str().unwrap(), 0).await;
+    assert!(result.is_err(), "rollback(0) doit Err");
+}
+
+#[tokio::test]
+async fn test_rollback_trop_de_steps_retourne_err() {
+    let dir = temp_dir("rollback_too_many");
+    let by_time = applied_dir(&dir).join("by_time");
+    fs::create_dir_all(&by_time).unwrap();
+    fs::write(
+        by_time.join("20260101_120000.rs"),
+        down_drop_column_source("users", "bio"),
+    )
+    .unwrap();
+
+    let result = rollback(dir.to_str().unwrap(), 2).await;
+    let err = result.expect_err("rollback(2) avec 1 batch doit Err");
+    assert!(err.to_string().contains("only 1 available"), "{}", err);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// rollback(steps) — SQLite : transaction unique + batch marqué
+// ═══════════════════════════════════════════════════════════════
+
+async fn sqlite_with_users(dir: &Path) -> (String, sea_orm::DatabaseConnection) {
+    use sea_orm::ConnectionTrait;
+    let url = format!("sqlite://{}?mode=rwc", dir.join("rollback.db").display());
+    let db = sea_orm::Database::connect(&url).await.unwrap();
+    db.execute_unprepared("CREATE TABLE users (id INTEGER PRIMARY KEY, bio TEXT, age INTEGER)")
+        .await
+        .unwrap();
+    (url, db)
+}
+
+async fn column_names(db: &sea_orm::DatabaseConnection) -> Vec<String> {
+    use sea_orm::{ConnectionTrait, Statement};
+    db.query_all_raw(Statement::from_string(
+        db.get_database_backend(),
+        "SELECT name FROM pragma_table_info('users')",
+    ))
+    .await
+    .unwrap()
+    .iter()
+    .map(|r| r.try_get::<String>("", "name").unwrap())
+    .collect()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_rollback_sqlite_marque_le_batch() {
+    let dir = temp_dir("rollback_sqlite_mark");
+    let (url, db) = sqlite_with_users(&dir).await;
+    let by_time = applied_dir(&dir).join("by_time");
+    fs::create_dir_all(&by_time).unwrap();
+    fs::write(
+        by_time.join("20260101_120000.rs"),
+        down_drop_column_source("users", "bio"),
+    )
+    .unwrap();
+    unsafe { std::env::set_var("DATABASE_URL", &url) };
+
+    rollback(dir.to_str().unwrap(), 1).await.unwrap();
+    assert!(!column_names(&db).await.contains(&"bio".to_string()));
+    assert!(by_time.join("20260101_120000.rs.rolled_back").exists());
+
+    // Le même batch n'est pas rejoué
+    let err = rollback(dir.to_str().unwrap(), 1).await.unwrap_err();
+    assert!(err.to_string().contains("only 0 available"), "{}", err);
+
+    unsafe { std::env::remove_var("DATABASE_URL") };
+}
+
+#[tokio::test]
+#[serial]
+async fn test_rollback_sqlite_plusieurs_steps_atomique() {
+    let dir = temp_dir("rollback_sqlite_atomic");
+    let (url, db) = sqlite_with_users(&dir).await;
+    let by_time = applied_dir(&dir).join("by_time");
+    fs::create_dir_all(&by_time).unwrap();
+    // Le plus ancien échoue (colonne absente) : le plus récent doit être annulé aussi
+    fs::write(
+        by_time.join("20260101_120000.rs"),
+        down_drop_column_source("users", "missing"),
+    )
+    .unwrap();
+    fs::write(
+        by_time.join("20260102_120000.rs"),
+        down_drop_column_source("users", "age"),
+    )
+    .unwrap();
+    unsafe { std::env::set_var("DATABASE_URL", &url) };
+
+    assert!(rollback(dir.to_str().unwrap(), 2).await.is_err());
+    assert!(column_names(&db).await.contains(&"age".to_string()));
+    assert!(by_time.join("20260102_120000.rs").exists());
+
+    unsafe { std::env::remove_var("DATABASE_URL") };
+}
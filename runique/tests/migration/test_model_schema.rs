@@ -164,10 +164,201 @@ fn test_schema_diff_colonne_supprimee() {
     let diff = old.diff(&new);
     assert!(!diff.is_empty());
     assert_eq!(diff.dropped_columns.len(), 1);
-    assert_eq!(diff.dropped_columns[0], "email");
+    assert_eq!(diff.dropped_columns[0].name, "email");
     assert!(diff.added_columns.is_empty());
 }
 
+#[test]
+fn test_schema_diff_nullable_vers_not_null() {
+    let old = ModelSchema::new("Blog").column(ColumnDef::new("title").string().nullable());
+    let new = ModelSchema::new("Blog").column(ColumnDef::new("title").string().required());
+    let diff = old.diff(&new);
+    assert!(!diff.is_empty());
+    assert!(diff.added_columns.is_empty());
+    assert!(diff.dropped_columns.is_empty());
+    assert_eq!(diff.modified_columns.len(), 1);
+    let (before, after) = &diff.modified_columns[0];
+    assert!(before.nullable);
+    assert!(!after.nullable);
+    assert_eq!(before.changed_attributes(after), vec!["nullable"]);
+}
+
+#[test]
+fn test_schema_diff_varchar_vers_text() {
+    let old = ModelSchema::new("Blog").column(ColumnDef::new("body").varchar(50));
+    let new = ModelSchema::new("Blog").column(ColumnDef::new("body").text());
+    let diff = old.diff(&new);
+    assert_eq!(diff.modified_columns.len(), 1);
+    let (before, after) = &diff.modified_columns[0];
+    assert_eq!(before.name, "body");
+    assert_eq!(before.changed_attributes(after), vec!["col_type"]);
+}
+
+#[test]
+fn test_schema_diff_bornes_numeriques_modifiees() {
+    let old = ModelSchema::new("Blog").column(ColumnDef::new("rating").integer().max_i64(5));
+    let new = ModelSchema::new("Blog").column(ColumnDef::new("rating").integer().max_i64(10));
+    let diff = old.diff(&new);
+    assert_eq!(diff.modified_columns.len(), 1);
+    let (before, after) = &diff.modified_columns[0];
+    assert_eq!(before.changed_attributes(after), vec!["max_value"]);
+}
+
+#[test]
+fn test_schema_diff_colonne_identique_non_modifiee() {
+    let old = ModelSchema::new("Blog").column(ColumnDef::new("slug").varchar(100).unique());
+    let new = ModelSchema::new("Blog").column(ColumnDef::new("slug").varchar(100).unique());
+    let diff = old.diff(&new);
+    assert!(diff.modified_columns.is_empty());
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Renommages
+// ═══════════════════════════════════════════════════════════════
+
+fn bio_schema() -> ModelSchema {
+    ModelSchema::new("Profile")
+        .column(ColumnDef::new("name").string())
+        .column(ColumnDef::new("bio").text().nullable())
+}
+
+fn about_schema() -> ModelSchema {
+    ModelSchema::new("Profile")
+        .column(ColumnDef::new("name").string())
+        .column(ColumnDef::new("about").text().nullable())
+}
+
+#[test]
+fn test_schema_diff_renommage_sans_hint_drop_add() {
+    let diff = bio_schema().diff(&about_schema());
+    assert!(diff.renamed_columns.is_empty());
+    assert_eq!(diff.added_columns.len(), 1);
+    assert_eq!(diff.dropped_columns.len(), 1);
+}
+
+#[test]
+fn test_schema_diff_renommage_avec_hint() {
+    let diff = bio_schema().diff_with_renames(&about_schema(), &[("bio", "about")]);
+    assert_eq!(
+        diff.renamed_columns,
+        vec![("bio".to_string(), "about".to_string())]
+    );
+    assert!(diff.added_columns.is_empty());
+    assert!(diff.dropped_columns.is_empty());
+    assert!(diff.modified_columns.is_empty());
+}
+
+#[test]
+fn test_schema_diff_renommage_hint_obsolete_ignore() {
+    // "bio" existe encore → le hint ne s'applique pas
+    let new = about_schema().column(ColumnDef::new("bio").text().nullable());
+    let diff = bio_schema().diff_with_renames(&new, &[("bio", "about")]);
+    assert!(diff.renamed_columns.is_empty());
+    assert_eq!(diff.added_columns.len(), 1);
+}
+
+#[test]
+fn test_schema_diff_renommage_avec_changement_de_type() {
+    let new = ModelSchema::new("Profile")
+        .column(ColumnDef::new("name").string())
+        .column(ColumnDef::new("about").varchar(500).nullable());
+    let diff = bio_schema().diff_with_renames(&new, &[("bio", "about")]);
+    assert_eq!(diff.renamed_columns.len(), 1);
+    assert_eq!(diff.modified_columns.len(), 1);
+    assert_eq!(diff.modified_columns[0].0.name, "about");
+}
+
+#[test]
+fn test_schema_diff_detect_renames_heuristique() {
+    let diff = bio_schema().diff(&about_schema()).detect_renames();
+    assert_eq!(
+        diff.renamed_columns,
+        vec![("bio".to_string(), "about".to_string())]
+    );
+    assert!(diff.added_columns.is_empty());
+    assert!(diff.dropped_columns.is_empty());
+}
+
+#[test]
+fn test_schema_diff_detect_renames_types_differents_ignore() {
+    let new = ModelSchema::new("Profile")
+        .column(ColumnDef::new("name").string())
+        .column(ColumnDef::new("about").integer());
+    let diff = bio_schema().diff(&new).detect_renames();
+    assert!(diff.renamed_columns.is_empty());
+    assert_eq!(diff.dropped_columns.len(), 1);
+}
+
+#[test]
+fn test_schema_diff_detect_renames_plusieurs_candidats_ignore() {
+    let old = bio_schema().column(ColumnDef::new("motto").text().nullable());
+    let new = about_schema().column(ColumnDef::new("tagline").text().nullable());
+    let diff = old.diff(&new).detect_renames();
+    assert!(diff.renamed_columns.is_empty());
+    assert_eq!(diff.dropped_columns.len(), 2);
+}
+
+#[test]
+fn test_schema_diff_up_renommage_sans_drop() {
+    use sea_query::PostgresQueryBuilder;
+    let diff = bio_schema().diff(&about_schema()).detect_renames();
+    let sql: Vec<String> = diff
+        .to_up_migration()
+        .iter()
+        .map(|st| st.to_string(PostgresQueryBuilder))
+        .collect();
+    assert_eq!(sql.len(), 1);
+    assert!(
+        sql[0].contains(r#"RENAME COLUMN "bio" TO "about""#),
+        "{}",
+        sql[0]
+    );
+    assert!(
+        sql.iter().all(|st| !st.contains("DROP COLUMN")),
+        "{:?}",
+        sql
+    );
+}
+
+#[test]
+fn test_schema_diff_up_renommage_puis_modification() {
+    use sea_query::PostgresQueryBuilder;
+    let new = ModelSchema::new("Profile")
+        .column(ColumnDef::new("name").string())
+        .column(ColumnDef::new("about").varchar(500).nullable());
+    let sql: Vec<String> = bio_schema()
+        .diff_with_renames(&new, &[("bio", "about")])
+        .to_up_migration()
+        .iter()
+        .map(|st| st.to_string(PostgresQueryBuilder))
+        .collect();
+    assert_eq!(sql.len(), 2);
+    assert!(sql[0].contains("RENAME COLUMN"), "{}", sql[0]);
+    assert!(sql[1].contains(r#"ALTER COLUMN "about""#), "{}", sql[1]);
+    assert!(
+        sql.iter().all(|st| !st.contains("DROP COLUMN")),
+        "{:?}",
+        sql
+    );
+}
+
+#[test]
+fn test_schema_diff_down_inverse_renommage() {
+    use sea_query::PostgresQueryBuilder;
+    let diff = bio_schema().diff_with_renames(&about_schema(), &[("bio", "about")]);
+    let sql: Vec<String> = diff
+        .to_down_migration()
+        .iter()
+        .map(|st| st.to_string(PostgresQueryBuilder))
+        .collect();
+    assert_eq!(sql.len(), 1);
+    assert!(
+        sql[0].contains(r#"RENAME COLUMN "about" TO "bio""#),
+        "{}",
+        sql[0]
+    );
+}
+
 // ═══════════════════════════════════════════════════════════════
 // SchemaDiff
 // ═══════════════════════════════════════════════════════════════
@@ -182,6 +373,287 @@ fn test_schema_diff_new_est_vide() {
     assert!(diff.modified_columns.is_empty());
 }
 
+// ═══════════════════════════════════════════════════════════════
+// Clé primaire composite
+// ═══════════════════════════════════════════════════════════════
+
+fn user_role_schema() -> ModelSchema {
+    use sea_query::ColumnType;
+    ModelSchema::new("UserRole").primary_key(PrimaryKeyDef::composite(vec![
+        ("user_id".to_string(), ColumnType::Integer),
+        ("role_id".to_string(), ColumnType::Integer),
+    ]))
+}
+
+#[test]
+fn test_schema_pk_composite_to_migration() {
+    use sea_query::PostgresQueryBuilder;
+    let sql = user_role_schema()
+        .to_migration()
+        .to_string(PostgresQueryBuilder);
+    assert!(
+        sql.contains(r#"PRIMARY KEY ("user_id", "role_id")"#),
+        "{}",
+        sql
+    );
+}
+
+#[test]
+fn test_schema_pk_composite_to_model() {
+    let model = user_role_schema().to_model();
+    assert_eq!(
+        model
+            .matches("#[sea_orm(primary_key, auto_increment = false)]")
+            .count(),
+        2
+    );
+    assert!(model.contains("pub user_id: i32,"));
+    assert!(model.contains("pub role_id: i32,"));
+}
+
+#[test]
+fn test_schema_pk_composite_auto_increment_build_err() {
+    use sea_query::ColumnType;
+    let result = ModelSchema::new("UserRole")
+        .primary_key(
+            PrimaryKeyDef::composite(vec![
+                ("user_id".to_string(), ColumnType::Integer),
+                ("role_id".to_string(), ColumnType::Integer),
+            ])
+            .auto_increment(),
+        )
+        .build();
+    assert!(result.unwrap_err().contains("auto_increment"));
+}
+
+#[test]
+fn test_schema_to_model_soft_delete_manager() {
+    let s = ModelSchema::new("Post")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .column(ColumnDef::new("title").string())
+        .column(ColumnDef::soft_delete_marker());
+    let out = s.to_model();
+    assert!(
+        out.contains("impl_objects!(Entity, soft_delete = Column::DeletedAt);"),
+        "{}",
+        out
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Down migrations
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_schema_to_down_migration_drop_table() {
+    use sea_query::PostgresQueryBuilder;
+    let s = ModelSchema::new("Post")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .column(ColumnDef::new("title").string());
+    let sql = s.to_down_migration().to_string(PostgresQueryBuilder);
+    assert_eq!(sql, r#"DROP TABLE IF EXISTS "post""#);
+}
+
+#[test]
+fn test_schema_diff_comment_seul_metadata_only() {
+    let old = ModelSchema::new("Blog").column(ColumnDef::new("title").string().comment("Titre"));
+    let new =
+        ModelSchema::new("Blog").column(ColumnDef::new("title").string().comment("Titre affiché"));
+    let diff = old.diff(&new);
+    assert_eq!(diff.modified_columns.len(), 1);
+    assert!(diff.is_metadata_only());
+
+    let typed = ModelSchema::new("Blog").column(ColumnDef::new("title").text().comment("Titre"));
+    assert!(!old.diff(&typed).is_metadata_only());
+}
+
+#[test]
+fn test_schema_diff_down_inverse_ajout_et_suppression() {
+    use sea_query::PostgresQueryBuilder;
+    let old = ModelSchema::new("Blog")
+        .column(ColumnDef::new("title").string())
+        .column(ColumnDef::new("legacy").string().nullable());
+    let new = ModelSchema::new("Blog")
+        .column(ColumnDef::new("title").string())
+        .column(ColumnDef::new("summary").text().nullable());
+    let diff = old.diff(&new);
+    let sql: Vec<String> = diff
+        .to_down_migration()
+        .iter()
+        .map(|st| st.to_string(PostgresQueryBuilder))
+        .collect();
+    assert_eq!(sql.len(), 2);
+    assert!(sql[0].contains("ADD COLUMN \"legacy\""), "{}", sql[0]);
+    assert!(sql[1].contains("DROP COLUMN \"summary\""), "{}", sql[1]);
+    assert!(diff.down_warnings().is_empty());
+}
+
+#[test]
+fn test_schema_diff_down_restaure_colonne_modifiee() {
+    use sea_query::PostgresQueryBuilder;
+    let old = ModelSchema::new("Blog").column(ColumnDef::new("body").varchar(50));
+    let new = ModelSchema::new("Blog").column(ColumnDef::new("body").text());
+    let sql: Vec<String> = old
+        .diff(&new)
+        .to_down_migration()
+        .iter()
+        .map(|st| st.to_string(PostgresQueryBuilder))
+        .collect();
+    assert_eq!(sql.len(), 1);
+    assert!(sql[0].contains("varchar(50)"), "{}", sql[0]);
+}
+
+#[test]
+fn test_schema_diff_down_warning_not_null_sans_defaut() {
+    let old = ModelSchema::new("Blog")
+        .column(ColumnDef::new("title").string())
+        .column(ColumnDef::new("author").string());
+    let new = ModelSchema::new("Blog").column(ColumnDef::new("title").string());
+    let warnings = old.diff(&new).down_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("blog.author"));
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Enum — ajout de variantes sans recréer la colonne
+// ═══════════════════════════════════════════════════════════════
+
+fn status_schema(variants: &[&str]) -> ModelSchema {
+    ModelSchema::new("Post").column(ColumnDef::new("status").enum_type(
+        "post_status",
+        variants.iter().map(|v| v.to_string()).collect(),
+    ))
+}
+
+#[test]
+fn test_enum_ajout_en_fin_etend_le_type() {
+    let diff = status_schema(&["draft", "published"]).diff(&status_schema(&[
+        "draft",
+        "published",
+        "archived",
+    ]));
+    assert!(diff.modified_columns.is_empty());
+    assert_eq!(diff.extended_enums.len(), 1);
+    assert!(!diff.is_empty());
+    assert!(diff.unsafe_changes().is_empty());
+    // Pas de MODIFY/ALTER COLUMN générique : l'extension passe par to_enum_migration_sql
+    assert!(diff.to_up_migration().is_empty());
+}
+
+#[test]
+fn test_enum_ajout_postgres_alter_type_add_value() {
+    use runique::db::DatabaseEngine;
+    let diff = status_schema(&["draft", "published"]).diff(&status_schema(&[
+        "draft",
+        "published",
+        "archived",
+    ]));
+    assert_eq!(
+        diff.to_enum_migration_sql(&DatabaseEngine::PostgreSQL),
+        [r#"ALTER TYPE "post_status" ADD VALUE IF NOT EXISTS 'archived'"#]
+    );
+}
+
+#[test]
+fn test_enum_ajout_au_milieu_postgres_before() {
+    use runique::db::DatabaseEngine;
+    let diff = status_schema(&["draft", "published"]).diff(&status_schema(&[
+        "draft",
+        "review",
+        "published",
+        "archived",
+    ]));
+    assert_eq!(
+        diff.to_enum_migration_sql(&DatabaseEngine::PostgreSQL),
+        [
+            r#"ALTER TYPE "post_status" ADD VALUE IF NOT EXISTS 'review' BEFORE 'published'"#,
+            r#"ALTER TYPE "post_status" ADD VALUE IF NOT EXISTS 'archived'"#,
+        ]
+    );
+}
+
+#[test]
+fn test_enum_ajout_mysql_et_sqlite() {
+    use runique::db::DatabaseEngine;
+    let diff = status_schema(&["draft", "published"]).diff(&status_schema(&[
+        "draft",
+        "published",
+        "archived",
+    ]));
+    let mysql = diff.to_enum_migration_sql(&DatabaseEngine::MySQL);
+    assert_eq!(mysql.len(), 1);
+    assert!(mysql[0].contains("MODIFY COLUMN `status`"), "{}", mysql[0]);
+    assert!(
+        mysql[0].contains("ENUM('draft', 'published', 'archived')"),
+        "{}",
+        mysql[0]
+    );
+    // SQLite : colonne texte sans contrainte, rien à modifier
+    assert!(
+        diff.to_enum_migration_sql(&DatabaseEngine::SQLite)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_enum_suppression_signalee_unsafe() {
+    let diff = status_schema(&["draft", "published", "archived"])
+        .diff(&status_schema(&["draft", "published"]));
+    assert!(diff.extended_enums.is_empty());
+    assert_eq!(diff.modified_columns.len(), 1);
+    let unsafe_changes = diff.unsafe_changes();
+    assert_eq!(unsafe_changes.len(), 1);
+    assert!(
+        unsafe_changes[0].starts_with("post.status"),
+        "{}",
+        unsafe_changes[0]
+    );
+}
+
+#[test]
+fn test_enum_reordonnancement_signale_unsafe() {
+    let diff = status_schema(&["draft", "published"]).diff(&status_schema(&[
+        "published",
+        "draft",
+        "archived",
+    ]));
+    assert!(diff.extended_enums.is_empty());
+    assert_eq!(diff.unsafe_changes().len(), 1);
+}
+
+#[test]
+fn test_enum_ajout_et_autre_changement_reste_modifie() {
+    let old = status_schema(&["draft"]);
+    let new = ModelSchema::new("Post").column(
+        ColumnDef::new("status")
+            .enum_type("post_status", vec!["draft".into(), "archived".into()])
+            .nullable(),
+    );
+    let diff = old.diff(&new);
+    assert!(diff.extended_enums.is_empty());
+    assert_eq!(diff.modified_columns.len(), 1);
+    assert!(diff.unsafe_changes().is_empty());
+}
+
+#[test]
+fn test_enum_ajout_reflete_dans_le_choice_field() {
+    let diff = status_schema(&["draft", "published"]).diff(&status_schema(&[
+        "draft",
+        "published",
+        "archived",
+    ]));
+    let (_, after) = &diff.extended_enums[0];
+    use runique::forms::base::FormField;
+    let meta = after.to_form_field().unwrap().to_json_meta();
+    let values: Vec<&str> = meta["choices"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["value"].as_str().unwrap())
+        .collect();
+    assert_eq!(values, ["draft", "published", "archived"]);
+}
+
 // ═══════════════════════════════════════════════════════════════
 // to_migration() — ne panique pas
 // ═══════════════════════════════════════════════════════════════
@@ -263,10 +735,24 @@ fn test_schema_to_model_colonne_ignoree_absente() {
 }
 
 #[test]
-fn test_schema_to_model_contient_active_model_behavior() {
+fn test_schema_to_model_contient_impl_signals() {
     let s = ModelSchema::new("User").primary_key(PrimaryKeyDef::new("id"));
     let code = s.to_model();
-    assert!(code.contains("ActiveModelBehavior"));
+    assert!(code.contains("impl_signals!(ActiveModel);"));
+}
+
+#[test]
+fn test_schema_to_model_impl_signals_avec_auto_now() {
+    let s = ModelSchema::new("Article")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .column(ColumnDef::new("created_at").datetime().auto_now())
+        .column(ColumnDef::new("updated_at").datetime().auto_now_update());
+    let code = s.to_model();
+    assert!(code.contains("auto_now = [Column::CreatedAt]"), "{code}");
+    assert!(
+        code.contains("auto_now_update = [Column::UpdatedAt]"),
+        "{code}"
+    );
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -296,10 +782,19 @@ fn test_schema_to_model_belongs_to() {
         .relation(RelationDef::belongs_to("User", "user_id", "id"));
     let code = s.to_model();
     assert!(
-        code.contains("belongs_to"),
-        "BelongsTo doit générer belongs_to"
+        code.contains(
+            "    #[sea_orm(belongs_to = \"super::user::Entity\", from = \"Column::UserId\", to = \"super::user::Column::Id\")]\n    User,\n"
+        ),
+        "{}",
+        code
+    );
+    assert!(
+        code.contains(
+            "impl Related<super::user::Entity> for Entity {\n    fn to() -> RelationDef {\n        Relation::User.def()\n    }\n}\n"
+        ),
+        "{}",
+        code
     );
-    assert!(code.contains("user_id") || code.contains("UserId"));
 }
 
 #[test]
@@ -308,7 +803,13 @@ fn test_schema_to_model_has_many() {
         .primary_key(PrimaryKeyDef::new("id"))
         .relation(RelationDef::has_many("post"));
     let code = s.to_model();
-    assert!(code.contains("has_many"), "HasMany doit générer has_many");
+    assert!(
+        code.contains("    #[sea_orm(has_many = \"super::post::Entity\")]\n    Post,\n"),
+        "{}",
+        code
+    );
+    assert!(code.contains("impl Related<super::post::Entity> for Entity {"));
+    assert!(code.contains("        Relation::Post.def()\n"));
 }
 
 #[test]
@@ -317,7 +818,13 @@ fn test_schema_to_model_has_one() {
         .primary_key(PrimaryKeyDef::new("id"))
         .relation(RelationDef::has_one("profile"));
     let code = s.to_model();
-    assert!(code.contains("has_many") || code.contains("has_one") || code.contains("Profile"));
+    assert!(
+        code.contains("    #[sea_orm(has_one = \"super::profile::Entity\")]\n    Profile,\n"),
+        "{}",
+        code
+    );
+    assert!(!code.contains("has_many"));
+    assert!(code.contains("impl Related<super::profile::Entity> for Entity {"));
 }
 
 #[test]
@@ -326,10 +833,50 @@ fn test_schema_to_model_many_to_many() {
         .primary_key(PrimaryKeyDef::new("id"))
         .relation(RelationDef::many_to_many("tag", "post_tag"));
     let code = s.to_model();
+    // La relation directe porte sur la table de jonction
+    assert!(
+        code.contains("    #[sea_orm(has_many = \"super::post_tag::Entity\")]\n    PostTag,\n"),
+        "{}",
+        code
+    );
+    assert!(!code.contains("many_to_many"));
+    assert!(
+        code.contains(
+            "impl Related<super::tag::Entity> for Entity {\n    fn to() -> RelationDef {\n        super::post_tag::Relation::Tag.def()\n    }\n\n    fn via() -> Option<RelationDef> {\n        Some(super::post_tag::Relation::Post.def().rev())\n    }\n}\n"
+        ),
+        "{}",
+        code
+    );
+}
+
+#[test]
+fn test_schema_to_model_sans_relation_enum_vide() {
+    let code = ModelSchema::new("Post")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .to_model();
     assert!(
-        code.contains("many_to_many") || code.contains("via"),
-        "ManyToMany doit générer via"
+        code.contains("pub enum Relation {\n}\n\nimpl_signals!"),
+        "{}",
+        code
     );
+    assert!(!code.contains("impl Related"));
+}
+
+#[test]
+fn test_schema_to_model_relations_dedoublonnees() {
+    // Deux FK vers la même cible : un seul variant, un seul `Related`
+    let s = ModelSchema::new("Post")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .relation(RelationDef::belongs_to("user", "author_id", "id"))
+        .relation(RelationDef::belongs_to("user", "editor_id", "id"))
+        .relation(RelationDef::many_to_many("tag", "post_tag"))
+        .relation(RelationDef::has_many("post_tag"));
+    let code = s.to_model();
+    assert_eq!(code.matches("    User,\n").count(), 1, "{}", code);
+    assert!(code.contains("from = \"Column::AuthorId\""));
+    assert_eq!(code.matches("    PostTag,\n").count(), 1, "{}", code);
+    assert_eq!(code.matches("impl Related<super::user::Entity>").count(), 1);
+    assert_eq!(code.matches("impl Related<").count(), 3, "{}", code);
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -499,3 +1046,156 @@ fn test_schema_to_migration_ignored_col_skipped() {
     // Ne doit pas paniquer et ignorer le champ
     let _ = s.to_migration();
 }
+
+#[test]
+#[should_panic(expected = "auto_increment")]
+fn test_schema_to_migration_valide_la_pk() {
+    use sea_query::ColumnType;
+    let s = ModelSchema::new("UserRole").primary_key(
+        PrimaryKeyDef::composite(vec![
+            ("user_id".to_string(), ColumnType::Integer),
+            ("role_id".to_string(), ColumnType::Integer),
+        ])
+        .auto_increment(),
+    );
+    let _ = s.to_migration();
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Clé étrangère — colonne créée si absente
+// ═══════════════════════════════════════════════════════════════
+
+fn create_sql(s: &ModelSchema) -> String {
+    s.to_migration().to_string(sea_query::PostgresQueryBuilder)
+}
+
+#[test]
+fn test_fk_colonne_absente_ajoutee_en_entier() {
+    let s = ModelSchema::new("Post")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .foreign_key(ForeignKeyDef::new("author_id").references("users"));
+    let sql = create_sql(&s);
+    assert!(sql.contains(r#""author_id" integer NOT NULL"#), "{sql}");
+    assert!(sql.contains("FOREIGN KEY"), "{sql}");
+}
+
+#[test]
+fn test_fk_optionnelle_colonne_nullable() {
+    let s = ModelSchema::new("Post")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .foreign_key(
+            ForeignKeyDef::new("author_id")
+                .references("users")
+                .optional(),
+        );
+    let sql = create_sql(&s);
+    assert!(sql.contains(r#""author_id" integer"#), "{sql}");
+    assert!(!sql.contains(r#""author_id" integer NOT NULL"#), "{sql}");
+}
+
+#[test]
+fn test_fk_colonne_typee_comme_la_pk_referencee() {
+    let user = ModelSchema::new("User")
+        .table_name("users")
+        .primary_key(PrimaryKeyDef::new("id").uuid());
+    let s = ModelSchema::new("Post")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .foreign_key(ForeignKeyDef::new("author_id").references_model(&user));
+    let sql = create_sql(&s);
+    assert!(sql.contains(r#""author_id" uuid NOT NULL"#), "{sql}");
+}
+
+#[test]
+fn test_fk_colonne_declaree_non_dupliquee() {
+    let s = ModelSchema::new("Post")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .column(ColumnDef::new("author_id").big_integer())
+        .foreign_key(
+            ForeignKeyDef::new("author_id")
+                .references("users")
+                .key_type(sea_query::ColumnType::Integer),
+        );
+    let sql = create_sql(&s);
+    assert_eq!(sql.matches(r#""author_id" "#).count(), 1, "{sql}");
+    assert!(s.missing_fk_columns().unwrap().is_empty());
+}
+
+#[test]
+fn test_fk_build_ajoute_la_colonne() {
+    let s = ModelSchema::new("Post")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .foreign_key(ForeignKeyDef::new("author_id").references("users"))
+        .build()
+        .unwrap();
+    let col = s.columns.iter().find(|c| c.name == "author_id").unwrap();
+    assert_eq!(col.col_type, sea_query::ColumnType::Integer);
+    // Idempotent : la migration ne l'ajoute pas une seconde fois
+    assert_eq!(create_sql(&s).matches(r#""author_id" "#).count(), 1);
+}
+
+#[test]
+fn test_fk_build_refuse_un_type_incompatible() {
+    let err = ModelSchema::new("Post")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .column(ColumnDef::new("author_id").string())
+        .foreign_key(
+            ForeignKeyDef::new("author_id")
+                .references("users")
+                .key_type(sea_query::ColumnType::Integer),
+        )
+        .build()
+        .unwrap_err();
+    assert!(err.contains("Post"), "{err}");
+    assert!(err.contains("author_id"), "{err}");
+    assert!(err.contains("users.id"), "{err}");
+}
+
+#[test]
+#[should_panic(expected = "foreign key 'author_id'")]
+fn test_fk_to_migration_panique_sur_type_incompatible() {
+    let s = ModelSchema::new("Post")
+        .primary_key(PrimaryKeyDef::new("id"))
+        .column(ColumnDef::new("author_id").integer())
+        .foreign_key(
+            ForeignKeyDef::new("author_id")
+                .references("users")
+                .key_type(sea_query::ColumnType::Uuid),
+        );
+    let _ = s.to_migration();
+}
+
+// ═══════════════════════════════════════════════════════════════
+// ModelSchema::fk_column_to() — détection de la FK vers un parent
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_fk_column_to_par_foreign_key() {
+    let article = ModelSchema::new("Article").table_name("articles");
+    let comment = ModelSchema::new("Comment")
+        .foreign_key(ForeignKeyDef::new("author_id").references("users"))
+        .foreign_key(ForeignKeyDef::new("article_id").references("articles"));
+    assert_eq!(
+        comment.fk_column_to(&article).as_deref(),
+        Some("article_id")
+    );
+}
+
+#[test]
+fn test_fk_column_to_par_relation_belongs_to() {
+    let article = ModelSchema::new("Article").table_name("articles");
+    let comment =
+        ModelSchema::new("Comment").relation(RelationDef::belongs_to("article", "post_id", "id"));
+    assert_eq!(comment.fk_column_to(&article).as_deref(), Some("post_id"));
+}
+
+#[test]
+fn test_fk_column_to_ambigu_ou_absent() {
+    let user = ModelSchema::new("User").table_name("users");
+    let message = ModelSchema::new("Message")
+        .foreign_key(ForeignKeyDef::new("sender_id").references("users"))
+        .foreign_key(ForeignKeyDef::new("recipient_id").references("users"));
+    assert_eq!(message.fk_column_to(&user), None);
+
+    let tag = ModelSchema::new("Tag");
+    assert_eq!(tag.fk_column_to(&user), None);
+}
@@ -43,6 +43,23 @@ fn test_single_extend_block() {
     assert_eq!(result[0].columns[0].name, "bio");
 }
 
+#[test]
+fn test_form_hooks_ignore_par_le_parseur() {
+    let source = r#"
+        extend! {
+            table: "eihwaz_users",
+            form_hooks: true,
+            fields: {
+                bio: textarea,
+            }
+        }
+    "#;
+    let result = parse_extend_blocks_from_source(source);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].columns.len(), 1);
+    assert_eq!(result[0].columns[0].name, "bio");
+}
+
 #[test]
 fn test_primary_key_is_none() {
     let source = r#"
@@ -0,0 +1,361 @@
+//! Tests — migration/plan (`migrate --dry-run`, `showmigrations`, `rollback --to`)
+//!
+//! Couverture :
+//!   - base vierge : toutes les migrations planifiées, rien n'est exécuté
+//!     (ni les tables, ni `seaql_migrations`)
+//!   - migrations déjà appliquées exclues du plan
+//!   - SQL rendu pour le backend de la connexion, dans l'ordre du `Migrator`
+//!   - avertissement de perte de données sur `DROP COLUMN` / `DROP TABLE`
+//!   - statut : appliquée (avec horodatage) / en attente / orpheline
+//!   - rollback --to : SQL des `down()` du plus récent au plus ancien, exécution
+//!     jusqu'à la cible, refus si cible inconnue / non appliquée / orpheline présente
+
+use crate::helpers::db;
+use runique::migration::plan::{
+    MigrationState, migration_status, plan_pending, plan_rollback_to, rollback_to,
+};
+use sea_orm::ConnectionTrait;
+use sea_orm_migration::prelude::*;
+
+mod m1_create_notes {
+    use sea_orm_migration::prelude::*;
+
+    pub struct Migration;
+
+    impl MigrationName for Migration {
+        fn name(&self) -> &str {
+            "m1_create_notes"
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for Migration {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .create_table(
+                    Table::create()
+                        .table(Alias::new("plan_notes"))
+                        .col(
+                            ColumnDef::new(Alias::new("id"))
+                                .integer()
+                                .not_null()
+                                .primary_key(),
+                        )
+                        .col(ColumnDef::new(Alias::new("body")).text().not_null())
+                        .col(ColumnDef::new(Alias::new("legacy")).text().null())
+                        .to_owned(),
+                )
+                .await
+        }
+
+        async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .drop_table(Table::drop().table(Alias::new("plan_notes")).to_owned())
+                .await
+        }
+    }
+}
+
+mod m2_drop_legacy {
+    use sea_orm_migration::prelude::*;
+
+    pub struct Migration;
+
+    impl MigrationName for Migration {
+        fn name(&self) -> &str {
+            "m2_drop_legacy"
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for Migration {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new("plan_notes"))
+                        .drop_column(Alias::new("legacy"))
+                        .to_owned(),
+                )
+                .await?;
+            manager
+                .get_connection()
+                .execute_unprepared("DROP TABLE IF EXISTS plan_archive")
+                .await?;
+            Ok(())
+        }
+
+        async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new("plan_notes"))
+                        .add_column(ColumnDef::new(Alias::new("legacy")).text().null())
+                        .to_owned(),
+                )
+                .await
+        }
+    }
+}
+
+struct Migrator;
+
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m1_create_notes::Migration),
+            Box::new(m2_drop_legacy::Migration),
+        ]
+    }
+}
+
+async fn table_existe(conn: &sea_orm::DatabaseConnection, table: &str) -> bool {
+    let row = conn
+        .query_one_raw(sea_orm::Statement::from_string(
+            conn.get_database_backend(),
+            format!(
+                "SELECT COUNT(*) AS n FROM sqlite_master WHERE type = 'table' AND name = '{table}'"
+            ),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    row.try_get::<i32>("", "n").unwrap() > 0
+}
+
+#[tokio::test]
+async fn test_plan_base_vierge_rien_n_est_execute() {
+    let conn = db::fresh_db().await;
+
+    let plan = plan_pending::<Migrator>(&conn).await.unwrap();
+
+    let noms: Vec<&str> = plan.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(noms, ["m1_create_notes", "m2_drop_legacy"]);
+    assert!(!table_existe(&conn, "plan_notes").await);
+    assert!(!table_existe(&conn, "seaql_migrations").await);
+}
+
+#[tokio::test]
+async fn test_plan_sql_rendu_pour_le_backend() {
+    let conn = db::fresh_db().await;
+
+    let plan = plan_pending::<Migrator>(&conn).await.unwrap();
+
+    assert_eq!(plan[0].statements.len(), 1);
+    let create = &plan[0].statements[0];
+    assert!(
+        create.starts_with("CREATE TABLE \"plan_notes\""),
+        "{create}"
+    );
+    assert!(create.contains("\"body\" text NOT NULL"), "{create}");
+    assert!(plan[0].warnings.is_empty());
+    assert_eq!(
+        plan[1].statements,
+        [
+            "ALTER TABLE \"plan_notes\" DROP COLUMN \"legacy\"",
+            "DROP TABLE IF EXISTS plan_archive",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_plan_ignore_les_migrations_appliquees() {
+    let conn = db::fresh_db().await;
+    Migrator::up(&conn, Some(1)).await.unwrap();
+
+    let plan = plan_pending::<Migrator>(&conn).await.unwrap();
+
+    assert_eq!(plan.len(), 1);
+    assert_eq!(plan[0].name, "m2_drop_legacy");
+}
+
+#[tokio::test]
+async fn test_plan_tout_applique_est_vide() {
+    let conn = db::fresh_db().await;
+    Migrator::up(&conn, None).await.unwrap();
+
+    assert!(plan_pending::<Migrator>(&conn).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_plan_avertit_des_pertes_de_donnees() {
+    let conn = db::fresh_db().await;
+
+    let plan = plan_pending::<Migrator>(&conn).await.unwrap();
+
+    let warnings = &plan[1].warnings;
+    assert_eq!(warnings.len(), 2, "{warnings:?}");
+    assert!(warnings[0].contains("drops a column"), "{}", warnings[0]);
+    assert!(warnings[0].contains("legacy"));
+    assert!(warnings[1].contains("drops a table"), "{}", warnings[1]);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// migration_status (showmigrations)
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_statut_base_vierge_tout_en_attente() {
+    let conn = db::fresh_db().await;
+
+    let statut = migration_status::<Migrator>(&conn).await.unwrap();
+
+    assert_eq!(statut.len(), 2);
+    assert!(
+        statut
+            .iter()
+            .all(|m| m.state == MigrationState::Pending && m.applied_at.is_none())
+    );
+    assert!(!table_existe(&conn, "seaql_migrations").await);
+}
+
+#[tokio::test]
+async fn test_statut_appliquee_avec_horodatage() {
+    let conn = db::fresh_db().await;
+    let avant = chrono::Utc::now().timestamp();
+    Migrator::up(&conn, Some(1)).await.unwrap();
+
+    let statut = migration_status::<Migrator>(&conn).await.unwrap();
+
+    assert_eq!(statut[0].name, "m1_create_notes");
+    assert_eq!(statut[0].state, MigrationState::Applied);
+    let applique = statut[0].applied_at.expect("applied_at renseigné");
+    assert!(applique.timestamp() >= avant);
+    assert_eq!(statut[1].name, "m2_drop_legacy");
+    assert_eq!(statut[1].state, MigrationState::Pending);
+}
+
+#[tokio::test]
+async fn test_statut_detecte_les_orphelines() {
+    let conn = db::fresh_db().await;
+    Migrator::up(&conn, None).await.unwrap();
+    db::exec(
+        &conn,
+        "INSERT INTO seaql_migrations (version, applied_at) VALUES ('m0_supprimee', 1700000000)",
+    )
+    .await;
+
+    let statut = migration_status::<Migrator>(&conn).await.unwrap();
+
+    assert_eq!(statut.len(), 3);
+    let orpheline = statut.last().unwrap();
+    assert_eq!(orpheline.name, "m0_supprimee");
+    assert_eq!(orpheline.state, MigrationState::Orphaned);
+    assert_eq!(orpheline.applied_at.unwrap().timestamp(), 1_700_000_000);
+    assert!(
+        statut[..2]
+            .iter()
+            .all(|m| m.state == MigrationState::Applied)
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════
+// rollback_to (rollback --to)
+// ═══════════════════════════════════════════════════════════════
+
+#[tokio::test]
+async fn test_rollback_plan_down_du_plus_recent_au_plus_ancien() {
+    let conn = db::fresh_db().await;
+    Migrator::up(&conn, None).await.unwrap();
+
+    let plan = plan_rollback_to::<Migrator>(&conn, "m1_create_notes")
+        .await
+        .unwrap();
+
+    assert_eq!(plan.len(), 1);
+    assert_eq!(plan[0].name, "m2_drop_legacy");
+    assert_eq!(
+        plan[0].statements,
+        ["ALTER TABLE \"plan_notes\" ADD COLUMN \"legacy\" text NULL"]
+    );
+    // Le plan ne touche pas à la base
+    assert_eq!(
+        migration_status::<Migrator>(&conn).await.unwrap()[1].state,
+        MigrationState::Applied
+    );
+}
+
+#[tokio::test]
+async fn test_rollback_to_rejoue_les_down_jusqu_a_la_cible() {
+    let conn = db::fresh_db().await;
+    Migrator::up(&conn, None).await.unwrap();
+
+    let n = rollback_to::<Migrator>(&conn, "m1_create_notes")
+        .await
+        .unwrap();
+
+    assert_eq!(n, 1);
+    let statut = migration_status::<Migrator>(&conn).await.unwrap();
+    assert_eq!(statut[0].state, MigrationState::Applied);
+    assert_eq!(statut[1].state, MigrationState::Pending);
+    db::exec(
+        &conn,
+        "INSERT INTO plan_notes (id, body, legacy) VALUES (1, 'a', 'b')",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_rollback_cible_deja_la_plus_recente() {
+    let conn = db::fresh_db().await;
+    Migrator::up(&conn, None).await.unwrap();
+
+    let n = rollback_to::<Migrator>(&conn, "m2_drop_legacy")
+        .await
+        .unwrap();
+
+    assert_eq!(n, 0);
+    assert_eq!(
+        migration_status::<Migrator>(&conn).await.unwrap()[1].state,
+        MigrationState::Applied
+    );
+}
+
+#[tokio::test]
+async fn test_rollback_refuse_cible_non_appliquee() {
+    let conn = db::fresh_db().await;
+    Migrator::up(&conn, Some(1)).await.unwrap();
+
+    let err = rollback_to::<Migrator>(&conn, "m2_drop_legacy")
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("is not applied"), "{err}");
+    assert_eq!(
+        migration_status::<Migrator>(&conn).await.unwrap()[0].state,
+        MigrationState::Applied
+    );
+}
+
+#[tokio::test]
+async fn test_rollback_refuse_cible_inconnue() {
+    let conn = db::fresh_db().await;
+    Migrator::up(&conn, None).await.unwrap();
+
+    let err = plan_rollback_to::<Migrator>(&conn, "m9_inexistante")
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("unknown migration"), "{err}");
+}
+
+#[tokio::test]
+async fn test_rollback_refuse_avec_une_orpheline() {
+    let conn = db::fresh_db().await;
+    Migrator::up(&conn, None).await.unwrap();
+    db::exec(
+        &conn,
+        "INSERT INTO seaql_migrations (version, applied_at) VALUES ('m0_supprimee', 1700000000)",
+    )
+    .await;
+
+    let err = rollback_to::<Migrator>(&conn, "m1_create_notes")
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("m0_supprimee"), "{err}");
+    assert_eq!(
+        migration_status::<Migrator>(&conn).await.unwrap()[1].state,
+        MigrationState::Applied
+    );
+}
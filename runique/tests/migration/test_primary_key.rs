@@ -75,3 +75,83 @@ fn test_primary_key_to_sea_column_compile() {
     let pk = PrimaryKeyDef::new("id").i32();
     let _ = pk.to_sea_column();
 }
+
+// ═══════════════════════════════════════════════════════════════
+// Clé composite
+// ═══════════════════════════════════════════════════════════════
+
+fn user_role_pk() -> PrimaryKeyDef {
+    PrimaryKeyDef::composite(vec![
+        ("user_id".to_string(), ColumnType::Integer),
+        ("role_id".to_string(), ColumnType::Integer),
+    ])
+}
+
+#[test]
+fn test_primary_key_composite_defauts() {
+    let pk = user_role_pk();
+    assert!(pk.is_composite());
+    assert!(!pk.auto_increment);
+    assert_eq!(pk.name, "user_id");
+    assert_eq!(pk.column_names(), vec!["user_id", "role_id"]);
+}
+
+#[test]
+fn test_primary_key_simple_n_est_pas_composite() {
+    let pk = PrimaryKeyDef::new("id");
+    assert!(!pk.is_composite());
+    assert_eq!(pk.column_names(), vec!["id"]);
+    let (cols, index) = pk.to_sea_statements();
+    assert_eq!(cols.len(), 1);
+    assert!(index.is_none());
+}
+
+#[test]
+fn test_primary_key_composite_to_sea_statements() {
+    let (cols, index) = user_role_pk().to_sea_statements();
+    assert_eq!(cols.len(), 2);
+    assert!(index.is_some());
+}
+
+#[test]
+fn test_primary_key_composite_auto_increment_rejete() {
+    let pk = user_role_pk().auto_increment();
+    let err = pk.validate().unwrap_err();
+    assert!(err.contains("auto_increment"));
+    assert!(user_role_pk().validate().is_ok());
+}
+
+#[test]
+fn test_primary_key_composite_vide_rejete() {
+    let err = PrimaryKeyDef::composite(vec![]).validate().unwrap_err();
+    assert!(err.contains("at least one column"));
+}
+
+#[test]
+fn test_primary_key_composite_i64_sur_chaque_membre() {
+    let pk = user_role_pk().i64();
+    assert!(
+        pk.members()
+            .iter()
+            .all(|(_, t)| matches!(t, ColumnType::BigInteger))
+    );
+    assert!(matches!(pk.col_type, ColumnType::BigInteger));
+}
+
+#[test]
+fn test_primary_key_composite_uuid_sur_chaque_membre() {
+    let pk = user_role_pk().uuid();
+    assert!(
+        pk.members()
+            .iter()
+            .all(|(_, t)| matches!(t, ColumnType::Uuid))
+    );
+    assert!(!pk.auto_increment);
+    assert!(pk.validate().is_ok());
+}
+
+#[test]
+#[should_panic(expected = "composite primary key")]
+fn test_primary_key_composite_to_sea_column_panique() {
+    let _ = user_role_pk().to_sea_column();
+}
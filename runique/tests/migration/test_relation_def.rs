@@ -1,7 +1,7 @@
 // Tests pour RelationDef
 // RelationKind (Debug) est déjà couvert par test_relation_kind.rs
 
-use runique::migration::relation::{RelationDef, RelationKind};
+use runique::migration::relation::{ManyToManyWidget, RelationDef, RelationKind};
 
 // ═══════════════════════════════════════════════════════════════
 // has_one
@@ -98,3 +98,83 @@ fn test_relation_clone_many_to_many() {
     let cloned = rel.clone();
     assert_eq!(cloned.target, "tags");
 }
+
+// ═══════════════════════════════════════════════════════════════
+// ManyToMany — réglages du formulaire
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_relation_field_name_par_defaut_la_cible() {
+    let rel = RelationDef::many_to_many("tags", "article_tags");
+    assert_eq!(rel.field_name(), "tags");
+}
+
+#[test]
+fn test_relation_as_name_renomme_le_champ() {
+    let rel = RelationDef::many_to_many("tag", "article_tags").as_name("labels");
+    assert_eq!(rel.name.as_deref(), Some("labels"));
+    assert_eq!(rel.field_name(), "labels");
+}
+
+#[test]
+fn test_relation_widget_par_defaut_multiselect() {
+    let rel = RelationDef::many_to_many("tag", "article_tags");
+    assert_eq!(rel.m2m.widget, ManyToManyWidget::MultiSelect);
+    let rel = rel.widget(ManyToManyWidget::Checkboxes);
+    assert_eq!(rel.m2m.widget, ManyToManyWidget::Checkboxes);
+}
+
+#[test]
+fn test_relation_junction_colonnes_par_defaut() {
+    let rel = RelationDef::many_to_many("Tag", "ArticleTags");
+    assert_eq!(
+        rel.junction("BlogArticle"),
+        Some((
+            "article_tags".to_string(),
+            "blog_article_id".to_string(),
+            "tag_id".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_relation_junction_through() {
+    let rel = RelationDef::many_to_many("tag", "post_labels").through("post", "label");
+    assert_eq!(
+        rel.junction("Article"),
+        Some((
+            "post_labels".to_string(),
+            "post".to_string(),
+            "label".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_relation_junction_absente_hors_many_to_many() {
+    assert!(RelationDef::has_many("posts").junction("User").is_none());
+    assert!(
+        RelationDef::belongs_to("users", "user_id", "id")
+            .junction("Post")
+            .is_none()
+    );
+}
+
+#[test]
+fn test_relation_to_form_field_seulement_many_to_many() {
+    assert!(RelationDef::has_one("profile").to_form_field().is_none());
+    assert!(
+        RelationDef::many_to_many("tag", "article_tags")
+            .to_form_field()
+            .is_some()
+    );
+}
+
+#[test]
+fn test_relation_target_table_et_label_column() {
+    let rel = RelationDef::many_to_many("Label", "post_labels")
+        .target_table("tag")
+        .label_column("name");
+    assert_eq!(rel.m2m.target_table.as_deref(), Some("tag"));
+    assert_eq!(rel.m2m.label_column.as_deref(), Some("name"));
+}
@@ -2,7 +2,9 @@ pub mod clean_tpm_test;
 pub mod env;
 pub mod switch_lang;
 pub mod test_constante_parse;
+pub mod test_create_superuser;
 pub mod test_flash_message;
+pub mod test_i18n;
 pub mod test_init_logging;
 pub mod test_mailer;
 pub mod test_parse_boolean;
@@ -11,4 +13,7 @@ pub mod test_password;
 pub mod test_reset_token;
 pub mod test_runique_log;
 pub mod test_sanitizer;
+pub mod test_slugify;
+pub mod test_start_app;
+pub mod test_storage;
 pub mod test_url_params;
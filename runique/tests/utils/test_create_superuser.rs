@@ -0,0 +1,112 @@
+//! Tests — utils/cli/cli_admin.rs : création d'un superuser sans prompt (`--noinput`)
+//! Couvre : insertion staff + superuser hachée en Argon2, nom / email déjà pris,
+//!          longueur minimale et PasswordRules, variables d'environnement
+
+use crate::helpers::db;
+use crate::utils::env::{del_env, set_env};
+use runique::forms::PasswordRules;
+use runique::utils::cli::{SuperuserInput, insert_superuser};
+use runique::utils::password::BaseHash;
+
+const USERS_DDL: &str = "
+    CREATE TABLE eihwaz_users (
+        id          INTEGER PRIMARY KEY AUTOINCREMENT,
+        username    TEXT NOT NULL UNIQUE,
+        email       TEXT NOT NULL UNIQUE,
+        password    TEXT NOT NULL,
+        is_active   INTEGER NOT NULL DEFAULT 1,
+        is_staff    INTEGER NOT NULL DEFAULT 0,
+        is_superuser INTEGER NOT NULL DEFAULT 0,
+        created_at  TEXT,
+        updated_at  TEXT
+    )
+";
+
+fn input(username: &str, email: &str, password: &str) -> SuperuserInput {
+    SuperuserInput {
+        username: username.into(),
+        email: email.into(),
+        password: password.into(),
+    }
+}
+
+#[tokio::test]
+async fn test_insertion_superuser_argon2() {
+    let conn = db::fresh_db_with_schema(USERS_DDL).await;
+    let user = insert_superuser(
+        &conn,
+        input(" admin ", "Admin@Example.com", "correct-horse-battery"),
+        &PasswordRules::default(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(user.username, "admin");
+    assert_eq!(user.email, "admin@example.com");
+    assert!(user.is_active && user.is_staff && user.is_superuser);
+    assert!(user.password.starts_with("$argon2"));
+    assert!(BaseHash::new().verify("correct-horse-battery", &user.password));
+}
+
+#[tokio::test]
+async fn test_nom_ou_email_deja_pris_refuse() {
+    let conn = db::fresh_db_with_schema(USERS_DDL).await;
+    let rules = PasswordRules::default();
+    insert_superuser(&conn, input("admin", "a@example.com", "0123456789"), &rules)
+        .await
+        .unwrap();
+    assert!(
+        insert_superuser(&conn, input("admin", "b@example.com", "0123456789"), &rules)
+            .await
+            .is_err()
+    );
+    assert!(
+        insert_superuser(&conn, input("autre", "a@example.com", "0123456789"), &rules)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_mot_de_passe_et_email_invalides() {
+    let conn = db::fresh_db_with_schema(USERS_DDL).await;
+    let rules = PasswordRules {
+        require_digit: true,
+        ..Default::default()
+    };
+    for (email, password) in [
+        ("a@example.com", "court1"),
+        ("a@example.com", "sans-chiffre-du-tout"),
+        ("pas-un-email", "0123456789"),
+    ] {
+        assert!(
+            insert_superuser(&conn, input("admin", email, password), &rules)
+                .await
+                .is_err()
+        );
+    }
+    insert_superuser(
+        &conn,
+        input("admin", "a@example.com", "avec-1-chiffre"),
+        &rules,
+    )
+    .await
+    .unwrap();
+}
+
+#[test]
+fn test_lecture_des_variables_d_environnement() {
+    set_env("RUNIQUE_SUPERUSER_USERNAME", "root");
+    set_env("RUNIQUE_SUPERUSER_EMAIL", "root@example.com");
+    del_env("RUNIQUE_SUPERUSER_PASSWORD");
+    assert!(SuperuserInput::from_env().is_err());
+
+    set_env("RUNIQUE_SUPERUSER_PASSWORD", "0123456789");
+    let input = SuperuserInput::from_env().unwrap();
+    assert_eq!(input.username, "root");
+    assert_eq!(input.email, "root@example.com");
+    assert_eq!(input.password, "0123456789");
+
+    del_env("RUNIQUE_SUPERUSER_USERNAME");
+    del_env("RUNIQUE_SUPERUSER_EMAIL");
+    del_env("RUNIQUE_SUPERUSER_PASSWORD");
+}
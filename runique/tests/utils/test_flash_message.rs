@@ -1,7 +1,15 @@
-//! Tests — FlashMessage & MessageLevel
-//! Couvre : constructeurs, level CSS classes, contenu
-
-use runique::flash::{FlashMessage, MessageLevel};
+//! Tests — FlashMessage, MessageLevel & FlashConfig
+//! Couvre : constructeurs, level CSS classes, contenu, level sérialisé en minuscules,
+//!          plafond de la file (plus anciens supprimés), dédoublonnage consécutif,
+//!          rendu `alert-{{ m.level }}` via les macros
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use axum::{Router, response::IntoResponse, routing::get};
+use runique::app::RuniqueAppBuilder;
+use runique::config::app::RuniqueConfig;
+use runique::context::Request as TplRequest;
+use runique::flash::{FlashConfig, FlashMessage, MessageLevel};
+use runique::testing::{TestClient, test_db};
 
 // ── Constructeurs ─────────────────────────────────────────────────────────────
 
@@ -101,3 +109,103 @@ fn test_flash_now_macro_info() {
     let msgs = runique::flash_now!(info => "Info A", "Info B", "Info C");
     assert_eq!(msgs.len(), 3);
 }
+
+// ── Sérialisation du level ───────────────────────────────────────────────────
+
+#[test]
+fn test_level_serialise_en_minuscules() {
+    let json = serde_json::to_value(FlashMessage::warning("Attention")).unwrap();
+    assert_eq!(json["level"], "warning");
+    assert_eq!(MessageLevel::Success.as_str(), "success");
+}
+
+#[test]
+fn test_level_ancien_format_relu() {
+    // Messages mis en session avant le format minuscule
+    let msg: FlashMessage =
+        serde_json::from_str(r#"{ "content": "Ancien", "level": "Error" }"#).unwrap();
+    assert_eq!(msg.level, MessageLevel::Error);
+}
+
+// ── FlashConfig ───────────────────────────────────────────────────────────────
+
+fn enqueue_all(config: FlashConfig, messages: &[FlashMessage]) -> Vec<FlashMessage> {
+    let mut queue = Vec::new();
+    for msg in messages {
+        config.enqueue(&mut queue, msg.clone());
+    }
+    queue
+}
+
+#[test]
+fn test_plafond_supprime_les_plus_anciens() {
+    let messages: Vec<_> = (1..=5)
+        .map(|i| FlashMessage::info(format!("m{i}")))
+        .collect();
+    let queue = enqueue_all(FlashConfig::default().max_messages(3), &messages);
+    let contents: Vec<_> = queue.iter().map(|m| m.content.as_str()).collect();
+    assert_eq!(contents, ["m3", "m4", "m5"]);
+
+    assert_eq!(enqueue_all(FlashConfig::default(), &messages).len(), 5);
+}
+
+#[test]
+fn test_dedoublonnage_messages_consecutifs() {
+    let messages = [
+        FlashMessage::error("Échec"),
+        FlashMessage::error("Échec"),
+        FlashMessage::warning("Échec"),
+        FlashMessage::error("Échec"),
+    ];
+    // Même contenu mais level différent, ou non consécutif : conservé
+    let queue = enqueue_all(FlashConfig::default().deduplicate(true), &messages);
+    assert_eq!(queue.len(), 3);
+
+    assert_eq!(enqueue_all(FlashConfig::default(), &messages).len(), 4);
+}
+
+// ── Rendu ─────────────────────────────────────────────────────────────────────
+
+async fn push(req: TplRequest) -> impl IntoResponse {
+    runique::success!(req.notices => "Enregistré", "Enregistré");
+    runique::warning!(req.notices => "Quota proche");
+    runique::info!(req.notices => "Un", "Deux");
+    "ok"
+}
+
+async fn page(mut req: TplRequest) -> impl IntoResponse {
+    req.render("flash.html")
+}
+
+#[tokio::test]
+async fn test_rendu_classe_par_level_avec_politique() {
+    let templates = TestTempDir::new("runique_test_flash_message", "rendu");
+    std::fs::write(
+        templates.join("flash.html"),
+        r#"{% for m in messages %}[alert-{{ m.level }}:{{ m.content }}]{% endfor %}"#,
+    )
+    .unwrap();
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    config.static_files.templates_dir = vec![templates.as_str().to_string()];
+    let app = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_template_autoreload(false)
+        .with_flash(|f| f.max_messages(3).deduplicate(true))
+        .with_database(test_db().await)
+        .routes(
+            Router::new()
+                .route("/push", get(push))
+                .route("/page", get(page)),
+        )
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    let client = TestClient::from_app(app);
+
+    client.get("/push").await;
+    assert_eq!(
+        client.get("/page").await.text(),
+        "[alert-warning:Quota proche][alert-info:Un][alert-info:Deux]"
+    );
+}
@@ -0,0 +1,230 @@
+//! Tests — utils/trad/catalog.rs + utils/trad/locale.rs + balise `{% trans %}`
+//! Couvre : catalogues JSON (clés imbriquées) et .po (multi-lignes, fuzzy, pluriel),
+//!          repli locale par défaut → clé, négociation Accept-Language, fichier invalide,
+//!          résolution de la locale (préfixe d'URL, session, en-tête, défaut),
+//!          locale exposée au RuniqueContext
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use axum::{
+    Router, body::Body, extract::Path, http::Request as HttpRequest, response::IntoResponse,
+    routing::get,
+};
+use runique::app::RuniqueAppBuilder;
+use runique::config::{I18nConfig, app::RuniqueConfig};
+use runique::context::{Request as TplRequest, RuniqueContext};
+use runique::testing::{TestClient, test_db};
+use runique::utils::trad::{Catalogs, set_locale};
+use std::fs;
+
+const PO_FR: &str = r#"
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+
+# Navigation
+msgid "nav.home"
+msgstr "Accueil"
+
+msgid "intro"
+msgstr ""
+"Bienvenue "
+"sur le \"site\""
+
+#, fuzzy
+msgid "draft"
+msgstr "Brouillon"
+
+msgid "untranslated"
+msgstr ""
+
+msgid "item"
+msgid_plural "items"
+msgstr[0] "article"
+msgstr[1] "articles"
+"#;
+
+fn locales(name: &str) -> TestTempDir {
+    let dir = TestTempDir::new("runique_test_i18n", name);
+    fs::write(dir.join("fr.po"), PO_FR).unwrap();
+    fs::write(
+        dir.join("en.json"),
+        r#"{ "nav": { "home": "Home", "about": "About" }, "only_en": "English only" }"#,
+    )
+    .unwrap();
+    dir
+}
+
+fn load(dir: &TestTempDir) -> Catalogs {
+    Catalogs::load(&I18nConfig::default().locale_dir(dir.as_str())).unwrap()
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Catalogues
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_catalogue_json_cles_imbriquees() {
+    let dir = locales("json");
+    let catalogs = load(&dir);
+    assert_eq!(catalogs.lookup("en", "nav.home"), Some("Home"));
+    assert_eq!(catalogs.lookup("en", "nav.about"), Some("About"));
+}
+
+#[test]
+fn test_catalogue_po() {
+    let dir = locales("po");
+    let catalogs = load(&dir);
+    assert_eq!(catalogs.lookup("fr", "nav.home"), Some("Accueil"));
+    assert_eq!(
+        catalogs.lookup("fr", "intro"),
+        Some(r#"Bienvenue sur le "site""#)
+    );
+    assert_eq!(catalogs.lookup("fr", "item"), Some("article"));
+    // En-tête, fuzzy et non traduit : ignorés
+    assert_eq!(catalogs.lookup("fr", ""), None);
+    assert_eq!(catalogs.lookup("fr", "draft"), None);
+    assert_eq!(catalogs.lookup("fr", "untranslated"), None);
+}
+
+#[test]
+fn test_repli_locale_par_defaut_puis_cle() {
+    let dir = locales("repli");
+    let catalogs = load(&dir);
+    assert_eq!(catalogs.translate("fr", "nav.home"), "Accueil");
+    assert_eq!(catalogs.translate("fr", "only_en"), "English only");
+    assert_eq!(catalogs.translate("fr", "absent.key"), "absent.key");
+    assert_eq!(catalogs.translate("de", "nav.home"), "Home");
+}
+
+#[test]
+fn test_negociation_accept_language() {
+    let dir = locales("negociation");
+    let catalogs = load(&dir);
+    assert_eq!(catalogs.negotiate("fr-FR,fr;q=0.9,en;q=0.8"), Some("fr"));
+    assert_eq!(catalogs.negotiate("de-DE, en;q=0.5, fr;q=0.7"), Some("fr"));
+    assert_eq!(catalogs.negotiate("de, it;q=0.5"), None);
+    assert_eq!(catalogs.negotiate("fr;q=0, en"), Some("en"));
+    assert_eq!(catalogs.supported("FR_ca"), Some("fr"));
+}
+
+#[test]
+fn test_fichier_invalide_erreur() {
+    let dir = TestTempDir::new("runique_test_i18n", "invalide");
+    fs::write(dir.join("fr.po"), "msgid \"a\"\nmsgstr sans guillemets\n").unwrap();
+    let err = Catalogs::load(&I18nConfig::default().locale_dir(dir.as_str())).unwrap_err();
+    assert!(err.contains("fr.po") && err.contains("line 2"), "{err}");
+
+    fs::write(dir.join("fr.po"), "").unwrap();
+    fs::write(dir.join("en.json"), r#"{ "count": 3 }"#).unwrap();
+    let err = Catalogs::load(&I18nConfig::default().locale_dir(dir.as_str())).unwrap_err();
+    assert!(err.contains("count"), "{err}");
+}
+
+#[test]
+fn test_dossier_absent_catalogues_vides() {
+    let catalogs =
+        Catalogs::load(&I18nConfig::default().locale_dir("/chemin/inexistant/runique")).unwrap();
+    assert_eq!(catalogs.translate("fr", "nav.home"), "nav.home");
+    assert_eq!(catalogs.locales().collect::<Vec<_>>(), ["en"]);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Application : {% trans %} + résolution de la locale
+// ═══════════════════════════════════════════════════════════════
+
+async fn page(mut req: TplRequest) -> impl IntoResponse {
+    req.render("page.html")
+}
+
+async fn choose(Path(lang): Path<String>, req: TplRequest) -> impl IntoResponse {
+    set_locale(&req.session, &lang).await.unwrap();
+    "ok"
+}
+
+async fn context_locale(ctx: RuniqueContext) -> impl IntoResponse {
+    format!("{}:{}", ctx.locale, ctx.tpl.trans("nav.home"))
+}
+
+async fn client(locales: &TestTempDir, templates: &TestTempDir) -> TestClient {
+    fs::write(
+        templates.join("page.html"),
+        r#"{{ locale }}|{% trans "nav.home" %}|{% trans 'only_en' %}|{% trans "absent" %}"#,
+    )
+    .unwrap();
+    let mut config = RuniqueConfig::default();
+    config.server.secret_key = "test_secret_key_at_least_32_characters_long".to_string();
+    config.static_files.templates_dir = vec![templates.as_str().to_string()];
+    let app = RuniqueAppBuilder::new(config)
+        .no_statics()
+        .with_template_autoreload(false)
+        .with_i18n(|i| i.locale_dir(locales.as_str()).default_locale("en"))
+        .with_database(test_db().await)
+        .routes(
+            Router::new()
+                .route("/page", get(page))
+                .route("/fr/page", get(page))
+                .route("/lang/{lang}", get(choose))
+                .route("/ctx", get(context_locale)),
+        )
+        .build()
+        .await
+        .unwrap_or_else(|e| panic!("build failed: {e}"));
+    TestClient::from_app(app)
+}
+
+fn with_accept_language(path: &str, value: &str) -> HttpRequest<Body> {
+    HttpRequest::get(path)
+        .header("accept-language", value)
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_trans_locale_par_defaut() {
+    let (locales, templates) = (
+        locales("app_defaut"),
+        TestTempDir::new("runique_test_i18n", "tpl_defaut"),
+    );
+    let client = client(&locales, &templates).await;
+    assert_eq!(
+        client.get("/page").await.text(),
+        "en|Home|English only|absent"
+    );
+}
+
+#[tokio::test]
+async fn test_locale_accept_language_et_prefixe_url() {
+    let (locales, templates) = (
+        locales("app_header"),
+        TestTempDir::new("runique_test_i18n", "tpl_header"),
+    );
+    let client = client(&locales, &templates).await;
+
+    let resp = client
+        .request(with_accept_language("/page", "fr-FR,fr;q=0.9"))
+        .await;
+    assert_eq!(resp.text(), "fr|Accueil|English only|absent");
+
+    // Le préfixe d'URL l'emporte sur l'en-tête
+    let resp = client.request(with_accept_language("/fr/page", "en")).await;
+    assert_eq!(resp.text(), "fr|Accueil|English only|absent");
+}
+
+#[tokio::test]
+async fn test_locale_session_puis_contexte() {
+    let (locales, templates) = (
+        locales("app_session"),
+        TestTempDir::new("runique_test_i18n", "tpl_session"),
+    );
+    let client = client(&locales, &templates).await;
+
+    assert_eq!(client.get("/lang/fr").await.text(), "ok");
+    // La session l'emporte sur Accept-Language
+    let resp = client.request(with_accept_language("/page", "en")).await;
+    assert_eq!(resp.text(), "fr|Accueil|English only|absent");
+    assert_eq!(client.get("/ctx").await.text(), "fr:Accueil");
+
+    // Locale inconnue en session : ignorée
+    client.get("/lang/de").await;
+    assert_eq!(client.get("/ctx").await.text(), "en:Home");
+}
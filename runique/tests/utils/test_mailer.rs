@@ -1,4 +1,8 @@
-use runique::utils::mailer::{Email, MailerBackend, MailerConfig, dispatch_email, mailer_init};
+use runique::tera::{Context, Tera};
+use runique::utils::mailer::{
+    Email, MailerBackend, MailerConfig, MemoryMailer, dispatch_email, mailer_init, set_mailer,
+};
+use std::sync::OnceLock;
 
 fn init_console_mailer() {
     mailer_init(MailerConfig {
@@ -53,3 +57,101 @@ async fn test_email_sans_contenu_renvoie_erreur() {
         .await;
     assert!(result.is_err());
 }
+
+// ═══════════════════════════════════════════════════════════════
+// MemoryMailer
+// ═══════════════════════════════════════════════════════════════
+
+/// Mailer global partagé par tous les tests : une seule boîte, installée une fois.
+/// Chaque test filtre sur son propre destinataire.
+fn outbox() -> MemoryMailer {
+    static OUTBOX: OnceLock<MemoryMailer> = OnceLock::new();
+    OUTBOX
+        .get_or_init(|| {
+            let outbox = MemoryMailer::new().from("app@runique.rs");
+            set_mailer(outbox.clone());
+            outbox
+        })
+        .clone()
+}
+
+#[tokio::test]
+async fn test_memory_mailer_capture_l_email() {
+    let outbox = outbox();
+    dispatch_email("capture@example.com", "Sujet", "Corps")
+        .await
+        .unwrap();
+    // mailer_init ne remplace pas un mailer déjà installé
+    init_console_mailer();
+    Email::new()
+        .to("capture@example.com")
+        .subject("Second")
+        .html("<p>Deux</p>")
+        .reply_to("support@runique.rs")
+        .send()
+        .await
+        .unwrap();
+
+    let sent = outbox.sent_to("Capture@Example.com");
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[0].from, "app@runique.rs");
+    assert_eq!(sent[0].subject, "Sujet");
+    assert_eq!(sent[0].text.as_deref(), Some("Corps"));
+    assert_eq!(sent[1].body(), "<p>Deux</p>");
+    assert_eq!(sent[1].reply_to.as_deref(), Some("support@runique.rs"));
+}
+
+#[tokio::test]
+async fn test_memory_mailer_expediteur_explicite() {
+    let outbox = outbox();
+    Email::new()
+        .from("equipe@runique.rs")
+        .to("from@example.com")
+        .subject("Expéditeur")
+        .text("Corps")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        outbox.sent_to("from@example.com")[0].from,
+        "equipe@runique.rs"
+    );
+}
+
+#[tokio::test]
+async fn test_memory_mailer_corps_html_et_texte_depuis_templates() {
+    let outbox = outbox();
+    let mut tera = Tera::default();
+    tera.add_raw_templates(vec![
+        ("emails/reset.html", "<p>Bonjour {{ username }}</p>"),
+        ("emails/reset.txt", "Réinitialiser : {{ url }}"),
+    ])
+    .unwrap();
+    let mut ctx = Context::new();
+    ctx.insert("username", "Alice");
+    ctx.insert("url", "https://example.com/reset/abc");
+
+    Email::new()
+        .to("template@example.com")
+        .subject("Réinitialisation")
+        .template(&tera, "emails/reset.html", ctx.clone())
+        .unwrap()
+        .text_template(&tera, "emails/reset.txt", ctx)
+        .unwrap()
+        .send()
+        .await
+        .unwrap();
+
+    let sent = &outbox.sent_to("template@example.com")[0];
+    assert_eq!(sent.html.as_deref(), Some("<p>Bonjour Alice</p>"));
+    assert_eq!(
+        sent.text.as_deref(),
+        Some("Réinitialiser : https://example.com/reset/abc")
+    );
+}
+
+#[tokio::test]
+async fn test_template_inconnu_renvoie_erreur() {
+    let result = Email::new().template(&Tera::default(), "absent.html", Context::new());
+    assert!(result.is_err_and(|e| e.contains("absent.html")));
+}
@@ -1,7 +1,12 @@
-// Tests pour sanitize_strict, sanitize_rich, sanitize
+// Tests pour sanitize_strict, sanitize_rich, sanitize, SanitizePolicy
 // (complement to inline tests in sanitizer.rs)
 
-use runique::utils::forms::sanitizer::{sanitize, sanitize_rich, sanitize_strict};
+use runique::forms::base::FormField;
+use runique::forms::fields::TextField;
+use runique::utils::forms::sanitizer::{
+    SanitizePolicy, get_sanitize_policy, is_suspicious_content, register_sanitize_policy,
+    render_markdown, sanitize, sanitize_rich, sanitize_strict,
+};
 
 // ═══════════════════════════════════════════════════════════════
 // sanitize_strict — comportements de base
@@ -145,3 +150,139 @@ fn test_sanitize_champ_riche_bloque_quand_meme_script() {
     let out = sanitize("content", "<script>alert(1)</script>texte");
     assert!(!out.contains("<script>"));
 }
+
+// ═══════════════════════════════════════════════════════════════
+// SanitizePolicy — liste blanche configurable
+// ═══════════════════════════════════════════════════════════════
+
+fn politique_blog() -> SanitizePolicy {
+    SanitizePolicy::empty()
+        .allow_tags(["b", "a", "img", "span"])
+        .allow_attributes("a", ["href"])
+        .allow_attributes("img", ["src", "alt"])
+        .allow_attributes("span", ["class"])
+        .allow_url_schemes(["https", "mailto:"])
+}
+
+#[test]
+fn test_politique_defaut_identique_a_sanitize_rich() {
+    let html = r#"<p><b>gras</b> <a href="https://ex.fr">lien</a><span class="x">s</span></p>"#;
+    assert_eq!(SanitizePolicy::default().clean(html), sanitize_rich(html));
+}
+
+#[test]
+fn test_politique_vide_ne_garde_que_le_texte() {
+    assert_eq!(
+        SanitizePolicy::empty().clean("<p><b>gras</b> texte</p>"),
+        "gras texte"
+    );
+}
+
+#[test]
+fn test_politique_garde_balises_et_attributs_autorises() {
+    let out = politique_blog().clean(
+        r#"<b>gras</b><a href="mailto:a@b.fr">mail</a><img src="https://ex.fr/i.png" alt="i"><span class="hl">s</span><p>para</p>"#,
+    );
+    assert!(out.contains("<b>gras</b>"), "{out}");
+    assert!(out.contains(r#"href="mailto:a@b.fr""#), "{out}");
+    assert!(
+        out.contains(r#"<img src="https://ex.fr/i.png" alt="i">"#),
+        "{out}"
+    );
+    assert!(out.contains(r#"<span class="hl">s</span>"#), "{out}");
+    assert!(!out.contains("<p>"), "{out}");
+}
+
+#[test]
+fn test_politique_bloque_script_evenements_et_schemas() {
+    let out = politique_blog()
+        .allow_tags(["script"])
+        .allow_attributes("b", ["onclick"])
+        .clean(
+            r#"<b onclick="alert(1)">x</b><script>alert(1)</script><a href="javascript:alert(1)">j</a><img src="data:image/png;base64,AA">"#,
+        );
+    assert!(!out.contains("script"), "{out}");
+    assert!(!out.contains("onclick"), "{out}");
+    assert!(!out.contains("javascript"), "{out}");
+    assert!(!out.contains("data:"), "{out}");
+}
+
+#[test]
+fn test_politique_deny_tags_retire_du_defaut() {
+    let out = SanitizePolicy::default()
+        .deny_tags(["img"])
+        .clean(r#"<b>x</b><img src="https://ex.fr/i.png">"#);
+    assert_eq!(out, "<b>x</b>");
+}
+
+#[test]
+fn test_politique_enregistree_par_nom() {
+    register_sanitize_policy("test_sanitizer_blog", politique_blog());
+    let policy = get_sanitize_policy("test_sanitizer_blog").unwrap();
+    assert_eq!(
+        policy.clean("<span class=\"a\">s</span>"),
+        r#"<span class="a">s</span>"#
+    );
+    assert!(get_sanitize_policy("test_sanitizer_inconnue").is_none());
+}
+
+#[test]
+fn test_champ_richtext_avec_politique() {
+    let html = r#"<span class="hl" onclick="x()">s</span><script>alert(1)</script>"#;
+
+    let mut defaut = TextField::richtext("body");
+    defaut.set_value(html);
+    assert_eq!(defaut.base.value, "s");
+
+    let mut blog = TextField::richtext("body").sanitize_policy(politique_blog());
+    blog.set_value(html);
+    assert_eq!(blog.base.value, r#"<span class="hl">s</span>"#);
+}
+
+// ═══════════════════════════════════════════════════════════════
+// render_markdown — Markdown rendu puis nettoyé
+// ═══════════════════════════════════════════════════════════════
+
+#[test]
+fn test_render_markdown_conserve_le_markdown_legitime() {
+    let html = render_markdown(
+        "# Titre\n\n**gras** et [lien](https://example.com)\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n`code`",
+    );
+    assert!(html.contains("<h1>Titre</h1>"));
+    assert!(html.contains("<strong>gras</strong>"));
+    assert!(html.contains(r#"href="https://example.com""#));
+    assert!(html.contains("<table>"));
+    assert!(html.contains("<code>code</code>"));
+}
+
+#[test]
+fn test_render_markdown_bloque_les_injections() {
+    let payloads = [
+        "<script>alert(1)</script>",
+        "<img src=x onerror=alert(1)>",
+        "[clic](javascript:alert(1))",
+        "![img](data:text/html;base64,PHNjcmlwdD4=)",
+        "<iframe src=\"https://evil.example\"></iframe>",
+        "<a href=\"javascript:alert(1)\">x</a>",
+        "<p style=\"background:url(x)\" onclick=\"x()\">texte</p>",
+    ];
+    for p in payloads {
+        let html = render_markdown(p);
+        assert!(!is_suspicious_content(&html), "{p} → {html}");
+        assert!(!html.contains("<script"), "{p} → {html}");
+        assert!(!html.contains("<iframe"), "{p} → {html}");
+        assert!(!html.contains("onclick"), "{p} → {html}");
+        assert!(!html.contains("style="), "{p} → {html}");
+    }
+}
+
+#[test]
+fn test_render_markdown_liens_avec_rel() {
+    let html = render_markdown("[site](https://example.com)");
+    assert!(html.contains(r#"rel="noopener noreferrer""#));
+}
+
+#[test]
+fn test_render_markdown_vide() {
+    assert_eq!(render_markdown(""), "");
+}
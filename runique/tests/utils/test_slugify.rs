@@ -0,0 +1,47 @@
+//! Tests — utils/slug.rs
+//! Couvre : slugify (minuscules, accents, séparateurs, ponctuation), slugify_max
+
+use runique::utils::{slugify, slugify_max};
+
+#[test]
+fn test_slugify_basique() {
+    assert_eq!(slugify("Hello World"), "hello-world");
+}
+
+#[test]
+fn test_slugify_accents() {
+    assert_eq!(slugify("Crème brûlée à l'œuf"), "creme-brulee-a-loeuf");
+    assert_eq!(slugify("Straße Ærø"), "strasse-aero");
+}
+
+#[test]
+fn test_slugify_repetitions_fusionnees() {
+    assert_eq!(slugify("  a  --  b__c  "), "a-b-c");
+}
+
+#[test]
+fn test_slugify_ponctuation_supprimee() {
+    assert_eq!(slugify("Rust 2024: what's new?!"), "rust-2024-whats-new");
+}
+
+#[test]
+fn test_slugify_sans_caractere_latin() {
+    assert_eq!(slugify("日本語"), "");
+    assert_eq!(slugify("--"), "");
+}
+
+#[test]
+fn test_slugify_max_coupe_sur_un_mot() {
+    assert_eq!(slugify_max("The quick brown fox", 12), "the-quick");
+    assert_eq!(slugify_max("The quick brown fox", 9), "the-quick");
+}
+
+#[test]
+fn test_slugify_max_mot_unique_tronque() {
+    assert_eq!(slugify_max("Supercalifragilistic", 5), "super");
+}
+
+#[test]
+fn test_slugify_max_court_inchange() {
+    assert_eq!(slugify_max("abc", 10), "abc");
+}
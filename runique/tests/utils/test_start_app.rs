@@ -0,0 +1,64 @@
+//! Tests — utils/cli/start_app.rs : `runique startapp <name>`
+//! Couvre : fichiers générés et substitutions, aucun écrasement, noms refusés,
+//!          dossier src/ requis
+
+use crate::utils::clean_tpm_test::TestTempDir;
+use runique::utils::cli::create_app;
+use std::fs;
+
+fn project(suffix: &str) -> TestTempDir {
+    let dir = TestTempDir::new("runique_test_startapp", suffix);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    dir
+}
+
+#[test]
+fn test_fichiers_generes_et_substitues() {
+    let dir = project("ok");
+    create_app(&dir, "blog_post").unwrap();
+
+    for file in ["mod", "models", "forms", "views", "url", "admin"] {
+        assert!(dir.join(format!("src/blog_post/{file}.rs")).is_file());
+    }
+    let models = fs::read_to_string(dir.join("src/blog_post/models.rs")).unwrap();
+    assert!(models.contains("BlogPost,"));
+    assert!(models.contains(r#"table: "blog_post""#));
+    let forms = fs::read_to_string(dir.join("src/blog_post/forms.rs")).unwrap();
+    assert!(forms.contains("pub struct BlogPostForm;"));
+    let url = fs::read_to_string(dir.join("src/blog_post/url.rs")).unwrap();
+    assert!(url.contains(r#""/blog_post" => view!{ index }"#));
+    let html = fs::read_to_string(dir.join("templates/blog_post/index.html")).unwrap();
+    assert!(html.contains(r#"action="/blog_post""#));
+    assert!(html.contains("{{ title }}"));
+    assert!(!html.contains("APP_NAME"));
+}
+
+#[test]
+fn test_aucun_ecrasement() {
+    let dir = project("existant");
+    fs::create_dir_all(dir.join("src/blog")).unwrap();
+    fs::write(dir.join("src/blog/views.rs"), "// mes vues").unwrap();
+
+    assert!(create_app(&dir, "blog").is_err());
+    assert_eq!(
+        fs::read_to_string(dir.join("src/blog/views.rs")).unwrap(),
+        "// mes vues"
+    );
+    assert!(!dir.join("src/blog/models.rs").exists());
+}
+
+#[test]
+fn test_noms_refuses() {
+    let dir = project("noms");
+    for name in ["", "Blog", "blog-post", "1blog", "_blog", "mod", "views"] {
+        assert!(create_app(&dir, name).is_err(), "{name:?}");
+    }
+    assert_eq!(fs::read_dir(dir.join("src")).unwrap().count(), 0);
+}
+
+#[test]
+fn test_dossier_src_requis() {
+    let dir = TestTempDir::new("runique_test_startapp", "sans_src");
+    assert!(create_app(&dir, "blog").is_err());
+    assert!(!dir.join("src").exists());
+}